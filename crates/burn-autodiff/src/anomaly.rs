@@ -0,0 +1,133 @@
+//! Anomaly detection for the backward pass.
+//!
+//! When enabled with [set_detect_anomaly], every tracked operation created on the current thread
+//! records its name and, with the `std` feature, the backtrace of its creation. During the
+//! backward pass, the gradients produced by the backward step of those operations are checked,
+//! and the backward pass stops with a panic naming the forward operation as soon as a gradient
+//! contains NaN or infinite values.
+//!
+//! Anomaly detection synchronizes the device after each backward step, so it is meant for
+//! debugging. When disabled, it only costs reading a flag per operation.
+
+use alloc::format;
+use alloc::string::String;
+use burn_backend::{Backend, TensorMetadata, get_device_settings, tensor::FloatTensor};
+use burn_std::reader::try_read_sync;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DETECT_ANOMALY: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+// Without threads to scope it to, the flag is global.
+#[cfg(not(feature = "std"))]
+static DETECT_ANOMALY: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+
+/// Enables or disables anomaly detection for the operations created from now on on the current
+/// thread.
+#[cfg(feature = "std")]
+pub fn set_detect_anomaly(enabled: bool) {
+    DETECT_ANOMALY.with(|flag| flag.set(enabled));
+}
+
+/// Returns whether anomaly detection is enabled on the current thread.
+#[cfg(feature = "std")]
+pub fn is_anomaly_detection_enabled() -> bool {
+    DETECT_ANOMALY.with(|flag| flag.get())
+}
+
+/// Enables or disables anomaly detection for the operations created from now on.
+#[cfg(not(feature = "std"))]
+pub fn set_detect_anomaly(enabled: bool) {
+    DETECT_ANOMALY.store(enabled, core::sync::atomic::Ordering::Relaxed);
+}
+
+/// Returns whether anomaly detection is enabled.
+#[cfg(not(feature = "std"))]
+pub fn is_anomaly_detection_enabled() -> bool {
+    DETECT_ANOMALY.load(core::sync::atomic::Ordering::Relaxed)
+}
+
+/// The information recorded on a graph node when anomaly detection is enabled.
+#[derive(Debug)]
+pub struct NodeAnomaly {
+    op: &'static str,
+    #[cfg(feature = "std")]
+    backtrace: std::backtrace::Backtrace,
+}
+
+impl NodeAnomaly {
+    /// Records the operation with the given backward type, if anomaly detection is enabled.
+    pub(crate) fn capture<T>() -> Option<Self> {
+        if !is_anomaly_detection_enabled() {
+            return None;
+        }
+
+        Some(Self {
+            op: op_name(core::any::type_name::<T>()),
+            #[cfg(feature = "std")]
+            backtrace: std::backtrace::Backtrace::force_capture(),
+        })
+    }
+
+    /// The name of the forward operation.
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+
+    /// Panics with the name and the creation backtrace of the operation.
+    pub(crate) fn report(&self) -> ! {
+        panic!("{}", self.message())
+    }
+
+    fn message(&self) -> String {
+        #[cfg(feature = "std")]
+        let location = format!("\nThe operation was created at:\n{}", self.backtrace);
+        #[cfg(not(feature = "std"))]
+        let location = String::new();
+
+        format!(
+            "Anomaly detected: the backward pass of the operation `{}` produced a gradient with \
+             NaN or infinite values.{location}",
+            self.op
+        )
+    }
+}
+
+/// Returns whether the gradient contains NaN or infinite values.
+pub(crate) fn is_non_finite<B: Backend>(grad: FloatTensor<B>) -> bool {
+    let bool_dtype = get_device_settings::<B>(&grad.device()).bool_dtype;
+    let is_nan = B::float_is_nan(grad.clone(), bool_dtype);
+    let is_inf = B::float_is_inf(grad, bool_dtype);
+    let any = B::bool_any(B::bool_or(is_nan, is_inf));
+
+    try_read_sync(B::bool_into_data(any))
+        .expect("Anomaly detection needs to read the gradients synchronously.")
+        .expect("Anomaly detection failed to read the gradients.")
+        .iter::<bool>()
+        .next()
+        .unwrap_or(false)
+}
+
+/// Extracts the name of a backward type from its full path, without the module path nor the
+/// generic arguments.
+fn op_name(type_name: &'static str) -> &'static str {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut end = type_name.len();
+
+    for (index, char) in type_name.char_indices() {
+        match char {
+            '<' if depth == 0 && index > start => {
+                end = index;
+                break;
+            }
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ':' if depth == 0 => start = index + 1,
+            _ => {}
+        }
+    }
+
+    &type_name[start..end]
+}
@@ -0,0 +1,94 @@
+use alloc::{format, string::String};
+use core::marker::PhantomData;
+
+use burn_backend::backend::{Backend, BackendTypes, ExecutionError};
+
+use super::ForwardTensor;
+
+/// Enable forward-mode differentiation on a backend.
+///
+/// This works as a backend decorator: every float tensor carries a tangent next to its values,
+/// and every operation applies the chain rule to the tangent, so the tangent of an output is the
+/// Jacobian-vector product of the computation along the input tangents. Unlike
+/// [Autodiff](crate::Autodiff), no graph is recorded and the derivative is available as soon as
+/// the output is computed.
+///
+/// Decorating a backend with autodiff, as in `Dual<Autodiff<B>>`, tracks the tangent
+/// computations themselves, so a backward pass over a tangent gives second-order derivatives
+/// such as Hessian-vector products.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: BackendTypes> BackendTypes for Dual<B> {
+    type Device = B::Device;
+
+    type FloatTensorPrimitive = ForwardTensor<B>;
+
+    type IntTensorPrimitive = B::IntTensorPrimitive;
+
+    type BoolTensorPrimitive = B::BoolTensorPrimitive;
+
+    type QuantizedTensorPrimitive = B::QuantizedTensorPrimitive;
+
+    // A captured graph would only replay the primal values.
+    type GraphPrimitive = burn_backend::GraphUnsupported;
+}
+
+impl<B: Backend> Backend for Dual<B> {
+    fn ad_enabled(device: &Self::Device) -> bool {
+        B::ad_enabled(device)
+    }
+
+    fn name(device: &Self::Device) -> String {
+        format!("dual<{}>", B::name(device))
+    }
+
+    fn seed(device: &B::Device, seed: u64) {
+        B::seed(device, seed)
+    }
+
+    fn sync(device: &B::Device) -> Result<(), ExecutionError> {
+        B::sync(device)
+    }
+
+    fn memory_persistent_allocations<
+        Output: Send,
+        Input: Send,
+        Func: Fn(Input) -> Output + Send,
+    >(
+        device: &Self::Device,
+        input: Input,
+        func: Func,
+    ) -> Output {
+        B::memory_persistent_allocations(device, input, func)
+    }
+
+    fn memory_cleanup(device: &Self::Device) {
+        B::memory_cleanup(device)
+    }
+
+    fn staging<'a, Iter>(data: Iter, device: &Self::Device)
+    where
+        Iter: Iterator<Item = &'a mut burn_backend::TensorData>,
+    {
+        B::staging(data, device);
+    }
+
+    fn supports_dtype(device: &Self::Device, dtype: burn_std::DType) -> bool {
+        B::supports_dtype(device, dtype)
+    }
+
+    fn dtype_usage(device: &Self::Device, dtype: burn_std::DType) -> burn_backend::DTypeUsageSet {
+        B::dtype_usage(device, dtype)
+    }
+
+    fn device_count(type_id: u16) -> usize {
+        B::device_count(type_id)
+    }
+
+    fn flush(device: &Self::Device) {
+        B::flush(device)
+    }
+}
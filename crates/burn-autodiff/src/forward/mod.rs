@@ -0,0 +1,16 @@
+//! Forward-mode automatic differentiation with dual numbers.
+//!
+//! The [Dual] backend decorator carries a tangent next to the values of every float tensor
+//! ([ForwardTensor]) and applies the derivative of each operation to it, so a single forward
+//! pass gives the Jacobian-vector product of a computation. Constants have no tangent and run
+//! the inner backend directly.
+//!
+//! Differentiating a tangent with respect to the inputs, by running the decorator over the
+//! [Autodiff](crate::Autodiff) backend, gives Hessian-vector products (forward over reverse).
+
+mod backend;
+mod ops;
+mod tensor;
+
+pub use backend::*;
+pub use tensor::*;
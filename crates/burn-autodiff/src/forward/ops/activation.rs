@@ -0,0 +1,6 @@
+use burn_backend::{Backend, ops::ActivationOps};
+
+use crate::forward::Dual;
+
+// The default activations are written with float operations, which carry the tangents.
+impl<B: Backend> ActivationOps<Self> for Dual<B> {}
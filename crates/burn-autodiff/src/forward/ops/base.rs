@@ -0,0 +1,108 @@
+use burn_backend::{Backend, TensorMetadata, tensor::FloatTensor};
+use burn_std::Shape;
+
+use crate::forward::ForwardTensor;
+
+/// Applies a unary operation to the primal values.
+///
+/// The tangent function receives the input tangent, the input primal values and the output
+/// primal values, and is only called when the input has a tangent.
+pub(crate) fn unary<B, F, T>(tensor: ForwardTensor<B>, func: F, tangent: T) -> ForwardTensor<B>
+where
+    B: Backend,
+    F: FnOnce(FloatTensor<B>) -> FloatTensor<B>,
+    T: FnOnce(FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) -> FloatTensor<B>,
+{
+    match tensor.tangent {
+        None => ForwardTensor::constant(func(tensor.primal)),
+        Some(input_tangent) => {
+            let output = func(tensor.primal.clone());
+            let output_tangent = tangent(input_tangent, tensor.primal, output.clone());
+            ForwardTensor::new(output, output_tangent)
+        }
+    }
+}
+
+/// Applies a linear operation, which maps the tangent the same way as the primal values.
+pub(crate) fn linear<B, F>(tensor: ForwardTensor<B>, func: F) -> ForwardTensor<B>
+where
+    B: Backend,
+    F: Fn(FloatTensor<B>) -> FloatTensor<B>,
+{
+    let primal = func(tensor.primal);
+    let tangent = tensor.tangent.map(func);
+    ForwardTensor::from_parts(primal, tangent)
+}
+
+/// Applies a binary operation to the primal values.
+///
+/// The tangent function receives both input tangents, `None` for constants, followed by the
+/// primal values of both inputs and of the output. It is only called when at least one input
+/// has a tangent, and its result is broadcast to the shape of the output.
+pub(crate) fn binary<B, F, T>(
+    lhs: ForwardTensor<B>,
+    rhs: ForwardTensor<B>,
+    func: F,
+    tangent: T,
+) -> ForwardTensor<B>
+where
+    B: Backend,
+    F: FnOnce(FloatTensor<B>, FloatTensor<B>) -> FloatTensor<B>,
+    T: FnOnce(
+        Option<FloatTensor<B>>,
+        Option<FloatTensor<B>>,
+        FloatTensor<B>,
+        FloatTensor<B>,
+        FloatTensor<B>,
+    ) -> FloatTensor<B>,
+{
+    if lhs.is_constant() && rhs.is_constant() {
+        return ForwardTensor::constant(func(lhs.primal, rhs.primal));
+    }
+
+    let output = func(lhs.primal.clone(), rhs.primal.clone());
+    let output_tangent = tangent(
+        lhs.tangent,
+        rhs.tangent,
+        lhs.primal,
+        rhs.primal,
+        output.clone(),
+    );
+    let output_tangent = expand_to::<B>(output_tangent, output.shape());
+
+    ForwardTensor::new(output, output_tangent)
+}
+
+/// Adds the tangent terms of a binary operation, at least one of which is present.
+pub(crate) fn add_tangents<B: Backend>(
+    lhs: Option<FloatTensor<B>>,
+    rhs: Option<FloatTensor<B>>,
+) -> FloatTensor<B> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => B::float_add(lhs, rhs),
+        (Some(tangent), None) | (None, Some(tangent)) => tangent,
+        (None, None) => unreachable!("At least one operand should have a tangent"),
+    }
+}
+
+/// Subtracts the tangent terms of a binary operation, at least one of which is present.
+pub(crate) fn sub_tangents<B: Backend>(
+    lhs: Option<FloatTensor<B>>,
+    rhs: Option<FloatTensor<B>>,
+) -> FloatTensor<B> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => B::float_sub(lhs, rhs),
+        (Some(tangent), None) => tangent,
+        (None, Some(tangent)) => B::float_neg(tangent),
+        (None, None) => unreachable!("At least one operand should have a tangent"),
+    }
+}
+
+/// Expands a tangent computed from a broadcast operand to the shape of the output.
+pub(crate) fn expand_to<B: Backend>(tangent: FloatTensor<B>, shape: Shape) -> FloatTensor<B> {
+    if tangent.shape() == shape {
+        tangent
+    } else {
+        B::float_expand(tangent, shape)
+    }
+}
@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use burn_backend::{
+    Backend, DeviceId,
+    distributed::{
+        CollectiveTensor, DistributedConfig, DistributedOps, DistributedParams, ReduceOperation,
+        TensorRef,
+    },
+    tensor::FloatTensor,
+};
+
+use crate::forward::{Dual, ForwardTensor};
+
+impl<B: Backend> DistributedOps<Self> for Dual<B> {
+    fn start_communication_server(devices: &[B::Device], config: DistributedConfig) {
+        B::start_communication_server(devices, config);
+    }
+
+    fn close_communication_server(device: &B::Device) {
+        B::close_communication_server(device);
+    }
+
+    fn register_sync_parameters(device: &B::Device, distributed_params: Vec<DistributedParams>) {
+        B::register_sync_parameters(device, distributed_params);
+    }
+
+    fn submit_sync_collective(device: &B::Device) {
+        B::submit_sync_collective(device);
+    }
+
+    fn submit_gradient_sync(tensor: TensorRef<Self>, distributed_params: DistributedParams) {
+        let mut tensor = unsafe { (*tensor.0).clone() };
+        B::submit_gradient_sync(TensorRef(&mut tensor.primal), distributed_params);
+    }
+
+    fn all_reduce(
+        tensor: FloatTensor<Self>,
+        op: ReduceOperation,
+        device_ids: Vec<DeviceId>,
+    ) -> CollectiveTensor<Self> {
+        // The supported reductions are linear, so the tangents are reduced the same way.
+        let primal = B::all_reduce(tensor.primal, op, device_ids.clone());
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::all_reduce(tangent, op, device_ids));
+
+        // Safety: the handles are only wrapped in a new `CollectiveTensor`, which is resolved
+        // with `sync_collective` before use.
+        let output = unsafe {
+            ForwardTensor::from_parts(
+                primal.assume_resolved(),
+                tangent.map(|tangent| tangent.assume_resolved()),
+            )
+        };
+        CollectiveTensor::new(output)
+    }
+
+    fn sync_collective(device: &B::Device) {
+        B::sync_collective(device);
+    }
+}
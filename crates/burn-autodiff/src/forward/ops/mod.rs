@@ -0,0 +1,10 @@
+mod activation;
+mod base;
+mod bool_tensor;
+mod distributed;
+mod int_tensor;
+mod module;
+mod qtensor;
+mod sparse;
+mod tensor;
+mod transaction;
@@ -0,0 +1,458 @@
+use alloc::vec::Vec;
+
+use burn_backend::ops::attention::attention_fallback;
+use burn_backend::ops::*;
+use burn_backend::tensor::{BoolTensor, FloatTensor, IntTensor};
+use burn_backend::{Backend, TensorMetadata, get_device_settings};
+use burn_std::{IntDType, Shape};
+
+use super::base::linear;
+use crate::forward::{Dual, ForwardTensor};
+
+impl<B: Backend> ModuleOps<Self> for Dual<B> {
+    fn conv2d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv2d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn deform_conv2d(
+        x: FloatTensor<Self>,
+        offset: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        mask: Option<FloatTensor<Self>>,
+        bias: Option<FloatTensor<Self>>,
+        options: DeformConvOptions<2>,
+    ) -> FloatTensor<Self> {
+        assert!(
+            offset.is_constant(),
+            "Forward-mode differentiation of deform_conv2d along the offset isn't supported"
+        );
+
+        let func = |x, mask, weight, bias| {
+            B::deform_conv2d(
+                x,
+                offset.primal.clone(),
+                weight,
+                mask,
+                bias,
+                options.clone(),
+            )
+        };
+        let output = func(
+            x.primal.clone(),
+            mask.as_ref().map(|mask| mask.primal.clone()),
+            weight.primal.clone(),
+            bias.as_ref().map(|bias| bias.primal.clone()),
+        );
+
+        // The convolution is linear in the input, the mask and the weight taken separately.
+        let mask_primal = mask.as_ref().map(|mask| mask.primal.clone());
+        let terms = [
+            x.tangent
+                .map(|tangent| func(tangent, mask_primal.clone(), weight.primal.clone(), None)),
+            mask.and_then(|mask| mask.tangent)
+                .map(|tangent| func(x.primal.clone(), Some(tangent), weight.primal.clone(), None)),
+            weight
+                .tangent
+                .map(|tangent| func(x.primal, mask_primal, tangent, None)),
+            bias.and_then(|bias| bias.tangent)
+                .map(|tangent| bias_tangent::<B>(tangent, output.shape())),
+        ];
+
+        with_tangent_terms(output, terms)
+    }
+
+    fn deform_conv2d_backward(
+        x: FloatTensor<Self>,
+        offset: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        mask: Option<FloatTensor<Self>>,
+        bias: Option<FloatTensor<Self>>,
+        output_grad: FloatTensor<Self>,
+        options: DeformConvOptions<2>,
+    ) -> DeformConv2dBackward<Self> {
+        assert!(
+            x.is_constant()
+                && offset.is_constant()
+                && weight.is_constant()
+                && mask.as_ref().is_none_or(ForwardTensor::is_constant)
+                && bias.as_ref().is_none_or(ForwardTensor::is_constant),
+            "Forward-mode differentiation of deform_conv2d_backward is only supported along the \
+             output gradient"
+        );
+
+        // The gradients are linear in the output gradient.
+        let func = |output_grad| {
+            B::deform_conv2d_backward(
+                x.primal.clone(),
+                offset.primal.clone(),
+                weight.primal.clone(),
+                mask.as_ref().map(|mask| mask.primal.clone()),
+                bias.as_ref().map(|bias| bias.primal.clone()),
+                output_grad,
+                options.clone(),
+            )
+        };
+        let primal = func(output_grad.primal);
+        let tangent = output_grad.tangent.map(func);
+
+        let (x_tangent, offset_tangent, weight_tangent, mask_tangent, bias_tangent) = match tangent
+        {
+            Some(tangent) => (
+                Some(tangent.x_grad),
+                Some(tangent.offset_grad),
+                Some(tangent.weight_grad),
+                tangent.mask_grad,
+                tangent.bias_grad,
+            ),
+            None => (None, None, None, None, None),
+        };
+
+        DeformConv2dBackward::new(
+            ForwardTensor::from_parts(primal.x_grad, x_tangent),
+            ForwardTensor::from_parts(primal.offset_grad, offset_tangent),
+            ForwardTensor::from_parts(primal.weight_grad, weight_tangent),
+            primal
+                .mask_grad
+                .map(|mask_grad| ForwardTensor::from_parts(mask_grad, mask_tangent)),
+            primal
+                .bias_grad
+                .map(|bias_grad| ForwardTensor::from_parts(bias_grad, bias_tangent)),
+        )
+    }
+
+    fn conv3d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvOptions<3>,
+    ) -> FloatTensor<Self> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv3d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv_transpose2d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn conv_transpose3d(
+        x: FloatTensor<Self>,
+        weight: FloatTensor<Self>,
+        bias: Option<FloatTensor<Self>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<Self> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv_transpose3d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        linear(x, |x| {
+            B::avg_pool2d(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            )
+        })
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        linear(grad, |grad| {
+            B::avg_pool2d_backward(
+                x.primal.clone(),
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            )
+        })
+    }
+
+    fn adaptive_avg_pool2d(x: FloatTensor<Self>, output_size: [usize; 2]) -> FloatTensor<Self> {
+        linear(x, |x| B::adaptive_avg_pool2d(x, output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        linear(grad, |grad| {
+            B::adaptive_avg_pool2d_backward(x.primal.clone(), grad)
+        })
+    }
+
+    fn adaptive_avg_pool3d(x: FloatTensor<Self>, output_size: [usize; 3]) -> FloatTensor<Self> {
+        linear(x, |x| B::adaptive_avg_pool3d(x, output_size))
+    }
+
+    fn adaptive_avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        linear(grad, |grad| {
+            B::adaptive_avg_pool3d_backward(x.primal.clone(), grad)
+        })
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        if x.is_constant() {
+            return ForwardTensor::constant(B::max_pool2d(
+                x.primal,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+            ));
+        }
+
+        let dtype = get_device_settings::<B>(&x.device()).int_dtype;
+        Self::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation, ceil_mode, dtype)
+            .output
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        ceil_mode: bool,
+        indices_dtype: IntDType,
+    ) -> MaxPool2dWithIndices<Self> {
+        let output = B::max_pool2d_with_indices(
+            x.primal,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+            indices_dtype,
+        );
+        let tangent = x
+            .tangent
+            .map(|tangent| gather_pooled::<B>(tangent, output.indices.clone()));
+
+        MaxPool2dWithIndices::new(
+            ForwardTensor::from_parts(output.output, tangent),
+            output.indices,
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        ceil_mode: bool,
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool2dBackward<Self> {
+        let x_grad = linear(output_grad, |output_grad| {
+            B::max_pool2d_with_indices_backward(
+                x.primal.clone(),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+                output_grad,
+                indices.clone(),
+            )
+            .x_grad
+        });
+
+        MaxPool2dBackward::new(x_grad)
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self> {
+        linear(x, |x| B::interpolate(x, output_size, options.clone()))
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self> {
+        linear(grad, |grad| {
+            B::interpolate_backward(x.primal.clone(), grad, output_size, options.clone())
+        })
+    }
+
+    fn attention(
+        query: FloatTensor<Self>,
+        key: FloatTensor<Self>,
+        value: FloatTensor<Self>,
+        mask: Option<BoolTensor<Self>>,
+        attn_bias: Option<FloatTensor<Self>>,
+        options: AttentionModuleOptions,
+    ) -> FloatTensor<Self> {
+        let constant = query.is_constant()
+            && key.is_constant()
+            && value.is_constant()
+            && attn_bias.as_ref().is_none_or(ForwardTensor::is_constant);
+
+        if constant {
+            return ForwardTensor::constant(B::attention(
+                query.primal,
+                key.primal,
+                value.primal,
+                mask,
+                attn_bias.map(|bias| bias.primal),
+                options,
+            ));
+        }
+
+        // The fallback is written with differentiable operations.
+        attention_fallback::<Self>(query, key, value, mask, attn_bias, options)
+    }
+
+    fn rfft(
+        signal: FloatTensor<Self>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (re, im) = B::rfft(signal.primal, dim, n);
+        let (re_tangent, im_tangent) = match signal.tangent {
+            Some(tangent) => {
+                let (re, im) = B::rfft(tangent, dim, n);
+                (Some(re), Some(im))
+            }
+            None => (None, None),
+        };
+
+        (
+            ForwardTensor::from_parts(re, re_tangent),
+            ForwardTensor::from_parts(im, im_tangent),
+        )
+    }
+
+    fn irfft(
+        spectrum_re: FloatTensor<Self>,
+        spectrum_im: FloatTensor<Self>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> FloatTensor<Self> {
+        let tangent = (!spectrum_re.is_constant() || !spectrum_im.is_constant()).then(|| {
+            B::irfft(
+                spectrum_re.tangent_or_zeros(),
+                spectrum_im.tangent_or_zeros(),
+                dim,
+                n,
+            )
+        });
+
+        ForwardTensor::from_parts(
+            B::irfft(spectrum_re.primal, spectrum_im.primal, dim, n),
+            tangent,
+        )
+    }
+}
+
+/// Applies a convolution, which is linear in the input and the weight taken separately, with an
+/// optional bias added to each output channel.
+fn conv<B, F>(
+    x: ForwardTensor<B>,
+    weight: ForwardTensor<B>,
+    bias: Option<ForwardTensor<B>>,
+    func: F,
+) -> ForwardTensor<B>
+where
+    B: Backend,
+    F: Fn(FloatTensor<B>, FloatTensor<B>, Option<FloatTensor<B>>) -> FloatTensor<B>,
+{
+    let output = func(
+        x.primal.clone(),
+        weight.primal.clone(),
+        bias.as_ref().map(|bias| bias.primal.clone()),
+    );
+
+    let terms = [
+        x.tangent
+            .map(|tangent| func(tangent, weight.primal.clone(), None)),
+        weight.tangent.map(|tangent| func(x.primal, tangent, None)),
+        bias.and_then(|bias| bias.tangent)
+            .map(|tangent| bias_tangent::<B>(tangent, output.shape())),
+    ];
+
+    with_tangent_terms(output, terms)
+}
+
+/// Creates the output tensor with the sum of the tangent terms, or a constant without terms.
+fn with_tangent_terms<B: Backend, const N: usize>(
+    output: FloatTensor<B>,
+    terms: [Option<FloatTensor<B>>; N],
+) -> ForwardTensor<B> {
+    let tangent = terms.into_iter().flatten().reduce(B::float_add);
+    ForwardTensor::from_parts(output, tangent)
+}
+
+/// Broadcasts the tangent of a bias with one value per channel to the shape of the output.
+fn bias_tangent<B: Backend>(tangent: FloatTensor<B>, output_shape: Shape) -> FloatTensor<B> {
+    let mut shape: Vec<usize> = alloc::vec![1; output_shape.num_dims()];
+    shape[1] = output_shape[1];
+
+    B::float_expand(B::float_reshape(tangent, Shape::from(shape)), output_shape)
+}
+
+/// Gathers the tangent of a pooling input at the flat spatial indices of the pooled values.
+fn gather_pooled<B: Backend>(tangent: FloatTensor<B>, indices: IntTensor<B>) -> FloatTensor<B> {
+    let [batch_size, channels, height, width] = tangent.shape().dims();
+    let output_shape = indices.shape();
+    let [_, _, output_height, output_width] = output_shape.dims();
+
+    let tangent = B::float_reshape(tangent, Shape::new([batch_size, channels, height * width]));
+    let indices = B::int_reshape(
+        indices,
+        Shape::new([batch_size, channels, output_height * output_width]),
+    );
+
+    B::float_reshape(B::float_gather(2, tangent, indices), output_shape)
+}
@@ -0,0 +1,74 @@
+use burn_backend::{
+    Backend, ExecutionError, TensorData,
+    ops::QTensorOps,
+    quantization::QuantizationParametersPrimitive,
+    tensor::{Device, FloatTensor, IntTensor, QuantizedTensor},
+};
+use burn_std::{FloatDType, IntDType, QuantScheme, Shape};
+
+use crate::forward::{Dual, ForwardTensor};
+
+impl<B: Backend> QTensorOps<Self> for Dual<B> {
+    fn q_from_data(data: TensorData, device: &Device<Self>) -> QuantizedTensor<Self> {
+        B::q_from_data(data, device)
+    }
+
+    fn quantize(
+        tensor: FloatTensor<Self>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self> {
+        // The rounding isn't differentiable, so the tangent is dropped.
+        B::quantize(
+            tensor.primal,
+            scheme,
+            QuantizationParametersPrimitive {
+                scales: qparams.scales.primal,
+            },
+        )
+    }
+
+    fn quantize_dynamic(tensor: FloatTensor<Self>, scheme: &QuantScheme) -> QuantizedTensor<Self> {
+        B::quantize_dynamic(tensor.primal, scheme)
+    }
+
+    fn dequantize(tensor: QuantizedTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::dequantize(tensor, dtype))
+    }
+
+    fn q_to_device(tensor: QuantizedTensor<Self>, device: &Device<Self>) -> QuantizedTensor<Self> {
+        B::q_to_device(tensor, device)
+    }
+
+    fn q_reshape(tensor: QuantizedTensor<Self>, shape: Shape) -> QuantizedTensor<Self> {
+        B::q_reshape(tensor, shape)
+    }
+
+    async fn q_into_data(tensor: QuantizedTensor<Self>) -> Result<TensorData, ExecutionError> {
+        B::q_into_data(tensor).await
+    }
+
+    fn q_swap_dims(
+        tensor: QuantizedTensor<Self>,
+        dim1: usize,
+        dim2: usize,
+    ) -> QuantizedTensor<Self> {
+        B::q_swap_dims(tensor, dim1, dim2)
+    }
+
+    fn q_permute(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        B::q_permute(tensor, axes)
+    }
+
+    fn q_flip(tensor: QuantizedTensor<Self>, axes: &[usize]) -> QuantizedTensor<Self> {
+        B::q_flip(tensor, axes)
+    }
+
+    fn q_argmax(tensor: QuantizedTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<Self> {
+        B::q_argmax(tensor, dim, out_dtype)
+    }
+
+    fn q_argmin(tensor: QuantizedTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<Self> {
+        B::q_argmin(tensor, dim, out_dtype)
+    }
+}
@@ -0,0 +1,64 @@
+use burn_backend::{
+    Backend,
+    ops::SparseTensorOps,
+    tensor::{FloatTensor, IntTensor},
+};
+
+use super::base::{add_tangents, binary};
+use crate::forward::Dual;
+
+// The product is bilinear in the values and the dense matrix, like a dense matmul.
+impl<B: Backend> SparseTensorOps<Self> for Dual<B> {
+    fn sparse_coo_spmm(
+        row_indices: IntTensor<Self>,
+        col_indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+        rows: usize,
+    ) -> FloatTensor<Self> {
+        let spmm = |values, dense| {
+            B::sparse_coo_spmm(
+                row_indices.clone(),
+                col_indices.clone(),
+                values,
+                dense,
+                rows,
+            )
+        };
+
+        binary(
+            values,
+            dense,
+            spmm,
+            |values_tangent, dense_tangent, values, dense, _| {
+                add_tangents::<B>(
+                    values_tangent.map(|tangent| spmm(tangent, dense)),
+                    dense_tangent.map(|tangent| spmm(values, tangent)),
+                )
+            },
+        )
+    }
+
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<Self>,
+        col_indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        let spmm = |values, dense| {
+            B::sparse_csr_spmm(crow_indices.clone(), col_indices.clone(), values, dense)
+        };
+
+        binary(
+            values,
+            dense,
+            spmm,
+            |values_tangent, dense_tangent, values, dense, _| {
+                add_tangents::<B>(
+                    values_tangent.map(|tangent| spmm(tangent, dense)),
+                    dense_tangent.map(|tangent| spmm(values, tangent)),
+                )
+            },
+        )
+    }
+}
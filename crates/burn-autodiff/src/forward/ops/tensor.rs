@@ -0,0 +1,1199 @@
+use alloc::{vec, vec::Vec};
+
+use burn_backend::{
+    Backend, Distribution, ExecutionError, Scalar, TensorData, TensorMetadata, get_device_settings,
+    ops::{FloatTensorOps, GridSampleOptions, grid_sample::float_grid_sample_2d_ref},
+    tensor::{BoolTensor, Device, FloatTensor, IntTensor},
+};
+use burn_std::{BoolDType, FloatDType, IndexingUpdateOp, IntDType, Shape, Slice};
+
+use super::base::{add_tangents, binary, linear, sub_tangents, unary};
+use crate::forward::{Dual, ForwardTensor};
+
+impl<B: Backend> FloatTensorOps<Self> for Dual<B> {
+    fn float_from_data(data: TensorData, device: &Device<Self>) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_from_data(data, device))
+    }
+
+    fn float_random(
+        shape: Shape,
+        distribution: Distribution,
+        device: &Device<Self>,
+        dtype: FloatDType,
+    ) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_random(shape, distribution, device, dtype))
+    }
+
+    fn float_random_gamma(alpha: FloatTensor<Self>) -> FloatTensor<Self> {
+        // Sampling isn't differentiated, the samples are constants.
+        ForwardTensor::constant(B::float_random_gamma(alpha.primal))
+    }
+
+    fn float_zeros(shape: Shape, device: &Device<Self>, dtype: FloatDType) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_zeros(shape, device, dtype))
+    }
+
+    fn float_ones(shape: Shape, device: &Device<Self>, dtype: FloatDType) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_ones(shape, device, dtype))
+    }
+
+    fn float_full(
+        shape: Shape,
+        fill_value: Scalar,
+        device: &Device<Self>,
+        dtype: FloatDType,
+    ) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_full(shape, fill_value, device, dtype))
+    }
+
+    async fn float_into_data(tensor: FloatTensor<Self>) -> Result<TensorData, ExecutionError> {
+        B::float_into_data(tensor.primal).await
+    }
+
+    fn float_to_device(tensor: FloatTensor<Self>, device: &Device<Self>) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_to_device(tensor, device))
+    }
+
+    fn float_into_int(tensor: FloatTensor<Self>, out_dtype: IntDType) -> IntTensor<Self> {
+        B::float_into_int(tensor.primal, out_dtype)
+    }
+
+    fn float_empty(shape: Shape, device: &Device<Self>, dtype: FloatDType) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_empty(shape, device, dtype))
+    }
+
+    fn float_repeat_dim(tensor: FloatTensor<Self>, dim: usize, times: usize) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_repeat_dim(tensor, dim, times))
+    }
+
+    fn float_add(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary(lhs, rhs, B::float_add, |lhs, rhs, _, _, _| {
+            add_tangents::<B>(lhs, rhs)
+        })
+    }
+
+    fn float_add_scalar(lhs: FloatTensor<Self>, rhs: Scalar) -> FloatTensor<Self> {
+        unary(
+            lhs,
+            |lhs| B::float_add_scalar(lhs, rhs),
+            |tangent, _, _| tangent,
+        )
+    }
+
+    fn float_clamp_min(tensor: FloatTensor<Self>, min: Scalar) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_clamp_min(tensor, min),
+            |tangent, tensor, _| {
+                let dtype = bool_dtype::<B>(&tensor);
+                let clamped = B::float_lower_elem(tensor, min, dtype);
+                B::float_mask_fill(tangent, clamped, 0.into())
+            },
+        )
+    }
+
+    fn float_clamp_max(tensor: FloatTensor<Self>, max: Scalar) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_clamp_max(tensor, max),
+            |tangent, tensor, _| {
+                let dtype = bool_dtype::<B>(&tensor);
+                let clamped = B::float_greater_elem(tensor, max, dtype);
+                B::float_mask_fill(tangent, clamped, 0.into())
+            },
+        )
+    }
+
+    fn float_clamp(tensor: FloatTensor<Self>, min: Scalar, max: Scalar) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_clamp(tensor, min, max),
+            |tangent, tensor, _| {
+                let dtype = bool_dtype::<B>(&tensor);
+                let clamped = B::bool_or(
+                    B::float_lower_elem(tensor.clone(), min, dtype),
+                    B::float_greater_elem(tensor, max, dtype),
+                );
+                B::float_mask_fill(tangent, clamped, 0.into())
+            },
+        )
+    }
+
+    fn float_sub(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary(lhs, rhs, B::float_sub, |lhs, rhs, _, _, _| {
+            sub_tangents::<B>(lhs, rhs)
+        })
+    }
+
+    fn float_sub_scalar(lhs: FloatTensor<Self>, rhs: Scalar) -> FloatTensor<Self> {
+        unary(
+            lhs,
+            |lhs| B::float_sub_scalar(lhs, rhs),
+            |tangent, _, _| tangent,
+        )
+    }
+
+    fn float_mul(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary(
+            lhs,
+            rhs,
+            B::float_mul,
+            |lhs_tangent, rhs_tangent, lhs, rhs, _| {
+                add_tangents::<B>(
+                    lhs_tangent.map(|tangent| B::float_mul(tangent, rhs)),
+                    rhs_tangent.map(|tangent| B::float_mul(lhs, tangent)),
+                )
+            },
+        )
+    }
+
+    fn float_mul_scalar(lhs: FloatTensor<Self>, rhs: Scalar) -> FloatTensor<Self> {
+        linear(lhs, |lhs| B::float_mul_scalar(lhs, rhs))
+    }
+
+    fn float_div(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // (a / b)' = (a' - (a / b) b') / b
+        binary(
+            lhs,
+            rhs,
+            B::float_div,
+            |lhs_tangent, rhs_tangent, _, rhs, output| {
+                let numerator = sub_tangents::<B>(
+                    lhs_tangent,
+                    rhs_tangent.map(|tangent| B::float_mul(output, tangent)),
+                );
+                B::float_div(numerator, rhs)
+            },
+        )
+    }
+
+    fn float_div_scalar(lhs: FloatTensor<Self>, rhs: Scalar) -> FloatTensor<Self> {
+        linear(lhs, |lhs| B::float_div_scalar(lhs, rhs))
+    }
+
+    fn float_remainder(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // a % b = a - floor(a / b) b, where the floor is piecewise constant.
+        binary(
+            lhs,
+            rhs,
+            B::float_remainder,
+            |lhs_tangent, rhs_tangent, lhs, rhs, _| {
+                sub_tangents::<B>(
+                    lhs_tangent,
+                    rhs_tangent.map(|tangent| {
+                        B::float_mul(B::float_floor(B::float_div(lhs, rhs)), tangent)
+                    }),
+                )
+            },
+        )
+    }
+
+    fn float_remainder_scalar(lhs: FloatTensor<Self>, rhs: Scalar) -> FloatTensor<Self> {
+        unary(
+            lhs,
+            |lhs| B::float_remainder_scalar(lhs, rhs),
+            |tangent, _, _| tangent,
+        )
+    }
+
+    fn float_matmul(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary(
+            lhs,
+            rhs,
+            B::float_matmul,
+            |lhs_tangent, rhs_tangent, lhs, rhs, _| {
+                add_tangents::<B>(
+                    lhs_tangent.map(|tangent| B::float_matmul(tangent, rhs)),
+                    rhs_tangent.map(|tangent| B::float_matmul(lhs, tangent)),
+                )
+            },
+        )
+    }
+
+    fn float_cross(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        binary(
+            lhs,
+            rhs,
+            |lhs, rhs| B::float_cross(lhs, rhs, dim),
+            |lhs_tangent, rhs_tangent, lhs, rhs, _| {
+                add_tangents::<B>(
+                    lhs_tangent.map(|tangent| B::float_cross(tangent, rhs, dim)),
+                    rhs_tangent.map(|tangent| B::float_cross(lhs, tangent, dim)),
+                )
+            },
+        )
+    }
+
+    fn float_neg(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        linear(tensor, B::float_neg)
+    }
+
+    fn float_recip(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_recip, |tangent, _, output| {
+            B::float_neg(B::float_mul(tangent, B::float_mul(output.clone(), output)))
+        })
+    }
+
+    fn float_transpose(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        linear(tensor, B::float_transpose)
+    }
+
+    fn float_swap_dims(tensor: FloatTensor<Self>, dim1: usize, dim2: usize) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_swap_dims(tensor, dim1, dim2))
+    }
+
+    fn float_permute(tensor: FloatTensor<Self>, axes: &[usize]) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_permute(tensor, axes))
+    }
+
+    fn float_flip(tensor: FloatTensor<Self>, axes: &[usize]) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_flip(tensor, axes))
+    }
+
+    fn float_reshape(tensor: FloatTensor<Self>, shape: Shape) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_reshape(tensor, shape.clone()))
+    }
+
+    fn float_gather(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        linear(tensor, |tensor| {
+            B::float_gather(dim, tensor, indices.clone())
+        })
+    }
+
+    fn float_scatter_add(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        bilinear_update(tensor, value, |tensor, value| {
+            B::float_scatter_add(dim, tensor, indices.clone(), value)
+        })
+    }
+
+    fn float_scatter_nd(
+        data: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        reduction: IndexingUpdateOp,
+    ) -> FloatTensor<Self> {
+        match reduction {
+            IndexingUpdateOp::Add | IndexingUpdateOp::Assign => {
+                bilinear_update(data, values, |data, values| {
+                    B::float_scatter_nd(data, indices.clone(), values, reduction)
+                })
+            }
+            IndexingUpdateOp::Mul => {
+                // out[idx] = data[idx] values, so out' = data' (at idx: * values) + data[idx] values'
+                // at the indexed positions, assuming unique indices.
+                let primal = B::float_scatter_nd(
+                    data.primal.clone(),
+                    indices.clone(),
+                    values.primal.clone(),
+                    reduction,
+                );
+                if data.is_constant() && values.is_constant() {
+                    return ForwardTensor::constant(primal);
+                }
+
+                let data_term = B::float_scatter_nd(
+                    data.tangent_or_zeros(),
+                    indices.clone(),
+                    values.primal,
+                    IndexingUpdateOp::Mul,
+                );
+                let tangent = match values.tangent {
+                    Some(tangent) => {
+                        let scaled =
+                            B::float_mul(B::float_gather_nd(data.primal, indices.clone()), tangent);
+                        B::float_scatter_nd(data_term, indices, scaled, IndexingUpdateOp::Add)
+                    }
+                    None => data_term,
+                };
+                ForwardTensor::new(primal, tangent)
+            }
+            IndexingUpdateOp::Min | IndexingUpdateOp::Max => {
+                // The tangent follows the selected operand at each indexed position.
+                let primal = B::float_scatter_nd(
+                    data.primal.clone(),
+                    indices.clone(),
+                    values.primal.clone(),
+                    reduction,
+                );
+                if data.is_constant() && values.is_constant() {
+                    return ForwardTensor::constant(primal);
+                }
+
+                let dtype = bool_dtype::<B>(&primal);
+                let current = B::float_gather_nd(data.primal.clone(), indices.clone());
+                let values_selected = match reduction {
+                    IndexingUpdateOp::Min => B::float_lower(values.primal.clone(), current, dtype),
+                    _ => B::float_greater(values.primal.clone(), current, dtype),
+                };
+                let data_tangent = data.tangent_or_zeros();
+                let selected = B::float_mask_where(
+                    B::float_gather_nd(data_tangent.clone(), indices.clone()),
+                    values_selected,
+                    values.tangent_or_zeros(),
+                );
+                let tangent =
+                    B::float_scatter_nd(data_tangent, indices, selected, IndexingUpdateOp::Assign);
+                ForwardTensor::new(primal, tangent)
+            }
+        }
+    }
+
+    fn float_gather_nd(data: FloatTensor<Self>, indices: IntTensor<Self>) -> FloatTensor<Self> {
+        linear(data, |data| B::float_gather_nd(data, indices.clone()))
+    }
+
+    fn float_select(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
+    ) -> FloatTensor<Self> {
+        linear(tensor, |tensor| {
+            B::float_select(tensor, dim, indices.clone())
+        })
+    }
+
+    fn float_select_add(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        bilinear_update(tensor, value, |tensor, value| {
+            B::float_select_add(tensor, dim, indices.clone(), value)
+        })
+    }
+
+    fn float_slice(tensor: FloatTensor<Self>, slices: &[Slice]) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_slice(tensor, slices))
+    }
+
+    fn float_slice_assign(
+        tensor: FloatTensor<Self>,
+        slices: &[Slice],
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        bilinear_update(tensor, value, |tensor, value| {
+            B::float_slice_assign(tensor, slices, value)
+        })
+    }
+
+    fn float_mask_where(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<Self>,
+        value: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        bilinear_update(tensor, value, |tensor, value| {
+            B::float_mask_where(tensor, mask.clone(), value)
+        })
+    }
+
+    fn float_mask_fill(
+        tensor: FloatTensor<Self>,
+        mask: BoolTensor<Self>,
+        value: Scalar,
+    ) -> FloatTensor<Self> {
+        let mask_tangent = mask.clone();
+        unary(
+            tensor,
+            |tensor| B::float_mask_fill(tensor, mask, value),
+            |tangent, _, _| B::float_mask_fill(tangent, mask_tangent, 0.into()),
+        )
+    }
+
+    fn float_equal(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_equal(lhs.primal, rhs.primal, out_dtype)
+    }
+
+    fn float_not_equal(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_not_equal(lhs.primal, rhs.primal, out_dtype)
+    }
+
+    fn float_equal_elem(
+        lhs: FloatTensor<Self>,
+        rhs: Scalar,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_equal_elem(lhs.primal, rhs, out_dtype)
+    }
+
+    fn float_not_equal_elem(
+        lhs: FloatTensor<Self>,
+        rhs: Scalar,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_not_equal_elem(lhs.primal, rhs, out_dtype)
+    }
+
+    fn float_greater(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_greater(lhs.primal, rhs.primal, out_dtype)
+    }
+
+    fn float_greater_elem(
+        lhs: FloatTensor<Self>,
+        rhs: Scalar,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_greater_elem(lhs.primal, rhs, out_dtype)
+    }
+
+    fn float_greater_equal(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_greater_equal(lhs.primal, rhs.primal, out_dtype)
+    }
+
+    fn float_greater_equal_elem(
+        lhs: FloatTensor<Self>,
+        rhs: Scalar,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_greater_equal_elem(lhs.primal, rhs, out_dtype)
+    }
+
+    fn float_lower(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_lower(lhs.primal, rhs.primal, out_dtype)
+    }
+
+    fn float_lower_elem(
+        lhs: FloatTensor<Self>,
+        rhs: Scalar,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_lower_elem(lhs.primal, rhs, out_dtype)
+    }
+
+    fn float_lower_equal(
+        lhs: FloatTensor<Self>,
+        rhs: FloatTensor<Self>,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_lower_equal(lhs.primal, rhs.primal, out_dtype)
+    }
+
+    fn float_lower_equal_elem(
+        lhs: FloatTensor<Self>,
+        rhs: Scalar,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_lower_equal_elem(lhs.primal, rhs, out_dtype)
+    }
+
+    fn float_is_nan(tensor: FloatTensor<Self>, out_dtype: BoolDType) -> BoolTensor<Self> {
+        B::float_is_nan(tensor.primal, out_dtype)
+    }
+
+    fn float_is_inf(tensor: FloatTensor<Self>, out_dtype: BoolDType) -> BoolTensor<Self> {
+        B::float_is_inf(tensor.primal, out_dtype)
+    }
+
+    fn float_detach(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // Detaching stops the derivatives, in forward mode as well.
+        ForwardTensor::constant(B::float_detach(tensor.primal))
+    }
+
+    fn float_set_require_grad(tensor: FloatTensor<Self>, require_grad: bool) -> FloatTensor<Self> {
+        ForwardTensor::from_parts(
+            B::float_set_require_grad(tensor.primal, require_grad),
+            tensor.tangent,
+        )
+    }
+
+    fn float_is_require_grad(tensor: &FloatTensor<Self>) -> bool {
+        B::float_is_require_grad(&tensor.primal)
+    }
+
+    fn float_set_per_sample_grad(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        ForwardTensor::from_parts(B::float_set_per_sample_grad(tensor.primal), tensor.tangent)
+    }
+
+    fn float_is_per_sample_grad(tensor: &FloatTensor<Self>) -> bool {
+        B::float_is_per_sample_grad(&tensor.primal)
+    }
+
+    fn float_sum(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        linear(tensor, B::float_sum)
+    }
+
+    fn float_sum_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_sum_dim(tensor, dim))
+    }
+
+    fn float_prod(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // prod(x)' = sum(x' prod(x) / x), which isn't defined when an element is zero.
+        unary(tensor, B::float_prod, |tangent, tensor, output| {
+            B::float_sum(B::float_mul(tangent, B::float_div(output, tensor)))
+        })
+    }
+
+    fn float_prod_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_prod_dim(tensor, dim),
+            |tangent, tensor, output| {
+                B::float_sum_dim(B::float_mul(tangent, B::float_div(output, tensor)), dim)
+            },
+        )
+    }
+
+    fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        linear(tensor, B::float_mean)
+    }
+
+    fn float_mean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_mean_dim(tensor, dim))
+    }
+
+    fn float_logsumexp_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        // The tangent is the mean of the input tangent weighted by the softmax of the input.
+        unary(
+            tensor,
+            |tensor| B::float_logsumexp_dim(tensor, dim),
+            |tangent, tensor, output| {
+                let softmax = B::float_exp(B::float_sub(tensor, output));
+                B::float_sum_dim(B::float_mul(tangent, softmax), dim)
+            },
+        )
+    }
+
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_cumsum(tensor, dim))
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        // cumprod(x)' = cumprod(x) cumsum(x' / x), which isn't defined when an element is zero.
+        unary(
+            tensor,
+            |tensor| B::float_cumprod(tensor, dim),
+            |tangent, tensor, output| {
+                B::float_mul(output, B::float_cumsum(B::float_div(tangent, tensor), dim))
+            },
+        )
+    }
+
+    fn float_cummin(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_cummin(tensor, dim),
+            |tangent, tensor, output| {
+                let indices = cumulative_source_indices::<B>(tensor, output, dim);
+                B::float_gather(dim, tangent, indices)
+            },
+        )
+    }
+
+    fn float_cummax(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_cummax(tensor, dim),
+            |tangent, tensor, output| {
+                let indices = cumulative_source_indices::<B>(tensor, output, dim);
+                B::float_gather(dim, tangent, indices)
+            },
+        )
+    }
+
+    fn float_linear_recurrence(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        // Differentiating `h[t] = a[t] h[t - 1] + b[t]` gives the recurrence
+        // `h'[t] = a[t] h'[t - 1] + (a'[t] h[t - 1] + b'[t])` with the same coefficients.
+        let output = B::float_linear_recurrence(a.primal.clone(), b.primal, dim);
+        if a.is_constant() && b.is_constant() {
+            return ForwardTensor::constant(output);
+        }
+
+        let previous = shift_forward::<B>(output.clone(), dim);
+        let input = add_tangents::<B>(
+            a.tangent.map(|tangent| B::float_mul(tangent, previous)),
+            b.tangent,
+        );
+        let tangent = B::float_linear_recurrence(a.primal, input, dim);
+
+        ForwardTensor::new(output, tangent)
+    }
+
+    fn float_argmax(tensor: FloatTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<Self> {
+        B::float_argmax(tensor.primal, dim, out_dtype)
+    }
+
+    fn float_argmin(tensor: FloatTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<Self> {
+        B::float_argmin(tensor.primal, dim, out_dtype)
+    }
+
+    fn float_argtopk(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        k: usize,
+        out_dtype: IntDType,
+    ) -> IntTensor<Self> {
+        B::float_argtopk(tensor.primal, dim, k, out_dtype)
+    }
+
+    fn float_topk_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        k: usize,
+        out_dtype: IntDType,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        let (values, indices) = B::float_topk_with_indices(tensor.primal, dim, k, out_dtype);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (ForwardTensor::from_parts(values, tangent), indices)
+    }
+
+    fn float_topk(tensor: FloatTensor<Self>, dim: usize, k: usize) -> FloatTensor<Self> {
+        if tensor.is_constant() {
+            return ForwardTensor::constant(B::float_topk(tensor.primal, dim, k));
+        }
+
+        let dtype = get_device_settings::<B>(&tensor.device()).int_dtype;
+        Self::float_topk_with_indices(tensor, dim, k, dtype).0
+    }
+
+    fn float_exp(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_exp, |tangent, _, output| {
+            B::float_mul(tangent, output)
+        })
+    }
+
+    fn float_log(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_log, |tangent, tensor, _| {
+            B::float_div(tangent, tensor)
+        })
+    }
+
+    fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_log1p, |tangent, tensor, _| {
+            B::float_div(tangent, B::float_add_scalar(tensor, 1.into()))
+        })
+    }
+
+    fn float_powf(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // (a^b)' = b a^(b - 1) a' + a^b log(a) b'
+        binary(
+            lhs,
+            rhs,
+            B::float_powf,
+            |lhs_tangent, rhs_tangent, lhs, rhs, output| {
+                add_tangents::<B>(
+                    lhs_tangent.map(|tangent| {
+                        let exponent = B::float_sub_scalar(rhs.clone(), 1.into());
+                        let derivative = B::float_mul(rhs, B::float_powf(lhs.clone(), exponent));
+                        B::float_mul(tangent, derivative)
+                    }),
+                    rhs_tangent.map(|tangent| {
+                        B::float_mul(tangent, B::float_mul(output, B::float_log(lhs)))
+                    }),
+                )
+            },
+        )
+    }
+
+    fn float_powf_scalar_impl(tensor: FloatTensor<Self>, value: Scalar) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_powf_scalar_impl(tensor, value),
+            |tangent, tensor, _| {
+                let exponent = value.elem::<f64>() - 1.0;
+                let derivative =
+                    B::float_mul_scalar(B::float_powf_scalar(tensor, exponent.into()), value);
+                B::float_mul(tangent, derivative)
+            },
+        )
+    }
+
+    fn float_sqrt(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_sqrt, |tangent, _, output| {
+            B::float_div(tangent, B::float_mul_scalar(output, 2.into()))
+        })
+    }
+
+    fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        binary(
+            lhs,
+            rhs,
+            B::float_hypot,
+            |lhs_tangent, rhs_tangent, lhs, rhs, output| {
+                let numerator = add_tangents::<B>(
+                    lhs_tangent.map(|tangent| B::float_mul(tangent, lhs)),
+                    rhs_tangent.map(|tangent| B::float_mul(tangent, rhs)),
+                );
+                B::float_div(numerator, output)
+            },
+        )
+    }
+
+    fn float_abs(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_abs, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_sign(tensor))
+        })
+    }
+
+    fn float_cos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_cos, |tangent, tensor, _| {
+            B::float_neg(B::float_mul(tangent, B::float_sin(tensor)))
+        })
+    }
+
+    fn float_sin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_sin, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_cos(tensor))
+        })
+    }
+
+    fn float_tan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_tan, |tangent, _, output| {
+            let derivative = B::float_add_scalar(B::float_mul(output.clone(), output), 1.into());
+            B::float_mul(tangent, derivative)
+        })
+    }
+
+    fn float_cosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_cosh, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_sinh(tensor))
+        })
+    }
+
+    fn float_sinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_sinh, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_cosh(tensor))
+        })
+    }
+
+    fn float_tanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_tanh, |tangent, _, output| {
+            let derivative = B::float_neg(B::float_sub_scalar(
+                B::float_mul(output.clone(), output),
+                1.into(),
+            ));
+            B::float_mul(tangent, derivative)
+        })
+    }
+
+    fn float_acos(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_acos, |tangent, tensor, _| {
+            B::float_neg(B::float_div(tangent, sqrt_one_minus_square::<B>(tensor)))
+        })
+    }
+
+    fn float_acosh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_acosh, |tangent, tensor, _| {
+            let square = B::float_mul(tensor.clone(), tensor);
+            B::float_div(
+                tangent,
+                B::float_sqrt(B::float_sub_scalar(square, 1.into())),
+            )
+        })
+    }
+
+    fn float_asin(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_asin, |tangent, tensor, _| {
+            B::float_div(tangent, sqrt_one_minus_square::<B>(tensor))
+        })
+    }
+
+    fn float_asinh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_asinh, |tangent, tensor, _| {
+            let square = B::float_mul(tensor.clone(), tensor);
+            B::float_div(
+                tangent,
+                B::float_sqrt(B::float_add_scalar(square, 1.into())),
+            )
+        })
+    }
+
+    fn float_atan(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_atan, |tangent, tensor, _| {
+            let square = B::float_mul(tensor.clone(), tensor);
+            B::float_div(tangent, B::float_add_scalar(square, 1.into()))
+        })
+    }
+
+    fn float_atanh(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_atanh, |tangent, tensor, _| {
+            let square = B::float_mul(tensor.clone(), tensor);
+            B::float_div(tangent, B::float_neg(B::float_sub_scalar(square, 1.into())))
+        })
+    }
+
+    fn float_atan2(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
+        // atan2(y, x)' = (x y' - y x') / (x^2 + y^2)
+        binary(
+            lhs,
+            rhs,
+            B::float_atan2,
+            |lhs_tangent, rhs_tangent, lhs, rhs, _| {
+                let denominator = B::float_add(
+                    B::float_mul(lhs.clone(), lhs.clone()),
+                    B::float_mul(rhs.clone(), rhs.clone()),
+                );
+                let numerator = sub_tangents::<B>(
+                    lhs_tangent.map(|tangent| B::float_mul(tangent, rhs)),
+                    rhs_tangent.map(|tangent| B::float_mul(tangent, lhs)),
+                );
+                B::float_div(numerator, denominator)
+            },
+        )
+    }
+
+    fn float_round(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_round(tensor.primal))
+    }
+
+    fn float_floor(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_floor(tensor.primal))
+    }
+
+    fn float_ceil(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_ceil(tensor.primal))
+    }
+
+    fn float_trunc(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_trunc(tensor.primal))
+    }
+
+    fn float_erf(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // erf(x)' = 2 / sqrt(pi) exp(-x^2)
+        unary(tensor, B::float_erf, |tangent, tensor, _| {
+            let square = B::float_mul(tensor.clone(), tensor);
+            let derivative = B::float_mul_scalar(
+                B::float_exp(B::float_neg(square)),
+                core::f64::consts::FRAC_2_SQRT_PI.into(),
+            );
+            B::float_mul(tangent, derivative)
+        })
+    }
+
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // erfinv(x)' = sqrt(pi) / 2 exp(erfinv(x)^2)
+        unary(tensor, B::float_erfinv, |tangent, _, output| {
+            let square = B::float_mul(output.clone(), output);
+            let derivative = B::float_div_scalar(
+                B::float_exp(square),
+                core::f64::consts::FRAC_2_SQRT_PI.into(),
+            );
+            B::float_mul(tangent, derivative)
+        })
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_lgamma, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_digamma(tensor))
+        })
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_digamma, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_polygamma(tensor, 1))
+        })
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: usize) -> FloatTensor<Self> {
+        unary(
+            tensor,
+            |tensor| B::float_polygamma(tensor, n),
+            |tangent, tensor, _| B::float_mul(tangent, B::float_polygamma(tensor, n + 1)),
+        )
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary(tensor, B::float_i0, |tangent, tensor, _| {
+            B::float_mul(tangent, B::float_i1(tensor))
+        })
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        // i1(x)' = i0(x) - i1(x) / x, with the limit 1 / 2 at zero.
+        unary(tensor, B::float_i1, |tangent, tensor, output| {
+            let dtype = bool_dtype::<B>(&tensor);
+            let is_zero = B::float_equal_elem(tensor.clone(), 0.into(), dtype);
+            let derivative =
+                B::float_sub(B::float_i0(tensor.clone()), B::float_div(output, tensor));
+            let derivative = B::float_mask_fill(derivative, is_zero, 0.5.into());
+            B::float_mul(tangent, derivative)
+        })
+    }
+
+    fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
+        let tangents = tensors.iter().any(|tensor| !tensor.is_constant()).then(|| {
+            tensors
+                .iter()
+                .map(ForwardTensor::tangent_or_zeros)
+                .collect()
+        });
+        let primals = tensors.into_iter().map(|tensor| tensor.primal).collect();
+
+        ForwardTensor::from_parts(
+            B::float_cat(primals, dim),
+            tangents.map(|tangents| B::float_cat(tangents, dim)),
+        )
+    }
+
+    fn float_max_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        if tensor.is_constant() {
+            return ForwardTensor::constant(B::float_max_dim(tensor.primal, dim));
+        }
+
+        let dtype = get_device_settings::<B>(&tensor.device()).int_dtype;
+        Self::float_max_dim_with_indices(tensor, dim, dtype).0
+    }
+
+    fn float_max_dim_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        indices_dtype: IntDType,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        let (values, indices) = B::float_max_dim_with_indices(tensor.primal, dim, indices_dtype);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (ForwardTensor::from_parts(values, tangent), indices)
+    }
+
+    fn float_min_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        if tensor.is_constant() {
+            return ForwardTensor::constant(B::float_min_dim(tensor.primal, dim));
+        }
+
+        let dtype = get_device_settings::<B>(&tensor.device()).int_dtype;
+        Self::float_min_dim_with_indices(tensor, dim, dtype).0
+    }
+
+    fn float_min_dim_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        indices_dtype: IntDType,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        let (values, indices) = B::float_min_dim_with_indices(tensor.primal, dim, indices_dtype);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (ForwardTensor::from_parts(values, tangent), indices)
+    }
+
+    fn float_any(tensor: FloatTensor<Self>, out_dtype: BoolDType) -> BoolTensor<Self> {
+        B::float_any(tensor.primal, out_dtype)
+    }
+
+    fn float_any_dim(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_any_dim(tensor.primal, dim, out_dtype)
+    }
+
+    fn float_all(tensor: FloatTensor<Self>, out_dtype: BoolDType) -> BoolTensor<Self> {
+        B::float_all(tensor.primal, out_dtype)
+    }
+
+    fn float_all_dim(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        out_dtype: BoolDType,
+    ) -> BoolTensor<Self> {
+        B::float_all_dim(tensor.primal, dim, out_dtype)
+    }
+
+    fn float_sign(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_sign(tensor.primal))
+    }
+
+    fn float_expand(tensor: FloatTensor<Self>, shape: Shape) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_expand(tensor, shape.clone()))
+    }
+
+    fn float_sort(tensor: FloatTensor<Self>, dim: usize, descending: bool) -> FloatTensor<Self> {
+        if tensor.is_constant() {
+            return ForwardTensor::constant(B::float_sort(tensor.primal, dim, descending));
+        }
+
+        let dtype = get_device_settings::<B>(&tensor.device()).int_dtype;
+        Self::float_sort_with_indices(tensor, dim, descending, dtype).0
+    }
+
+    fn float_sort_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        descending: bool,
+        indices_dtype: IntDType,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        let (values, indices) =
+            B::float_sort_with_indices(tensor.primal, dim, descending, indices_dtype);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::float_gather(dim, tangent, indices.clone()));
+
+        (ForwardTensor::from_parts(values, tangent), indices)
+    }
+
+    fn float_argsort(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        descending: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<Self> {
+        B::float_argsort(tensor.primal, dim, descending, out_dtype)
+    }
+
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<Self> {
+        B::float_searchsorted(sorted_sequence.primal, values.primal, right, out_dtype)
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: Scalar,
+        max: Scalar,
+    ) -> FloatTensor<Self> {
+        ForwardTensor::constant(B::float_histc(tensor.primal, bins, min, max))
+    }
+
+    fn float_bincount(
+        tensor: IntTensor<Self>,
+        weights: FloatTensor<Self>,
+        length: usize,
+    ) -> FloatTensor<Self> {
+        linear(weights, |weights| {
+            B::float_bincount(tensor.clone(), weights, length)
+        })
+    }
+
+    fn float_grid_sample_2d(
+        tensor: FloatTensor<Self>,
+        grid: FloatTensor<Self>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<Self> {
+        if tensor.is_constant() && grid.is_constant() {
+            return ForwardTensor::constant(B::float_grid_sample_2d(
+                tensor.primal,
+                grid.primal,
+                options,
+            ));
+        }
+
+        // The reference implementation is written with differentiable operations.
+        float_grid_sample_2d_ref::<Self>(tensor, grid, options)
+    }
+
+    fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_cast(tensor, dtype))
+    }
+
+    fn float_unfold(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        size: usize,
+        step: usize,
+    ) -> FloatTensor<Self> {
+        linear(tensor, |tensor| B::float_unfold(tensor, dim, size, step))
+    }
+}
+
+fn bool_dtype<B: Backend>(tensor: &FloatTensor<B>) -> BoolDType {
+    get_device_settings::<B>(&tensor.device()).bool_dtype
+}
+
+/// Applies an update of a tensor by a value, such as an indexed addition or assignment, which
+/// is linear in both, so the tangent is the same update of the tensor tangent by the value
+/// tangent.
+fn bilinear_update<B, F>(
+    tensor: ForwardTensor<B>,
+    value: ForwardTensor<B>,
+    func: F,
+) -> ForwardTensor<B>
+where
+    B: Backend,
+    F: Fn(FloatTensor<B>, FloatTensor<B>) -> FloatTensor<B>,
+{
+    let tangent = (!tensor.is_constant() || !value.is_constant())
+        .then(|| func(tensor.tangent_or_zeros(), value.tangent_or_zeros()));
+
+    ForwardTensor::from_parts(func(tensor.primal, value.primal), tangent)
+}
+
+fn sqrt_one_minus_square<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let square = B::float_mul(tensor.clone(), tensor);
+    B::float_sqrt(B::float_neg(B::float_sub_scalar(square, 1.into())))
+}
+
+/// Returns the positions of the input values selected by a cumulative minimum or maximum.
+fn cumulative_source_indices<B: Backend>(
+    input: FloatTensor<B>,
+    output: FloatTensor<B>,
+    dim: usize,
+) -> IntTensor<B> {
+    let shape = input.shape();
+    let device = input.device();
+    let settings = get_device_settings::<B>(&device);
+    let dim_size = shape[dim];
+
+    let mut arange_shape = vec![1; shape.num_dims()];
+    arange_shape[dim] = dim_size;
+    let arange = B::int_arange(0..dim_size as i64, &device, settings.int_dtype);
+    let arange = B::int_expand(
+        B::int_reshape(arange, Shape::from(arange_shape)),
+        shape.clone(),
+    );
+
+    // The source of each output is the last position where the output equals the input.
+    let is_source = B::float_equal(output, input, settings.bool_dtype);
+    let is_source = B::bool_into_int(is_source, settings.int_dtype);
+    B::int_cummax(B::int_mul(arange, is_source), dim)
+}
+
+/// Shifts a tensor by one position towards the end of a dimension, filling the first position
+/// with zeros.
+fn shift_forward<B: Backend>(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let size = shape[dim];
+    let mut pad_shape = shape.clone();
+    pad_shape[dim] = 1;
+    let mut slices = vec![Slice::full(); shape.num_dims()];
+    slices[dim] = Slice::new(0, Some(size as isize - 1), 1);
+
+    let zeros = B::float_zeros(pad_shape, &tensor.device(), tensor.dtype().into());
+    B::float_cat(vec![zeros, B::float_slice(tensor, &slices)], dim)
+}
@@ -0,0 +1,24 @@
+use burn_backend::{
+    Backend, ExecutionError,
+    ops::{TransactionOps, TransactionPrimitive},
+};
+
+use crate::forward::Dual;
+
+impl<B: Backend> TransactionOps<Self> for Dual<B> {
+    async fn tr_execute(
+        transaction: TransactionPrimitive<Self>,
+    ) -> Result<burn_backend::ops::TransactionPrimitiveData, ExecutionError> {
+        B::tr_execute(TransactionPrimitive::new(
+            transaction
+                .read_floats
+                .into_iter()
+                .map(|t| t.primal)
+                .collect(),
+            transaction.read_qfloats,
+            transaction.read_ints,
+            transaction.read_bools,
+        ))
+        .await
+    }
+}
@@ -0,0 +1,95 @@
+use burn_backend::{Backend, BackendTypes, TensorMetadata};
+
+/// The float tensor primitive of the [Dual](super::Dual) backend.
+///
+/// It holds the primal values of the inner backend with their tangent, the directional
+/// derivative of the values along the input tangents. A tensor without tangent is a constant,
+/// which lets the operations that only involve constants run the inner backend directly.
+#[derive(Debug, Clone)]
+pub struct ForwardTensor<B: BackendTypes> {
+    /// The primal values.
+    pub primal: B::FloatTensorPrimitive,
+    /// The tangent, with the shape and the data type of the primal values, or `None` for a
+    /// constant.
+    pub tangent: Option<B::FloatTensorPrimitive>,
+}
+
+impl<B: Backend> ForwardTensor<B> {
+    /// Creates a tensor from its primal values and its tangent.
+    ///
+    /// # Panics
+    ///
+    /// If the tangent doesn't have the shape of the primal values.
+    pub fn new(primal: B::FloatTensorPrimitive, tangent: B::FloatTensorPrimitive) -> Self {
+        assert_eq!(
+            primal.shape(),
+            tangent.shape(),
+            "The tangent should have the shape of the primal values"
+        );
+        Self {
+            primal,
+            tangent: Some(tangent),
+        }
+    }
+
+    /// Creates a constant tensor, which has a zero tangent.
+    pub fn constant(primal: B::FloatTensorPrimitive) -> Self {
+        Self {
+            primal,
+            tangent: None,
+        }
+    }
+
+    /// Returns true if the tensor has no tangent.
+    pub fn is_constant(&self) -> bool {
+        self.tangent.is_none()
+    }
+
+    /// Returns the tangent, materializing the zero tangent of a constant.
+    pub fn tangent_or_zeros(&self) -> B::FloatTensorPrimitive {
+        match &self.tangent {
+            Some(tangent) => tangent.clone(),
+            None => zeros_like::<B>(&self.primal),
+        }
+    }
+
+    /// Returns the primal values and the tangent.
+    pub fn into_parts(self) -> (B::FloatTensorPrimitive, Option<B::FloatTensorPrimitive>) {
+        (self.primal, self.tangent)
+    }
+
+    pub(crate) fn from_parts(
+        primal: B::FloatTensorPrimitive,
+        tangent: Option<B::FloatTensorPrimitive>,
+    ) -> Self {
+        Self { primal, tangent }
+    }
+}
+
+impl<B: BackendTypes> TensorMetadata for ForwardTensor<B> {
+    type Device = B::Device;
+
+    fn dtype(&self) -> burn_std::DType {
+        self.primal.dtype()
+    }
+
+    fn shape(&self) -> burn_std::Shape {
+        self.primal.shape()
+    }
+
+    fn rank(&self) -> usize {
+        self.primal.rank()
+    }
+
+    fn device(&self) -> B::Device {
+        self.primal.device()
+    }
+
+    fn can_mut(&self) -> bool {
+        self.primal.can_mut()
+    }
+}
+
+pub(crate) fn zeros_like<B: Backend>(tensor: &B::FloatTensorPrimitive) -> B::FloatTensorPrimitive {
+    B::float_zeros(tensor.shape(), &tensor.device(), tensor.dtype().into())
+}
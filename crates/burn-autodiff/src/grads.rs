@@ -123,6 +123,13 @@ impl Gradients {
             .map(|tensor| tensor.tensor())
     }
 
+    /// Gets the grad tensor of the given node from the container.
+    pub(crate) fn get_by_id<B: Backend>(&self, node_id: NodeId) -> Option<FloatTensor<B>> {
+        self.container
+            .get::<TensorPrimitive<B>>(&node_id.value)
+            .map(|tensor| tensor.tensor())
+    }
+
     /// Register a grad tensor in the container.
     ///
     /// If the tensor already exists, add both tensors together before saving the result.
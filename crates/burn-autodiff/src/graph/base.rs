@@ -1,7 +1,14 @@
 use super::NodeId;
-use crate::{checkpoint::base::Checkpointer, grads::Gradients, graph::Parent};
+use crate::{
+    anomaly::NodeAnomaly, checkpoint::base::Checkpointer, grads::Gradients, graph::Parent,
+};
 use alloc::boxed::Box;
 
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
 use burn_backend::distributed::DistributedParams;
 
 /// Backward step for reverse mode autodiff.
@@ -21,6 +28,14 @@ pub trait Step: Send + core::fmt::Debug {
     fn distributed_params(&self) -> Option<DistributedParams> {
         None
     }
+
+    /// Returns the operation recorded on the node associated to the step when
+    /// [anomaly detection](crate::anomaly) is enabled.
+    ///
+    /// Defaults to `None`; steps whose node records the operation override this.
+    fn anomaly(&self) -> Option<Arc<NodeAnomaly>> {
+        None
+    }
 }
 
 pub type StepBoxed = Box<dyn Step>;
@@ -13,6 +13,7 @@ use alloc::sync::Arc;
 #[cfg(not(target_has_atomic = "ptr"))]
 use portable_atomic_util::Arc;
 
+use crate::anomaly::NodeAnomaly;
 use crate::checkpoint::retro_forward::RetroForward;
 use crate::runtime::AutodiffClientImpl;
 
@@ -46,6 +47,10 @@ pub struct Node {
     pub properties: ComputingProperty,
     pub client: AutodiffClientImpl,
     pub distributed_params: Option<DistributedParams>,
+    /// Whether the gradients of the node are kept per sample instead of summed over the batch.
+    pub per_sample: bool,
+    /// The operation that created the node, recorded when anomaly detection is enabled.
+    pub anomaly: Option<Arc<NodeAnomaly>>,
 }
 pub type NodeRef = Arc<Node>;
 
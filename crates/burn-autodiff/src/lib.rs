@@ -14,11 +14,14 @@ extern crate derive_new;
 
 extern crate alloc;
 
+pub mod anomaly;
 /// Checkpoint module.
 pub mod checkpoint;
 #[cfg(feature = "std")]
 /// Distributed utils.
 pub mod distributed;
+/// Forward-mode differentiation module.
+pub mod forward;
 /// Gradients module.
 pub mod grads;
 /// Operation module.
@@ -1,4 +1,4 @@
-use super::{Ops, OpsPrep};
+use super::{Ops, OpsPrep, per_sample::assert_summed};
 use crate::{
     checkpoint::{base::Checkpointer, builder::CheckpointerBuilder, strategy::CheckpointStrategy},
     grads::Gradients,
@@ -62,11 +62,13 @@ pub fn binary<B, FLhs, FRhs>(
     let [node_lhs, node_rhs] = parents;
 
     if let Some(node) = node_lhs {
+        assert_summed(&node);
         let grad = func_lhs(grad_4lhs.unwrap());
         grads.register::<B>(node.id, grad)
     }
 
     if let Some(node) = node_rhs {
+        assert_summed(&node);
         let grad = func_rhs(grad_4rhs.unwrap());
         grads.register::<B>(node.id, grad)
     }
@@ -82,6 +84,7 @@ where
     let grad = grads.consume::<B>(&node);
 
     if let Some(node) = parent_node {
+        assert_summed(&node);
         let grad = func(grad);
         grads.register::<B>(node.id, grad)
     }
@@ -1,5 +1,6 @@
 use super::Backward;
 use crate::{
+    anomaly::NodeAnomaly,
     checkpoint::{
         base::Checkpointer,
         builder::{ActionType, CheckpointerBuilder},
@@ -15,6 +16,11 @@ use burn_backend::{Backend, TensorMetadata, tensor::FloatTensor};
 use burn_std::Shape;
 use core::marker::PhantomData;
 
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
 use burn_backend::distributed::DistributedParams;
 
 /// Operation in preparation.
@@ -179,6 +185,7 @@ where
             &self.nodes,
             self.requirement,
             self.compute_property,
+            None,
         );
         let parents = self.nodes.map(|node| node.clone_if_require_grad());
         let ops = Ops::new(parents, output.node.clone(), ());
@@ -202,6 +209,7 @@ where
             &self.nodes,
             self.requirement,
             self.compute_property,
+            NodeAnomaly::capture::<BO>(),
         );
         let parents = self.nodes.map(|node| node.clone_if_require_grad());
         let ops = Ops::new(parents, output.node.clone(), state);
@@ -275,6 +283,10 @@ where
     fn distributed_params(&self) -> Option<DistributedParams> {
         self.ops.node.distributed_params.clone()
     }
+
+    fn anomaly(&self) -> Option<Arc<NodeAnomaly>> {
+        self.ops.node.anomaly.clone()
+    }
 }
 
 #[derive(new, Debug)]
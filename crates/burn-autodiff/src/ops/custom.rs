@@ -0,0 +1,225 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    anomaly::NodeAnomaly,
+    checkpoint::{
+        base::Checkpointer,
+        builder::{ActionType, CheckpointerBuilder},
+        retro_forward::RetroForward,
+        state::BackwardStates,
+        strategy::CheckpointStrategy,
+    },
+    grads::Gradients,
+    graph::{ComputingProperty, NodeId, NodeRef, Parent, Requirement, Step},
+    tensor::AutodiffTensor,
+};
+
+use burn_backend::{Backend, distributed::DistributedParams, tensor::FloatTensor};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
+/// The backward pass of a [custom operation](custom_op).
+///
+/// It receives the gradient of the output and whether each input requires a gradient, and
+/// returns the gradient of each input, in the same order as the inputs. The gradients of the
+/// inputs that don't require one are ignored.
+pub type CustomBackward<B> =
+    Box<dyn FnOnce(FloatTensor<B>, &[bool]) -> Vec<Option<FloatTensor<B>>> + Send>;
+
+/// The forward pass of a [custom operation](custom_op).
+///
+/// It computes the output from the inner tensors of the inputs, and returns it with the
+/// [backward](CustomBackward) pass, which holds the state it needs. It is called again during
+/// the backward pass when the operation is recomputed.
+pub type CustomForward<B> =
+    Arc<dyn Fn(Vec<FloatTensor<B>>) -> (FloatTensor<B>, CustomBackward<B>) + Send + Sync>;
+
+/// Registers an operation with a user defined backward pass.
+///
+/// The output is computed by `forward` from the inner tensors of the inputs, so its operations
+/// are not recorded: the gradients of the inputs only come from the returned backward pass.
+///
+/// Without checkpointing, the backward pass and its state are kept until they are needed. With
+/// a checkpointing strategy that recomputes memory bound operations, the operation is memory
+/// bound: the inputs are checkpointed instead, and `forward` is called again during the backward
+/// pass to get the output and the backward pass.
+pub fn custom_op<B: Backend, C: CheckpointStrategy>(
+    inputs: Vec<AutodiffTensor<B>>,
+    forward: CustomForward<B>,
+) -> AutodiffTensor<B> {
+    let primitives = inputs
+        .iter()
+        .map(|tensor| tensor.primitive.clone())
+        .collect();
+    let (output, backward) = forward(primitives);
+    let nodes = inputs
+        .iter()
+        .map(|tensor| tensor.node.clone())
+        .collect::<Vec<_>>();
+    let requirement = Requirement::from_nodes(&nodes);
+
+    if requirement.is_none() {
+        return AutodiffTensor::from_parents(
+            output,
+            &nodes,
+            requirement,
+            ComputingProperty::ComputeBound,
+            None,
+        );
+    }
+
+    let mut checkpointer_builder = CheckpointerBuilder::default();
+    let ids = nodes.iter().map(|node| node.id).collect::<Vec<_>>();
+    let retro_forward = CustomRetroForward::<B> {
+        inputs: ids.clone(),
+        forward: forward.clone(),
+    };
+    let compute_property = match C::checkpoint_parents(&inputs, &mut checkpointer_builder) {
+        Ok(..) => C::compute_property(retro_forward),
+        Err(..) => ComputingProperty::ComputeBound,
+    };
+
+    let state = match compute_property {
+        ComputingProperty::MemoryBound { .. } => {
+            // The state of the backward pass is dropped and recomputed from the inputs.
+            for input in inputs.iter() {
+                checkpointer_builder.checkpoint(input, ActionType::Explicit);
+            }
+            CustomState::Recompute {
+                inputs: ids,
+                forward,
+            }
+        }
+        ComputingProperty::ComputeBound | ComputingProperty::Ambiguous => {
+            CustomState::Saved(backward)
+        }
+    };
+
+    let output = AutodiffTensor::from_parents(
+        output,
+        &nodes,
+        requirement,
+        compute_property,
+        NodeAnomaly::capture::<CustomStep<B>>(),
+    );
+
+    let nodes = nodes
+        .iter()
+        .map(|node| node.clone_if_require_grad())
+        .collect::<Vec<_>>();
+    let parents = nodes
+        .iter()
+        .flatten()
+        .map(|node| Parent::new(node.id))
+        .collect();
+    let step = CustomStep::<B> {
+        nodes,
+        output: output.node.clone(),
+        parents,
+        state,
+    };
+
+    output.register_step(step, checkpointer_builder)
+}
+
+/// How a [custom operation](custom_op) gets its backward pass.
+enum CustomState<B: Backend> {
+    /// The backward pass returned by the forward pass.
+    Saved(CustomBackward<B>),
+    /// The forward pass is called again with the checkpointed inputs.
+    Recompute {
+        inputs: Vec<NodeId>,
+        forward: CustomForward<B>,
+    },
+}
+
+/// Recomputes the output of a memory bound [custom operation](custom_op).
+struct CustomRetroForward<B: Backend> {
+    inputs: Vec<NodeId>,
+    forward: CustomForward<B>,
+}
+
+impl<B: Backend> core::fmt::Debug for CustomRetroForward<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CustomRetroForward")
+            .field("inputs", &self.inputs)
+            .finish()
+    }
+}
+
+impl<B: Backend> RetroForward for CustomRetroForward<B> {
+    fn forward(&self, states: &mut BackwardStates, out_node: NodeId) {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|id| states.get_state::<FloatTensor<B>>(id))
+            .collect();
+        let (output, _backward) = (self.forward)(inputs);
+        states.save(out_node, output)
+    }
+}
+
+struct CustomStep<B: Backend> {
+    nodes: Vec<Option<NodeRef>>,
+    output: NodeRef,
+    parents: Vec<Parent>,
+    state: CustomState<B>,
+}
+
+impl<B: Backend> core::fmt::Debug for CustomStep<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CustomStep")
+            .field("nodes", &self.nodes)
+            .field("output", &self.output)
+            .finish()
+    }
+}
+
+impl<B: Backend> Step for CustomStep<B> {
+    fn step(self: Box<Self>, grads: &mut Gradients, checkpointer: &mut Checkpointer) {
+        let backward = match self.state {
+            CustomState::Saved(backward) => backward,
+            CustomState::Recompute { inputs, forward } => {
+                let inputs = inputs
+                    .into_iter()
+                    .map(|id| checkpointer.retrieve_node_output::<FloatTensor<B>>(id))
+                    .collect();
+                let (_output, backward) = forward(inputs);
+                backward
+            }
+        };
+
+        let grad = grads.consume::<B>(&self.output);
+        let required = self.nodes.iter().map(Option::is_some).collect::<Vec<_>>();
+        let grads_inputs = backward(grad, &required);
+
+        for (node, grad) in self.nodes.into_iter().zip(grads_inputs) {
+            if let (Some(node), Some(grad)) = (node, grad) {
+                grads.register::<B>(node.id, grad);
+            }
+        }
+    }
+
+    fn node(&self) -> NodeId {
+        self.output.id
+    }
+
+    fn parents(&self) -> &[Parent] {
+        &self.parents
+    }
+
+    fn depth(&self) -> usize {
+        self.output.order
+    }
+
+    fn distributed_params(&self) -> Option<DistributedParams> {
+        self.output.distributed_params.clone()
+    }
+
+    fn anomaly(&self) -> Option<Arc<NodeAnomaly>> {
+        self.output.anomaly.clone()
+    }
+}
@@ -381,6 +381,18 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_argsort(tensor, dim, descending)
     }
 
+    fn int_searchsorted(
+        sorted_sequence: IntTensor<Self>,
+        values: IntTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        B::int_searchsorted(sorted_sequence, values, right)
+    }
+
+    fn int_bincount(tensor: IntTensor<Self>, length: usize) -> IntTensor<Self> {
+        B::int_bincount(tensor, length)
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         B::bitwise_and(lhs, rhs)
     }
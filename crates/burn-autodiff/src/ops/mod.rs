@@ -2,10 +2,13 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod custom;
 mod distributed;
 mod int_tensor;
 mod module;
+mod per_sample;
 mod qtensor;
+mod sparse;
 mod tensor;
 mod transaction;
 
@@ -14,3 +17,4 @@ pub(crate) mod sort;
 
 pub use backward::*;
 pub use base::*;
+pub use custom::*;
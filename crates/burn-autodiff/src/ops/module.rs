@@ -15,7 +15,7 @@ use burn_backend::tensor::{FloatTensor, IntTensor};
 use burn_backend::{Backend, get_device_settings};
 use burn_std::{IntDType, Slice};
 
-use super::OpsKind;
+use super::{OpsKind, per_sample};
 
 impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B, C> {
     fn embedding(weights: AutodiffTensor<B>, indices: IntTensor<B>) -> AutodiffTensor<B> {
@@ -33,9 +33,16 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             ) {
                 let (weights, indices) = ops.state;
 
-                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
-                    B::embedding_backward(weights, grad, indices)
-                });
+                let [node_weights] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                if let Some(node) = node_weights {
+                    let grad = match node.per_sample {
+                        true => per_sample::embedding::<B>(weights, grad, indices),
+                        false => B::embedding_backward(weights, grad, indices),
+                    };
+                    grads.register::<B>(node.id, grad)
+                }
             }
         }
 
@@ -93,11 +100,17 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_weight {
-                    let grad = B::linear_weight_backward(x.unwrap(), grad.clone());
+                    let grad = match node.per_sample {
+                        true => per_sample::linear_weight::<B>(x.unwrap(), grad.clone()),
+                        false => B::linear_weight_backward(x.unwrap(), grad.clone()),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_bias {
-                    let grad = B::linear_bias_backward(grad);
+                    let grad = match node.per_sample {
+                        true => per_sample::linear_bias::<B>(grad),
+                        false => B::linear_bias_backward(grad),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
             }
@@ -126,7 +139,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_weight {
-                    let grad = B::linear_weight_backward(x.unwrap(), grad);
+                    let grad = match node.per_sample {
+                        true => per_sample::linear_weight::<B>(x.unwrap(), grad),
+                        false => B::linear_weight_backward(x.unwrap(), grad),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
             }
@@ -233,11 +249,21 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_weight {
-                    let grad = B::conv1d_weight_backward(x.clone(), weight, grad.clone(), options);
+                    let grad = match node.per_sample {
+                        true => {
+                            per_sample::conv1d_weight::<B>(x.clone(), weight, grad.clone(), options)
+                        }
+                        false => {
+                            B::conv1d_weight_backward(x.clone(), weight, grad.clone(), options)
+                        }
+                    };
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_bias {
-                    let grad = B::conv1d_bias_backward(x, bias, grad);
+                    let grad = match node.per_sample {
+                        true => per_sample::conv_bias::<B>(grad),
+                        false => B::conv1d_bias_backward(x, bias, grad),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
             }
@@ -270,7 +296,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_weight {
-                    let grad = B::conv1d_weight_backward(x, weight, grad, options);
+                    let grad = match node.per_sample {
+                        true => per_sample::conv1d_weight::<B>(x, weight, grad, options),
+                        false => B::conv1d_weight_backward(x, weight, grad, options),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
             }
@@ -491,12 +520,27 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_weight {
-                    let grad =
-                        B::conv2d_weight_backward(x.clone(), weight.clone(), grad.clone(), options);
+                    let grad = match node.per_sample {
+                        true => per_sample::conv2d_weight::<B>(
+                            x.clone(),
+                            weight.clone(),
+                            grad.clone(),
+                            options,
+                        ),
+                        false => B::conv2d_weight_backward(
+                            x.clone(),
+                            weight.clone(),
+                            grad.clone(),
+                            options,
+                        ),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_bias {
-                    let grad = B::conv2d_bias_backward(x, bias, grad);
+                    let grad = match node.per_sample {
+                        true => per_sample::conv_bias::<B>(grad),
+                        false => B::conv2d_bias_backward(x, bias, grad),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
             }
@@ -529,7 +573,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                     grads.register::<B>(node.id, grad)
                 }
                 if let Some(node) = node_weight {
-                    let grad = B::conv2d_weight_backward(x, weight, grad, options);
+                    let grad = match node.per_sample {
+                        true => per_sample::conv2d_weight::<B>(x, weight, grad, options),
+                        false => B::conv2d_weight_backward(x, weight, grad, options),
+                    };
                     grads.register::<B>(node.id, grad)
                 }
             }
@@ -1879,6 +1926,275 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate adaptive avg pool3d backward.");
     }
 
+    fn avg_pool3d(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 1> for AvgPool3D {
+            type State = (NodeId, [usize; 3], [usize; 3], [usize; 3], bool, bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (x_state, kernel_size, stride, padding, count_include_pad, ceil_mode) =
+                    ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    );
+                    grads.register::<B>(node.id, grad);
+                }
+            }
+        }
+
+        match AvgPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(
+                    (
+                        x_state,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    ),
+                    B::avg_pool3d(
+                        x.primitive.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::avg_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            )),
+        }
+    }
+
+    fn avg_pool3d_backward(
+        _x: AutodiffTensor<B>,
+        _grad: AutodiffTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _count_include_pad: bool,
+        _ceil_mode: bool,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate avg pool 3d backward.");
+    }
+
+    fn max_pool3d(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+    ) -> AutodiffTensor<B> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let settings = get_device_settings::<B>(&x.primitive.device());
+                let output = B::max_pool3d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                    settings.int_dtype,
+                );
+                prep.finish(
+                    (
+                        x_state,
+                        output.indices,
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                        ceil_mode,
+                    ),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::max_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+            )),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: AutodiffTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        int_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+
+                let output = B::max_pool3d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                    int_dtype,
+                );
+
+                let output_tensor = prep.finish(
+                    (
+                        x_state,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                        ceil_mode,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::max_pool3d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                    int_dtype,
+                );
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indices_backward(
+        _x: AutodiffTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _ceil_mode: bool,
+        _output_grad: AutodiffTensor<B>,
+        _indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<Self> {
+        panic!("Can't differentiate max pool3d with indices backward.");
+    }
+
+    fn adaptive_max_pool3d(x: AutodiffTensor<B>, output_size: [usize; 3]) -> AutodiffTensor<B> {
+        match AdaptiveMaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let settings = get_device_settings::<B>(&x.primitive.device());
+                let output = B::adaptive_max_pool3d_with_indices(
+                    x.primitive,
+                    output_size,
+                    settings.int_dtype,
+                );
+                prep.finish((x_state, output.indices), output.output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_max_pool3d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_max_pool3d_with_indices(
+        x: AutodiffTensor<B>,
+        output_size: [usize; 3],
+        int_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        match AdaptiveMaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output =
+                    B::adaptive_max_pool3d_with_indices(x.primitive, output_size, int_dtype);
+                let output_tensor = prep.finish((x_state, output.indices.clone()), output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output =
+                    B::adaptive_max_pool3d_with_indices(x.primitive, output_size, int_dtype);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn adaptive_max_pool3d_with_indices_backward(
+        _x: AutodiffTensor<B>,
+        _output_grad: AutodiffTensor<B>,
+        _indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<Self> {
+        panic!("Can't differentiate adaptive max pool3d with indices backward.");
+    }
+
     fn interpolate(
         x: AutodiffTensor<B>,
         output_size: [usize; 2],
@@ -1933,6 +2249,60 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate interpolate backward.");
     }
 
+    fn interpolate3d(
+        x: AutodiffTensor<B>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct Interpolate3d;
+        impl<B: Backend> Backward<B, 1> for Interpolate3d {
+            type State = (NodeId, [usize; 3], InterpolateOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (x_state, output_size, options) = ops.state;
+                let state = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate3d_backward(state, grad, output_size, options);
+                    grads.register::<B>(node.id, grad);
+                }
+            }
+        }
+
+        match Interpolate3d
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::interpolate3d(x.primitive.clone(), output_size, options.clone());
+                prep.finish((x_state, output_size, options), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::interpolate3d(x.primitive, output_size, options))
+            }
+        }
+    }
+
+    fn interpolate3d_backward(
+        _x: FloatTensor<Autodiff<B, C>>,
+        _grad: FloatTensor<Autodiff<B, C>>,
+        _output_size: [usize; 3],
+        _options: InterpolateOptions,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate interpolate3d backward.");
+    }
+
     fn attention(
         query: FloatTensor<Autodiff<B, C>>,
         key: FloatTensor<Autodiff<B, C>>,
@@ -2292,3 +2662,70 @@ impl<B: Backend> Backward<B, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 1> for MaxPool3D {
+    type State = (
+        NodeId,
+        IntTensor<B>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        bool,
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B>(&ops.node);
+        let (x_state, indices, kernel_size, stride, padding, dilation, ceil_mode) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::max_pool3d_with_indices_backward(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+                grad,
+                indices,
+            );
+
+            grads.register::<B>(node.id, grad.x_grad);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AdaptiveMaxPool3D;
+
+impl<B: Backend> Backward<B, 1> for AdaptiveMaxPool3D {
+    type State = (NodeId, IntTensor<B>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B>(&ops.node);
+        let (x_state, indices) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::adaptive_max_pool3d_with_indices_backward(x, grad, indices);
+
+            grads.register::<B>(node.id, grad.x_grad);
+        }
+    }
+}
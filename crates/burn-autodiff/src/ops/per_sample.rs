@@ -0,0 +1,195 @@
+//! Per-sample gradients of the parameters of the linear, convolution and embedding operations.
+//!
+//! They are computed from the inputs saved for the backward pass and the output gradients, with
+//! the batch kept as the leading dimension of the gradients, so the whole batch is handled by a
+//! few batched operations.
+
+use alloc::vec::Vec;
+
+use burn_backend::ops::ConvOptions;
+use burn_backend::tensor::{FloatTensor, IntTensor};
+use burn_backend::{Backend, TensorMetadata};
+use burn_std::Shape;
+
+use crate::graph::NodeRef;
+
+/// Panics if the node receives per-sample gradients, for operations that only compute the
+/// gradients summed over the batch.
+pub(crate) fn assert_summed(node: &NodeRef) {
+    assert!(
+        !node.per_sample,
+        "Per-sample gradients are only supported for the weight and the bias of the linear, \
+         convolution and embedding operations"
+    );
+}
+
+/// Gradients of a linear weight `[d_input, d_output]` for each sample, with the shape
+/// `[batch_size, d_input, d_output]`.
+pub(crate) fn linear_weight<B: Backend>(
+    x: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let x = batch_rows::<B>(x);
+    let output_grad = batch_rows::<B>(output_grad);
+
+    B::float_matmul(B::float_swap_dims(x, 1, 2), output_grad)
+}
+
+/// Gradients of a linear bias `[d_output]` for each sample, with the shape
+/// `[batch_size, d_output]`.
+pub(crate) fn linear_bias<B: Backend>(output_grad: FloatTensor<B>) -> FloatTensor<B> {
+    let output_grad = batch_rows::<B>(output_grad);
+    let [batch_size, _, d_output] = output_grad.shape().dims();
+
+    B::float_reshape(
+        B::float_sum_dim(output_grad, 1),
+        Shape::new([batch_size, d_output]),
+    )
+}
+
+/// Gradients of a 1D convolution weight for each sample, with the batch as leading dimension.
+pub(crate) fn conv1d_weight<B: Backend>(
+    x: FloatTensor<B>,
+    weight: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: ConvOptions<1>,
+) -> FloatTensor<B> {
+    let batch_size = x.shape()[0];
+    let (x, weight, output_grad) = grouped_by_sample::<B>(x, weight, output_grad);
+    let options = ConvOptions::new(
+        options.stride,
+        options.padding,
+        options.dilation,
+        options.groups * batch_size,
+    );
+
+    unfold_samples::<B>(
+        B::conv1d_weight_backward(x, weight, output_grad, options),
+        batch_size,
+    )
+}
+
+/// Gradients of a 2D convolution weight for each sample, with the batch as leading dimension.
+pub(crate) fn conv2d_weight<B: Backend>(
+    x: FloatTensor<B>,
+    weight: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: ConvOptions<2>,
+) -> FloatTensor<B> {
+    let batch_size = x.shape()[0];
+    let (x, weight, output_grad) = grouped_by_sample::<B>(x, weight, output_grad);
+    let options = ConvOptions::new(
+        options.stride,
+        options.padding,
+        options.dilation,
+        options.groups * batch_size,
+    );
+
+    unfold_samples::<B>(
+        B::conv2d_weight_backward(x, weight, output_grad, options),
+        batch_size,
+    )
+}
+
+/// Gradients of a convolution bias `[channels_out]` for each sample, with the shape
+/// `[batch_size, channels_out]`.
+pub(crate) fn conv_bias<B: Backend>(output_grad: FloatTensor<B>) -> FloatTensor<B> {
+    let shape = output_grad.shape();
+    let [batch_size, channels_out] = [shape[0], shape[1]];
+    let output_grad = B::float_reshape(
+        output_grad,
+        Shape::new([
+            batch_size,
+            channels_out,
+            shape.num_elements() / (batch_size * channels_out),
+        ]),
+    );
+
+    B::float_reshape(
+        B::float_sum_dim(output_grad, 2),
+        Shape::new([batch_size, channels_out]),
+    )
+}
+
+/// Gradients of the embedding weights `[n_embeddings, d_model]` for each sample, with the shape
+/// `[batch_size, n_embeddings, d_model]`.
+pub(crate) fn embedding<B: Backend>(
+    weights: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    indices: IntTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, seq_length] = indices.shape().dims();
+    let [n_embeddings, d_model] = weights.shape().dims();
+    let device = weights.device();
+
+    // Each sample scatters into its own block of rows.
+    let offsets = B::int_arange(0..batch_size as i64, &device, indices.dtype().into());
+    let offsets = B::int_mul_scalar(offsets, (n_embeddings as i64).into());
+    let offsets = B::int_reshape(offsets, Shape::new([batch_size, 1]));
+    let indices = B::int_add(indices, offsets);
+
+    let weights = B::float_zeros(
+        Shape::new([batch_size * n_embeddings, d_model]),
+        &device,
+        output_grad.dtype().into(),
+    );
+    let indices = B::int_reshape(indices, Shape::new([1, batch_size * seq_length]));
+    let output_grad = B::float_reshape(
+        output_grad,
+        Shape::new([1, batch_size * seq_length, d_model]),
+    );
+    let grad = B::embedding_backward(weights, output_grad, indices);
+
+    B::float_reshape(grad, Shape::new([batch_size, n_embeddings, d_model]))
+}
+
+/// Reshapes a tensor `[batch_size, ..., d]` to `[batch_size, rows, d]`, where a tensor of rank 1
+/// is a single sample.
+fn batch_rows<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let rank = shape.num_dims();
+    let d = shape[rank - 1];
+    let batch_size = if rank > 1 { shape[0] } else { 1 };
+    let rows = shape.num_elements() / (batch_size * d);
+
+    B::float_reshape(tensor, Shape::new([batch_size, rows, d]))
+}
+
+/// Folds the batch into the channels, so a convolution with one group per sample computes the
+/// weight gradients of every sample at once.
+fn grouped_by_sample<B: Backend>(
+    x: FloatTensor<B>,
+    weight: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+) -> (FloatTensor<B>, FloatTensor<B>, FloatTensor<B>) {
+    let batch_size = x.shape()[0];
+
+    (
+        fold_batch::<B>(x),
+        B::float_repeat_dim(weight, 0, batch_size),
+        fold_batch::<B>(output_grad),
+    )
+}
+
+/// Reshapes `[batch_size, channels, ...]` to `[1, batch_size * channels, ...]`.
+fn fold_batch<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let shape = tensor.shape();
+    let dims: Vec<usize> = [1, shape[0] * shape[1]]
+        .into_iter()
+        .chain(shape.iter().skip(2).copied())
+        .collect();
+
+    B::float_reshape(tensor, Shape::from(dims))
+}
+
+/// Reshapes the weight gradients `[batch_size * channels_out, ...]` of the grouped convolution
+/// to `[batch_size, channels_out, ...]`.
+fn unfold_samples<B: Backend>(grad: FloatTensor<B>, batch_size: usize) -> FloatTensor<B> {
+    let shape = grad.shape();
+    let dims: Vec<usize> = [batch_size, shape[0] / batch_size]
+        .into_iter()
+        .chain(shape.iter().skip(1).copied())
+        .collect();
+
+    B::float_reshape(grad, Shape::from(dims))
+}
@@ -0,0 +1,131 @@
+use crate::{
+    Autodiff,
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    ops::{Backward, Ops, OpsKind},
+};
+use burn_backend::{
+    Backend, TensorMetadata,
+    ops::{SparseTensorOps, sparse::expand_csr_rows},
+    tensor::{FloatTensor, IntTensor},
+};
+use burn_std::Shape;
+
+#[derive(Debug)]
+struct SparseMatmul;
+
+impl<B: Backend> Backward<B, 2> for SparseMatmul {
+    // Saves the rows and the columns of the values, the values and the dense matrix.
+    type State = (
+        IntTensor<B>,
+        IntTensor<B>,
+        B::FloatTensorPrimitive,
+        B::FloatTensorPrimitive,
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node_values, node_dense] = ops.parents;
+        let grad = grads.consume::<B>(&ops.node);
+        let (row_indices, col_indices, values, dense) = ops.state;
+        let nnz = values.shape().num_elements();
+        let k = dense.shape()[0];
+
+        if let Some(node) = node_values {
+            // Each value scales one row of the dense matrix into one row of the output.
+            let grad = B::float_mul(
+                B::float_select(grad.clone(), 0, row_indices.clone()),
+                B::float_select(dense, 0, col_indices.clone()),
+            );
+            let grad = B::float_reshape(B::float_sum_dim(grad, 1), Shape::new([nnz]));
+            grads.register::<B>(node.id, grad)
+        }
+        if let Some(node) = node_dense {
+            // The transposed sparse matrix swaps the roles of the rows and the columns.
+            let grad = B::sparse_coo_spmm(col_indices, row_indices, values, grad, k);
+            grads.register::<B>(node.id, grad)
+        }
+    }
+}
+
+impl<B: Backend, C: CheckpointStrategy> SparseTensorOps<Self> for Autodiff<B, C> {
+    fn sparse_coo_spmm(
+        row_indices: IntTensor<B>,
+        col_indices: IntTensor<B>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+        rows: usize,
+    ) -> FloatTensor<Self> {
+        match SparseMatmul
+            .prepare::<C>([values.node, dense.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    row_indices.clone(),
+                    col_indices.clone(),
+                    values.primitive.clone(),
+                    dense.primitive.clone(),
+                ),
+                B::sparse_coo_spmm(
+                    row_indices,
+                    col_indices,
+                    values.primitive,
+                    dense.primitive,
+                    rows,
+                ),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::sparse_coo_spmm(
+                row_indices,
+                col_indices,
+                values.primitive,
+                dense.primitive,
+                rows,
+            )),
+        }
+    }
+
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<B>,
+        col_indices: IntTensor<B>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        match SparseMatmul
+            .prepare::<C>([values.node, dense.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                // The backward pass works on the row of each value.
+                let nnz = values.primitive.shape().num_elements();
+                let row_indices = expand_csr_rows::<B>(crow_indices.clone(), nnz);
+                prep.finish(
+                    (
+                        row_indices,
+                        col_indices.clone(),
+                        values.primitive.clone(),
+                        dense.primitive.clone(),
+                    ),
+                    B::sparse_csr_spmm(
+                        crow_indices,
+                        col_indices,
+                        values.primitive,
+                        dense.primitive,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::sparse_csr_spmm(
+                crow_indices,
+                col_indices,
+                values.primitive,
+                dense.primitive,
+            )),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use num_traits::float::Float;
 
 use crate::{
     Autodiff,
+    anomaly::NodeAnomaly,
     checkpoint::{
         base::Checkpointer, builder::CheckpointerBuilder, retro_forward::RetroForward,
         state::BackwardStates, strategy::CheckpointStrategy,
@@ -29,6 +30,11 @@ use burn_std::{BoolDType, FloatDType, IndexingUpdateOp, IntDType, Shape, Slice};
 
 use burn_backend::distributed::DistributedParams;
 
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
 use super::maxmin::MaxMinDim;
 
 // Unsqueeze op on primitive.
@@ -66,6 +72,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         AutodiffTensor::new(B::float_random(shape, distribution, device, dtype))
     }
 
+    fn float_random_gamma(alpha: FloatTensor<Self>) -> FloatTensor<Self> {
+        AutodiffTensor::new(B::float_random_gamma(alpha.primitive))
+    }
+
     fn float_zeros(shape: Shape, device: &Device<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         AutodiffTensor::new(B::float_zeros(shape, device, dtype))
     }
@@ -1122,7 +1132,187 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                     )),
                 }
             }
-            other => unimplemented!("float_scatter with {other:?} update is not implemented"),
+            IndexingUpdateOp::Mul => {
+                // Forward: out = tensor * prod(values scattered at each position), with
+                // duplicate indices multiplying together.
+                // Backward:
+                //   grad_tensor = grad * scatter(ones_like(tensor), idx, values, Mul)
+                //   grad_values = gather(grad * out, idx) / values
+                #[derive(Debug)]
+                struct ScatterMul;
+
+                impl<B: Backend> Backward<B, 2> for ScatterMul {
+                    type State = (usize, IntTensor<B>, FloatTensor<B>, Option<FloatTensor<B>>);
+
+                    fn backward(
+                        self,
+                        ops: Ops<Self::State, 2>,
+                        grads: &mut Gradients,
+                        _checkpointer: &mut Checkpointer,
+                    ) {
+                        let (dim, indices, values, output) = ops.state;
+                        let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                        let [values_4lhs, values_4rhs] = duplicate(&ops.parents, Some(values));
+
+                        binary::<B, _, _>(
+                            ops.parents,
+                            ops.node,
+                            grads,
+                            |grad| {
+                                let ones = B::float_ones(
+                                    grad.shape(),
+                                    &grad.device(),
+                                    grad.dtype().into(),
+                                );
+                                let factor = B::float_scatter(
+                                    dim,
+                                    ones,
+                                    indices_4lhs.unwrap(),
+                                    values_4lhs.unwrap(),
+                                    IndexingUpdateOp::Mul,
+                                );
+                                B::float_mul(grad, factor)
+                            },
+                            |grad| {
+                                // Like `float_prod_dim`, this divides by the values and produces
+                                // NaN gradients when they contain zeros.
+                                let grad = B::float_mul(grad, output.unwrap());
+                                let grad = B::float_gather(dim, grad, indices_4rhs.unwrap());
+                                B::float_div(grad, values_4rhs.unwrap())
+                            },
+                        );
+                    }
+                }
+
+                let values_tracked = value.is_tracked();
+
+                match ScatterMul
+                    .prepare::<C>([tensor.node, value.node])
+                    .compute_bound()
+                    .stateful()
+                {
+                    OpsKind::Tracked(prep) => {
+                        let output = B::float_scatter(
+                            dim,
+                            tensor.primitive,
+                            indices.clone(),
+                            value.primitive.clone(),
+                            IndexingUpdateOp::Mul,
+                        );
+                        prep.finish(
+                            (
+                                dim,
+                                indices,
+                                value.primitive,
+                                values_tracked.then(|| output.clone()),
+                            ),
+                            output,
+                        )
+                    }
+                    OpsKind::UnTracked(prep) => prep.finish(B::float_scatter(
+                        dim,
+                        tensor.primitive,
+                        indices,
+                        value.primitive,
+                        IndexingUpdateOp::Mul,
+                    )),
+                }
+            }
+            IndexingUpdateOp::Min | IndexingUpdateOp::Max => {
+                // Forward (Max): out = max(tensor, values scattered at each position).
+                // Backward, with the gradient split evenly between the elements equal to the
+                // output, including duplicate indices (matches the `max_dim` convention):
+                //   tensor_won  = tensor == out
+                //   values_won  = values == gather(out, idx)
+                //   count       = scatter(tensor_won, idx, values_won, Add)
+                //   grad_tensor = grad * tensor_won / count
+                //   grad_values = gather(grad / count, idx) * values_won
+                #[derive(Debug)]
+                struct ScatterMinMax;
+
+                impl<B: Backend> Backward<B, 2> for ScatterMinMax {
+                    type State = (
+                        usize,
+                        IntTensor<B>,
+                        FloatTensor<B>,
+                        FloatTensor<B>,
+                        FloatTensor<B>,
+                    );
+
+                    fn backward(
+                        self,
+                        ops: Ops<Self::State, 2>,
+                        grads: &mut Gradients,
+                        _checkpointer: &mut Checkpointer,
+                    ) {
+                        let (dim, indices, tensor, values, output) = ops.state;
+                        let dtype = tensor.dtype().into();
+                        let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+
+                        let output_at_idx = B::float_gather(dim, output.clone(), indices.clone());
+                        let tensor_won =
+                            B::bool_into_float(B::float_equal(tensor, output, bool_dtype), dtype);
+                        let values_won = B::bool_into_float(
+                            B::float_equal(values, output_at_idx, bool_dtype),
+                            dtype,
+                        );
+                        // The output always comes from at least one element, the clamp only
+                        // guards the positions where it is NaN.
+                        let count = B::float_scatter_add(
+                            dim,
+                            tensor_won.clone(),
+                            indices.clone(),
+                            values_won.clone(),
+                        );
+                        let count = B::float_clamp_min(count, 1f64.into());
+
+                        binary::<B, _, _>(
+                            ops.parents,
+                            ops.node,
+                            grads,
+                            |grad| B::float_div(B::float_mul(grad, tensor_won), count.clone()),
+                            |grad| {
+                                let grad = B::float_div(grad, count.clone());
+                                let grad = B::float_gather(dim, grad, indices);
+                                B::float_mul(grad, values_won)
+                            },
+                        );
+                    }
+                }
+
+                match ScatterMinMax
+                    .prepare::<C>([tensor.node, value.node])
+                    .compute_bound()
+                    .stateful()
+                {
+                    OpsKind::Tracked(prep) => {
+                        let output = B::float_scatter(
+                            dim,
+                            tensor.primitive.clone(),
+                            indices.clone(),
+                            value.primitive.clone(),
+                            update,
+                        );
+                        prep.finish(
+                            (
+                                dim,
+                                indices,
+                                tensor.primitive,
+                                value.primitive,
+                                output.clone(),
+                            ),
+                            output,
+                        )
+                    }
+                    OpsKind::UnTracked(prep) => prep.finish(B::float_scatter(
+                        dim,
+                        tensor.primitive,
+                        indices,
+                        value.primitive,
+                        update,
+                    )),
+                }
+            }
         }
     }
 
@@ -2067,6 +2257,14 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         matches!(tensor.node.requirement, Requirement::Grad)
     }
 
+    fn float_set_per_sample_grad(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        tensor.grad_per_sample()
+    }
+
+    fn float_is_per_sample_grad(tensor: &FloatTensor<Self>) -> bool {
+        tensor.node.per_sample
+    }
+
     fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Mean;
@@ -2206,6 +2404,170 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_logsumexp_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct LogSumExpDim;
+
+        impl<B: Backend> Backward<B, 1> for LogSumExpDim {
+            // Saves the input and the reduced output (size 1 along `dim`).
+            type State = (B::FloatTensorPrimitive, B::FloatTensorPrimitive);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx_i logsumexp(x) = exp(x_i - logsumexp(x)), i.e. the softmax along
+                    // `dim`. The grad and output broadcast back over the input along `dim`.
+                    let softmax = B::float_exp(B::float_sub(input, output));
+                    B::float_mul(softmax, grad)
+                });
+            }
+        }
+
+        match LogSumExpDim
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_logsumexp_dim(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_logsumexp_dim(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_nansum_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct NanSumDim;
+
+        impl<B: Backend> Backward<B, 1> for NanSumDim {
+            type State = B::FloatTensorPrimitive;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let input = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // Like `sum_dim`, except that the NaN elements don't contribute.
+                    let bool_dtype = get_device_settings::<B>(&input.device()).bool_dtype;
+                    let shape = input.shape();
+                    let is_nan = B::float_is_nan(input, bool_dtype);
+                    B::float_mask_fill(B::float_expand(grad, shape), is_nan, 0f64.into())
+                });
+            }
+        }
+
+        match NanSumDim
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                tensor.primitive.clone(),
+                B::float_nansum_dim(tensor.primitive, dim),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_nansum_dim(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_nanmean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct NanMeanDim;
+
+        impl<B: Backend> Backward<B, 1> for NanMeanDim {
+            type State = (B::FloatTensorPrimitive, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, dim) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // Like `mean_dim`, dividing by the number of non-NaN elements instead of
+                    // the size of `dim`, and the NaN elements don't contribute.
+                    let bool_dtype = get_device_settings::<B>(&input.device()).bool_dtype;
+                    let shape = input.shape();
+                    let dtype = input.dtype().into();
+                    let is_nan = B::float_is_nan(input, bool_dtype);
+                    let count = B::bool_into_float(B::bool_not(is_nan.clone()), dtype);
+                    let grad = B::float_div(grad, B::float_sum_dim(count, dim));
+
+                    B::float_mask_fill(B::float_expand(grad, shape), is_nan, 0f64.into())
+                });
+            }
+        }
+
+        match NanMeanDim
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), dim),
+                B::float_nanmean_dim(tensor.primitive, dim),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_nanmean_dim(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_nanmax_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct NanMaxDim;
+
+        impl<B: Backend> Backward<B, 1> for NanMaxDim {
+            // Saves the input and the reduced output (size 1 along `dim`).
+            type State = (B::FloatTensorPrimitive, B::FloatTensorPrimitive, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (input, output, dim) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient is split evenly between the elements equal to the maximum.
+                    // NaN is never equal to the output, so all-NaN slices get no gradient.
+                    let bool_dtype = get_device_settings::<B>(&input.device()).bool_dtype;
+                    let shape = input.shape();
+                    let dtype = input.dtype().into();
+                    let output = B::float_expand(output, shape.clone());
+                    let is_max = B::float_equal(input, output, bool_dtype);
+                    let ties = B::float_sum_dim(B::bool_into_float(is_max.clone(), dtype), dim);
+                    let grad = B::float_expand(B::float_div(grad, ties), shape);
+
+                    B::float_mask_fill(grad, B::bool_not(is_max), 0f64.into())
+                });
+            }
+        }
+
+        match NanMaxDim
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_nanmax_dim(tensor.primitive.clone(), dim);
+                prep.finish((tensor.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_nanmax_dim(tensor.primitive, dim)),
+        }
+    }
+
     fn float_prod(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Prod;
@@ -2571,6 +2933,91 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_linear_recurrence(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct LinearRecurrence;
+
+        /// Shifts a tensor by one position along `dim`, towards the end when `forward` is
+        /// true, filling the freed position with zeros.
+        fn shift<B: Backend>(
+            tensor: B::FloatTensorPrimitive,
+            dim: usize,
+            forward: bool,
+        ) -> B::FloatTensorPrimitive {
+            let shape = tensor.shape();
+            let size = shape[dim];
+            let device = tensor.device();
+            let dtype = tensor.dtype().into();
+
+            if size <= 1 {
+                return B::float_zeros(shape, &device, dtype);
+            }
+
+            let mut pad_shape = shape.clone();
+            pad_shape[dim] = 1;
+            let zeros = B::float_zeros(pad_shape, &device, dtype);
+            let mut slices = vec![Slice::full(); shape.num_dims()];
+            if forward {
+                slices[dim] = Slice::new(0, Some(size as isize - 1), 1);
+                B::float_cat(vec![zeros, B::float_slice(tensor, &slices)], dim)
+            } else {
+                slices[dim] = Slice::new(1, None, 1);
+                B::float_cat(vec![B::float_slice(tensor, &slices), zeros], dim)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 2> for LinearRecurrence {
+            // Saves the decay factors, the states and the dimension.
+            type State = (B::FloatTensorPrimitive, B::FloatTensorPrimitive, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let [node_a, node_b] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+                let (a, output, dim) = ops.state;
+
+                // The gradient of the inputs runs the recurrence backwards:
+                // `grad_b[t] = grad[t] + a[t + 1] * grad_b[t + 1]`.
+                let decay = B::float_flip(shift::<B>(a, dim, false), &[dim]);
+                let grad_b = B::float_flip(
+                    B::float_linear_recurrence(decay, B::float_flip(grad, &[dim]), dim),
+                    &[dim],
+                );
+
+                if let Some(node) = node_a {
+                    // `h[t]` depends on `a[t]` through `a[t] * h[t - 1]`.
+                    let grad = B::float_mul(grad_b.clone(), shift::<B>(output, dim, true));
+                    grads.register::<B>(node.id, grad)
+                }
+                if let Some(node) = node_b {
+                    grads.register::<B>(node.id, grad_b)
+                }
+            }
+        }
+
+        match LinearRecurrence
+            .prepare::<C>([a.node, b.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_linear_recurrence(a.primitive.clone(), b.primitive, dim);
+                prep.finish((a.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_linear_recurrence(a.primitive, b.primitive, dim))
+            }
+        }
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<B> {
         B::float_argmax(tensor.primitive, dim, out_dtype)
     }
@@ -3584,6 +4031,265 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Erfinv;
+
+        retro_unary!(RetroErfinv, B::float_erfinv);
+
+        impl<B: Backend> Backward<B, 1> for Erfinv {
+            type State = NodeId;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let input = checkpointer.retrieve_node_output(ops.state);
+                    // d/dx erfinv(x) = sqrt(pi) / 2 * exp(erfinv(x)^2)
+                    let output = B::float_erfinv(input);
+                    let exponent = B::float_mul(output.clone(), output);
+                    let value = B::float_mul_scalar(
+                        B::float_exp(exponent),
+                        (1.0 / core::f64::consts::FRAC_2_SQRT_PI).into(),
+                    );
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfinv
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfinv::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfinv(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Lgamma;
+
+        retro_unary!(RetroLgamma, B::float_lgamma);
+
+        impl<B: Backend> Backward<B, 1> for Lgamma {
+            type State = NodeId;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let input = checkpointer.retrieve_node_output(ops.state);
+                    let value = B::float_digamma(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Lgamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroLgamma::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_lgamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lgamma(tensor.primitive)),
+        }
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Digamma;
+
+        retro_unary!(RetroDigamma, B::float_digamma);
+
+        impl<B: Backend> Backward<B, 1> for Digamma {
+            type State = NodeId;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let input = checkpointer.retrieve_node_output(ops.state);
+                    let value = B::float_polygamma(input, 1);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Digamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroDigamma::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_digamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_digamma(tensor.primitive)),
+        }
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: usize) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Polygamma;
+
+        #[derive(new, Debug)]
+        struct RetroPolygamma<B: Backend> {
+            input_id: NodeId,
+            n: usize,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroPolygamma<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeId) {
+                let input = states.get_state::<B::FloatTensorPrimitive>(&self.input_id);
+                let out = B::float_polygamma(input, self.n);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for Polygamma {
+            type State = (NodeId, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (input_id, n) = ops.state;
+                let input = checkpointer.retrieve_node_output(input_id);
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, B::float_polygamma(input, n + 1))
+                });
+            }
+        }
+
+        match Polygamma
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroPolygamma::<B>::new(tensor.node.id, n))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = (prep.checkpoint(&tensor), n);
+                prep.finish(state, B::float_polygamma(tensor.primitive, n))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_polygamma(tensor.primitive, n)),
+        }
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct I0;
+
+        retro_unary!(RetroI0, B::float_i0);
+
+        impl<B: Backend> Backward<B, 1> for I0 {
+            type State = NodeId;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let input = checkpointer.retrieve_node_output(ops.state);
+                    let value = B::float_i1(input);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match I0
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroI0::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_i0(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_i0(tensor.primitive)),
+        }
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct I1;
+
+        retro_unary!(RetroI1, B::float_i1);
+
+        impl<B: Backend> Backward<B, 1> for I1 {
+            type State = NodeId;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    let input = checkpointer.retrieve_node_output(ops.state);
+                    // d/dx i1(x) = i0(x) - i1(x) / x, which tends to 1/2 at x = 0.
+                    let bool_dtype = get_device_settings::<B>(&input.device()).bool_dtype;
+                    let is_zero = B::float_equal_elem(input.clone(), 0f64.into(), bool_dtype);
+                    let safe = B::float_mask_fill(input.clone(), is_zero.clone(), 1f64.into());
+                    let ratio = B::float_div(B::float_i1(safe.clone()), safe);
+                    let value = B::float_sub(B::float_i0(input), ratio);
+                    let value = B::float_mask_fill(value, is_zero, 0.5f64.into());
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match I1
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroI1::<B>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_i1(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_i1(tensor.primitive)),
+        }
+    }
+
     fn float_cat(tensors: Vec<FloatTensor<Self>>, dim: usize) -> FloatTensor<Self> {
         #[derive(new, Debug)]
         struct CatStep<B: Backend> {
@@ -3638,6 +4344,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
             fn distributed_params(&self) -> Option<DistributedParams> {
                 self.output.distributed_params.clone()
             }
+
+            fn anomaly(&self) -> Option<Arc<NodeAnomaly>> {
+                self.output.anomaly.clone()
+            }
         }
 
         let mut nodes = Vec::with_capacity(tensors.len());
@@ -3663,11 +4373,17 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 &nodes,
                 requirement,
                 cat_computing_property,
+                None,
             );
         }
 
-        let output =
-            AutodiffTensor::from_parents(output, &nodes, requirement, cat_computing_property);
+        let output = AutodiffTensor::from_parents(
+            output,
+            &nodes,
+            requirement,
+            cat_computing_property,
+            NodeAnomaly::capture::<CatStep<B>>(),
+        );
 
         let mut parents = Vec::new();
 
@@ -4069,6 +4785,82 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argsort(tensor.primitive, dim, descending, out_dtype)
     }
 
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<B> {
+        B::float_searchsorted(
+            sorted_sequence.primitive,
+            values.primitive,
+            right,
+            out_dtype,
+        )
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: Scalar,
+        max: Scalar,
+    ) -> FloatTensor<Self> {
+        AutodiffTensor::new(B::float_histc(tensor.primitive, bins, min, max))
+    }
+
+    fn float_bincount(
+        tensor: IntTensor<B>,
+        weights: FloatTensor<Self>,
+        length: usize,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct Bincount;
+
+        impl<B: Backend> Backward<B, 1> for Bincount {
+            type State = (IntTensor<B>, usize);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (indices, length) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    // Each weight receives the gradient of its bin, and the ignored values
+                    // outside of `0..length` receive none.
+                    let bool_dtype = get_device_settings::<B>(&grad.device()).bool_dtype;
+                    let ignored = B::bool_or(
+                        B::int_lower_elem(indices.clone(), 0i64.into(), bool_dtype),
+                        B::int_greater_equal_elem(
+                            indices.clone(),
+                            (length as i64).into(),
+                            bool_dtype,
+                        ),
+                    );
+                    let indices = B::int_mask_fill(indices, ignored.clone(), 0i64.into());
+
+                    B::float_mask_fill(B::float_gather(0, grad, indices), ignored, 0f64.into())
+                });
+            }
+        }
+
+        match Bincount
+            .prepare::<C>([weights.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.clone(), length),
+                B::float_bincount(tensor, weights.primitive, length),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_bincount(tensor, weights.primitive, length))
+            }
+        }
+    }
+
     fn float_repeat_dim(tensor: FloatTensor<Self>, dim: usize, times: usize) -> FloatTensor<Self> {
         #[derive(Debug)]
         struct Repeat;
@@ -1,6 +1,7 @@
 use super::memory_management::GraphMemoryManagement;
 use crate::{
     NodeId,
+    anomaly::is_non_finite,
     checkpoint::{
         base::{Checkpointer, NodeTree},
         builder::CheckpointerBuilder,
@@ -84,7 +85,7 @@ impl AutodiffServer {
             _ => Gradients::new::<B>(root_node, root_tensor),
         };
 
-        let gradients = Self::execute_steps(tape_result.tape, grads, tape_result.checkpointer);
+        let gradients = Self::execute_steps::<B>(tape_result.tape, grads, tape_result.checkpointer);
 
         self.cleanup::<NC>(&consumed);
 
@@ -177,15 +178,30 @@ impl AutodiffServer {
         }
     }
 
-    fn execute_steps(
+    fn execute_steps<B: Backend>(
         tape: Vec<Vec<StepBoxed>>,
         mut grads: Gradients,
         mut checkpointer: Checkpointer,
     ) -> Gradients {
         tape.into_iter().rev().for_each(|steps| {
-            steps
-                .into_iter()
-                .for_each(|step| step.step(&mut grads, &mut checkpointer))
+            steps.into_iter().for_each(|step| match step.anomaly() {
+                None => step.step(&mut grads, &mut checkpointer),
+                // The node was created with anomaly detection enabled, so the gradients produced by
+                // its backward step are checked before going further.
+                Some(anomaly) => {
+                    let parents = step.parents().to_vec();
+                    step.step(&mut grads, &mut checkpointer);
+
+                    let non_finite = parents.iter().any(|parent| {
+                        grads
+                            .get_by_id::<B>(parent.id)
+                            .is_some_and(is_non_finite::<B>)
+                    });
+                    if non_finite {
+                        anomaly.report();
+                    }
+                }
+            })
         });
 
         // For checkpointing tests
@@ -1,4 +1,5 @@
 use crate::{
+    anomaly::NodeAnomaly,
     checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
     grads::{BackwardMode, Gradients},
     graph::{ComputingProperty, Node, NodeId, NodeRef, Parent, Requirement, Step},
@@ -89,6 +90,8 @@ impl<B: Backend> AutodiffTensor<B> {
             ComputingProperty::Ambiguous,
             AutodiffClientImpl::new(),
             None,
+            false,
+            None,
         )
         .into();
 
@@ -123,6 +126,8 @@ impl<B: Backend> AutodiffTensor<B> {
                     self.node.properties.clone(),
                     self.node.client.clone(),
                     self.node.distributed_params.clone(),
+                    self.node.per_sample,
+                    None,
                 )
                 .into();
                 let step = RootStep::new(self.node.clone());
@@ -138,6 +143,7 @@ impl<B: Backend> AutodiffTensor<B> {
         parent_nodes: &[NodeRef],
         requirement: Requirement,
         computing_properties: ComputingProperty,
+        anomaly: Option<NodeAnomaly>,
     ) -> Self {
         let order = parent_nodes
             .iter()
@@ -163,6 +169,8 @@ impl<B: Backend> AutodiffTensor<B> {
             computing_properties,
             client,
             None,
+            false,
+            anomaly.map(Arc::new),
         )
         .into();
 
@@ -230,6 +238,40 @@ impl<B: Backend> AutodiffTensor<B> {
             self.node.properties.clone(),
             self.node.client.clone(),
             Some(DistributedParams { param_id }),
+            self.node.per_sample,
+            self.node.anomaly.clone(),
+        )
+        .into();
+        let step = RootStep::new(self.node.clone());
+
+        self.register_step(step, CheckpointerBuilder::default())
+    }
+
+    /// Mark the tensor to receive per-sample gradients.
+    ///
+    /// Its gradient gets an extra leading dimension with the gradient of each sample of the
+    /// batch, instead of their sum. Only the linear, convolution and embedding operations support
+    /// it, from their saved inputs and output gradients.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the tensor doesn't require gradients.
+    pub fn grad_per_sample(mut self) -> Self {
+        assert!(
+            matches!(self.node.requirement, Requirement::Grad),
+            "Only tensors that require gradients can receive per-sample gradients"
+        );
+
+        self.node = Node::new(
+            vec![],
+            0,
+            self.node.id,
+            self.node.requirement,
+            self.node.properties.clone(),
+            self.node.client.clone(),
+            self.node.distributed_params.clone(),
+            true,
+            self.node.anomaly.clone(),
         )
         .into();
         let step = RootStep::new(self.node.clone());
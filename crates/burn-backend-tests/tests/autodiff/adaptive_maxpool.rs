@@ -0,0 +1,69 @@
+use super::*;
+use burn_tensor::module::{adaptive_max_pool1d, adaptive_max_pool2d, adaptive_max_pool3d};
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+#[test]
+fn test_adaptive_max_pool1d_overlapping_windows() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<3>::from_data([[[1.0, 5.0, 2.0, 4.0, 3.0]]], &device).require_grad();
+
+    // The windows [0, 2) and [1, 4) share their maximum.
+    let output = adaptive_max_pool1d(x.clone(), 3);
+    let grads = output.backward();
+
+    let x_grad_expected = TestTensor::<3>::from_data([[[0.0, 2.0, 0.0, 1.0, 0.0]]], &device);
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
+
+#[test]
+fn test_adaptive_max_pool2d() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<4>::from_data(
+        [[[[0.1, 0.9, 0.3], [0.8, 0.2, 0.7], [0.4, 0.6, 0.5]]]],
+        &device,
+    )
+    .require_grad();
+
+    // The windows are [0, 2) and [1, 3) along each dimension.
+    let output = adaptive_max_pool2d(x.clone(), [2, 2]);
+    let grads = output.backward();
+
+    let x_grad_expected = TestTensor::<4>::from_data(
+        [[[[0.0, 2.0, 0.0], [1.0, 0.0, 1.0], [0.0, 0.0, 0.0]]]],
+        &device,
+    );
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
+
+#[test]
+fn test_adaptive_max_pool3d() {
+    let device = AutodiffDevice::new();
+    let shape = Shape::new([1, 1, 3, 3, 3]);
+    let x = TestTensor::<5>::from_data(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &device)
+            .reshape::<5, _>(shape)
+            .into_data(),
+        &device,
+    )
+    .require_grad();
+
+    let output = adaptive_max_pool3d(x.clone(), [2, 2, 2]);
+    let grads = output.backward();
+
+    let mut expected = [0.0; 27];
+    for index in [13, 14, 16, 17, 22, 23, 25, 26] {
+        expected[index] = 1.0;
+    }
+    let x_grad_expected =
+        TestTensor::<5>::from_data(TensorData::new(expected.to_vec(), [1, 1, 3, 3, 3]), &device);
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
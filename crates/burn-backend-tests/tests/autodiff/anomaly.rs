@@ -0,0 +1,41 @@
+use super::*;
+use burn_autodiff::anomaly::set_detect_anomaly;
+use burn_tensor::TensorData;
+
+#[test]
+#[should_panic(expected = "`Sqrt` produced a gradient with NaN or infinite values")]
+fn should_detect_non_finite_gradient() {
+    set_detect_anomaly(true);
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<1>::from_data([0.0, 4.0], &device).require_grad();
+
+    // The gradient of sqrt is infinite at zero.
+    let _grads = x.sqrt().mul_scalar(2.0).sum().backward();
+}
+
+#[test]
+fn should_not_report_finite_gradients() {
+    set_detect_anomaly(true);
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<1>::from_data([1.0, 4.0], &device).require_grad();
+
+    let grads = x.clone().sqrt().sum().backward();
+    set_detect_anomaly(false);
+
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0.5, 0.25]), false);
+}
+
+#[test]
+fn should_not_check_gradients_when_disabled() {
+    set_detect_anomaly(false);
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<1>::from_data([0.0, 4.0], &device).require_grad();
+
+    let grads = x.clone().sqrt().sum().backward();
+
+    let grad = x.grad(&grads).unwrap().into_data();
+    assert!(grad.iter::<f32>().next().unwrap().is_infinite());
+}
@@ -0,0 +1,70 @@
+use super::*;
+use burn_tensor::module::avg_pool3d;
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+fn arange_5d(shape: [usize; 5], device: &AutodiffDevice) -> TestTensor<5> {
+    let shape = Shape::new(shape);
+    TestTensor::from_data(
+        TestTensorInt::arange(0..shape.num_elements() as i64, device)
+            .reshape::<5, _>(shape)
+            .into_data(),
+        device,
+    )
+}
+
+#[test]
+fn test_avg_pool3d_simple() {
+    let device = AutodiffDevice::new();
+    let x = arange_5d([1, 1, 3, 3, 3], &device).require_grad();
+
+    let output = avg_pool3d(x.clone(), [2, 2, 2], [1, 1, 1], [0, 0, 0], true, false);
+    let grads = output.backward();
+
+    // Each element receives 1/8 per window covering it.
+    let x_grad_expected = TestTensor::<5>::from_data(
+        [[[
+            [
+                [0.125, 0.25, 0.125],
+                [0.25, 0.5, 0.25],
+                [0.125, 0.25, 0.125],
+            ],
+            [[0.25, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.25]],
+            [
+                [0.125, 0.25, 0.125],
+                [0.25, 0.5, 0.25],
+                [0.125, 0.25, 0.125],
+            ],
+        ]]],
+        &device,
+    );
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
+
+#[test]
+fn test_avg_pool3d_count_include_pad() {
+    let device = AutodiffDevice::new();
+
+    // Every window covers the whole input, so each element receives 8 times the inverse of the
+    // divisor.
+    for (count_include_pad, grad) in [(true, 8.0f32 / 27.0), (false, 1.0)] {
+        let x = arange_5d([1, 1, 2, 2, 2], &device).require_grad();
+        let output = avg_pool3d(
+            x.clone(),
+            [3, 3, 3],
+            [1, 1, 1],
+            [1, 1, 1],
+            count_include_pad,
+            false,
+        );
+        let grads = output.backward();
+
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_actual.to_data().assert_approx_eq::<FloatElem>(
+            &TensorData::full([1, 1, 2, 2, 2], grad),
+            Tolerance::default(),
+        );
+    }
+}
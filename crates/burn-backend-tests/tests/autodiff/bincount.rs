@@ -0,0 +1,20 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_diff_bincount_weighted() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensorInt::<1>::from_data([0, 2, 2, 5], &device);
+    let weights = TestTensor::<1>::from_data([0.5, 1.0, 2.0, 4.0], &device).require_grad();
+    let scale = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+
+    let sums = tensor.bincount_weighted(weights.clone(), 3);
+    let grads = (sums * scale).sum().backward();
+
+    // Each weight receives the gradient of its bin, and the value 5 is outside of the bins.
+    let grad = weights.grad(&grads).unwrap();
+    grad.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([1.0, 3.0, 3.0, 0.0]),
+        Tolerance::default(),
+    );
+}
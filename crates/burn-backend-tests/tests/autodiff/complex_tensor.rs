@@ -0,0 +1,92 @@
+use super::*;
+use burn_tensor::complex::ComplexTensor;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_diff_complex_squared_abs() {
+    let device = AutodiffDevice::new();
+    let re = TestTensor::<1>::from_data([1.0, 3.0], &device);
+    let im = TestTensor::<1>::from_data([2.0, -1.0], &device);
+    let z = ComplexTensor::new(re, im).require_grad();
+
+    // |z|^2 = z * conj(z), whose gradient is 2z.
+    let grads = z.clone().mul(z.clone().conj()).real().sum().backward();
+
+    let (grad_re, grad_im) = z.grad(&grads).unwrap().into_parts();
+    grad_re
+        .to_data()
+        .assert_eq(&TensorData::from([2.0, 6.0]), false);
+    grad_im
+        .to_data()
+        .assert_eq(&TensorData::from([4.0, -2.0]), false);
+}
+
+#[test]
+fn should_diff_complex_mul() {
+    let device = AutodiffDevice::new();
+    let z = ComplexTensor::new(
+        TestTensor::<1>::from_data([1.0, 3.0], &device),
+        TestTensor::<1>::from_data([2.0, -1.0], &device),
+    )
+    .require_grad();
+    let w = ComplexTensor::new(
+        TestTensor::<1>::from_data([2.0, 0.0], &device),
+        TestTensor::<1>::from_data([-1.0, 1.0], &device),
+    );
+
+    // The gradient of Re(w * z) is conj(w).
+    let grads = w.mul(z.clone()).real().sum().backward();
+
+    let (grad_re, grad_im) = z.grad(&grads).unwrap().into_parts();
+    grad_re
+        .to_data()
+        .assert_eq(&TensorData::from([2.0, 0.0]), false);
+    grad_im
+        .to_data()
+        .assert_eq(&TensorData::from([1.0, -1.0]), false);
+}
+
+#[test]
+fn should_diff_complex_matmul() {
+    let device = AutodiffDevice::new();
+    let lhs = ComplexTensor::new(
+        TestTensor::<2>::from_data([[1.0, 2.0], [0.0, 0.0]], &device),
+        TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 1.0]], &device),
+    )
+    .require_grad();
+    let rhs = ComplexTensor::new(
+        TestTensor::<2>::from_data([[1.0, 0.0], [1.0, 0.0]], &device),
+        TestTensor::<2>::from_data([[0.0, 1.0], [-1.0, 0.0]], &device),
+    );
+
+    let grads = lhs.clone().matmul(rhs).real().sum().backward();
+
+    let (grad_re, grad_im) = lhs.grad(&grads).unwrap().into_parts();
+    grad_re
+        .to_data()
+        .assert_eq(&TensorData::from([[1.0, 1.0], [1.0, 1.0]]), false);
+    grad_im
+        .to_data()
+        .assert_eq(&TensorData::from([[-1.0, 1.0], [-1.0, 1.0]]), false);
+}
+
+#[test]
+fn should_diff_complex_abs() {
+    let device = AutodiffDevice::new();
+    let z = ComplexTensor::new(
+        TestTensor::<1>::from_data([3.0], &device),
+        TestTensor::<1>::from_data([4.0], &device),
+    )
+    .require_grad();
+
+    // The gradient of |z| is z / |z|.
+    let grads = z.clone().abs().sum().backward();
+
+    let (grad_re, grad_im) = z.grad(&grads).unwrap().into_parts();
+    grad_re
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([0.6]), Tolerance::default());
+    grad_im
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([0.8]), Tolerance::default());
+}
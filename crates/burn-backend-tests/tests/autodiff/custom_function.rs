@@ -0,0 +1,155 @@
+use super::*;
+use burn_tensor::{CustomFunction, FunctionCtx, Tensor, TensorData};
+
+/// Computes `x^3`, with the gradient `3x^2`.
+struct Cube;
+
+impl CustomFunction<2> for Cube {
+    type Inputs = Tensor<2>;
+
+    fn forward(&self, ctx: &mut FunctionCtx, x: Tensor<2>) -> Tensor<2> {
+        ctx.save_for_backward(x.clone());
+        x.powi_scalar(3)
+    }
+
+    fn backward(&self, ctx: &FunctionCtx, grad: Tensor<2>) -> Option<Tensor<2>> {
+        let x = ctx.saved_tensor::<2>(0);
+        Some(grad * x.square().mul_scalar(3))
+    }
+}
+
+/// Computes `x * scale + bias`, with the bias broadcast over the rows.
+struct ScaleShift {
+    scale: f32,
+    bias_grad: bool,
+}
+
+impl CustomFunction<2> for ScaleShift {
+    type Inputs = (Tensor<2>, Tensor<1>);
+
+    fn forward(&self, _ctx: &mut FunctionCtx, (x, bias): (Tensor<2>, Tensor<1>)) -> Tensor<2> {
+        x.mul_scalar(self.scale) + bias.unsqueeze()
+    }
+
+    fn backward(
+        &self,
+        ctx: &FunctionCtx,
+        grad: Tensor<2>,
+    ) -> (Option<Tensor<2>>, Option<Tensor<1>>) {
+        let [_, cols] = grad.dims();
+        let grad_bias = (self.bias_grad && ctx.needs_input_grad(1))
+            .then(|| grad.clone().sum_dim(0).reshape([cols]));
+
+        (Some(grad.mul_scalar(self.scale)), grad_bias)
+    }
+}
+
+#[test]
+fn should_diff_custom_function() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+    let y = Cube.apply(x.clone());
+    let grads = y.clone().sum().backward();
+
+    y.into_data()
+        .assert_eq(&TensorData::from([[1.0, 8.0], [27.0, 64.0]]), false);
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[3.0, 12.0], [27.0, 48.0]]), false);
+}
+
+#[test]
+fn should_diff_custom_function_in_graph() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+    // d/dx (2x)^3 = 24x^2
+    let y = Cube.apply(x.clone().mul_scalar(2.0)).add_scalar(1.0);
+    let grads = y.sum().backward();
+
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[24.0, 96.0], [216.0, 384.0]]), false);
+}
+
+#[test]
+fn should_diff_custom_function_with_multiple_inputs() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+    let bias = TestTensor::<1>::from_data([1.0, -1.0], &device).require_grad();
+    let function = ScaleShift {
+        scale: 2.0,
+        bias_grad: true,
+    };
+
+    // y = [[3, 3], [7, 7]] and dL/dy = 2y.
+    let y = function.apply((x.clone(), bias.clone()));
+    let grads = (y.clone() * y).sum().backward();
+
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[12.0, 12.0], [28.0, 28.0]]), false);
+    bias.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([20.0, 20.0]), false);
+}
+
+#[test]
+fn should_skip_custom_function_inputs_without_grad() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+    let bias = TestTensor::<1>::from_data([1.0, -1.0], &device);
+    let function = ScaleShift {
+        scale: 2.0,
+        bias_grad: true,
+    };
+
+    let y = function.apply((x.clone(), bias.clone()));
+    let grads = y.sum().backward();
+
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[2.0, 2.0], [2.0, 2.0]]), false);
+    assert!(bias.grad(&grads).is_none());
+}
+
+#[test]
+fn should_use_zero_grad_for_missing_custom_function_grad() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+    let bias = TestTensor::<1>::from_data([1.0, -1.0], &device).require_grad();
+    let function = ScaleShift {
+        scale: 2.0,
+        bias_grad: false,
+    };
+
+    let y = function.apply((x, bias.clone()));
+    let grads = y.sum().backward();
+
+    bias.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0.0, 0.0]), false);
+}
+
+#[test]
+fn should_diff_custom_function_between_memory_bound_ops() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+    // With checkpointing, the forward pass is recomputed from the input `x + 1`, which is
+    // itself recomputed. d/dx ((x + 1)^3 * x) = 3(x + 1)^2 * x + (x + 1)^3
+    let y = Cube.apply(x.clone().add_scalar(1.0)) * x.clone();
+    let grads = y.sum().backward();
+
+    x.grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[20.0, 81.0], [208.0, 425.0]]), false);
+}
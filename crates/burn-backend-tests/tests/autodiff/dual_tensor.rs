@@ -0,0 +1,82 @@
+use super::*;
+use burn_tensor::dual::DualTensor;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_vjp_elementwise() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let cotangent = TestTensor::<1>::from_data([1.0, -1.0, 0.5], &device);
+
+    let (output, vjp) = x.vjp(cotangent, |x| x.clone().mul(x));
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([1.0, 4.0, 9.0]), false);
+    vjp.into_data()
+        .assert_eq(&TensorData::from([2.0, -4.0, 3.0]), false);
+}
+
+#[test]
+fn should_vjp_matmul() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0]], &device);
+    let weight = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let cotangent = TestTensor::<2>::from_data([[1.0, 0.0, 1.0]], &device);
+
+    let (_, vjp) = x.vjp(cotangent, |x| x.matmul(weight));
+
+    vjp.into_data()
+        .assert_eq(&TensorData::from([[4.0, 10.0]]), false);
+}
+
+#[test]
+fn should_hvp_cubic() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let vector = TestTensor::<1>::from_data([1.0, 0.0, 2.0], &device);
+
+    // The Hessian of sum(x^3) is diag(6x).
+    let hvp = x.hvp(vector, |x| x.powf_scalar(3.0).sum());
+
+    hvp.into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([6.0, 0.0, 36.0]), Tolerance::default());
+}
+
+#[test]
+fn should_hvp_quadratic_form() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<2>::from_data([[2.0, 1.0]], &device);
+    let matrix = TestTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device);
+    let vector = TestTensor::<2>::from_data([[1.0, -1.0]], &device);
+
+    // The Hessian of x^T A x / 2 is A for a symmetric A.
+    let hvp = x.hvp(vector, |x| {
+        x.clone()
+            .matmul(DualTensor::constant(matrix))
+            .mul(x)
+            .sum()
+            .mul_scalar(0.5)
+    });
+
+    hvp.into_data()
+        .assert_eq(&TensorData::from([[1.0, -2.0]]), false);
+}
+
+#[test]
+fn should_hvp_tanh() {
+    let device = AutodiffDevice::new();
+    let values = [-0.5, 0.25, 1.0];
+    let x = TestTensor::<1>::from_data(values, &device);
+    let vector = TestTensor::<1>::from_data([1.0, 2.0, -1.0], &device);
+
+    let hvp = x.clone().hvp(vector.clone(), |x| x.tanh().sum());
+
+    // tanh'' = -2 tanh (1 - tanh^2)
+    let tanh = x.tanh();
+    let expected = tanh.clone().mul_scalar(-2.0) * (tanh.square().neg() + 1.0) * vector;
+    hvp.into_data().assert_approx_eq::<FloatElem>(
+        &expected.into_data(),
+        Tolerance::default().set_half_precision_relative(1e-2),
+    );
+}
@@ -0,0 +1,45 @@
+use super::*;
+use burn_tensor::{TensorData, einsum};
+
+#[test]
+fn should_diff_einsum_matmul() {
+    let data_1 = TensorData::from([[1.0, 7.0], [2.0, 3.0]]);
+    let data_2 = TensorData::from([[4.0, 7.0], [2.0, 3.0]]);
+
+    let device = AutodiffDevice::new();
+    let tensor_1 = TestTensor::<2>::from_data(data_1, &device).require_grad();
+    let tensor_2 = TestTensor::from_data(data_2, &device).require_grad();
+
+    let tensor_3: TestTensor<2> = einsum("ij,jk->ik", &[tensor_1.clone(), tensor_2.clone()]);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    grad_1
+        .to_data()
+        .assert_eq(&TensorData::from([[11.0, 5.0], [11.0, 5.0]]), false);
+    grad_2
+        .to_data()
+        .assert_eq(&TensorData::from([[3.0, 3.0], [10.0, 10.0]]), false);
+    tensor_3
+        .to_data()
+        .assert_eq(&TensorData::from([[18.0, 28.0], [14.0, 23.0]]), false);
+}
+
+#[test]
+fn should_diff_einsum_trace() {
+    let data = TensorData::from([[1.0, 7.0], [2.0, 3.0]]);
+
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data(data, &device).require_grad();
+
+    let trace: TestTensor<1> = einsum("ii", &[tensor.clone()]);
+    let grads = trace.backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+
+    grad.to_data()
+        .assert_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), false);
+    trace.to_data().assert_eq(&TensorData::from([4.0]), false);
+}
@@ -0,0 +1,141 @@
+use super::*;
+use burn_tensor::{
+    TensorData, Tolerance,
+    linalg::{cholesky, eigh, inverse, pinv, solve, solve_triangular, svd},
+};
+
+#[test]
+fn should_diff_cholesky() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[4.0, 2.0], [2.0, 5.0]], &device).require_grad();
+
+    let l = cholesky(tensor.clone());
+    let grads = l.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    // Only the lower triangular part of the input is read.
+    let expected = TensorData::from([[0.1875, 0.0], [0.25, 0.25]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_diff_inverse() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+    let output = inverse(tensor.clone());
+    let grads = output.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    // d(sum(A^-1))/dA = -A^-T 1 1^T A^-T
+    let expected = TensorData::from([[0.04, -0.08], [-0.03, 0.06]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_diff_solve() {
+    let device = AutodiffDevice::new();
+    let a = TestTensor::<2>::from_data([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+    let b = TestTensor::<2>::from_data([[9.0], [8.0]], &device).require_grad();
+
+    let x = solve(a.clone(), b.clone());
+    let grads = x.sum().backward();
+    let grad_a = a.grad(&grads).unwrap();
+    let grad_b = b.grad(&grads).unwrap();
+
+    // With g = A^-T 1 = [0.2, 0.4]: dL/dB = g and dL/dA = -g x^T, with x = [2, 3].
+    let expected_b = TensorData::from([[0.2], [0.4]]);
+    let expected_a = TensorData::from([[-0.4, -0.6], [-0.8, -1.2]]);
+    grad_b
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&expected_b, Tolerance::default());
+    grad_a
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&expected_a, Tolerance::default());
+}
+
+#[test]
+fn should_diff_solve_triangular() {
+    let device = AutodiffDevice::new();
+    let a = TestTensor::<2>::from_data([[2.0, 0.0], [1.0, 4.0]], &device).require_grad();
+    let b = TestTensor::<2>::from_data([[2.0], [9.0]], &device).require_grad();
+
+    let x = solve_triangular(a.clone(), b.clone(), false, true, false);
+    let grads = x.sum().backward();
+    let grad_a = a.grad(&grads).unwrap();
+    let grad_b = b.grad(&grads).unwrap();
+
+    // With g = A^-T 1 = [0.375, 0.25] and x = [1, 2]; the upper part is never read.
+    let expected_b = TensorData::from([[0.375], [0.25]]);
+    let expected_a = TensorData::from([[-0.375, 0.0], [-0.25, -0.5]]);
+    grad_b
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&expected_b, Tolerance::default());
+    grad_a
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&expected_a, Tolerance::default());
+}
+
+#[test]
+fn should_diff_eigh_eigenvalues() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[2.0, 1.0], [1.0, 2.0]], &device).require_grad();
+
+    let (eigenvalues, _eigenvectors) = eigh::<2, 1>(tensor.clone());
+    let grads = eigenvalues.slice([1..2]).sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    // The gradient of an eigenvalue is v v^T, with v = [1, 1] / sqrt(2) for l = 3.
+    let expected = TensorData::from([[0.5, 0.5], [0.5, 0.5]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::rel_abs(1e-3, 1e-4));
+}
+
+#[test]
+fn should_diff_eigh_trace() {
+    let device = AutodiffDevice::new();
+    let tensor =
+        TestTensor::<2>::from_data([[2.0, 0.0, 0.0], [0.0, 3.0, 1.0], [0.0, 1.0, 3.0]], &device)
+            .require_grad();
+
+    let (eigenvalues, _eigenvectors) = eigh::<2, 1>(tensor.clone());
+    let grads = eigenvalues.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    // The sum of the eigenvalues is the trace, even with repeated eigenvalues.
+    let expected = TensorData::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::rel_abs(1e-3, 1e-4));
+}
+
+#[test]
+fn should_diff_svd_singular_values() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[3.0, 0.0], [0.0, -2.0]], &device).require_grad();
+
+    let (_u, s, _vh) = svd::<2, 1>(tensor.clone(), false);
+    let grads = s.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    // The gradient of the nuclear norm is U V^T.
+    let expected = TensorData::from([[1.0, 0.0], [0.0, -1.0]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::rel_abs(1e-3, 1e-4));
+}
+
+#[test]
+fn should_diff_pinv() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+    let output = pinv(tensor.clone(), None);
+    let grads = output.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    // The pseudo-inverse of an invertible matrix is its inverse: -A^-T 1 1^T A^-T
+    let expected = TensorData::from([[0.04, -0.08], [-0.03, 0.06]]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::rel_abs(1e-3, 1e-4));
+}
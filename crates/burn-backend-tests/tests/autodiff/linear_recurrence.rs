@@ -0,0 +1,26 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_diff_linear_recurrence() {
+    let device = AutodiffDevice::new();
+    let a = TestTensor::<2>::from_data([[0.5, 2.0, -1.0]], &device).require_grad();
+    let b = TestTensor::<2>::from_data([[1.0, 2.0, 3.0]], &device).require_grad();
+    let weights = TestTensor::<2>::from_data([[1.0, 2.0, 3.0]], &device);
+
+    // h = [1.0, 4.0, -1.0]
+    let output = a.clone().linear_recurrence(b.clone(), 1);
+    let grads = (output * weights).sum().backward();
+
+    let grad_a = a.grad(&grads).unwrap();
+    let grad_b = b.grad(&grads).unwrap();
+
+    grad_a.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[0.0, -1.0, 12.0]]),
+        Tolerance::default(),
+    );
+    grad_b.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[-1.0, -1.0, 3.0]]),
+        Tolerance::default(),
+    );
+}
@@ -0,0 +1,22 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_diff_logsumexp() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [1000.0, 1000.0, 1000.0]], &device)
+        .require_grad();
+
+    let output = tensor.clone().logsumexp(1);
+    let grads = output.backward();
+
+    // The gradient of logsumexp is the softmax of the input.
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([
+            [0.09003057, 0.24472847, 0.66524096],
+            [0.33333334, 0.33333334, 0.33333334],
+        ]),
+        Tolerance::default(),
+    );
+}
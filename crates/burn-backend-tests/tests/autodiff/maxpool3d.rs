@@ -0,0 +1,54 @@
+use super::*;
+use burn_tensor::module::max_pool3d;
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+fn arange_5d(shape: [usize; 5], device: &AutodiffDevice) -> TestTensor<5> {
+    let shape = Shape::new(shape);
+    TestTensor::from_data(
+        TestTensorInt::arange(0..shape.num_elements() as i64, device)
+            .reshape::<5, _>(shape)
+            .into_data(),
+        device,
+    )
+}
+
+#[test]
+fn test_max_pool3d_simple() {
+    let device = AutodiffDevice::new();
+    let x = arange_5d([1, 1, 3, 3, 3], &device).require_grad();
+
+    let output = max_pool3d(x.clone(), [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1], false);
+    let grads = output.backward();
+
+    // The maximum of each window is its last element.
+    let mut expected = [0.0; 27];
+    for index in [13, 14, 16, 17, 22, 23, 25, 26] {
+        expected[index] = 1.0;
+    }
+    let x_grad_expected =
+        TestTensor::<5>::from_data(TensorData::new(expected.to_vec(), [1, 1, 3, 3, 3]), &device);
+
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
+
+#[test]
+fn test_max_pool3d_overlapping_windows() {
+    let device = AutodiffDevice::new();
+    let x = arange_5d([1, 1, 2, 2, 2], &device).require_grad();
+
+    // Every window covers the whole input, so the last element receives all the gradients.
+    let output = max_pool3d(x.clone(), [3, 3, 3], [1, 1, 1], [1, 1, 1], [1, 1, 1], false);
+    let grads = output.backward();
+
+    let x_grad_expected = TestTensor::<5>::from_data(
+        [[[[[0.0, 0.0], [0.0, 0.0]], [[0.0, 0.0], [0.0, 8.0]]]]],
+        &device,
+    );
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
@@ -5,13 +5,17 @@ mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod adaptive_avgpool3d;
+mod adaptive_maxpool;
 mod add;
 mod aggregation;
 #[cfg(feature = "distributed")]
 mod all_reduce;
+mod anomaly;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
+mod bincount;
 mod bridge;
 mod broadcast;
 mod cast;
@@ -19,6 +23,7 @@ mod cat;
 mod ceil;
 mod checkpoint;
 mod complex;
+mod complex_tensor;
 mod conv1d;
 mod conv2d;
 mod conv3d;
@@ -32,8 +37,11 @@ mod cummax;
 mod cummin;
 mod cumprod;
 mod cumsum;
+mod custom_function;
 mod deform_conv2d;
 mod div;
+mod dual_tensor;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
@@ -44,19 +52,24 @@ mod gather_scatter_nd;
 mod gelu;
 mod gradients;
 mod hypot;
+mod linalg;
 mod linear;
+mod linear_recurrence;
 mod log;
 mod log1p;
 mod log_sigmoid;
+mod logsumexp;
 mod mask;
 mod mask_select;
 mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod memory_management;
 mod mul;
 mod multithread;
+mod nan_reduce;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
@@ -71,6 +84,7 @@ mod repeat_dim;
 mod reshape;
 mod rfft;
 mod round;
+mod scatter_reduce;
 mod select;
 mod sigmoid;
 mod sign;
@@ -78,8 +92,11 @@ mod slice;
 mod slice_assign;
 mod softmax;
 mod sort;
+mod sparse;
+mod special;
 mod sqrt;
 mod sub;
 mod transpose;
 mod trig;
+mod trilinear_interpolate;
 mod unfold;
@@ -0,0 +1,64 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_diff_nansum() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[1.0, f32::NAN, 3.0]], &device).require_grad();
+
+    let output = tensor.clone().nansum_dim(1).mul_scalar(2.0);
+    let grads = output.backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data()
+        .assert_eq(&TensorData::from([[2.0, 0.0, 2.0]]), false);
+}
+
+#[test]
+fn should_diff_nanmean() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[1.0, f32::NAN, 3.0, 4.0]], &device).require_grad();
+
+    let output = tensor.clone().nanmean_dim(1);
+    let grads = output.backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[0.33333334, 0.0, 0.33333334, 0.33333334]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_diff_nanmax() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data(
+        [[1.0, 3.0, f32::NAN, 3.0], [f32::NAN, 2.0, 0.0, -1.0]],
+        &device,
+    )
+    .require_grad();
+
+    let output = tensor.clone().nanmax_dim(1);
+    let grads = output.backward();
+
+    // Ties share the gradient.
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data().assert_eq(
+        &TensorData::from([[0.0, 0.5, 0.0, 0.5], [0.0, 1.0, 0.0, 0.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_diff_nan_to_num() {
+    let device = AutodiffDevice::new();
+    let tensor =
+        TestTensor::<1>::from_data([1.0, f32::NAN, f32::INFINITY, -2.0], &device).require_grad();
+
+    let output = tensor.clone().nan_to_num(0.0, None, None).mul_scalar(3.0);
+    let grads = output.backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data()
+        .assert_eq(&TensorData::from([3.0, 0.0, 0.0, 3.0]), false);
+}
@@ -199,3 +199,28 @@ fn round_trip_inverse_with_some_n_less() {
 
     TensorData::assert_approx_eq::<FloatElem>(&lhs.to_data(), &rhs.to_data(), Tolerance::default());
 }
+
+#[test]
+#[cfg(not(feature = "ndarray"))]
+fn should_diff_fft2_ortho() {
+    if !matches!(FloatElem::dtype(), DType::F32 | DType::F64) {
+        return;
+    }
+
+    let device = AutodiffDevice::new();
+
+    let random = TensorData::from([
+        [[0.26, 0.13], [0.36, 0.24], [0.40, 0.93], [0.12, 0.18]],
+        [[0.03, 0.74], [0.33, 0.70], [0.07, 0.61], [0.32, 0.66]],
+    ]);
+
+    let tensor = TestTensor::<3>::from_data(random.clone(), &device).require_grad();
+
+    // The orthonormal FFT is unitary, so the gradient of half the spectrum energy is the signal.
+    let spectrum = signal::fft2(tensor.clone(), None, signal::FftNorm::Ortho);
+    let loss = spectrum.powi_scalar(2).sum() * 0.5;
+    let grads = loss.backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    TensorData::assert_approx_eq::<FloatElem>(&grad.to_data(), &random, Tolerance::default());
+}
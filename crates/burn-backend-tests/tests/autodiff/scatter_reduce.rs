@@ -0,0 +1,97 @@
+use super::*;
+use burn_tensor::{ScatterReduce, TensorData};
+
+#[test]
+fn should_diff_scatter_reduce_amax_with_ties() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([1.0, 5.0, 3.0], &device).require_grad();
+    let values = TestTensor::<1>::from_data([5.0, 2.0, 3.0], &device).require_grad();
+    let indices = TestTensorInt::<1>::from_ints([0, 0, 2], &device);
+    let weights = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+
+    let output =
+        tensor
+            .clone()
+            .scatter_reduce(0, indices, values.clone(), ScatterReduce::Amax, true);
+    let grads = output.mul(weights).sum().backward();
+
+    // The tie at the last position splits the gradient between the tensor and the value.
+    tensor
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0.0, 2.0, 1.5]), false);
+    values
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([1.0, 0.0, 1.5]), false);
+}
+
+#[test]
+fn should_diff_scatter_reduce_prod() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([2.0, 3.0], &device).require_grad();
+    let values = TestTensor::<1>::from_data([4.0, 5.0, 6.0], &device).require_grad();
+    let indices = TestTensorInt::<1>::from_ints([0, 0, 1], &device);
+
+    let output =
+        tensor
+            .clone()
+            .scatter_reduce(0, indices, values.clone(), ScatterReduce::Prod, true);
+    let grads = output.sum().backward();
+
+    tensor
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([20.0, 6.0]), false);
+    values
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([10.0, 8.0, 3.0]), false);
+}
+
+#[test]
+fn should_diff_scatter_reduce_mean_exclude_self() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device).require_grad();
+    let values = TestTensor::<1>::from_data([3.0, 5.0, 9.0], &device).require_grad();
+    let indices = TestTensorInt::<1>::from_ints([0, 0, 2], &device);
+    let weights = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+
+    let output =
+        tensor
+            .clone()
+            .scatter_reduce(0, indices, values.clone(), ScatterReduce::Mean, false);
+    let grads = output.mul(weights).sum().backward();
+
+    // The scattered positions are overwritten, so only the middle one depends on the tensor.
+    tensor
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0.0, 2.0, 0.0]), false);
+    values
+        .grad(&grads)
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0.5, 0.5, 3.0]), false);
+}
+
+#[test]
+fn should_diff_segment_max() {
+    let device = AutodiffDevice::new();
+    let tensor =
+        TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 0.0], [5.0, 6.0]], &device).require_grad();
+    let segment_ids = TestTensorInt::<1>::from_ints([0, 0, 1], &device);
+
+    let output = tensor.clone().segment_max(segment_ids, 2);
+    let grads = output.sum().backward();
+
+    tensor.grad(&grads).unwrap().into_data().assert_eq(
+        &TensorData::from([[0.0, 1.0], [1.0, 0.0], [1.0, 1.0]]),
+        false,
+    );
+}
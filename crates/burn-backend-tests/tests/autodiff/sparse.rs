@@ -0,0 +1,69 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::sparse::SparseTensor;
+
+#[test]
+fn should_diff_spmm_coo() {
+    let device = AutodiffDevice::new();
+    let indices = TestTensorInt::<2>::from_data([[1, 0, 1, 0], [2, 1, 2, 0]], &device);
+    let values = TestTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+    let dense =
+        TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device).require_grad();
+
+    let sparse = SparseTensor::from_coo(indices, values.clone(), [3, 3]);
+    let grads = sparse.spmm(dense.clone()).sum().backward();
+
+    values
+        .grad(&grads)
+        .unwrap()
+        .to_data()
+        .assert_eq(&TensorData::from([11.0, 7.0, 11.0, 3.0]), false);
+    dense.grad(&grads).unwrap().to_data().assert_eq(
+        &TensorData::from([[4.0, 4.0], [2.0, 2.0], [4.0, 4.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_diff_spmm_csr() {
+    let device = AutodiffDevice::new();
+    let crow_indices = TestTensorInt::<1>::from_data([0, 2, 3, 3], &device);
+    let col_indices = TestTensorInt::<1>::from_data([0, 1, 2], &device);
+    let values = TestTensor::<1>::from_data([4.0, 2.0, 4.0], &device).require_grad();
+    let dense =
+        TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device).require_grad();
+
+    let sparse = SparseTensor::from_csr(crow_indices, col_indices, values.clone(), [3, 3]);
+    let grads = sparse.spmm(dense.clone()).sum().backward();
+
+    values
+        .grad(&grads)
+        .unwrap()
+        .to_data()
+        .assert_eq(&TensorData::from([3.0, 7.0, 11.0]), false);
+    dense.grad(&grads).unwrap().to_data().assert_eq(
+        &TensorData::from([[4.0, 4.0], [2.0, 2.0], [4.0, 4.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_diff_sparse_dense_round_trip() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<2>::from_data([[0.0, 2.0], [3.0, 0.0]], &device).require_grad();
+    let weights = TestTensor::<2>::from_data([[5.0, 6.0], [7.0, 8.0]], &device);
+
+    let grads = tensor
+        .clone()
+        .to_sparse()
+        .to_dense()
+        .mul(weights)
+        .sum()
+        .backward();
+
+    tensor
+        .grad(&grads)
+        .unwrap()
+        .to_data()
+        .assert_eq(&TensorData::from([[0.0, 6.0], [7.0, 0.0]]), false);
+}
@@ -0,0 +1,103 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+fn tolerance() -> Tolerance<FloatElem> {
+    Tolerance::rel_abs(1e-3, 1e-4).set_half_precision_relative(1e-2)
+}
+
+#[test]
+fn should_diff_lgamma() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([0.5, 1.0, 2.5, 4.0], &device).require_grad();
+
+    let grads = tensor.clone().lgamma().sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([-1.9635100, -0.5772157, 0.7031566, 1.2561177]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_diff_digamma() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([0.5, 1.0, 2.5, 4.0], &device).require_grad();
+
+    let grads = tensor.clone().digamma().sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([4.9348022, 1.6449341, 0.4903578, 0.2838230]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_diff_polygamma() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([0.5, 1.0, 2.5, 4.0], &device).require_grad();
+
+    let grads = tensor.clone().polygamma(1).sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([-16.828797, -2.4041138, -0.2362041, -0.0800397]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_diff_erfinv() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([-0.5, 0.0, 0.3, 0.9], &device).require_grad();
+
+    let grads = tensor.clone().erfinv().sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([1.1125848, 0.8862269, 0.9545204, 3.4280428]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_diff_i0() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([-2.0, 0.0, 1.0, 3.0], &device).require_grad();
+
+    let grads = tensor.clone().i0().sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([-1.5906369, 0.0, 0.5651591, 3.9533702]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_diff_i1() {
+    let device = AutodiffDevice::new();
+    let tensor = TestTensor::<1>::from_data([-2.0, 0.0, 1.0, 3.0], &device).require_grad();
+
+    let grads = tensor.clone().i1().sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    let expected = TensorData::from([1.4842669, 0.5, 0.7009068, 3.5630025]);
+    grad.to_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_diff_xlogy_with_zeros() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<1>::from_data([0.0, 2.0, 0.5], &device).require_grad();
+    let y = TestTensor::<1>::from_data([0.0, 4.0, 0.25], &device).require_grad();
+
+    let grads = x.clone().xlogy(y.clone()).sum().backward();
+    let grad_x = x.grad(&grads).unwrap();
+    let grad_y = y.grad(&grads).unwrap();
+
+    grad_x.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([0.0, 1.3862944, -1.3862944]),
+        Tolerance::default(),
+    );
+    grad_y
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([0.0, 0.5, 2.0]), Tolerance::default());
+}
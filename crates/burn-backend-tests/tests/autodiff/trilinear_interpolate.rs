@@ -0,0 +1,52 @@
+use super::*;
+use burn_tensor::module::{interpolate_antialiased, interpolate3d};
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+#[test]
+fn test_trilinear_interpolate_backward() {
+    let device = AutodiffDevice::new();
+    let shape = Shape::new([1, 1, 2, 2, 2]);
+    let x = TestTensor::<5>::from_data(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &device)
+            .reshape::<5, _>(shape)
+            .into_data(),
+        &device,
+    )
+    .require_grad();
+
+    let output = interpolate3d(
+        x.clone(),
+        [3, 3, 3],
+        InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(true),
+    );
+    let grads = output.backward();
+
+    // Along each dimension, both inputs receive the weights 1 + 0.5 of the outputs.
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_actual.to_data().assert_approx_eq::<FloatElem>(
+        &TensorData::full([1, 1, 2, 2, 2], 3.375f32),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_antialias_interpolate_backward() {
+    let device = AutodiffDevice::new();
+    let x = TestTensor::<4>::from_data([[[[0.0, 1.0, 2.0, 3.0]]]], &device).require_grad();
+
+    let output = interpolate_antialiased(
+        x.clone(),
+        [1, 2],
+        InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(false),
+    );
+    let grads = output.backward();
+
+    // The sums of the filter weights [3, 3, 1] / 7 and [1, 3, 3] / 7 of each input.
+    let x_grad_expected =
+        TestTensor::<4>::from_data([[[[3.0 / 7.0, 4.0 / 7.0, 4.0 / 7.0, 3.0 / 7.0]]]], &device);
+    let x_grad_actual = x.grad(&grads).unwrap();
+    x_grad_expected
+        .to_data()
+        .assert_approx_eq::<FloatElem>(&x_grad_actual.to_data(), Tolerance::default());
+}
@@ -0,0 +1,205 @@
+use super::*;
+use burn_tensor::signal::{FftNorm, cfft, fft, fft2, fftn, ifft, ifft2, ifftn, irfft2, rfft2};
+use burn_tensor::{Tensor, TensorData, Tolerance};
+
+/// Builds a complex tensor `[..., 2]` from its real and imaginary parts.
+fn complex<const D: usize, const D2: usize>(re: TestTensor<D>, im: TestTensor<D>) -> Tensor<D2> {
+    Tensor::stack(vec![re, im], D)
+}
+
+fn assert_close<const D: usize>(actual: Tensor<D>, expected: Tensor<D>) {
+    actual
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::absolute(1e-3));
+}
+
+#[test]
+fn fft_complex_exponential() {
+    // x[n] = exp(i * 2π * n / 4) → X = [0, 4, 0, 0]
+    let signal = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]]);
+
+    let spectrum = fft(signal, 0, None, FftNorm::Backward);
+
+    let expected = TensorData::from([[0.0, 0.0], [4.0, 0.0], [0.0, 0.0], [0.0, 0.0]]);
+    spectrum
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::absolute(1e-3));
+}
+
+#[test]
+fn fft_matches_cfft() {
+    let re = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0], [0.5, -1.0, 2.0, 0.0]]);
+    let im = TestTensor::<2>::from([[0.0, 1.0, 0.0, -1.0], [2.0, 0.0, -0.5, 1.0]]);
+
+    let spectrum = fft(complex(re.clone(), im.clone()), -1, None, FftNorm::Backward);
+    let (expected_re, expected_im) = cfft(re, im, 1, None);
+
+    assert_eq!(spectrum.dims(), [2, 4, 2]);
+    assert_close::<3>(spectrum, complex(expected_re, expected_im));
+}
+
+#[test]
+fn fft_with_n_padding() {
+    // [1, 2] zero-padded to 4 → [3, 1-2i, -1, 1+2i]
+    let signal = TestTensor::<2>::from([[1.0, 0.0], [2.0, 0.0]]);
+
+    let spectrum = fft(signal, 0, Some(4), FftNorm::Backward);
+
+    let expected = TensorData::from([[3.0, 0.0], [1.0, -2.0], [-1.0, 0.0], [1.0, 2.0]]);
+    spectrum
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::absolute(1e-3));
+}
+
+#[test]
+fn fft_norm_modes() {
+    let signal = TestTensor::<2>::from([[1.0, 0.0], [1.0, 0.0], [1.0, 0.0], [1.0, 0.0]]);
+
+    let backward = fft(signal.clone(), 0, None, FftNorm::Backward);
+    let ortho = fft(signal.clone(), 0, None, FftNorm::Ortho);
+    let forward = fft(signal, 0, None, FftNorm::Forward);
+
+    let expected = |dc: f32| TensorData::from([[dc, 0.0], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0]]);
+    let tolerance = Tolerance::absolute(1e-3);
+    backward
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected(4.0), tolerance);
+    ortho
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected(2.0), tolerance);
+    forward
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected(1.0), tolerance);
+}
+
+#[test]
+fn fft_ifft_roundtrip_all_norms() {
+    let signal = TestTensor::<3>::from([
+        [[0.26, 0.13], [0.36, 0.24], [0.40, 0.93], [0.12, 0.18]],
+        [[0.03, 0.74], [0.33, 0.70], [0.07, 0.61], [0.32, 0.66]],
+    ]);
+
+    for norm in [FftNorm::Backward, FftNorm::Ortho, FftNorm::Forward] {
+        let spectrum = fft(signal.clone(), 1, None, norm);
+        let reconstructed = ifft(spectrum, 1, None, norm);
+        assert_close(reconstructed, signal.clone());
+    }
+}
+
+#[test]
+fn fft_ortho_preserves_energy() {
+    let signal = TestTensor::<2>::from([
+        [0.26, 0.13],
+        [0.36, 0.24],
+        [0.40, 0.93],
+        [0.12, 0.18],
+        [0.03, 0.74],
+        [0.33, 0.70],
+        [0.07, 0.61],
+        [0.32, 0.66],
+    ]);
+
+    let spectrum = fft(signal.clone(), 0, None, FftNorm::Ortho);
+
+    assert_close(spectrum.square().sum(), signal.square().sum());
+}
+
+#[test]
+fn fft2_known_values() {
+    // fft2([[1, 2], [3, 4]]) = [[10, -2], [-4, 0]]
+    let re = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    let signal = complex::<2, 3>(re.clone(), re.zeros_like());
+
+    let spectrum = fft2(signal, None, FftNorm::Backward);
+
+    let expected_re = TestTensor::<2>::from([[10.0, -2.0], [-4.0, 0.0]]);
+    assert_close::<3>(
+        spectrum,
+        complex(expected_re.clone(), expected_re.zeros_like()),
+    );
+}
+
+#[test]
+fn fft2_ifft2_roundtrip_with_padding() {
+    let re = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.5, -1.0], [2.0, 0.0]]]);
+    let im = TestTensor::<3>::from([[[0.0, 1.0], [0.0, -1.0]], [[2.0, 0.0], [-0.5, 1.0]]]);
+    let signal = complex::<3, 4>(re, im);
+
+    let spectrum = fft2(signal.clone(), Some([4, 2]), FftNorm::Ortho);
+    assert_eq!(spectrum.dims(), [2, 4, 2, 2]);
+
+    let reconstructed = ifft2(spectrum, Some([4, 2]), FftNorm::Ortho);
+    let padded = Tensor::cat(vec![signal.clone(), signal.zeros_like()], 1);
+    assert_close(reconstructed, padded);
+}
+
+#[test]
+fn fftn_matches_successive_ffts() {
+    let re = TestTensor::<3>::from([
+        [[1.0, 2.0, 3.0, 4.0], [0.5, -1.0, 2.0, 0.0]],
+        [[0.0, 1.0, 0.0, -1.0], [2.0, 0.0, -0.5, 1.0]],
+    ]);
+    let signal = complex::<3, 4>(re.clone(), re.flip([2]));
+
+    let spectrum = fftn(signal.clone(), [0, 2], None, FftNorm::Backward);
+    let expected = fft(signal.clone(), 0, None, FftNorm::Backward);
+    let expected = fft(expected, 2, None, FftNorm::Backward);
+
+    assert_close(spectrum, expected);
+
+    let reconstructed = ifftn(
+        fftn(signal.clone(), [0, 1, 2], None, FftNorm::Forward),
+        [0, 1, 2],
+        None,
+        FftNorm::Forward,
+    );
+    assert_close(reconstructed, signal);
+}
+
+#[test]
+fn rfft2_matches_fft2_half_spectrum() {
+    let signal = TestTensor::<2>::from([
+        [0.26, 0.13, 0.36, 0.24],
+        [0.40, 0.93, 0.12, 0.18],
+        [0.03, 0.74, 0.33, 0.70],
+        [0.07, 0.61, 0.32, 0.66],
+    ]);
+
+    let spectrum: Tensor<3> = rfft2(signal.clone(), None, FftNorm::Ortho);
+    let full = fft2(
+        complex::<2, 3>(signal.clone(), signal.zeros_like()),
+        None,
+        FftNorm::Ortho,
+    );
+
+    assert_eq!(spectrum.dims(), [4, 3, 2]);
+    assert_close(spectrum, full.narrow(1, 0, 3));
+}
+
+#[test]
+fn rfft2_irfft2_roundtrip() {
+    let signal = TestTensor::<3>::from([
+        [[0.26, 0.13, 0.36, 0.24], [0.40, 0.93, 0.12, 0.18]],
+        [[0.03, 0.74, 0.33, 0.70], [0.07, 0.61, 0.32, 0.66]],
+    ]);
+
+    for norm in [FftNorm::Backward, FftNorm::Ortho, FftNorm::Forward] {
+        let spectrum: Tensor<4> = rfft2(signal.clone(), None, norm);
+        let reconstructed: Tensor<3> = irfft2(spectrum, None, norm);
+        assert_close(reconstructed, signal.clone());
+    }
+}
+
+#[test]
+#[should_panic]
+fn fft_rejects_non_complex_input() {
+    let signal = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let _ = fft(signal, 0, None, FftNorm::Backward);
+}
+
+#[test]
+#[should_panic]
+fn fft_rejects_non_power_of_two_n() {
+    let signal = TestTensor::<2>::from([[1.0, 0.0], [2.0, 0.0], [3.0, 0.0], [4.0, 0.0]]);
+    let _ = fft(signal, 0, Some(3), FftNorm::Backward);
+}
@@ -12,6 +12,7 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cross;
 mod fft;
+mod fftn;
 mod gather;
 mod interpolate_nearest;
 mod mask_fill;
@@ -0,0 +1,42 @@
+//! FP8 tensors have no fused representation, so every operation touching them must fall back to
+//! the regular backend kernels while the surrounding float operations keep fusing.
+
+use super::*;
+use burn_tensor::{DType, Device, FloatDType, TensorData, Tolerance};
+
+fn supports_fp8() -> bool {
+    Device::default().supports_dtype(DType::F8E4M3)
+}
+
+#[test]
+fn should_fall_back_on_fp8_in_elementwise_chain() {
+    if !supports_fp8() {
+        return;
+    }
+    let tensor = TestTensor::<2>::from([[1.0, -2.0], [0.5, 4.0]]);
+
+    // The float ops before and after the casts are fusable, the casts through FP8 are not.
+    let fp8 = (tensor.clone() * 2.0).cast(FloatDType::F8E4M3);
+    let output = fp8.cast(FloatDType::F32) + 1.0;
+
+    output.into_data().assert_approx_eq::<f32>(
+        &TensorData::from([[3.0, -3.0], [2.0, 9.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_fall_back_on_fp8_before_reduction() {
+    if !supports_fp8() {
+        return;
+    }
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 4.0], [8.0, 16.0, 32.0]]);
+
+    let fp8 = tensor.cast(FloatDType::F8E5M2);
+    let output = fp8.cast(FloatDType::F32).exp().log().sum_dim(1);
+
+    output.into_data().assert_approx_eq::<f32>(
+        &TensorData::from([[7.0], [56.0]]),
+        Tolerance::rel_abs(0.01, 0.01),
+    );
+}
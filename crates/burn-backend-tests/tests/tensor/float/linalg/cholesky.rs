@@ -0,0 +1,112 @@
+use super::*;
+use burn_tensor::{Distribution, Tolerance, linalg::cholesky};
+
+const REL: f32 = 5e-3;
+const ABS: f32 = 1e-3;
+
+/// Builds a batch of well-conditioned symmetric positive-definite matrices.
+fn random_spd<const D: usize>(dims: [usize; D]) -> TestTensor<D> {
+    let device = Default::default();
+    let n = dims[D - 1];
+    let a = TestTensor::<D>::random(dims, Distribution::Default, &device);
+    let identity = TestTensor::<2>::eye(n, &device).mul_scalar(n as f32);
+    a.clone().matmul(a.transpose()) + identity.unsqueeze()
+}
+
+#[test]
+fn test_cholesky_2x2() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[4.0, 2.0], [2.0, 5.0]], &device);
+    let l = cholesky(tensor);
+    let expected = TestTensor::<2>::from_data([[2.0, 0.0], [1.0, 2.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    l.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_cholesky_3x3() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data(
+        [
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ],
+        &device,
+    );
+    let l = cholesky(tensor);
+    let expected = TestTensor::<2>::from_data(
+        [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]],
+        &device,
+    );
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    l.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_cholesky_ignores_upper_part() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[4.0, 100.0], [2.0, 5.0]], &device);
+    let l = cholesky(tensor);
+    let expected = TestTensor::<2>::from_data([[2.0, 0.0], [1.0, 2.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    l.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_cholesky_2d_reconstruction() {
+    let tensor = random_spd([8, 8]);
+    let l = cholesky(tensor.clone());
+    let llt = l.clone().matmul(l.transpose());
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    llt.into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+}
+
+#[test]
+fn test_cholesky_3d_reconstruction() {
+    let tensor = random_spd([3, 6, 6]);
+    let l = cholesky(tensor.clone());
+    let llt = l.clone().matmul(l.clone().transpose());
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    llt.into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+
+    // The factor must be lower triangular.
+    l.clone()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&l.tril(0).into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_cholesky_4d_reconstruction() {
+    let tensor = random_spd([2, 3, 5, 5]);
+    let l = cholesky(tensor.clone());
+    let llt = l.clone().matmul(l.transpose());
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    llt.into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+}
+
+// ---------------------------------------------------------------------
+// Tensor Check Panics
+// ---------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_cholesky_panic_rank_less_than_2() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let _ = cholesky(tensor);
+}
+
+#[test]
+#[should_panic]
+fn test_cholesky_panic_not_square() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let _ = cholesky(tensor);
+}
@@ -0,0 +1,139 @@
+use super::*;
+use burn_tensor::{Distribution, Tolerance, linalg::eigh};
+
+const REL: f32 = 5e-3;
+const ABS: f32 = 1e-3;
+
+/// Builds a batch of random symmetric matrices.
+fn random_symmetric<const D: usize>(dims: [usize; D]) -> TestTensor<D> {
+    let device = Default::default();
+    let a = TestTensor::<D>::random(dims, Distribution::Default, &device);
+    a.clone() + a.transpose()
+}
+
+/// Checks that `V diag(L) V^T` reconstructs the input and that `V` is orthonormal.
+fn assert_eigh_reconstruction<const D: usize, const D1: usize>(tensor: TestTensor<D>) {
+    let device = Default::default();
+    let n = tensor.dims()[D - 1];
+    let (eigenvalues, eigenvectors) = eigh::<D, D1>(tensor.clone());
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    let scaled = eigenvectors.clone() * eigenvalues.clone().unsqueeze_dim::<D>(D - 2);
+    let reconstructed = scaled.matmul(eigenvectors.clone().transpose());
+    reconstructed
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+
+    let identity = TestTensor::<2>::eye(n, &device)
+        .unsqueeze::<D>()
+        .expand(eigenvectors.dims());
+    eigenvectors
+        .clone()
+        .transpose()
+        .matmul(eigenvectors)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&identity.into_data(), tolerance);
+
+    // The eigenvalues must be sorted in ascending order.
+    let sorted = eigenvalues.clone().sort(D1 - 1);
+    eigenvalues
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&sorted.into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_eigh_2x2() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[2.0, 1.0], [1.0, 2.0]], &device);
+    let (eigenvalues, eigenvectors) = eigh::<2, 1>(tensor);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    let expected = TestTensor::<1>::from_data([1.0, 3.0], &device);
+    eigenvalues
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+
+    // The eigenvectors are only defined up to their sign.
+    let expected = TestTensor::<2>::from_data(
+        [
+            [
+                core::f32::consts::FRAC_1_SQRT_2,
+                core::f32::consts::FRAC_1_SQRT_2,
+            ],
+            [
+                core::f32::consts::FRAC_1_SQRT_2,
+                core::f32::consts::FRAC_1_SQRT_2,
+            ],
+        ],
+        &device,
+    );
+    eigenvectors
+        .abs()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_eigh_diagonal() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data(
+        [[3.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 2.0]],
+        &device,
+    );
+    let (eigenvalues, _eigenvectors) = eigh::<2, 1>(tensor);
+    let expected = TestTensor::<1>::from_data([-1.0, 2.0, 3.0], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    eigenvalues
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_eigh_repeated_eigenvalues() {
+    let device = Default::default();
+    let tensor =
+        TestTensor::<2>::from_data([[2.0, 0.0, 0.0], [0.0, 3.0, 1.0], [0.0, 1.0, 3.0]], &device);
+    let (eigenvalues, _eigenvectors) = eigh::<2, 1>(tensor.clone());
+    let expected = TestTensor::<1>::from_data([2.0, 2.0, 4.0], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    eigenvalues
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+
+    assert_eigh_reconstruction::<2, 1>(tensor);
+}
+
+#[test]
+fn test_eigh_2d_reconstruction() {
+    assert_eigh_reconstruction::<2, 1>(random_symmetric([7, 7]));
+}
+
+#[test]
+fn test_eigh_3d_reconstruction() {
+    assert_eigh_reconstruction::<3, 2>(random_symmetric([3, 6, 6]));
+}
+
+#[test]
+fn test_eigh_4d_reconstruction() {
+    assert_eigh_reconstruction::<4, 3>(random_symmetric([2, 3, 5, 5]));
+}
+
+// ---------------------------------------------------------------------
+// Tensor Check Panics
+// ---------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_eigh_panic_wrong_generic_param() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 1.0]], &device);
+    let _ = eigh::<2, 2>(tensor);
+}
+
+#[test]
+#[should_panic]
+fn test_eigh_panic_not_square() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let _ = eigh::<2, 1>(tensor);
+}
@@ -1,11 +1,17 @@
 use super::*;
 
+pub(crate) mod cholesky;
 pub(crate) mod cosine_similarity;
 pub(crate) mod det;
 pub(crate) mod diag;
+pub(crate) mod eigh;
 pub(crate) mod lu;
 pub(crate) mod matvec;
 pub(crate) mod outer;
+pub(crate) mod pinv;
 pub(crate) mod qr;
+pub(crate) mod solve;
+pub(crate) mod solve_triangular;
+pub(crate) mod svd;
 pub(crate) mod trace;
 pub(crate) mod vector_norm;
@@ -0,0 +1,91 @@
+use super::*;
+use burn_tensor::{Distribution, Tolerance, linalg::pinv};
+
+const REL: f32 = 5e-3;
+const ABS: f32 = 1e-3;
+
+#[test]
+fn test_pinv_diagonal() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]], &device);
+    let result = pinv(tensor, None);
+    let expected = TestTensor::<2>::from_data([[1.0, 0.0, 0.0], [0.0, 0.5, 0.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    result
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_pinv_rank_deficient() {
+    let device = Default::default();
+    // A = x y^T with x = [1, 2] and y = [1, 1], so pinv(A) = y x^T / (|x|^2 |y|^2).
+    let tensor = TestTensor::<2>::from_data([[1.0, 1.0], [2.0, 2.0]], &device);
+    let result = pinv(tensor, None);
+    let expected = TestTensor::<2>::from_data([[0.1, 0.2], [0.1, 0.2]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    result
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_pinv_rtol_discards_small_singular_values() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[4.0, 0.0], [0.0, 0.01]], &device);
+    let result = pinv(tensor, Some(0.1));
+    let expected = TestTensor::<2>::from_data([[0.25, 0.0], [0.0, 0.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    result
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_pinv_matches_inverse() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device);
+    let result = pinv(tensor, None);
+    let expected = TestTensor::<2>::from_data([[0.6, -0.7], [-0.2, 0.4]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    result
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_pinv_3d_penrose_conditions() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::random([2, 5, 3], Distribution::Default, &device);
+    let result = pinv(tensor.clone(), None);
+    assert_eq!(result.dims(), [2, 3, 5]);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    // A A+ A = A
+    tensor
+        .clone()
+        .matmul(result.clone())
+        .matmul(tensor.clone())
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.clone().into_data(), tolerance);
+
+    // A+ A A+ = A+
+    result
+        .clone()
+        .matmul(tensor)
+        .matmul(result.clone())
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&result.into_data(), tolerance);
+}
+
+// ---------------------------------------------------------------------
+// Tensor Check Panics
+// ---------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_pinv_panic_rank_less_than_2() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let _ = pinv(tensor, None);
+}
@@ -0,0 +1,87 @@
+use super::*;
+use burn_tensor::{
+    Distribution, Tolerance,
+    linalg::{inverse, solve},
+};
+
+const REL: f32 = 5e-3;
+const ABS: f32 = 1e-3;
+
+#[test]
+fn test_solve_2x2() {
+    let device = Default::default();
+    let a = TestTensor::<2>::from_data([[3.0, 1.0], [1.0, 2.0]], &device);
+    let b = TestTensor::<2>::from_data([[9.0], [8.0]], &device);
+    let x = solve(a, b);
+    let expected = TestTensor::<2>::from_data([[2.0], [3.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    x.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_solve_requires_pivoting() {
+    let device = Default::default();
+    // The leading zero forces a row swap.
+    let a =
+        TestTensor::<2>::from_data([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]], &device);
+    let b = TestTensor::<2>::from_data([[5.0, 1.0], [3.0, 2.0], [11.0, 3.0]], &device);
+    let x = solve(a.clone(), b.clone());
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    a.matmul(x)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&b.into_data(), tolerance);
+}
+
+#[test]
+fn test_solve_3d() {
+    let device = Default::default();
+    let a = TestTensor::<3>::random([4, 6, 6], Distribution::Default, &device)
+        + TestTensor::<2>::eye(6, &device).mul_scalar(3.0).unsqueeze();
+    let b = TestTensor::<3>::random([4, 6, 2], Distribution::Default, &device);
+    let x = solve(a.clone(), b.clone());
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    a.matmul(x)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&b.into_data(), tolerance);
+}
+
+#[test]
+fn test_inverse_2x2() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device);
+    let output = inverse(tensor);
+    let expected = TestTensor::<2>::from_data([[0.6, -0.7], [-0.2, 0.4]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_inverse_4d() {
+    let device = Default::default();
+    let tensor = TestTensor::<4>::random([2, 3, 5, 5], Distribution::Default, &device)
+        + TestTensor::<2>::eye(5, &device).mul_scalar(3.0).unsqueeze();
+    let output = inverse(tensor.clone());
+    let identity = TestTensor::<2>::eye(5, &device)
+        .unsqueeze::<4>()
+        .expand([2, 3, 5, 5]);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    tensor
+        .matmul(output)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&identity.into_data(), tolerance);
+}
+
+// ---------------------------------------------------------------------
+// Tensor Check Panics
+// ---------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_inverse_panic_not_square() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let _ = inverse(tensor);
+}
@@ -0,0 +1,87 @@
+use super::*;
+use burn_tensor::{Distribution, Tolerance, linalg::solve_triangular};
+
+const REL: f32 = 5e-3;
+const ABS: f32 = 1e-3;
+
+/// Builds a batch of well-conditioned triangular matrices.
+fn random_triangular<const D: usize>(dims: [usize; D], upper: bool) -> TestTensor<D> {
+    let device = Default::default();
+    let n = dims[D - 1];
+    let a = TestTensor::<D>::random(dims, Distribution::Uniform(-1.0, 1.0), &device);
+    let identity = TestTensor::<2>::eye(n, &device).mul_scalar(n as f32);
+    let a = a + identity.unsqueeze();
+    if upper { a.triu(0) } else { a.tril(0) }
+}
+
+#[test]
+fn test_solve_triangular_lower_left() {
+    let device = Default::default();
+    let a = TestTensor::<2>::from_data([[2.0, 0.0], [1.0, 4.0]], &device);
+    let b = TestTensor::<2>::from_data([[2.0], [9.0]], &device);
+    let x = solve_triangular(a, b, false, true, false);
+    let expected = TestTensor::<2>::from_data([[1.0], [2.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    x.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_solve_triangular_upper_left() {
+    let device = Default::default();
+    let a = TestTensor::<2>::from_data([[2.0, 1.0], [0.0, 4.0]], &device);
+    let b = TestTensor::<2>::from_data([[4.0], [8.0]], &device);
+    let x = solve_triangular(a, b, true, true, false);
+    let expected = TestTensor::<2>::from_data([[1.0], [2.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    x.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_solve_triangular_unit_diagonal_ignores_diagonal() {
+    let device = Default::default();
+    let a = TestTensor::<2>::from_data([[5.0, 0.0], [3.0, 7.0]], &device);
+    let b = TestTensor::<2>::from_data([[1.0], [5.0]], &device);
+    let x = solve_triangular(a, b, false, true, true);
+    let expected = TestTensor::<2>::from_data([[1.0], [2.0]], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    x.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_solve_triangular_3d_all_variants() {
+    let device = Default::default();
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    for upper in [false, true] {
+        let a = random_triangular([3, 6, 6], upper);
+
+        let b = TestTensor::<3>::random([3, 6, 4], Distribution::Default, &device);
+        let x = solve_triangular(a.clone(), b.clone(), upper, true, false);
+        a.clone()
+            .matmul(x)
+            .into_data()
+            .assert_approx_eq::<FloatElem>(&b.into_data(), tolerance);
+
+        let b = TestTensor::<3>::random([3, 4, 6], Distribution::Default, &device);
+        let x = solve_triangular(a.clone(), b.clone(), upper, false, false);
+        x.matmul(a)
+            .into_data()
+            .assert_approx_eq::<FloatElem>(&b.into_data(), tolerance);
+    }
+}
+
+// ---------------------------------------------------------------------
+// Tensor Check Panics
+// ---------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_solve_triangular_panic_incompatible_rhs() {
+    let device = Default::default();
+    let a = TestTensor::<2>::from_data([[1.0, 0.0], [1.0, 1.0]], &device);
+    let b = TestTensor::<2>::from_data([[1.0], [2.0], [3.0]], &device);
+    let _ = solve_triangular(a, b, false, true, false);
+}
@@ -0,0 +1,164 @@
+use super::*;
+use burn_tensor::{Distribution, Tolerance, linalg::svd};
+
+const REL: f32 = 5e-3;
+const ABS: f32 = 1e-3;
+
+fn assert_orthonormal_columns<const D: usize>(
+    tensor: TestTensor<D>,
+    tolerance: Tolerance<FloatElem>,
+) {
+    let device = Default::default();
+    let k = tensor.dims()[D - 1];
+    let mut dims = tensor.dims();
+    dims[D - 2] = k;
+    let identity = TestTensor::<2>::eye(k, &device)
+        .unsqueeze::<D>()
+        .expand(dims);
+    tensor
+        .clone()
+        .transpose()
+        .matmul(tensor)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&identity.into_data(), tolerance);
+}
+
+/// Checks that `U diag(S) Vh` reconstructs the input and that the factors are orthonormal.
+fn assert_svd_reconstruction<const D: usize, const D1: usize>(dims: [usize; D]) {
+    let device = Default::default();
+    let tensor = TestTensor::<D>::random(dims, Distribution::Default, &device);
+    let (u, s, vh) = svd::<D, D1>(tensor.clone(), false);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    let k = dims[D - 2].min(dims[D - 1]);
+    assert_eq!(u.dims()[D - 1], k);
+    assert_eq!(s.dims()[D1 - 1], k);
+    assert_eq!(vh.dims()[D - 2], k);
+
+    let reconstructed = (u.clone() * s.clone().unsqueeze_dim::<D>(D - 2)).matmul(vh.clone());
+    reconstructed
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+
+    assert_orthonormal_columns(u, tolerance);
+    assert_orthonormal_columns(vh.transpose(), tolerance);
+
+    // The singular values must be non-negative and sorted in descending order.
+    let sorted = s.clone().sort_descending(D1 - 1);
+    s.clone()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&sorted.into_data(), Tolerance::default());
+    s.clone()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&s.abs().into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_svd_diagonal() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[3.0, 0.0], [0.0, -2.0], [0.0, 0.0]], &device);
+    let (u, s, vh) = svd::<2, 1>(tensor, false);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    let expected = TestTensor::<1>::from_data([3.0, 2.0], &device);
+    s.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+
+    // The singular vectors are only defined up to their sign.
+    let expected = TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]], &device);
+    u.abs()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+    let expected = TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 1.0]], &device);
+    vh.abs()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_svd_singular_values_2x2() {
+    let device = Default::default();
+    // A^T A = [[25, 15], [15, 25]], with eigenvalues 40 and 10.
+    let tensor = TestTensor::<2>::from_data([[4.0, 0.0], [3.0, -5.0]], &device);
+    let (_u, s, _vh) = svd::<2, 1>(tensor, false);
+    let expected = TestTensor::<1>::from_data([40f32.sqrt(), 10f32.sqrt()], &device);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+    s.into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn test_svd_rank_deficient() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]], &device);
+    let (u, s, vh) = svd::<2, 1>(tensor.clone(), false);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    let expected = TestTensor::<1>::from_data([70f32.sqrt(), 0.0], &device);
+    s.clone()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+
+    let reconstructed = (u * s.unsqueeze_dim::<2>(0)).matmul(vh);
+    reconstructed
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+}
+
+#[test]
+fn test_svd_full_matrices() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::random([5, 3], Distribution::Default, &device);
+    let (u, s, vh) = svd::<2, 1>(tensor.clone(), true);
+    let tolerance = Tolerance::rel_abs(REL, ABS).set_half_precision_absolute(5e-2);
+
+    assert_eq!(u.dims(), [5, 5]);
+    assert_eq!(s.dims(), [3]);
+    assert_eq!(vh.dims(), [3, 3]);
+    assert_orthonormal_columns(u.clone(), tolerance);
+
+    let reconstructed = (u.slice_dim(1, 0..3) * s.unsqueeze_dim::<2>(0)).matmul(vh);
+    reconstructed
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance);
+}
+
+#[test]
+fn test_svd_2d_tall_reconstruction() {
+    assert_svd_reconstruction::<2, 1>([7, 4]);
+}
+
+#[test]
+fn test_svd_2d_wide_reconstruction() {
+    assert_svd_reconstruction::<2, 1>([3, 6]);
+}
+
+#[test]
+fn test_svd_3d_reconstruction() {
+    assert_svd_reconstruction::<3, 2>([3, 5, 5]);
+}
+
+#[test]
+fn test_svd_4d_reconstruction() {
+    assert_svd_reconstruction::<4, 3>([2, 2, 4, 6]);
+}
+
+// ---------------------------------------------------------------------
+// Tensor Check Panics
+// ---------------------------------------------------------------------
+
+#[test]
+#[should_panic]
+fn test_svd_panic_wrong_generic_param() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 0.0], [0.0, 1.0]], &device);
+    let _ = svd::<2, 2>(tensor, false);
+}
+
+#[test]
+#[should_panic]
+fn test_svd_panic_rank_less_than_2() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let _ = svd::<1, 0>(tensor, false);
+}
@@ -0,0 +1,82 @@
+use super::*;
+use burn_tensor::module::{
+    adaptive_max_pool1d, adaptive_max_pool1d_with_indices, adaptive_max_pool2d_with_indices,
+    adaptive_max_pool3d, adaptive_max_pool3d_with_indices,
+};
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+#[test]
+fn test_adaptive_max_pool1d_overlapping_windows() {
+    let x = TestTensor::<3>::from([[[1.0, 5.0, 2.0, 4.0, 3.0]]]);
+
+    // The windows are [0, 2), [1, 4) and [3, 5).
+    let (output, indices) = adaptive_max_pool1d_with_indices(x.clone(), 3);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[5.0, 5.0, 4.0]]]),
+        Tolerance::default(),
+    );
+    indices
+        .into_data()
+        .assert_eq(&TensorData::from([[[1, 1, 3]]]), false);
+
+    let output = adaptive_max_pool1d(x, 5);
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[1.0, 5.0, 2.0, 4.0, 3.0]]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_adaptive_max_pool2d_with_indices() {
+    let shape = Shape::new([1, 1, 4, 4]);
+    let x = TestTensor::<4>::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<4, _>(shape)
+            .into_data(),
+    );
+
+    let (output, indices) = adaptive_max_pool2d_with_indices(x, [2, 2]);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[[5.0, 7.0], [13.0, 15.0]]]]),
+        Tolerance::default(),
+    );
+    indices
+        .into_data()
+        .assert_eq(&TensorData::from([[[[5, 7], [13, 15]]]]), false);
+}
+
+#[test]
+fn test_adaptive_max_pool3d_with_indices() {
+    let shape = Shape::new([1, 2, 3, 3, 3]);
+    let x = TestTensor::<5>::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<5, _>(shape)
+            .into_data(),
+    );
+
+    // The windows are [0, 2) and [1, 3) along each dimension.
+    let (output, indices) = adaptive_max_pool3d_with_indices(x.clone(), [2, 2, 2]);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[
+            [[[13.0, 14.0], [16.0, 17.0]], [[22.0, 23.0], [25.0, 26.0]]],
+            [[[40.0, 41.0], [43.0, 44.0]], [[49.0, 50.0], [52.0, 53.0]]],
+        ]]),
+        Tolerance::default(),
+    );
+    indices.into_data().assert_eq(
+        &TensorData::from([[
+            [[[13, 14], [16, 17]], [[22, 23], [25, 26]]],
+            [[[13, 14], [16, 17]], [[22, 23], [25, 26]]],
+        ]]),
+        false,
+    );
+
+    let output = adaptive_max_pool3d(x, [1, 1, 1]);
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[[[26.0]]], [[[53.0]]]]]),
+        Tolerance::default(),
+    );
+}
@@ -0,0 +1,76 @@
+use super::*;
+use burn_tensor::module::{interpolate, interpolate_antialiased, interpolate3d_antialiased};
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+fn options(mode: InterpolateMode) -> InterpolateOptions {
+    InterpolateOptions::new(mode).with_align_corners(false)
+}
+
+#[test]
+fn test_antialias_bilinear_downsample() {
+    let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0, 3.0]]]]);
+
+    let output = interpolate_antialiased(x, [1, 2], options(InterpolateMode::Bilinear));
+
+    // The triangle filter spans 3 inputs with the weights [3, 3, 1] / 7.
+    let expected = TensorData::from([[[[5.0 / 7.0, 16.0 / 7.0]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_antialias_bicubic_downsample() {
+    let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0, 3.0]]]]);
+
+    let output = interpolate_antialiased(x, [1, 2], options(InterpolateMode::Bicubic));
+
+    let expected = TensorData::from([[[[0.586777, 2.413223]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_antialias_upsample_matches_bilinear() {
+    let shape = Shape::new([1, 2, 2, 3]);
+    let x = TestTensor::<4>::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<4, _>(shape)
+            .into_data(),
+    );
+
+    // Without downsampling, the filter isn't widened.
+    let output = interpolate_antialiased(x.clone(), [4, 5], options(InterpolateMode::Bilinear));
+    let expected = interpolate(x, [4, 5], options(InterpolateMode::Bilinear));
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
+
+#[test]
+fn test_antialias_interpolate3d() {
+    let shape = Shape::new([1, 1, 2, 1, 4]);
+    let x = TestTensor::<5>::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<5, _>(shape)
+            .into_data(),
+    );
+
+    let output = interpolate3d_antialiased(x, [1, 1, 2], options(InterpolateMode::Bilinear));
+
+    let expected = TensorData::from([[[[[2.0 + 5.0 / 7.0, 2.0 + 16.0 / 7.0]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+#[should_panic]
+fn test_antialias_nearest_panics() {
+    let x = TestTensor::<4>::from([[[[0.0, 1.0, 2.0, 3.0]]]]);
+
+    let _output = interpolate_antialiased(x, [1, 2], options(InterpolateMode::Nearest));
+}
@@ -4,6 +4,7 @@ use burn_tensor::TensorData;
 use burn_tensor::Tolerance;
 use burn_tensor::module::attention;
 use burn_tensor::module::attention_fallback;
+use burn_tensor::module::paged_attention;
 use burn_tensor::ops::AttentionModuleOptions;
 use num_traits::cast::cast;
 
@@ -475,6 +476,7 @@ fn test_attention_all_options() {
         scale: Some(0.05),
         softcap: Some(30.0),
         is_causal: true,
+        ..Default::default()
     };
 
     let output = attention(
@@ -640,3 +642,257 @@ fn test_attention_bool_mask_broadcast_batch_and_heads() {
         Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1),
     );
 }
+
+/// Builds the `[1, 1, seq_q, seq_kv]` relative positions `row - col` of the queries and keys,
+/// with the last query aligned to the last key.
+fn relative_positions(seq_q: usize, seq_kv: usize) -> TestTensorInt<4> {
+    let device = Default::default();
+    let offset = seq_kv as i64 - seq_q as i64;
+    let rows = TestTensorInt::<1>::arange(offset..offset + seq_q as i64, &device)
+        .reshape([seq_q, 1])
+        .expand([seq_q, seq_kv]);
+    let cols = TestTensorInt::<1>::arange(0..seq_kv as i64, &device)
+        .reshape([1, seq_kv])
+        .expand([seq_q, seq_kv]);
+
+    (rows - cols).reshape([1, 1, seq_q, seq_kv])
+}
+
+#[test]
+fn test_attention_sliding_window() {
+    let [num_batches, num_heads, seq_q, seq_kv, head_dim] = [2, 2, 8, 24, 32];
+    let window = 5;
+
+    let query = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_q, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let key = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_kv, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let value = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_kv, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+
+    let options = AttentionModuleOptions {
+        is_causal: true,
+        sliding_window: Some(window),
+        ..Default::default()
+    };
+
+    let output = attention(
+        query.clone(),
+        key.clone(),
+        value.clone(),
+        None,
+        None,
+        options,
+    );
+
+    // Mask the future keys and the keys `window` or more positions behind each query.
+    let positions = relative_positions(seq_q, seq_kv);
+    let mask = positions
+        .clone()
+        .lower_elem(0)
+        .bool_or(positions.greater_equal_elem(window as i64));
+    let expected = attention_fallback(query, key, value, Some(mask), None, Default::default());
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &expected.into_data(),
+        Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1),
+    );
+}
+
+#[test]
+fn test_attention_sliding_window_many_queries() {
+    // More query rows than the 65535 cubes of a single cube dimension.
+    let [num_batches, num_heads, seq_q, seq_kv, head_dim] = [1, 1, 70_000, 4, 2];
+    let window = 2;
+
+    let query = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_q, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let key = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_kv, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let value = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_kv, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+
+    let options = AttentionModuleOptions {
+        sliding_window: Some(window),
+        ..Default::default()
+    };
+
+    let output = attention(
+        query.clone(),
+        key.clone(),
+        value.clone(),
+        None,
+        None,
+        options,
+    );
+
+    let mask = relative_positions(seq_q, seq_kv).greater_equal_elem(window as i64);
+    let expected = attention_fallback(query, key, value, Some(mask), None, Default::default());
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &expected.into_data(),
+        Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1),
+    );
+}
+
+#[test]
+fn test_attention_alibi() {
+    // 6 heads: the slopes of 4 heads, then every other slope of 8 heads.
+    let [num_batches, num_heads, seq_len, head_dim] = [2, 6, 16, 32];
+    let slopes = [0.25, 0.0625, 0.015625, 0.00390625, 0.5, 0.125];
+
+    let query = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_len, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let key = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_len, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+    let value = TestTensor::<4>::random(
+        [num_batches, num_heads, seq_len, head_dim],
+        Distribution::Uniform(-1., 1.),
+        &Default::default(),
+    );
+
+    let options = AttentionModuleOptions {
+        is_causal: true,
+        alibi_max_bias: Some(8.0),
+        ..Default::default()
+    };
+
+    let output = attention(
+        query.clone(),
+        key.clone(),
+        value.clone(),
+        None,
+        None,
+        options,
+    );
+
+    let distance = relative_positions(seq_len, seq_len).abs().float();
+    let slopes =
+        TestTensor::<1>::from_floats(slopes, &Default::default()).reshape([1, num_heads, 1, 1]);
+    let bias = -(distance * slopes);
+    let expected = attention_fallback(
+        query,
+        key,
+        value,
+        None,
+        Some(bias),
+        AttentionModuleOptions {
+            is_causal: true,
+            ..Default::default()
+        },
+    );
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &expected.into_data(),
+        Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1),
+    );
+}
+
+#[test]
+fn test_paged_attention_should_match_attention_per_sequence() {
+    assert_paged_attention_matches_attention(Default::default());
+}
+
+#[test]
+fn test_paged_attention_alibi_sliding_window_should_match_attention_per_sequence() {
+    assert_paged_attention_matches_attention(AttentionModuleOptions {
+        sliding_window: Some(2),
+        alibi_max_bias: Some(8.0),
+        ..Default::default()
+    });
+}
+
+fn assert_paged_attention_matches_attention(options: AttentionModuleOptions) {
+    let [num_heads, num_kv_heads, block_size, head_dim, seq_q] = [4, 2, 2, 8, 2];
+    let device = Default::default();
+    let key_blocks = TestTensor::<4>::random(
+        [6, num_kv_heads, block_size, head_dim],
+        Distribution::Uniform(0., 1.),
+        &device,
+    );
+    let value_blocks = TestTensor::<4>::random(
+        [6, num_kv_heads, block_size, head_dim],
+        Distribution::Uniform(0., 1.),
+        &device,
+    );
+    let query = TestTensor::<4>::random(
+        [2, num_heads, seq_q, head_dim],
+        Distribution::Uniform(0., 1.),
+        &device,
+    );
+    // The first sequence has 5 tokens in the blocks 4, 1 and 3, the second one 3 tokens in the
+    // blocks 0 and 5, padded with the block 0.
+    let tables = [[4i64, 1, 3], [0, 5, 0]];
+    let lengths = [5i64, 3];
+
+    let output = paged_attention(
+        query.clone(),
+        key_blocks.clone(),
+        value_blocks.clone(),
+        TestTensorInt::<2>::from_data(tables, &device),
+        TestTensorInt::<1>::from_data(lengths, &device),
+        options,
+    );
+
+    for (i, (table, len)) in tables.iter().zip(lengths).enumerate() {
+        let len = len as usize;
+        // The keys and values of the sequence, with each head repeated for its group of queries.
+        let gather = |blocks: TestTensor<4>| {
+            let blocks = table
+                .iter()
+                .map(|&block| blocks.clone().slice(block as usize..block as usize + 1))
+                .collect();
+            TestTensor::cat(blocks, 2)
+                .slice([0..1, 0..num_kv_heads, 0..len, 0..head_dim])
+                .reshape([1, num_kv_heads, 1, len, head_dim])
+                .expand([1, num_kv_heads, num_heads / num_kv_heads, len, head_dim])
+                .reshape([1, num_heads, len, head_dim])
+        };
+        let options = AttentionModuleOptions {
+            is_causal: true,
+            ..options
+        };
+
+        let expected = attention_fallback(
+            query.clone().slice(i..i + 1),
+            gather(key_blocks.clone()),
+            gather(value_blocks.clone()),
+            None,
+            None,
+            options,
+        );
+
+        output
+            .clone()
+            .slice(i..i + 1)
+            .into_data()
+            .assert_approx_eq::<FloatElem>(
+                &expected.into_data(),
+                Tolerance::rel_abs(1e-2, 1e-3).set_half_precision_relative(1e-1),
+            );
+    }
+}
@@ -0,0 +1,55 @@
+use super::*;
+use burn_tensor::module::avg_pool3d;
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+fn arange_5d(shape: [usize; 5]) -> TestTensor<5> {
+    let shape = Shape::new(shape);
+    TestTensor::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<5, _>(shape)
+            .into_data(),
+    )
+}
+
+#[test]
+fn test_avg_pool3d_simple() {
+    let x = arange_5d([1, 1, 3, 3, 3]);
+
+    let output = avg_pool3d(x, [2, 2, 2], [1, 1, 1], [0, 0, 0], true, false);
+
+    let expected = TensorData::from([[[[[6.5, 7.5], [9.5, 10.5]], [[15.5, 16.5], [18.5, 19.5]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_avg_pool3d_count_include_pad() {
+    let x = arange_5d([1, 1, 2, 2, 2]);
+
+    // Every window covers the whole input, 8 values summing to 28 out of 27 positions.
+    let output = avg_pool3d(x.clone(), [3, 3, 3], [1, 1, 1], [1, 1, 1], true, false);
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::full([1, 1, 2, 2, 2], 28.0f32 / 27.0),
+        Tolerance::default(),
+    );
+
+    let output = avg_pool3d(x, [3, 3, 3], [1, 1, 1], [1, 1, 1], false, false);
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::full([1, 1, 2, 2, 2], 3.5f32),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_avg_pool3d_ceil_mode() {
+    let x = arange_5d([1, 1, 1, 3, 3]);
+
+    let output = avg_pool3d(x, [1, 2, 2], [1, 2, 2], [0, 0, 0], false, true);
+
+    // The last windows are clipped to the input.
+    let expected = TensorData::from([[[[[2.0, 3.5], [6.5, 8.0]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
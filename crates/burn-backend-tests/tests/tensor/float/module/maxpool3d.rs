@@ -0,0 +1,69 @@
+use super::*;
+use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+fn arange_5d(shape: [usize; 5]) -> TestTensor<5> {
+    let shape = Shape::new(shape);
+    TestTensor::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<5, _>(shape)
+            .into_data(),
+    )
+}
+
+#[test]
+fn test_max_pool3d_simple() {
+    let x = arange_5d([1, 1, 3, 3, 3]);
+
+    let output = max_pool3d(x, [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1], false);
+
+    // The maximum of each window is its last element.
+    let expected =
+        TensorData::from([[[[[13.0, 14.0], [16.0, 17.0]], [[22.0, 23.0], [25.0, 26.0]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_max_pool3d_with_indices_ceil_mode() {
+    let x = arange_5d([1, 1, 3, 3, 3]).neg();
+
+    let (output, indices) =
+        max_pool3d_with_indices(x, [2, 2, 2], [2, 2, 2], [0, 0, 0], [1, 1, 1], true);
+
+    // The last windows only cover the last slice of each dimension, and the maximum of each
+    // window is its first element.
+    let expected = TensorData::from([[[
+        [[0.0, -2.0], [-6.0, -8.0]],
+        [[-18.0, -20.0], [-24.0, -26.0]],
+    ]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+    indices.into_data().assert_eq(
+        &TensorData::from([[[[[0, 2], [6, 8]], [[18, 20], [24, 26]]]]]),
+        false,
+    );
+}
+
+#[test]
+fn test_max_pool3d_with_indices_padding_dilation() {
+    let x = arange_5d([1, 2, 2, 3, 3]);
+
+    let (output, indices) =
+        max_pool3d_with_indices(x, [2, 2, 2], [1, 1, 1], [1, 0, 0], [1, 2, 2], false);
+
+    // Each window spans the corners of the [height, width] plane and one or two depth slices.
+    let expected = TensorData::from([[
+        [[[8.0]], [[17.0]], [[17.0]]],
+        [[[26.0]], [[35.0]], [[35.0]]],
+    ]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+    indices.into_data().assert_eq(
+        &TensorData::from([[[[[8]], [[17]], [[17]]], [[[8]], [[17]], [[17]]]]]),
+        false,
+    );
+}
@@ -3,9 +3,12 @@ use super::*;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod adaptive_avgpool3d;
+mod adaptive_maxpool;
+mod antialias_interpolate;
 mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
 mod conv1d;
@@ -22,5 +25,7 @@ mod lanczos3_interpolate;
 mod linear;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod nearest_interpolate;
+mod trilinear_interpolate;
 mod unfold4d;
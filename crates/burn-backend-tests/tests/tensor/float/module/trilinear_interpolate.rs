@@ -0,0 +1,67 @@
+use super::*;
+use burn_tensor::module::interpolate3d;
+use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+use burn_tensor::{Shape, TensorData, Tolerance};
+
+fn arange_5d(shape: [usize; 5]) -> TestTensor<5> {
+    let shape = Shape::new(shape);
+    TestTensor::from(
+        TestTensorInt::arange(0..shape.num_elements() as i64, &Default::default())
+            .reshape::<5, _>(shape)
+            .into_data(),
+    )
+}
+
+#[test]
+fn test_trilinear_interpolate_align_corners() {
+    let x = arange_5d([1, 1, 2, 2, 2]);
+
+    let output = interpolate3d(
+        x,
+        [3, 3, 3],
+        InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(true),
+    );
+
+    // The input is linear in the coordinates, so is the output.
+    let expected = TensorData::from([[[
+        [[0.0, 0.5, 1.0], [1.0, 1.5, 2.0], [2.0, 2.5, 3.0]],
+        [[2.0, 2.5, 3.0], [3.0, 3.5, 4.0], [4.0, 4.5, 5.0]],
+        [[4.0, 4.5, 5.0], [5.0, 5.5, 6.0], [6.0, 6.5, 7.0]],
+    ]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_trilinear_interpolate_half_pixel() {
+    let x = arange_5d([1, 1, 2, 1, 2]);
+
+    let output = interpolate3d(
+        x,
+        [4, 1, 2],
+        InterpolateOptions::new(InterpolateMode::Bilinear).with_align_corners(false),
+    );
+
+    // The depth coordinates are clamped to [0, 1] at -0.25, 0.25, 0.75 and 1.25.
+    let expected = TensorData::from([[[[[0.0, 1.0]], [[0.5, 1.5]], [[1.5, 2.5]], [[2.0, 3.0]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn test_nearest_interpolate3d() {
+    let x = arange_5d([1, 1, 2, 1, 2]);
+
+    let output = interpolate3d(
+        x,
+        [4, 1, 1],
+        InterpolateOptions::new(InterpolateMode::Nearest),
+    );
+
+    let expected = TensorData::from([[[[[0.0]], [[0.0]], [[2.0]], [[2.0]]]]]);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
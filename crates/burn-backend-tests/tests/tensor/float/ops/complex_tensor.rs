@@ -0,0 +1,146 @@
+use super::*;
+use burn_tensor::complex::ComplexTensor;
+use burn_tensor::signal::FftNorm;
+use burn_tensor::{TensorData, Tolerance};
+
+fn complex<const D: usize, T: Into<TensorData>>(re: T, im: T) -> ComplexTensor<D> {
+    ComplexTensor::new(TestTensor::from(re), TestTensor::from(im))
+}
+
+fn assert_complex<const D: usize, T: Into<TensorData>>(tensor: ComplexTensor<D>, re: T, im: T) {
+    let (actual_re, actual_im) = tensor.into_parts();
+    let tolerance = Tolerance::default().set_half_precision_relative(1e-2);
+    actual_re
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&re.into(), tolerance);
+    actual_im
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&im.into(), tolerance);
+}
+
+#[test]
+fn should_support_complex_mul() {
+    let lhs = complex::<1, _>([1.0, 3.0], [2.0, -1.0]);
+    let rhs = complex::<1, _>([2.0, 0.0], [-1.0, 1.0]);
+
+    assert_complex(lhs.mul(rhs), [4.0, 1.0], [3.0, 3.0]);
+}
+
+#[test]
+fn should_support_complex_div() {
+    let lhs = complex::<1, _>([1.0, 3.0], [2.0, -1.0]);
+    let rhs = complex::<1, _>([2.0, 0.0], [-1.0, 1.0]);
+
+    assert_complex(lhs.div(rhs), [0.0, -1.0], [1.0, -3.0]);
+}
+
+#[test]
+fn should_support_complex_conj() {
+    let tensor = complex::<1, _>([1.0, 3.0], [2.0, -1.0]);
+
+    assert_complex(tensor.conj(), [1.0, 3.0], [-2.0, 1.0]);
+}
+
+#[test]
+fn should_support_complex_abs_and_angle() {
+    let tensor = complex::<1, _>([3.0, 0.0], [4.0, -2.0]);
+
+    tensor
+        .clone()
+        .abs()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([5.0, 2.0]), Tolerance::default());
+    tensor.angle().into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([0.9273, -core::f64::consts::FRAC_PI_2]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_support_complex_exp() {
+    let tensor = complex::<1, _>([0.0, 0.0], [0.0, core::f64::consts::PI]);
+
+    assert_complex(tensor.exp(), [1.0, -1.0], [0.0, 0.0]);
+}
+
+#[test]
+fn should_support_complex_matmul() {
+    let lhs = complex::<2, _>([[1.0, 2.0], [0.0, 0.0]], [[1.0, 0.0], [0.0, 1.0]]);
+    let rhs = complex::<2, _>([[1.0, 0.0], [1.0, 0.0]], [[0.0, 1.0], [-1.0, 0.0]]);
+
+    assert_complex(
+        lhs.matmul(rhs),
+        [[3.0, -1.0], [1.0, 0.0]],
+        [[-1.0, 1.0], [1.0, 0.0]],
+    );
+}
+
+#[test]
+fn should_support_complex_data_round_trip() {
+    let tensor = complex::<1, _>([1.0, 3.0], [2.0, -1.0]);
+
+    let data = tensor.to_data();
+    assert_eq!(data.dtype, tensor.dtype());
+    assert!(data.dtype.is_complex());
+    assert_eq!(data.shape, [2].into());
+
+    let tensor = ComplexTensor::<1>::from_data(data, &Default::default());
+    assert_complex(tensor, [1.0, 3.0], [2.0, -1.0]);
+}
+
+#[test]
+fn should_support_complex_interleaved_round_trip() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, -1.0]]);
+
+    let complex = ComplexTensor::<1>::from_interleaved(tensor.clone());
+    assert_complex(complex.clone(), [1.0, 3.0], [2.0, -1.0]);
+
+    complex
+        .into_interleaved::<2>()
+        .into_data()
+        .assert_eq(&tensor.into_data(), false);
+}
+
+#[test]
+fn should_support_complex_fft() {
+    // x[n] = exp(i * 2π * n / 4)
+    let signal = complex::<1, _>([1.0, 0.0, -1.0, 0.0], [0.0, 1.0, 0.0, -1.0]);
+
+    let spectrum = signal.fft(0, None, FftNorm::Backward);
+    assert_complex(spectrum.clone(), [0.0, 4.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]);
+
+    let signal = spectrum.ifft(0, None, FftNorm::Backward);
+    assert_complex(signal, [1.0, 0.0, -1.0, 0.0], [0.0, 1.0, 0.0, -1.0]);
+}
+
+#[test]
+fn should_support_complex_rfft() {
+    let signal = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0]);
+
+    let spectrum = ComplexTensor::rfft(signal, 0, None);
+    assert!(spectrum.dtype().is_complex());
+    assert_complex(spectrum.clone(), [10.0, -2.0, -2.0], [0.0, 2.0, 0.0]);
+
+    spectrum
+        .irfft(0, None)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([1.0, 2.0, 3.0, 4.0]),
+            Tolerance::default(),
+        );
+}
+
+#[test]
+fn should_support_complex_fftn_ortho() {
+    let signal = complex::<2, _>([[1.0, 1.0], [1.0, 1.0]], [[0.0, 0.0], [0.0, 0.0]]);
+
+    let spectrum = signal.fftn([0, 1], None, FftNorm::Ortho);
+
+    assert_complex(spectrum, [[2.0, 0.0], [0.0, 0.0]], [[0.0, 0.0], [0.0, 0.0]]);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_complex_parts_shape_mismatch() {
+    let _tensor = ComplexTensor::new(TestTensor::<1>::from([1.0, 2.0]), TestTensor::from([1.0]));
+}
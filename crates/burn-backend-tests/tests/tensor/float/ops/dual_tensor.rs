@@ -0,0 +1,82 @@
+use super::*;
+use burn_tensor::dual::DualTensor;
+use burn_tensor::{TensorData, Tolerance};
+
+fn assert_close<const D: usize>(tensor: TestTensor<D>, expected: TestTensor<D>) {
+    let tolerance = Tolerance::default().set_half_precision_relative(1e-2);
+    tensor
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), tolerance);
+}
+
+#[test]
+fn should_jvp_sum_of_squares() {
+    let x = TestTensor::<1>::from([1.0, 2.0, -3.0]);
+    let tangent = TestTensor::<1>::from([1.0, 0.5, 2.0]);
+
+    let (output, jvp) = x.jvp(tangent, |x| x.clone().mul(x).sum());
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([14.0]), false);
+    // 2 x . t
+    jvp.into_data().assert_eq(&TensorData::from([-8.0]), false);
+}
+
+#[test]
+fn should_jvp_matmul() {
+    let x = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tangent = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+    let weight = TestTensor::<2>::from([[2.0, -1.0], [0.5, 3.0]]);
+
+    let (output, jvp) = x.jvp(tangent, |x| {
+        x.clone()
+            .matmul(DualTensor::constant(weight.clone()))
+            .add(x)
+    });
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([[4.0, 7.0], [11.0, 13.0]]), false);
+    jvp.into_data()
+        .assert_eq(&TensorData::from([[3.0, -1.0], [0.5, 4.0]]), false);
+}
+
+#[test]
+fn should_jvp_unary_ops() {
+    let values = [0.5, 1.0, 2.0];
+    let tangent = TestTensor::<1>::from([1.0, -2.0, 0.5]);
+    let x = TestTensor::<1>::from(values);
+
+    let (_, jvp) = x
+        .clone()
+        .jvp(tangent.clone(), |x| x.clone().exp().mul(x.sin()));
+    let expected = x.clone().exp() * (x.clone().sin() + x.clone().cos()) * tangent.clone();
+    assert_close(jvp, expected);
+
+    let (_, jvp) = x.clone().jvp(tangent.clone(), |x| x.tanh());
+    let expected = (x.clone().tanh().square().neg() + 1.0) * tangent.clone();
+    assert_close(jvp, expected);
+
+    let (_, jvp) = x
+        .clone()
+        .jvp(tangent.clone(), |x| x.clone().log().div(x.sqrt()));
+    // d/dx log(x) / sqrt(x) = (2 - log(x)) / (2 x^1.5)
+    let expected =
+        (x.clone().log().neg() + 2.0) / x.clone().powf_scalar(1.5).mul_scalar(2.0) * tangent;
+    assert_close(jvp, expected);
+}
+
+#[test]
+fn should_jvp_relu() {
+    let x = TestTensor::<1>::from([-1.0, 0.5, 2.0]);
+    let tangent = TestTensor::<1>::from([3.0, 3.0, -1.0]);
+
+    let (output, jvp) = x.jvp(tangent, |x| x.relu());
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([0.0, 0.5, 2.0]), false);
+    jvp.into_data()
+        .assert_eq(&TensorData::from([0.0, 3.0, -1.0]), false);
+}
@@ -0,0 +1,193 @@
+use super::*;
+use burn_tensor::{Distribution, TensorData, Tolerance, einsum};
+
+#[test]
+fn should_support_einsum_matmul() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    let rhs = TestTensor::<2>::from_data([[1.0, 0.0, 2.0], [-1.0, 1.0, 0.5]], &device);
+
+    let output: TestTensor<2> = einsum("ij,jk->ik", &[lhs, rhs]);
+    let expected = TensorData::from([[-1.0, 2.0, 3.0], [-1.0, 4.0, 8.0], [-1.0, 6.0, 13.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_attention_scores() {
+    let device = Default::default();
+    let q = TestTensor::<4>::random([2, 3, 4, 5], Distribution::Default, &device);
+    let k = TestTensor::<4>::random([2, 3, 6, 5], Distribution::Default, &device);
+
+    let output: TestTensor<4> = einsum("bhqd,bhkd->bhqk", &[q.clone(), k.clone()]);
+    let expected = q.matmul(k.swap_dims(2, 3));
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_transpose() {
+    let device = Default::default();
+    let tensor = TestTensor::<3>::random([2, 3, 4], Distribution::Default, &device);
+
+    let output: TestTensor<3> = einsum("abc->cab", &[tensor.clone()]);
+    let expected = tensor.permute([2, 0, 1]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_sum() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+    let rows: TestTensor<1> = einsum("ij->i", &[tensor.clone()]);
+    let total: TestTensor<1> = einsum("ij->", &[tensor]);
+
+    rows.into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([6.0, 15.0]), Tolerance::default());
+    total
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([21.0]), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_trace_and_diagonal() {
+    let device = Default::default();
+    let tensor =
+        TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], &device);
+
+    let trace: TestTensor<1> = einsum("ii", &[tensor.clone()]);
+    let diagonal: TestTensor<1> = einsum("ii->i", &[tensor]);
+
+    trace
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([15.0]), Tolerance::default());
+    diagonal
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([1.0, 5.0, 9.0]), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_implicit_output() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    let rhs = TestTensor::<2>::from_data([[5.0, 6.0], [7.0, 8.0]], &device);
+
+    // Implicit output "ki": the transpose of the matrix product.
+    let output: TestTensor<2> = einsum("kj,ji", &[lhs.clone(), rhs.clone()]);
+    let expected = lhs.matmul(rhs).transpose();
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_outer_product() {
+    let device = Default::default();
+    let lhs = TestTensor::<1>::from_data([1.0, 2.0], &device);
+    let rhs = TestTensor::<1>::from_data([3.0, 4.0, 5.0], &device);
+
+    let output: TestTensor<2> = einsum("i,j->ij", &[lhs, rhs]);
+    let expected = TensorData::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_hadamard_product() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    let rhs = TestTensor::<2>::from_data([[5.0, 6.0], [7.0, 8.0]], &device);
+
+    let output: TestTensor<2> = einsum("ij,ij->ij", &[lhs, rhs]);
+    let expected = TensorData::from([[5.0, 12.0], [21.0, 32.0]]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_ellipsis_broadcast() {
+    let device = Default::default();
+    let lhs = TestTensor::<3>::random([4, 2, 3], Distribution::Default, &device);
+    let rhs = TestTensor::<3>::random([1, 3, 5], Distribution::Default, &device);
+
+    let output: TestTensor<3> = einsum("...ij,...jk->...ik", &[lhs.clone(), rhs.clone()]);
+    let expected = lhs.matmul(rhs);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_chain_of_operands() {
+    let device = Default::default();
+    let a = TestTensor::<2>::random([3, 8], Distribution::Default, &device);
+    let b = TestTensor::<2>::random([8, 2], Distribution::Default, &device);
+    let c = TestTensor::<2>::random([2, 6], Distribution::Default, &device);
+    let d = TestTensor::<2>::random([6, 4], Distribution::Default, &device);
+
+    let output: TestTensor<2> = einsum(
+        "ij,jk,kl,lm->im",
+        &[a.clone(), b.clone(), c.clone(), d.clone()],
+    );
+    let expected = a.matmul(b).matmul(c).matmul(d);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected.into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_support_einsum_bilinear_form() {
+    let device = Default::default();
+    let x = TestTensor::<2>::from_data([[1.0, 2.0], [0.0, 1.0]], &device);
+    let a = TestTensor::<2>::from_data([[2.0, 1.0], [1.0, 3.0]], &device);
+    let y = TestTensor::<2>::from_data([[1.0, -1.0], [2.0, 1.0]], &device);
+
+    // out[b] = x[b]^T A y[b]
+    let output: TestTensor<1> = einsum("bi,ij,bj->b", &[x, a, y]);
+    let expected = TensorData::from([-3.0, 5.0]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+#[should_panic]
+fn should_panic_einsum_wrong_number_of_operands() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::ones([2, 2], &device);
+    let _: TestTensor<2> = einsum("ij,jk->ik", &[tensor]);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_einsum_mismatched_sizes() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::ones([2, 3], &device);
+    let rhs = TestTensor::<2>::ones([4, 2], &device);
+    let _: TestTensor<2> = einsum("ij,jk->ik", &[lhs, rhs]);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_einsum_wrong_output_rank() {
+    let device = Default::default();
+    let lhs = TestTensor::<2>::ones([2, 3], &device);
+    let rhs = TestTensor::<2>::ones([3, 2], &device);
+    let _: TestTensor<3> = einsum("ij,jk->ik", &[lhs, rhs]);
+}
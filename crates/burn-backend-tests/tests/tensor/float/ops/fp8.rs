@@ -0,0 +1,61 @@
+use super::*;
+use burn_tensor::{DType, Device, FloatDType, TensorData, Tolerance};
+
+// FP8 is a storage type, only available on the backends that can hold it.
+fn supports_fp8() -> bool {
+    Device::default().supports_dtype(DType::F8E4M3)
+}
+
+#[test]
+fn should_cast_to_fp8_and_back() {
+    if !supports_fp8() {
+        return;
+    }
+    let tensor = TestTensor::<1>::from([1.0, -2.0, 0.3, 1000.0]);
+
+    let fp8 = tensor.cast(FloatDType::F8E4M3);
+    assert_eq!(fp8.dtype(), DType::F8E4M3);
+
+    // 0.3 rounds to the nearest E4M3 value and 1000 saturates to the largest finite value.
+    fp8.cast(FloatDType::F32)
+        .into_data()
+        .assert_eq(&TensorData::from([1.0, -2.0, 0.3125, 448.0]), false);
+}
+
+#[test]
+fn should_round_trip_fp8_scaled() {
+    if !supports_fp8() {
+        return;
+    }
+    let tensor = TestTensor::<2>::from([[0.5, -896.0], [3.0, 64.0]]);
+
+    let (fp8, scale) = tensor.clone().to_fp8_scaled(FloatDType::F8E4M3);
+    assert_eq!(fp8.dtype(), DType::F8E4M3);
+    scale
+        .clone()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([2.0]), Tolerance::default());
+
+    // The scaled values are exact in E4M3.
+    fp8.from_fp8_scaled(scale, FloatDType::F32)
+        .into_data()
+        .assert_approx_eq::<f32>(&tensor.into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_scaled_matmul_fp8() {
+    if !supports_fp8() {
+        return;
+    }
+    let lhs = TestTensor::<2>::from([[1.0, 2.0], [4.0, 8.0]]);
+    let rhs = TestTensor::<2>::from([[8.0, 0.0], [0.0, -16.0]]);
+
+    let (lhs, lhs_scale) = lhs.to_fp8_scaled(FloatDType::F8E4M3);
+    let (rhs, rhs_scale) = rhs.to_fp8_scaled(FloatDType::F8E5M2);
+    let output = lhs.scaled_matmul(rhs, lhs_scale, rhs_scale, FloatDType::F32);
+
+    output.into_data().assert_approx_eq::<f32>(
+        &TensorData::from([[8.0, -32.0], [32.0, -128.0]]),
+        Tolerance::rel_abs(0.01, 0.0),
+    );
+}
@@ -0,0 +1,34 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_histc() {
+    let tensor = TestTensor::<2>::from([[0.0, 0.5, 1.0], [2.0, 2.5, 4.0]]);
+
+    let histogram = tensor.histc(4, 0.0, 4.0);
+
+    histogram.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([2.0, 1.0, 2.0, 1.0]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_ignore_values_outside_of_histc_range() {
+    let tensor = TestTensor::<1>::from([-1.0, -0.5, 0.2, 0.7, 1.0, 1.5, f32::NAN]);
+
+    let histogram = tensor.histc(2, -0.5, 1.0);
+
+    // Bins: [-0.5, 0.25), [0.25, 1.0]
+    histogram
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([2.0, 2.0]), Tolerance::default());
+}
+
+#[test]
+#[should_panic]
+fn should_panic_histc_with_empty_range() {
+    let tensor = TestTensor::<1>::from([0.0, 1.0]);
+
+    let _ = tensor.histc(4, 1.0, 1.0);
+}
@@ -0,0 +1,42 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_logsumexp() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+
+    let output = tensor.logsumexp(1);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[3.4076059], [1.0986123]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_not_overflow_logsumexp() {
+    let tensor = TestTensor::<2>::from([[1000.0, 1000.0], [-1000.0, -1000.0]]);
+
+    let output = tensor.logsumexp(-1);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1000.6931], [-999.3069]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_support_logsumexp_with_infinite_max() {
+    let tensor = TestTensor::<2>::from([
+        [f32::NEG_INFINITY, f32::NEG_INFINITY],
+        [f32::INFINITY, 1.0],
+        [f32::NEG_INFINITY, 0.0],
+    ]);
+
+    let output = tensor.logsumexp(1);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[f32::NEG_INFINITY], [f32::INFINITY], [0.0]]),
+        false,
+    );
+}
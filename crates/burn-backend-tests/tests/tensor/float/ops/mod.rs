@@ -67,11 +67,14 @@ mod chunk;
 mod clamp;
 mod close;
 mod comparison;
+mod complex_tensor;
 mod create_like;
 mod cross;
 mod cumulative;
 mod div;
 mod dot;
+mod dual_tensor;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
@@ -80,25 +83,30 @@ mod flatten;
 mod flip;
 mod floor;
 mod fmod;
+mod fp8;
 mod full;
 mod gather_scatter;
 mod gather_scatter_nd;
 mod grid_sample;
 mod hamming_window;
 mod hann_window;
+mod histc;
 mod hypot;
 mod inf;
 mod init;
 mod iter_dim;
 mod log;
 mod log1p;
+mod logsumexp;
 mod mask;
 mod mask_select;
 mod matmul;
 mod maxmin;
 mod movedim;
 mod mul;
+mod multinomial;
 mod nan;
+mod nan_reduce;
 mod narrow;
 mod neg;
 mod negative_dims;
@@ -115,11 +123,16 @@ mod repeat;
 mod repeat_dim;
 mod reshape;
 mod round;
+mod scan;
+mod scatter_reduce;
+mod searchsorted;
 mod select;
 mod sign;
 mod slice;
 mod slice_assign;
 mod sort_argsort;
+mod sparse;
+mod special;
 mod split;
 mod sqrt;
 mod square;
@@ -134,3 +147,5 @@ mod tri;
 mod trig;
 mod trunc;
 mod unfold;
+mod unique;
+mod xlogy;
@@ -0,0 +1,94 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn should_sample_multinomial_with_replacement() {
+    let weights = TestTensor::<2>::from([[0.0, 1.0, 0.0, 3.0], [0.0, 0.0, 5.0, 0.0]]);
+
+    let samples = weights.multinomial(1000, true);
+    let [rows, num_samples] = samples.dims();
+    assert_eq!([rows, num_samples], [2, 1000]);
+
+    // Categories with a zero weight are never drawn.
+    let first = samples.clone().slice([0..1]);
+    let drawn_1 = first.clone().equal_elem(1).int().sum().into_scalar::<i64>();
+    let drawn_3 = first.equal_elem(3).int().sum().into_scalar::<i64>();
+    assert_eq!(drawn_1 + drawn_3, 1000);
+    assert!(
+        (200..300).contains(&drawn_1),
+        "Drew category 1 {drawn_1} times"
+    );
+
+    samples
+        .slice([1..2])
+        .into_data()
+        .assert_eq(&TensorData::from([[2i64; 1000]]), false);
+}
+
+#[test]
+fn should_sample_multinomial_without_replacement() {
+    let weights = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0], [0.0, 1.0, 0.0, 1.0]]);
+
+    let samples = weights.multinomial(2, false);
+
+    // All the categories with a non-zero weight are drawn once.
+    samples
+        .clone()
+        .slice([1..2])
+        .sort(1)
+        .into_data()
+        .assert_eq(&TensorData::from([[1i64, 3]]), false);
+
+    let first = samples.slice([0..1]);
+    let [a, b] = first.into_data().convert::<i64>().to_vec::<i64>().unwrap()[..] else {
+        panic!("Expected two samples");
+    };
+    assert_ne!(a, b);
+}
+
+#[test]
+fn should_sample_every_category_without_replacement() {
+    let weights = TestTensor::<1>::from([0.1, 0.2, 0.3, 0.4, 0.5]);
+
+    let samples = weights.multinomial(5, false);
+
+    samples
+        .sort(0)
+        .into_data()
+        .assert_eq(&TensorData::from([0i64, 1, 2, 3, 4]), false);
+}
+
+#[test]
+fn should_sample_dirichlet() {
+    let concentration = TestTensor::<2>::from([[1.0, 1.0, 2.0], [0.5, 0.5, 3.0]])
+        .unsqueeze_dim::<3>(0)
+        .repeat_dim(0, 2000);
+
+    let samples = concentration.dirichlet();
+
+    // Each sample is a probability vector.
+    samples
+        .clone()
+        .sum_dim(2)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TestTensor::<3>::ones([2000, 2, 1], &Default::default()).into_data(),
+            burn_tensor::Tolerance::absolute(1e-3),
+        );
+    assert!(!samples.clone().lower_elem(0.0).any().into_scalar::<bool>());
+
+    // The mean is the normalized concentration.
+    samples
+        .mean_dim(0)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([[[0.25, 0.25, 0.5], [0.125, 0.125, 0.75]]]),
+            burn_tensor::Tolerance::absolute(3e-2),
+        );
+}
+
+#[test]
+#[should_panic = "The concentrations must be finite and positive"]
+fn should_panic_dirichlet_with_non_positive_concentration() {
+    TestTensor::<2>::from([[1.0, 0.0, 2.0]]).dirichlet();
+}
@@ -0,0 +1,91 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_nansum() {
+    let tensor = TestTensor::<2>::from([[1.0, f32::NAN, 3.0], [f32::NAN, f32::NAN, f32::NAN]]);
+
+    tensor
+        .clone()
+        .nansum_dim(1)
+        .into_data()
+        .assert_eq(&TensorData::from([[4.0], [0.0]]), false);
+    tensor
+        .nansum()
+        .into_data()
+        .assert_eq(&TensorData::from([4.0]), false);
+}
+
+#[test]
+fn should_support_nanmean() {
+    let tensor = TestTensor::<2>::from([[1.0, f32::NAN, 4.0], [f32::NAN, f32::NAN, f32::NAN]]);
+
+    let output = tensor.clone().nanmean_dim(1);
+
+    output
+        .clone()
+        .is_nan()
+        .into_data()
+        .assert_eq(&TensorData::from([[false], [true]]), false);
+    output
+        .nan_to_num(0.0, None, None)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([[2.5], [0.0]]), Tolerance::default());
+    tensor
+        .nanmean()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([2.5]), Tolerance::default());
+}
+
+#[test]
+fn should_support_nanmax() {
+    let tensor = TestTensor::<2>::from([
+        [f32::NAN, -2.0, -1.0],
+        [f32::NAN, f32::NAN, f32::NAN],
+        [f32::NEG_INFINITY, 5.0, f32::NAN],
+    ]);
+
+    let output = tensor.clone().nanmax_dim(1);
+
+    output
+        .clone()
+        .is_nan()
+        .into_data()
+        .assert_eq(&TensorData::from([[false], [true], [false]]), false);
+    output
+        .nan_to_num(0.0, None, None)
+        .into_data()
+        .assert_eq(&TensorData::from([[-1.0], [0.0], [5.0]]), false);
+    tensor
+        .nanmax()
+        .into_data()
+        .assert_eq(&TensorData::from([5.0]), false);
+}
+
+#[test]
+fn should_support_nan_to_num() {
+    let tensor = TestTensor::<1>::from([1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+
+    let output = tensor.nan_to_num(0.5, Some(100.0), Some(-100.0));
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([1.0, 0.5, 100.0, -100.0]), false);
+}
+
+#[test]
+fn should_support_nan_to_num_with_dtype_limits() {
+    let tensor = TestTensor::<1>::from([f32::INFINITY, f32::NEG_INFINITY]);
+
+    let output = tensor.nan_to_num(0.0, None, None);
+
+    output
+        .clone()
+        .is_finite()
+        .into_data()
+        .assert_eq(&TensorData::from([true, true]), false);
+    output
+        .greater_elem(0.0)
+        .into_data()
+        .assert_eq(&TensorData::from([true, false]), false);
+}
@@ -51,3 +51,88 @@ fn test_seed_reproducibility() {
     t1.into_data()
         .assert_approx_eq::<FloatElem>(&t2.into_data(), Tolerance::default());
 }
+
+/// Asserts that the empirical mean of the samples is within `tolerance` of `expected`.
+fn assert_mean_near(tensor: TestTensor<1>, expected: f32, tolerance: f32) {
+    let mean = tensor.mean().into_scalar::<f32>();
+    assert!(
+        (mean - expected).abs() < tolerance,
+        "Expected a mean close to {expected}, got {mean}"
+    );
+}
+
+#[test]
+fn rand_exponential() {
+    let tensor =
+        TestTensor::<1>::random([10_000], Distribution::Exponential(2.), &Default::default());
+
+    assert!(!tensor.clone().lower_elem(0.).any().into_scalar::<bool>());
+    assert_mean_near(tensor, 0.5, 0.05);
+}
+
+#[test]
+fn rand_gamma() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::random([10_000], Distribution::Gamma(2., 3.), &device);
+
+    assert!(!tensor.clone().lower_elem(0.).any().into_scalar::<bool>());
+    assert_mean_near(tensor, 6., 0.3);
+
+    // Shapes below 1 use a different method.
+    let tensor = TestTensor::<1>::random([10_000], Distribution::Gamma(0.5, 1.), &device);
+    assert_mean_near(tensor, 0.5, 0.05);
+}
+
+#[test]
+fn rand_gamma_variance() {
+    let device = Default::default();
+
+    // A fallback value for rejected candidates would shrink the variance.
+    for (shape, tolerance) in [(2., 0.25), (0.5, 0.1)] {
+        let tensor = TestTensor::<1>::random([10_000], Distribution::Gamma(shape, 1.), &device);
+        let variance = tensor.var(0).into_scalar::<f32>();
+        assert!(
+            (variance - shape as f32).abs() < tolerance,
+            "Expected a variance close to {shape}, got {variance}"
+        );
+    }
+}
+
+#[test]
+fn rand_beta() {
+    let tensor = TestTensor::<1>::random([10_000], Distribution::Beta(2., 6.), &Default::default());
+
+    tensor
+        .clone()
+        .into_data()
+        .assert_within_range_inclusive(0.elem::<FloatElem>()..=1.elem::<FloatElem>());
+    assert_mean_near(tensor, 0.25, 0.02);
+}
+
+#[test]
+fn rand_poisson() {
+    let device = Default::default();
+
+    for rate in [3., 40.] {
+        let tensor = TestTensor::<1>::random([10_000], Distribution::Poisson(rate), &device);
+
+        // The samples are non-negative integers.
+        tensor
+            .clone()
+            .into_data()
+            .assert_eq(&tensor.clone().round().clamp_min(0.).into_data(), false);
+        assert_mean_near(tensor, rate as f32, rate.sqrt() as f32 * 0.1);
+    }
+}
+
+#[test]
+#[should_panic = "The shape and scale of the gamma distribution must be finite and positive"]
+fn rand_gamma_should_panic_with_non_positive_shape() {
+    TestTensor::<1>::random([10], Distribution::Gamma(0., 1.), &Default::default());
+}
+
+#[test]
+#[should_panic = "The parameters of the beta distribution must be finite and positive"]
+fn rand_beta_should_panic_with_nan_parameter() {
+    TestTensor::<1>::random([10], Distribution::Beta(f64::NAN, 1.), &Default::default());
+}
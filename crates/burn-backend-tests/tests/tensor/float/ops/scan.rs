@@ -0,0 +1,77 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_scan() {
+    let tensor = TestTensor::<2>::from([[1.0, 3.0, 2.0, 5.0, 4.0], [4.0, 0.0, 6.0, 1.0, 7.0]]);
+
+    let output = tensor.scan(1, |earlier, later| earlier.max_pair(later));
+
+    output.into_data().assert_eq(
+        &TensorData::from([[1.0, 3.0, 3.0, 5.0, 5.0], [4.0, 4.0, 6.0, 6.0, 7.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_match_cumsum_with_scan() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+    let output = tensor.clone().scan(0, |earlier, later| earlier + later);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.cumsum(0).into_data(), Tolerance::default());
+}
+
+#[test]
+fn should_support_linear_recurrence() {
+    let a = TestTensor::<2>::from([[0.5, 0.5, 0.5, 0.5, 0.5], [1.0, 2.0, 0.0, 1.0, -1.0]]);
+    let b = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 1.0, 1.0, 1.0, 1.0]]);
+
+    let output = a.linear_recurrence(b, 1);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.0, 2.5, 4.25, 6.125, 8.0625], [1.0, 3.0, 1.0, 2.0, -1.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_support_linear_recurrence_along_first_dim() {
+    let a = TestTensor::<2>::from([[0.5, 2.0], [1.0, -1.0], [0.0, 0.5]]);
+    let b = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+    let output = a.linear_recurrence(b, 0);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.0, 2.0], [4.0, 2.0], [5.0, 7.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_support_linear_recurrence_non_contiguous() {
+    let a = TestTensor::<2>::from([[0.5, 1.0, 0.0], [2.0, -1.0, 0.5]]).transpose();
+    let b = TestTensor::<2>::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]).transpose();
+
+    let output = a.linear_recurrence(b, -2);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.0, 2.0], [4.0, 2.0], [5.0, 7.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn should_match_cumsum_with_linear_recurrence_over_long_dim() {
+    // Long enough to be scanned in several nested chunks.
+    let b = TestTensor::<3>::ones([2, 1100, 3], &Default::default());
+    let a = b.clone();
+
+    let output = a.linear_recurrence(b.clone(), 1);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&b.cumsum(1).into_data(), Tolerance::default());
+}
@@ -0,0 +1,133 @@
+use super::*;
+use burn_tensor::{ScatterReduce, TensorData};
+
+#[test]
+fn should_scatter_reduce_sum_with_duplicates() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let indices = TestTensorInt::from_ints([[0, 0, 2], [1, 1, 1]], &device);
+    let values = TestTensor::from_data([[1.0, 1.0, 1.0], [2.0, 3.0, 4.0]], &device);
+
+    let output = tensor.scatter_reduce(1, indices, values, ScatterReduce::Sum, true);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[3.0, 2.0, 4.0], [4.0, 14.0, 6.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_scatter_reduce_prod_exclude_self() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    let indices = TestTensorInt::from_ints([[0, 0, 2], [1, 1, 1]], &device);
+    let values = TestTensor::from_data([[2.0, 3.0, 4.0], [2.0, 3.0, 4.0]], &device);
+
+    let output = tensor.scatter_reduce(1, indices, values, ScatterReduce::Prod, false);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[6.0, 2.0, 4.0], [4.0, 24.0, 6.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_scatter_reduce_mean() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 2.0, 3.0], &device);
+    let indices = TestTensorInt::from_ints([0, 0, 2], &device);
+    let values = TestTensor::from_data([3.0, 5.0, 9.0], &device);
+
+    let output = tensor.clone().scatter_reduce(
+        0,
+        indices.clone(),
+        values.clone(),
+        ScatterReduce::Mean,
+        true,
+    );
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([3.0, 2.0, 6.0]), false);
+
+    let output = tensor.scatter_reduce(0, indices, values, ScatterReduce::Mean, false);
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([4.0, 2.0, 9.0]), false);
+}
+
+#[test]
+fn should_scatter_reduce_amax_amin() {
+    let device = Default::default();
+    let tensor = TestTensor::<1>::from_data([1.0, 10.0, 3.0], &device);
+    let indices = TestTensorInt::from_ints([0, 0, 1, 2], &device);
+    let values = TestTensor::from_data([5.0, -1.0, 4.0, 2.0], &device);
+
+    let output = tensor.clone().scatter_reduce(
+        0,
+        indices.clone(),
+        values.clone(),
+        ScatterReduce::Amax,
+        true,
+    );
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([5.0, 10.0, 3.0]), false);
+
+    let output = tensor.clone().scatter_reduce(
+        0,
+        indices.clone(),
+        values.clone(),
+        ScatterReduce::Amax,
+        false,
+    );
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([5.0, 4.0, 2.0]), false);
+
+    let output = tensor.scatter_reduce(0, indices, values, ScatterReduce::Amin, true);
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([-1.0, 4.0, 2.0]), false);
+}
+
+#[test]
+fn should_segment_reduce() {
+    let device = Default::default();
+    let tensor =
+        TestTensor::<2>::from_data([[-1.0, 2.0], [-3.0, 4.0], [5.0, 6.0], [7.0, 8.0]], &device);
+    let segment_ids = TestTensorInt::<1>::from_ints([0, 0, 1, 3], &device);
+
+    let output = tensor.clone().segment_sum(segment_ids.clone(), 4);
+    output.into_data().assert_eq(
+        &TensorData::from([[-4.0, 6.0], [5.0, 6.0], [0.0, 0.0], [7.0, 8.0]]),
+        false,
+    );
+
+    let output = tensor.clone().segment_mean(segment_ids.clone(), 4);
+    output.into_data().assert_eq(
+        &TensorData::from([[-2.0, 3.0], [5.0, 6.0], [0.0, 0.0], [7.0, 8.0]]),
+        false,
+    );
+
+    let output = tensor.clone().segment_max(segment_ids.clone(), 4);
+    output.into_data().assert_eq(
+        &TensorData::from([[-1.0, 4.0], [5.0, 6.0], [0.0, 0.0], [7.0, 8.0]]),
+        false,
+    );
+
+    let output = tensor.segment_min(segment_ids, 4);
+    output.into_data().assert_eq(
+        &TensorData::from([[-3.0, 2.0], [5.0, 6.0], [0.0, 0.0], [7.0, 8.0]]),
+        false,
+    );
+}
+
+#[test]
+#[should_panic]
+fn should_panic_segment_reduce_wrong_ids_length() {
+    let device = Default::default();
+    let tensor = TestTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    let segment_ids = TestTensorInt::<1>::from_ints([0, 0, 1], &device);
+
+    let _output = tensor.segment_sum(segment_ids, 2);
+}
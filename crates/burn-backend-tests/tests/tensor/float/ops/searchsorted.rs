@@ -0,0 +1,56 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn should_support_searchsorted_left_and_right() {
+    let sorted = TestTensor::<1>::from([1.0, 3.0, 3.0, 5.0]);
+    let values = TestTensor::<1>::from([0.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let left = sorted.clone().searchsorted(values.clone(), false);
+    let right = sorted.searchsorted(values, true);
+
+    left.into_data()
+        .assert_eq(&TensorData::from([0, 1, 3, 3, 4]), false);
+    right
+        .into_data()
+        .assert_eq(&TensorData::from([0, 3, 3, 4, 4]), false);
+}
+
+#[test]
+fn should_support_searchsorted_per_row() {
+    let sorted = TestTensor::<2>::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+    let values = TestTensor::<2>::from([[3.0, 6.0], [1.0, 4.0]]);
+
+    let indices = sorted.searchsorted(values, false);
+
+    indices
+        .into_data()
+        .assert_eq(&TensorData::from([[1, 3], [0, 1]]), false);
+}
+
+#[test]
+fn should_support_bucketize() {
+    let boundaries = TestTensor::<1>::from([1.0, 3.0, 5.0]);
+    let tensor = TestTensor::<3>::from([[[0.5, 3.0], [4.0, 9.0]], [[1.0, 5.0], [-2.0, 2.0]]]);
+
+    let left = tensor.clone().bucketize(boundaries.clone(), false);
+    let right = tensor.bucketize(boundaries, true);
+
+    left.into_data().assert_eq(
+        &TensorData::from([[[0, 1], [2, 3]], [[0, 2], [0, 1]]]),
+        false,
+    );
+    right.into_data().assert_eq(
+        &TensorData::from([[[0, 2], [2, 3]], [[1, 3], [0, 1]]]),
+        false,
+    );
+}
+
+#[test]
+#[should_panic]
+fn should_panic_searchsorted_with_mismatched_leading_dims() {
+    let sorted = TestTensor::<2>::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+    let values = TestTensor::<2>::from([[3.0, 6.0]]);
+
+    let _ = sorted.searchsorted(values, false);
+}
@@ -0,0 +1,204 @@
+use super::*;
+use burn_tensor::TensorData;
+use burn_tensor::sparse::{SparseLayout, SparseTensor};
+
+/// A 3x3 matrix with the duplicate index (1, 2), out of row-major order.
+fn sparse_uncoalesced() -> SparseTensor<2> {
+    let indices = TestTensorInt::<2>::from([[1, 0, 1, 0], [2, 1, 2, 0]]);
+    let values = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0]);
+    SparseTensor::from_coo(indices, values, [3, 3])
+}
+
+#[test]
+fn should_support_sparse_to_dense() {
+    let dense = sparse_uncoalesced().to_dense();
+
+    dense.into_data().assert_eq(
+        &TensorData::from([[4.0, 2.0, 0.0], [0.0, 0.0, 4.0], [0.0, 0.0, 0.0]]),
+        false,
+    );
+}
+
+#[test]
+fn should_support_sparse_from_dense() {
+    let tensor = TestTensor::<2>::from([[0.0, 1.5, 0.0], [-2.0, 0.0, 3.0]]);
+
+    let sparse = tensor.clone().to_sparse();
+
+    assert!(sparse.is_coalesced());
+    assert_eq!(sparse.nnz(), 3);
+    sparse
+        .indices()
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 1, 1], [1, 0, 2]]), false);
+    sparse
+        .values()
+        .into_data()
+        .assert_eq(&TensorData::from([1.5, -2.0, 3.0]), false);
+    sparse
+        .to_dense()
+        .into_data()
+        .assert_eq(&tensor.into_data(), false);
+}
+
+#[test]
+fn should_support_sparse_coalesce() {
+    let sparse = sparse_uncoalesced().coalesce();
+
+    assert!(sparse.is_coalesced());
+    assert_eq!(sparse.nnz(), 3);
+    sparse
+        .indices()
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 0, 1], [0, 1, 2]]), false);
+    sparse
+        .values()
+        .into_data()
+        .assert_eq(&TensorData::from([4.0, 2.0, 4.0]), false);
+}
+
+#[test]
+fn should_support_sparse_coalesce_3d() {
+    let indices = TestTensorInt::<2>::from([[1, 0, 1], [0, 1, 0], [1, 1, 1]]);
+    let values = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+    let sparse = SparseTensor::from_coo(indices, values, [2, 2, 2]).coalesce();
+
+    sparse
+        .indices()
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 1], [1, 0], [1, 1]]), false);
+    sparse
+        .values()
+        .into_data()
+        .assert_eq(&TensorData::from([2.0, 4.0]), false);
+}
+
+#[test]
+fn should_support_sparse_coo_to_csr() {
+    let sparse = sparse_uncoalesced().to_csr();
+
+    assert_eq!(sparse.layout(), SparseLayout::Csr);
+    sparse
+        .crow_indices()
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0, 2, 3, 3]), false);
+    sparse
+        .col_indices()
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0, 1, 2]), false);
+    sparse
+        .values()
+        .into_data()
+        .assert_eq(&TensorData::from([4.0, 2.0, 4.0]), false);
+}
+
+#[test]
+fn should_support_sparse_csr_to_coo() {
+    let crow_indices = TestTensorInt::<1>::from([0, 0, 2, 2, 3]);
+    let col_indices = TestTensorInt::<1>::from([0, 2, 1]);
+    let values = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+    let sparse = SparseTensor::from_csr(crow_indices, col_indices, values, [4, 3]);
+
+    let coo = sparse.clone().to_coo();
+
+    assert_eq!(coo.layout(), SparseLayout::Coo);
+    coo.indices()
+        .into_data()
+        .assert_eq(&TensorData::from([[1, 1, 3], [0, 2, 1]]), false);
+    sparse.to_dense().into_data().assert_eq(
+        &TensorData::from([
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 2.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 3.0, 0.0],
+        ]),
+        false,
+    );
+}
+
+#[test]
+fn should_coalesce_sparse_csr_to_csr() {
+    // Row 1 has the duplicate column 2, before column 0.
+    let crow_indices = TestTensorInt::<1>::from([0, 0, 3, 3]);
+    let col_indices = TestTensorInt::<1>::from([2, 0, 2]);
+    let values = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+    let sparse = SparseTensor::from_csr(crow_indices, col_indices, values, [3, 3]).to_csr();
+
+    assert!(sparse.is_coalesced());
+    sparse
+        .crow_indices()
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0, 0, 2, 2]), false);
+    sparse
+        .col_indices()
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0, 2]), false);
+    sparse
+        .values()
+        .into_data()
+        .assert_eq(&TensorData::from([2.0, 4.0]), false);
+}
+
+#[test]
+fn should_support_sparse_sum_dim() {
+    let sparse = sparse_uncoalesced();
+
+    let rows = sparse.clone().sum_dim(1);
+    let cols = sparse.sum_dim(0);
+
+    assert_eq!(rows.dims(), [3, 1]);
+    rows.to_dense()
+        .into_data()
+        .assert_eq(&TensorData::from([[6.0], [4.0], [0.0]]), false);
+    cols.coalesce()
+        .to_dense()
+        .into_data()
+        .assert_eq(&TensorData::from([[4.0, 2.0, 4.0]]), false);
+}
+
+#[test]
+fn should_support_spmm() {
+    let dense = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    let expected = TensorData::from([[10.0, 16.0], [20.0, 24.0], [0.0, 0.0]]);
+
+    let output = sparse_uncoalesced().spmm(dense.clone());
+    output.into_data().assert_eq(&expected, false);
+
+    let output = sparse_uncoalesced().to_csr().spmm(dense);
+    output.into_data().assert_eq(&expected, false);
+}
+
+#[test]
+fn should_support_spmm_matches_matmul() {
+    let matrix = TestTensor::<2>::from([
+        [0.0, 2.0, 0.0, 1.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [3.0, 0.0, -1.0, 0.0],
+    ]);
+    let dense = TestTensor::<2>::from([[1.0, -1.0], [0.5, 2.0], [4.0, 0.0], [-3.0, 1.0]]);
+
+    let output = matrix.clone().to_sparse().to_csr().spmm(dense.clone());
+
+    output
+        .into_data()
+        .assert_eq(&matrix.matmul(dense).into_data(), false);
+}
+
+#[test]
+fn should_support_spmm_csr_with_empty_rows() {
+    let crow_indices = TestTensorInt::<1>::from([0, 0, 2, 2]);
+    let col_indices = TestTensorInt::<1>::from([0, 1]);
+    let values = TestTensor::<1>::from([1.0, 2.0]);
+    let dense = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let output = SparseTensor::from_csr(crow_indices, col_indices, values, [3, 2]).spmm(dense);
+
+    output.into_data().assert_eq(
+        &TensorData::from([[0.0, 0.0, 0.0], [9.0, 12.0, 15.0], [0.0, 0.0, 0.0]]),
+        false,
+    );
+}
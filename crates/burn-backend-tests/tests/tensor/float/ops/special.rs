@@ -0,0 +1,128 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+fn tolerance() -> Tolerance<FloatElem> {
+    Tolerance::rel_abs(1e-3, 1e-4).set_half_precision_relative(1e-2)
+}
+
+#[test]
+fn should_support_lgamma() {
+    let tensor = TestTensor::<1>::from([0.5, 1.0, 2.5, -0.5, 10.0]);
+
+    let output = tensor.lgamma();
+    let expected = TensorData::from([0.5723649, 0.0, 0.2846829, 1.2655121, 12.801827]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_digamma() {
+    let tensor = TestTensor::<1>::from([0.5, 1.0, 2.5, -0.5, 10.0]);
+
+    let output = tensor.digamma();
+    let expected = TensorData::from([-1.9635100, -0.5772157, 0.7031566, 0.0364900, 2.2517526]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_polygamma() {
+    let tensor = TestTensor::<1>::from([0.5, 1.0, 2.5, 4.0]);
+
+    tensor
+        .clone()
+        .polygamma(1)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([4.9348022, 1.6449341, 0.4903578, 0.2838230]),
+            tolerance(),
+        );
+    tensor
+        .clone()
+        .polygamma(2)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([-16.828797, -2.4041138, -0.2362041, -0.0800397]),
+            tolerance(),
+        );
+    tensor
+        .polygamma(3)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([97.409091, 6.4939394, 0.2239058, 0.0448653]),
+            tolerance(),
+        );
+}
+
+#[test]
+fn should_support_polygamma_zero_as_digamma() {
+    let tensor = TestTensor::<1>::from([0.5, 1.0, 2.5]);
+
+    let output = tensor.clone().polygamma(0);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.digamma().into_data(), tolerance());
+}
+
+#[test]
+fn should_support_erfinv() {
+    let tensor = TestTensor::<1>::from([-0.9, -0.5, 0.0, 0.3, 0.99]);
+
+    let output = tensor.clone().erfinv();
+    let expected = TensorData::from([-1.1630872, -0.4769363, 0.0, 0.2724627, 1.8213864]);
+
+    output
+        .clone()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+    output
+        .erf()
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&tensor.into_data(), tolerance());
+}
+
+#[test]
+fn should_support_erfinv_bounds() {
+    let tensor = TestTensor::<1>::from([-1.0, 1.0]);
+
+    let output = tensor.erfinv();
+
+    output
+        .clone()
+        .is_inf()
+        .into_data()
+        .assert_eq(&TensorData::from([true, true]), false);
+    output
+        .sign()
+        .into_data()
+        .assert_eq(&TensorData::from([-1.0, 1.0]), false);
+}
+
+#[test]
+fn should_support_i0() {
+    let tensor = TestTensor::<1>::from([-5.0, -1.0, 0.0, 2.0, 4.0]);
+
+    let output = tensor.i0();
+    let expected = TensorData::from([27.239872, 1.2660659, 1.0, 2.2795853, 11.301922]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
+
+#[test]
+fn should_support_i1() {
+    let tensor = TestTensor::<1>::from([-5.0, -1.0, 0.0, 2.0, 4.0]);
+
+    let output = tensor.i1();
+    let expected = TensorData::from([-24.335642, -0.5651591, 0.0, 1.5906369, 9.7594653]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance());
+}
@@ -0,0 +1,82 @@
+use super::*;
+use burn_tensor::{ElementConversion, TensorData};
+
+#[test]
+fn should_support_unique_sorted() {
+    let tensor = TestTensor::<2>::from([[2.0, 1.0, 2.0], [3.0, 1.0, 0.5]]);
+
+    let unique = tensor.unique(true, true, true);
+
+    unique
+        .values
+        .into_data()
+        .assert_eq(&TensorData::from([0.5, 1.0, 2.0, 3.0]), false);
+    unique
+        .inverse
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[2, 1, 2], [3, 1, 0]]), false);
+    unique
+        .counts
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([1, 2, 2, 1]), false);
+}
+
+#[test]
+fn should_support_unique_first_occurrence_order() {
+    let tensor = TestTensor::<1>::from([3.0, 1.0, 3.0, 2.0, 1.0]);
+
+    let unique = tensor.unique(false, true, false);
+
+    assert!(unique.counts.is_none());
+    unique
+        .values
+        .into_data()
+        .assert_eq(&TensorData::from([3.0, 1.0, 2.0]), false);
+    unique
+        .inverse
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0, 1, 0, 2, 1]), false);
+}
+
+#[test]
+fn should_keep_each_nan_unique() {
+    let tensor = TestTensor::<1>::from([f32::NAN, 1.0, f32::NAN, 1.0]);
+
+    let unique = tensor.unique(true, false, true);
+
+    let values = unique.values.into_data();
+    let values = values.as_slice::<FloatElem>().unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0], 1.0f32.elem::<FloatElem>());
+    assert!(values[1].is_nan() && values[2].is_nan());
+    unique
+        .counts
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([2, 1, 1]), false);
+}
+
+#[test]
+fn should_support_unique_consecutive() {
+    let tensor = TestTensor::<1>::from([1.0, 1.0, 2.0, 2.0, 2.0, 1.0, 3.0]);
+
+    let unique = tensor.unique_consecutive(true, true);
+
+    unique
+        .values
+        .into_data()
+        .assert_eq(&TensorData::from([1.0, 2.0, 1.0, 3.0]), false);
+    unique
+        .inverse
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([0, 0, 1, 1, 1, 2, 3]), false);
+    unique
+        .counts
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([2, 3, 1, 1]), false);
+}
@@ -0,0 +1,41 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_xlogy() {
+    let x = TestTensor::<1>::from([0.0, 0.0, 2.0, 0.5]);
+    let y = TestTensor::<1>::from([0.0, 5.0, 3.0, 0.25]);
+
+    let output = x.xlogy(y);
+    let expected = TensorData::from([0.0, 0.0, 2.1972246, -0.6931472]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_support_xlog1py() {
+    let x = TestTensor::<1>::from([0.0, 0.0, 2.0, 0.5]);
+    let y = TestTensor::<1>::from([-1.0, 5.0, 3.0, -0.5]);
+
+    let output = x.xlog1py(y);
+    let expected = TensorData::from([0.0, 0.0, 2.7725887, -0.3465736]);
+
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
+}
+
+#[test]
+fn should_propagate_nan_in_xlogy() {
+    let x = TestTensor::<1>::from([0.0, 1.0, f32::NAN]);
+    let y = TestTensor::<1>::from([f32::NAN, f32::NAN, 1.0]);
+
+    let output = x.xlogy(y);
+
+    output
+        .is_nan()
+        .into_data()
+        .assert_eq(&TensorData::from([true, true, true]), false);
+}
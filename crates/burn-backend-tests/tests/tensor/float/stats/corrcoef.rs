@@ -0,0 +1,41 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn test_corrcoef() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0], [1.0, 3.0, 2.0, 4.0]]);
+
+    let output = tensor.corrcoef(1);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.0, 0.8], [0.8, 1.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_corrcoef_observations_first() {
+    let tensor = TestTensor::<2>::from([[1.0, 3.0], [2.0, 2.0], [3.0, 1.0]]);
+
+    let output = tensor.corrcoef(0);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.0, -1.0], [-1.0, 1.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_corrcoef_batched() {
+    let tensor = TestTensor::<3>::from([
+        [[1.0, 2.0, 3.0, 4.0], [1.0, 3.0, 2.0, 4.0]],
+        [[1.0, 2.0, 3.0, 4.0], [4.0, 3.0, 2.0, 1.0]],
+    ]);
+
+    let output = tensor.corrcoef(-1);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[[1.0, 0.8], [0.8, 1.0]], [[1.0, -1.0], [-1.0, 1.0]]]),
+        Tolerance::default(),
+    );
+}
@@ -76,3 +76,24 @@ fn test_cov_negative_dim() {
         .into_data()
         .assert_approx_eq::<FloatElem>(&expected, Tolerance::default());
 }
+
+#[test]
+fn test_cov_batched_last_dim() {
+    let data = TensorData::from([
+        [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+        [[3.0, -4.0, 5.0, 0.0], [0.5, 1.8, 0.2, -2.0]],
+    ]);
+    let tensor = TestTensor::<3>::from_data(data, &Default::default());
+
+    let output = tensor.cov_batched(2, 1);
+    let expected = TensorData::from([
+        [[2.48917, -1.73333], [-1.73333, 15.33333]],
+        [[15.33333, -1.73333], [-1.73333, 2.48917]],
+    ])
+    .convert::<FloatElem>();
+
+    let tolerance = Tolerance::default().set_half_precision_relative(1e-3);
+    output
+        .into_data()
+        .assert_approx_eq::<FloatElem>(&expected, tolerance);
+}
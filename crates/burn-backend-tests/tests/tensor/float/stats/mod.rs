@@ -1,7 +1,10 @@
 pub use super::*; // re-export test types
 
+mod corrcoef;
 mod cov;
 mod display;
 mod eye;
 mod median;
+mod mode;
+mod quantile;
 mod var;
@@ -0,0 +1,35 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn test_mode() {
+    let tensor = TestTensor::<2>::from([[3.0, 1.0, 3.0, 2.0], [1.0, 2.0, 2.0, 1.0]]);
+
+    let (values, indices) = tensor.clone().mode(1);
+
+    // Ties go to the smallest value.
+    values
+        .clone()
+        .into_data()
+        .assert_eq(&TensorData::from([[3.0], [1.0]]), false);
+    // The indices point to an occurrence of the mode.
+    tensor
+        .gather(1, indices)
+        .into_data()
+        .assert_eq(&values.into_data(), false);
+}
+
+#[test]
+fn test_mode_dim_0() {
+    let tensor = TestTensor::<2>::from([[1.0, 2.0], [1.0, 3.0], [2.0, 3.0]]);
+
+    let (values, indices) = tensor.mode(0);
+
+    values
+        .into_data()
+        .assert_eq(&TensorData::from([[1.0, 3.0]]), false);
+    let indices = indices.into_data();
+    let indices = indices.iter::<i64>().collect::<Vec<_>>();
+    assert!(indices[0] == 0 || indices[0] == 1);
+    assert!(indices[1] == 1 || indices[1] == 2);
+}
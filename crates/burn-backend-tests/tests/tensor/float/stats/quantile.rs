@@ -0,0 +1,98 @@
+use super::*;
+use burn_tensor::{QuantileInterpolation, TensorData, Tolerance};
+
+fn quantile_input() -> TestTensor<2> {
+    // Sorted rows: [-2.0, 0.2, 0.5, 1.8] and [-4.0, 0.0, 3.0, 5.0]
+    TestTensor::<2>::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]])
+}
+
+#[test]
+fn test_quantile_interpolations() {
+    // The quantile is at position 1.2 of the sorted rows.
+    let cases = [
+        (QuantileInterpolation::Linear, [[0.26], [0.6]]),
+        (QuantileInterpolation::Lower, [[0.2], [0.0]]),
+        (QuantileInterpolation::Higher, [[0.5], [3.0]]),
+        (QuantileInterpolation::Nearest, [[0.2], [0.0]]),
+        (QuantileInterpolation::Midpoint, [[0.35], [1.5]]),
+    ];
+
+    for (interpolation, expected) in cases {
+        quantile_input()
+            .quantile(0.4, 1, interpolation)
+            .into_data()
+            .assert_approx_eq::<FloatElem>(&TensorData::from(expected), Tolerance::default());
+    }
+}
+
+#[test]
+fn test_quantile_bounds() {
+    let tensor = quantile_input();
+
+    tensor
+        .clone()
+        .quantile(0.0, 1, QuantileInterpolation::Linear)
+        .into_data()
+        .assert_eq(&TensorData::from([[-2.0], [-4.0]]), false);
+    tensor
+        .quantile(1.0, -1, QuantileInterpolation::Linear)
+        .into_data()
+        .assert_eq(&TensorData::from([[1.8], [5.0]]), false);
+}
+
+#[test]
+fn test_quantile_dim_0() {
+    let tensor = quantile_input();
+
+    let output = tensor.quantile(0.5, 0, QuantileInterpolation::Linear);
+
+    output.into_data().assert_approx_eq::<FloatElem>(
+        &TensorData::from([[1.75, -1.1, 2.6, -1.0]]),
+        Tolerance::default(),
+    );
+}
+
+#[test]
+fn test_quantile_and_nanquantile_with_nan() {
+    let tensor = TestTensor::<2>::from([
+        [1.0, f32::NAN, 3.0, 2.0],
+        [f32::NAN, f32::NAN, f32::NAN, f32::NAN],
+        [4.0, 5.0, 6.0, 7.0],
+    ]);
+
+    let quantile = tensor
+        .clone()
+        .quantile(0.5, 1, QuantileInterpolation::Linear);
+    quantile
+        .clone()
+        .is_nan()
+        .into_data()
+        .assert_eq(&TensorData::from([[true], [true], [false]]), false);
+    quantile
+        .nan_to_num(0.0, None, None)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([[0.0], [0.0], [5.5]]),
+            Tolerance::default(),
+        );
+
+    let nanquantile = tensor.nanquantile(0.5, 1, QuantileInterpolation::Linear);
+    nanquantile
+        .clone()
+        .is_nan()
+        .into_data()
+        .assert_eq(&TensorData::from([[false], [true], [false]]), false);
+    nanquantile
+        .nan_to_num(0.0, None, None)
+        .into_data()
+        .assert_approx_eq::<FloatElem>(
+            &TensorData::from([[2.0], [0.0], [5.5]]),
+            Tolerance::default(),
+        );
+}
+
+#[test]
+#[should_panic]
+fn test_quantile_out_of_range() {
+    let _ = quantile_input().quantile(1.5, 1, QuantileInterpolation::Linear);
+}
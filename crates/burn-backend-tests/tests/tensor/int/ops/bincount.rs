@@ -0,0 +1,66 @@
+use super::*;
+use burn_tensor::{TensorData, Tolerance};
+
+#[test]
+fn should_support_bincount() {
+    let tensor = TestTensorInt::<1>::from([0, 2, 2, 3, 2]);
+
+    let counts = tensor.bincount(5);
+
+    counts
+        .into_data()
+        .assert_eq(&TensorData::from([1, 0, 3, 1, 0]), false);
+}
+
+#[test]
+fn should_ignore_values_outside_of_bincount_length() {
+    let tensor = TestTensorInt::<1>::from([-1, 0, 1, 1, 3, 7]);
+
+    let counts = tensor.bincount(2);
+
+    counts
+        .into_data()
+        .assert_eq(&TensorData::from([1, 2]), false);
+}
+
+#[test]
+fn should_support_bincount_with_few_bins() {
+    let tensor = TestTensorInt::<1>::arange(0..10_000, &Default::default()).remainder_scalar(3);
+
+    let counts = tensor.bincount(3);
+
+    counts
+        .into_data()
+        .assert_eq(&TensorData::from([3334, 3333, 3333]), false);
+}
+
+#[test]
+fn should_support_bincount_of_empty_tensor() {
+    let tensor = TestTensorInt::<1>::zeros([0], &Default::default());
+
+    let counts = tensor.bincount(2);
+
+    counts
+        .into_data()
+        .assert_eq(&TensorData::from([0, 0]), false);
+}
+
+#[test]
+fn should_support_bincount_weighted() {
+    let tensor = TestTensorInt::<1>::from([0, 2, 2, 5]);
+    let weights = TestTensor::<1>::from([0.5, 1.0, 2.0, 4.0]);
+
+    let sums = tensor.bincount_weighted(weights, 3);
+
+    sums.into_data()
+        .assert_approx_eq::<FloatElem>(&TensorData::from([0.5, 0.0, 3.0]), Tolerance::default());
+}
+
+#[test]
+#[should_panic]
+fn should_panic_bincount_weighted_with_mismatched_weights() {
+    let tensor = TestTensorInt::<1>::from([0, 1]);
+    let weights = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+    let _ = tensor.bincount_weighted(weights, 2);
+}
@@ -8,6 +8,7 @@ mod any;
 mod arange;
 mod arange_step;
 mod arg;
+mod bincount;
 mod bitwise;
 mod cartesian_grid;
 mod cast;
@@ -37,6 +38,7 @@ mod repeat;
 mod repeat_dim;
 mod reshape;
 mod roll;
+mod searchsorted;
 mod select;
 mod sign;
 mod slice;
@@ -50,3 +52,4 @@ mod topk;
 mod transpose;
 mod tri;
 mod unfold;
+mod unique;
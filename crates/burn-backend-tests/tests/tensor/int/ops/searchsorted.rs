@@ -0,0 +1,29 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn should_support_searchsorted_int() {
+    let sorted = TestTensorInt::<2>::from([[1, 3, 3, 5], [0, 10, 20, 30]]);
+    let values = TestTensorInt::<2>::from([[3, 6], [10, -1]]);
+
+    let left = sorted.clone().searchsorted(values.clone(), false);
+    let right = sorted.searchsorted(values, true);
+
+    left.into_data()
+        .assert_eq(&TensorData::from([[1, 4], [1, 0]]), false);
+    right
+        .into_data()
+        .assert_eq(&TensorData::from([[3, 4], [2, 0]]), false);
+}
+
+#[test]
+fn should_support_bucketize_int() {
+    let boundaries = TestTensorInt::<1>::from([0, 10, 100]);
+    let tensor = TestTensorInt::<2>::from([[-5, 0, 5], [10, 50, 500]]);
+
+    let buckets = tensor.bucketize(boundaries, true);
+
+    buckets
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 1, 1], [2, 2, 3]]), false);
+}
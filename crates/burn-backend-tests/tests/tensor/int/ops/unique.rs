@@ -0,0 +1,60 @@
+use super::*;
+use burn_tensor::TensorData;
+
+#[test]
+fn should_support_unique_int() {
+    let tensor = TestTensorInt::<2>::from([[4, 7, 4], [1, 7, 4]]);
+
+    let unique = tensor.unique(true, true, true);
+
+    unique
+        .values
+        .into_data()
+        .assert_eq(&TensorData::from([1, 4, 7]), false);
+    unique
+        .inverse
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[1, 2, 1], [0, 2, 1]]), false);
+    unique
+        .counts
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([1, 3, 2]), false);
+}
+
+#[test]
+fn should_support_unique_int_without_outputs() {
+    let tensor = TestTensorInt::<1>::from([5, 5, 5]);
+
+    let unique = tensor.unique(false, false, false);
+
+    assert!(unique.inverse.is_none());
+    assert!(unique.counts.is_none());
+    unique
+        .values
+        .into_data()
+        .assert_eq(&TensorData::from([5]), false);
+}
+
+#[test]
+fn should_support_unique_consecutive_int() {
+    let tensor = TestTensorInt::<2>::from([[0, 0, 1], [1, 0, 0]]);
+
+    let unique = tensor.unique_consecutive(true, true);
+
+    unique
+        .values
+        .into_data()
+        .assert_eq(&TensorData::from([0, 1, 0]), false);
+    unique
+        .inverse
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([[0, 0, 1], [1, 2, 2]]), false);
+    unique
+        .counts
+        .unwrap()
+        .into_data()
+        .assert_eq(&TensorData::from([2, 2, 2]), false);
+}
@@ -116,6 +116,7 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + SparseTensorOps<Self>
     + QTensorOps<Self>
     + TransactionOps<Self>
     + DistributedOps<Self>
@@ -0,0 +1,182 @@
+use crate::tensor::{FloatTensor, IntTensor};
+use crate::{Backend, Scalar, TensorMetadata, get_device_settings};
+use alloc::vec::Vec;
+use burn_std::{FloatDType, IntDType, Shape};
+
+/// Default `searchsorted` implementation for float tensors.
+///
+/// The sorted sequence has the shape `[..., M]` with the same leading dimensions as the values
+/// `[..., K]`, or the shape `[M]` to be shared by every row of values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Every value is compared against every boundary, which needs `O(values * boundaries)` memory.
+/// Ideally, it is supposed to be implemented by the backend with a binary search.
+pub fn searchsorted_float<B: Backend>(
+    sorted_sequence: FloatTensor<B>,
+    values: FloatTensor<B>,
+    right: bool,
+    out_dtype: IntDType,
+) -> IntTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&values.device()).bool_dtype;
+    let values_shape = values.shape();
+    let (sorted_shape, expanded_values_shape, expanded_shape) =
+        searchsorted_shapes(sorted_sequence.shape(), values.shape());
+    let rank = expanded_shape.num_dims();
+
+    let sorted_sequence = B::float_expand(
+        B::float_reshape(sorted_sequence, sorted_shape),
+        expanded_shape.clone(),
+    );
+    let values = B::float_expand(
+        B::float_reshape(values, expanded_values_shape),
+        expanded_shape,
+    );
+
+    // The insertion index is the number of boundaries before the value.
+    let before = match right {
+        false => B::float_lower(sorted_sequence, values, bool_dtype),
+        true => B::float_lower_equal(sorted_sequence, values, bool_dtype),
+    };
+    let count = B::int_sum_dim(B::bool_into_int(before, out_dtype), rank - 1);
+
+    B::int_reshape(count, values_shape)
+}
+
+/// Default `searchsorted` implementation for int tensors.
+///
+/// See [searchsorted_float] for details.
+pub fn searchsorted_int<B: Backend>(
+    sorted_sequence: IntTensor<B>,
+    values: IntTensor<B>,
+    right: bool,
+) -> IntTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&values.device()).bool_dtype;
+    let out_dtype: IntDType = values.dtype().into();
+    let values_shape = values.shape();
+    let (sorted_shape, expanded_values_shape, expanded_shape) =
+        searchsorted_shapes(sorted_sequence.shape(), values.shape());
+    let rank = expanded_shape.num_dims();
+
+    let sorted_sequence = B::int_expand(
+        B::int_reshape(sorted_sequence, sorted_shape),
+        expanded_shape.clone(),
+    );
+    let values = B::int_expand(
+        B::int_reshape(values, expanded_values_shape),
+        expanded_shape,
+    );
+
+    let before = match right {
+        false => B::int_lower(sorted_sequence, values, bool_dtype),
+        true => B::int_lower_equal(sorted_sequence, values, bool_dtype),
+    };
+    let count = B::int_sum_dim(B::bool_into_int(before, out_dtype), rank - 1);
+
+    B::int_reshape(count, values_shape)
+}
+
+/// Computes the shapes used to compare every value of `[..., K]` against every boundary of the
+/// sorted sequence `[..., M]` (or `[M]`, shared by all rows).
+///
+/// Returns the shape of the sorted sequence `[..., 1, M]`, of the values `[..., K, 1]`, and the
+/// shape `[..., K, M]` they are both expanded to.
+fn searchsorted_shapes(sorted_shape: Shape, values_shape: Shape) -> (Shape, Shape, Shape) {
+    let num_boundaries = sorted_shape[sorted_shape.num_dims() - 1];
+    let batch_dims = &values_shape[..values_shape.num_dims() - 1];
+    let num_values = values_shape[values_shape.num_dims() - 1];
+
+    let batch_sorted: Vec<usize> = match sorted_shape.num_dims() {
+        1 => batch_dims.iter().map(|_| 1).collect(),
+        _ => sorted_shape[..sorted_shape.num_dims() - 1].to_vec(),
+    };
+
+    let sorted = [&batch_sorted[..], &[1, num_boundaries]].concat();
+    let values = [batch_dims, &[num_values, 1]].concat();
+    let expanded = [batch_dims, &[num_values, num_boundaries]].concat();
+
+    (
+        Shape::from(sorted),
+        Shape::from(values),
+        Shape::from(expanded),
+    )
+}
+
+/// Default `histc` implementation.
+///
+/// Each value in `[min, max]` is mapped to its bin, and the bins are counted with
+/// [float_bincount](crate::ops::FloatTensorOps::float_bincount). Values outside of the range
+/// (and NaN) get a zero weight.
+pub fn histc<B: Backend>(
+    tensor: FloatTensor<B>,
+    bins: usize,
+    min: Scalar,
+    max: Scalar,
+) -> FloatTensor<B> {
+    let settings = get_device_settings::<B>(&tensor.device());
+    let dtype: FloatDType = tensor.dtype().into();
+    let num_elements = tensor.shape().num_elements();
+    let tensor = B::float_reshape(tensor, Shape::new([num_elements]));
+
+    let in_range = B::bool_and(
+        B::float_greater_equal_elem(tensor.clone(), min, settings.bool_dtype),
+        B::float_lower_equal_elem(tensor.clone(), max, settings.bool_dtype),
+    );
+
+    let scale = bins as f64 / (max.elem::<f64>() - min.elem::<f64>());
+    let indices = B::float_mul_scalar(B::float_sub_scalar(tensor, min), scale.into());
+    // `max` belongs to the last bin.
+    let indices = B::float_clamp(
+        B::float_floor(indices),
+        0f64.into(),
+        ((bins - 1) as f64).into(),
+    );
+    let indices = B::float_mask_fill(indices, B::bool_not(in_range.clone()), 0f64.into());
+    let indices = B::float_into_int(indices, settings.int_dtype);
+    let weights = B::bool_into_float(in_range, dtype);
+
+    B::float_bincount(indices, weights, bins)
+}
+
+/// Default weighted `bincount` implementation, scattering the weights into `length` bins.
+///
+/// Values outside of `0..length` are ignored.
+pub fn bincount_float<B: Backend>(
+    tensor: IntTensor<B>,
+    weights: FloatTensor<B>,
+    length: usize,
+) -> FloatTensor<B> {
+    let device = tensor.device();
+    let bool_dtype = get_device_settings::<B>(&device).bool_dtype;
+    let dtype: FloatDType = weights.dtype().into();
+
+    let ignored = B::bool_or(
+        B::int_lower_elem(tensor.clone(), 0i64.into(), bool_dtype),
+        B::int_greater_equal_elem(tensor.clone(), (length as i64).into(), bool_dtype),
+    );
+    let indices = B::int_mask_fill(tensor, ignored.clone(), 0i64.into());
+    let weights = B::float_mask_fill(weights, ignored, 0f64.into());
+
+    let bins = B::float_zeros(Shape::new([length]), &device, dtype);
+    B::float_scatter_add(0, bins, indices, weights)
+}
+
+/// Default `bincount` implementation, counting the occurrences of each value in `0..length`.
+///
+/// Values outside of `0..length` are ignored.
+pub fn bincount_int<B: Backend>(tensor: IntTensor<B>, length: usize) -> IntTensor<B> {
+    let device = tensor.device();
+    let bool_dtype = get_device_settings::<B>(&device).bool_dtype;
+    let dtype: IntDType = tensor.dtype().into();
+
+    let ignored = B::bool_or(
+        B::int_lower_elem(tensor.clone(), 0i64.into(), bool_dtype),
+        B::int_greater_equal_elem(tensor.clone(), (length as i64).into(), bool_dtype),
+    );
+    let counts = B::bool_into_int(B::bool_not(ignored.clone()), dtype);
+    let indices = B::int_mask_fill(tensor, ignored, 0i64.into());
+
+    let bins = B::int_zeros(Shape::new([length]), &device, dtype);
+    B::int_scatter_add(0, bins, indices, counts)
+}
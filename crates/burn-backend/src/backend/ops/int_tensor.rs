@@ -1,6 +1,9 @@
 use super::cat::cat_with_slice_assign;
+use super::histogram::{bincount_int, searchsorted_int};
 use super::repeat_dim::repeat_with_slice_assign;
+use super::scatter::scatter_nd_indices;
 use super::sort::{argsort, sort, sort_with_indices};
+use super::unique::{unique_consecutive_int, unique_int};
 use crate::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
 use crate::{Backend, Distribution, TensorData, TensorMetadata};
 use crate::{ExecutionError, Scalar, get_device_settings};
@@ -211,7 +214,9 @@ pub trait IntTensorOps<B: Backend> {
 
     /// Scatter elements into a tensor using the specified update operation.
     ///
-    /// Backend implementations may override this to support operations beyond add.
+    /// Additions use [int_scatter_add](Self::int_scatter_add) and the other update operations
+    /// are lowered to [int_scatter_nd](Self::int_scatter_nd) by default. Backend implementations
+    /// may override this with dedicated kernels.
     fn int_scatter(
         dim: usize,
         tensor: IntTensor<B>,
@@ -221,7 +226,10 @@ pub trait IntTensorOps<B: Backend> {
     ) -> IntTensor<B> {
         match update {
             IndexingUpdateOp::Add => Self::int_scatter_add(dim, tensor, indices, value),
-            other => unimplemented!("int_scatter with {other:?} update is not implemented"),
+            other => {
+                let indices = scatter_nd_indices::<B>(dim, indices);
+                Self::int_scatter_nd(tensor, indices, value, other)
+            }
         }
     }
 
@@ -1509,6 +1517,88 @@ pub trait IntTensorOps<B: Backend> {
         })
     }
 
+    /// Finds the indices where the `values` should be inserted to keep the `sorted_sequence`
+    /// sorted along its last dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The tensor sorted in ascending order along its last dimension, with
+    ///   the shape `[..., M]` where the leading dimensions match the ones of `values`, or the
+    ///   shape `[M]` to be shared by every row of `values`.
+    /// * `values` - The values to insert, with the shape `[..., K]`.
+    /// * `right` - If false, the first suitable index is returned (`sorted[i - 1] < v <= sorted[i]`),
+    ///   otherwise the last one (`sorted[i - 1] <= v < sorted[i]`).
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `values`, containing indices in `0..=M`.
+    fn int_searchsorted(
+        sorted_sequence: IntTensor<B>,
+        values: IntTensor<B>,
+        right: bool,
+    ) -> IntTensor<B> {
+        searchsorted_int::<B>(sorted_sequence, values, right)
+    }
+
+    /// Counts the occurrences of each value of a 1D int tensor into `length` bins.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The 1D int tensor of bin indices.
+    /// * `length` - The number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the shape `[length]`, where element `i` is the number of elements equal
+    /// to `i`. Values outside of `0..length` are ignored.
+    fn int_bincount(tensor: IntTensor<B>, length: usize) -> IntTensor<B> {
+        bincount_int::<B>(tensor, length)
+    }
+
+    /// Returns the unique elements of the flattened tensor.
+    ///
+    /// Because the number of unique elements depends on the tensor values, the output shape is
+    /// data-dependent: computing it may require synchronizing with the device, which is why this
+    /// operation is asynchronous.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `sorted` - Whether to return the unique elements in ascending order instead of the order
+    ///   of their first occurrence.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the 1D tensor of unique elements, the index of the unique element of
+    /// each input element (with the shape of the input), and the number of occurrences of each
+    /// unique element.
+    fn int_unique(
+        tensor: IntTensor<B>,
+        sorted: bool,
+    ) -> impl Future<Output = (IntTensor<B>, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        async move { unique_int::<B>(tensor, sorted).await }
+    }
+
+    /// Collapses the consecutive equal elements of the flattened tensor.
+    ///
+    /// Like [int_unique](IntTensorOps::int_unique), the output shape is data-dependent, which is
+    /// why this operation is asynchronous.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the 1D tensor of collapsed elements, the index of the collapsed element
+    /// of each input element (with the shape of the input), and the length of each run of equal
+    /// elements.
+    fn int_unique_consecutive(
+        tensor: IntTensor<B>,
+    ) -> impl Future<Output = (IntTensor<B>, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        async move { unique_consecutive_int::<B>(tensor).await }
+    }
+
     /// Bitwise AND operation for Int Tensors
     fn bitwise_and(lhs: IntTensor<B>, rhs: IntTensor<B>) -> IntTensor<B>;
 
@@ -8,13 +8,20 @@ mod transaction;
 
 pub(crate) mod argwhere;
 pub(crate) mod cat;
+pub(crate) mod histogram;
 pub(crate) mod repeat_dim;
+pub mod sampling;
+pub mod scatter;
 pub(crate) mod sort;
+pub mod sparse;
+pub(crate) mod special;
+pub(crate) mod unique;
 
 pub use activation::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
 pub use qtensor::*;
+pub use sparse::SparseTensorOps;
 pub use tensor::*;
 pub use transaction::*;
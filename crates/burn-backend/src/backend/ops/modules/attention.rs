@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::f32;
 #[allow(unused_imports)]
 use num_traits::Float as _;
@@ -5,9 +6,9 @@ use num_traits::Float as _;
 use burn_std::Shape;
 
 use crate::{
-    Backend, TensorMetadata, get_device_settings,
+    Backend, TensorData, TensorMetadata, get_device_settings,
     ops::AttentionModuleOptions,
-    tensor::{BoolTensor, FloatTensor},
+    tensor::{BoolTensor, FloatTensor, IntTensor},
 };
 
 /// Computes softmax(QKᵗ * scale) · V using separate kernels.
@@ -23,6 +24,9 @@ pub fn attention_fallback<B: Backend>(
     if let Some(softcap) = options.softcap {
         assert!(softcap > 0.0, "softcap must be positive, got {softcap}");
     }
+    if let Some(window) = options.sliding_window {
+        assert!(window > 0, "sliding_window must be positive, got {window}");
+    }
 
     // Attention scores: A = QKᵗ * scale
     let query_shape = query.shape().dims::<4>();
@@ -59,7 +63,23 @@ pub fn attention_fallback<B: Backend>(
         attention_scores
     };
 
-    // Additive bias (ALiBi, relative position biases, etc.)
+    // Sliding window masking: mask positions where col <= row - window (past the window)
+    let attention_scores = if let Some(window) = options.sliding_window {
+        let window_mask = build_sliding_window_mask::<B>(&attention_scores, window);
+        B::float_mask_fill(attention_scores, window_mask, f32::NEG_INFINITY.into())
+    } else {
+        attention_scores
+    };
+
+    // ALiBi linear biases: -slope * |row - col|, with one slope per head
+    let attention_scores = if let Some(max_bias) = options.alibi_max_bias {
+        let alibi_bias = build_alibi_bias::<B>(&attention_scores, max_bias);
+        B::float_add(attention_scores, alibi_bias)
+    } else {
+        attention_scores
+    };
+
+    // Additive bias (relative position biases, etc.)
     let attention_scores = if let Some(bias) = attn_bias {
         B::float_add(attention_scores, bias)
     } else {
@@ -85,6 +105,115 @@ pub fn attention_fallback<B: Backend>(
     B::float_matmul(softmax, value)
 }
 
+/// Computes the attention over the blocks of a paged KV cache by gathering the keys and values
+/// of each sequence, padded to the longest block table, then applying the attention with a mask.
+/// Serves as a fallback when the backend has no paged attention kernel.
+pub fn paged_attention_fallback<B: Backend>(
+    query: FloatTensor<B>,
+    key_blocks: FloatTensor<B>,
+    value_blocks: FloatTensor<B>,
+    block_tables: IntTensor<B>,
+    seq_lengths: IntTensor<B>,
+    options: AttentionModuleOptions,
+) -> FloatTensor<B> {
+    let [batch_size, num_heads, seq_q, head_dim] = query.shape().dims::<4>();
+    let [_num_blocks, num_kv_heads, block_size, _] = key_blocks.shape().dims::<4>();
+    let val_dim = value_blocks.shape()[3];
+    let max_blocks = block_tables.shape()[1];
+    let seq_k = max_blocks * block_size;
+    assert!(
+        num_heads.is_multiple_of(num_kv_heads),
+        "The number of heads ({num_heads}) must be divisible by the number of key and value heads ({num_kv_heads})"
+    );
+    let n_groups = num_heads / num_kv_heads;
+    let device = query.device();
+    let settings = get_device_settings::<B>(&device);
+    let int_dtype = seq_lengths.dtype().into();
+    let dtype = query.dtype();
+
+    // Keys and values `[batch_size, num_kv_heads, max_blocks * block_size, dim]`.
+    let indices = B::int_reshape(block_tables, Shape::new([batch_size * max_blocks]));
+    let gather = |blocks: FloatTensor<B>, dim: usize| {
+        let blocks = B::float_select(blocks, 0, indices.clone());
+        let blocks = B::float_reshape(
+            blocks,
+            Shape::new([batch_size, max_blocks, num_kv_heads, block_size, dim]),
+        );
+        let blocks = B::float_swap_dims(blocks, 1, 2);
+        B::float_reshape(blocks, Shape::new([batch_size, num_kv_heads, seq_k, dim]))
+    };
+    let key = gather(key_blocks, head_dim);
+    let value = gather(value_blocks, val_dim);
+
+    // Position of each query in its sequence `[batch_size, seq_q, 1]`, and of each key
+    // `[1, 1, seq_k]`. The causal mask also masks the padding after the last token.
+    let lengths = B::int_reshape(seq_lengths, Shape::new([batch_size, 1, 1]));
+    let rows = B::int_reshape(
+        B::int_arange(0..seq_q as i64, &device, int_dtype),
+        Shape::new([1, seq_q, 1]),
+    );
+    let positions = B::int_add(B::int_sub_scalar(lengths, (seq_q as i64).into()), rows);
+    let cols = B::int_reshape(
+        B::int_arange(0..seq_k as i64, &device, int_dtype),
+        Shape::new([1, 1, seq_k]),
+    );
+
+    let mut mask = B::int_greater(cols.clone(), positions.clone(), settings.bool_dtype);
+    if let Some(window) = options.sliding_window {
+        assert!(window > 0, "sliding_window must be positive, got {window}");
+        let cols_shifted = B::int_add_scalar(cols.clone(), (window as i64).into());
+        mask = B::bool_or(
+            mask,
+            B::int_lower_equal(cols_shifted, positions.clone(), settings.bool_dtype),
+        );
+    }
+
+    // ALiBi biases `-slope * |position - col|` of each head, on the positions of each sequence.
+    let alibi_bias = options.alibi_max_bias.map(|max_bias| {
+        let distance = B::int_abs(B::int_sub(positions, cols));
+        let distance = B::int_into_float(distance, dtype.into());
+        let distance = B::float_reshape(distance, Shape::new([batch_size, 1, seq_q, seq_k]));
+
+        let slopes = alibi_slopes(num_heads, max_bias)
+            .into_iter()
+            .map(|slope| -slope as f32)
+            .collect::<Vec<_>>();
+        let slopes = B::float_from_data(
+            TensorData::new(slopes, [1, num_heads, 1, 1]).convert_dtype(dtype),
+            &device,
+        );
+        B::float_reshape(
+            B::float_mul(distance, slopes),
+            Shape::new([batch_size, num_kv_heads, n_groups * seq_q, seq_k]),
+        )
+    });
+
+    // Query head `h` attends the key and value head `h / n_groups`.
+    let query = B::float_reshape(
+        query,
+        Shape::new([batch_size, num_kv_heads, n_groups * seq_q, head_dim]),
+    );
+    let mask = B::bool_reshape(mask, Shape::new([batch_size, 1, 1, seq_q, seq_k]));
+    let mask = B::bool_expand(
+        mask,
+        Shape::new([batch_size, num_kv_heads, n_groups, seq_q, seq_k]),
+    );
+    let mask = B::bool_reshape(
+        mask,
+        Shape::new([batch_size, num_kv_heads, n_groups * seq_q, seq_k]),
+    );
+
+    let options = AttentionModuleOptions {
+        is_causal: false,
+        sliding_window: None,
+        alibi_max_bias: None,
+        ..options
+    };
+    let context = B::attention(query, key, value, Some(mask), alibi_bias, options);
+
+    B::float_reshape(context, Shape::new([batch_size, num_heads, seq_q, val_dim]))
+}
+
 /// Builds a causal (upper-triangular) bool mask where `true` means "mask this position".
 /// Shape: [batch_size, num_heads, seq_q, seq_k], masking positions where col > row.
 fn build_causal_mask<B: Backend>(attention_scores: &FloatTensor<B>) -> BoolTensor<B> {
@@ -92,10 +221,72 @@ fn build_causal_mask<B: Backend>(attention_scores: &FloatTensor<B>) -> BoolTenso
     let scores_shape = attention_scores.shape().dims::<4>();
     let [batch_size, num_heads, seq_q, seq_k] = scores_shape;
     let settings = get_device_settings::<B>(&device);
+    let (rows_shifted, cols) = build_positions::<B>(attention_scores);
+
+    // mask where col > row + offset (upper triangle)
+    let mask_2d = B::int_lower(rows_shifted, cols, settings.bool_dtype);
+
+    // Reshape to [1, 1, seq_q, seq_k] then expand to [batch_size, num_heads, seq_q, seq_k]
+    let mask_4d = B::bool_reshape(mask_2d, Shape::new([1, 1, seq_q, seq_k]));
+    B::bool_expand(mask_4d, Shape::new([batch_size, num_heads, seq_q, seq_k]))
+}
+
+/// Builds a sliding window bool mask where `true` means "mask this position".
+/// Shape: [batch_size, num_heads, seq_q, seq_k], masking positions where col <= row - window.
+fn build_sliding_window_mask<B: Backend>(
+    attention_scores: &FloatTensor<B>,
+    window: usize,
+) -> BoolTensor<B> {
+    let device = attention_scores.device();
+    let scores_shape = attention_scores.shape().dims::<4>();
+    let [batch_size, num_heads, seq_q, seq_k] = scores_shape;
+    let settings = get_device_settings::<B>(&device);
+    let (rows_shifted, cols) = build_positions::<B>(attention_scores);
+
+    // mask where col + window <= row + offset (lower triangle, past the window)
+    let cols_shifted = B::int_add_scalar(cols, (window as i64).into());
+    let mask_2d = B::int_lower_equal(cols_shifted, rows_shifted, settings.bool_dtype);
+
+    let mask_4d = B::bool_reshape(mask_2d, Shape::new([1, 1, seq_q, seq_k]));
+    B::bool_expand(mask_4d, Shape::new([batch_size, num_heads, seq_q, seq_k]))
+}
+
+/// Builds the ALiBi bias `-slope * |row - col|` of each head.
+/// Shape: [1, num_heads, seq_q, seq_k], broadcast over the batch.
+fn build_alibi_bias<B: Backend>(
+    attention_scores: &FloatTensor<B>,
+    max_bias: f64,
+) -> FloatTensor<B> {
+    let device = attention_scores.device();
+    let dtype = attention_scores.dtype();
+    let [_batch_size, num_heads, seq_q, seq_k] = attention_scores.shape().dims::<4>();
+    let (rows_shifted, cols) = build_positions::<B>(attention_scores);
+
+    let distance = B::int_abs(B::int_sub(rows_shifted, cols));
+    let distance = B::int_into_float(distance, dtype.into());
+    let distance = B::float_reshape(distance, Shape::new([1, 1, seq_q, seq_k]));
+
+    let slopes = alibi_slopes(num_heads, max_bias)
+        .into_iter()
+        .map(|slope| -slope as f32)
+        .collect::<Vec<_>>();
+    let slopes = B::float_from_data(
+        TensorData::new(slopes, [1, num_heads, 1, 1]).convert_dtype(dtype),
+        &device,
+    );
+
+    B::float_mul(distance, slopes)
+}
+
+/// Builds the row (query) positions `[seq_q, 1]` and col (key) positions `[1, seq_k]`.
+///
+/// The row positions are offset so that the last query aligns with the last key (bottom-right
+/// corner), which handles cross-attention and decoding with a cache (seq_k > seq_q) correctly.
+fn build_positions<B: Backend>(attention_scores: &FloatTensor<B>) -> (IntTensor<B>, IntTensor<B>) {
+    let device = attention_scores.device();
+    let [_batch_size, _num_heads, seq_q, seq_k] = attention_scores.shape().dims::<4>();
+    let settings = get_device_settings::<B>(&device);
 
-    // row indices [seq_q, 1] and col indices [1, seq_k]
-    // Offset col indices so that the causal boundary aligns at the bottom-right corner,
-    // which handles cross-attention (seq_k > seq_q) correctly.
     let offset = seq_k as i64 - seq_q as i64;
     let rows = B::int_reshape(
         B::int_arange(0..seq_q as i64, &device, settings.int_dtype),
@@ -106,11 +297,44 @@ fn build_causal_mask<B: Backend>(attention_scores: &FloatTensor<B>) -> BoolTenso
         Shape::new([1, seq_k]),
     );
 
-    // mask where col > row + offset (upper triangle)
-    let rows_shifted = B::int_add_scalar(rows, offset.into());
-    let mask_2d = B::int_lower(rows_shifted, cols, settings.bool_dtype);
+    (B::int_add_scalar(rows, offset.into()), cols)
+}
 
-    // Reshape to [1, 1, seq_q, seq_k] then expand to [batch_size, num_heads, seq_q, seq_k]
-    let mask_4d = B::bool_reshape(mask_2d, Shape::new([1, 1, seq_q, seq_k]));
-    B::bool_expand(mask_4d, Shape::new([batch_size, num_heads, seq_q, seq_k]))
+/// Computes the ALiBi slope of each head, from the geometric sequence of the paper
+/// [Train Short, Test Long](https://arxiv.org/abs/2108.12409).
+///
+/// With `n` heads and `n` a power of two, the slope of head `h` is `2^(-max_bias * (h + 1) / n)`,
+/// so `max_bias = 8` gives `1/2, 1/4, ..., 1/256` for 8 heads. Otherwise, the first heads use the
+/// slopes of the closest lower power of two, and the remaining heads take every other slope of the
+/// next power of two, as in the reference implementation.
+pub fn alibi_slopes(num_heads: usize, max_bias: f64) -> Vec<f64> {
+    assert!(num_heads > 0, "ALiBi requires at least one head");
+
+    let closest = 1usize << num_heads.ilog2();
+    let slope = |exponent: usize, n: usize| 2f64.powf(-max_bias * exponent as f64 / n as f64);
+
+    (0..closest)
+        .map(|h| slope(h + 1, closest))
+        .chain((0..num_heads - closest).map(|h| slope(2 * h + 1, 2 * closest)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alibi_slopes() {
+        assert_eq!(
+            alibi_slopes(8, 8.0),
+            [
+                0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625, 0.0078125, 0.00390625
+            ]
+        );
+        // Non power of two: the slopes of 4 heads, then every other slope of 8 heads.
+        assert_eq!(
+            alibi_slopes(6, 8.0),
+            [0.25, 0.0625, 0.015625, 0.00390625, 0.5, 0.125]
+        );
+    }
 }
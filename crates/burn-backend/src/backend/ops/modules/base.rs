@@ -1,4 +1,4 @@
-use super::{conv, ctc, linear, pool};
+use super::{attention, conv, ctc, fft, interpolate, linear, pool};
 use crate::ops::unfold::{create_unfolding_weight, unfold4d_using_conv2d};
 use crate::tensor::{BoolTensor, FloatTensor, IntTensor};
 use crate::{Backend, TensorMetadata};
@@ -88,6 +88,23 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: IntTensor<B>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indices).
+#[derive(new)]
+pub struct MaxPool3dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
 #[derive(new)]
 pub struct InterpolateBackward<B: Backend> {
@@ -749,6 +766,189 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B>,
     ) -> MaxPool2dBackward<B>;
 
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<B> {
+        pool::avg_pool3d_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: FloatTensor<B>,
+        grad: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<B> {
+        pool::avg_pool3d_backward_from_2d::<B>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
+    }
+
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+    ) -> FloatTensor<B> {
+        B::max_pool3d_with_indices(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+            IntDType::I64,
+        )
+        .output
+    }
+
+    /// Three dimensional max pooling with indices.
+    ///
+    /// The indices are flattened over the `[depth, height, width]` dimensions of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indices(
+        x: FloatTensor<B>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<B> {
+        pool::max_pool3d_with_indices_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+            indices_dtype,
+        )
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indices) operation.
+    #[allow(clippy::too_many_arguments)]
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<B>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _ceil_mode: bool,
+        output_grad: FloatTensor<B>,
+        indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<B> {
+        pool::max_pool3d_backward_from_indices::<B>(x, output_grad, indices)
+    }
+
+    /// One dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d(x: FloatTensor<B>, output_size: usize) -> FloatTensor<B> {
+        B::adaptive_max_pool1d_with_indices(x, output_size, IntDType::I64).output
+    }
+    /// One dimensional adaptive max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d_with_indices(
+        x: FloatTensor<B>,
+        output_size: usize,
+        indices_dtype: IntDType,
+    ) -> MaxPool1dWithIndices<B> {
+        pool::adaptive_max_pool1d_with_indices_from_3d::<B>(x, output_size, indices_dtype)
+    }
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B>, output_size: [usize; 2]) -> FloatTensor<B> {
+        B::adaptive_max_pool2d_with_indices(x, output_size, IntDType::I64).output
+    }
+    /// Two dimensional adaptive max pooling with indices.
+    ///
+    /// The indices are flattened over the `[height, width]` dimensions of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<B>,
+        output_size: [usize; 2],
+        indices_dtype: IntDType,
+    ) -> MaxPool2dWithIndices<B> {
+        pool::adaptive_max_pool2d_with_indices_from_3d::<B>(x, output_size, indices_dtype)
+    }
+    /// Three dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn adaptive_max_pool3d(x: FloatTensor<B>, output_size: [usize; 3]) -> FloatTensor<B> {
+        B::adaptive_max_pool3d_with_indices(x, output_size, IntDType::I64).output
+    }
+    /// Three dimensional adaptive max pooling with indices.
+    ///
+    /// The indices are flattened over the `[depth, height, width]` dimensions of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn adaptive_max_pool3d_with_indices(
+        x: FloatTensor<B>,
+        output_size: [usize; 3],
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<B> {
+        pool::adaptive_max_pool3d_with_indices_from_dims::<B>(x, output_size, indices_dtype)
+    }
+    /// Backward pass for the [adaptive max pooling 3d](ModuleOps::adaptive_max_pool3d_with_indices)
+    /// operation.
+    fn adaptive_max_pool3d_with_indices_backward(
+        x: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        indices: IntTensor<B>,
+    ) -> MaxPool3dBackward<B> {
+        pool::max_pool3d_backward_from_indices::<B>(x, output_grad, indices)
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -768,9 +968,34 @@ pub trait ModuleOps<B: Backend> {
         options: InterpolateOptions,
     ) -> FloatTensor<B>;
 
+    /// Down/up samples the input over its three spatial dimensions, for example with a trilinear
+    /// interpolation when the mode is [Bilinear](InterpolateMode::Bilinear).
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, depth, height, width]`,
+    fn interpolate3d(
+        x: FloatTensor<B>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B> {
+        interpolate::interpolate3d_from_2d::<B>(x, output_size, options)
+    }
+
+    /// Backward pass for the [interpolate3d](ModuleOps::interpolate3d) operation.
+    fn interpolate3d_backward(
+        x: FloatTensor<B>,
+        grad: FloatTensor<B>,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B> {
+        interpolate::interpolate3d_backward_from_2d::<B>(x, grad, output_size, options)
+    }
+
     /// Computes scaled dot-product attention: softmax(QKᵗ * scale) · V,
     /// where scale defaults to 1/sqrt(head_dim). Optionally applies masking,
-    /// additive bias, causal masking, and softcap to the attention scores.
+    /// additive bias, causal and sliding window masking, ALiBi, and softcap to the
+    /// attention scores.
     ///
     /// # Arguments
     /// - `query`: Query tensor of shape `[batch_size, num_heads, seq_len_q, head_dim]`
@@ -779,8 +1004,9 @@ pub trait ModuleOps<B: Backend> {
     /// - `mask`: Optional boolean mask of shape `[batch_size, num_heads, seq_len_q, seq_len_k]`,
     ///   where `true` indicates positions to mask (i.e. set to -inf before softmax).
     /// - `attn_bias`: Optional float tensor of shape `[batch_size, num_heads, seq_len_q, seq_len_k]`
-    ///   added to the attention scores before softmax (e.g. ALiBi, relative position biases).
-    /// - `options`: Additional attention options (custom scale, softcap, causal masking).
+    ///   added to the attention scores before softmax (e.g. relative position biases).
+    /// - `options`: Additional attention options (custom scale, softcap, causal masking,
+    ///   sliding window and ALiBi).
     ///
     /// # Returns
     /// A tensor of shape `[batch_size, num_heads, seq_len_q, val_dim]`
@@ -798,6 +1024,45 @@ pub trait ModuleOps<B: Backend> {
         options: AttentionModuleOptions,
     ) -> FloatTensor<B>;
 
+    /// Computes the attention of a batch of sequences over their keys and values, stored in
+    /// fixed-size blocks of a paged KV cache.
+    ///
+    /// The queries are the last `seq_len_q` tokens of each sequence and attend causally to the
+    /// tokens of their sequence, found through its row of the block table. With fewer key and
+    /// value heads than query heads, each key and value head is shared by a group of query heads.
+    ///
+    /// # Arguments
+    /// - `query`: Query tensor of shape `[batch_size, num_heads, seq_len_q, head_dim]`
+    /// - `key_blocks`: Key blocks of shape `[num_blocks, num_kv_heads, block_size, head_dim]`
+    /// - `value_blocks`: Value blocks of shape `[num_blocks, num_kv_heads, block_size, val_dim]`
+    /// - `block_tables`: Int tensor of shape `[batch_size, max_blocks]` with the blocks of each
+    ///   sequence in order, padded with any valid block index.
+    /// - `seq_lengths`: Int tensor of shape `[batch_size]` with the number of tokens of each
+    ///   sequence, including the queries.
+    /// - `options`: Additional attention options (custom scale, softcap, sliding window and ALiBi,
+    ///   the last two applied to the positions of each sequence). The attention is always causal.
+    ///
+    /// # Returns
+    /// A tensor of shape `[batch_size, num_heads, seq_len_q, val_dim]`
+    /// representing the attended context per head.
+    fn paged_attention(
+        query: FloatTensor<B>,
+        key_blocks: FloatTensor<B>,
+        value_blocks: FloatTensor<B>,
+        block_tables: IntTensor<B>,
+        seq_lengths: IntTensor<B>,
+        options: AttentionModuleOptions,
+    ) -> FloatTensor<B> {
+        attention::paged_attention_fallback::<B>(
+            query,
+            key_blocks,
+            value_blocks,
+            block_tables,
+            seq_lengths,
+            options,
+        )
+    }
+
     /// Applies Layer Normalization over the last dimension of the input tensor.
     ///
     /// Computes `(x - mean) / sqrt(var + epsilon) * gamma + beta`, where `mean` and
@@ -948,4 +1213,36 @@ pub trait ModuleOps<B: Backend> {
         dim: usize,
         n: Option<usize>,
     ) -> FloatTensor<B>;
+
+    /// Complex-valued FFT with optional size parameter.
+    ///
+    /// The signal is given by its real and imaginary parts, which must have the same shape.
+    /// The same power-of-two constraints as [rfft](ModuleOps::rfft) apply: when `n` is `None`,
+    /// the signal must be a power of two along `dim`, otherwise it is truncated or zero-padded
+    /// to `n`. The output has `n` frequency bins along `dim`.
+    ///
+    /// Returns two tensors: the real part and the imaginary part.
+    fn fft(
+        signal_re: FloatTensor<B>,
+        signal_im: FloatTensor<B>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<B>, FloatTensor<B>) {
+        fft::fft_default::<B>(signal_re, signal_im, dim, n)
+    }
+
+    /// Inverse complex-valued FFT with optional size parameter.
+    ///
+    /// Same constraints as [fft](ModuleOps::fft). The output is scaled by `1 / n`, so that
+    /// `ifft(fft(x)) == x`.
+    ///
+    /// Returns two tensors: the real part and the imaginary part.
+    fn ifft(
+        spectrum_re: FloatTensor<B>,
+        spectrum_im: FloatTensor<B>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<B>, FloatTensor<B>) {
+        fft::ifft_default::<B>(spectrum_re, spectrum_im, dim, n)
+    }
 }
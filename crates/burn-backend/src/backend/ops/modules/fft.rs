@@ -0,0 +1,99 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_std::Slice;
+
+use crate::{Backend, TensorMetadata, tensor::FloatTensor};
+
+/// Default complex FFT implementation, composed from [rfft](super::ModuleOps::rfft).
+///
+/// By linearity, the transform of `x_re + i x_im` is `FFT(x_re) + i FFT(x_im)`. Both real
+/// transforms are computed with `rfft` and extended to the full spectrum through Hermitian
+/// symmetry, so backends that only implement `rfft` (and its autodiff) get a complex FFT for free.
+///
+/// # Arguments
+///
+/// * `signal_re` - Real part of the signal
+/// * `signal_im` - Imaginary part of the signal, with the same shape as `signal_re`
+/// * `dim` - Dimension along which to take the FFT
+/// * `n` - Optional FFT length (power of two), the signal is truncated or zero-padded to it
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrum, with `n` elements along `dim`.
+pub fn fft_default<B: Backend>(
+    signal_re: FloatTensor<B>,
+    signal_im: FloatTensor<B>,
+    dim: usize,
+    n: Option<usize>,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let fft_size = n.unwrap_or(signal_re.shape()[dim]);
+
+    let (xr, xi) = B::rfft(signal_re, dim, n);
+    let (yr, yi) = B::rfft(signal_im, dim, n);
+    let (xr, xi) = hermitian_extend::<B>(xr, xi, dim, fft_size);
+    let (yr, yi) = hermitian_extend::<B>(yr, yi, dim, fft_size);
+
+    // (Xr + i Xi) + i (Yr + i Yi) = (Xr - Yi) + i (Xi + Yr)
+    (B::float_sub(xr, yi), B::float_add(xi, yr))
+}
+
+/// Default inverse complex FFT implementation, using `IFFT(X) = conj(FFT(conj(X))) / N`.
+///
+/// # Arguments
+///
+/// * `spectrum_re` - Real part of the spectrum
+/// * `spectrum_im` - Imaginary part of the spectrum, with the same shape as `spectrum_re`
+/// * `dim` - Dimension along which to take the inverse FFT
+/// * `n` - Optional FFT length (power of two), the spectrum is truncated or zero-padded to it
+///
+/// # Returns
+///
+/// The real and imaginary parts of the signal, with `n` elements along `dim`.
+pub fn ifft_default<B: Backend>(
+    spectrum_re: FloatTensor<B>,
+    spectrum_im: FloatTensor<B>,
+    dim: usize,
+    n: Option<usize>,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let fft_size = n.unwrap_or(spectrum_re.shape()[dim]);
+    let scale = 1.0 / fft_size as f64;
+
+    let (re, im) = B::fft(spectrum_re, B::float_neg(spectrum_im), dim, n);
+
+    (
+        B::float_mul_scalar(re, scale.into()),
+        B::float_mul_scalar(im, (-scale).into()),
+    )
+}
+
+/// Extends a half-spectrum from `rfft` (`N/2 + 1` bins) to the full `N`-bin spectrum using
+/// Hermitian symmetry: `X[k] = conj(X[N-k])` for `k > N/2`.
+fn hermitian_extend<B: Backend>(
+    half_re: FloatTensor<B>,
+    half_im: FloatTensor<B>,
+    dim: usize,
+    full_len: usize,
+) -> (FloatTensor<B>, FloatTensor<B>) {
+    let rank = half_re.shape().num_dims();
+    let half_len = half_re.shape()[dim];
+
+    // For N <= 2, the half-spectrum already covers all bins.
+    if full_len <= half_len {
+        return (half_re, half_im);
+    }
+
+    // Bins 1..N/2 (skipping DC and Nyquist), reversed: X[N/2+1], ..., X[N-1].
+    let mut slices: Vec<Slice> = vec![Slice::full(); rank];
+    slices[dim] = Slice::new(1, Some((full_len - half_len + 1) as isize), 1);
+
+    let mirror_re = B::float_flip(B::float_slice(half_re.clone(), &slices), &[dim]);
+    let mirror_im = B::float_neg(B::float_flip(
+        B::float_slice(half_im.clone(), &slices),
+        &[dim],
+    ));
+
+    (
+        B::float_cat(vec![half_re, mirror_re], dim),
+        B::float_cat(vec![half_im, mirror_im], dim),
+    )
+}
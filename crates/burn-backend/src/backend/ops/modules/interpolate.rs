@@ -0,0 +1,77 @@
+use crate::tensor::FloatTensor;
+use crate::{Backend, TensorMetadata};
+use burn_std::{InterpolateOptions, Shape};
+
+/// Interpolates the `[height, width]` planes, then the depth with a 2D interpolation over
+/// `[depth, height * width]`.
+///
+/// All the interpolation modes are separable and leave a dimension unchanged when its size is
+/// kept, so the two passes are equivalent to a 3D interpolation.
+pub fn interpolate3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [depth_out, height_out, width_out] = output_size;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::interpolate(x, [height_out, width_out], options.clone());
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::interpolate(x, [depth_out, height_out * width_out], options);
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+/// Backward of [interpolate3d_from_2d], with the two passes in reverse order.
+pub fn interpolate3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [depth_out, height_out, width_out] = output_size;
+
+    // The input of the depth pass, only its shape is used.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &x.device(),
+        grad.dtype().into(),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::interpolate_backward(
+        x_depth,
+        grad,
+        [depth_out, height_out * width_out],
+        options.clone(),
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::interpolate_backward(x, grad, [height_out, width_out], options);
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
@@ -10,6 +10,9 @@ pub mod attention;
 /// Module with CTC loss operations.
 pub mod ctc;
 
+/// Module with FFT operations.
+pub mod fft;
+
 /// Module with unfold operations.
 pub mod unfold;
 
@@ -19,6 +22,9 @@ pub mod pool;
 /// Module for grid_sample operations
 pub mod grid_sample;
 
+/// Module with interpolate operations.
+pub mod interpolate;
+
 mod base;
 
 pub use base::*;
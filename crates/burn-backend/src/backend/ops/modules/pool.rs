@@ -1,8 +1,13 @@
+use alloc::vec::Vec;
+
 use crate::tensor::{FloatTensor, IntTensor};
 use crate::{Backend, TensorMetadata};
-use burn_std::{IntDType, Shape};
+use burn_std::{IntDType, Shape, Slice};
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{
+    MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dWithIndices, MaxPool3dBackward,
+    MaxPool3dWithIndices,
+};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B>,
@@ -176,3 +181,279 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+// The 3D pooling defaults are separable: the window is a box, so pooling over height and width
+// first and then over depth gives the same result. With `count_include_pad = false`, the divisor
+// of the average is the product of the valid counts along each axis, which is also separable.
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+    ceil_mode: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+        ceil_mode,
+    );
+    let [_, _, height_out, width_out] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+        ceil_mode,
+    );
+    let [_, _, depth_out, _] = x.shape().dims();
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    grad: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+    ceil_mode: bool,
+) -> FloatTensor<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+    let [_, _, depth_out, height_out, width_out] = grad.shape().dims();
+
+    // The input of the depth pass, only its shape is used.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &x.device(),
+        grad.dtype().into(),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+        ceil_mode,
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+        ceil_mode,
+    );
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+    ceil_mode: bool,
+    indices_dtype: IntDType,
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let MaxPool2dWithIndices {
+        output,
+        indices: indices_hw,
+    } = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+        ceil_mode,
+        indices_dtype,
+    );
+    let [_, _, height_out, width_out] = output.shape().dims();
+    let shape_hw = Shape::from([batch_size, channels, depth, height_out * width_out]);
+
+    let output = B::float_reshape(output, shape_hw.clone());
+    let MaxPool2dWithIndices {
+        output,
+        indices: indices_d,
+    } = B::max_pool2d_with_indices(
+        output,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+        ceil_mode,
+        indices_dtype,
+    );
+    let [_, _, depth_out, _] = output.shape().dims();
+
+    // The depth pass indexes the flattened `[depth, height_out * width_out]` plane, where each
+    // window stays in its column.
+    let index_d = B::int_div_scalar(indices_d, ((height_out * width_out) as i64).into());
+    let indices_hw = B::int_gather(2, B::int_reshape(indices_hw, shape_hw), index_d.clone());
+    let indices = B::int_add(
+        B::int_mul_scalar(index_d, ((height * width) as i64).into()),
+        indices_hw,
+    );
+
+    let shape_out = Shape::from([batch_size, channels, depth_out, height_out, width_out]);
+    MaxPool3dWithIndices::new(
+        B::float_reshape(output, shape_out.clone()),
+        B::int_reshape(indices, shape_out),
+    )
+}
+
+/// Routes the output gradient of a 3D max pooling to the input positions of the flat indices.
+pub(crate) fn max_pool3d_backward_from_indices<B: Backend>(
+    x: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    indices: IntTensor<B>,
+) -> MaxPool3dBackward<B> {
+    let shape = x.shape();
+    let [batch_size, channels, depth, height, width] = shape.dims();
+    let [_, _, depth_out, height_out, width_out] = output_grad.shape().dims();
+    let num_outputs = depth_out * height_out * width_out;
+
+    let x_grad = B::float_zeros(
+        Shape::from([batch_size, channels, depth * height * width]),
+        &x.device(),
+        output_grad.dtype().into(),
+    );
+    let output_grad = B::float_reshape(
+        output_grad,
+        Shape::from([batch_size, channels, num_outputs]),
+    );
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, num_outputs]));
+    let x_grad = B::float_scatter_add(2, x_grad, indices, output_grad);
+
+    MaxPool3dBackward::new(B::float_reshape(x_grad, shape))
+}
+
+/// Adaptive max pooling along a single dimension, returning the position of the maximum along
+/// that dimension.
+fn adaptive_max_pool_dim<B: Backend>(
+    x: FloatTensor<B>,
+    dim: usize,
+    output_size: usize,
+    indices_dtype: IntDType,
+) -> (FloatTensor<B>, IntTensor<B>) {
+    let shape = x.shape();
+    let size = shape[dim];
+
+    let (values, indices): (Vec<_>, Vec<_>) = (0..output_size)
+        .map(|i| {
+            let start = (i * size) / output_size;
+            let end = ((i + 1) * size).div_ceil(output_size);
+
+            let mut slices = alloc::vec![Slice::full(); shape.num_dims()];
+            slices[dim] = Slice::new(start as isize, Some(end as isize), 1);
+            let window = B::float_slice(x.clone(), &slices);
+            let (value, index) = B::float_max_dim_with_indices(window, dim, indices_dtype);
+
+            (value, B::int_add_scalar(index, (start as i64).into()))
+        })
+        .unzip();
+
+    (B::float_cat(values, dim), B::int_cat(indices, dim))
+}
+
+pub(crate) fn adaptive_max_pool3d_with_indices_from_dims<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 3],
+    indices_dtype: IntDType,
+) -> MaxPool3dWithIndices<B> {
+    let [_, _, _, height, width] = x.shape().dims();
+
+    let (output, index_w) = adaptive_max_pool_dim::<B>(x, 4, output_size[2], indices_dtype);
+    let (output, index_h) = adaptive_max_pool_dim::<B>(output, 3, output_size[1], indices_dtype);
+    let index_w = B::int_gather(3, index_w, index_h.clone());
+    let (output, index_d) = adaptive_max_pool_dim::<B>(output, 2, output_size[0], indices_dtype);
+    let index_h = B::int_gather(2, index_h, index_d.clone());
+    let index_w = B::int_gather(2, index_w, index_d.clone());
+
+    let index_dh = B::int_add(B::int_mul_scalar(index_d, (height as i64).into()), index_h);
+    let indices = B::int_add(B::int_mul_scalar(index_dh, (width as i64).into()), index_w);
+
+    MaxPool3dWithIndices::new(output, indices)
+}
+
+pub(crate) fn adaptive_max_pool1d_with_indices_from_3d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: usize,
+    indices_dtype: IntDType,
+) -> MaxPool1dWithIndices<B> {
+    let [batch_size, channels, length] = x.shape().dims();
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, 1, 1, length]));
+    let x = B::adaptive_max_pool3d_with_indices(x, [1, 1, output_size], indices_dtype);
+
+    let shape = Shape::from([batch_size, channels, output_size]);
+    MaxPool1dWithIndices::new(
+        B::float_reshape(x.output, shape.clone()),
+        B::int_reshape(x.indices, shape),
+    )
+}
+
+pub(crate) fn adaptive_max_pool2d_with_indices_from_3d<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+    indices_dtype: IntDType,
+) -> MaxPool2dWithIndices<B> {
+    let [batch_size, channels, height, width] = x.shape().dims();
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, 1, height, width]));
+    let x =
+        B::adaptive_max_pool3d_with_indices(x, [1, output_size[0], output_size[1]], indices_dtype);
+
+    // With a depth of one, the flat indices over `[depth, height, width]` are the indices over
+    // `[height, width]`.
+    let shape = Shape::from([batch_size, channels, output_size[0], output_size[1]]);
+    MaxPool2dWithIndices::new(
+        B::float_reshape(x.output, shape.clone()),
+        B::int_reshape(x.indices, shape),
+    )
+}
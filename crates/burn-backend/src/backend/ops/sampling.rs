@@ -0,0 +1,245 @@
+//! Default implementations of the distributions that are sampled from uniform and normal random
+//! values, for backends without dedicated kernels.
+
+use burn_std::reader::try_read_sync;
+use burn_std::{Distribution, FloatDType, Shape};
+use num_traits::Float as _;
+
+use crate::tensor::{BoolTensor, Device, FloatTensor};
+use crate::{Backend, TensorMetadata, get_device_settings};
+
+/// Rate from which the Poisson distribution is sampled with the transformed rejection method
+/// instead of the inversion.
+const POISSON_LARGE_RATE: f64 = 10.0;
+
+/// Maximum number of rounds of a rejection method. Each candidate is accepted with a probability
+/// above 85%, so it's only reached when the parameters can't accept any, e.g. a non-positive
+/// gamma shape.
+const MAX_ROUNDS: usize = 32;
+
+/// Default implementation of [float_random](crate::ops::FloatTensorOps::float_random) for the
+/// [exponential](Distribution::Exponential), [gamma](Distribution::Gamma),
+/// [beta](Distribution::Beta) and [Poisson](Distribution::Poisson) distributions.
+///
+/// The other distributions are forwarded to `float_random`.
+pub fn random_default<B: Backend>(
+    shape: Shape,
+    distribution: Distribution,
+    device: &Device<B>,
+    dtype: FloatDType,
+) -> FloatTensor<B> {
+    match distribution {
+        Distribution::Exponential(rate) => {
+            // Inversion, with `1 - u` in `(0, 1]`.
+            let uniform = B::float_random(shape, Distribution::Default, device, dtype);
+            let log = B::float_log(B::float_add_scalar(B::float_neg(uniform), 1f64.into()));
+            B::float_div_scalar(B::float_neg(log), rate.into())
+        }
+        Distribution::Gamma(alpha, scale) => {
+            let alpha = B::float_full(shape, alpha.into(), device, dtype);
+            B::float_mul_scalar(B::float_random_gamma(alpha), scale.into())
+        }
+        Distribution::Beta(alpha, beta) => {
+            let x =
+                B::float_random_gamma(B::float_full(shape.clone(), alpha.into(), device, dtype));
+            let y = B::float_random_gamma(B::float_full(shape, beta.into(), device, dtype));
+            B::float_div(x.clone(), B::float_add(x, y))
+        }
+        Distribution::Poisson(rate) if rate < POISSON_LARGE_RATE => {
+            poisson_inversion::<B>(shape, rate, device, dtype)
+        }
+        Distribution::Poisson(rate) => poisson_rejection::<B>(shape, rate, device, dtype),
+        distribution => B::float_random(shape, distribution, device, dtype),
+    }
+}
+
+/// Default implementation of [float_random_gamma](crate::ops::FloatTensorOps::float_random_gamma).
+///
+/// Samples `Gamma(alpha, 1)` with the method of Marsaglia and Tsang, where
+/// `Gamma(alpha) = Gamma(alpha + 1) * U^(1 / alpha)` for `alpha < 1`. New candidates are drawn
+/// until every element has accepted one, so the samples are exact, up to [MAX_ROUNDS] rounds
+/// after which the remaining samples are NaN.
+pub fn gamma<B: Backend>(alpha: FloatTensor<B>) -> FloatTensor<B> {
+    let shape = alpha.shape();
+    let device = alpha.device();
+    let dtype = alpha.dtype().into();
+    let bool_dtype = get_device_settings::<B>(&device).bool_dtype;
+
+    let boosted = B::float_lower_elem(alpha.clone(), 1f64.into(), bool_dtype);
+    let shape_param = B::float_mask_where(
+        alpha.clone(),
+        boosted.clone(),
+        B::float_add_scalar(alpha.clone(), 1f64.into()),
+    );
+    let d = B::float_sub_scalar(shape_param, (1.0 / 3.0f64).into());
+    let c = B::float_recip(B::float_sqrt(B::float_mul_scalar(d.clone(), 9f64.into())));
+
+    // Every sample is positive, so a negative value marks the elements still to be accepted.
+    let mut result = B::float_full(shape.clone(), (-1f64).into(), &device, dtype);
+    for _ in 0..MAX_ROUNDS {
+        let pending = B::float_lower_elem(result.clone(), 0f64.into(), bool_dtype);
+        if !any::<B>(pending.clone()) {
+            break;
+        }
+
+        let z = B::float_random(
+            shape.clone(),
+            Distribution::Normal(0.0, 1.0),
+            &device,
+            dtype,
+        );
+        let u = B::float_random(shape.clone(), Distribution::Default, &device, dtype);
+
+        // v = (1 + c z)^3 is accepted when ln(u) < z^2 / 2 + d - d v + d ln(v), which is false
+        // for a non-positive v since its logarithm is NaN or -inf.
+        let t = B::float_add_scalar(B::float_mul(z.clone(), c.clone()), 1f64.into());
+        let v = B::float_mul(B::float_mul(t.clone(), t.clone()), t);
+        let bound = B::float_add(
+            B::float_mul_scalar(B::float_mul(z.clone(), z), 0.5f64.into()),
+            B::float_mul(
+                B::float_add(
+                    B::float_add_scalar(B::float_neg(v.clone()), 1f64.into()),
+                    B::float_log(v.clone()),
+                ),
+                d.clone(),
+            ),
+        );
+        let accept = B::bool_and(pending, B::float_lower(B::float_log(u), bound, bool_dtype));
+        result = B::float_mask_where(result, accept, B::float_mul(v, d.clone()));
+    }
+    let result = reject_pending::<B>(result);
+
+    let u = B::float_random(shape, Distribution::Default, &device, dtype);
+    let boost = B::float_powf(u, B::float_recip(alpha));
+    B::float_mask_where(result.clone(), boosted, B::float_mul(result, boost))
+}
+
+/// Replaces the samples that are still negative after [MAX_ROUNDS] rounds with NaN.
+fn reject_pending<B: Backend>(samples: FloatTensor<B>) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&samples.device()).bool_dtype;
+    let pending = B::float_lower_elem(samples.clone(), 0f64.into(), bool_dtype);
+
+    B::float_mask_fill(samples, pending, f64::NAN.into())
+}
+
+/// Whether any element of `tensor` is true.
+fn any<B: Backend>(tensor: BoolTensor<B>) -> bool {
+    let msg = "Failed to synchronously read the rejected samples.";
+    try_read_sync(B::bool_into_data(B::bool_any(tensor)))
+        .expect(msg)
+        .expect(msg)
+        .iter::<bool>()
+        .next()
+        .unwrap()
+}
+
+/// Samples `Poisson(rate)` by inversion, counting the values of the cumulative distribution
+/// function that are below a uniform sample.
+fn poisson_inversion<B: Backend>(
+    shape: Shape,
+    rate: f64,
+    device: &Device<B>,
+    dtype: FloatDType,
+) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(device).bool_dtype;
+    let uniform = B::float_random(shape.clone(), Distribution::Default, device, dtype);
+    let mut result = B::float_zeros(shape, device, dtype);
+
+    let mut probability = (-rate).exp();
+    let mut cdf = probability;
+    let mut k = 0.0;
+    // The tail beyond `1 - 1e-12` is negligible, and reached before `k = 64` for small rates.
+    while cdf < 1.0 - 1e-12 && k < 64.0 {
+        let above = B::float_greater_elem(uniform.clone(), cdf.into(), bool_dtype);
+        result = B::float_add(result, B::bool_into_float(above, dtype));
+        k += 1.0;
+        probability *= rate / k;
+        cdf += probability;
+    }
+    result
+}
+
+/// Samples `Poisson(rate)` with the transformed rejection method of W. Hörmann, "The transformed
+/// rejection method for generating Poisson random variables".
+fn poisson_rejection<B: Backend>(
+    shape: Shape,
+    rate: f64,
+    device: &Device<B>,
+    dtype: FloatDType,
+) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(device).bool_dtype;
+    let b = 0.931 + 2.53 * rate.sqrt();
+    let a = -0.059 + 0.02483 * b;
+    let log_inv_alpha = (1.1239 + 1.1328 / (b - 3.4)).ln();
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+    // Every sample is non-negative, so a negative value marks the elements still to be accepted.
+    let mut result = B::float_full(shape.clone(), (-1f64).into(), device, dtype);
+    for _ in 0..MAX_ROUNDS {
+        let pending = B::float_lower_elem(result.clone(), 0f64.into(), bool_dtype);
+        if !any::<B>(pending.clone()) {
+            break;
+        }
+
+        let u = B::float_sub_scalar(
+            B::float_random(shape.clone(), Distribution::Default, device, dtype),
+            0.5f64.into(),
+        );
+        let v = B::float_random(shape.clone(), Distribution::Default, device, dtype);
+        let us = B::float_add_scalar(B::float_neg(B::float_abs(u.clone())), 0.5f64.into());
+
+        // k = floor((2a / us + b) u + rate + 0.43)
+        let k = B::float_floor(B::float_add_scalar(
+            B::float_mul(
+                B::float_add_scalar(
+                    B::float_mul_scalar(B::float_recip(us.clone()), (2.0 * a).into()),
+                    b.into(),
+                ),
+                u,
+            ),
+            (rate + 0.43).into(),
+        ));
+
+        // Candidates inside the squeeze are accepted without evaluating the density.
+        let squeeze = B::bool_and(
+            B::float_greater_equal_elem(us.clone(), 0.07f64.into(), bool_dtype),
+            B::float_lower_equal_elem(v.clone(), v_r.into(), bool_dtype),
+        );
+
+        // ln(v) + ln(1 / alpha) - ln(a / us^2 + b) <= -rate + k ln(rate) - lgamma(k + 1)
+        let lhs = B::float_sub(
+            B::float_add_scalar(B::float_log(v.clone()), log_inv_alpha.into()),
+            B::float_log(B::float_add_scalar(
+                B::float_mul_scalar(
+                    B::float_recip(B::float_mul(us.clone(), us.clone())),
+                    a.into(),
+                ),
+                b.into(),
+            )),
+        );
+        let rhs = B::float_sub(
+            B::float_add_scalar(
+                B::float_mul_scalar(k.clone(), rate.ln().into()),
+                (-rate).into(),
+            ),
+            B::float_lgamma(B::float_add_scalar(k.clone(), 1f64.into())),
+        );
+        let rejected = B::bool_or(
+            B::float_lower_elem(k.clone(), 0f64.into(), bool_dtype),
+            B::bool_and(
+                B::float_lower_elem(us.clone(), 0.013f64.into(), bool_dtype),
+                B::float_greater(v, us, bool_dtype),
+            ),
+        );
+        let accept = B::bool_or(
+            squeeze,
+            B::bool_and(
+                B::bool_not(rejected),
+                B::float_lower_equal(lhs, rhs, bool_dtype),
+            ),
+        );
+        let accept = B::bool_and(pending, accept);
+        result = B::float_mask_where(result, accept, k);
+    }
+    reject_pending::<B>(result)
+}
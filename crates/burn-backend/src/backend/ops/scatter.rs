@@ -0,0 +1,44 @@
+//! Default implementation of the scatter update operations beyond add, for backends without
+//! dedicated kernels.
+
+use alloc::vec::Vec;
+use burn_std::{IntDType, Shape};
+
+use crate::tensor::IntTensor;
+use crate::{Backend, TensorMetadata};
+
+/// Converts the indices of a scatter along `dim` to the indices of the equivalent
+/// [scatter_nd](crate::ops::FloatTensorOps::float_scatter_nd).
+///
+/// The indices of shape `[d_0, ..., d_{n-1}]` become full coordinates of shape
+/// `[d_0, ..., d_{n-1}, n]`, where the coordinate along `dim` is the scatter index and the others
+/// are the position of the index itself. The values of the scatter are used unchanged.
+pub fn scatter_nd_indices<B: Backend>(dim: usize, indices: IntTensor<B>) -> IntTensor<B> {
+    let shape = indices.shape();
+    let rank = shape.num_dims();
+    let device = indices.device();
+    let dtype: IntDType = indices.dtype().into();
+
+    let mut coord_shape = shape.iter().copied().collect::<Vec<_>>();
+    coord_shape.push(1);
+    let coord_shape = Shape::from(coord_shape);
+
+    let coords = (0..rank)
+        .map(|d| {
+            let coord = if d == dim {
+                indices.clone()
+            } else {
+                let mut arange_shape = alloc::vec![1; rank];
+                arange_shape[d] = shape[d];
+                let arange = B::int_arange(0..shape[d] as i64, &device, dtype);
+                B::int_expand(
+                    B::int_reshape(arange, Shape::from(arange_shape)),
+                    shape.clone(),
+                )
+            };
+            B::int_reshape(coord, coord_shape.clone())
+        })
+        .collect::<Vec<_>>();
+
+    B::int_cat(coords, rank)
+}
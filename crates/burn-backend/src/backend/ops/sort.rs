@@ -30,7 +30,12 @@ macro_rules! sort_dispatch_dtype {
                     DType::U32 => $fn::<u32, $index_ty>($data, $($args),*),
                     DType::U16 => $fn::<u16, $index_ty>($data, $($args),*),
                     DType::U8 => $fn::<u8, $index_ty>($data, $($args),*),
-                    DType::Bool(_) | DType::QFloat(_) => {
+                    DType::F8E4M3
+                    | DType::F8E5M2
+                    | DType::Bool(_)
+                    | DType::QFloat(_)
+                    | DType::Complex64
+                    | DType::Complex32 => {
                         unimplemented!("not supported for sorting operations")
                     }
                 }
@@ -64,7 +69,12 @@ macro_rules! sort_dispatch_dtype {
             DType::U32 => $fn::<u32>($data, $($args),*),
             DType::U16 => $fn::<u16>($data, $($args),*),
             DType::U8 => $fn::<u8>($data, $($args),*),
-            DType::Bool(_) | DType::QFloat(_) => {
+            DType::F8E4M3
+            | DType::F8E5M2
+            | DType::Bool(_)
+            | DType::QFloat(_)
+            | DType::Complex64
+            | DType::Complex32 => {
                 unimplemented!("not supported for sorting operations")
             }
         }
@@ -0,0 +1,105 @@
+use crate::tensor::{FloatTensor, IntTensor};
+use crate::{Backend, TensorMetadata};
+use burn_std::{Shape, Slice};
+
+/// Sparse tensor operations.
+///
+/// A sparse matrix isn't a backend primitive: it's passed to the operations as its index tensors
+/// and its values, in the coordinate (COO) or compressed sparse row (CSR) layout. The default
+/// implementations are composed of dense gather and scatter operations, and backends can override
+/// them with kernels that iterate over the stored values directly.
+pub trait SparseTensorOps<B: Backend> {
+    /// Multiplies a sparse matrix in the coordinate layout with a dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_indices` - The row of each stored value, of shape `[nnz]`.
+    /// * `col_indices` - The column of each stored value, of shape `[nnz]`.
+    /// * `values` - The stored values, of shape `[nnz]`.
+    /// * `dense` - The dense matrix of shape `[k, n]`.
+    /// * `rows` - The number of rows `m` of the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// The dense matrix of shape `[m, n]`. Duplicate indices are summed.
+    fn sparse_coo_spmm(
+        row_indices: IntTensor<B>,
+        col_indices: IntTensor<B>,
+        values: FloatTensor<B>,
+        dense: FloatTensor<B>,
+        rows: usize,
+    ) -> FloatTensor<B> {
+        let [_, cols] = dense.shape().dims();
+        let nnz = values.shape().num_elements();
+        let device = dense.device();
+        let dtype = dense.dtype().into();
+
+        let values = B::float_reshape(values, Shape::new([nnz, 1]));
+        let contributions = B::float_mul(B::float_select(dense, 0, col_indices), values);
+        B::float_select_add(
+            B::float_zeros(Shape::new([rows, cols]), &device, dtype),
+            0,
+            row_indices,
+            contributions,
+        )
+    }
+
+    /// Multiplies a sparse matrix in the compressed sparse row layout with a dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `crow_indices` - The offsets of the rows in the values, of shape `[m + 1]`.
+    /// * `col_indices` - The column of each stored value, of shape `[nnz]`.
+    /// * `values` - The stored values, of shape `[nnz]`.
+    /// * `dense` - The dense matrix of shape `[k, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The dense matrix of shape `[m, n]`.
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<B>,
+        col_indices: IntTensor<B>,
+        values: FloatTensor<B>,
+        dense: FloatTensor<B>,
+    ) -> FloatTensor<B> {
+        let rows = crow_indices.shape()[0] - 1;
+        let nnz = values.shape().num_elements();
+        let row_indices = expand_csr_rows::<B>(crow_indices, nnz);
+        B::sparse_coo_spmm(row_indices, col_indices, values, dense, rows)
+    }
+}
+
+/// Expands the row offsets of a compressed sparse row matrix into the row of each stored value.
+///
+/// # Arguments
+///
+/// * `crow_indices` - The offsets of the rows in the values, of shape `[m + 1]`.
+/// * `nnz` - The number of stored values.
+///
+/// # Returns
+///
+/// The row of each stored value, of shape `[nnz]`.
+pub fn expand_csr_rows<B: Backend>(crow_indices: IntTensor<B>, nnz: usize) -> IntTensor<B> {
+    let rows = crow_indices.shape()[0] - 1;
+    let device = crow_indices.device();
+    let dtype = crow_indices.dtype().into();
+    if rows <= 1 {
+        return B::int_zeros(Shape::new([nnz]), &device, dtype);
+    }
+
+    // Every row offset after the first one marks the start of a new row, so the row of a value is
+    // the number of offsets at or before its position. Offsets of trailing empty rows are equal to
+    // `nnz`, hence the extra position.
+    let starts = B::int_slice(crow_indices, &[Slice::new(1, Some(rows as isize), 1)]);
+    let markers = B::int_ones(Shape::new([rows - 1]), &device, dtype);
+    let counts = B::int_select_add(
+        B::int_zeros(Shape::new([nnz + 1]), &device, dtype),
+        0,
+        starts,
+        markers,
+    );
+    B::int_slice(
+        B::int_cumsum(counts, 0),
+        &[Slice::new(0, Some(nnz as isize), 1)],
+    )
+}
@@ -0,0 +1,353 @@
+//! Default implementations of the special functions, composed of elementwise tensor operations.
+//!
+//! # Remarks
+//!
+//! These are fallback solutions that are used only when the backend doesn't have the corresponding
+//! implementation. Every branch of the approximations is evaluated for all the elements before
+//! selecting the right one, so backends are expected to provide a dedicated kernel.
+
+use crate::tensor::FloatTensor;
+use crate::{Backend, Scalar, TensorMetadata, get_device_settings};
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+/// `0.5 * ln(2 * pi)`.
+const HALF_LN_2PI: f64 = 0.918_938_533_204_672_8;
+
+/// Lanczos approximation of the gamma function with `g = 7` and 9 coefficients.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// `B_2k / (2k)!` for `k = 1..=5`, with `B_2k` the Bernoulli numbers.
+const BERNOULLI_OVER_FACTORIAL: [f64; 5] = [
+    1.0 / 12.0,
+    -1.0 / 720.0,
+    1.0 / 30_240.0,
+    -1.0 / 1_209_600.0,
+    1.0 / 47_900_160.0,
+];
+
+/// Number of recurrence steps applied before the asymptotic expansion of digamma.
+const DIGAMMA_SHIFT: usize = 6;
+
+/// Coefficients of the erfinv approximation of M. Giles, "Approximating the erfinv function",
+/// for `w = -ln(1 - x^2) < 5` (in `w - 2.5`) and otherwise (in `sqrt(w) - 3`).
+const ERFINV_CENTRAL: [f64; 9] = [
+    2.810_226_36e-8,
+    3.432_739_39e-7,
+    -3.523_387_7e-6,
+    -4.391_506_54e-6,
+    2.185_808_7e-4,
+    -1.253_725_03e-3,
+    -4.177_681_64e-3,
+    2.466_407_27e-1,
+    1.501_409_41,
+];
+const ERFINV_TAIL: [f64; 9] = [
+    -2.002_142_57e-4,
+    1.009_505_58e-4,
+    1.349_343_22e-3,
+    -3.673_428_44e-3,
+    5.739_507_73e-3,
+    -7.622_461_3e-3,
+    9.438_870_47e-3,
+    1.001_674_06,
+    2.832_976_82,
+];
+
+/// Polynomial approximations of the modified Bessel functions from Abramowitz and Stegun
+/// (9.8.1 to 9.8.4), in `(x / 3.75)^2` for `|x| < 3.75` and in `3.75 / |x|` otherwise.
+const I0_SMALL: [f64; 7] = [
+    0.004_581_3,
+    0.036_076_8,
+    0.265_973_2,
+    1.206_749_2,
+    3.089_942_4,
+    3.515_622_9,
+    1.0,
+];
+const I0_LARGE: [f64; 9] = [
+    0.003_923_77,
+    -0.016_476_33,
+    0.026_355_37,
+    -0.020_577_06,
+    0.009_162_81,
+    -0.001_575_65,
+    0.002_253_19,
+    0.013_285_92,
+    0.398_942_28,
+];
+const I1_SMALL: [f64; 7] = [
+    0.000_324_11,
+    0.003_015_32,
+    0.026_587_33,
+    0.150_849_34,
+    0.514_988_69,
+    0.878_905_94,
+    0.5,
+];
+const I1_LARGE: [f64; 9] = [
+    -0.004_200_59,
+    0.017_876_54,
+    -0.028_953_12,
+    0.022_829_67,
+    -0.010_315_55,
+    0.001_638_01,
+    -0.003_620_18,
+    -0.039_880_24,
+    0.398_942_28,
+];
+
+/// Default `lgamma` implementation, with the Lanczos approximation for `x >= 0.5` and the
+/// reflection formula `lgamma(x) = ln(pi / |sin(pi x)|) - lgamma(1 - x)` otherwise.
+pub fn lgamma<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+    let reflect = B::float_lower_elem(tensor.clone(), 0.5f64.into(), bool_dtype);
+    let x = B::float_mask_where(
+        tensor.clone(),
+        reflect.clone(),
+        one_minus::<B>(tensor.clone()),
+    );
+
+    // Lanczos approximation of lgamma(z + 1).
+    let z = B::float_sub_scalar(x, 1f64.into());
+    let mut series = B::float_add_scalar(
+        B::float_mul_scalar(
+            B::float_recip(B::float_add_scalar(z.clone(), 1f64.into())),
+            LANCZOS_COEFFICIENTS[1].into(),
+        ),
+        LANCZOS_COEFFICIENTS[0].into(),
+    );
+    for (i, coefficient) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(2) {
+        let term = B::float_recip(B::float_add_scalar(z.clone(), (i as f64).into()));
+        series = B::float_add(series, B::float_mul_scalar(term, (*coefficient).into()));
+    }
+    let t = B::float_add_scalar(z.clone(), (LANCZOS_G + 0.5).into());
+    let lanczos = B::float_add(
+        B::float_sub(
+            B::float_mul(
+                B::float_add_scalar(z, 0.5f64.into()),
+                B::float_log(t.clone()),
+            ),
+            t,
+        ),
+        B::float_add_scalar(B::float_log(series), HALF_LN_2PI.into()),
+    );
+
+    let sin = B::float_abs(B::float_sin(B::float_mul_scalar(tensor, PI.into())));
+    let reflection = B::float_sub(
+        B::float_log(B::float_mul_scalar(B::float_recip(sin), PI.into())),
+        lanczos.clone(),
+    );
+    B::float_mask_where(lanczos, reflect, reflection)
+}
+
+/// Default `digamma` implementation.
+///
+/// The argument is shifted with the recurrence `psi(x) = psi(x + 1) - 1 / x` before using the
+/// asymptotic expansion, and the reflection formula `psi(x) = psi(1 - x) - pi / tan(pi x)`
+/// is used for `x < 0.5`.
+pub fn digamma<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+    let reflect = B::float_lower_elem(tensor.clone(), 0.5f64.into(), bool_dtype);
+    let x = B::float_mask_where(
+        tensor.clone(),
+        reflect.clone(),
+        one_minus::<B>(tensor.clone()),
+    );
+
+    let mut shift = B::float_recip(x.clone());
+    for k in 1..DIGAMMA_SHIFT {
+        let term = B::float_recip(B::float_add_scalar(x.clone(), (k as f64).into()));
+        shift = B::float_add(shift, term);
+    }
+
+    // psi(y) ~ ln(y) - 1 / 2y - sum(B_2k / (2k y^2k))
+    let y = B::float_add_scalar(x, (DIGAMMA_SHIFT as f64).into());
+    let r = B::float_recip(B::float_mul(y.clone(), y.clone()));
+    let coefficients: Vec<f64> = BERNOULLI_OVER_FACTORIAL
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(k, c)| -c * factorial(2 * k + 1))
+        .collect();
+    let series = B::float_mul(r.clone(), horner::<B>(r, &coefficients));
+    let asymptotic = B::float_sub(
+        B::float_log(y.clone()),
+        B::float_mul_scalar(B::float_recip(y), 0.5f64.into()),
+    );
+    let digamma = B::float_sub(B::float_add(asymptotic, series), shift);
+
+    let tan = B::float_tan(B::float_mul_scalar(tensor, PI.into()));
+    let reflection = B::float_sub(
+        digamma.clone(),
+        B::float_mul_scalar(B::float_recip(tan), PI.into()),
+    );
+    B::float_mask_where(digamma, reflect, reflection)
+}
+
+/// Default `polygamma` implementation.
+///
+/// For `n >= 1`, `psi^(n)(x) = (-1)^(n + 1) n! zeta(n + 1, x)`, where the Hurwitz zeta function
+/// is computed with the recurrence `zeta(s, x) = zeta(s, x + 1) + x^-s` followed by its
+/// Euler-Maclaurin expansion.
+pub fn polygamma<B: Backend>(tensor: FloatTensor<B>, n: usize) -> FloatTensor<B> {
+    if n == 0 {
+        return B::float_digamma(tensor);
+    }
+
+    let s = (n + 1) as f64;
+    let shift = DIGAMMA_SHIFT + n;
+
+    let mut zeta = B::float_powf_scalar(tensor.clone(), (-s).into());
+    for k in 1..shift {
+        let term = B::float_powf_scalar(
+            B::float_add_scalar(tensor.clone(), (k as f64).into()),
+            (-s).into(),
+        );
+        zeta = B::float_add(zeta, term);
+    }
+
+    // zeta(s, y) ~ y^-s (y / (s - 1) + 1 / 2 + y sum(B_2k / (2k)! (s)_(2k - 1) y^-2k))
+    let y = B::float_add_scalar(tensor, (shift as f64).into());
+    let r = B::float_recip(B::float_mul(y.clone(), y.clone()));
+    let coefficients: Vec<f64> = BERNOULLI_OVER_FACTORIAL
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(k, c)| c * rising_factorial(s, 2 * k + 1))
+        .collect();
+    let series = B::float_mul(
+        B::float_mul(r.clone(), horner::<B>(r, &coefficients)),
+        y.clone(),
+    );
+    let tail = B::float_add(
+        B::float_add_scalar(
+            B::float_div_scalar(y.clone(), (s - 1.0).into()),
+            0.5f64.into(),
+        ),
+        series,
+    );
+    let zeta = B::float_add(
+        zeta,
+        B::float_mul(B::float_powf_scalar(y, (-s).into()), tail),
+    );
+
+    let sign = if n % 2 == 0 { -1.0 } else { 1.0 };
+    B::float_mul_scalar(zeta, (sign * factorial(n)).into())
+}
+
+/// Default `erfinv` implementation, with the approximation of M. Giles refined by a Newton step.
+pub fn erfinv<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+
+    // w = -ln((1 - x) (1 + x))
+    let w = B::float_neg(B::float_log(B::float_mul(
+        one_minus::<B>(tensor.clone()),
+        B::float_add_scalar(tensor.clone(), 1f64.into()),
+    )));
+    let central = horner::<B>(
+        B::float_sub_scalar(w.clone(), 2.5f64.into()),
+        &ERFINV_CENTRAL,
+    );
+    let tail = horner::<B>(
+        B::float_sub_scalar(B::float_sqrt(w.clone()), 3f64.into()),
+        &ERFINV_TAIL,
+    );
+    let is_central = B::float_lower_elem(w, 5f64.into(), bool_dtype);
+    let y = B::float_mul(
+        B::float_mask_where(tail, is_central, central),
+        tensor.clone(),
+    );
+
+    // y -= (erf(y) - x) / erf'(y)
+    let error = B::float_sub(B::float_erf(y.clone()), tensor.clone());
+    let derivative = B::float_mul_scalar(
+        B::float_exp(B::float_neg(B::float_mul(y.clone(), y.clone()))),
+        FRAC_2_SQRT_PI.into(),
+    );
+    let y = B::float_sub(y, B::float_div(error, derivative));
+
+    let one = B::float_equal_elem(tensor.clone(), 1f64.into(), bool_dtype);
+    let minus_one = B::float_equal_elem(tensor, (-1f64).into(), bool_dtype);
+    let y = B::float_mask_fill(y, one, f64::INFINITY.into());
+    B::float_mask_fill(y, minus_one, f64::NEG_INFINITY.into())
+}
+
+/// Default `i0` implementation, the modified Bessel function of the first kind of order 0.
+pub fn i0<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    bessel_i::<B>(tensor, &I0_SMALL, &I0_LARGE, false)
+}
+
+/// Default `i1` implementation, the modified Bessel function of the first kind of order 1.
+pub fn i1<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    bessel_i::<B>(tensor, &I1_SMALL, &I1_LARGE, true)
+}
+
+/// Evaluates the Abramowitz and Stegun approximation of `I0` or `I1` (which is `odd`).
+fn bessel_i<B: Backend>(
+    tensor: FloatTensor<B>,
+    small: &[f64],
+    large: &[f64],
+    odd: bool,
+) -> FloatTensor<B> {
+    let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+    let abs = B::float_abs(tensor.clone());
+
+    // |x| < 3.75, the odd function is `x * p((x / 3.75)^2)`.
+    let t = B::float_div_scalar(tensor.clone(), 3.75f64.into());
+    let mut small = horner::<B>(B::float_mul(t.clone(), t), small);
+    if odd {
+        small = B::float_mul(small, tensor.clone());
+    }
+
+    // |x| >= 3.75, `p(3.75 / |x|) e^|x| / sqrt(|x|)`.
+    let u = B::float_mul_scalar(B::float_recip(abs.clone()), 3.75f64.into());
+    let mut large = B::float_div(
+        B::float_mul(horner::<B>(u, large), B::float_exp(abs.clone())),
+        B::float_sqrt(abs.clone()),
+    );
+    if odd {
+        large = B::float_mul(large, B::float_sign(tensor));
+    }
+
+    let is_small = B::float_lower_elem(abs, 3.75f64.into(), bool_dtype);
+    B::float_mask_where(large, is_small, small)
+}
+
+/// Evaluates the polynomial with the given coefficients (highest degree first) with Horner's
+/// method.
+fn horner<B: Backend>(x: FloatTensor<B>, coefficients: &[f64]) -> FloatTensor<B> {
+    let scalar = |value: f64| -> Scalar { value.into() };
+    let mut result = B::float_add_scalar(
+        B::float_mul_scalar(x.clone(), scalar(coefficients[0])),
+        scalar(coefficients[1]),
+    );
+    for coefficient in &coefficients[2..] {
+        result = B::float_add_scalar(B::float_mul(result, x.clone()), scalar(*coefficient));
+    }
+    result
+}
+
+fn one_minus<B: Backend>(tensor: FloatTensor<B>) -> FloatTensor<B> {
+    B::float_add_scalar(B::float_neg(tensor), 1f64.into())
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).fold(1.0, |acc, k| acc * k as f64)
+}
+
+/// `s (s + 1) ... (s + count - 1)`.
+fn rising_factorial(s: f64, count: usize) -> f64 {
+    (0..count).fold(1.0, |acc, k| acc * (s + k as f64))
+}
@@ -1,7 +1,11 @@
 use super::cat::cat_with_slice_assign;
 use super::grid_sample::float_grid_sample_2d_ref;
+use super::histogram::{bincount_float, histc, searchsorted_float};
 use super::repeat_dim::repeat_with_slice_assign;
+use super::scatter::scatter_nd_indices;
 use super::sort::{argsort, sort, sort_with_indices};
+use super::special;
+use super::unique::{unique_consecutive_float, unique_float};
 use crate::ops::GridSampleOptions;
 use crate::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
 use crate::{Backend, Distribution, TensorData, get_device_settings};
@@ -43,6 +47,20 @@ pub trait FloatTensorOps<B: Backend> {
         dtype: FloatDType,
     ) -> FloatTensor<B>;
 
+    /// Samples from the gamma distributions whose shape parameters are given by `alpha`, with a
+    /// scale of 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The positive shape parameter of each sample.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `alpha` and the random values.
+    fn float_random_gamma(alpha: FloatTensor<B>) -> FloatTensor<B> {
+        super::sampling::gamma::<B>(alpha)
+    }
+
     /// Creates a new tensor with zeros.
     ///
     /// # Arguments
@@ -455,7 +473,9 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Scatter elements into a tensor using the specified update operation.
     ///
-    /// Backend implementations may override this to support operations beyond add.
+    /// Additions use [float_scatter_add](Self::float_scatter_add) and the other update operations
+    /// are lowered to [float_scatter_nd](Self::float_scatter_nd) by default. Backend implementations
+    /// may override this with dedicated kernels.
     fn float_scatter(
         dim: usize,
         tensor: FloatTensor<B>,
@@ -465,7 +485,10 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> FloatTensor<B> {
         match update {
             IndexingUpdateOp::Add => Self::float_scatter_add(dim, tensor, indices, value),
-            other => unimplemented!("float_scatter with {other:?} update is not implemented"),
+            other => {
+                let indices = scatter_nd_indices::<B>(dim, indices);
+                Self::float_scatter_nd(tensor, indices, value, other)
+            }
         }
     }
 
@@ -871,6 +894,21 @@ pub trait FloatTensorOps<B: Backend> {
         false
     }
 
+    /// Marks a tensor that requires gradients to receive per-sample gradients.
+    ///
+    /// The gradient of the tensor gets an extra leading dimension with the gradient of each
+    /// sample of the batch, instead of their sum.
+    fn float_set_per_sample_grad(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        // Should only be overridden by autodiff backends.
+        tensor
+    }
+
+    /// Returns true if the tensor receives per-sample gradients.
+    fn float_is_per_sample_grad(_tensor: &FloatTensor<B>) -> bool {
+        // Should only be overridden by autodiff backends.
+        false
+    }
+
     /// Sum of all elements in a tensor.
     ///
     /// # Arguments
@@ -948,6 +986,88 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the mean of all elements in `tensor` along `dim`.
     fn float_mean_dim(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B>;
 
+    /// Log of the sum of the exponentials of the elements along a dimension.
+    ///
+    /// The elements are shifted by their maximum before taking the exponential, so the result
+    /// doesn't overflow for large inputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to reduce.
+    /// * `dim` - The dimension along which to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with `log(sum(exp(tensor)))` along `dim`.
+    fn float_logsumexp_dim(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+        let max = B::float_max_dim(B::float_detach(tensor.clone()), dim);
+        // An infinite maximum would give `inf - inf = NaN`, so those slices aren't shifted:
+        // `-inf` everywhere gives `log(0) = -inf`, and any `inf` gives `inf`.
+        let is_inf = B::float_is_inf(max.clone(), bool_dtype);
+        let max = B::float_mask_fill(max, is_inf, 0f64.into());
+
+        let exp = B::float_exp(B::float_sub(tensor, max.clone()));
+        B::float_add(B::float_log(B::float_sum_dim(exp, dim)), max)
+    }
+
+    /// Sum of the elements along a dimension, treating NaN as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension along which to sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the sum of the non-NaN elements in `tensor` along `dim`.
+    fn float_nansum_dim(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+        let is_nan = B::float_is_nan(tensor.clone(), bool_dtype);
+        B::float_sum_dim(B::float_mask_fill(tensor, is_nan, 0f64.into()), dim)
+    }
+
+    /// Mean of the elements along a dimension, ignoring NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to mean.
+    /// * `dim` - The dimension along which to mean.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the mean of the non-NaN elements in `tensor` along `dim`, or NaN where all
+    /// of them are NaN.
+    fn float_nanmean_dim(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+        let dtype: FloatDType = tensor.dtype().into();
+        let is_nan = B::float_is_nan(tensor.clone(), bool_dtype);
+        let count = B::float_sum_dim(B::bool_into_float(B::bool_not(is_nan.clone()), dtype), dim);
+        let sum = B::float_sum_dim(B::float_mask_fill(tensor, is_nan, 0f64.into()), dim);
+
+        B::float_div(sum, count)
+    }
+
+    /// Maximum of the elements along a dimension, ignoring NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to reduce.
+    /// * `dim` - The dimension along which to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the maximum of the non-NaN elements in `tensor` along `dim`, or NaN where
+    /// all of them are NaN.
+    fn float_nanmax_dim(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+        let is_nan = B::float_is_nan(tensor.clone(), bool_dtype);
+        let all_nan = B::bool_all_dim(is_nan.clone(), dim);
+        let tensor = B::float_mask_fill(tensor, is_nan, f64::NEG_INFINITY.into());
+
+        B::float_mask_fill(B::float_max_dim(tensor, dim), all_nan, f64::NAN.into())
+    }
+
     /// Computes the cumulative sum of elements along a dimension.
     ///
     /// # Arguments
@@ -1000,6 +1120,61 @@ pub trait FloatTensorOps<B: Backend> {
     /// of all elements up to and including that position along the dimension.
     fn float_cummax(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B>;
 
+    /// Computes the linear recurrence `h[t] = a[t] * h[t - 1] + b[t]` along a dimension, with
+    /// `h[-1] = 0`.
+    ///
+    /// The default implementation is a parallel (Hillis-Steele) scan over the associative
+    /// composition of the affine maps `h -> a * h + b`, taking `ceil(log2(n))` steps of
+    /// element-wise operations. Backends can override it with a dedicated kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The decay factors.
+    /// * `b` - The inputs, with the same shape as `a`.
+    /// * `dim` - The dimension along which to run the recurrence.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `b` holding every state `h[t]` along the dimension.
+    fn float_linear_recurrence(a: FloatTensor<B>, b: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        let shape = b.shape();
+        let size = shape[dim];
+        let device = b.device();
+        let dtype: FloatDType = b.dtype().into();
+
+        let mut a = a;
+        let mut b = b;
+        let mut offset = 1;
+        while offset < size {
+            let mut slices = alloc::vec![Slice::full(); shape.num_dims()];
+            slices[dim] = Slice::new(0, Some((size - offset) as isize), 1);
+            let mut pad_shape = shape.clone();
+            pad_shape[dim] = offset;
+
+            // The maps `offset` steps back, padded with the identity `h -> 1 * h + 0`.
+            let a_prev = B::float_cat(
+                alloc::vec![
+                    B::float_ones(pad_shape.clone(), &device, dtype),
+                    B::float_slice(a.clone(), &slices),
+                ],
+                dim,
+            );
+            let b_prev = B::float_cat(
+                alloc::vec![
+                    B::float_zeros(pad_shape, &device, dtype),
+                    B::float_slice(b.clone(), &slices),
+                ],
+                dim,
+            );
+
+            b = B::float_add(B::float_mul(a.clone(), b_prev), b);
+            a = B::float_mul(a, a_prev);
+            offset *= 2;
+        }
+
+        b
+    }
+
     /// Converts a tensor to another floating point data type.
     ///
     /// # Arguments
@@ -1441,6 +1616,87 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf(tensor: FloatTensor<B>) -> FloatTensor<B>;
 
+    /// Returns a new tensor with the inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of, with values in `[-1, 1]`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values.
+    fn float_erfinv(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::erfinv::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the natural logarithm of the absolute value of the gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the log-gamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with log-gamma function values.
+    fn float_lgamma(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::lgamma::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the digamma function values, the derivative of the log-gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the digamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with digamma function values.
+    fn float_digamma(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::digamma::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the `n`-th derivative of the digamma function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the polygamma function of.
+    /// * `n` - The order of the derivative, where `0` is the digamma function.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with polygamma function values.
+    fn float_polygamma(tensor: FloatTensor<B>, n: usize) -> FloatTensor<B> {
+        special::polygamma::<B>(tensor, n)
+    }
+
+    /// Returns a new tensor with the modified Bessel function of the first kind of order 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the Bessel function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `I0` values.
+    fn float_i0(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::i0::<B>(tensor)
+    }
+
+    /// Returns a new tensor with the modified Bessel function of the first kind of order 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the Bessel function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with `I1` values.
+    fn float_i1(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        special::i1::<B>(tensor)
+    }
+
     /// Concatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1922,6 +2178,121 @@ pub trait FloatTensorOps<B: Backend> {
         })
     }
 
+    /// Finds the indices where the `values` should be inserted to keep the `sorted_sequence`
+    /// sorted along its last dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The tensor sorted in ascending order along its last dimension, with
+    ///   the shape `[..., M]` where the leading dimensions match the ones of `values`, or the
+    ///   shape `[M]` to be shared by every row of `values`.
+    /// * `values` - The values to insert, with the shape `[..., K]`.
+    /// * `right` - If false, the first suitable index is returned (`sorted[i - 1] < v <= sorted[i]`),
+    ///   otherwise the last one (`sorted[i - 1] <= v < sorted[i]`).
+    /// * `out_dtype` - The output tensor dtype.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `values`, containing indices in `0..=M`.
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<B>,
+        values: FloatTensor<B>,
+        right: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<B> {
+        searchsorted_float::<B>(sorted_sequence, values, right, out_dtype)
+    }
+
+    /// Computes the histogram of a tensor, with `bins` equal-width bins between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor, flattened to compute the histogram.
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower end of the range (inclusive).
+    /// * `max` - The upper end of the range (inclusive), must be greater than `min`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the shape `[bins]` containing the number of elements in each bin.
+    /// Elements outside of `[min, max]` and NaN are ignored.
+    fn float_histc(
+        tensor: FloatTensor<B>,
+        bins: usize,
+        min: Scalar,
+        max: Scalar,
+    ) -> FloatTensor<B> {
+        histc::<B>(tensor, bins, min, max)
+    }
+
+    /// Sums the `weights` of each value of a 1D int tensor into `length` bins.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The 1D int tensor of bin indices.
+    /// * `weights` - The weight of each element of `tensor`, with the same shape.
+    /// * `length` - The number of bins.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the shape `[length]`, where element `i` is the sum of the weights of the
+    /// elements equal to `i`. Values outside of `0..length` are ignored.
+    fn float_bincount(
+        tensor: IntTensor<B>,
+        weights: FloatTensor<B>,
+        length: usize,
+    ) -> FloatTensor<B> {
+        bincount_float::<B>(tensor, weights, length)
+    }
+
+    /// Returns the unique elements of the flattened tensor.
+    ///
+    /// Because the number of unique elements depends on the tensor values, the output shape is
+    /// data-dependent: computing it may require synchronizing with the device, which is why this
+    /// operation is asynchronous.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `sorted` - Whether to return the unique elements in ascending order instead of the order
+    ///   of their first occurrence.
+    /// * `out_dtype` - The output data type of the inverse indices and counts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the 1D tensor of unique elements, the index of the unique element of
+    /// each input element (with the shape of the input), and the number of occurrences of each
+    /// unique element.
+    fn float_unique(
+        tensor: FloatTensor<B>,
+        sorted: bool,
+        out_dtype: IntDType,
+    ) -> impl Future<Output = (FloatTensor<B>, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        async move { unique_float::<B>(tensor, sorted, out_dtype).await }
+    }
+
+    /// Collapses the consecutive equal elements of the flattened tensor.
+    ///
+    /// Like [float_unique](FloatTensorOps::float_unique), the output shape is data-dependent,
+    /// which is why this operation is asynchronous.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `out_dtype` - The output data type of the inverse indices and counts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the 1D tensor of collapsed elements, the index of the collapsed element
+    /// of each input element (with the shape of the input), and the length of each run of equal
+    /// elements.
+    fn float_unique_consecutive(
+        tensor: FloatTensor<B>,
+        out_dtype: IntDType,
+    ) -> impl Future<Output = (FloatTensor<B>, IntTensor<B>, IntTensor<B>)> + 'static + Send {
+        async move { unique_consecutive_float::<B>(tensor, out_dtype).await }
+    }
+
     /// Samples tensor as a two-dimensional spatial grid of (possibly multi-channel) values,
     /// using the given locations in [-1, 1].
     ///
@@ -1983,4 +2354,32 @@ pub trait FloatTensorOps<B: Backend> {
     fn float_is_inf(tensor: FloatTensor<B>, out_dtype: BoolDType) -> BoolTensor<B> {
         B::float_equal_elem(B::float_abs(tensor), f64::INFINITY.into(), out_dtype)
     }
+
+    /// Replaces the NaN, positive infinity and negative infinity elements with the given values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `nan` - The value replacing NaN.
+    /// * `posinf` - The value replacing positive infinity.
+    /// * `neginf` - The value replacing negative infinity.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with the non-finite values replaced.
+    fn float_nan_to_num(
+        tensor: FloatTensor<B>,
+        nan: Scalar,
+        posinf: Scalar,
+        neginf: Scalar,
+    ) -> FloatTensor<B> {
+        let bool_dtype = get_device_settings::<B>(&tensor.device()).bool_dtype;
+        let is_nan = B::float_is_nan(tensor.clone(), bool_dtype);
+        let is_posinf = B::float_equal_elem(tensor.clone(), f64::INFINITY.into(), bool_dtype);
+        let is_neginf = B::float_equal_elem(tensor.clone(), f64::NEG_INFINITY.into(), bool_dtype);
+
+        let tensor = B::float_mask_fill(tensor, is_nan, nan);
+        let tensor = B::float_mask_fill(tensor, is_posinf, posinf);
+        B::float_mask_fill(tensor, is_neginf, neginf)
+    }
 }
@@ -0,0 +1,210 @@
+use crate::tensor::{Device, FloatTensor, IntTensor};
+use crate::{Backend, TensorData, TensorMetadata};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_std::{IntDType, Shape};
+use core::cmp::Ordering;
+
+/// Default `unique` implementation for float tensors.
+///
+/// Returns the unique values of the flattened tensor, the index of the unique value of each
+/// element (shaped like the input) and the number of occurrences of each unique value.
+///
+/// When `sorted` is true the unique values are returned in ascending order, otherwise in the
+/// order of their first occurrence. NaN values are never equal to each other, so each one is
+/// returned as a separate value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// The output length depends on the data, so the tensor is read back to compute the unique values.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub async fn unique_float<B: Backend>(
+    tensor: FloatTensor<B>,
+    sorted: bool,
+    out_dtype: IntDType,
+) -> (FloatTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let values = read_float::<B>(tensor.clone()).await;
+    let indices = UniqueIndices::new(&values, sorted, nan_last_cmp);
+    select_float::<B>(tensor, indices, out_dtype)
+}
+
+/// Default `unique_consecutive` implementation for float tensors.
+///
+/// Same as [unique_float], but only consecutive equal elements of the flattened tensor are
+/// collapsed, so the order of the elements is preserved.
+pub async fn unique_consecutive_float<B: Backend>(
+    tensor: FloatTensor<B>,
+    out_dtype: IntDType,
+) -> (FloatTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let values = read_float::<B>(tensor.clone()).await;
+    let indices = UniqueIndices::consecutive(&values);
+    select_float::<B>(tensor, indices, out_dtype)
+}
+
+/// Default `unique` implementation for int tensors.
+///
+/// See [unique_float] for details. The indices and counts have the dtype of the input tensor.
+pub async fn unique_int<B: Backend>(
+    tensor: IntTensor<B>,
+    sorted: bool,
+) -> (IntTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let values = read_int::<B>(tensor.clone()).await;
+    let indices = UniqueIndices::new(&values, sorted, Ord::cmp);
+    select_int::<B>(tensor, indices)
+}
+
+/// Default `unique_consecutive` implementation for int tensors.
+///
+/// See [unique_consecutive_float] for details.
+pub async fn unique_consecutive_int<B: Backend>(
+    tensor: IntTensor<B>,
+) -> (IntTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let values = read_int::<B>(tensor.clone()).await;
+    let indices = UniqueIndices::consecutive(&values);
+    select_int::<B>(tensor, indices)
+}
+
+async fn read_float<B: Backend>(tensor: FloatTensor<B>) -> Vec<f64> {
+    let data = B::float_into_data(tensor)
+        .await
+        .expect("Can read the data without error");
+    data.iter::<f64>().collect()
+}
+
+async fn read_int<B: Backend>(tensor: IntTensor<B>) -> Vec<i64> {
+    let data = B::int_into_data(tensor)
+        .await
+        .expect("Can read the data without error");
+    data.iter::<i64>().collect()
+}
+
+fn select_float<B: Backend>(
+    tensor: FloatTensor<B>,
+    indices: UniqueIndices,
+    out_dtype: IntDType,
+) -> (FloatTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let tensor = B::float_reshape(tensor, Shape::new([shape.num_elements()]));
+    let (first, inverse, counts) = indices.into_tensors::<B>(shape, &device, out_dtype);
+
+    let values = match counts.shape()[0] {
+        // The input is empty, so it is also the (empty) output.
+        0 => tensor,
+        _ => B::float_select(tensor, 0, first),
+    };
+    (values, inverse, counts)
+}
+
+fn select_int<B: Backend>(
+    tensor: IntTensor<B>,
+    indices: UniqueIndices,
+) -> (IntTensor<B>, IntTensor<B>, IntTensor<B>) {
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let out_dtype: IntDType = tensor.dtype().into();
+    let tensor = B::int_reshape(tensor, Shape::new([shape.num_elements()]));
+    let (first, inverse, counts) = indices.into_tensors::<B>(shape, &device, out_dtype);
+
+    let values = match counts.shape()[0] {
+        0 => tensor,
+        _ => B::int_select(tensor, 0, first),
+    };
+    (values, inverse, counts)
+}
+
+/// Orders the numbers as usual, with every NaN after them.
+fn nan_last_cmp(a: &f64, b: &f64) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// The unique values of a flat sequence, described by indices.
+struct UniqueIndices {
+    /// Index of the first occurrence of each unique value.
+    first: Vec<i64>,
+    /// Index of the unique value of each element.
+    inverse: Vec<i64>,
+    /// Number of occurrences of each unique value.
+    counts: Vec<i64>,
+}
+
+impl UniqueIndices {
+    fn new<T: PartialEq>(values: &[T], sorted: bool, cmp: fn(&T, &T) -> Ordering) -> Self {
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        // The sort is stable, so each group starts with its first occurrence.
+        order.sort_by(|&a, &b| cmp(&values[a], &values[b]));
+
+        let unique = Self::group(values, &order);
+        match sorted {
+            true => unique,
+            false => unique.by_first_occurrence(),
+        }
+    }
+
+    fn consecutive<T: PartialEq>(values: &[T]) -> Self {
+        let order: Vec<usize> = (0..values.len()).collect();
+        Self::group(values, &order)
+    }
+
+    /// Groups the equal neighbors when visiting `values` in the given `order`.
+    fn group<T: PartialEq>(values: &[T], order: &[usize]) -> Self {
+        let mut first = Vec::new();
+        let mut counts = Vec::new();
+        let mut inverse = vec![0; values.len()];
+
+        for (position, &index) in order.iter().enumerate() {
+            if position == 0 || values[order[position - 1]] != values[index] {
+                first.push(index as i64);
+                counts.push(0);
+            }
+            counts[first.len() - 1] += 1;
+            inverse[index] = (first.len() - 1) as i64;
+        }
+
+        Self {
+            first,
+            inverse,
+            counts,
+        }
+    }
+
+    /// Reorders the unique values by their first occurrence.
+    fn by_first_occurrence(self) -> Self {
+        let mut order: Vec<usize> = (0..self.first.len()).collect();
+        order.sort_by_key(|&unique| self.first[unique]);
+
+        let mut rank = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() {
+            rank[old] = new as i64;
+        }
+
+        Self {
+            first: order.iter().map(|&unique| self.first[unique]).collect(),
+            inverse: self.inverse.iter().map(|&u| rank[u as usize]).collect(),
+            counts: order.iter().map(|&unique| self.counts[unique]).collect(),
+        }
+    }
+
+    fn into_tensors<B: Backend>(
+        self,
+        shape: Shape,
+        device: &Device<B>,
+        dtype: IntDType,
+    ) -> (IntTensor<B>, IntTensor<B>, IntTensor<B>) {
+        let num_unique = self.first.len();
+        let tensor = |data: Vec<i64>, shape: Shape| {
+            let data = TensorData::new(data, shape).convert_dtype(dtype.into());
+            B::int_from_data(data, device)
+        };
+
+        (
+            tensor(self.first, Shape::new([num_unique])),
+            tensor(self.inverse, shape),
+            tensor(self.counts, Shape::new([num_unique])),
+        )
+    }
+}
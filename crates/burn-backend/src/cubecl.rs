@@ -25,12 +25,9 @@ pub fn elem_type_to_dtype(value: ElemType) -> DType {
             FloatKind::F32 => DType::F32,
             FloatKind::F64 => DType::F64,
             FloatKind::TF32 => panic!("Not a valid DType for tensors."),
-            FloatKind::E2M1
-            | FloatKind::E2M3
-            | FloatKind::E3M2
-            | FloatKind::E4M3
-            | FloatKind::E5M2
-            | FloatKind::UE8M0 => {
+            FloatKind::E4M3 => DType::F8E4M3,
+            FloatKind::E5M2 => DType::F8E5M2,
+            FloatKind::E2M1 | FloatKind::E2M3 | FloatKind::E3M2 | FloatKind::UE8M0 => {
                 unimplemented!("Not yet supported, will be used for quantization")
             }
         },
@@ -62,6 +59,8 @@ pub fn dtype_to_elem_type(dtype: DType) -> ElemType {
         DType::Flex32 => ElemType::Float(FloatKind::Flex32),
         DType::F16 => ElemType::Float(FloatKind::F16),
         DType::BF16 => ElemType::Float(FloatKind::BF16),
+        DType::F8E4M3 => ElemType::Float(FloatKind::E4M3),
+        DType::F8E5M2 => ElemType::Float(FloatKind::E5M2),
         DType::I64 => ElemType::Int(IntKind::I64),
         DType::I32 => ElemType::Int(IntKind::I32),
         DType::I16 => ElemType::Int(IntKind::I16),
@@ -94,6 +93,9 @@ pub fn dtype_to_elem_type(dtype: DType) -> ElemType {
                 other => panic!("{other:?} doesn't support native packing"),
             },
         },
+        DType::Complex64 | DType::Complex32 => {
+            panic!("Complex values are stored as their real and imaginary parts")
+        }
     }
 }
 
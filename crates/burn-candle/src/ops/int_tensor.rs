@@ -437,6 +437,16 @@ impl IntTensorOps<Self> for Candle {
             // Use our own seed since candle doesn't support it on CPU
             return Self::int_from_data(cpu_random(shape, distribution, dtype.into()), device);
         }
+        if matches!(
+            distribution,
+            Distribution::Exponential(_)
+                | Distribution::Gamma(..)
+                | Distribution::Beta(..)
+                | Distribution::Poisson(_)
+        ) {
+            let tensor = Self::float_random(shape, distribution, device, FloatDType::F32);
+            return Self::float_into_int(tensor, dtype);
+        }
 
         let shape = shape.to_vec();
         let device = &(device.clone()).into();
@@ -474,6 +484,7 @@ impl IntTensorOps<Self> for Candle {
                     .to_dtype(dtype.into_dtype())
                     .unwrap(),
             ),
+            _ => unreachable!("Sampled from the float distributions"),
         }
     }
 
@@ -5,6 +5,7 @@ mod candle_utils;
 mod int_tensor;
 mod module;
 mod qtensor;
+mod sparse;
 mod tensor;
 mod transaction;
 mod utils;
@@ -0,0 +1,4 @@
+use crate::Candle;
+use burn_backend::ops::SparseTensorOps;
+
+impl SparseTensorOps<Self> for Candle {}
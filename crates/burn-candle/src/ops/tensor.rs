@@ -3,7 +3,7 @@ use std::borrow::Borrow;
 use burn_backend::{
     DType, Distribution, ElementConversion, ExecutionError, FloatDType, Scalar, Shape, Slice,
     TensorData, bf16, f16,
-    ops::FloatTensorOps,
+    ops::{FloatTensorOps, sampling::random_default},
     tensor::{BoolTensor, Device, FloatTensor, IntTensor},
 };
 use burn_std::{BoolDType, IntDType};
@@ -37,6 +37,15 @@ impl FloatTensorOps<Self> for Candle {
             // Use our own seed since candle doesn't support it on CPU
             return Self::float_from_data(cpu_random(shape, distribution, dtype.into()), device);
         }
+        if matches!(
+            distribution,
+            Distribution::Exponential(_)
+                | Distribution::Gamma(..)
+                | Distribution::Beta(..)
+                | Distribution::Poisson(_)
+        ) {
+            return random_default::<Self>(shape, distribution, device, dtype);
+        }
 
         let shape = shape.to_vec();
         let device = &(device.clone()).into();
@@ -74,6 +83,7 @@ impl FloatTensorOps<Self> for Candle {
                     .to_dtype(dtype.into_dtype())
                     .unwrap(),
             ),
+            _ => unreachable!("Sampled from the uniform and normal distributions"),
         }
     }
 
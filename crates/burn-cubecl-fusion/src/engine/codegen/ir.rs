@@ -993,6 +993,9 @@ impl From<DType> for FuseType {
             DType::F64 => Self::F64,
             DType::QFloat(scheme) => Self::from_quant_scheme(scheme)
                 .unwrap_or_else(|| unimplemented!("Unsupported precision for fusion")),
+            DType::F8E4M3 | DType::F8E5M2 | DType::Complex64 | DType::Complex32 => {
+                unreachable!("FP8 and complex tensors are never fused")
+            }
         }
     }
 }
@@ -16,6 +16,16 @@ use burn_std::{
 };
 use cubecl::ir::ElemType;
 
+/// Returns true when the operation reads or writes an FP8 or a complex tensor.
+///
+/// Those dtypes have no [fuse type](super::codegen::ir::FuseType), so the operations always fall
+/// back to the regular backend kernels.
+pub(crate) fn has_unfused_tensor(op: &OperationIr) -> bool {
+    op.nodes()
+        .iter()
+        .any(|tensor| tensor.dtype.is_fp8() || tensor.dtype.is_complex())
+}
+
 /// The base operation fuser that can be used to fuse [all supported fuse operations](FuseOp).
 ///
 ///
@@ -83,6 +93,12 @@ impl OperationFuser<FuseTrace> for TraceOperationFuser {
         // if the fuser closes on this op.
         let prev_num_ops = self.num_ops;
 
+        if has_unfused_tensor(op) {
+            self.status = FuserStatus::Closed;
+            self.log_closed(op, prev_num_ops, "fp8 and complex tensors are not fused");
+            return;
+        }
+
         match op {
             OperationIr::Drop(tensor) => {
                 if self.num_ops == 0 {
@@ -1,6 +1,9 @@
 use super::optimization::{FusedMatmul, MatmulOptimization};
 use crate::{
-    engine::{fuser::TraceOperationFuser, settings::FuseSettings},
+    engine::{
+        fuser::{TraceOperationFuser, has_unfused_tensor},
+        settings::FuseSettings,
+    },
     optim::CubeOptimization,
     optim::matmul::args::MatmulArg,
 };
@@ -70,6 +73,12 @@ impl<R: Runtime> OperationFuser<CubeOptimization<R>> for MatmulFuser<R> {
             return;
         }
 
+        if has_unfused_tensor(operation) {
+            self.fuser.close();
+            self.fuser_fallback.close();
+            return;
+        }
+
         if self.matmul.is_none() {
             if let OperationIr::Float(_, FloatOperationIr::Matmul(op)) = operation {
                 // Precision shouldn't be hardcoded but I don't know how to get float precision of the backend
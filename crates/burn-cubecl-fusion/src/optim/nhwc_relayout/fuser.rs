@@ -1,6 +1,6 @@
 use crate::{
     engine::{
-        fuser::TraceOperationFuser,
+        fuser::{TraceOperationFuser, has_unfused_tensor},
         settings::{FuseSettings, RefLayoutSetting, VectorizationSetting},
     },
     optim::{CubeOptimization, nhwc_relayout::optimization::NHWCRelayoutOptimization},
@@ -87,6 +87,11 @@ impl<R: Runtime> OperationFuser<CubeOptimization<R>> for NHWCRelayoutFuser<R> {
             return;
         }
 
+        if has_unfused_tensor(operation) {
+            self.status = FuserStatus::Closed;
+            return;
+        }
+
         match operation {
             OperationIr::Module(ir) if let Some(tensor) = nhwc_relayout_tensor(ir) => {
                 self.op = Some(operation.clone());
@@ -5,7 +5,7 @@ use super::{
 use crate::{
     engine::{
         codegen::ir::FuseType,
-        fuser::TraceOperationFuser,
+        fuser::{TraceOperationFuser, has_unfused_tensor},
         settings::{FuseSettings, RefLayoutSetting, VectorizationSetting},
     },
     optim::CubeOptimization,
@@ -219,6 +219,12 @@ impl<R: Runtime> OperationFuser<CubeOptimization<R>> for ReduceFuser<R> {
             return;
         }
 
+        if has_unfused_tensor(operation) {
+            self.fuser.close();
+            self.fuser_read_fallback.close();
+            return;
+        }
+
         if self.reduce.is_none() {
             if let OperationIr::NumericFloat(_, op) = operation {
                 match op {
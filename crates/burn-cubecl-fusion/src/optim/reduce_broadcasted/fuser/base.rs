@@ -1,12 +1,15 @@
-use crate::optim::{
-    CubeOptimization,
-    reduce::{ReduceFuser, ReduceFuserInfo, ReduceSettings},
-    reduce_broadcasted::{
-        ReduceBlockOptimInfo, ReduceBroadcastedOptimization, ReduceBroadcastedOptimizationInfo,
-        fuser::{
-            block::{ReduceBlockFuser, ReduceBlockFusionAnalysis, ReduceBroadcastedStatus},
-            full::ReduceBroadcastedFullFuser,
-            full_analyzer::FullFuserAnalyzer,
+use crate::{
+    engine::fuser::has_unfused_tensor,
+    optim::{
+        CubeOptimization,
+        reduce::{ReduceFuser, ReduceFuserInfo, ReduceSettings},
+        reduce_broadcasted::{
+            ReduceBlockOptimInfo, ReduceBroadcastedOptimization, ReduceBroadcastedOptimizationInfo,
+            fuser::{
+                block::{ReduceBlockFuser, ReduceBlockFusionAnalysis, ReduceBroadcastedStatus},
+                full::ReduceBroadcastedFullFuser,
+                full_analyzer::FullFuserAnalyzer,
+            },
         },
     },
 };
@@ -145,6 +148,11 @@ impl<R: Runtime> OperationFuser<CubeOptimization<R>> for ReduceBroadcastedFuser<
             return;
         }
 
+        if has_unfused_tensor(operation) {
+            self.state = ReduceBroadcastedStatus::Closed;
+            return;
+        }
+
         // We first need to simulate the fusion to check the consistency, then we perform the
         // fusion.
         let mut next = self.clone();
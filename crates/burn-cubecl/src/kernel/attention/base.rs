@@ -1,5 +1,6 @@
 #[cfg(feature = "autotune")]
 use crate::kernel::attention::attention_autotune;
+use crate::kernel::attention::online_attention;
 use crate::{CubeBackend, CubeRuntime, ops::numeric::empty_device_dtype, tensor::CubeTensor};
 use burn_backend::cubecl::dtype_to_storage_type;
 use burn_backend::{
@@ -26,6 +27,9 @@ pub enum AttentionStrategy {
     /// Fallback implementation using multiple separate kernels.
     Fallback,
 
+    /// Single-pass kernel with an online softmax, supporting every attention option.
+    Online,
+
     /// Automatically benchmark and select the best strategy at runtime.
     #[cfg(feature = "autotune")]
     Autotune,
@@ -67,6 +71,11 @@ pub fn attention<R: CubeRuntime>(
                 query, key, value, mask, attn_bias, options,
             ));
         }
+        AttentionStrategy::Online => {
+            return Ok(online_attention(
+                query, key, value, mask, attn_bias, options,
+            ));
+        }
         #[cfg(feature = "autotune")]
         AttentionStrategy::Autotune => {
             return Ok(attention_autotune(
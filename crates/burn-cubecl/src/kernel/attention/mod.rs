@@ -1,9 +1,11 @@
 mod base;
 #[cfg(feature = "autotune")]
 mod bounds;
+mod online;
 #[cfg(feature = "autotune")]
 mod tune;
 
 pub use base::*;
+pub use online::*;
 #[cfg(feature = "autotune")]
 pub use tune::*;
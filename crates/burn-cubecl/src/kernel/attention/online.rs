@@ -0,0 +1,307 @@
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::ops::{AttentionModuleOptions, FloatTensorOps, attention::alibi_slopes};
+use burn_backend::{DType, Shape, TensorData};
+use cubecl::prelude::*;
+
+use super::base::init_attention_output;
+use crate::{CubeBackend, CubeRuntime, kernel::into_contiguous, ops::expand, tensor::CubeTensor};
+
+/// Number of keys scored at once by a cube, one per unit.
+const TILE_KV: u32 = 32;
+
+/// Single-pass attention kernel with an online softmax.
+///
+/// Each cube handles one query row of one `(batch, head)` pair and walks the keys in tiles of
+/// `tile_size`, one key per unit. The scores of a tile are shared so that every unit can update
+/// the running max and sum, then each unit accumulates the value columns it owns. Only the
+/// scores of the current tile are kept, so the `[seq_q, seq_kv]` score matrix is never
+/// materialized, whatever the masking and biases.
+///
+/// Masked scores use a large finite negative sentinel instead of `-inf`, which WGSL can't
+/// express as a literal. Rows where every key is masked produce zeros, like the fallback.
+#[cube(launch)]
+fn online_attention_kernel<F: Float, M: Int>(
+    query: &Tensor<F>,                          // [batch, heads, seq_q, head_dim]
+    key: &Tensor<F>,                            // [batch, kv_heads, seq_kv, head_dim]
+    value: &Tensor<F>,                          // [batch, kv_heads, seq_kv, val_dim]
+    mask: ComptimeOption<&Tensor<M>>,           // [batch, heads, seq_q, seq_kv], broadcast
+    bias: ComptimeOption<&Tensor<F>>,           // [batch, heads, seq_q, seq_kv], broadcast
+    alibi_slopes: ComptimeOption<&Tensor<f32>>, // [heads]
+    output: &mut Tensor<F>,                     // [batch, heads, seq_q, val_dim]
+    num_rows: u32,
+    scale: f32,
+    softcap: f32,
+    window: u32,
+    #[comptime] tile_size: u32,
+    #[comptime] val_capacity: u32,
+    #[comptime] causal: bool,
+    #[comptime] has_softcap: bool,
+    #[comptime] has_window: bool,
+    #[define(F, M)] _dtypes: [StorageType; 2],
+) {
+    // The rows are spread over two cube dimensions, since one is limited to 65535 cubes.
+    let row = CUBE_POS_Y as usize * CUBE_COUNT_X as usize + CUBE_POS_X as usize;
+    if row >= num_rows as usize {
+        terminate!();
+    }
+
+    let unit = UNIT_POS_X as usize;
+    let tile = tile_size as usize;
+
+    let heads = query.shape(1);
+    let seq_q = query.shape(2);
+    let head_dim = query.shape(3);
+    let qi = row % seq_q;
+    let h = (row / seq_q) % heads;
+    let b = row / (seq_q * heads);
+    let kv_heads = key.shape(1);
+    let seq_kv = key.shape(2);
+    let val_dim = value.shape(3);
+
+    // GQA/MQA: each key and value head is shared by `heads / kv_heads` query heads.
+    let kv_h = h / (heads / kv_heads);
+    // The last query aligns with the last key, like the causal mask.
+    let q_pos = (qi + seq_kv) as i32 - seq_q as i32;
+
+    let q_offset = b * query.stride(0) + h * query.stride(1) + qi * query.stride(2);
+    let k_offset = b * key.stride(0) + kv_h * key.stride(1);
+    let v_offset = b * value.stride(0) + kv_h * value.stride(1);
+
+    let mut scores = Shared::<f32>::new_slice(tile);
+    let mut acc = Shared::<f32>::new_slice(val_capacity as usize);
+
+    let mut d = unit;
+    while d < val_dim {
+        acc[d] = 0.0;
+        d += tile;
+    }
+
+    let masked = -3.0e38f32;
+    let mut row_max = masked;
+    let mut row_sum = 0.0f32;
+
+    let mut tile_start = 0usize;
+    while tile_start < seq_kv {
+        let j = tile_start + unit;
+        let mut score = masked;
+
+        if j < seq_kv {
+            let mut dot = 0.0f32;
+            for k in 0..head_dim {
+                let q_value = f32::cast_from(query[q_offset + k * query.stride(3)]);
+                let k_value = f32::cast_from(key[k_offset + j * key.stride(2) + k * key.stride(3)]);
+                dot += q_value * k_value;
+            }
+
+            let mut s = dot * scale;
+            if has_softcap {
+                s = softcap * (s / softcap).tanh();
+            }
+
+            #[comptime]
+            let unmasked = match &mask {
+                ComptimeOption::Some(mask) => {
+                    let index = b * mask.stride(0)
+                        + h * mask.stride(1)
+                        + qi * mask.stride(2)
+                        + j * mask.stride(3);
+                    mask[index] == M::new(0)
+                }
+                ComptimeOption::None => true,
+            };
+            let mut allowed = unmasked;
+            if causal {
+                if j as i32 > q_pos {
+                    allowed = false;
+                }
+            }
+            if has_window {
+                if (j as i32) + window as i32 <= q_pos {
+                    allowed = false;
+                }
+            }
+
+            #[comptime]
+            let alibi_bias = match &alibi_slopes {
+                ComptimeOption::Some(slopes) => {
+                    let distance = f32::cast_from(q_pos - j as i32);
+                    slopes[h] * distance.abs()
+                }
+                ComptimeOption::None => 0.0f32,
+            };
+            #[comptime]
+            let attn_bias = match &bias {
+                ComptimeOption::Some(bias) => {
+                    let index = b * bias.stride(0)
+                        + h * bias.stride(1)
+                        + qi * bias.stride(2)
+                        + j * bias.stride(3);
+                    f32::cast_from(bias[index])
+                }
+                ComptimeOption::None => 0.0f32,
+            };
+
+            if allowed {
+                score = s - alibi_bias + attn_bias;
+            }
+        }
+
+        scores[unit] = score;
+        sync_cube();
+
+        let mut tile_len = seq_kv - tile_start;
+        if tile_len > tile {
+            tile_len = tile;
+        }
+
+        let mut tile_max = masked;
+        for t in 0..tile_len {
+            if scores[t] > tile_max {
+                tile_max = scores[t];
+            }
+        }
+
+        // Every unit reads the same scores, so the branch is uniform across the cube.
+        if tile_max > masked {
+            let mut new_max = row_max;
+            if tile_max > new_max {
+                new_max = tile_max;
+            }
+            let mut correction = 0.0f32;
+            if row_max > masked {
+                correction = (row_max - new_max).exp();
+            }
+
+            let mut d = unit;
+            while d < val_dim {
+                acc[d] = acc[d] * correction;
+                d += tile;
+            }
+
+            let mut tile_sum = 0.0f32;
+            for t in 0..tile_len {
+                let s = scores[t];
+                if s > masked {
+                    let p = (s - new_max).exp();
+                    let v_row = v_offset + (tile_start + t) * value.stride(2);
+                    tile_sum += p;
+
+                    let mut d = unit;
+                    while d < val_dim {
+                        acc[d] = acc[d] + p * f32::cast_from(value[v_row + d * value.stride(3)]);
+                        d += tile;
+                    }
+                }
+            }
+
+            row_sum = row_sum * correction + tile_sum;
+            row_max = new_max;
+        }
+
+        // The scores of the next tile overwrite the shared scores.
+        sync_cube();
+        tile_start += tile;
+    }
+
+    let o_offset = b * output.stride(0) + h * output.stride(1) + qi * output.stride(2);
+    let mut d = unit;
+    while d < val_dim {
+        let mut out = 0.0f32;
+        if row_sum > 0.0 {
+            out = acc[d] / row_sum;
+        }
+        output[o_offset + d * output.stride(3)] = F::cast_from(out);
+        d += tile;
+    }
+}
+
+/// Attention with every [option](AttentionModuleOptions) and an additive bias, without
+/// materializing the score matrix.
+///
+/// Used for the sliding window and ALiBi, which the flash kernels don't support. Masks and biases
+/// are read with their strides, so the ones broadcast over the batch or the heads are never
+/// expanded in memory.
+pub fn online_attention<R: CubeRuntime>(
+    query: CubeTensor<R>,
+    key: CubeTensor<R>,
+    value: CubeTensor<R>,
+    mask: Option<CubeTensor<R>>,
+    attn_bias: Option<CubeTensor<R>>,
+    options: AttentionModuleOptions,
+) -> CubeTensor<R> {
+    if let Some(softcap) = options.softcap {
+        assert!(softcap > 0.0, "softcap must be positive, got {softcap}");
+    }
+    if let Some(window) = options.sliding_window {
+        assert!(window > 0, "sliding_window must be positive, got {window}");
+    }
+
+    let query = into_contiguous(query);
+    let key = into_contiguous(key);
+    let value = into_contiguous(value);
+
+    let [batch, heads, seq_q, head_dim] = query.meta.shape().dims::<4>();
+    let kv_heads = key.meta.shape()[1];
+    let seq_kv = key.meta.shape()[2];
+    let val_dim = value.meta.shape()[3];
+    assert!(
+        kv_heads > 0 && heads.is_multiple_of(kv_heads),
+        "attention: q_heads ({heads}) must be divisible by kv_heads ({kv_heads})"
+    );
+
+    let target = Shape::new([batch, heads, seq_q, seq_kv]);
+    let mask = mask.map(|mask| expand(mask, target.clone()));
+    let attn_bias = attn_bias.map(|bias| expand(bias, target));
+    let alibi_slopes = options.alibi_max_bias.map(|max_bias| {
+        let slopes = alibi_slopes(heads, max_bias)
+            .into_iter()
+            .map(|slope| slope as f32)
+            .collect::<Vec<_>>();
+
+        CubeBackend::<R>::float_from_data(TensorData::new(slopes, [heads]), &query.device)
+    });
+
+    let client = query.client.clone();
+    let output = init_attention_output(&query, &value);
+    let scale = options
+        .scale
+        .unwrap_or_else(|| 1.0 / (head_dim as f64).sqrt());
+    let mask_dtype = mask.as_ref().map(|mask| mask.dtype).unwrap_or(DType::U8);
+    let dtypes = [
+        dtype_to_storage_type(query.dtype),
+        dtype_to_storage_type(mask_dtype),
+    ];
+
+    let num_rows = batch * heads * seq_q;
+    if num_rows == 0 {
+        return output;
+    }
+    let cubes_x = num_rows.min(u16::MAX as usize);
+    let cube_count = CubeCount::Static(cubes_x as u32, num_rows.div_ceil(cubes_x) as u32, 1);
+    let cube_dim = CubeDim::new_1d(TILE_KV);
+
+    online_attention_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        query.into_tensor_arg(),
+        key.into_tensor_arg(),
+        value.into_tensor_arg(),
+        mask.map(|mask| mask.into_tensor_arg()).into(),
+        attn_bias.map(|bias| bias.into_tensor_arg()).into(),
+        alibi_slopes.map(|slopes| slopes.into_tensor_arg()).into(),
+        output.clone().into_tensor_arg(),
+        num_rows as u32,
+        scale as f32,
+        options.softcap.unwrap_or(1.0) as f32,
+        options.sliding_window.unwrap_or(0) as u32,
+        TILE_KV,
+        val_dim as u32,
+        options.is_causal,
+        options.softcap.is_some(),
+        options.sliding_window.is_some(),
+        dtypes,
+    );
+
+    output
+}
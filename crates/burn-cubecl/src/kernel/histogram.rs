@@ -0,0 +1,342 @@
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{DType, Shape, TensorMetadata};
+use cubecl::{calculate_cube_count_elemwise, features::AtomicUsage, ir::FloatKind, prelude::*};
+
+use crate::{
+    CubeRuntime,
+    kernel::{cast, into_contiguous},
+    ops::numeric::{empty_device_dtype, zeros_client},
+    tensor::CubeTensor,
+};
+
+/// Binary search of each value in its row of the sorted sequence.
+///
+/// Each thread handles one value. The sorted sequence is either one row per row of values,
+/// or a single row shared by all of them when `shared` is set.
+#[cube(launch)]
+fn searchsorted_kernel<E: Numeric, I: Numeric>(
+    sorted_sequence: &Tensor<E>, // [rows, M] or [M]
+    values: &Tensor<E>,          // [rows, K]
+    output: &mut Tensor<I>,      // [rows, K]
+    num_values: u32,
+    num_boundaries: u32,
+    #[comptime] shared: bool,
+    #[comptime] right: bool,
+    #[define(E, I)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let num_boundaries = num_boundaries as usize;
+    let mut offset = 0usize;
+    if !shared {
+        offset = (pos / num_values as usize) * num_boundaries;
+    }
+
+    let value = values[pos];
+    let mut low = 0usize;
+    let mut high = num_boundaries;
+    while low < high {
+        let mid = (low + high) / 2;
+        let boundary = sorted_sequence[offset + mid];
+        let before = if right {
+            boundary <= value
+        } else {
+            boundary < value
+        };
+        if before {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    output[pos] = I::cast_from(low as u32);
+}
+
+/// Histogram kernel. Each thread maps one element to its bin and increments the count of the
+/// bin atomically, so the work is proportional to the number of elements.
+#[cube(launch)]
+fn histc_kernel<F: Float>(
+    input: &Tensor<F>,                // [N]
+    output: &mut Tensor<Atomic<u32>>, // [bins]
+    min: InputScalar,
+    max: InputScalar,
+    scale: InputScalar,
+    #[define(F)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= input.len() {
+        terminate!();
+    }
+
+    let min = min.get::<F>();
+    let max = max.get::<F>();
+    let scale = scale.get::<F>();
+    let last_bin = output.len() - 1;
+
+    let x = input[pos];
+    if x >= min && x <= max {
+        // `max` belongs to the last bin.
+        let mut bin = u32::cast_from(((x - min) * scale).floor()) as usize;
+        if bin > last_bin {
+            bin = last_bin;
+        }
+        output[bin].fetch_add(1u32);
+    }
+}
+
+/// Counting kernel. Each thread increments the count of its index atomically, ignoring the
+/// indices outside of the bins.
+#[cube(launch)]
+fn bincount_kernel<I: Int>(
+    indices: &Tensor<I>,              // [N]
+    output: &mut Tensor<Atomic<u32>>, // [length]
+    #[define(I)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= indices.len() {
+        terminate!();
+    }
+
+    let index = indices[pos];
+    if index >= I::new(0) && index < I::cast_from(output.len() as u32) {
+        output[usize::cast_from(index)].fetch_add(1u32);
+    }
+}
+
+/// Weighted counting kernel. Each thread adds its weight to the bin of its index with an
+/// atomic float addition.
+#[cube(launch)]
+fn bincount_weighted_kernel<I: Int, F: Float>(
+    indices: &Tensor<I>,              // [N]
+    weights: &Tensor<F>,              // [N]
+    output: &mut Tensor<Atomic<f32>>, // [length]
+    #[define(I, F)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= indices.len() {
+        terminate!();
+    }
+
+    let index = indices[pos];
+    if index >= I::new(0) && index < I::cast_from(output.len() as u32) {
+        output[usize::cast_from(index)].fetch_add(f32::cast_from(weights[pos]));
+    }
+}
+
+/// Weighted counting kernel for devices without atomic float additions, where the `f32` bins
+/// are stored as bits and updated with a compare-and-swap loop.
+#[cube(launch)]
+fn bincount_weighted_cas_kernel<I: Int, F: Float>(
+    indices: &Tensor<I>,              // [N]
+    weights: &Tensor<F>,              // [N]
+    output: &mut Tensor<Atomic<u32>>, // [length]
+    #[define(I, F)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= indices.len() {
+        terminate!();
+    }
+
+    let index = indices[pos];
+    let weight = f32::cast_from(weights[pos]);
+    if index >= I::new(0) && index < I::cast_from(output.len() as u32) && weight != 0.0 {
+        let bin = usize::cast_from(index);
+        let mut bits = output[bin].load();
+        loop {
+            let prev = bits;
+            let sum = (f32::from_bits(bits) + weight).to_bits();
+            bits = output[bin].compare_exchange_weak(bits, sum);
+            if prev == bits {
+                break;
+            }
+        }
+    }
+}
+
+/// Finds the insertion indices of `values` into the last dimension of `sorted_sequence`.
+pub(crate) fn searchsorted<R: CubeRuntime>(
+    sorted_sequence: CubeTensor<R>,
+    values: CubeTensor<R>,
+    right: bool,
+    out_dtype: DType,
+) -> CubeTensor<R> {
+    let sorted_sequence = into_contiguous(sorted_sequence);
+    let values = into_contiguous(values);
+
+    let sorted_shape = sorted_sequence.shape();
+    let values_shape = values.shape();
+    let rank = values_shape.num_dims();
+    let shared = sorted_shape.num_dims() == 1;
+    assert!(
+        shared
+            || (sorted_shape.num_dims() == rank
+                && sorted_shape[..rank - 1] == values_shape[..rank - 1]),
+        "searchsorted: sorted sequence shape {sorted_shape:?} incompatible with values shape {values_shape:?}"
+    );
+    let num_boundaries = sorted_shape[sorted_shape.num_dims() - 1];
+    let num_values = values_shape[rank - 1];
+
+    let client = values.client.clone();
+    let dtype = values.dtype;
+    let output = empty_device_dtype::<R>(
+        client.clone(),
+        values.device.clone(),
+        values_shape,
+        out_dtype,
+    );
+
+    let num_elems = output.shape().num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    searchsorted_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        sorted_sequence.into_tensor_arg(),
+        values.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        num_values as u32,
+        num_boundaries as u32,
+        shared,
+        right,
+        [
+            dtype_to_storage_type(dtype),
+            dtype_to_storage_type(out_dtype),
+        ],
+    );
+
+    output
+}
+
+/// Computes the histogram of all the elements with `bins` equal-width bins over `[min, max]`.
+pub(crate) fn histc<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    bins: usize,
+    min: f64,
+    max: f64,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let client = tensor.client.clone();
+    let dtype = tensor.dtype;
+    let storage = dtype_to_storage_type(dtype);
+    let counts = zeros_client::<R>(
+        client.clone(),
+        tensor.device.clone(),
+        Shape::new([bins]),
+        DType::U32,
+    );
+
+    let num_elems = tensor.shape().num_elements();
+    if bins == 0 || num_elems == 0 {
+        return cast(counts, dtype);
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+    let scale = bins as f64 / (max - min);
+
+    histc_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        tensor.into_tensor_arg(),
+        counts.clone().into_tensor_arg(),
+        InputScalar::new(min, storage),
+        InputScalar::new(max, storage),
+        InputScalar::new(scale, storage),
+        storage,
+    );
+
+    cast(counts, dtype)
+}
+
+/// Counts the occurrences of each index in `0..length`, ignoring the others.
+pub(crate) fn bincount<R: CubeRuntime>(tensor: CubeTensor<R>, length: usize) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let client = tensor.client.clone();
+    let dtype = tensor.dtype;
+    let counts = zeros_client::<R>(
+        client.clone(),
+        tensor.device.clone(),
+        Shape::new([length]),
+        DType::U32,
+    );
+
+    let num_elems = tensor.shape().num_elements();
+    if length == 0 || num_elems == 0 {
+        return cast(counts, dtype);
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    bincount_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        tensor.into_tensor_arg(),
+        counts.clone().into_tensor_arg(),
+        dtype_to_storage_type(dtype),
+    );
+
+    cast(counts, dtype)
+}
+
+/// Sums the `weights` of each index in `0..length`, ignoring the others.
+///
+/// The bins are accumulated in `f32`, with atomic float additions when the device supports
+/// them.
+pub(crate) fn bincount_weighted<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    weights: CubeTensor<R>,
+    length: usize,
+) -> CubeTensor<R> {
+    let tensor = into_contiguous(tensor);
+    let weights = into_contiguous(weights);
+    let client = tensor.client.clone();
+    let (int_dtype, float_dtype) = (tensor.dtype, weights.dtype);
+
+    let supports_fadd = client
+        .properties()
+        .atomic_type_usage(Type::atomic(FloatKind::F32))
+        .contains(AtomicUsage::Add);
+    // Without atomic float additions, the zeros are bound as their `u32` bits.
+    let sums = zeros_client::<R>(
+        client.clone(),
+        tensor.device.clone(),
+        Shape::new([length]),
+        DType::F32,
+    );
+
+    let num_elems = tensor.shape().num_elements();
+    if length == 0 || num_elems == 0 {
+        return cast(sums, float_dtype);
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    let launch = match supports_fadd {
+        true => bincount_weighted_kernel::launch::<R>,
+        false => bincount_weighted_cas_kernel::launch::<R>,
+    };
+    launch(
+        &client,
+        cube_count,
+        cube_dim,
+        tensor.into_tensor_arg(),
+        weights.into_tensor_arg(),
+        sums.clone().into_tensor_arg(),
+        [
+            dtype_to_storage_type(int_dtype),
+            dtype_to_storage_type(float_dtype),
+        ],
+    );
+
+    cast(sums, float_dtype)
+}
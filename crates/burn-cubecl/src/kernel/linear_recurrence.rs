@@ -0,0 +1,249 @@
+use crate::{
+    CubeRuntime, kernel::into_contiguous, ops::numeric::empty_device_dtype, tensor::CubeTensor,
+};
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{DType, Shape};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The number of steps each unit runs sequentially.
+const CHUNK_SIZE: usize = 32;
+
+/// First pass of the chunked linear recurrence.
+///
+/// The scanned dimension is split in chunks of `chunk_size` steps, and each unit runs the
+/// recurrence of one lane over one chunk, starting from a zero state. The units are ordered like
+/// the tensor `[outer, num_chunks, inner]`, so neighbouring units read neighbouring elements and
+/// each unit writes the carry of its chunk at its own position: the product of the decays
+/// `carry_a` and the final state `carry_b`, which follow the same recurrence over the chunks.
+#[cube(launch)]
+fn linear_recurrence_chunk_kernel<F: Float>(
+    a: &Tensor<F>,           // [outer, dim_size, inner]
+    b: &Tensor<F>,           // [outer, dim_size, inner]
+    output: &mut Tensor<F>,  // [outer, dim_size, inner]
+    carry_a: &mut Tensor<F>, // [outer, num_chunks, inner]
+    carry_b: &mut Tensor<F>, // [outer, num_chunks, inner]
+    num_units: u32,
+    dim_size: u32,
+    inner_size: u32,
+    chunk_size: u32,
+    #[define(F)] _dtype: StorageType,
+) {
+    let unit = ABSOLUTE_POS;
+    if unit >= num_units as usize {
+        terminate!();
+    }
+
+    let dim_size = dim_size as usize;
+    let inner_size = inner_size as usize;
+    let chunk_size = chunk_size as usize;
+    let num_chunks = (dim_size + chunk_size - 1) / chunk_size;
+
+    let base = lane_base(unit, num_chunks, dim_size, inner_size);
+    let start = chunk_start(unit, num_chunks, inner_size, chunk_size);
+    let end = chunk_end(start, dim_size, chunk_size);
+
+    let mut decay = F::new(1.0);
+    let mut state = F::new(0.0);
+    for i in start..end {
+        let index = base + i * inner_size;
+        decay *= a[index];
+        state = a[index] * state + b[index];
+        output[index] = state;
+    }
+
+    carry_a[unit] = decay;
+    carry_b[unit] = state;
+}
+
+/// Second pass of the chunked linear recurrence.
+///
+/// Each unit adds the state carried from the end of the previous chunk, decayed up to each step,
+/// to the states of its chunk. `states` holds the states at the end of each chunk.
+#[cube(launch)]
+fn linear_recurrence_carry_kernel<F: Float>(
+    a: &Tensor<F>,          // [outer, dim_size, inner]
+    states: &Tensor<F>,     // [outer, num_chunks, inner]
+    output: &mut Tensor<F>, // [outer, dim_size, inner]
+    num_units: u32,
+    dim_size: u32,
+    inner_size: u32,
+    chunk_size: u32,
+    #[define(F)] _dtype: StorageType,
+) {
+    let unit = ABSOLUTE_POS;
+    if unit >= num_units as usize {
+        terminate!();
+    }
+
+    let dim_size = dim_size as usize;
+    let inner_size = inner_size as usize;
+    let chunk_size = chunk_size as usize;
+    let num_chunks = (dim_size + chunk_size - 1) / chunk_size;
+
+    let base = lane_base(unit, num_chunks, dim_size, inner_size);
+    let start = chunk_start(unit, num_chunks, inner_size, chunk_size);
+    // The first chunk starts from the zero state.
+    if start == 0 {
+        terminate!();
+    }
+
+    let end = chunk_end(start, dim_size, chunk_size);
+
+    let carry = states[unit - inner_size];
+    let mut decay = F::new(1.0);
+    for i in start..end {
+        let index = base + i * inner_size;
+        decay *= a[index];
+        output[index] += decay * carry;
+    }
+}
+
+/// Returns the index of the first step of the lane of a unit.
+#[cube]
+fn lane_base(unit: usize, num_chunks: usize, dim_size: usize, inner_size: usize) -> usize {
+    let inner = unit % inner_size;
+    let outer = unit / (inner_size * num_chunks);
+
+    outer * dim_size * inner_size + inner
+}
+
+/// Returns the first step of the chunk of a unit along the scanned dimension.
+#[cube]
+fn chunk_start(unit: usize, num_chunks: usize, inner_size: usize, chunk_size: usize) -> usize {
+    ((unit / inner_size) % num_chunks) * chunk_size
+}
+
+/// Returns the end of a chunk, clamped to the size of the scanned dimension.
+#[cube]
+fn chunk_end(start: usize, dim_size: usize, chunk_size: usize) -> usize {
+    let mut end = start + chunk_size;
+    if end > dim_size {
+        end = dim_size;
+    }
+    end
+}
+
+/// Computes `h[t] = a[t] * h[t - 1] + b[t]` along `dim`, with `h[-1] = 0`.
+///
+/// The recurrence is a scan over the affine maps `h -> a[t] * h + b[t]`, computed in chunks: each
+/// chunk is scanned in parallel from a zero state, the carries of the chunks are scanned
+/// recursively with the same recurrence, and each chunk then adds the state carried from the
+/// previous one. The units run at most [CHUNK_SIZE] steps per pass, instead of the whole
+/// dimension.
+pub(crate) fn linear_recurrence<R: CubeRuntime>(
+    a: CubeTensor<R>,
+    b: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    assert_eq!(
+        a.meta.shape(),
+        b.meta.shape(),
+        "linear_recurrence: a and b must have the same shape"
+    );
+
+    let a = into_contiguous(a);
+    let b = into_contiguous(b);
+    let shape = b.meta.shape().clone();
+
+    let dim_size = shape[dim];
+    let inner_size: usize = shape[dim + 1..].iter().product();
+    let outer_size: usize = shape[..dim].iter().product();
+
+    let output = empty_device_dtype(b.client.clone(), b.device.clone(), shape, b.dtype);
+    if outer_size * dim_size * inner_size == 0 {
+        return output;
+    }
+
+    let carries = chunked_recurrence(
+        a.clone(),
+        b,
+        output.clone(),
+        outer_size,
+        dim_size,
+        inner_size,
+    );
+    if let Some((carry_a, carry_b, num_units)) = carries {
+        // The states at the end of each chunk.
+        let states = linear_recurrence(carry_a, carry_b, 1);
+        carry_recurrence(a, states, output.clone(), num_units, dim_size, inner_size);
+    }
+
+    output
+}
+
+/// Runs the recurrence within each chunk, and returns the carries of the chunks with the number
+/// of units, unless there is a single chunk.
+fn chunked_recurrence<R: CubeRuntime>(
+    a: CubeTensor<R>,
+    b: CubeTensor<R>,
+    output: CubeTensor<R>,
+    outer_size: usize,
+    dim_size: usize,
+    inner_size: usize,
+) -> Option<(CubeTensor<R>, CubeTensor<R>, usize)> {
+    let num_chunks = dim_size.div_ceil(CHUNK_SIZE);
+    let num_units = outer_size * num_chunks * inner_size;
+
+    let client = b.client.clone();
+    let carry = |dtype: DType| {
+        empty_device_dtype(
+            client.clone(),
+            b.device.clone(),
+            Shape::new([outer_size, num_chunks, inner_size]),
+            dtype,
+        )
+    };
+    let carry_a = carry(b.dtype);
+    let carry_b = carry(b.dtype);
+
+    let cube_dim = CubeDim::new(&client, num_units);
+    let cube_count = calculate_cube_count_elemwise(&client, num_units, cube_dim);
+    let dtype = dtype_to_storage_type(b.dtype);
+
+    linear_recurrence_chunk_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        a.into_tensor_arg(),
+        b.into_tensor_arg(),
+        output.into_tensor_arg(),
+        carry_a.clone().into_tensor_arg(),
+        carry_b.clone().into_tensor_arg(),
+        num_units as u32,
+        dim_size as u32,
+        inner_size as u32,
+        CHUNK_SIZE as u32,
+        dtype,
+    );
+
+    (num_chunks > 1).then_some((carry_a, carry_b, num_units))
+}
+
+/// Adds the states carried from the previous chunks to the output.
+fn carry_recurrence<R: CubeRuntime>(
+    a: CubeTensor<R>,
+    states: CubeTensor<R>,
+    output: CubeTensor<R>,
+    num_units: usize,
+    dim_size: usize,
+    inner_size: usize,
+) {
+    let client = output.client.clone();
+    let cube_dim = CubeDim::new(&client, num_units);
+    let cube_count = calculate_cube_count_elemwise(&client, num_units, cube_dim);
+    let dtype = dtype_to_storage_type(output.dtype);
+
+    linear_recurrence_carry_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        a.into_tensor_arg(),
+        states.into_tensor_arg(),
+        output.into_tensor_arg(),
+        num_units as u32,
+        dim_size as u32,
+        inner_size as u32,
+        CHUNK_SIZE as u32,
+        dtype,
+    );
+}
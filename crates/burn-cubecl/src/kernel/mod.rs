@@ -8,8 +8,12 @@ mod clamp;
 mod comparison;
 mod contiguous;
 mod cross;
+mod histogram;
 mod index;
+mod linear_recurrence;
 mod mask;
+mod sparse;
+mod special;
 mod unary_float;
 mod unary_int;
 mod unary_numeric;
@@ -20,7 +24,11 @@ pub(crate) use binary_int::*;
 pub use cast::*;
 pub use contiguous::*;
 pub(crate) use cross::*;
+pub(crate) use histogram::*;
+pub(crate) use linear_recurrence::*;
 pub use mask::*;
+pub(crate) use sparse::*;
+pub(crate) use special::*;
 pub(crate) use unary_float::*;
 pub(crate) use unary_int::*;
 pub(crate) use unary_numeric::*;
@@ -1,3 +1,5 @@
 mod base;
+mod pool3d;
 
 pub(crate) use base::*;
+pub(crate) use pool3d::*;
@@ -0,0 +1,714 @@
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{DType, Shape, ops::conv::calculate_pool_output_size};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    CubeRuntime, kernel::into_contiguous, ops::numeric::empty_device_dtype, tensor::CubeTensor,
+};
+
+/// Sizes of a 3D pooling over `[batch * channels, depth, height, width]` planes.
+///
+/// The window parameters are unused by the adaptive kernels, where they are set to one.
+#[derive(CubeLaunch, CubeType)]
+struct Pool3dArgs {
+    in_d: usize,
+    in_h: usize,
+    in_w: usize,
+    out_d: usize,
+    out_h: usize,
+    out_w: usize,
+    kernel_d: usize,
+    kernel_h: usize,
+    kernel_w: usize,
+    stride_d: usize,
+    stride_h: usize,
+    stride_w: usize,
+    padding_d: usize,
+    padding_h: usize,
+    padding_w: usize,
+    dilation_d: usize,
+    dilation_h: usize,
+    dilation_w: usize,
+}
+
+/// The window of an average pooling output along one dimension, as the `[start, end)` range of
+/// valid inputs and the pool size counting the padding.
+#[cube]
+fn avg_window(
+    out: usize,
+    size_in: usize,
+    kernel: usize,
+    stride: usize,
+    padding: usize,
+) -> (usize, usize, usize) {
+    // In padded coordinates, the window can't go past the padding on the right.
+    let start_padded = out * stride;
+    let mut end_padded = start_padded + kernel;
+    if end_padded > size_in + 2 * padding {
+        end_padded = size_in + 2 * padding;
+    }
+    let pool_size = end_padded - start_padded;
+
+    let mut start = 0usize;
+    if start_padded > padding {
+        start = start_padded - padding;
+    }
+    let mut end = 0usize;
+    if end_padded > padding {
+        end = end_padded - padding;
+    }
+    if end > size_in {
+        end = size_in;
+    }
+
+    (start, end, pool_size)
+}
+
+/// The `[start, end)` range of the outputs whose window may contain the input `pos` along one
+/// dimension.
+#[cube]
+fn output_range(
+    pos: usize,
+    size_in: usize,
+    size_out: usize,
+    kernel: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+    #[comptime] adaptive: bool,
+) -> (usize, usize) {
+    let mut start = 0usize;
+    let mut end = 0usize;
+
+    if adaptive {
+        start = (pos * size_out) / size_in;
+        end = ((pos + 1) * size_out + size_in - 1) / size_in;
+    } else {
+        let extent = dilation * (kernel - 1) + 1;
+        let shifted = pos + padding;
+        if shifted >= extent {
+            start = (shifted - extent + stride - 1) / stride;
+        }
+        end = shifted / stride + 1;
+    }
+    if end > size_out {
+        end = size_out;
+    }
+
+    (start, end)
+}
+
+/// Max pooling, each thread computes one output and the flat index of its maximum over the
+/// `[depth, height, width]` plane of the input.
+#[cube(launch)]
+fn max_pool3d_with_indices_kernel<E: Numeric, I: Int>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    indices: &mut Tensor<I>,
+    args: Pool3dArgs,
+    #[define(E, I)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let ow = pos % args.out_w;
+    let oh = (pos / args.out_w) % args.out_h;
+    let od = (pos / (args.out_w * args.out_h)) % args.out_d;
+    let plane = pos / (args.out_w * args.out_h * args.out_d);
+    let offset = plane * args.in_d * args.in_h * args.in_w;
+
+    let mut max_val = E::min_value();
+    let mut max_index = 0usize;
+    let mut found = false;
+
+    for kd in 0..args.kernel_d {
+        let id = (od * args.stride_d + kd * args.dilation_d) as i32 - args.padding_d as i32;
+        if id >= 0 && id < args.in_d as i32 {
+            for kh in 0..args.kernel_h {
+                let ih = (oh * args.stride_h + kh * args.dilation_h) as i32 - args.padding_h as i32;
+                if ih >= 0 && ih < args.in_h as i32 {
+                    for kw in 0..args.kernel_w {
+                        let iw = (ow * args.stride_w + kw * args.dilation_w) as i32
+                            - args.padding_w as i32;
+                        if iw >= 0 && iw < args.in_w as i32 {
+                            let index =
+                                (id as usize * args.in_h + ih as usize) * args.in_w + iw as usize;
+                            let value = input[offset + index];
+                            if !found || value > max_val {
+                                max_val = value;
+                                max_index = index;
+                                found = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    output[pos] = max_val;
+    indices[pos] = I::cast_from(max_index as u32);
+}
+
+/// Adaptive max pooling, each thread computes one output and the flat index of its maximum over
+/// the `[depth, height, width]` plane of the input.
+#[cube(launch)]
+fn adaptive_max_pool3d_with_indices_kernel<E: Numeric, I: Int>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    indices: &mut Tensor<I>,
+    args: Pool3dArgs,
+    #[define(E, I)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let ow = pos % args.out_w;
+    let oh = (pos / args.out_w) % args.out_h;
+    let od = (pos / (args.out_w * args.out_h)) % args.out_d;
+    let plane = pos / (args.out_w * args.out_h * args.out_d);
+    let offset = plane * args.in_d * args.in_h * args.in_w;
+
+    // start = floor(out * in / out_size), end = ceil((out + 1) * in / out_size)
+    let d_start = (od * args.in_d) / args.out_d;
+    let d_end = ((od + 1) * args.in_d + args.out_d - 1) / args.out_d;
+    let h_start = (oh * args.in_h) / args.out_h;
+    let h_end = ((oh + 1) * args.in_h + args.out_h - 1) / args.out_h;
+    let w_start = (ow * args.in_w) / args.out_w;
+    let w_end = ((ow + 1) * args.in_w + args.out_w - 1) / args.out_w;
+
+    let mut max_val = E::min_value();
+    let mut max_index = 0usize;
+    let mut found = false;
+
+    for id in d_start..d_end {
+        for ih in h_start..h_end {
+            for iw in w_start..w_end {
+                let index = (id * args.in_h + ih) * args.in_w + iw;
+                let value = input[offset + index];
+                if !found || value > max_val {
+                    max_val = value;
+                    max_index = index;
+                    found = true;
+                }
+            }
+        }
+    }
+
+    output[pos] = max_val;
+    indices[pos] = I::cast_from(max_index as u32);
+}
+
+/// Backward of the max poolings, each thread sums the gradients of the outputs whose index
+/// points to its input, so no atomics are needed.
+#[cube(launch)]
+fn max_pool3d_backward_kernel<E: Numeric, I: Int>(
+    grad: &Tensor<E>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<E>,
+    args: Pool3dArgs,
+    #[comptime] adaptive: bool,
+    #[define(E, I)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let iw = pos % args.in_w;
+    let ih = (pos / args.in_w) % args.in_h;
+    let id = (pos / (args.in_w * args.in_h)) % args.in_d;
+    let plane = pos / (args.in_w * args.in_h * args.in_d);
+    let offset = plane * args.out_d * args.out_h * args.out_w;
+    let target = I::cast_from(((id * args.in_h + ih) * args.in_w + iw) as u32);
+
+    let (d_start, d_end) = output_range(
+        id,
+        args.in_d,
+        args.out_d,
+        args.kernel_d,
+        args.stride_d,
+        args.padding_d,
+        args.dilation_d,
+        adaptive,
+    );
+    let (h_start, h_end) = output_range(
+        ih,
+        args.in_h,
+        args.out_h,
+        args.kernel_h,
+        args.stride_h,
+        args.padding_h,
+        args.dilation_h,
+        adaptive,
+    );
+    let (w_start, w_end) = output_range(
+        iw,
+        args.in_w,
+        args.out_w,
+        args.kernel_w,
+        args.stride_w,
+        args.padding_w,
+        args.dilation_w,
+        adaptive,
+    );
+
+    let mut sum = E::from_int(0);
+    for od in d_start..d_end {
+        for oh in h_start..h_end {
+            for ow in w_start..w_end {
+                let index = offset + (od * args.out_h + oh) * args.out_w + ow;
+                if indices[index] == target {
+                    sum += grad[index];
+                }
+            }
+        }
+    }
+
+    output[pos] = sum;
+}
+
+/// Average pooling, each thread computes one output.
+#[cube(launch)]
+fn avg_pool3d_kernel<E: Numeric>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    args: Pool3dArgs,
+    #[comptime] count_include_pad: bool,
+    #[define(E)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let ow = pos % args.out_w;
+    let oh = (pos / args.out_w) % args.out_h;
+    let od = (pos / (args.out_w * args.out_h)) % args.out_d;
+    let plane = pos / (args.out_w * args.out_h * args.out_d);
+    let offset = plane * args.in_d * args.in_h * args.in_w;
+
+    let (d_start, d_end, d_size) =
+        avg_window(od, args.in_d, args.kernel_d, args.stride_d, args.padding_d);
+    let (h_start, h_end, h_size) =
+        avg_window(oh, args.in_h, args.kernel_h, args.stride_h, args.padding_h);
+    let (w_start, w_end, w_size) =
+        avg_window(ow, args.in_w, args.kernel_w, args.stride_w, args.padding_w);
+
+    let mut sum = E::from_int(0);
+    for id in d_start..d_end {
+        for ih in h_start..h_end {
+            for iw in w_start..w_end {
+                sum += input[offset + (id * args.in_h + ih) * args.in_w + iw];
+            }
+        }
+    }
+
+    let mut divisor = (d_end - d_start) * (h_end - h_start) * (w_end - w_start);
+    if count_include_pad {
+        divisor = d_size * h_size * w_size;
+    }
+
+    output[pos] = sum / E::cast_from(divisor as u32);
+}
+
+/// Backward of the average pooling, each thread sums the gradients of the outputs whose window
+/// contains its input.
+#[cube(launch)]
+fn avg_pool3d_backward_kernel<E: Numeric>(
+    grad: &Tensor<E>,
+    output: &mut Tensor<E>,
+    args: Pool3dArgs,
+    #[comptime] count_include_pad: bool,
+    #[define(E)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let iw = pos % args.in_w;
+    let ih = (pos / args.in_w) % args.in_h;
+    let id = (pos / (args.in_w * args.in_h)) % args.in_d;
+    let plane = pos / (args.in_w * args.in_h * args.in_d);
+    let offset = plane * args.out_d * args.out_h * args.out_w;
+
+    let (od_start, od_end) = output_range(
+        id,
+        args.in_d,
+        args.out_d,
+        args.kernel_d,
+        args.stride_d,
+        args.padding_d,
+        1,
+        false,
+    );
+    let (oh_start, oh_end) = output_range(
+        ih,
+        args.in_h,
+        args.out_h,
+        args.kernel_h,
+        args.stride_h,
+        args.padding_h,
+        1,
+        false,
+    );
+    let (ow_start, ow_end) = output_range(
+        iw,
+        args.in_w,
+        args.out_w,
+        args.kernel_w,
+        args.stride_w,
+        args.padding_w,
+        1,
+        false,
+    );
+
+    let mut sum = E::from_int(0);
+    for od in od_start..od_end {
+        let (d_start, d_end, d_size) =
+            avg_window(od, args.in_d, args.kernel_d, args.stride_d, args.padding_d);
+        for oh in oh_start..oh_end {
+            let (h_start, h_end, h_size) =
+                avg_window(oh, args.in_h, args.kernel_h, args.stride_h, args.padding_h);
+            for ow in ow_start..ow_end {
+                let (w_start, w_end, w_size) =
+                    avg_window(ow, args.in_w, args.kernel_w, args.stride_w, args.padding_w);
+
+                let mut divisor = (d_end - d_start) * (h_end - h_start) * (w_end - w_start);
+                if count_include_pad {
+                    divisor = d_size * h_size * w_size;
+                }
+
+                let index = offset + (od * args.out_h + oh) * args.out_w + ow;
+                sum += grad[index] / E::cast_from(divisor as u32);
+            }
+        }
+    }
+
+    output[pos] = sum;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pool3d_args(
+    input_shape: &Shape,
+    output_shape: &Shape,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Pool3dArgsLaunch {
+    Pool3dArgsLaunch::new(
+        input_shape[2],
+        input_shape[3],
+        input_shape[4],
+        output_shape[2],
+        output_shape[3],
+        output_shape[4],
+        kernel_size[0],
+        kernel_size[1],
+        kernel_size[2],
+        stride[0],
+        stride[1],
+        stride[2],
+        padding[0],
+        padding[1],
+        padding[2],
+        dilation[0],
+        dilation[1],
+        dilation[2],
+    )
+}
+
+fn pool3d_output_shape(
+    x: &Shape,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+    ceil_mode: bool,
+) -> Shape {
+    let [batch_size, channels, in_d, in_h, in_w] = x.dims();
+    let size = |dim: usize, size_in: usize| {
+        calculate_pool_output_size(
+            kernel_size[dim],
+            stride[dim],
+            padding[dim],
+            dilation[dim],
+            size_in,
+            ceil_mode,
+        )
+    };
+
+    Shape::new([
+        batch_size,
+        channels,
+        size(0, in_d),
+        size(1, in_h),
+        size(2, in_w),
+    ])
+}
+
+pub(crate) fn max_pool3d_with_indices<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+    ceil_mode: bool,
+    dtype_indices: DType,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let x = into_contiguous(x);
+    let shape_in = x.meta.shape().clone();
+    let shape_out =
+        pool3d_output_shape(&shape_in, kernel_size, stride, padding, dilation, ceil_mode);
+
+    let client = x.client.clone();
+    let dtype = x.dtype;
+    let output = empty_device_dtype(client.clone(), x.device.clone(), shape_out.clone(), dtype);
+    let indices = empty_device_dtype(
+        client.clone(),
+        x.device.clone(),
+        shape_out.clone(),
+        dtype_indices,
+    );
+
+    let num_elems = shape_out.num_elements();
+    if num_elems == 0 {
+        return (output, indices);
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    max_pool3d_with_indices_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        x.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        indices.clone().into_tensor_arg(),
+        pool3d_args(
+            &shape_in,
+            &shape_out,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        ),
+        [
+            dtype_to_storage_type(dtype),
+            dtype_to_storage_type(dtype_indices),
+        ],
+    );
+
+    (output, indices)
+}
+
+pub(crate) fn adaptive_max_pool3d_with_indices<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    output_size: [usize; 3],
+    dtype_indices: DType,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let x = into_contiguous(x);
+    let shape_in = x.meta.shape().clone();
+    let shape_out = Shape::new([
+        shape_in[0],
+        shape_in[1],
+        output_size[0],
+        output_size[1],
+        output_size[2],
+    ]);
+
+    let client = x.client.clone();
+    let dtype = x.dtype;
+    let output = empty_device_dtype(client.clone(), x.device.clone(), shape_out.clone(), dtype);
+    let indices = empty_device_dtype(
+        client.clone(),
+        x.device.clone(),
+        shape_out.clone(),
+        dtype_indices,
+    );
+
+    let num_elems = shape_out.num_elements();
+    if num_elems == 0 {
+        return (output, indices);
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    adaptive_max_pool3d_with_indices_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        x.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        indices.clone().into_tensor_arg(),
+        pool3d_args(&shape_in, &shape_out, [1; 3], [1; 3], [0; 3], [1; 3]),
+        [
+            dtype_to_storage_type(dtype),
+            dtype_to_storage_type(dtype_indices),
+        ],
+    );
+
+    (output, indices)
+}
+
+pub(crate) fn max_pool3d_with_indices_backward<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> CubeTensor<R> {
+    max_pool3d_backward(
+        x,
+        grad,
+        indices,
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        false,
+    )
+}
+
+pub(crate) fn adaptive_max_pool3d_with_indices_backward<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    indices: CubeTensor<R>,
+) -> CubeTensor<R> {
+    max_pool3d_backward(x, grad, indices, [1; 3], [1; 3], [0; 3], [1; 3], true)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn max_pool3d_backward<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+    adaptive: bool,
+) -> CubeTensor<R> {
+    let grad = into_contiguous(grad);
+    let indices = into_contiguous(indices);
+    let shape_in = x.meta.shape().clone();
+    let shape_out = grad.meta.shape().clone();
+
+    let client = grad.client.clone();
+    let dtype = grad.dtype;
+    let dtype_indices = indices.dtype;
+    let output = empty_device_dtype(client.clone(), x.device.clone(), shape_in.clone(), dtype);
+
+    let num_elems = shape_in.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    max_pool3d_backward_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        grad.into_tensor_arg(),
+        indices.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        pool3d_args(
+            &shape_in,
+            &shape_out,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        ),
+        adaptive,
+        [
+            dtype_to_storage_type(dtype),
+            dtype_to_storage_type(dtype_indices),
+        ],
+    );
+
+    output
+}
+
+pub(crate) fn avg_pool3d<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+    ceil_mode: bool,
+) -> CubeTensor<R> {
+    let x = into_contiguous(x);
+    let shape_in = x.meta.shape().clone();
+    let shape_out = pool3d_output_shape(&shape_in, kernel_size, stride, padding, [1; 3], ceil_mode);
+
+    let client = x.client.clone();
+    let dtype = x.dtype;
+    let output = empty_device_dtype(client.clone(), x.device.clone(), shape_out.clone(), dtype);
+
+    let num_elems = shape_out.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    avg_pool3d_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        x.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        pool3d_args(&shape_in, &shape_out, kernel_size, stride, padding, [1; 3]),
+        count_include_pad,
+        dtype_to_storage_type(dtype),
+    );
+
+    output
+}
+
+pub(crate) fn avg_pool3d_backward<R: CubeRuntime>(
+    x: CubeTensor<R>,
+    grad: CubeTensor<R>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> CubeTensor<R> {
+    let grad = into_contiguous(grad);
+    let shape_in = x.meta.shape().clone();
+    let shape_out = grad.meta.shape().clone();
+
+    let client = grad.client.clone();
+    let dtype = grad.dtype;
+    let output = empty_device_dtype(client.clone(), x.device.clone(), shape_in.clone(), dtype);
+
+    let num_elems = shape_in.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    avg_pool3d_backward_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        grad.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        pool3d_args(&shape_in, &shape_out, kernel_size, stride, padding, [1; 3]),
+        count_include_pad,
+        dtype_to_storage_type(dtype),
+    );
+
+    output
+}
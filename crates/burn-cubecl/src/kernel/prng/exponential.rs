@@ -0,0 +1,48 @@
+use crate::{CubeRuntime, tensor::CubeTensor};
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{DType, Shape, TensorMetadata};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::random_uniform;
+
+/// Replaces the uniform samples in `[0, 1)` with `-ln(1 - u) / rate`.
+#[cube(launch)]
+fn exponential_kernel<F: Float>(
+    values: &mut Tensor<F>,
+    rate: InputScalar,
+    #[define(F)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= values.len() {
+        terminate!();
+    }
+
+    values[pos] = -(F::new(1.0) - values[pos]).ln() / rate.get::<F>();
+}
+
+/// Pseudo-random generator with exponential distribution, sampled by inversion.
+pub fn random_exponential<R: CubeRuntime>(
+    shape: Shape,
+    device: &R::Device,
+    rate: f32,
+    dtype: DType,
+) -> CubeTensor<R> {
+    let output = random_uniform(shape, device, 0., 1., dtype);
+    let client = output.client.clone();
+
+    let num_elems = output.shape().num_elements();
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+    let storage = dtype_to_storage_type(dtype);
+
+    exponential_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        output.clone().into_tensor_arg(),
+        InputScalar::new(rate, storage),
+        storage,
+    );
+
+    output
+}
@@ -0,0 +1,160 @@
+use crate::{
+    CubeRuntime,
+    kernel::into_contiguous,
+    ops::numeric::{add, div, full_device_dtype, mul_scalar},
+    tensor::CubeTensor,
+};
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{DType, Shape, TensorMetadata};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{MAX_LAUNCHES, has_pending, random_normal, random_uniform, reject_pending};
+
+/// Number of candidates drawn for each sample in a launch. Each candidate is accepted with a
+/// probability above 95%, so another launch is rarely needed.
+const ATTEMPTS: usize = 8;
+
+/// Marsaglia and Tsang's method for `Gamma(alpha, 1)`, with `d = alpha - 1/3` and
+/// `c = 1 / sqrt(9d)`.
+///
+/// Only the elements of `output` that are still negative, i.e. that haven't accepted a candidate
+/// yet, are sampled. The candidates are read from the rows of `normals` (`[ATTEMPTS, N]`) and
+/// `uniforms` (`[ATTEMPTS + 1, N]`), whose last row is used to boost `Gamma(alpha + 1, 1)`
+/// samples to `Gamma(alpha, 1)` when the shape is below 1.
+#[cube(launch)]
+fn gamma_kernel<F: Float>(
+    alpha: &Tensor<F>,
+    normals: &Tensor<F>,
+    uniforms: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[define(F)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    let num_elems = output.len();
+    if pos >= num_elems {
+        terminate!();
+    }
+    if output[pos] >= F::new(0.0) {
+        terminate!();
+    }
+
+    let alpha = alpha[pos];
+    let boosted = alpha < F::new(1.0);
+    let mut shape = alpha;
+    if boosted {
+        shape += F::new(1.0);
+    }
+    let d = shape - F::new(1.0 / 3.0);
+    let c = F::new(1.0) / (F::new(9.0) * d).sqrt();
+    let mut result = F::new(-1.0);
+
+    for attempt in 0..ATTEMPTS {
+        let z = normals[attempt * num_elems + pos];
+        let u = uniforms[attempt * num_elems + pos];
+        let t = F::new(1.0) + c * z;
+        if result < F::new(0.0) && t > F::new(0.0) {
+            let v = t * t * t;
+            if u.ln() < F::new(0.5) * z * z + d - d * v + d * v.ln() {
+                result = d * v;
+            }
+        }
+    }
+
+    // Gamma(alpha) = Gamma(alpha + 1) * U^(1 / alpha) for alpha < 1.
+    if boosted && result >= F::new(0.0) {
+        let u = uniforms[ATTEMPTS * num_elems + pos];
+        result *= F::powf(u, F::new(1.0) / alpha);
+    }
+
+    output[pos] = result;
+}
+
+/// Pseudo-random generator with gamma distribution whose shape parameters are given by `alpha`,
+/// with a scale of 1.
+///
+/// Launches are repeated with new candidates until every sample has accepted one, up to
+/// [MAX_LAUNCHES] launches after which the remaining samples are NaN.
+pub fn random_gamma_like<R: CubeRuntime>(alpha: CubeTensor<R>) -> CubeTensor<R> {
+    let alpha = into_contiguous(alpha);
+    let client = alpha.client.clone();
+    let device = alpha.device.clone();
+    let dtype = alpha.dtype;
+    let storage = dtype_to_storage_type(dtype);
+    let num_elems = alpha.shape().num_elements();
+
+    // Every sample is positive, so a negative value marks the ones still to be accepted.
+    let output = full_device_dtype(
+        client.clone(),
+        alpha.shape(),
+        device.clone(),
+        InputScalar::new(-1.0f32, storage),
+        dtype,
+    );
+
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    for _ in 0..MAX_LAUNCHES {
+        if !has_pending(&output) {
+            return output;
+        }
+
+        let normals = random_normal(Shape::new([ATTEMPTS, num_elems]), &device, 0., 1., dtype);
+        let uniforms = random_uniform(
+            Shape::new([ATTEMPTS + 1, num_elems]),
+            &device,
+            0.,
+            1.,
+            dtype,
+        );
+
+        gamma_kernel::launch::<R>(
+            &client,
+            cube_count.clone(),
+            cube_dim,
+            alpha.clone().into_tensor_arg(),
+            normals.into_tensor_arg(),
+            uniforms.into_tensor_arg(),
+            output.clone().into_tensor_arg(),
+            storage,
+        );
+    }
+
+    reject_pending(output)
+}
+
+/// Pseudo-random generator with gamma distribution of the given shape and scale.
+pub fn random_gamma<R: CubeRuntime>(
+    shape: Shape,
+    device: &R::Device,
+    gamma_shape: f32,
+    scale: f32,
+    dtype: DType,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let storage = dtype_to_storage_type(dtype);
+    let alpha = full_device_dtype(
+        client,
+        shape,
+        device.clone(),
+        InputScalar::new(gamma_shape, storage),
+        dtype,
+    );
+
+    mul_scalar(random_gamma_like(alpha), InputScalar::new(scale, storage))
+}
+
+/// Pseudo-random generator with beta distribution, sampled as `X / (X + Y)` with
+/// `X ~ Gamma(alpha, 1)` and `Y ~ Gamma(beta, 1)`.
+pub fn random_beta<R: CubeRuntime>(
+    shape: Shape,
+    device: &R::Device,
+    alpha: f32,
+    beta: f32,
+    dtype: DType,
+) -> CubeTensor<R> {
+    let x = random_gamma(shape.clone(), device, alpha, 1.0, dtype);
+    let y = random_gamma(shape, device, beta, 1.0, dtype);
+
+    div(x.clone(), add(x, y))
+}
@@ -1,7 +1,45 @@
 mod bernoulli;
+mod exponential;
+mod gamma;
 mod normal;
+mod poisson;
 mod uniform;
 
 pub use bernoulli::*;
+pub use exponential::*;
+pub use gamma::*;
 pub use normal::*;
+pub use poisson::*;
 pub use uniform::*;
+
+use crate::{CubeBackend, CubeRuntime, ops::into_data, tensor::CubeTensor};
+use burn_backend::{
+    get_device_settings,
+    ops::{BoolTensorOps, FloatTensorOps},
+};
+
+/// Maximum number of launches of a rejection method. Every launch tries several candidates, so
+/// it's only reached when the parameters can't accept any, e.g. a non-positive gamma shape.
+pub(crate) const MAX_LAUNCHES: usize = 8;
+
+/// Whether an element of `samples` is still negative, which the rejection methods use to mark
+/// the samples that haven't accepted a candidate yet.
+pub(crate) fn has_pending<R: CubeRuntime>(samples: &CubeTensor<R>) -> bool {
+    let bool_dtype = get_device_settings::<CubeBackend<R>>(&samples.device).bool_dtype;
+    let pending = CubeBackend::<R>::float_lower_elem(samples.clone(), 0f64.into(), bool_dtype);
+    let pending = CubeBackend::<R>::bool_any(pending);
+
+    burn_std::future::block_on(into_data(pending))
+        .expect("Failed to read the rejected samples")
+        .iter::<bool>()
+        .next()
+        .unwrap()
+}
+
+/// Replaces the samples that are still negative after [MAX_LAUNCHES] launches with NaN.
+pub(crate) fn reject_pending<R: CubeRuntime>(samples: CubeTensor<R>) -> CubeTensor<R> {
+    let bool_dtype = get_device_settings::<CubeBackend<R>>(&samples.device).bool_dtype;
+    let pending = CubeBackend::<R>::float_lower_elem(samples.clone(), 0f64.into(), bool_dtype);
+
+    CubeBackend::<R>::float_mask_fill(samples, pending, f64::NAN.into())
+}
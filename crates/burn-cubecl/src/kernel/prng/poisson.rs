@@ -0,0 +1,145 @@
+use crate::{
+    CubeRuntime,
+    kernel::special::lgamma,
+    ops::numeric::{empty_device_dtype, full_device_dtype},
+    tensor::CubeTensor,
+};
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{DType, Shape};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use super::{MAX_LAUNCHES, has_pending, random_uniform, reject_pending};
+
+/// Rate from which the transformed rejection method is used instead of the inversion.
+const LARGE_RATE: f32 = 10.0;
+
+/// Number of steps of the inversion, the probability of a larger sample is negligible for a
+/// rate below [`LARGE_RATE`].
+const INVERSION_STEPS: u32 = 64;
+
+/// Number of candidates drawn for each sample in a launch of the transformed rejection method.
+/// Each candidate is accepted with a probability above 89%, so another launch is rarely needed.
+const ATTEMPTS: usize = 10;
+
+/// Samples `Poisson(rate)` from the uniforms in `[0, 1)` of `uniforms`, which has a single row
+/// for the inversion and `2 * ATTEMPTS` rows for the transformed rejection method of W. Hörmann,
+/// "The transformed rejection method for generating Poisson random variables".
+///
+/// The rejection method only samples the elements of `output` that are still negative, i.e.
+/// that haven't accepted a candidate yet.
+#[cube(launch)]
+fn poisson_kernel<F: Float>(
+    uniforms: &Tensor<F>,
+    output: &mut Tensor<F>,
+    rate: InputScalar,
+    #[comptime] large: bool,
+    #[define(F)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    let num_elems = output.len();
+    if pos >= num_elems {
+        terminate!();
+    }
+
+    let rate = rate.get::<F>();
+
+    if comptime!(large) {
+        if output[pos] >= F::new(0.0) {
+            terminate!();
+        }
+
+        let sqrt_rate = rate.sqrt();
+        let log_rate = rate.ln();
+        let b = F::new(0.931) + F::new(2.53) * sqrt_rate;
+        let a = F::new(-0.059) + F::new(0.024_83) * b;
+        let inv_alpha = F::new(1.123_9) + F::new(1.132_8) / (b - F::new(3.4));
+        let v_r = F::new(0.927_7) - F::new(3.622_4) / (b - F::new(2.0));
+
+        let mut result = F::new(-1.0);
+        let mut found = false;
+        for attempt in 0..ATTEMPTS {
+            let u = uniforms[2 * attempt * num_elems + pos] - F::new(0.5);
+            let v = uniforms[(2 * attempt + 1) * num_elems + pos];
+            let us = F::new(0.5) - u.abs();
+            let k = ((F::new(2.0) * a / us + b) * u + rate + F::new(0.43)).floor();
+
+            if !found && us >= F::new(0.07) && v <= v_r {
+                result = k;
+                found = true;
+            }
+            let rejected = k < F::new(0.0) || (us < F::new(0.013) && v > us);
+            if !found && !rejected {
+                let log_ratio = v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln();
+                if log_ratio <= -rate + k * log_rate - lgamma::<F>(k + F::new(1.0)) {
+                    result = k;
+                    found = true;
+                }
+            }
+        }
+        output[pos] = result;
+    } else {
+        let u = uniforms[pos];
+        let mut p = (-rate).exp();
+        let mut cdf = p;
+        let mut k = F::new(0.0);
+        for i in 1..INVERSION_STEPS {
+            if u > cdf {
+                k = F::cast_from(i);
+                p *= rate / k;
+                cdf += p;
+            }
+        }
+        output[pos] = k;
+    }
+}
+
+/// Pseudo-random generator with Poisson distribution of the given rate.
+pub fn random_poisson<R: CubeRuntime>(
+    shape: Shape,
+    device: &R::Device,
+    rate: f32,
+    dtype: DType,
+) -> CubeTensor<R> {
+    let client = R::client(device);
+    let storage = dtype_to_storage_type(dtype);
+    let num_elems = shape.num_elements();
+
+    let large = rate >= LARGE_RATE;
+    let rows = if large { 2 * ATTEMPTS } else { 1 };
+    let output = if large {
+        // Every sample is non-negative, so a negative value marks the ones still to be accepted.
+        full_device_dtype(
+            client.clone(),
+            shape,
+            device.clone(),
+            InputScalar::new(-1.0f32, storage),
+            dtype,
+        )
+    } else {
+        empty_device_dtype(client.clone(), device.clone(), shape, dtype)
+    };
+
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    for _ in 0..MAX_LAUNCHES {
+        let uniforms = random_uniform(Shape::new([rows, num_elems]), device, 0., 1., dtype);
+
+        poisson_kernel::launch::<R>(
+            &client,
+            cube_count.clone(),
+            cube_dim,
+            uniforms.into_tensor_arg(),
+            output.clone().into_tensor_arg(),
+            InputScalar::new(rate, storage),
+            large,
+            storage,
+        );
+
+        if !large || !has_pending(&output) {
+            return output;
+        }
+    }
+
+    reject_pending(output)
+}
@@ -1,9 +1,11 @@
 mod base;
 #[cfg(feature = "autotune")]
 mod bounds;
+mod nan;
 #[cfg(feature = "autotune")]
 mod tune;
 
 pub use base::*;
+pub use nan::*;
 #[cfg(feature = "autotune")]
 pub use tune::*;
@@ -0,0 +1,92 @@
+use burn_backend::{
+    get_device_settings,
+    ops::{BoolTensorOps, FloatTensorOps},
+};
+use burn_std::BoolDType;
+use cubek::reduce::components::instructions::ReduceOperationConfig;
+
+use crate::{CubeBackend, CubeRuntime, tensor::CubeTensor};
+
+use super::{reduce_dim, reduce_logical};
+
+type B<R> = CubeBackend<R>;
+
+fn reduce_along<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+    config: ReduceOperationConfig,
+) -> CubeTensor<R> {
+    reduce_dim(tensor, None, dim, Default::default(), config).unwrap()
+}
+
+fn bool_dtype<R: CubeRuntime>(tensor: &CubeTensor<R>) -> BoolDType {
+    get_device_settings::<B<R>>(&tensor.device).bool_dtype
+}
+
+/// Computes `log(sum(exp(x)))` along `dim`, keeping it with a size of 1.
+///
+/// The line is shifted by its maximum before the exponential so the sum can't overflow. Both
+/// passes run on the [max](ReduceOperationConfig::Max) and [sum](ReduceOperationConfig::Sum)
+/// reduce instructions.
+pub fn logsumexp_dim<R: CubeRuntime>(tensor: CubeTensor<R>, dim: usize) -> CubeTensor<R> {
+    let bool_dtype = bool_dtype(&tensor);
+    let max = reduce_along(tensor.clone(), dim, ReduceOperationConfig::Max);
+
+    // An infinite maximum is the result, so the shift is zeroed to avoid `inf - inf`.
+    let is_inf = B::<R>::float_is_inf(max.clone(), bool_dtype);
+    let shift = B::<R>::float_mask_fill(max, is_inf, 0f64.into());
+
+    let exp = B::<R>::float_exp(B::<R>::float_sub(tensor, shift.clone()));
+    let sum = reduce_along(exp, dim, ReduceOperationConfig::Sum);
+
+    B::<R>::float_add(B::<R>::float_log(sum), shift)
+}
+
+/// Sums the non-NaN elements along `dim`, keeping it with a size of 1.
+pub fn nansum_dim<R: CubeRuntime>(tensor: CubeTensor<R>, dim: usize) -> CubeTensor<R> {
+    let is_nan = B::<R>::float_is_nan(tensor.clone(), bool_dtype(&tensor));
+    let tensor = B::<R>::float_mask_fill(tensor, is_nan, 0f64.into());
+
+    reduce_along(tensor, dim, ReduceOperationConfig::Sum)
+}
+
+/// Averages the non-NaN elements along `dim`, keeping it with a size of 1.
+///
+/// An all-NaN line gives NaN, as `0 / 0`.
+pub fn nanmean_dim<R: CubeRuntime>(tensor: CubeTensor<R>, dim: usize) -> CubeTensor<R> {
+    let dtype = tensor.dtype;
+    let is_nan = B::<R>::float_is_nan(tensor.clone(), bool_dtype(&tensor));
+    let is_number = B::<R>::bool_not(is_nan.clone());
+
+    let sum = reduce_along(
+        B::<R>::float_mask_fill(tensor, is_nan, 0f64.into()),
+        dim,
+        ReduceOperationConfig::Sum,
+    );
+    let count = reduce_along(
+        B::<R>::bool_into_float(is_number, dtype.into()),
+        dim,
+        ReduceOperationConfig::Sum,
+    );
+
+    B::<R>::float_div(sum, count)
+}
+
+/// Takes the maximum of the non-NaN elements along `dim`, keeping it with a size of 1.
+///
+/// An all-NaN line gives NaN.
+pub fn nanmax_dim<R: CubeRuntime>(tensor: CubeTensor<R>, dim: usize) -> CubeTensor<R> {
+    let bool_dtype = bool_dtype(&tensor);
+    let is_nan = B::<R>::float_is_nan(tensor.clone(), bool_dtype);
+    let all_nan = reduce_logical(
+        is_nan.clone(),
+        Some(dim),
+        ReduceOperationConfig::All,
+        bool_dtype,
+    );
+
+    let tensor = B::<R>::float_mask_fill(tensor, is_nan, f64::NEG_INFINITY.into());
+    let max = reduce_along(tensor, dim, ReduceOperationConfig::Max);
+
+    B::<R>::float_mask_fill(max, all_nan, f64::NAN.into())
+}
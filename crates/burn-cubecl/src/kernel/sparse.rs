@@ -0,0 +1,94 @@
+use burn_backend::cubecl::dtype_to_storage_type;
+use burn_backend::{Shape, TensorMetadata};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    CubeRuntime,
+    kernel::{cast, into_contiguous},
+    ops::numeric::{empty_device_dtype, zeros_client},
+    tensor::CubeTensor,
+};
+
+/// Sparse-dense matrix multiplication kernel for the compressed sparse row layout.
+///
+/// Each thread computes one output element, so it only visits the stored values of its row and
+/// no atomic accumulation is needed.
+#[cube(launch)]
+fn csr_spmm_kernel<F: Float, I: Int>(
+    crow_indices: &Tensor<I>, // [m + 1]
+    col_indices: &Tensor<I>,  // [nnz]
+    values: &Tensor<F>,       // [nnz]
+    dense: &Tensor<F>,        // [k, n]
+    output: &mut Tensor<F>,   // [m, n]
+    #[define(F, I)] _dtypes: [StorageType; 2],
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    let n = output.shape(1);
+    let row = pos / n;
+    let col = pos % n;
+    let start = usize::cast_from(crow_indices[row]);
+    let end = usize::cast_from(crow_indices[row + 1]);
+
+    let mut sum = F::new(0.0);
+    for i in start..end {
+        let k = usize::cast_from(col_indices[i]);
+        sum += values[i] * dense[k * n + col];
+    }
+
+    output[pos] = sum;
+}
+
+/// Multiplies a sparse matrix in the compressed sparse row layout with the dense matrix.
+pub(crate) fn csr_spmm<R: CubeRuntime>(
+    crow_indices: CubeTensor<R>,
+    col_indices: CubeTensor<R>,
+    values: CubeTensor<R>,
+    dense: CubeTensor<R>,
+) -> CubeTensor<R> {
+    let crow_indices = into_contiguous(crow_indices);
+    let index_dtype = crow_indices.dtype;
+    let col_indices = match col_indices.dtype == index_dtype {
+        true => into_contiguous(col_indices),
+        false => cast(into_contiguous(col_indices), index_dtype),
+    };
+    let dense = into_contiguous(dense);
+    let dtype = dense.dtype;
+    let values = match values.dtype == dtype {
+        true => into_contiguous(values),
+        false => cast(into_contiguous(values), dtype),
+    };
+
+    let client = dense.client.clone();
+    let rows = crow_indices.shape()[0] - 1;
+    let [_, cols] = dense.shape().dims();
+    let shape = Shape::new([rows, cols]);
+
+    let num_elems = shape.num_elements();
+    if num_elems == 0 || values.shape().num_elements() == 0 {
+        return zeros_client::<R>(client, dense.device.clone(), shape, dtype);
+    }
+    let output = empty_device_dtype::<R>(client.clone(), dense.device.clone(), shape, dtype);
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    csr_spmm_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        crow_indices.into_tensor_arg(),
+        col_indices.into_tensor_arg(),
+        values.into_tensor_arg(),
+        dense.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        [
+            dtype_to_storage_type(dtype),
+            dtype_to_storage_type(index_dtype),
+        ],
+    );
+
+    output
+}
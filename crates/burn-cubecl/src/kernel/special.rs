@@ -0,0 +1,321 @@
+#![allow(clippy::excessive_precision)]
+
+use burn_backend::TensorMetadata;
+use burn_backend::cubecl::dtype_to_storage_type;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{CubeRuntime, ops::numeric::empty_device_dtype, tensor::CubeTensor};
+
+/// Special functions evaluated elementwise by [`special_unary`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpecialFunction {
+    /// Natural logarithm of the absolute value of the gamma function.
+    Lgamma,
+    /// Logarithmic derivative of the gamma function.
+    Digamma,
+    /// `n`-th derivative of the digamma function, with `n >= 1`.
+    Polygamma,
+    /// Inverse of the error function.
+    Erfinv,
+    /// Modified Bessel function of the first kind of order 0.
+    I0,
+    /// Modified Bessel function of the first kind of order 1.
+    I1,
+}
+
+/// Number of recurrence steps applied before the asymptotic expansions of digamma and polygamma.
+const SHIFT: u32 = 6;
+
+/// `B_2k / (2k)!` for `k = 1..=5`, with `B_2k` the Bernoulli numbers.
+const BERNOULLI_OVER_FACTORIAL: [f64; 5] = [
+    1.0 / 12.0,
+    -1.0 / 720.0,
+    1.0 / 30_240.0,
+    -1.0 / 1_209_600.0,
+    1.0 / 47_900_160.0,
+];
+
+#[cube(launch)]
+fn special_unary_kernel<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] function: SpecialFunction,
+    #[comptime] order: u32,
+    #[define(F)] _dtype: StorageType,
+) {
+    let pos = ABSOLUTE_POS;
+    if pos >= output.len() {
+        terminate!();
+    }
+
+    // The output is contiguous, while the input can have any layout.
+    let mut offset = 0usize;
+    for d in 0..input.rank() {
+        let coord = (pos / output.stride(d)) % output.shape(d);
+        offset += coord * input.stride(d);
+    }
+    let x = input[offset];
+
+    if comptime!(function == SpecialFunction::Lgamma) {
+        output[pos] = lgamma(x);
+    } else if comptime!(function == SpecialFunction::Digamma) {
+        output[pos] = digamma(x);
+    } else if comptime!(function == SpecialFunction::Polygamma) {
+        output[pos] = polygamma(x, order);
+    } else if comptime!(function == SpecialFunction::Erfinv) {
+        output[pos] = erfinv(x);
+    } else if comptime!(function == SpecialFunction::I0) {
+        output[pos] = bessel_i0(x);
+    } else {
+        output[pos] = bessel_i1(x);
+    }
+}
+
+/// `acc * x + c`, the step of Horner's method.
+#[cube]
+fn horner_step<F: Float>(acc: F, x: F, #[comptime] c: f32) -> F {
+    acc * x + F::new(c)
+}
+
+/// Lanczos approximation with `g = 7`, and the reflection formula for `x < 0.5`.
+#[cube]
+pub(crate) fn lgamma<F: Float>(input: F) -> F {
+    let reflect = input < F::new(0.5);
+    let mut x = input;
+    if reflect {
+        x = F::new(1.0) - input;
+    }
+
+    let z = x - F::new(1.0);
+    let mut series = F::new(1.0);
+    series += F::new(676.520_4) / (z + F::new(1.0));
+    series += F::new(-1_259.139_2) / (z + F::new(2.0));
+    series += F::new(771.323_4) / (z + F::new(3.0));
+    series += F::new(-176.615_03) / (z + F::new(4.0));
+    series += F::new(12.507_343) / (z + F::new(5.0));
+    series += F::new(-0.138_571_1) / (z + F::new(6.0));
+    series += F::new(9.984_369_6e-6) / (z + F::new(7.0));
+    series += F::new(1.505_632_7e-7) / (z + F::new(8.0));
+
+    let t = z + F::new(7.5);
+    let mut result = (z + F::new(0.5)) * t.ln() - t + series.ln() + F::new(0.918_938_5);
+
+    if reflect {
+        let sin = (input * F::new(core::f32::consts::PI)).sin().abs();
+        result = (F::new(core::f32::consts::PI) / sin).ln() - result;
+    }
+    result
+}
+
+/// Recurrence up to `x + 6` followed by the asymptotic expansion, and the reflection formula
+/// for `x < 0.5`.
+#[cube]
+fn digamma<F: Float>(input: F) -> F {
+    let reflect = input < F::new(0.5);
+    let mut x = input;
+    if reflect {
+        x = F::new(1.0) - input;
+    }
+
+    let mut shift = F::new(0.0);
+    for k in 0..SHIFT {
+        shift += F::new(1.0) / (x + F::cast_from(k));
+    }
+
+    let y = x + F::cast_from(SHIFT);
+    let r = F::new(1.0) / (y * y);
+    let mut series = F::new(comptime!(digamma_coefficient(4)));
+    series = horner_step::<F>(series, r, comptime!(digamma_coefficient(3)));
+    series = horner_step::<F>(series, r, comptime!(digamma_coefficient(2)));
+    series = horner_step::<F>(series, r, comptime!(digamma_coefficient(1)));
+    series = horner_step::<F>(series, r, comptime!(digamma_coefficient(0)));
+    let mut result = y.ln() - F::new(0.5) / y + series * r - shift;
+
+    if reflect {
+        let tan = (input * F::new(core::f32::consts::PI)).tan();
+        result -= F::new(core::f32::consts::PI) / tan;
+    }
+    result
+}
+
+/// `(-1)^(n + 1) n! zeta(n + 1, x)`, with the Hurwitz zeta function computed with its
+/// recurrence followed by the Euler-Maclaurin expansion.
+#[cube]
+fn polygamma<F: Float>(x: F, #[comptime] n: u32) -> F {
+    let exponent = F::new(comptime!(-((n + 1) as f32)));
+    let shift = comptime!(SHIFT + n);
+
+    let mut zeta = F::new(0.0);
+    for k in 0..shift {
+        zeta += F::powf(x + F::cast_from(k), exponent);
+    }
+
+    let y = x + F::cast_from(shift);
+    let r = F::new(1.0) / (y * y);
+    let mut series = F::new(comptime!(polygamma_coefficient(n, 4)));
+    series = horner_step::<F>(series, r, comptime!(polygamma_coefficient(n, 3)));
+    series = horner_step::<F>(series, r, comptime!(polygamma_coefficient(n, 2)));
+    series = horner_step::<F>(series, r, comptime!(polygamma_coefficient(n, 1)));
+    series = horner_step::<F>(series, r, comptime!(polygamma_coefficient(n, 0)));
+    let tail = y / F::new(comptime!(n as f32)) + F::new(0.5) + series * r * y;
+    zeta += F::powf(y, exponent) * tail;
+
+    zeta * F::new(comptime!(polygamma_factor(n)))
+}
+
+/// Approximation of M. Giles, "Approximating the erfinv function", refined by a Newton step.
+#[cube]
+fn erfinv<F: Float>(x: F) -> F {
+    let w = -((F::new(1.0) - x) * (F::new(1.0) + x)).ln();
+    let mut p = F::new(0.0);
+    if w < F::new(5.0) {
+        let t = w - F::new(2.5);
+        p = F::new(2.810_226_36e-8);
+        p = horner_step::<F>(p, t, 3.432_739_39e-7);
+        p = horner_step::<F>(p, t, -3.523_387_7e-6);
+        p = horner_step::<F>(p, t, -4.391_506_54e-6);
+        p = horner_step::<F>(p, t, 2.185_808_7e-4);
+        p = horner_step::<F>(p, t, -1.253_725_03e-3);
+        p = horner_step::<F>(p, t, -4.177_681_64e-3);
+        p = horner_step::<F>(p, t, 2.466_407_27e-1);
+        p = horner_step::<F>(p, t, 1.501_409_41);
+    } else {
+        let t = w.sqrt() - F::new(3.0);
+        p = F::new(-2.002_142_57e-4);
+        p = horner_step::<F>(p, t, 1.009_505_58e-4);
+        p = horner_step::<F>(p, t, 1.349_343_22e-3);
+        p = horner_step::<F>(p, t, -3.673_428_44e-3);
+        p = horner_step::<F>(p, t, 5.739_507_73e-3);
+        p = horner_step::<F>(p, t, -7.622_461_3e-3);
+        p = horner_step::<F>(p, t, 9.438_870_47e-3);
+        p = horner_step::<F>(p, t, 1.001_674_06);
+        p = horner_step::<F>(p, t, 2.832_976_82);
+    }
+    let mut y = p * x;
+
+    if x.abs() < F::new(1.0) {
+        let error = y.erf() - x;
+        y -= error / (F::new(core::f32::consts::FRAC_2_SQRT_PI) * (-y * y).exp());
+    } else if x.abs() == F::new(1.0) {
+        // The division by zero gives an infinity with the sign of `x`.
+        y = x / F::new(0.0);
+    }
+    y
+}
+
+/// Abramowitz and Stegun approximation (9.8.1 and 9.8.2).
+#[cube]
+fn bessel_i0<F: Float>(x: F) -> F {
+    let abs = x.abs();
+    let mut result = F::new(0.0);
+    if abs < F::new(3.75) {
+        let t = (x / F::new(3.75)) * (x / F::new(3.75));
+        let mut p = F::new(0.004_581_3);
+        p = horner_step::<F>(p, t, 0.036_076_8);
+        p = horner_step::<F>(p, t, 0.265_973_2);
+        p = horner_step::<F>(p, t, 1.206_749_2);
+        p = horner_step::<F>(p, t, 3.089_942_4);
+        p = horner_step::<F>(p, t, 3.515_622_9);
+        result = horner_step::<F>(p, t, 1.0);
+    } else {
+        let u = F::new(3.75) / abs;
+        let mut p = F::new(0.003_923_77);
+        p = horner_step::<F>(p, u, -0.016_476_33);
+        p = horner_step::<F>(p, u, 0.026_355_37);
+        p = horner_step::<F>(p, u, -0.020_577_06);
+        p = horner_step::<F>(p, u, 0.009_162_81);
+        p = horner_step::<F>(p, u, -0.001_575_65);
+        p = horner_step::<F>(p, u, 0.002_253_19);
+        p = horner_step::<F>(p, u, 0.013_285_92);
+        p = horner_step::<F>(p, u, 0.398_942_28);
+        result = p * abs.exp() / abs.sqrt();
+    }
+    result
+}
+
+/// Abramowitz and Stegun approximation (9.8.3 and 9.8.4).
+#[cube]
+fn bessel_i1<F: Float>(x: F) -> F {
+    let abs = x.abs();
+    let mut result = F::new(0.0);
+    if abs < F::new(3.75) {
+        let t = (x / F::new(3.75)) * (x / F::new(3.75));
+        let mut p = F::new(0.000_324_11);
+        p = horner_step::<F>(p, t, 0.003_015_32);
+        p = horner_step::<F>(p, t, 0.026_587_33);
+        p = horner_step::<F>(p, t, 0.150_849_34);
+        p = horner_step::<F>(p, t, 0.514_988_69);
+        p = horner_step::<F>(p, t, 0.878_905_94);
+        p = horner_step::<F>(p, t, 0.5);
+        result = p * x;
+    } else {
+        let u = F::new(3.75) / abs;
+        let mut p = F::new(-0.004_200_59);
+        p = horner_step::<F>(p, u, 0.017_876_54);
+        p = horner_step::<F>(p, u, -0.028_953_12);
+        p = horner_step::<F>(p, u, 0.022_829_67);
+        p = horner_step::<F>(p, u, -0.010_315_55);
+        p = horner_step::<F>(p, u, 0.001_638_01);
+        p = horner_step::<F>(p, u, -0.003_620_18);
+        p = horner_step::<F>(p, u, -0.039_880_24);
+        p = horner_step::<F>(p, u, 0.398_942_28);
+        result = p * abs.exp() / abs.sqrt();
+        if x < F::new(0.0) {
+            result = -result;
+        }
+    }
+    result
+}
+
+/// Coefficient of `y^-2(k + 1)` in the asymptotic expansion of digamma, `-B_2k / 2k`.
+fn digamma_coefficient(k: usize) -> f32 {
+    (-BERNOULLI_OVER_FACTORIAL[k] * factorial(2 * k + 1)) as f32
+}
+
+/// Coefficient of `y^-2(k + 1)` in the Euler-Maclaurin expansion of the Hurwitz zeta function
+/// of order `n + 1`, `B_2k / (2k)! (n + 1)_(2k - 1)`.
+fn polygamma_coefficient(n: u32, k: usize) -> f32 {
+    let s = (n + 1) as f64;
+    let rising = (0..2 * k + 1).fold(1.0, |acc, i| acc * (s + i as f64));
+    (BERNOULLI_OVER_FACTORIAL[k] * rising) as f32
+}
+
+/// `(-1)^(n + 1) n!`.
+fn polygamma_factor(n: u32) -> f32 {
+    let sign = if n % 2 == 0 { -1.0 } else { 1.0 };
+    (sign * factorial(n as usize)) as f32
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).fold(1.0, |acc, k| acc * k as f64)
+}
+
+/// Evaluates the special `function` elementwise, where `order` is the order of the polygamma
+/// function and is ignored otherwise.
+pub(crate) fn special_unary<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    function: SpecialFunction,
+    order: u32,
+) -> CubeTensor<R> {
+    let client = tensor.client.clone();
+    let dtype = tensor.dtype;
+    let output =
+        empty_device_dtype::<R>(client.clone(), tensor.device.clone(), tensor.shape(), dtype);
+
+    let num_elems = output.shape().num_elements();
+    let cube_dim = CubeDim::new(&client, num_elems);
+    let cube_count = calculate_cube_count_elemwise(&client, num_elems, cube_dim);
+
+    special_unary_kernel::launch::<R>(
+        &client,
+        cube_count,
+        cube_dim,
+        tensor.into_tensor_arg(),
+        output.clone().into_tensor_arg(),
+        function,
+        order,
+        dtype_to_storage_type(dtype),
+    );
+
+    output
+}
@@ -15,7 +15,10 @@ use crate::{
     },
 };
 use burn_backend::tensor::{BoolTensor, Device, FloatTensor, IntTensor};
-use burn_backend::{DType, IntDType, Slice, ops::IntTensorOps};
+use burn_backend::{
+    DType, IntDType, Slice,
+    ops::{FloatTensorOps, IntTensorOps},
+};
 use burn_backend::{Distribution, ElementConversion, Shape, TensorData, get_device_settings};
 use burn_backend::{ExecutionError, Scalar};
 use burn_std::{BoolDType, FloatDType};
@@ -619,6 +622,11 @@ impl<R: CubeRuntime> IntTensorOps<Self> for CubeBackend<R> {
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem(), dtype)
             }
+            // The other distributions are only sampled by the float kernels.
+            distribution => kernel::cast(
+                Self::float_random(shape, distribution, device, FloatDType::F32),
+                dtype,
+            ),
         }
     }
 
@@ -703,6 +711,19 @@ impl<R: CubeRuntime> IntTensorOps<Self> for CubeBackend<R> {
         unfold(tensor, dim, size, step)
     }
 
+    fn int_searchsorted(
+        sorted_sequence: IntTensor<Self>,
+        values: IntTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        let dtype = values.dtype;
+        kernel::searchsorted(sorted_sequence, values, right, dtype)
+    }
+
+    fn int_bincount(tensor: IntTensor<Self>, length: usize) -> IntTensor<Self> {
+        kernel::bincount(tensor, length)
+    }
+
     // TODO
     // fn int_powi(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
     //     todo!()
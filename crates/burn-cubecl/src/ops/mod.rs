@@ -3,6 +3,7 @@ mod bool_tensor;
 mod int_tensor;
 mod module;
 mod qtensor;
+mod sparse;
 mod tensor;
 mod transaction;
 
@@ -7,7 +7,8 @@ use burn_backend::{
     TensorMetadata,
     ops::{
         AttentionModuleOptions, ConvOptions, ConvTransposeOptions, DeformConv2dBackward,
-        DeformConvOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+        DeformConvOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices,
+        MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps,
     },
 };
 use burn_std::IntDType;
@@ -284,6 +285,102 @@ where
         ))
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        kernel::pool::avg_pool3d(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        _ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        kernel::pool::avg_pool3d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        let (output, indices) = kernel::pool::max_pool3d_with_indices(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+            indices_dtype.into(),
+        );
+
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        _ceil_mode: bool,
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        MaxPool3dBackward::new(kernel::pool::max_pool3d_with_indices_backward(
+            x,
+            output_grad,
+            indices,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        ))
+    }
+
+    fn adaptive_max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: [usize; 3],
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        let (output, indices) =
+            kernel::pool::adaptive_max_pool3d_with_indices(x, output_size, indices_dtype.into());
+
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn adaptive_max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        MaxPool3dBackward::new(kernel::pool::adaptive_max_pool3d_with_indices_backward(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
     fn adaptive_avg_pool2d(x: FloatTensor<Self>, output_size: [usize; 2]) -> FloatTensor<Self> {
         kernel::pool::adaptive_avg_pool2d(x, output_size)
     }
@@ -331,7 +428,22 @@ where
         attn_bias: Option<FloatTensor<Self>>,
         options: AttentionModuleOptions,
     ) -> FloatTensor<Self> {
-        // Fall back to naive attention for features the flash kernel doesn't support.
+        // Use the online kernel for the sliding window and ALiBi, which the flash kernel doesn't
+        // support, so the score matrix is never materialized.
+        if options.sliding_window.is_some() || options.alibi_max_bias.is_some() {
+            return kernel::attention::attention(
+                query,
+                key,
+                value,
+                mask,
+                attn_bias,
+                options,
+                kernel::attention::AttentionStrategy::Online,
+            )
+            .expect("Kernel to never fail");
+        }
+
+        // Fall back to naive attention for the other features the flash kernel doesn't support.
         if attn_bias.is_some() || options.softcap.is_some() || options.scale.is_some() {
             return burn_backend::ops::attention::attention_fallback::<Self>(
                 query, key, value, mask, attn_bias, options,
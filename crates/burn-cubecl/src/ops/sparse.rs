@@ -0,0 +1,18 @@
+use crate::{CubeBackend, CubeRuntime, kernel};
+use burn_backend::{
+    ops::SparseTensorOps,
+    tensor::{FloatTensor, IntTensor},
+};
+
+// The coordinate layout keeps the default gather and scatter composition, since its values
+// aren't grouped by row.
+impl<R: CubeRuntime> SparseTensorOps<Self> for CubeBackend<R> {
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<Self>,
+        col_indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        kernel::csr_spmm(crow_indices, col_indices, values, dense)
+    }
+}
@@ -2,7 +2,10 @@ use super::{expand, numeric, permute, unfold};
 use crate::CubeBackend;
 use crate::CubeRuntime;
 use crate::kernel::matmul::{MatmulStrategy, matmul};
-use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
+use crate::kernel::prng::{
+    random_bernoulli, random_beta, random_exponential, random_gamma, random_gamma_like,
+    random_normal, random_poisson, random_uniform,
+};
 use crate::kernel::unary_basic::BasicFloatUnaryKind;
 use crate::kernel::{
     self, FloatUnaryOp, FloatUnaryOpFamily, launch_unary_float, reduce, unary_basic,
@@ -50,9 +53,23 @@ where
             Distribution::Normal(mean, std) => {
                 random_normal(shape, device, mean.elem(), std.elem(), dtype)
             }
+            Distribution::Exponential(rate) => {
+                random_exponential(shape, device, rate.elem(), dtype)
+            }
+            Distribution::Gamma(gamma_shape, scale) => {
+                random_gamma(shape, device, gamma_shape.elem(), scale.elem(), dtype)
+            }
+            Distribution::Beta(alpha, beta) => {
+                random_beta(shape, device, alpha.elem(), beta.elem(), dtype)
+            }
+            Distribution::Poisson(rate) => random_poisson(shape, device, rate.elem(), dtype),
         }
     }
 
+    fn float_random_gamma(alpha: FloatTensor<Self>) -> FloatTensor<Self> {
+        random_gamma_like(alpha)
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(
         level="trace",
         skip(tensor),
@@ -473,6 +490,22 @@ where
         .unwrap()
     }
 
+    fn float_logsumexp_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce::logsumexp_dim(tensor, dim)
+    }
+
+    fn float_nansum_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce::nansum_dim(tensor, dim)
+    }
+
+    fn float_nanmean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce::nanmean_dim(tensor, dim)
+    }
+
+    fn float_nanmax_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce::nanmax_dim(tensor, dim)
+    }
+
     fn float_mean(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         reduce::reduce(
             tensor,
@@ -499,6 +532,14 @@ where
         numeric::cummax(tensor, dim)
     }
 
+    fn float_linear_recurrence(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        kernel::linear_recurrence(a, b, dim)
+    }
+
     fn float_prod(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         reduce::reduce(
             tensor,
@@ -639,6 +680,33 @@ where
         unary_basic::launch::<R, _>(tensor, |_| BasicFloatUnaryKind::Erf)
     }
 
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        kernel::special_unary(tensor, kernel::SpecialFunction::Erfinv, 0)
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        kernel::special_unary(tensor, kernel::SpecialFunction::Lgamma, 0)
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        kernel::special_unary(tensor, kernel::SpecialFunction::Digamma, 0)
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: usize) -> FloatTensor<Self> {
+        match n {
+            0 => kernel::special_unary(tensor, kernel::SpecialFunction::Digamma, 0),
+            n => kernel::special_unary(tensor, kernel::SpecialFunction::Polygamma, n as u32),
+        }
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        kernel::special_unary(tensor, kernel::SpecialFunction::I0, 0)
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        kernel::special_unary(tensor, kernel::SpecialFunction::I1, 0)
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<Self> {
         reduce::reduce_dim(
             tensor,
@@ -804,4 +872,30 @@ where
     ) -> FloatTensor<Self> {
         kernel::grid_sample::grid_sample(tensor, grid, options)
     }
+
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<Self> {
+        kernel::searchsorted(sorted_sequence, values, right, out_dtype.into())
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: Scalar,
+        max: Scalar,
+    ) -> FloatTensor<Self> {
+        kernel::histc(tensor, bins, min.elem(), max.elem())
+    }
+
+    fn float_bincount(
+        tensor: IntTensor<Self>,
+        weights: FloatTensor<Self>,
+        length: usize,
+    ) -> FloatTensor<Self> {
+        kernel::bincount_weighted(tensor, weights, length)
+    }
 }
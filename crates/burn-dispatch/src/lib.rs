@@ -43,6 +43,8 @@ pub mod remote_server;
 
 pub use backend::*;
 pub use device::*;
+#[cfg(feature = "autodiff")]
+pub use ops::{CustomBackward, CustomForward};
 pub use tensor::*;
 
 extern crate alloc;
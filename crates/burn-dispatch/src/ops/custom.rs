@@ -0,0 +1,125 @@
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use burn_backend::tensor::FloatTensor;
+
+use crate::{Dispatch, DispatchTensor};
+
+/// The backward pass of a [custom operation](Dispatch::float_custom_op).
+pub type CustomBackward = burn_autodiff::ops::CustomBackward<Dispatch>;
+
+/// The forward pass of a [custom operation](Dispatch::float_custom_op).
+pub type CustomForward = burn_autodiff::ops::CustomForward<Dispatch>;
+
+/// Match arm generator for `float_custom_op`.
+///
+/// Unwraps the autodiff inputs for the backend of the first input, and converts the inputs, the
+/// output and the gradients between the dispatch and the backend primitives in the forward and
+/// backward passes.
+macro_rules! custom_op_arms {
+    ($inputs:ident, $forward:ident; $([$Backend:ident, $cfg:meta]),*) => {{
+        let checkpointing = $inputs[0].checkpointing;
+
+        match &$inputs[0].kind {
+            $crate::DispatchTensorKind::Autodiff(inner) => match **inner {
+                $(
+                    #[cfg($cfg)]
+                    $crate::DispatchTensorKind::$Backend(_) => {
+                        let inputs = unwrap_vec!(@autodiff $Backend, $inputs, autodiff);
+                        let forward: burn_autodiff::ops::CustomForward<$crate::backends::$Backend> =
+                            Arc::new(move |inputs: Vec<FloatTensor<$crate::backends::$Backend>>| {
+                                let inputs = inputs
+                                    .into_iter()
+                                    .map(|input| DispatchTensor {
+                                        kind: $crate::DispatchTensorKind::$Backend(
+                                            $crate::BackendTensor::Float(input),
+                                        ),
+                                        checkpointing: None,
+                                    })
+                                    .collect();
+                                let (output, backward) = $forward(inputs);
+                                let output = match output.kind {
+                                    $crate::DispatchTensorKind::$Backend(output) => output.float(),
+                                    #[allow(unreachable_patterns)]
+                                    _ => panic!(
+                                        "Custom operation output is on the wrong backend (expected {}).",
+                                        stringify!($Backend)
+                                    ),
+                                };
+                                let backward: burn_autodiff::ops::CustomBackward<$crate::backends::$Backend> =
+                                    custom_backward!($Backend, backward);
+
+                                (output, backward)
+                            });
+
+                        wrap_float!(
+                            @wrap_autodiff Float,
+                            $Backend,
+                            checkpointing,
+                            match checkpointing {
+                                Some($crate::CheckpointingStrategy::Balanced) => burn_autodiff::ops::custom_op::<
+                                    _,
+                                    burn_autodiff::checkpoint::strategy::BalancedCheckpointing,
+                                >(inputs, forward),
+                                _ => burn_autodiff::ops::custom_op::<
+                                    _,
+                                    burn_autodiff::checkpoint::strategy::NoCheckpointing,
+                                >(inputs, forward),
+                            }
+                        )
+                    }
+                )*
+                $crate::DispatchTensorKind::Autodiff(..) => {
+                    unreachable!("Autodiff should not wrap an autodiff tensor.")
+                }
+            },
+            _ => panic!("Requires autodiff tensor."),
+        }
+    }};
+}
+
+/// Converts the gradients of a custom backward pass from the dispatch to the backend primitives.
+macro_rules! custom_backward {
+    ($Backend:ident, $backward:ident) => {
+        Box::new(move |grad, required: &[bool]| {
+            let grad = DispatchTensor {
+                kind: $crate::DispatchTensorKind::$Backend($crate::BackendTensor::Float(grad)),
+                checkpointing: None,
+            };
+
+            $backward(grad, required)
+                .into_iter()
+                .map(|grad| {
+                    grad.map(|grad| match grad.kind {
+                        $crate::DispatchTensorKind::$Backend(grad) => grad.float(),
+                        #[allow(unreachable_patterns)]
+                        _ => panic!(
+                            "Custom operation gradient is on the wrong backend (expected {}).",
+                            stringify!($Backend)
+                        ),
+                    })
+                })
+                .collect()
+        })
+    };
+}
+
+impl Dispatch {
+    /// Registers a float operation with a user defined backward pass on the autodiff graph.
+    ///
+    /// The `forward` pass computes the inner output from the inner tensors of the autodiff
+    /// `inputs`, and returns it with the backward pass, which computes the inner gradients of the
+    /// inputs from the inner gradient of the output. With gradient checkpointing, `forward` is
+    /// called again during the backward pass instead of keeping its state. The returned tensor is
+    /// the autodiff output, with the checkpointing strategy of the first input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `inputs` is empty, or if the tensors are not all on the same autodiff backend.
+    pub fn float_custom_op(inputs: Vec<DispatchTensor>, forward: CustomForward) -> DispatchTensor {
+        assert!(
+            !inputs.is_empty(),
+            "A custom operation requires at least one input."
+        );
+
+        backend_list!(custom_op_arms, inputs, forward)
+    }
+}
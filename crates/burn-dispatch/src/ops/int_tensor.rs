@@ -599,4 +599,37 @@ impl IntTensorOps<Self> for Dispatch {
     fn int_argsort(tensor: IntTensor<Self>, dim: usize, descending: bool) -> IntTensor<Self> {
         unary_op!(tensor, int, |tensor| B::int_argsort(tensor, dim, descending) => Int)
     }
+
+    fn int_searchsorted(
+        sorted_sequence: IntTensor<Self>,
+        values: IntTensor<Self>,
+        right: bool,
+    ) -> IntTensor<Self> {
+        binary_op!((sorted_sequence, int), (values, int), |sorted_sequence, values| B::int_searchsorted(sorted_sequence, values, right) => Int)
+    }
+
+    fn int_bincount(tensor: IntTensor<Self>, length: usize) -> IntTensor<Self> {
+        unary_op!(tensor, int, |tensor| B::int_bincount(tensor, length) => Int)
+    }
+
+    async fn int_unique(
+        tensor: IntTensor<Self>,
+        sorted: bool,
+    ) -> (IntTensor<Self>, IntTensor<Self>, IntTensor<Self>) {
+        multi_op!(
+            inputs[(tensor, int)],
+            outputs[(out, Int), (inverse, Int), (counts, Int)],
+            B::int_unique(tensor, sorted).await
+        )
+    }
+
+    async fn int_unique_consecutive(
+        tensor: IntTensor<Self>,
+    ) -> (IntTensor<Self>, IntTensor<Self>, IntTensor<Self>) {
+        multi_op!(
+            inputs[(tensor, int)],
+            outputs[(out, Int), (inverse, Int), (counts, Int)],
+            B::int_unique_consecutive(tensor).await
+        )
+    }
 }
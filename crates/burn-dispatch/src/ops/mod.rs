@@ -1,8 +1,14 @@
 mod activation;
 mod bool_tensor;
+#[cfg(feature = "autodiff")]
+mod custom;
 mod distributed;
 mod int_tensor;
 mod module;
 mod qtensor;
+mod sparse;
 mod tensor;
 mod transaction;
+
+#[cfg(feature = "autodiff")]
+pub use custom::{CustomBackward, CustomForward};
@@ -2,7 +2,7 @@ use burn_backend::{
     IntDType,
     ops::{
         DeformConv2dBackward, MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dBackward,
-        MaxPool2dWithIndices, ModuleOps,
+        MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps,
     },
     tensor::{FloatTensor, IntTensor},
 };
@@ -253,6 +253,31 @@ impl ModuleOps<Self> for Dispatch {
         )
     }
 
+    fn interpolate3d(
+        x: FloatTensor<Self>,
+        output_size: [usize; 3],
+        options: burn_backend::ops::InterpolateOptions,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float)],
+            => Float,
+            B::interpolate3d(x, output_size, options)
+        )
+    }
+
+    fn interpolate3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        output_size: [usize; 3],
+        options: burn_backend::ops::InterpolateOptions,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float), (grad, float)],
+            => Float,
+            B::interpolate3d_backward(x, grad, output_size, options)
+        )
+    }
+
     fn embedding(weights: FloatTensor<Self>, indices: IntTensor<Self>) -> FloatTensor<Self> {
         multi_op!(
             inputs[(weights, float), (indices, int)],
@@ -631,6 +656,166 @@ impl ModuleOps<Self> for Dispatch {
         MaxPool1dBackward::new(x_grad)
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float)],
+            => Float,
+            B::avg_pool3d(x, kernel_size, stride, padding, count_include_pad, ceil_mode)
+        )
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Self>,
+        grad: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(x, float), (grad, float)],
+            => Float,
+            B::avg_pool3d_backward(x, grad, kernel_size, stride, padding, count_include_pad, ceil_mode)
+        )
+    }
+
+    fn max_pool3d(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+    ) -> FloatTensor<Self> {
+        multi_op!(inputs[(x, float)], => Float,
+            B::max_pool3d(x, kernel_size, stride, padding, dilation, ceil_mode))
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        let (out, indices) = multi_op!(
+            inputs[(x, float)],
+            outputs[(out, Float), (indices, Int)],
+            {
+                let res = B::max_pool3d_with_indices(x, kernel_size, stride, padding, dilation, ceil_mode, indices_dtype);
+                (res.output, res.indices)
+            }
+        );
+        MaxPool3dWithIndices::new(out, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        let x_grad = multi_op!(
+            inputs[(x, float), (output_grad, float), (indices, int)],
+            => Float,
+            {
+                let res = B::max_pool3d_with_indices_backward(x, kernel_size, stride, padding, dilation, ceil_mode, output_grad, indices);
+                res.x_grad
+            }
+        );
+        MaxPool3dBackward::new(x_grad)
+    }
+
+    fn adaptive_max_pool1d(x: FloatTensor<Self>, output_size: usize) -> FloatTensor<Self> {
+        multi_op!(inputs[(x, float)], => Float, B::adaptive_max_pool1d(x, output_size))
+    }
+
+    fn adaptive_max_pool1d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: usize,
+        indices_dtype: IntDType,
+    ) -> MaxPool1dWithIndices<Self> {
+        let (out, indices) = multi_op!(
+            inputs[(x, float)],
+            outputs[(out, Float), (indices, Int)],
+            {
+                let res = B::adaptive_max_pool1d_with_indices(x, output_size, indices_dtype);
+                (res.output, res.indices)
+            }
+        );
+        MaxPool1dWithIndices::new(out, indices)
+    }
+
+    fn adaptive_max_pool2d(x: FloatTensor<Self>, output_size: [usize; 2]) -> FloatTensor<Self> {
+        multi_op!(inputs[(x, float)], => Float, B::adaptive_max_pool2d(x, output_size))
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: [usize; 2],
+        indices_dtype: IntDType,
+    ) -> MaxPool2dWithIndices<Self> {
+        let (out, indices) = multi_op!(
+            inputs[(x, float)],
+            outputs[(out, Float), (indices, Int)],
+            {
+                let res = B::adaptive_max_pool2d_with_indices(x, output_size, indices_dtype);
+                (res.output, res.indices)
+            }
+        );
+        MaxPool2dWithIndices::new(out, indices)
+    }
+
+    fn adaptive_max_pool3d(x: FloatTensor<Self>, output_size: [usize; 3]) -> FloatTensor<Self> {
+        multi_op!(inputs[(x, float)], => Float, B::adaptive_max_pool3d(x, output_size))
+    }
+
+    fn adaptive_max_pool3d_with_indices(
+        x: FloatTensor<Self>,
+        output_size: [usize; 3],
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        let (out, indices) = multi_op!(
+            inputs[(x, float)],
+            outputs[(out, Float), (indices, Int)],
+            {
+                let res = B::adaptive_max_pool3d_with_indices(x, output_size, indices_dtype);
+                (res.output, res.indices)
+            }
+        );
+        MaxPool3dWithIndices::new(out, indices)
+    }
+
+    fn adaptive_max_pool3d_with_indices_backward(
+        x: FloatTensor<Self>,
+        output_grad: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+    ) -> MaxPool3dBackward<Self> {
+        let x_grad = multi_op!(
+            inputs[(x, float), (output_grad, float), (indices, int)],
+            => Float,
+            {
+                let res = B::adaptive_max_pool3d_with_indices_backward(x, output_grad, indices);
+                res.x_grad
+            }
+        );
+        MaxPool3dBackward::new(x_grad)
+    }
+
     fn attention(
         query: FloatTensor<Self>,
         key: FloatTensor<Self>,
@@ -647,6 +832,21 @@ impl ModuleOps<Self> for Dispatch {
         )
     }
 
+    fn paged_attention(
+        query: FloatTensor<Self>,
+        key_blocks: FloatTensor<Self>,
+        value_blocks: FloatTensor<Self>,
+        block_tables: IntTensor<Self>,
+        seq_lengths: IntTensor<Self>,
+        options: burn_backend::ops::AttentionModuleOptions,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(query, float), (key_blocks, float), (value_blocks, float), (block_tables, int), (seq_lengths, int)],
+            => Float,
+            B::paged_attention(query, key_blocks, value_blocks, block_tables, seq_lengths, options)
+        )
+    }
+
     fn layer_norm(
         tensor: FloatTensor<Self>,
         gamma: FloatTensor<Self>,
@@ -693,6 +893,42 @@ impl ModuleOps<Self> for Dispatch {
         )
     }
 
+    fn fft(
+        signal_re: FloatTensor<Self>,
+        signal_im: FloatTensor<Self>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (real, imag) = multi_op!(
+            inputs[(signal_re, float), (signal_im, float)],
+            outputs[(real, Float), (imag, Float)],
+            {
+                let res = B::fft(signal_re, signal_im, dim, n);
+                (res.0, res.1)
+            }
+        );
+
+        (real, imag)
+    }
+
+    fn ifft(
+        spectrum_re: FloatTensor<Self>,
+        spectrum_im: FloatTensor<Self>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<Self>, FloatTensor<Self>) {
+        let (real, imag) = multi_op!(
+            inputs[(spectrum_re, float), (spectrum_im, float)],
+            outputs[(real, Float), (imag, Float)],
+            {
+                let res = B::ifft(spectrum_re, spectrum_im, dim, n);
+                (res.0, res.1)
+            }
+        );
+
+        (real, imag)
+    }
+
     fn has_ctc_loss_backward() -> bool {
         // Dispatch routes per-tensor at runtime, but autodiff queries this flag
         // statically. Returning `false` makes autodiff differentiate through
@@ -0,0 +1,35 @@
+use burn_backend::{
+    ops::SparseTensorOps,
+    tensor::{FloatTensor, IntTensor},
+};
+
+use crate::Dispatch;
+
+impl SparseTensorOps<Self> for Dispatch {
+    fn sparse_coo_spmm(
+        row_indices: IntTensor<Self>,
+        col_indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+        rows: usize,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(row_indices, int), (col_indices, int), (values, float), (dense, float)],
+            => Float,
+            B::sparse_coo_spmm(row_indices, col_indices, values, dense, rows)
+        )
+    }
+
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<Self>,
+        col_indices: IntTensor<Self>,
+        values: FloatTensor<Self>,
+        dense: FloatTensor<Self>,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(crow_indices, int), (col_indices, int), (values, float), (dense, float)],
+            => Float,
+            B::sparse_csr_spmm(crow_indices, col_indices, values, dense)
+        )
+    }
+}
@@ -26,6 +26,10 @@ impl FloatTensorOps<Self> for Dispatch {
         })
     }
 
+    fn float_random_gamma(alpha: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(alpha, float, |alpha| B::float_random_gamma(alpha) => Float)
+    }
+
     async fn float_into_data(tensor: FloatTensor<Self>) -> Result<TensorData, ExecutionError> {
         unary_float!(tensor, float, |tensor| B::float_into_data(tensor).await)
     }
@@ -349,6 +353,22 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::float_mean_dim(tensor, dim) => Float)
     }
 
+    fn float_logsumexp_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_logsumexp_dim(tensor, dim) => Float)
+    }
+
+    fn float_nansum_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_nansum_dim(tensor, dim) => Float)
+    }
+
+    fn float_nanmean_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_nanmean_dim(tensor, dim) => Float)
+    }
+
+    fn float_nanmax_dim(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_nanmax_dim(tensor, dim) => Float)
+    }
+
     fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
         unary_float!(tensor, float, |tensor| B::float_cumsum(tensor, dim) => Float)
     }
@@ -365,6 +385,14 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::float_cummax(tensor, dim) => Float)
     }
 
+    fn float_linear_recurrence(
+        a: FloatTensor<Self>,
+        b: FloatTensor<Self>,
+        dim: usize,
+    ) -> FloatTensor<Self> {
+        binary_float!((a, float), (b, float), |a, b| B::float_linear_recurrence(a, b, dim) => Float)
+    }
+
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         unary_float!(tensor, float, |tensor| B::float_cast(tensor, dtype) => Float)
     }
@@ -469,6 +497,30 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::float_erf(tensor) => Float)
     }
 
+    fn float_erfinv(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_erfinv(tensor) => Float)
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_lgamma(tensor) => Float)
+    }
+
+    fn float_digamma(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_digamma(tensor) => Float)
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Self>, n: usize) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_polygamma(tensor, n) => Float)
+    }
+
+    fn float_i0(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_i0(tensor) => Float)
+    }
+
+    fn float_i1(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_i1(tensor) => Float)
+    }
+
     fn float_argmax(tensor: FloatTensor<Self>, dim: usize, out_dtype: IntDType) -> IntTensor<Self> {
         unary_float!(tensor, float, |tensor| B::float_argmax(tensor, dim, out_dtype) => Int)
     }
@@ -530,6 +582,16 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(ref tensor, float, |tensor| B::float_is_require_grad(tensor))
     }
 
+    fn float_set_per_sample_grad(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_set_per_sample_grad(tensor) => Float)
+    }
+
+    fn float_is_per_sample_grad(tensor: &FloatTensor<Self>) -> bool {
+        unary_float!(ref tensor, float, |tensor| B::float_is_per_sample_grad(
+            tensor
+        ))
+    }
+
     // Default implementation
     fn float_zeros(shape: Shape, device: &DispatchDevice, dtype: FloatDType) -> FloatTensor<Self> {
         creation_op!(Float, device, |device| B::float_zeros(shape, device, dtype))
@@ -720,6 +782,58 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::float_argsort(tensor, dim, descending, out_dtype) => Int)
     }
 
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<Self>,
+        values: FloatTensor<Self>,
+        right: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<Self> {
+        binary_float!((sorted_sequence, float), (values, float), |sorted_sequence, values| B::float_searchsorted(sorted_sequence, values, right, out_dtype) => Int)
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Self>,
+        bins: usize,
+        min: Scalar,
+        max: Scalar,
+    ) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_histc(tensor, bins, min, max) => Float)
+    }
+
+    fn float_bincount(
+        tensor: IntTensor<Self>,
+        weights: FloatTensor<Self>,
+        length: usize,
+    ) -> FloatTensor<Self> {
+        multi_op!(
+            inputs[(tensor, int), (weights, float)], => Float,
+            B::float_bincount(tensor, weights, length)
+        )
+    }
+
+    async fn float_unique(
+        tensor: FloatTensor<Self>,
+        sorted: bool,
+        out_dtype: IntDType,
+    ) -> (FloatTensor<Self>, IntTensor<Self>, IntTensor<Self>) {
+        multi_op!(
+            inputs[(tensor, float)],
+            outputs[(out, Float), (inverse, Int), (counts, Int)],
+            B::float_unique(tensor, sorted, out_dtype).await
+        )
+    }
+
+    async fn float_unique_consecutive(
+        tensor: FloatTensor<Self>,
+        out_dtype: IntDType,
+    ) -> (FloatTensor<Self>, IntTensor<Self>, IntTensor<Self>) {
+        multi_op!(
+            inputs[(tensor, float)],
+            outputs[(out, Float), (inverse, Int), (counts, Int)],
+            B::float_unique_consecutive(tensor, out_dtype).await
+        )
+    }
+
     fn float_grid_sample_2d(
         tensor: FloatTensor<Self>,
         grid: FloatTensor<Self>,
@@ -736,6 +850,15 @@ impl FloatTensorOps<Self> for Dispatch {
         unary_float!(tensor, float, |tensor| B::float_is_inf(tensor, out_dtype) => Bool)
     }
 
+    fn float_nan_to_num(
+        tensor: FloatTensor<Self>,
+        nan: Scalar,
+        posinf: Scalar,
+        neginf: Scalar,
+    ) -> FloatTensor<Self> {
+        unary_float!(tensor, float, |tensor| B::float_nan_to_num(tensor, nan, posinf, neginf) => Float)
+    }
+
     fn float_hypot(lhs: FloatTensor<Self>, rhs: FloatTensor<Self>) -> FloatTensor<Self> {
         binary_float!((lhs, float), (rhs, float), |lhs, rhs| B::float_hypot(lhs, rhs) => Float)
     }
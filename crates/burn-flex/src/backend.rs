@@ -141,6 +141,8 @@ impl Backend for Flex {
             DType::F64 | DType::F32 | DType::F16 | DType::BF16 => {
                 DTypeUsage::Storage | DTypeUsage::Arithmetic
             }
+            // FP8: storage, with casts and matmul emulated in f32
+            DType::F8E4M3 | DType::F8E5M2 => DTypeUsage::Storage.into(),
             DType::I64 | DType::I32 | DType::I16 | DType::I8 => {
                 DTypeUsage::Storage | DTypeUsage::Arithmetic
             }
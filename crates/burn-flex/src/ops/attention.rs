@@ -13,10 +13,12 @@ use alloc::vec;
 use alloc::vec::Vec;
 use burn_backend::DType;
 use burn_backend::ops::AttentionModuleOptions;
+use burn_backend::ops::attention::alibi_slopes;
 use burn_std::Bytes;
 use bytemuck::Pod;
 use num_traits::Float;
 
+use crate::ops::gather_scatter::read_indices;
 use crate::{FlexTensor, Layout};
 
 /// KV tile size for flash attention.
@@ -308,6 +310,16 @@ where
     } else {
         None
     };
+    let window = options.sliding_window.map(|window| {
+        assert!(window > 0, "sliding_window must be positive, got {window}");
+        window as isize
+    });
+    let alibi_slopes: Option<Vec<T>> = options.alibi_max_bias.map(|max_bias| {
+        alibi_slopes(heads, max_bias)
+            .into_iter()
+            .map(|slope| T::from(slope).unwrap())
+            .collect()
+    });
 
     let q_data: &[T] = query.storage();
     let k_data: &[T] = key.storage();
@@ -336,10 +348,13 @@ where
     let o_batch_stride = heads * o_head_stride;
     let mask_tile_len = seq_q * seq_kv;
 
-    let params = AttentionParams {
+    let mut params = AttentionParams {
         scale,
         softcap,
         causal_offset,
+        position_offset: seq_kv as isize - seq_q as isize,
+        window,
+        alibi_slope: None,
         seq_q,
         seq_kv,
         head_dim,
@@ -363,6 +378,7 @@ where
             let o_off = b * o_batch_stride + h * o_head_stride;
             let mask_off = b * mask_batch_step + h * mask_head_step;
             let bias_off = b * bias_batch_step + h * bias_head_step;
+            params.alibi_slope = alibi_slopes.as_ref().map(|slopes| slopes[h]);
 
             flash_attention_head(
                 &q_data[q_off..q_off + q_head_stride],
@@ -463,6 +479,10 @@ struct AttentionParams<T> {
     scale: T,
     softcap: Option<T>,
     causal_offset: Option<isize>,
+    /// Offset of the query positions, aligning the last query with the last key.
+    position_offset: isize,
+    window: Option<isize>,
+    alibi_slope: Option<T>,
     seq_q: usize,
     seq_kv: usize,
     head_dim: usize,
@@ -498,6 +518,9 @@ fn flash_attention_head<T: FlashGemm>(
         scale,
         softcap,
         causal_offset,
+        position_offset,
+        window,
+        alibi_slope,
         seq_q,
         seq_kv,
         head_dim,
@@ -518,6 +541,13 @@ fn flash_attention_head<T: FlashGemm>(
         let kv_end = (kv_start + TILE_KV).min(seq_kv);
         let tile_kv = kv_end - kv_start;
 
+        // Skip the tiles behind the sliding window of every query row.
+        if let Some(window) = window
+            && (kv_end as isize - 1) <= position_offset - window
+        {
+            continue;
+        }
+
         // Step 1: Score matmul via gemm
         // scores[seq_q, tile_kv] = Q[seq_q, head_dim] @ K_tile[tile_kv, head_dim]^T
         //
@@ -571,6 +601,17 @@ fn flash_attention_head<T: FlashGemm>(
                     val = neg_inf;
                 }
 
+                if let Some(window) = window
+                    && (kv_idx as isize) <= (qi as isize) + position_offset - window
+                {
+                    val = neg_inf;
+                }
+
+                if let Some(slope) = alibi_slope {
+                    let distance = ((qi as isize) + position_offset - kv_idx as isize).abs();
+                    val = val - slope * T::from(distance).unwrap();
+                }
+
                 if let Some(b) = bias {
                     val += b[qi * seq_kv + kv_idx];
                 }
@@ -757,6 +798,16 @@ where
     } else {
         None
     };
+    let window = options.sliding_window.map(|window| {
+        assert!(window > 0, "sliding_window must be positive, got {window}");
+        window as isize
+    });
+    let alibi_slopes: Option<Vec<T>> = options.alibi_max_bias.map(|max_bias| {
+        alibi_slopes(heads, max_bias)
+            .into_iter()
+            .map(|slope| T::from(slope).unwrap())
+            .collect()
+    });
 
     let q_data: &[T] = query.storage();
     let k_data: &[T] = key.storage();
@@ -785,10 +836,13 @@ where
     let o_batch_stride = heads * o_head_stride;
     let mask_tile_len = seq_q * seq_kv;
 
-    let params = AttentionParams {
+    let mut params = AttentionParams {
         scale,
         softcap,
         causal_offset,
+        position_offset: seq_kv as isize - seq_q as isize,
+        window,
+        alibi_slope: None,
         seq_q,
         seq_kv,
         head_dim,
@@ -805,6 +859,7 @@ where
             let o_off = b * o_batch_stride + h * o_head_stride;
             let mask_off = b * mask_batch_step + h * mask_head_step;
             let bias_off = b * bias_batch_step + h * bias_head_step;
+            params.alibi_slope = alibi_slopes.as_ref().map(|slopes| slopes[h]);
 
             naive_attention_head(
                 &q_data[q_off..q_off + q_head_stride],
@@ -849,6 +904,9 @@ fn naive_attention_head<T: FlashGemm>(
         scale,
         softcap,
         causal_offset,
+        position_offset,
+        window,
+        alibi_slope,
         seq_q,
         seq_kv,
         head_dim,
@@ -899,6 +957,17 @@ fn naive_attention_head<T: FlashGemm>(
                 val = neg_inf;
             }
 
+            if let Some(window) = window
+                && (ki as isize) <= (qi as isize) + position_offset - window
+            {
+                val = neg_inf;
+            }
+
+            if let Some(slope) = alibi_slope {
+                let distance = ((qi as isize) + position_offset - ki as isize).abs();
+                val = val - slope * T::from(distance).unwrap();
+            }
+
             if let Some(b) = bias {
                 val += b[qi * seq_kv + ki];
             }
@@ -949,6 +1018,446 @@ fn naive_attention_head<T: FlashGemm>(
     }
 }
 
+/// Paged attention: attention of each sequence over the blocks of a paged KV cache.
+///
+/// Input shapes:
+///   query:        \[batch, heads, seq_q, head_dim\], the last seq_q tokens of each sequence
+///   key_blocks:   \[num_blocks, kv_heads, block_size, head_dim\]
+///   value_blocks: \[num_blocks, kv_heads, block_size, val_dim\]
+///   block_tables: \[batch, max_blocks\] (int)
+///   seq_lengths:  \[batch\] (int)
+///
+/// Output: \[batch, heads, seq_q, val_dim\]
+///
+/// Same online softmax as flash attention, with the KV tiles being the blocks of the
+/// sequence, read in place through its block table. The blocks after the last token of
+/// the sequence are never visited, so the padding of the block tables costs nothing.
+pub fn paged_attention(
+    query: FlexTensor,
+    key_blocks: FlexTensor,
+    value_blocks: FlexTensor,
+    block_tables: FlexTensor,
+    seq_lengths: FlexTensor,
+    options: AttentionModuleOptions,
+) -> FlexTensor {
+    let dtype = query.dtype();
+    debug_assert_eq!(
+        key_blocks.dtype(),
+        dtype,
+        "paged_attention: key dtype mismatch"
+    );
+    debug_assert_eq!(
+        value_blocks.dtype(),
+        dtype,
+        "paged_attention: value dtype mismatch"
+    );
+    let max_blocks = block_tables.layout().shape()[1];
+    let block_tables = block_tables.to_contiguous();
+    let seq_lengths = seq_lengths.to_contiguous();
+    let block_tables = read_indices(&block_tables);
+    let seq_lengths = read_indices(&seq_lengths);
+
+    match dtype {
+        DType::F32 => paged_attention_impl::<f32>(
+            query,
+            key_blocks,
+            value_blocks,
+            &block_tables,
+            max_blocks,
+            &seq_lengths,
+            options,
+        ),
+        DType::F64 => paged_attention_impl::<f64>(
+            query,
+            key_blocks,
+            value_blocks,
+            &block_tables,
+            max_blocks,
+            &seq_lengths,
+            options,
+        ),
+        DType::F16 => {
+            use burn_std::f16;
+            let r = paged_attention_impl::<f32>(
+                cast_to_f32(query, f16::to_f32),
+                cast_to_f32(key_blocks, f16::to_f32),
+                cast_to_f32(value_blocks, f16::to_f32),
+                &block_tables,
+                max_blocks,
+                &seq_lengths,
+                options,
+            );
+            cast_from_f32(r, f16::from_f32)
+        }
+        DType::BF16 => {
+            use burn_std::bf16;
+            let r = paged_attention_impl::<f32>(
+                cast_to_f32(query, bf16::to_f32),
+                cast_to_f32(key_blocks, bf16::to_f32),
+                cast_to_f32(value_blocks, bf16::to_f32),
+                &block_tables,
+                max_blocks,
+                &seq_lengths,
+                options,
+            );
+            cast_from_f32(r, bf16::from_f32)
+        }
+        dtype => panic!("paged_attention: unsupported dtype {:?}", dtype),
+    }
+}
+
+fn paged_attention_impl<T>(
+    query: FlexTensor,
+    key_blocks: FlexTensor,
+    value_blocks: FlexTensor,
+    block_tables: &[isize],
+    max_blocks: usize,
+    seq_lengths: &[isize],
+    options: AttentionModuleOptions,
+) -> FlexTensor
+where
+    T: FlashGemm + burn_backend::Element,
+{
+    if let Some(softcap) = options.softcap {
+        assert!(softcap > 0.0, "softcap must be positive, got {softcap}");
+    }
+
+    let query = query.to_contiguous();
+    let key_blocks = key_blocks.to_contiguous();
+    let value_blocks = value_blocks.to_contiguous();
+
+    let q_shape = query.layout().shape();
+    let k_shape = key_blocks.layout().shape();
+    let v_shape = value_blocks.layout().shape();
+    assert!(q_shape.num_dims() == 4, "paged_attention: query must be 4D");
+    assert!(
+        k_shape.num_dims() == 4,
+        "paged_attention: key blocks must be 4D"
+    );
+    assert!(
+        v_shape.num_dims() == 4,
+        "paged_attention: value blocks must be 4D"
+    );
+
+    let batch = q_shape[0];
+    let heads = q_shape[1];
+    let seq_q = q_shape[2];
+    let head_dim = q_shape[3];
+    assert!(head_dim > 0, "paged_attention: head_dim must be non-zero");
+
+    let num_blocks = k_shape[0];
+    let kv_heads = k_shape[1];
+    let block_size = k_shape[2];
+    let val_dim = v_shape[3];
+    assert_eq!(
+        k_shape[3], head_dim,
+        "paged_attention: key head_dim mismatch"
+    );
+    assert_eq!(
+        [v_shape[0], v_shape[1], v_shape[2]],
+        [num_blocks, kv_heads, block_size],
+        "paged_attention: key and value blocks must agree on their shape"
+    );
+    assert!(
+        kv_heads > 0 && heads.is_multiple_of(kv_heads),
+        "paged_attention: q_heads ({heads}) must be divisible by kv_heads ({kv_heads})"
+    );
+    assert_eq!(
+        seq_lengths.len(),
+        batch,
+        "paged_attention: seq_lengths batch mismatch"
+    );
+    assert_eq!(
+        block_tables.len(),
+        batch * max_blocks,
+        "paged_attention: block_tables batch mismatch"
+    );
+    let q_per_kv = heads / kv_heads;
+
+    let scale = T::from(
+        options
+            .scale
+            .unwrap_or_else(|| 1.0 / (head_dim as f64).sqrt()),
+    )
+    .unwrap();
+    let softcap: Option<T> = options.softcap.map(|s| T::from(s).unwrap());
+    let window = options.sliding_window.map(|window| {
+        assert!(window > 0, "sliding_window must be positive, got {window}");
+        window as isize
+    });
+    let alibi_slopes: Option<Vec<T>> = options.alibi_max_bias.map(|max_bias| {
+        alibi_slopes(heads, max_bias)
+            .into_iter()
+            .map(|slope| T::from(slope).unwrap())
+            .collect()
+    });
+
+    let q_data: &[T] = query.storage();
+    let k_data: &[T] = key_blocks.storage();
+    let v_data: &[T] = value_blocks.storage();
+
+    let mut output = vec![T::zero(); batch * heads * seq_q * val_dim];
+
+    let q_head_stride = seq_q * head_dim;
+    let k_block_stride = block_size * head_dim;
+    let v_block_stride = block_size * val_dim;
+    let o_head_stride = seq_q * val_dim;
+
+    let mut params = AttentionParams {
+        scale,
+        softcap,
+        causal_offset: Some(0),
+        position_offset: 0,
+        window,
+        alibi_slope: None,
+        seq_q,
+        seq_kv: 0,
+        head_dim,
+        val_dim,
+    };
+
+    let mut scratch = ScratchBuffers {
+        row_max: vec![T::neg_infinity(); seq_q],
+        row_sum: vec![T::zero(); seq_q],
+        scores: vec![T::zero(); seq_q * block_size],
+    };
+
+    for b in 0..batch {
+        let seq_len = seq_lengths[b];
+        assert!(
+            seq_len >= seq_q as isize && seq_len as usize <= max_blocks * block_size,
+            "paged_attention: sequence {b} has {seq_len} tokens, which must cover the {seq_q} \
+             queries and fit in its {max_blocks} blocks of {block_size} tokens"
+        );
+        let seq_len = seq_len as usize;
+        let table = &block_tables[b * max_blocks..b * max_blocks + seq_len.div_ceil(block_size)];
+        let table: Vec<usize> = table
+            .iter()
+            .map(|&block| {
+                assert!(
+                    block >= 0 && (block as usize) < num_blocks,
+                    "paged_attention: block {block} is out of bounds for {num_blocks} blocks"
+                );
+                block as usize
+            })
+            .collect();
+        // The queries are the last tokens, so query `qi` is at position `qi + offset`.
+        params.position_offset = (seq_len - seq_q) as isize;
+        params.causal_offset = Some(params.position_offset);
+        params.seq_kv = seq_len;
+
+        for h in 0..heads {
+            let kv_h = h / q_per_kv;
+            let q_off = (b * heads + h) * q_head_stride;
+            let o_off = (b * heads + h) * o_head_stride;
+            params.alibi_slope = alibi_slopes.as_ref().map(|slopes| slopes[h]);
+
+            paged_attention_head(
+                &q_data[q_off..q_off + q_head_stride],
+                |block| {
+                    let k_off = (table[block] * kv_heads + kv_h) * k_block_stride;
+                    &k_data[k_off..k_off + k_block_stride]
+                },
+                |block| {
+                    let v_off = (table[block] * kv_heads + kv_h) * v_block_stride;
+                    &v_data[v_off..v_off + v_block_stride]
+                },
+                block_size,
+                &mut output[o_off..o_off + o_head_stride],
+                &params,
+                &mut scratch,
+            );
+        }
+    }
+
+    let shape = burn_std::Shape::from(vec![batch, heads, seq_q, val_dim]);
+    FlexTensor::new(
+        Bytes::from_elems(output),
+        Layout::contiguous(shape),
+        T::dtype(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Process a single (batch, head) pair of the paged attention.
+///
+/// Like [flash_attention_head], with one KV tile per block of the sequence: `key_block` and
+/// `value_block` return the \[block_size, dim\] keys and values of the i-th block, and the
+/// last block is truncated to the `p.seq_kv` tokens of the sequence.
+fn paged_attention_head<'a, T: FlashGemm + 'a>(
+    q: &[T],
+    key_block: impl Fn(usize) -> &'a [T],
+    value_block: impl Fn(usize) -> &'a [T],
+    block_size: usize,
+    output: &mut [T],
+    p: &AttentionParams<T>,
+    scratch: &mut ScratchBuffers<T>,
+) {
+    debug_assert_eq!(q.len(), p.seq_q * p.head_dim);
+    debug_assert_eq!(output.len(), p.seq_q * p.val_dim);
+
+    let neg_inf = T::neg_infinity();
+    let AttentionParams {
+        scale,
+        softcap,
+        causal_offset,
+        position_offset,
+        window,
+        alibi_slope,
+        seq_q,
+        seq_kv,
+        head_dim,
+        val_dim,
+    } = *p;
+
+    let row_max = &mut scratch.row_max;
+    row_max.fill(neg_inf);
+    let row_sum = &mut scratch.row_sum;
+    row_sum.fill(T::zero());
+    let scores = &mut scratch.scores;
+
+    for block in 0..seq_kv.div_ceil(block_size) {
+        let kv_start = block * block_size;
+        let kv_end = (kv_start + block_size).min(seq_kv);
+        let tile_kv = kv_end - kv_start;
+
+        // Skip the blocks behind the sliding window of every query row.
+        if let Some(window) = window
+            && (kv_end as isize - 1) <= position_offset - window
+        {
+            continue;
+        }
+
+        let k = key_block(block);
+        let v = value_block(block);
+
+        // scores[seq_q, tile_kv] = Q[seq_q, head_dim] @ K_block[tile_kv, head_dim]^T
+        unsafe {
+            T::block_gemm(BlockGemmArgs {
+                m: seq_q,
+                n: tile_kv,
+                k: head_dim,
+                dst: scores.as_mut_ptr(),
+                dst_cs: 1,
+                dst_rs: tile_kv as isize,
+                read_dst: false,
+                lhs: q.as_ptr(),
+                lhs_cs: 1,
+                lhs_rs: head_dim as isize,
+                rhs: k.as_ptr(),
+                rhs_cs: head_dim as isize,
+                rhs_rs: 1,
+                alpha: T::zero(),
+                beta: T::one(),
+            });
+        }
+
+        for qi in 0..seq_q {
+            let score_row = &mut scores[qi * tile_kv..(qi + 1) * tile_kv];
+            let position = qi as isize + position_offset;
+
+            let mut tile_max = neg_inf;
+
+            for (ki, score) in score_row.iter_mut().enumerate() {
+                let kv_idx = (kv_start + ki) as isize;
+                let mut val = *score * scale;
+
+                if let Some(cap) = softcap {
+                    val = cap * (val / cap).tanh();
+                }
+
+                if let Some(offset) = causal_offset
+                    && kv_idx > qi as isize + offset
+                {
+                    val = neg_inf;
+                }
+
+                if let Some(window) = window
+                    && kv_idx <= position - window
+                {
+                    val = neg_inf;
+                }
+
+                if let Some(slope) = alibi_slope {
+                    let distance = (position - kv_idx).abs();
+                    val = val - slope * T::from(distance).unwrap();
+                }
+
+                *score = val;
+                if val > tile_max {
+                    tile_max = val;
+                }
+            }
+
+            if tile_max == neg_inf {
+                for score in score_row.iter_mut() {
+                    *score = T::zero();
+                }
+                continue;
+            }
+
+            let new_max = if row_max[qi] > tile_max {
+                row_max[qi]
+            } else {
+                tile_max
+            };
+
+            let mut tile_sum = T::zero();
+            for score in score_row.iter_mut() {
+                let e = (*score - new_max).exp();
+                *score = e;
+                tile_sum += e;
+            }
+
+            let correction = if row_max[qi] == neg_inf {
+                T::zero()
+            } else {
+                (row_max[qi] - new_max).exp()
+            };
+
+            let out_row = &mut output[qi * val_dim..(qi + 1) * val_dim];
+            for o in out_row.iter_mut() {
+                *o = *o * correction;
+            }
+
+            row_sum[qi] = row_sum[qi] * correction + tile_sum;
+            row_max[qi] = new_max;
+        }
+
+        // output[seq_q, val_dim] += P[seq_q, tile_kv] @ V_block[tile_kv, val_dim]
+        unsafe {
+            T::block_gemm(BlockGemmArgs {
+                m: seq_q,
+                n: val_dim,
+                k: tile_kv,
+                dst: output.as_mut_ptr(),
+                dst_cs: 1,
+                dst_rs: val_dim as isize,
+                read_dst: true,
+                lhs: scores.as_ptr(),
+                lhs_cs: 1,
+                lhs_rs: tile_kv as isize,
+                rhs: v.as_ptr(),
+                rhs_cs: 1,
+                rhs_rs: val_dim as isize,
+                alpha: T::one(),
+                beta: T::one(),
+            });
+        }
+    }
+
+    for qi in 0..seq_q {
+        let sum = row_sum[qi];
+        if sum > T::zero() {
+            let inv_sum = T::one() / sum;
+            let out_row = &mut output[qi * val_dim..(qi + 1) * val_dim];
+            for o in out_row.iter_mut() {
+                *o = *o * inv_sum;
+            }
+        }
+    }
+}
+
 // Tests kept here exercise flex-specific internals: direct calls into
 // `attention_flash` / `attention_naive` (the public `attention()` dispatcher
 // routes small shapes to naive so flash-path coverage requires a direct
@@ -1515,6 +2024,7 @@ mod tests {
             scale: Some(0.05),
             softcap: Some(30.0),
             is_causal: true,
+            ..Default::default()
         };
 
         run_both(1, 1, 4, 4, 8, 8, false, false, default, "basic_4x4");
@@ -1562,6 +2072,48 @@ mod tests {
             causal,
             "partial_tile_causal",
         );
+
+        // Windows smaller than a tile skip the leading KV tiles of the later queries.
+        let window = AttentionModuleOptions {
+            is_causal: true,
+            sliding_window: Some(16),
+            ..Default::default()
+        };
+        let alibi = AttentionModuleOptions {
+            alibi_max_bias: Some(8.0),
+            ..Default::default()
+        };
+        let window_alibi = AttentionModuleOptions {
+            is_causal: true,
+            sliding_window: Some(100),
+            alibi_max_bias: Some(8.0),
+            ..Default::default()
+        };
+        run_both(
+            1,
+            2,
+            32,
+            256,
+            16,
+            16,
+            false,
+            false,
+            window,
+            "sliding_window",
+        );
+        run_both(1, 3, 8, 100, 16, 16, false, false, alibi, "alibi");
+        run_both(
+            2,
+            4,
+            16,
+            200,
+            32,
+            32,
+            true,
+            true,
+            window_alibi,
+            "window_alibi_mask_bias",
+        );
     }
 
     #[test]
@@ -9,6 +9,7 @@ use burn_backend::{
 };
 use burn_std::{Bytes, FloatDType, IntDType, Shape, Slice, bf16, f16};
 
+use crate::ops::f32_to_fp8;
 use crate::{Flex, FlexTensor, Layout};
 
 impl BoolTensorOps<Flex> for Flex {
@@ -120,6 +121,14 @@ impl BoolTensorOps<Flex> for Flex {
                     .collect();
                 FlexTensor::new(Bytes::from_elems(data), Layout::contiguous(shape), out_dt)
             }
+            FloatDType::F8E4M3 | FloatDType::F8E5M2 => {
+                let [zero, one] = [0.0, 1.0].map(|v| f32_to_fp8(&[v], out_dt)[0]);
+                let data: Vec<u8> = bools
+                    .iter()
+                    .map(|&x| if x != 0 { one } else { zero })
+                    .collect();
+                FlexTensor::new(Bytes::from_elems(data), Layout::contiguous(shape), out_dt)
+            }
         }
     }
 
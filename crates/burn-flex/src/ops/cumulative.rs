@@ -256,6 +256,80 @@ pub fn cummax_half<E: Element + Pod + Default + Copy>(
     )
 }
 
+/// Linear recurrence `h[t] = a[t] * h[t - 1] + b[t]` along a dimension, with `h[-1] = 0`.
+pub fn linear_recurrence<E: Element + Pod + Default + Copy + Num>(
+    a: FlexTensor,
+    b: FlexTensor,
+    dim: usize,
+) -> FlexTensor {
+    linear_recurrence_op::<E, E>(a, b, dim, |x| x, |x| x)
+}
+
+/// Linear recurrence for half-precision types, accumulating in f32.
+pub fn linear_recurrence_half<E: Element + Pod + Default + Copy>(
+    a: FlexTensor,
+    b: FlexTensor,
+    dim: usize,
+    to_f32: fn(E) -> f32,
+    from_f32: fn(f32) -> E,
+) -> FlexTensor {
+    linear_recurrence_op::<E, f32>(a, b, dim, to_f32, from_f32)
+}
+
+/// Runs the recurrence sequentially along `dim`, with the states of the contiguous inner block
+/// updated together like [`cumulative_op`].
+fn linear_recurrence_op<E: Element + Pod + Default + Copy, A: Copy + Num>(
+    a: FlexTensor,
+    b: FlexTensor,
+    dim: usize,
+    to_acc: fn(E) -> A,
+    from_acc: fn(A) -> E,
+) -> FlexTensor {
+    let a = a.to_contiguous();
+    let b = b.to_contiguous();
+    let shape = b.layout().shape().clone();
+    let ndims = shape.num_dims();
+
+    assert!(
+        dim < ndims,
+        "dim {} out of bounds for {} dimensions",
+        dim,
+        ndims
+    );
+    assert_eq!(
+        a.layout().shape(),
+        &shape,
+        "linear_recurrence: a and b must have the same shape"
+    );
+
+    let a_data: &[E] = a.storage();
+    let b_data: &[E] = b.storage();
+    let mut result = vec![E::default(); shape.num_elements()];
+
+    let dim_size = shape[dim];
+    let inner_size: usize = shape[dim + 1..].iter().product();
+    let outer_size: usize = shape[..dim].iter().product();
+    let block_size = dim_size * inner_size;
+
+    let mut state = vec![A::zero(); inner_size];
+    for outer in 0..outer_size {
+        let base = outer * block_size;
+        state.fill(A::zero());
+
+        for i in 0..dim_size {
+            let offset = base + i * inner_size;
+            for j in 0..inner_size {
+                let h = to_acc(a_data[offset + j]) * state[j] + to_acc(b_data[offset + j]);
+                state[j] = h;
+                result[offset + j] = from_acc(h);
+            }
+        }
+    }
+
+    let bytes = Bytes::from_elems(result);
+    FlexTensor::new(bytes, Layout::contiguous(shape), E::dtype())
+}
+
 // Cumsum / cumprod / cummin / cummax coverage (basic, stride variants,
 // NaN propagation, int dtype) lives in
 // crates/burn-backend-tests/tests/tensor/{float,int}/ops/cumulative.rs so
@@ -1590,8 +1590,210 @@ pub fn irfft_bf16(
     super::module::cast_from_f32(result, bf16::from_f32)
 }
 
+// ============================================================================
+// Complex FFT (fft / ifft)
+// ============================================================================
+
+/// Runs `kernel` in place on every fiber of a complex tensor along `dim`.
+///
+/// Each fiber is truncated or zero-padded to the FFT size before the kernel is applied.
+fn complex_fft_fibers<E, K>(
+    signal_re: FlexTensor,
+    signal_im: FlexTensor,
+    dim: usize,
+    n: Option<usize>,
+    kernel: K,
+) -> (FlexTensor, FlexTensor)
+where
+    E: burn_backend::Element + bytemuck::Pod + Default + Send + Sync,
+    K: Fn(&mut [E], &mut [E], usize) + Sync,
+{
+    let signal_re = signal_re.to_contiguous();
+    let signal_im = signal_im.to_contiguous();
+    let shape = signal_re.layout().shape().clone();
+    assert!(
+        *signal_im.layout().shape() == shape,
+        "fft: real and imaginary parts must have the same shape"
+    );
+    assert!(
+        dim < shape.num_dims(),
+        "fft: dim {dim} out of bounds for {}-D tensor",
+        shape.num_dims()
+    );
+
+    let fft_size = n.unwrap_or_else(|| {
+        let sig_len = shape[dim];
+        assert!(
+            sig_len > 0 && sig_len.is_power_of_two(),
+            "fft: dimension size must be a power of 2, got {sig_len}"
+        );
+        sig_len
+    });
+    assert!(
+        fft_size.is_power_of_two(),
+        "fft: n must be a power of 2, got {fft_size}"
+    );
+    let sig_len = shape[dim].min(fft_size);
+
+    let mut out_dims: Vec<usize> = shape.as_slice().to_vec();
+    out_dims[dim] = fft_size;
+    let out_shape = Shape::from(out_dims);
+    let total_out = out_shape.num_elements();
+    let num_fibers = total_out / fft_size;
+
+    let re_data: &[E] = signal_re.storage();
+    let im_data: &[E] = signal_im.storage();
+    let in_strides = contiguous_strides_usize(&shape);
+    let out_strides = contiguous_strides_usize(&out_shape);
+    let in_stride = in_strides[dim];
+    let out_stride = out_strides[dim];
+
+    let load = |fiber_idx: usize, fiber_re: &mut [E], fiber_im: &mut [E]| {
+        let base = slice_base_offset(fiber_idx, &shape, &in_strides, dim);
+        for k in 0..sig_len {
+            fiber_re[k] = re_data[base + k * in_stride];
+            fiber_im[k] = im_data[base + k * in_stride];
+        }
+        for k in sig_len..fft_size {
+            fiber_re[k] = E::default();
+            fiber_im[k] = E::default();
+        }
+    };
+
+    let mut re_out = vec![E::default(); total_out];
+    let mut im_out = vec![E::default(); total_out];
+
+    #[cfg(feature = "rayon")]
+    if num_fibers >= 4 && fft_size >= 64 {
+        use rayon::prelude::*;
+
+        let fiber_results: Vec<(usize, Vec<E>, Vec<E>)> = (0..num_fibers)
+            .into_par_iter()
+            .map(|fiber_idx| {
+                let mut fiber_re = vec![E::default(); fft_size];
+                let mut fiber_im = vec![E::default(); fft_size];
+                load(fiber_idx, &mut fiber_re, &mut fiber_im);
+                kernel(&mut fiber_re, &mut fiber_im, fft_size);
+                (fiber_idx, fiber_re, fiber_im)
+            })
+            .collect();
+
+        for (fiber_idx, fiber_re, fiber_im) in fiber_results {
+            let out_base = slice_base_offset(fiber_idx, &out_shape, &out_strides, dim);
+            for k in 0..fft_size {
+                re_out[out_base + k * out_stride] = fiber_re[k];
+                im_out[out_base + k * out_stride] = fiber_im[k];
+            }
+        }
+
+        return make_tensors_typed(re_out, im_out, out_shape);
+    }
+
+    let mut fiber_re = vec![E::default(); fft_size];
+    let mut fiber_im = vec![E::default(); fft_size];
+
+    for fiber_idx in 0..num_fibers {
+        let out_base = slice_base_offset(fiber_idx, &out_shape, &out_strides, dim);
+        load(fiber_idx, &mut fiber_re, &mut fiber_im);
+        kernel(&mut fiber_re, &mut fiber_im, fft_size);
+
+        for k in 0..fft_size {
+            re_out[out_base + k * out_stride] = fiber_re[k];
+            im_out[out_base + k * out_stride] = fiber_im[k];
+        }
+    }
+
+    make_tensors_typed(re_out, im_out, out_shape)
+}
+
+/// Complex FFT along `dim`. With `inverse`, computes the inverse FFT scaled by 1/N.
+pub fn fft_f32(
+    signal_re: FlexTensor,
+    signal_im: FlexTensor,
+    dim: usize,
+    n: Option<usize>,
+    inverse: bool,
+) -> (FlexTensor, FlexTensor) {
+    let fft_size = n.unwrap_or_else(|| signal_re.layout().shape()[dim]);
+    let tw = get_twiddles(fft_size);
+
+    complex_fft_fibers::<f32, _>(signal_re, signal_im, dim, n, |re, im, len| {
+        if inverse {
+            inverse_complex_fft(re, im, len, &tw);
+        } else {
+            complex_fft(re, im, len, &tw);
+        }
+    })
+}
+
+pub fn fft_f64(
+    signal_re: FlexTensor,
+    signal_im: FlexTensor,
+    dim: usize,
+    n: Option<usize>,
+    inverse: bool,
+) -> (FlexTensor, FlexTensor) {
+    let fft_size = n.unwrap_or_else(|| signal_re.layout().shape()[dim]);
+    let tw = get_twiddles(fft_size);
+    let (tw_re, tw_im, tw_offsets) = (tw.re(), tw.im(), tw.offsets());
+
+    complex_fft_fibers::<f64, _>(signal_re, signal_im, dim, n, |re, im, len| {
+        if !inverse {
+            fft_f64_inplace(re, im, len, tw_re, tw_im, tw_offsets);
+            return;
+        }
+
+        // IFFT(X) = (1/N) * conj(FFT(conj(X)))
+        for v in im.iter_mut() {
+            *v = -*v;
+        }
+        fft_f64_inplace(re, im, len, tw_re, tw_im, tw_offsets);
+        let scale = 1.0 / len as f64;
+        for v in re.iter_mut() {
+            *v *= scale;
+        }
+        for v in im.iter_mut() {
+            *v = -*v * scale;
+        }
+    })
+}
+
+pub fn fft_f16(
+    signal_re: FlexTensor,
+    signal_im: FlexTensor,
+    dim: usize,
+    n: Option<usize>,
+    inverse: bool,
+) -> (FlexTensor, FlexTensor) {
+    use burn_std::f16;
+    let re = super::module::cast_to_f32(signal_re, f16::to_f32);
+    let im = super::module::cast_to_f32(signal_im, f16::to_f32);
+    let (re, im) = fft_f32(re, im, dim, n, inverse);
+    (
+        super::module::cast_from_f32(re, f16::from_f32),
+        super::module::cast_from_f32(im, f16::from_f32),
+    )
+}
+
+pub fn fft_bf16(
+    signal_re: FlexTensor,
+    signal_im: FlexTensor,
+    dim: usize,
+    n: Option<usize>,
+    inverse: bool,
+) -> (FlexTensor, FlexTensor) {
+    use burn_std::bf16;
+    let re = super::module::cast_to_f32(signal_re, bf16::to_f32);
+    let im = super::module::cast_to_f32(signal_im, bf16::to_f32);
+    let (re, im) = fft_f32(re, im, dim, n, inverse);
+    (
+        super::module::cast_from_f32(re, bf16::from_f32),
+        super::module::cast_from_f32(im, bf16::from_f32),
+    )
+}
+
 // Tests kept here exercise flex-specific internals: the FFT kernels
-// (`rfft_f32`/`_f64`/`_f16`, `irfft_*`, `complex_fft`, `inverse_complex_fft`)
+// (`rfft_f32`/`_f64`/`_f16`, `irfft_*`, `fft_*`, `complex_fft`, `inverse_complex_fft`)
 // across sizes that span the radix-4 and complex packing paths (N=1, 2, 4, 8,
 // 256, 1024, 4096), f16/f64 dtype handling, twiddle accuracy, Parseval's
 // theorem on synthetic inputs, and a reference cross-check against realfft.
@@ -2165,4 +2367,44 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn fft_f32_complex_exponential() {
+        // FFT of x[n] = exp(i * 2*pi * n / 4) is 4 at bin 1 and 0 elsewhere.
+        let re = make_f32(vec![1.0, 0.0, -1.0, 0.0], vec![4]);
+        let im = make_f32(vec![0.0, 1.0, 0.0, -1.0], vec![4]);
+        let (spec_re, spec_im) = fft_f32(re, im, 0, None, false);
+        assert_approx(spec_re, &[0.0, 4.0, 0.0, 0.0], 1e-5);
+        assert_approx(spec_im, &[0.0, 0.0, 0.0, 0.0], 1e-5);
+    }
+
+    #[test]
+    fn fft_ifft_roundtrip_2d_dim0_with_padding() {
+        let re_data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let im_data = vec![0.5, -1.0, 0.0, 2.0, -0.5, 1.0];
+        let re = make_f32(re_data, vec![3, 2]);
+        let im = make_f32(im_data, vec![3, 2]);
+
+        let (spec_re, spec_im) = fft_f32(re, im, 0, Some(4), false);
+        assert_eq!(spec_re.layout().shape().as_slice(), &[4, 2]);
+
+        let (re, im) = fft_f32(spec_re, spec_im, 0, Some(4), true);
+        assert_approx(re, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0, 0.0], 1e-5);
+        assert_approx(im, &[0.5, -1.0, 0.0, 2.0, -0.5, 1.0, 0.0, 0.0], 1e-5);
+    }
+
+    #[test]
+    fn fft_f64_ifft_roundtrip() {
+        let n = 256;
+        let re_data: Vec<f64> = (0..n).map(|i| (i as f64 * 0.1).cos()).collect();
+        let im_data: Vec<f64> = (0..n).map(|i| (i as f64 * 0.3).sin()).collect();
+        let re = make_f64(re_data.clone(), vec![n]);
+        let im = make_f64(im_data.clone(), vec![n]);
+
+        let (spec_re, spec_im) = fft_f64(re, im, 0, None, false);
+        assert_eq!(spec_re.dtype(), DType::F64);
+        let (re, im) = fft_f64(spec_re, spec_im, 0, None, true);
+        assert_approx_f64(re, &re_data, 1e-5);
+        assert_approx_f64(im, &im_data, 1e-5);
+    }
 }
@@ -18,6 +18,7 @@ use num_traits::ToPrimitive;
 use crate::ops::binary::{BinaryOp, binary_op, scalar_op};
 use crate::ops::matmul;
 use crate::ops::unary;
+use crate::ops::{f32_to_fp8, fp8_to_f32};
 use crate::{Flex, FlexTensor};
 
 impl FloatTensorOps<Flex> for Flex {
@@ -40,6 +41,10 @@ impl FloatTensorOps<Flex> for Flex {
             }
             FloatDType::F16 => TensorData::random::<f16, _, _>(shape, distribution, &mut rng),
             FloatDType::BF16 => TensorData::random::<bf16, _, _>(shape, distribution, &mut rng),
+            FloatDType::F8E4M3 | FloatDType::F8E5M2 => {
+                TensorData::random::<f32, _, _>(shape, distribution, &mut rng)
+                    .convert_dtype(dtype.into())
+            }
         };
         *seed = Some(rng);
         FlexTensor::from_data(data)
@@ -729,6 +734,22 @@ impl FloatTensorOps<Flex> for Flex {
         }
     }
 
+    fn float_linear_recurrence(
+        a: FloatTensor<Flex>,
+        b: FloatTensor<Flex>,
+        dim: usize,
+    ) -> FloatTensor<Flex> {
+        use crate::ops::cumulative::{linear_recurrence, linear_recurrence_half};
+
+        match b.dtype() {
+            DType::F32 => linear_recurrence::<f32>(a, b, dim),
+            DType::F64 => linear_recurrence::<f64>(a, b, dim),
+            DType::F16 => linear_recurrence_half(a, b, dim, f16::to_f32, f16::from_f32),
+            DType::BF16 => linear_recurrence_half(a, b, dim, bf16::to_f32, bf16::from_f32),
+            dtype => panic!("float_linear_recurrence: unsupported dtype {dtype:?}"),
+        }
+    }
+
     fn float_cast(tensor: FloatTensor<Flex>, dtype: FloatDType) -> FloatTensor<Flex> {
         use crate::Layout;
         use burn_std::{Bytes, bf16, f16};
@@ -762,6 +783,10 @@ impl FloatTensorOps<Flex> for Flex {
                 let src: &[bf16] = tensor.storage();
                 src.iter().map(|&v| v.to_f32() as f64).collect()
             }
+            DType::F8E4M3 | DType::F8E5M2 => fp8_to_f32(tensor.bytes(), src_dtype)
+                .into_iter()
+                .map(|v| v as f64)
+                .collect(),
             _ => panic!("float_cast: unsupported source dtype {:?}", src_dtype),
         };
 
@@ -786,6 +811,11 @@ impl FloatTensorOps<Flex> for Flex {
                 let bytes = Bytes::from_elems(result);
                 FlexTensor::new(bytes, Layout::contiguous(shape), DType::BF16)
             }
+            DType::F8E4M3 | DType::F8E5M2 => {
+                let f32_values: Vec<f32> = f64_values.iter().map(|&v| v as f32).collect();
+                let bytes = Bytes::from_elems(f32_to_fp8(&f32_values, target_dtype));
+                FlexTensor::new(bytes, Layout::contiguous(shape), target_dtype)
+            }
             _ => panic!("float_cast: unsupported target dtype {:?}", target_dtype),
         }
     }
@@ -897,6 +927,30 @@ impl FloatTensorOps<Flex> for Flex {
         unary::erf(tensor)
     }
 
+    fn float_erfinv(tensor: FloatTensor<Flex>) -> FloatTensor<Flex> {
+        crate::ops::special::erfinv(tensor)
+    }
+
+    fn float_lgamma(tensor: FloatTensor<Flex>) -> FloatTensor<Flex> {
+        crate::ops::special::lgamma(tensor)
+    }
+
+    fn float_digamma(tensor: FloatTensor<Flex>) -> FloatTensor<Flex> {
+        crate::ops::special::digamma(tensor)
+    }
+
+    fn float_polygamma(tensor: FloatTensor<Flex>, n: usize) -> FloatTensor<Flex> {
+        crate::ops::special::polygamma(tensor, n)
+    }
+
+    fn float_i0(tensor: FloatTensor<Flex>) -> FloatTensor<Flex> {
+        crate::ops::special::i0(tensor)
+    }
+
+    fn float_i1(tensor: FloatTensor<Flex>) -> FloatTensor<Flex> {
+        crate::ops::special::i1(tensor)
+    }
+
     fn float_argmax(
         tensor: FloatTensor<Flex>,
         dim: usize,
@@ -1025,6 +1079,54 @@ impl FloatTensorOps<Flex> for Flex {
         }
     }
 
+    fn float_searchsorted(
+        sorted_sequence: FloatTensor<Flex>,
+        values: FloatTensor<Flex>,
+        right: bool,
+        out_dtype: IntDType,
+    ) -> IntTensor<Flex> {
+        let indices = crate::ops::histogram::searchsorted(sorted_sequence, values, right);
+        if indices.dtype() != DType::from(out_dtype) {
+            Flex::int_cast(indices, out_dtype)
+        } else {
+            indices
+        }
+    }
+
+    fn float_histc(
+        tensor: FloatTensor<Flex>,
+        bins: usize,
+        min: Scalar,
+        max: Scalar,
+    ) -> FloatTensor<Flex> {
+        let dtype = tensor.dtype();
+        let counts = crate::ops::histogram::histc(
+            tensor,
+            bins,
+            min.to_f64().unwrap(),
+            max.to_f64().unwrap(),
+        );
+        if counts.dtype() != dtype {
+            Flex::float_cast(counts, dtype.into())
+        } else {
+            counts
+        }
+    }
+
+    fn float_bincount(
+        tensor: IntTensor<Flex>,
+        weights: FloatTensor<Flex>,
+        length: usize,
+    ) -> FloatTensor<Flex> {
+        let dtype = weights.dtype();
+        let sums = crate::ops::histogram::bincount_weighted(tensor, weights, length);
+        if sums.dtype() != dtype {
+            Flex::float_cast(sums, dtype.into())
+        } else {
+            sums
+        }
+    }
+
     fn float_powi(lhs: FloatTensor<Flex>, rhs: IntTensor<Flex>) -> FloatTensor<Flex> {
         let dtype = lhs.dtype();
         Self::float_powf(lhs, Flex::int_into_float(rhs, dtype.into()))
@@ -1121,6 +1223,23 @@ mod tests {
         assert_eq!(data, vec![0, 1, 127, 255]);
     }
 
+    #[test]
+    fn test_float_cast_fp8_round_trip() {
+        use burn_backend::ops::FloatTensorOps;
+        use burn_std::FloatDType;
+
+        let t = crate::FlexTensor::from_data(TensorData::from([[1.0f32, 0.3], [-2.0, 1000.0]]));
+        let fp8 = Flex::float_cast(t, FloatDType::F8E4M3);
+        assert_eq!(fp8.dtype(), burn_backend::DType::F8E4M3);
+
+        // Transposed to go through the non-contiguous FP8 copy.
+        let fp8 = Flex::float_swap_dims(fp8, 0, 1);
+        let result = Flex::float_cast(fp8, FloatDType::F32);
+        let data: Vec<f32> = result.into_data().to_vec().unwrap();
+        // 0.3 rounds to 0.3125 and 1000 saturates to 448.
+        assert_eq!(data, vec![1.0, -2.0, 0.3125, 448.0]);
+    }
+
     #[test]
     fn test_float_argmax_i32_out_dtype() {
         use burn_backend::ops::FloatTensorOps;
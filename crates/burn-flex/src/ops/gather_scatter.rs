@@ -51,7 +51,7 @@ use crate::{FlexTensor, Layout};
 /// ([`gather_f32`], [`select_f32`], ...) already share this helper without a
 /// check, and asymmetry between the int and float paths was what surfaced
 /// the bug.
-fn read_indices(tensor: &FlexTensor) -> Cow<'_, [isize]> {
+pub(crate) fn read_indices(tensor: &FlexTensor) -> Cow<'_, [isize]> {
     match tensor.dtype() {
         #[cfg(target_pointer_width = "64")]
         DType::I64 => {
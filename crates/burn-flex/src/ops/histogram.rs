@@ -0,0 +1,285 @@
+//! Binning operations for FlexTensor: searchsorted, histc and bincount.
+//!
+//! searchsorted runs a binary search per value; histc and bincount count
+//! directly into the output bins.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_backend::{DType, Element};
+use burn_std::{Bytes, Shape};
+use bytemuck::Pod;
+use core::cmp::Ordering;
+
+use crate::ops::gather_scatter::read_indices;
+use crate::ops::{INDEX_DTYPE, float_storage_as_f32};
+use crate::{FlexTensor, Layout};
+
+// ---------------------------------------------------------------------------
+// searchsorted
+// ---------------------------------------------------------------------------
+
+/// Find the insertion indices of `values` into `sorted_sequence` along the last dimension.
+///
+/// `sorted_sequence` is `[..., M]` with the same leading dimensions as `values` `[..., K]`,
+/// or `[M]` to be shared by every row. Returns `INDEX_DTYPE` indices shaped like `values`.
+pub fn searchsorted(sorted_sequence: FlexTensor, values: FlexTensor, right: bool) -> FlexTensor {
+    assert_eq!(
+        sorted_sequence.dtype(),
+        values.dtype(),
+        "searchsorted: dtype mismatch"
+    );
+    match values.dtype() {
+        DType::F32 => searchsorted_typed::<f32>(sorted_sequence, values, right, f32::total_cmp),
+        DType::F64 => searchsorted_typed::<f64>(sorted_sequence, values, right, f64::total_cmp),
+        DType::F16 | DType::BF16 => searchsorted_half(sorted_sequence, values, right),
+        DType::I64 => searchsorted_typed::<i64>(sorted_sequence, values, right, Ord::cmp),
+        DType::I32 => searchsorted_typed::<i32>(sorted_sequence, values, right, Ord::cmp),
+        DType::I16 => searchsorted_typed::<i16>(sorted_sequence, values, right, Ord::cmp),
+        DType::I8 => searchsorted_typed::<i8>(sorted_sequence, values, right, Ord::cmp),
+        DType::U64 => searchsorted_typed::<u64>(sorted_sequence, values, right, Ord::cmp),
+        DType::U32 => searchsorted_typed::<u32>(sorted_sequence, values, right, Ord::cmp),
+        DType::U16 => searchsorted_typed::<u16>(sorted_sequence, values, right, Ord::cmp),
+        DType::U8 => searchsorted_typed::<u8>(sorted_sequence, values, right, Ord::cmp),
+        dt => panic!("searchsorted: unsupported dtype {:?}", dt),
+    }
+}
+
+fn searchsorted_typed<E: Element + Pod>(
+    sorted_sequence: FlexTensor,
+    values: FlexTensor,
+    right: bool,
+    cmp: fn(&E, &E) -> Ordering,
+) -> FlexTensor {
+    let sorted_sequence = sorted_sequence.to_contiguous();
+    let values = values.to_contiguous();
+    let shape = values.layout().shape().clone();
+    let num_boundaries = searchsorted_boundaries(sorted_sequence.layout().shape(), &shape);
+
+    let indices = searchsorted_rows(
+        sorted_sequence.storage::<E>(),
+        values.storage::<E>(),
+        num_boundaries,
+        shape[shape.num_dims() - 1],
+        right,
+        cmp,
+    );
+    make_index_tensor(indices, shape)
+}
+
+/// Half-precision searchsorted: compare in f32, which preserves the ordering.
+fn searchsorted_half(sorted_sequence: FlexTensor, values: FlexTensor, right: bool) -> FlexTensor {
+    let sorted_sequence = sorted_sequence.to_contiguous();
+    let values = values.to_contiguous();
+    let shape = values.layout().shape().clone();
+    let num_boundaries = searchsorted_boundaries(sorted_sequence.layout().shape(), &shape);
+
+    let indices = searchsorted_rows(
+        &float_storage_as_f32(&sorted_sequence),
+        &float_storage_as_f32(&values),
+        num_boundaries,
+        shape[shape.num_dims() - 1],
+        right,
+        f32::total_cmp,
+    );
+    make_index_tensor(indices, shape)
+}
+
+/// Validate the shapes and return the number of boundaries per row.
+fn searchsorted_boundaries(sorted_shape: &Shape, values_shape: &Shape) -> usize {
+    let rank = values_shape.num_dims();
+    assert!(
+        sorted_shape.num_dims() == 1
+            || (sorted_shape.num_dims() == rank
+                && sorted_shape[..rank - 1] == values_shape[..rank - 1]),
+        "searchsorted: sorted sequence shape {:?} incompatible with values shape {:?}",
+        sorted_shape,
+        values_shape
+    );
+    sorted_shape[sorted_shape.num_dims() - 1]
+}
+
+/// Binary search every value of each row in its row of boundaries.
+///
+/// When `sorted` holds a single row, it is shared by every row of `values`.
+fn searchsorted_rows<E>(
+    sorted: &[E],
+    values: &[E],
+    num_boundaries: usize,
+    num_values: usize,
+    right: bool,
+    cmp: fn(&E, &E) -> Ordering,
+) -> Vec<isize> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+    let shared = sorted.len() == num_boundaries;
+
+    let mut indices = vec![0isize; values.len()];
+    for (row, (values, indices)) in values
+        .chunks_exact(num_values)
+        .zip(indices.chunks_exact_mut(num_values))
+        .enumerate()
+    {
+        let boundaries = match shared {
+            true => sorted,
+            false => &sorted[row * num_boundaries..(row + 1) * num_boundaries],
+        };
+        for (value, index) in values.iter().zip(indices.iter_mut()) {
+            let position = match right {
+                false => boundaries.partition_point(|b| cmp(b, value) == Ordering::Less),
+                true => boundaries.partition_point(|b| cmp(b, value) != Ordering::Greater),
+            };
+            *index = position as isize;
+        }
+    }
+    indices
+}
+
+// ---------------------------------------------------------------------------
+// histc / bincount
+// ---------------------------------------------------------------------------
+
+/// Histogram of all elements with `bins` equal-width bins over `[min, max]`.
+///
+/// Returns an F64 tensor for F64 inputs and an F32 tensor otherwise; the caller
+/// casts back to the input dtype.
+pub fn histc(tensor: FlexTensor, bins: usize, min: f64, max: f64) -> FlexTensor {
+    let tensor = tensor.to_contiguous();
+    match tensor.dtype() {
+        DType::F64 => {
+            let counts = histogram(tensor.storage::<f64>(), bins, min, max);
+            make_float_tensor(counts, DType::F64)
+        }
+        DType::F32 | DType::F16 | DType::BF16 => {
+            let counts = histogram(&float_storage_as_f32(&tensor), bins, min as f32, max as f32);
+            make_float_tensor(counts, DType::F32)
+        }
+        dt => panic!("histc: unsupported dtype {:?}", dt),
+    }
+}
+
+fn histogram<E: num_traits::Float>(data: &[E], bins: usize, min: E, max: E) -> Vec<E> {
+    let mut counts = vec![E::zero(); bins];
+    let scale = E::from(bins).unwrap() / (max - min);
+    for &x in data {
+        // Also rejects NaN.
+        if !(x >= min && x <= max) {
+            continue;
+        }
+        // `max` belongs to the last bin.
+        let bin = ((x - min) * scale)
+            .floor()
+            .to_usize()
+            .unwrap_or(0)
+            .min(bins - 1);
+        counts[bin] = counts[bin] + E::one();
+    }
+    counts
+}
+
+/// Count the occurrences of each index in `0..length`, ignoring the others.
+///
+/// Returns an I64 tensor; the caller casts back to the input dtype.
+pub fn bincount(tensor: FlexTensor, length: usize) -> FlexTensor {
+    let tensor = tensor.to_contiguous();
+    let indices = read_indices(&tensor);
+
+    let mut counts = vec![0i64; length];
+    for &index in indices.iter() {
+        if index >= 0 && (index as usize) < length {
+            counts[index as usize] += 1;
+        }
+    }
+    FlexTensor::new(
+        Bytes::from_elems(counts),
+        Layout::contiguous(Shape::new([length])),
+        DType::I64,
+    )
+}
+
+/// Sum the `weights` of each index in `0..length`, ignoring the others.
+///
+/// Returns an F64 tensor for F64 weights and an F32 tensor otherwise; the caller
+/// casts back to the weights dtype.
+pub fn bincount_weighted(tensor: FlexTensor, weights: FlexTensor, length: usize) -> FlexTensor {
+    let tensor = tensor.to_contiguous();
+    let weights = weights.to_contiguous();
+    let indices = read_indices(&tensor);
+    assert_eq!(
+        indices.len(),
+        weights.layout().shape().num_elements(),
+        "bincount: weights must have the same number of elements as the indices"
+    );
+
+    match weights.dtype() {
+        DType::F64 => {
+            let sums = weighted_counts(&indices, weights.storage::<f64>(), length);
+            make_float_tensor(sums, DType::F64)
+        }
+        DType::F32 | DType::F16 | DType::BF16 => {
+            let sums = weighted_counts(&indices, &float_storage_as_f32(&weights), length);
+            make_float_tensor(sums, DType::F32)
+        }
+        dt => panic!("bincount: unsupported weights dtype {:?}", dt),
+    }
+}
+
+fn weighted_counts<E: num_traits::Float>(
+    indices: &[isize],
+    weights: &[E],
+    length: usize,
+) -> Vec<E> {
+    let mut sums = vec![E::zero(); length];
+    for (&index, &weight) in indices.iter().zip(weights) {
+        if index >= 0 && (index as usize) < length {
+            sums[index as usize] = sums[index as usize] + weight;
+        }
+    }
+    sums
+}
+
+fn make_float_tensor<E: Element + Pod>(data: Vec<E>, dtype: DType) -> FlexTensor {
+    let shape = Shape::new([data.len()]);
+    FlexTensor::new(Bytes::from_elems(data), Layout::contiguous(shape), dtype)
+}
+
+fn make_index_tensor(indices: Vec<isize>, shape: Shape) -> FlexTensor {
+    let bytes = Bytes::from_elems(indices);
+    FlexTensor::new(bytes, Layout::contiguous(shape), INDEX_DTYPE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn searchsorted_rows_left_and_right() {
+        let sorted = [1.0f32, 3.0, 3.0, 5.0];
+        let values = [0.0f32, 3.0, 4.0, 6.0];
+
+        let left = searchsorted_rows(&sorted, &values, 4, 4, false, f32::total_cmp);
+        let right = searchsorted_rows(&sorted, &values, 4, 4, true, f32::total_cmp);
+
+        assert_eq!(left, vec![0, 1, 3, 4]);
+        assert_eq!(right, vec![0, 3, 3, 4]);
+    }
+
+    #[test]
+    fn searchsorted_rows_per_row_boundaries() {
+        let sorted = [1i64, 2, 3, 10, 20, 30];
+        let values = [2i64, 25];
+
+        let indices = searchsorted_rows(&sorted, &values, 3, 1, false, Ord::cmp);
+
+        assert_eq!(indices, vec![1, 2]);
+    }
+
+    #[test]
+    fn histogram_includes_max_and_ignores_out_of_range() {
+        let data = [0.0f64, 0.2, 0.5, 1.0, 2.0, -0.1, 2.1, f64::NAN];
+
+        let counts = histogram(&data, 4, 0.0, 2.0);
+
+        assert_eq!(counts, vec![2.0, 1.0, 1.0, 1.0]);
+    }
+}
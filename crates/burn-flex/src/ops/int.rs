@@ -11,6 +11,7 @@ use num_traits::ToPrimitive;
 
 use crate::Layout;
 use crate::ops::binary::{binary_op_typed, int_binary_op, int_scalar_op, scalar_op_typed};
+use crate::ops::f32_to_fp8;
 use crate::{Flex, FlexTensor, ops::matmul};
 
 /// Convert a Scalar to (i64, u64) pair for the given dtype.
@@ -482,6 +483,11 @@ impl IntTensorOps<Flex> for Flex {
                 let data: Vec<bf16> = read_ints!(|x| bf16::from_f32(x as f32));
                 FlexTensor::new(Bytes::from_elems(data), Layout::contiguous(shape), out_dt)
             }
+            FloatDType::F8E4M3 | FloatDType::F8E5M2 => {
+                let data: Vec<f32> = read_ints!(|x| x as f32);
+                let data = f32_to_fp8(&data, out_dt);
+                FlexTensor::new(Bytes::from_elems(data), Layout::contiguous(shape), out_dt)
+            }
         }
     }
 
@@ -1003,6 +1009,30 @@ impl IntTensorOps<Flex> for Flex {
         crate::ops::sort::argsort(tensor, dim, descending)
     }
 
+    fn int_searchsorted(
+        sorted_sequence: IntTensor<Flex>,
+        values: IntTensor<Flex>,
+        right: bool,
+    ) -> IntTensor<Flex> {
+        let dtype = values.dtype();
+        let indices = crate::ops::histogram::searchsorted(sorted_sequence, values, right);
+        if indices.dtype() != dtype {
+            Flex::int_cast(indices, dtype.into())
+        } else {
+            indices
+        }
+    }
+
+    fn int_bincount(tensor: IntTensor<Flex>, length: usize) -> IntTensor<Flex> {
+        let dtype = tensor.dtype();
+        let counts = crate::ops::histogram::bincount(tensor, length);
+        if counts.dtype() != dtype {
+            Flex::int_cast(counts, dtype.into())
+        } else {
+            counts
+        }
+    }
+
     fn int_powi_scalar(lhs: IntTensor<Flex>, rhs: burn_backend::Scalar) -> IntTensor<Flex> {
         use num_traits::ToPrimitive;
         match rhs.to_i64().unwrap() {
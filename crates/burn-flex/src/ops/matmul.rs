@@ -10,6 +10,7 @@ use alloc::vec::Vec;
 use burn_backend::{DType, Element};
 use burn_std::{Bytes, Shape, bf16, f16};
 
+use crate::ops::{f32_to_fp8, fp8_to_f32};
 use crate::{FlexTensor, Layout};
 
 /// Types that can be used with gemm-based matmul.
@@ -101,6 +102,7 @@ pub fn matmul(lhs: FlexTensor, rhs: FlexTensor) -> FlexTensor {
         DType::F64 => matmul_gemm::<f64>(lhs, rhs),
         DType::F16 => matmul_gemm::<f16>(lhs, rhs),
         DType::BF16 => matmul_bf16(lhs, rhs),
+        DType::F8E4M3 | DType::F8E5M2 => matmul_fp8(lhs, rhs),
         _ => panic!("matmul: unsupported dtype {:?}", lhs.dtype()),
     }
 }
@@ -446,6 +448,37 @@ fn matmul_batched_gemm<T: GemmScalar>(lhs: FlexTensor, rhs: FlexTensor) -> FlexT
     output
 }
 
+// ============================================================================
+// FP8 matmul (emulated via f32 conversion)
+// ============================================================================
+
+/// Decodes both operands to f32, accumulates in f32 and rounds the result back to the FP8
+/// dtype of the operands, like hardware FP8 GEMMs with an FP8 output.
+fn matmul_fp8(lhs: FlexTensor, rhs: FlexTensor) -> FlexTensor {
+    let dtype = lhs.dtype();
+    let lhs = lhs.to_contiguous();
+    let rhs = rhs.to_contiguous();
+
+    let lhs_f32 = FlexTensor::new(
+        Bytes::from_elems(fp8_to_f32(lhs.bytes(), dtype)),
+        Layout::contiguous(lhs.layout().shape().clone()),
+        DType::F32,
+    );
+    let rhs_f32 = FlexTensor::new(
+        Bytes::from_elems(fp8_to_f32(rhs.bytes(), dtype)),
+        Layout::contiguous(rhs.layout().shape().clone()),
+        DType::F32,
+    );
+
+    let result_f32 = matmul_gemm::<f32>(lhs_f32, rhs_f32).to_contiguous();
+
+    FlexTensor::new(
+        Bytes::from_elems(f32_to_fp8(result_f32.storage::<f32>(), dtype)),
+        result_f32.layout().clone(),
+        dtype,
+    )
+}
+
 // ============================================================================
 // bf16 matmul (via f32 conversion)
 // ============================================================================
@@ -838,8 +871,8 @@ fn matmul_batched_i64(lhs: FlexTensor, rhs: FlexTensor) -> FlexTensor {
 #[cfg(test)]
 mod tests {
     use alloc::vec;
-    use burn_backend::TensorData;
     use burn_backend::ops::FloatTensorOps;
+    use burn_backend::{DType, TensorData};
     use burn_std::{bf16, f16};
 
     use crate::{Flex, FlexTensor};
@@ -905,6 +938,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_matmul_fp8() {
+        for dtype in [DType::F8E4M3, DType::F8E5M2] {
+            let lhs = TensorData::from([[1.0f32, 2.0], [3.0, 4.0]]).convert_dtype(dtype);
+            let rhs = TensorData::from([[5.0f32, 6.0], [7.0, 8.0]]).convert_dtype(dtype);
+
+            let result = Flex::float_matmul(FlexTensor::from_data(lhs), FlexTensor::from_data(rhs));
+            assert_eq!(result.dtype(), dtype);
+
+            // The f32 products rounded once to the output dtype.
+            let expected = TensorData::from([[19.0f32, 22.0], [43.0, 50.0]]).convert_dtype(dtype);
+            result.into_data().assert_eq(&expected, true);
+        }
+    }
+
     #[test]
     fn test_matmul_batched_transposed_f64() {
         // Non-contiguous (swap_dims) batched matmul on the F64 dtype path.
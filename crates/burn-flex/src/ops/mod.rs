@@ -1,8 +1,9 @@
 //! Backend operations implementations.
 
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use burn_backend::DType;
-use burn_std::{bf16, f16};
+use burn_std::{bf16, e4m3, e5m2, f16};
 
 use crate::FlexTensor;
 
@@ -59,13 +60,13 @@ impl<T> SendMutPtr<T> {
 
 /// Read a float tensor's storage as f32 values, regardless of source dtype.
 /// Returns a borrowed slice for F32 (zero-copy) and an owned Vec for other
-/// float dtypes (F64, F16, BF16).
+/// float dtypes (F64, F16, BF16, F8E4M3, F8E5M2).
 ///
 /// Returns elements in underlying buffer order. Callers needing logical
 /// iteration order must call `to_contiguous()` first.
 ///
 /// # Panics
-/// Panics if the tensor's dtype is not one of F32, F64, F16, or BF16.
+/// Panics if the tensor's dtype is not a float dtype.
 pub(crate) fn float_storage_as_f32(tensor: &FlexTensor) -> Cow<'_, [f32]> {
     match tensor.dtype() {
         DType::F32 => Cow::Borrowed(tensor.storage::<f32>()),
@@ -84,10 +85,40 @@ pub(crate) fn float_storage_as_f32(tensor: &FlexTensor) -> Cow<'_, [f32]> {
                 .map(|x| f32::from(*x))
                 .collect(),
         ),
+        DType::F8E4M3 | DType::F8E5M2 => Cow::Owned(fp8_to_f32(tensor.bytes(), tensor.dtype())),
         other => panic!("float_storage_as_f32: unsupported dtype {:?}", other),
     }
 }
 
+/// Decode FP8 bits to f32 values.
+///
+/// FP8 has no native arithmetic on CPU, so the ops that support it (casts and matmul) emulate
+/// it by computing in f32 and rounding the result back with [`f32_to_fp8`].
+pub(crate) fn fp8_to_f32(bits: &[u8], dtype: DType) -> Vec<f32> {
+    match dtype {
+        DType::F8E4M3 => bits.iter().map(|&b| e4m3::from_bits(b).to_f32()).collect(),
+        DType::F8E5M2 => bits.iter().map(|&b| e5m2::from_bits(b).to_f32()).collect(),
+        other => panic!("fp8_to_f32: unsupported dtype {:?}", other),
+    }
+}
+
+/// Round f32 values to the nearest FP8 value, as bits.
+///
+/// Out of range values saturate for E4M3, which has no infinity, and become infinite for E5M2.
+pub(crate) fn f32_to_fp8(values: &[f32], dtype: DType) -> Vec<u8> {
+    match dtype {
+        DType::F8E4M3 => values
+            .iter()
+            .map(|&v| e4m3::from_f32(v).to_bits())
+            .collect(),
+        DType::F8E5M2 => values
+            .iter()
+            .map(|&v| e5m2::from_f32(v).to_bits())
+            .collect(),
+        other => panic!("f32_to_fp8: unsupported dtype {:?}", other),
+    }
+}
+
 pub mod activation;
 pub mod attention;
 pub mod binary;
@@ -106,6 +137,7 @@ pub mod flip;
 mod float;
 pub mod gather_scatter;
 pub mod grid_sample;
+pub mod histogram;
 mod int;
 pub mod interpolate;
 pub mod mask;
@@ -117,6 +149,8 @@ pub mod reduce;
 pub mod repeat_dim;
 pub mod slice;
 pub mod sort;
+pub mod sparse;
+pub mod special;
 mod transaction;
 pub mod unary;
 pub mod unfold;
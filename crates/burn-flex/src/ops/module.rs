@@ -9,7 +9,8 @@ use burn_backend::{
     ops::{
         AttentionModuleOptions, ConvOptions, ConvTransposeOptions, DeformConv2dBackward,
         DeformConvOptions, FloatTensorOps, IntTensorOps, InterpolateMode, InterpolateOptions,
-        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+        MaxPool2dBackward, MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices,
+        ModuleOps,
     },
     tensor::{BoolTensor, FloatTensor, IntTensor},
 };
@@ -42,6 +43,20 @@ pub(crate) fn cast_from_f32<E: Element + Pod + Copy>(
     FlexTensor::new(bytes, Layout::contiguous(shape), E::dtype())
 }
 
+/// Routes the output gradient of a 3D max pooling to the input positions of the flat indices.
+fn max_pool3d_backward(x: FlexTensor, output_grad: FlexTensor, indices: FlexTensor) -> FlexTensor {
+    match x.dtype() {
+        DType::F32 => pool::max_pool3d_backward_f32(x, output_grad, indices),
+        DType::F64 => pool::max_pool3d_backward_f64(x, output_grad, indices),
+        DType::F16 => pool::max_pool3d_backward_f16(x, output_grad, indices),
+        DType::BF16 => pool::max_pool3d_backward_bf16(x, output_grad, indices),
+        dtype => panic!(
+            "max_pool3d_with_indices_backward: unsupported dtype {:?}",
+            dtype
+        ),
+    }
+}
+
 impl ModuleOps<Flex> for Flex {
     fn conv1d(
         x: FloatTensor<Flex>,
@@ -544,6 +559,215 @@ impl ModuleOps<Flex> for Flex {
         MaxPool2dBackward::new(x_grad)
     }
 
+    fn avg_pool3d(
+        x: FloatTensor<Flex>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Flex> {
+        match x.dtype() {
+            DType::F32 => pool::avg_pool3d_f32(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            ),
+            DType::F64 => pool::avg_pool3d_f64(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            ),
+            DType::F16 => pool::avg_pool3d_f16(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            ),
+            DType::BF16 => pool::avg_pool3d_bf16(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            ),
+            dtype => panic!("avg_pool3d: unsupported dtype {:?}", dtype),
+        }
+    }
+
+    fn avg_pool3d_backward(
+        x: FloatTensor<Flex>,
+        grad: FloatTensor<Flex>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        _ceil_mode: bool,
+    ) -> FloatTensor<Flex> {
+        match x.dtype() {
+            DType::F32 => pool::avg_pool3d_backward_f32(
+                x,
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            ),
+            DType::F64 => pool::avg_pool3d_backward_f64(
+                x,
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            ),
+            DType::F16 => pool::avg_pool3d_backward_f16(
+                x,
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            ),
+            DType::BF16 => pool::avg_pool3d_backward_bf16(
+                x,
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            ),
+            dtype => panic!("avg_pool3d_backward: unsupported dtype {:?}", dtype),
+        }
+    }
+
+    fn max_pool3d(
+        x: FloatTensor<Flex>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+    ) -> FloatTensor<Flex> {
+        match x.dtype() {
+            DType::F32 => {
+                pool::max_pool3d_f32(x, kernel_size, stride, padding, dilation, ceil_mode)
+            }
+            DType::F64 => {
+                pool::max_pool3d_f64(x, kernel_size, stride, padding, dilation, ceil_mode)
+            }
+            DType::F16 => {
+                pool::max_pool3d_f16(x, kernel_size, stride, padding, dilation, ceil_mode)
+            }
+            DType::BF16 => {
+                pool::max_pool3d_bf16(x, kernel_size, stride, padding, dilation, ceil_mode)
+            }
+            dtype => panic!("max_pool3d: unsupported dtype {:?}", dtype),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: FloatTensor<Flex>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Flex> {
+        let (output, mut indices) = match x.dtype() {
+            DType::F32 => pool::max_pool3d_with_indices_f32(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+            ),
+            DType::F64 => pool::max_pool3d_with_indices_f64(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+            ),
+            DType::F16 => pool::max_pool3d_with_indices_f16(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+            ),
+            DType::BF16 => pool::max_pool3d_with_indices_bf16(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                ceil_mode,
+            ),
+            dtype => panic!("max_pool3d_with_indices: unsupported dtype {:?}", dtype),
+        };
+        if indices.dtype() != DType::from(indices_dtype) {
+            indices = Flex::int_cast(indices, indices_dtype);
+        }
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<Flex>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _ceil_mode: bool,
+        output_grad: FloatTensor<Flex>,
+        indices: IntTensor<Flex>,
+    ) -> MaxPool3dBackward<Flex> {
+        MaxPool3dBackward::new(max_pool3d_backward(x, output_grad, indices))
+    }
+
+    fn adaptive_max_pool3d_with_indices(
+        x: FloatTensor<Flex>,
+        output_size: [usize; 3],
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Flex> {
+        let (output, mut indices) = match x.dtype() {
+            DType::F32 => pool::adaptive_max_pool3d_with_indices_f32(x, output_size),
+            DType::F64 => pool::adaptive_max_pool3d_with_indices_f64(x, output_size),
+            DType::F16 => pool::adaptive_max_pool3d_with_indices_f16(x, output_size),
+            DType::BF16 => pool::adaptive_max_pool3d_with_indices_bf16(x, output_size),
+            dtype => panic!(
+                "adaptive_max_pool3d_with_indices: unsupported dtype {:?}",
+                dtype
+            ),
+        };
+        if indices.dtype() != DType::from(indices_dtype) {
+            indices = Flex::int_cast(indices, indices_dtype);
+        }
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn adaptive_max_pool3d_with_indices_backward(
+        x: FloatTensor<Flex>,
+        output_grad: FloatTensor<Flex>,
+        indices: IntTensor<Flex>,
+    ) -> MaxPool3dBackward<Flex> {
+        // The indices are flattened over the input like the ones of max pooling.
+        MaxPool3dBackward::new(max_pool3d_backward(x, output_grad, indices))
+    }
+
     fn interpolate(
         x: FloatTensor<Flex>,
         output_size: [usize; 2],
@@ -728,6 +952,24 @@ impl ModuleOps<Flex> for Flex {
         crate::ops::attention::attention(query, key, value, mask, attn_bias, options)
     }
 
+    fn paged_attention(
+        query: FloatTensor<Flex>,
+        key_blocks: FloatTensor<Flex>,
+        value_blocks: FloatTensor<Flex>,
+        block_tables: IntTensor<Flex>,
+        seq_lengths: IntTensor<Flex>,
+        options: AttentionModuleOptions,
+    ) -> FloatTensor<Flex> {
+        crate::ops::attention::paged_attention(
+            query,
+            key_blocks,
+            value_blocks,
+            block_tables,
+            seq_lengths,
+            options,
+        )
+    }
+
     fn rfft(
         signal: FloatTensor<Flex>,
         dim: usize,
@@ -757,6 +999,36 @@ impl ModuleOps<Flex> for Flex {
         }
     }
 
+    fn fft(
+        signal_re: FloatTensor<Flex>,
+        signal_im: FloatTensor<Flex>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<Flex>, FloatTensor<Flex>) {
+        match signal_re.dtype() {
+            DType::F32 => crate::ops::fft::fft_f32(signal_re, signal_im, dim, n, false),
+            DType::F64 => crate::ops::fft::fft_f64(signal_re, signal_im, dim, n, false),
+            DType::F16 => crate::ops::fft::fft_f16(signal_re, signal_im, dim, n, false),
+            DType::BF16 => crate::ops::fft::fft_bf16(signal_re, signal_im, dim, n, false),
+            dtype => panic!("fft: unsupported dtype {:?}", dtype),
+        }
+    }
+
+    fn ifft(
+        spectrum_re: FloatTensor<Flex>,
+        spectrum_im: FloatTensor<Flex>,
+        dim: usize,
+        n: Option<usize>,
+    ) -> (FloatTensor<Flex>, FloatTensor<Flex>) {
+        match spectrum_re.dtype() {
+            DType::F32 => crate::ops::fft::fft_f32(spectrum_re, spectrum_im, dim, n, true),
+            DType::F64 => crate::ops::fft::fft_f64(spectrum_re, spectrum_im, dim, n, true),
+            DType::F16 => crate::ops::fft::fft_f16(spectrum_re, spectrum_im, dim, n, true),
+            DType::BF16 => crate::ops::fft::fft_bf16(spectrum_re, spectrum_im, dim, n, true),
+            dtype => panic!("ifft: unsupported dtype {:?}", dtype),
+        }
+    }
+
     fn embedding(weights: FloatTensor<Flex>, indices: IntTensor<Flex>) -> FloatTensor<Flex> {
         let [batch_size, seq_length] = indices.shape().dims();
         let [_, d_model] = weights.shape().dims();
@@ -138,6 +138,15 @@ macro_rules! adaptive_avg_pool3d_backward_typed {
     };
 }
 
+/// Generates adaptive_max_pool3d_with_indices typed dispatchers.
+macro_rules! adaptive_max_pool3d_with_indices_typed {
+    ($fn_name:ident, $T:ty, $dtype:expr, $neg_inf:expr) => {
+        pub fn $fn_name(x: FlexTensor, output_size: [usize; 3]) -> (FlexTensor, FlexTensor) {
+            adaptive_max_pool3d_with_indices_impl::<$T>(x, output_size, $dtype, $neg_inf)
+        }
+    };
+}
+
 // ============================================================================
 // Output size calculation
 // ============================================================================
@@ -1202,6 +1211,153 @@ pub fn adaptive_avg_pool1d_f32(x: FlexTensor, output_size: usize) -> FlexTensor
     squeeze_3d_to_1d(result)
 }
 
+// ============================================================================
+// Adaptive Max Pool 3D - core implementation
+// ============================================================================
+
+adaptive_max_pool3d_with_indices_typed!(
+    adaptive_max_pool3d_with_indices_f32,
+    f32,
+    DType::F32,
+    f32::NEG_INFINITY
+);
+adaptive_max_pool3d_with_indices_typed!(
+    adaptive_max_pool3d_with_indices_f64,
+    f64,
+    DType::F64,
+    f64::NEG_INFINITY
+);
+adaptive_max_pool3d_with_indices_typed!(
+    adaptive_max_pool3d_with_indices_f16,
+    f16,
+    DType::F16,
+    f16::NEG_INFINITY
+);
+
+pub fn adaptive_max_pool3d_with_indices_bf16(
+    x: FlexTensor,
+    output_size: [usize; 3],
+) -> (FlexTensor, FlexTensor) {
+    let x_f32 = convert_bf16_to_f32(&x);
+    let (output_f32, indices) = adaptive_max_pool3d_with_indices_f32(x_f32, output_size);
+    (convert_f32_to_bf16(&output_f32), indices)
+}
+
+/// Generic 3D adaptive max pooling with indices implementation.
+///
+/// The indices are flattened over the `[depth, height, width]` dimensions of the input, like
+/// [max_pool3d_with_indices_impl], so the backward pass is shared.
+fn adaptive_max_pool3d_with_indices_impl<T>(
+    x: FlexTensor,
+    output_size: [usize; 3],
+    dtype: DType,
+    neg_inf: T,
+) -> (FlexTensor, FlexTensor)
+where
+    T: bytemuck::Pod + Copy + PartialOrd + Send + Sync + Element,
+{
+    let x = x.to_contiguous();
+    let x_shape = x.layout().shape();
+
+    let batch_size = x_shape[0];
+    let channels = x_shape[1];
+    let in_dims = [x_shape[2], x_shape[3], x_shape[4]];
+
+    let [out_d, out_h, out_w] = output_size;
+    let spatial_in = in_dims.iter().product::<usize>();
+    let spatial_out = out_d * out_h * out_w;
+    let x_data: &[T] = x.storage();
+
+    let mut output = vec![neg_inf; batch_size * channels * spatial_out];
+    let mut indices = vec![-1i64; batch_size * channels * spatial_out];
+
+    if spatial_out > 0 {
+        let pool_plane = |bc: usize, (output, indices): (&mut [T], &mut [i64])| {
+            let x_plane = &x_data[bc * spatial_in..(bc + 1) * spatial_in];
+            adaptive_max_pool3d_plane(x_plane, output, indices, in_dims, output_size);
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            output
+                .par_chunks_mut(spatial_out)
+                .zip(indices.par_chunks_mut(spatial_out))
+                .enumerate()
+                .for_each(|(bc, chunks)| pool_plane(bc, chunks));
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            output
+                .chunks_mut(spatial_out)
+                .zip(indices.chunks_mut(spatial_out))
+                .enumerate()
+                .for_each(|(bc, chunks)| pool_plane(bc, chunks));
+        }
+    }
+
+    let out_shape = Shape::from(vec![batch_size, channels, out_d, out_h, out_w]);
+    let output_tensor = FlexTensor::new(
+        Bytes::from_elems(output),
+        Layout::contiguous(out_shape.clone()),
+        dtype,
+    );
+    let indices_tensor = FlexTensor::new(
+        Bytes::from_elems(indices),
+        Layout::contiguous(out_shape),
+        DType::I64,
+    );
+
+    (output_tensor, indices_tensor)
+}
+
+/// Adaptive max pooling of a single `[depth, height, width]` plane.
+fn adaptive_max_pool3d_plane<T: Copy + PartialOrd>(
+    x: &[T],
+    output: &mut [T],
+    indices: &mut [i64],
+    [in_d, in_h, in_w]: [usize; 3],
+    [out_d, out_h, out_w]: [usize; 3],
+) {
+    for od in 0..out_d {
+        // start = floor(out * in / out_size), end = ceil((out+1) * in / out_size)
+        let d_start = (od * in_d) / out_d;
+        let d_end = ((od + 1) * in_d).div_ceil(out_d);
+
+        for oh in 0..out_h {
+            let h_start = (oh * in_h) / out_h;
+            let h_end = ((oh + 1) * in_h).div_ceil(out_h);
+
+            for ow in 0..out_w {
+                let w_start = (ow * in_w) / out_w;
+                let w_end = ((ow + 1) * in_w).div_ceil(out_w);
+
+                let out_idx = od * out_h * out_w + oh * out_w + ow;
+                let mut max_val = output[out_idx];
+                let mut max_idx: i64 = -1;
+
+                for id in d_start..d_end {
+                    for ih in h_start..h_end {
+                        for iw in w_start..w_end {
+                            let x_idx = id * in_h * in_w + ih * in_w + iw;
+                            let val = x[x_idx];
+
+                            if max_idx < 0 || val > max_val {
+                                max_val = val;
+                                max_idx = x_idx as i64;
+                            }
+                        }
+                    }
+                }
+
+                output[out_idx] = max_val;
+                indices[out_idx] = max_idx;
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Backward passes
 // ============================================================================
@@ -1257,6 +1413,18 @@ max_pool3d_backward_typed!(
     |a: f16, b: f16| f16::from_f32(a.to_f32() + b.to_f32())
 );
 
+/// Max pool 3D backward for bf16.
+pub fn max_pool3d_backward_bf16(
+    x: FlexTensor,
+    grad: FlexTensor,
+    indices: FlexTensor,
+) -> FlexTensor {
+    let x_f32 = convert_bf16_to_f32(&x);
+    let grad_f32 = convert_bf16_to_f32(&grad);
+    let result_f32 = max_pool3d_backward_f32(x_f32, grad_f32, indices);
+    convert_f32_to_bf16(&result_f32)
+}
+
 /// Generic max pool 3D backward implementation.
 fn max_pool3d_backward_impl<T, I>(
     x: FlexTensor,
@@ -1429,6 +1597,28 @@ avg_pool3d_backward_typed!(
     |val: f16, count| f16::from_f32(val.to_f32() / count as f32)
 );
 
+/// Avg pool 3D backward for bf16.
+pub fn avg_pool3d_backward_bf16(
+    x: FlexTensor,
+    grad: FlexTensor,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FlexTensor {
+    let x_f32 = convert_bf16_to_f32(&x);
+    let grad_f32 = convert_bf16_to_f32(&grad);
+    let result_f32 = avg_pool3d_backward_f32(
+        x_f32,
+        grad_f32,
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    );
+    convert_f32_to_bf16(&result_f32)
+}
+
 /// Generic avg pool 3D backward implementation.
 #[allow(clippy::too_many_arguments)]
 fn avg_pool3d_backward_impl<T>(
@@ -1884,6 +2074,27 @@ mod tests {
         assert!(grad_data.iter().all(|&v| (v - 1.0).abs() < 1e-5));
     }
 
+    #[test]
+    fn test_adaptive_max_pool3d_overlapping_windows() {
+        let x = FlexTensor::from_data(TensorData::new(
+            vec![1.0f32, 5.0, 2.0, 4.0, 3.0],
+            vec![1, 1, 1, 1, 5],
+        ));
+
+        // Windows [0, 2), [1, 4) and [3, 5): the maximum of the first two is shared
+        let (output, indices) = adaptive_max_pool3d_with_indices_f32(x.clone(), [1, 1, 3]);
+        let out_data: Vec<f32> = output.into_data().to_vec().unwrap();
+        let idx_data: Vec<i64> = indices.clone().into_data().to_vec().unwrap();
+        assert_eq!(out_data, vec![5.0, 5.0, 4.0]);
+        assert_eq!(idx_data, vec![1, 1, 3]);
+
+        // The gradients of the shared maximum accumulate
+        let grad = FlexTensor::from_data(TensorData::new(vec![1.0f32; 3], vec![1, 1, 1, 1, 3]));
+        let x_grad = max_pool3d_backward_f32(x, grad, indices);
+        let grad_data: Vec<f32> = x_grad.into_data().to_vec().unwrap();
+        assert_eq!(grad_data, vec![0.0, 2.0, 0.0, 1.0, 0.0]);
+    }
+
     #[test]
     #[should_panic(expected = "kernel size must be > 0")]
     fn test_pool_output_size_zero_kernel_panics() {
@@ -16,7 +16,7 @@ use burn_backend::{
 };
 use burn_std::{Bytes, Shape, Slice, bf16, f16};
 
-use super::float_storage_as_f32;
+use super::{f32_to_fp8, float_storage_as_f32};
 use crate::{Flex, FlexQTensor, FlexTensor, Layout};
 
 impl QTensorOps<Flex> for Flex {
@@ -221,6 +221,11 @@ impl QTensorOps<Flex> for Flex {
                 let data: Vec<bf16> = dequantized.iter().map(|&v| bf16::from_f32(v)).collect();
                 FlexTensor::new(Bytes::from_elems(data), layout, DType::BF16)
             }
+            FloatDType::F8E4M3 | FloatDType::F8E5M2 => {
+                let dtype = DType::from(dtype);
+                let data = f32_to_fp8(&dequantized, dtype);
+                FlexTensor::new(Bytes::from_elems(data), layout, dtype)
+            }
         }
     }
 
@@ -0,0 +1,247 @@
+//! Sparse-dense matrix multiplication for FlexTensor.
+//!
+//! The stored values are visited once, each one scaling a row of the dense matrix
+//! into a row of the output. CSR rows are independent and run in parallel with the
+//! rayon feature; COO values are accumulated in order, so duplicates are summed.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_backend::ops::{FloatTensorOps, SparseTensorOps};
+use burn_backend::tensor::{FloatTensor, IntTensor};
+use burn_backend::{DType, Element, TensorMetadata};
+use burn_std::{Bytes, Shape};
+use bytemuck::Pod;
+use num_traits::Float;
+
+use crate::ops::float_storage_as_f32;
+use crate::ops::gather_scatter::read_indices;
+use crate::{Flex, FlexTensor, Layout};
+
+impl SparseTensorOps<Flex> for Flex {
+    fn sparse_coo_spmm(
+        row_indices: IntTensor<Flex>,
+        col_indices: IntTensor<Flex>,
+        values: FloatTensor<Flex>,
+        dense: FloatTensor<Flex>,
+        rows: usize,
+    ) -> FloatTensor<Flex> {
+        let dtype = dense.dtype();
+        let output = coo_spmm(row_indices, col_indices, values, dense, rows);
+        if output.dtype() != dtype {
+            Flex::float_cast(output, dtype.into())
+        } else {
+            output
+        }
+    }
+
+    fn sparse_csr_spmm(
+        crow_indices: IntTensor<Flex>,
+        col_indices: IntTensor<Flex>,
+        values: FloatTensor<Flex>,
+        dense: FloatTensor<Flex>,
+    ) -> FloatTensor<Flex> {
+        let dtype = dense.dtype();
+        let output = csr_spmm(crow_indices, col_indices, values, dense);
+        if output.dtype() != dtype {
+            Flex::float_cast(output, dtype.into())
+        } else {
+            output
+        }
+    }
+}
+
+/// Multiply a COO sparse matrix with `rows` rows by the dense matrix `[k, n]`.
+///
+/// Returns an F64 tensor when both the values and the dense matrix are F64 and an
+/// F32 tensor otherwise; the caller casts back to the dense dtype.
+pub fn coo_spmm(
+    row_indices: FlexTensor,
+    col_indices: FlexTensor,
+    values: FlexTensor,
+    dense: FlexTensor,
+    rows: usize,
+) -> FlexTensor {
+    let row_indices = row_indices.to_contiguous();
+    let col_indices = col_indices.to_contiguous();
+    let row_indices = read_indices(&row_indices);
+    let col_indices = read_indices(&col_indices);
+    let values = values.to_contiguous();
+    let dense = dense.to_contiguous();
+    let [k, n] = dense.shape().dims();
+
+    if values.dtype() == DType::F64 && dense.dtype() == DType::F64 {
+        let values = values.storage::<f64>();
+        let dense = dense.storage::<f64>();
+        let output = coo_spmm_rows(&row_indices, &col_indices, values, dense, rows, k, n);
+        make_matrix(output, rows, n, DType::F64)
+    } else {
+        let values = float_storage_as_f32(&values);
+        let dense = float_storage_as_f32(&dense);
+        let output = coo_spmm_rows(&row_indices, &col_indices, &values, &dense, rows, k, n);
+        make_matrix(output, rows, n, DType::F32)
+    }
+}
+
+/// Multiply a CSR sparse matrix by the dense matrix `[k, n]`.
+///
+/// See [coo_spmm] for the output dtype.
+pub fn csr_spmm(
+    crow_indices: FlexTensor,
+    col_indices: FlexTensor,
+    values: FlexTensor,
+    dense: FlexTensor,
+) -> FlexTensor {
+    let crow_indices = crow_indices.to_contiguous();
+    let col_indices = col_indices.to_contiguous();
+    let crow_indices = read_indices(&crow_indices);
+    let col_indices = read_indices(&col_indices);
+    let values = values.to_contiguous();
+    let dense = dense.to_contiguous();
+    let [k, n] = dense.shape().dims();
+    let rows = crow_indices.len() - 1;
+
+    if values.dtype() == DType::F64 && dense.dtype() == DType::F64 {
+        let values = values.storage::<f64>();
+        let dense = dense.storage::<f64>();
+        let output = csr_spmm_rows(&crow_indices, &col_indices, values, dense, k, n);
+        make_matrix(output, rows, n, DType::F64)
+    } else {
+        let values = float_storage_as_f32(&values);
+        let dense = float_storage_as_f32(&dense);
+        let output = csr_spmm_rows(&crow_indices, &col_indices, &values, &dense, k, n);
+        make_matrix(output, rows, n, DType::F32)
+    }
+}
+
+fn coo_spmm_rows<E: Float>(
+    row_indices: &[isize],
+    col_indices: &[isize],
+    values: &[E],
+    dense: &[E],
+    rows: usize,
+    k: usize,
+    n: usize,
+) -> Vec<E> {
+    let mut output = vec![E::zero(); rows * n];
+    for ((&row, &col), &value) in row_indices.iter().zip(col_indices).zip(values) {
+        let row = checked_index(row, rows, "row");
+        let col = checked_index(col, k, "column");
+        accumulate_row(
+            &mut output[row * n..(row + 1) * n],
+            &dense[col * n..(col + 1) * n],
+            value,
+        );
+    }
+    output
+}
+
+fn csr_spmm_rows<E: Float + Send + Sync>(
+    crow_indices: &[isize],
+    col_indices: &[isize],
+    values: &[E],
+    dense: &[E],
+    k: usize,
+    n: usize,
+) -> Vec<E> {
+    let rows = crow_indices.len() - 1;
+    let mut output = vec![E::zero(); rows * n];
+    if n == 0 {
+        return output;
+    }
+
+    let run_row = |row: usize, out: &mut [E]| {
+        let start = crow_indices[row] as usize;
+        let end = crow_indices[row + 1] as usize;
+        for (&col, &value) in col_indices[start..end].iter().zip(&values[start..end]) {
+            let col = checked_index(col, k, "column");
+            accumulate_row(out, &dense[col * n..(col + 1) * n], value);
+        }
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        output
+            .par_chunks_mut(n)
+            .enumerate()
+            .for_each(|(row, out)| run_row(row, out));
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        for (row, out) in output.chunks_mut(n).enumerate() {
+            run_row(row, out);
+        }
+    }
+
+    output
+}
+
+/// `out += value * dense_row`.
+fn accumulate_row<E: Float>(out: &mut [E], dense_row: &[E], value: E) {
+    for (o, &d) in out.iter_mut().zip(dense_row) {
+        *o = *o + value * d;
+    }
+}
+
+fn checked_index(index: isize, size: usize, name: &str) -> usize {
+    assert!(
+        index >= 0 && (index as usize) < size,
+        "spmm: {name} index {index} is out of bounds for size {size}"
+    );
+    index as usize
+}
+
+fn make_matrix<E: Element + Pod>(
+    data: Vec<E>,
+    rows: usize,
+    cols: usize,
+    dtype: DType,
+) -> FlexTensor {
+    FlexTensor::new(
+        Bytes::from_elems(data),
+        Layout::contiguous(Shape::new([rows, cols])),
+        dtype,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // [[1, 0, 2],
+    //  [0, 0, 0],
+    //  [0, 3, 0]]
+    const CROW: [isize; 4] = [0, 2, 2, 3];
+    const ROWS: [isize; 3] = [0, 0, 2];
+    const COLS: [isize; 3] = [0, 2, 1];
+    const VALUES: [f32; 3] = [1.0, 2.0, 3.0];
+    const DENSE: [f32; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+    #[test]
+    fn csr_spmm_rows_skips_empty_rows() {
+        let output = csr_spmm_rows(&CROW, &COLS, &VALUES, &DENSE, 3, 2);
+
+        assert_eq!(output, vec![11.0, 14.0, 0.0, 0.0, 9.0, 12.0]);
+    }
+
+    #[test]
+    fn coo_spmm_rows_matches_csr() {
+        let output = coo_spmm_rows(&ROWS, &COLS, &VALUES, &DENSE, 3, 3, 2);
+
+        assert_eq!(output, csr_spmm_rows(&CROW, &COLS, &VALUES, &DENSE, 3, 2));
+    }
+
+    #[test]
+    fn coo_spmm_rows_sums_duplicates() {
+        let output = coo_spmm_rows(&[1, 1], &[0, 0], &[1.0f64, 2.0], &[1.0, 2.0], 2, 1, 2);
+
+        assert_eq!(output, vec![0.0, 0.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn coo_spmm_rows_rejects_out_of_bounds_column() {
+        coo_spmm_rows(&[0], &[3], &[1.0f32], &DENSE, 1, 3, 2);
+    }
+}
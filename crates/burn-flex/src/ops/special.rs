@@ -0,0 +1,190 @@
+//! Special functions for FlexTensor: lgamma, digamma, polygamma, erfinv, i0 and i1.
+//!
+//! lgamma delegates to libm. The others are evaluated in f64 (f32 and half
+//! precision inputs are widened) to avoid the cancellation of the recurrences.
+
+use crate::FlexTensor;
+use crate::ops::unary::unary_op;
+use core::f64::consts::{FRAC_2_SQRT_PI, PI};
+
+/// `B_2k / (2k)!` for `k = 1..=7`, with `B_2k` the Bernoulli numbers.
+const BERNOULLI_OVER_FACTORIAL: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 720.0,
+    1.0 / 30_240.0,
+    -1.0 / 1_209_600.0,
+    1.0 / 47_900_160.0,
+    -691.0 / 1_307_674_368_000.0,
+    1.0 / 74_724_249_600.0,
+];
+
+/// Natural logarithm of the absolute value of the gamma function.
+pub fn lgamma(tensor: FlexTensor) -> FlexTensor {
+    unary_op(tensor, libm::lgammaf, libm::lgamma)
+}
+
+/// Logarithmic derivative of the gamma function.
+pub fn digamma(tensor: FlexTensor) -> FlexTensor {
+    unary_op(tensor, |x| digamma_f64(x as f64) as f32, digamma_f64)
+}
+
+/// `n`-th derivative of the digamma function.
+pub fn polygamma(tensor: FlexTensor, n: usize) -> FlexTensor {
+    unary_op(
+        tensor,
+        move |x| polygamma_f64(x as f64, n) as f32,
+        move |x| polygamma_f64(x, n),
+    )
+}
+
+/// Inverse of the error function.
+pub fn erfinv(tensor: FlexTensor) -> FlexTensor {
+    unary_op(tensor, |x| erfinv_f64(x as f64) as f32, erfinv_f64)
+}
+
+/// Modified Bessel function of the first kind of order 0.
+pub fn i0(tensor: FlexTensor) -> FlexTensor {
+    unary_op(
+        tensor,
+        |x| bessel_i_f64(x as f64, 0) as f32,
+        |x| bessel_i_f64(x, 0),
+    )
+}
+
+/// Modified Bessel function of the first kind of order 1.
+pub fn i1(tensor: FlexTensor) -> FlexTensor {
+    unary_op(
+        tensor,
+        |x| bessel_i_f64(x as f64, 1) as f32,
+        |x| bessel_i_f64(x, 1),
+    )
+}
+
+/// Digamma with the reflection formula for `x < 0.5`, the recurrence
+/// `psi(x) = psi(x + 1) - 1 / x` up to `x >= 10` and the asymptotic expansion.
+fn digamma_f64(x: f64) -> f64 {
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x < 0.0 && x == libm::floor(x) {
+        return f64::NAN;
+    }
+    if x < 0.5 {
+        return digamma_f64(1.0 - x) - PI / libm::tan(PI * x);
+    }
+
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+
+    // psi(x) ~ ln(x) - 1 / 2x - sum(B_2k / (2k x^2k))
+    let r = 1.0 / (x * x);
+    let mut series = 0.0;
+    for (k, c) in BERNOULLI_OVER_FACTORIAL.iter().enumerate().rev() {
+        series = (series + c * factorial(2 * k + 1)) * r;
+    }
+    result + libm::log(x) - 0.5 / x - series
+}
+
+/// Polygamma as `(-1)^(n + 1) n! zeta(n + 1, x)`, with the Hurwitz zeta function computed with
+/// the recurrence `zeta(s, x) = zeta(s, x + 1) + x^-s` and its Euler-Maclaurin expansion.
+fn polygamma_f64(x: f64, n: usize) -> f64 {
+    if n == 0 {
+        return digamma_f64(x);
+    }
+    if x <= 0.0 && x == libm::floor(x) {
+        return f64::NAN;
+    }
+
+    let s = (n + 1) as f64;
+    let mut y = x;
+    let mut zeta = 0.0;
+    while y < 10.0 + n as f64 {
+        zeta += libm::pow(y, -s);
+        y += 1.0;
+    }
+
+    // zeta(s, y) ~ y^-s (y / (s - 1) + 1 / 2 + y sum(B_2k / (2k)! (s)_(2k - 1) y^-2k))
+    let r = 1.0 / (y * y);
+    let mut series = 0.0;
+    for (k, c) in BERNOULLI_OVER_FACTORIAL.iter().enumerate().rev() {
+        let rising = (0..2 * k + 1).fold(1.0, |acc, i| acc * (s + i as f64));
+        series = (series + c * rising) * r;
+    }
+    zeta += libm::pow(y, -s) * (y / (s - 1.0) + 0.5 + y * series);
+
+    let sign = if n % 2 == 0 { -1.0 } else { 1.0 };
+    sign * factorial(n) * zeta
+}
+
+/// Approximation of M. Giles, "Approximating the erfinv function", refined by two Newton steps.
+fn erfinv_f64(x: f64) -> f64 {
+    if x.is_nan() || libm::fabs(x) > 1.0 {
+        return f64::NAN;
+    }
+    if libm::fabs(x) == 1.0 {
+        return x * f64::INFINITY;
+    }
+
+    let w = -libm::log((1.0 - x) * (1.0 + x));
+    let (t, coefficients) = if w < 5.0 {
+        (
+            w - 2.5,
+            [
+                2.810_226_36e-8,
+                3.432_739_39e-7,
+                -3.523_387_7e-6,
+                -4.391_506_54e-6,
+                2.185_808_7e-4,
+                -1.253_725_03e-3,
+                -4.177_681_64e-3,
+                2.466_407_27e-1,
+                1.501_409_41,
+            ],
+        )
+    } else {
+        (
+            libm::sqrt(w) - 3.0,
+            [
+                -2.002_142_57e-4,
+                1.009_505_58e-4,
+                1.349_343_22e-3,
+                -3.673_428_44e-3,
+                5.739_507_73e-3,
+                -7.622_461_3e-3,
+                9.438_870_47e-3,
+                1.001_674_06,
+                2.832_976_82,
+            ],
+        )
+    };
+    let mut y = coefficients.iter().fold(0.0, |acc, c| acc * t + c) * x;
+
+    for _ in 0..2 {
+        let error = libm::erf(y) - x;
+        y -= error / (FRAC_2_SQRT_PI * libm::exp(-y * y));
+    }
+    y
+}
+
+/// Power series `sum((x / 2)^(2k + order) / (k! (k + order)!))` of the modified Bessel function
+/// of the first kind, whose terms all have the same sign.
+fn bessel_i_f64(x: f64, order: usize) -> f64 {
+    let q = x * x / 4.0;
+    let mut term = libm::pow(x / 2.0, order as f64) / factorial(order);
+    let mut sum = term;
+    let mut k = 1.0;
+    while libm::fabs(term) > libm::fabs(sum) * f64::EPSILON && sum.is_finite() {
+        term *= q / (k * (k + order as f64));
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).fold(1.0, |acc, k| acc * k as f64)
+}
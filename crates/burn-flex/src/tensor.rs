@@ -314,7 +314,7 @@ impl FlexTensor {
             DType::U64 => self.copy_contiguous::<u64>(),
             DType::U32 => self.copy_contiguous::<u32>(),
             DType::U16 => self.copy_contiguous::<u16>(),
-            DType::U8 => self.copy_contiguous::<u8>(),
+            DType::U8 | DType::F8E4M3 | DType::F8E5M2 => self.copy_contiguous::<u8>(),
             DType::Bool(burn_std::BoolStore::Native | burn_std::BoolStore::U8) => {
                 self.copy_contiguous::<u8>()
             }
@@ -0,0 +1,4 @@
+use crate::{Fusion, FusionBackend};
+use burn_backend::ops::SparseTensorOps;
+
+impl<B: FusionBackend> SparseTensorOps<Self> for Fusion<B> {}
@@ -311,6 +311,23 @@ pub enum ModuleOperationIr {
     /// Operation corresponding to
     /// [max pool 2d with indices backward](burn_backend::ops::ModuleOps::max_pool2d_with_indices_backward).
     MaxPool2dWithIndicesBackward(MaxPool2dWithIndicesBackwardOpIr),
+    /// Operation corresponding to [avg pool 3d](burn_backend::ops::ModuleOps::avg_pool3d).
+    AvgPool3d(AvgPool3dOpIr),
+    /// Operation corresponding to
+    /// [avg pool 3d backward](burn_backend::ops::ModuleOps::avg_pool3d_backward).
+    AvgPool3dBackward(AvgPool3dBackwardOpIr),
+    /// Operation corresponding to
+    /// [max pool 3d with indices](burn_backend::ops::ModuleOps::max_pool3d_with_indices).
+    MaxPool3dWithIndices(MaxPool3dWithIndicesOpIr),
+    /// Operation corresponding to
+    /// [max pool 3d with indices backward](burn_backend::ops::ModuleOps::max_pool3d_with_indices_backward).
+    MaxPool3dWithIndicesBackward(MaxPool3dWithIndicesBackwardOpIr),
+    /// Operation corresponding to
+    /// [adaptive max pool 3d with indices](burn_backend::ops::ModuleOps::adaptive_max_pool3d_with_indices).
+    AdaptiveMaxPool3dWithIndices(AdaptiveMaxPool3dWithIndicesOpIr),
+    /// Operation corresponding to
+    /// [adaptive max pool 3d with indices backward](burn_backend::ops::ModuleOps::adaptive_max_pool3d_with_indices_backward).
+    AdaptiveMaxPool3dWithIndicesBackward(AdaptiveMaxPool3dWithIndicesBackwardOpIr),
     /// Operation corresponding to [interpolate](burn_backend::ops::ModuleOps::interpolate).
     Interpolate(InterpolateOpIr),
     /// Operation corresponding to [interpolate backward](burn_backend::ops::ModuleOps::interpolate_backward).
@@ -1864,6 +1881,76 @@ pub struct MaxPool2dWithIndicesBackwardOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AvgPool3dOpIr {
+    pub x: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub count_include_pad: bool,
+    pub ceil_mode: bool,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AvgPool3dBackwardOpIr {
+    pub x: TensorIr,
+    pub grad: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub count_include_pad: bool,
+    pub ceil_mode: bool,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dWithIndicesOpIr {
+    pub x: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub ceil_mode: bool,
+    pub out: TensorIr,
+    pub out_indices: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct MaxPool3dWithIndicesBackwardOpIr {
+    pub x: TensorIr,
+    pub grad: TensorIr,
+    pub indices: TensorIr,
+    pub kernel_size: [usize; 3],
+    pub stride: [usize; 3],
+    pub padding: [usize; 3],
+    pub dilation: [usize; 3],
+    pub ceil_mode: bool,
+    pub out: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AdaptiveMaxPool3dWithIndicesOpIr {
+    pub x: TensorIr,
+    pub output_size: [usize; 3],
+    pub out: TensorIr,
+    pub out_indices: TensorIr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct AdaptiveMaxPool3dWithIndicesBackwardOpIr {
+    pub x: TensorIr,
+    pub grad: TensorIr,
+    pub indices: TensorIr,
+    pub out: TensorIr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum InterpolateModeIr {
@@ -1973,6 +2060,8 @@ pub struct AttentionOptionsIr {
     pub scale: Option<ScalarIr>,
     pub softcap: Option<ScalarIr>,
     pub is_causal: bool,
+    pub sliding_window: Option<usize>,
+    pub alibi_max_bias: Option<ScalarIr>,
 }
 
 impl From<AttentionOptionsIr> for AttentionModuleOptions {
@@ -1981,6 +2070,8 @@ impl From<AttentionOptionsIr> for AttentionModuleOptions {
             scale: ir.scale.map(|s| s.elem()),
             softcap: ir.softcap.map(|s| s.elem()),
             is_causal: ir.is_causal,
+            sliding_window: ir.sliding_window,
+            alibi_max_bias: ir.alibi_max_bias.map(|s| s.elem()),
         }
     }
 }
@@ -1991,6 +2082,8 @@ impl From<AttentionModuleOptions> for AttentionOptionsIr {
             scale: ir.scale.map(ScalarIr::Float),
             softcap: ir.softcap.map(ScalarIr::Float),
             is_causal: ir.is_causal,
+            sliding_window: ir.sliding_window,
+            alibi_max_bias: ir.alibi_max_bias.map(ScalarIr::Float),
         }
     }
 }
@@ -3879,6 +3972,20 @@ impl ModuleOperationIr {
             ModuleOperationIr::MaxPool2dWithIndicesBackward(repr) => {
                 Box::new([&repr.x, &repr.indices, &repr.grad].into_iter())
             }
+            ModuleOperationIr::AvgPool3d(repr) => Box::new([&repr.x].into_iter()),
+            ModuleOperationIr::AvgPool3dBackward(repr) => {
+                Box::new([&repr.x, &repr.grad].into_iter())
+            }
+            ModuleOperationIr::MaxPool3dWithIndices(repr) => Box::new([&repr.x].into_iter()),
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(repr) => {
+                Box::new([&repr.x, &repr.indices, &repr.grad].into_iter())
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndices(repr) => {
+                Box::new([&repr.x].into_iter())
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndicesBackward(repr) => {
+                Box::new([&repr.x, &repr.indices, &repr.grad].into_iter())
+            }
             ModuleOperationIr::Interpolate(repr) => Box::new([&repr.x].into_iter()),
             ModuleOperationIr::InterpolateBackward(repr) => {
                 Box::new([&repr.x, &repr.grad].into_iter())
@@ -4027,6 +4134,20 @@ impl ModuleOperationIr {
             ModuleOperationIr::MaxPool2dWithIndicesBackward(repr) => {
                 Box::new([&repr.out].into_iter())
             }
+            ModuleOperationIr::AvgPool3d(repr) => Box::new([&repr.out].into_iter()),
+            ModuleOperationIr::AvgPool3dBackward(repr) => Box::new([&repr.out].into_iter()),
+            ModuleOperationIr::MaxPool3dWithIndices(repr) => {
+                Box::new([&repr.out, &repr.out_indices].into_iter())
+            }
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(repr) => {
+                Box::new([&repr.out].into_iter())
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndices(repr) => {
+                Box::new([&repr.out, &repr.out_indices].into_iter())
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndicesBackward(repr) => {
+                Box::new([&repr.out].into_iter())
+            }
             ModuleOperationIr::Interpolate(repr) => Box::new([&repr.out].into_iter()),
             ModuleOperationIr::InterpolateBackward(repr) => Box::new([&repr.out].into_iter()),
             ModuleOperationIr::Rfft(repr) => Box::new([&repr.out_re, &repr.out_im].into_iter()),
@@ -4269,6 +4390,29 @@ impl ModuleOperationIr {
                 repr.x.mark_read_only(nodes, &mut output);
                 repr.grad.mark_read_only(nodes, &mut output);
             }
+            ModuleOperationIr::AvgPool3d(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::AvgPool3dBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grad.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::MaxPool3dWithIndices(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grad.mark_read_only(nodes, &mut output);
+                repr.indices.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndices(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndicesBackward(repr) => {
+                repr.x.mark_read_only(nodes, &mut output);
+                repr.grad.mark_read_only(nodes, &mut output);
+                repr.indices.mark_read_only(nodes, &mut output);
+            }
             ModuleOperationIr::Interpolate(repr) => {
                 repr.x.mark_read_only(nodes, &mut output);
             }
@@ -4597,6 +4741,37 @@ impl ModuleOperationIr {
                 v.visit_tensor_mut(&mut repr.grad);
                 v.visit_tensor_mut(&mut repr.out);
             }
+            ModuleOperationIr::AvgPool3d(repr) => {
+                v.visit_tensor_mut(&mut repr.x);
+                v.visit_tensor_mut(&mut repr.out);
+            }
+            ModuleOperationIr::AvgPool3dBackward(repr) => {
+                v.visit_tensor_mut(&mut repr.x);
+                v.visit_tensor_mut(&mut repr.grad);
+                v.visit_tensor_mut(&mut repr.out);
+            }
+            ModuleOperationIr::MaxPool3dWithIndices(repr) => {
+                v.visit_tensor_mut(&mut repr.x);
+                v.visit_tensor_mut(&mut repr.out);
+                v.visit_tensor_mut(&mut repr.out_indices);
+            }
+            ModuleOperationIr::MaxPool3dWithIndicesBackward(repr) => {
+                v.visit_tensor_mut(&mut repr.x);
+                v.visit_tensor_mut(&mut repr.indices);
+                v.visit_tensor_mut(&mut repr.grad);
+                v.visit_tensor_mut(&mut repr.out);
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndices(repr) => {
+                v.visit_tensor_mut(&mut repr.x);
+                v.visit_tensor_mut(&mut repr.out);
+                v.visit_tensor_mut(&mut repr.out_indices);
+            }
+            ModuleOperationIr::AdaptiveMaxPool3dWithIndicesBackward(repr) => {
+                v.visit_tensor_mut(&mut repr.x);
+                v.visit_tensor_mut(&mut repr.indices);
+                v.visit_tensor_mut(&mut repr.grad);
+                v.visit_tensor_mut(&mut repr.out);
+            }
             ModuleOperationIr::Interpolate(repr) => {
                 v.visit_tensor_mut(&mut repr.x);
                 v.visit_tensor_mut(&mut repr.out);
@@ -4633,6 +4808,9 @@ impl ModuleOperationIr {
                 if let Some(softcap) = &mut repr.options.softcap {
                     v.visit_scalar_mut(softcap);
                 }
+                if let Some(alibi_max_bias) = &mut repr.options.alibi_max_bias {
+                    v.visit_scalar_mut(alibi_max_bias);
+                }
             }
             ModuleOperationIr::CtcLoss(repr) => {
                 v.visit_tensor_mut(&mut repr.log_probs);
@@ -4772,6 +4950,10 @@ impl core::hash::Hash for RandomOpIr {
             Distribution::Bernoulli(_) => 2u8.hash(state),
             Distribution::Uniform(_, _) => 3u8.hash(state),
             Distribution::Normal(_, _) => 4u8.hash(state),
+            Distribution::Exponential(_) => 5u8.hash(state),
+            Distribution::Gamma(_, _) => 6u8.hash(state),
+            Distribution::Beta(_, _) => 7u8.hash(state),
+            Distribution::Poisson(_) => 8u8.hash(state),
         }
     }
 }
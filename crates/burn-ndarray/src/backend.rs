@@ -94,7 +94,13 @@ impl Backend for NdArray {
             | DType::U16
             | DType::U8
             | DType::Bool(BoolStore::Native) => burn_backend::DTypeUsage::general(),
-            DType::F16 | DType::BF16 | DType::Bool(_) => burn_backend::DTypeUsageSet::empty(),
+            DType::F16
+            | DType::BF16
+            | DType::F8E4M3
+            | DType::F8E5M2
+            | DType::Bool(_)
+            | DType::Complex64
+            | DType::Complex32 => burn_backend::DTypeUsageSet::empty(),
             DType::QFloat(scheme) => {
                 match scheme {
                     QuantScheme {
@@ -3,6 +3,7 @@ use crate::rand::get_seeded_rng;
 use alloc::vec::Vec;
 use burn_backend::backend::ExecutionError;
 use burn_backend::ops::IntTensorOps;
+use burn_backend::ops::scatter::scatter_nd_indices;
 use burn_backend::tensor::{FloatTensor, IntTensor};
 use burn_backend::{Distribution, IntDType, Scalar, TensorMetadata};
 
@@ -297,7 +298,10 @@ impl IntTensorOps<Self> for NdArray {
                     ))
                 })
             }
-            other => unimplemented!("int_scatter with {other:?} update is not implemented"),
+            other => {
+                let indices = scatter_nd_indices::<Self>(dim, indices);
+                Self::int_scatter_nd(tensor, indices, value, other)
+            }
         }
     }
 
@@ -6,6 +6,7 @@ mod module;
 mod qtensor;
 #[cfg(feature = "simd")]
 mod simd;
+mod sparse;
 mod tensor;
 mod transaction;
 
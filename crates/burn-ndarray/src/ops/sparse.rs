@@ -0,0 +1,4 @@
+use crate::NdArray;
+use burn_backend::ops::SparseTensorOps;
+
+impl SparseTensorOps<Self> for NdArray {}
@@ -2,6 +2,7 @@
 use alloc::vec::Vec;
 use burn_backend::backend::ExecutionError;
 use burn_backend::ops::GridSampleOptions;
+use burn_backend::ops::scatter::scatter_nd_indices;
 use burn_backend::tensor::FloatTensor;
 use burn_backend::{TensorMetadata, element::cast::ToElement};
 use burn_std::{BoolDType, IntDType};
@@ -218,7 +219,10 @@ impl FloatTensorOps<Self> for NdArray {
                     }
                 )
             }
-            other => unimplemented!("float_scatter with {other:?} update is not implemented"),
+            other => {
+                let indices = scatter_nd_indices::<Self>(dim, indices);
+                Self::float_scatter_nd(tensor, indices, value, other)
+            }
         }
     }
 
@@ -1,13 +1,15 @@
 use burn_core as burn;
 
 use crate::activation::Gelu;
-use crate::cache::TensorCache;
+use crate::cache::{PagedKvCache, SequenceId, TensorCache};
 use crate::{Dropout, DropoutConfig, Linear, LinearConfig};
 use burn::config::Config;
 use burn::module::{Content, DisplaySettings, Initializer, Module, ModuleDisplay};
+use burn::tensor::ops::AttentionModuleOptions;
 use burn::tensor::{Bool, Device, Tensor};
 
 use burn::tensor::activation::{quiet_softmax, softmax};
+use burn::tensor::module::attention;
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::Float as _;
@@ -19,6 +21,13 @@ pub struct MultiHeadAttentionConfig {
     pub d_model: usize,
     /// The number of heads.
     pub n_heads: usize,
+    /// The number of key and value heads, shared by groups of `n_heads / num_kv_heads` query
+    /// heads (grouped-query attention). Default: `n_heads`
+    ///
+    /// The key and value heads are not repeated: the query heads of a group attend their key and
+    /// value head together.
+    #[config(default = "None")]
+    pub num_kv_heads: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
@@ -63,8 +72,8 @@ pub struct MultiHeadAttentionConfig {
 /// # Params
 ///
 /// - `query`: [`Linear`] layer with `d_model` input and output features.
-/// - `key`: [`Linear`] layer with `d_model` input and output features.
-/// - `value`: [`Linear`] layer with `d_model` input and output features.
+/// - `key`: [`Linear`] layer with `d_model` input features and `n_kv_heads * d_k` output features.
+/// - `value`: [`Linear`] layer with `d_model` input features and `n_kv_heads * d_k` output features.
 /// - `output`: [`Linear`] layer with `d_model` input and output features.
 ///
 /// Should be created with [MultiHeadAttentionConfig].
@@ -87,6 +96,8 @@ pub struct MultiHeadAttention {
     pub d_model: usize,
     /// The number of heads.
     pub n_heads: usize,
+    /// The number of key and value heads.
+    pub n_kv_heads: usize,
     /// Size of the key and query vectors.
     pub d_k: usize,
     /// Minimum value a float can take.
@@ -106,6 +117,7 @@ impl ModuleDisplay for MultiHeadAttention {
         content
             .add("d_model", &self.d_model)
             .add("n_heads", &self.n_heads)
+            .add("n_kv_heads", &self.n_kv_heads)
             .add("d_k", &self.d_k)
             .add("dropout", &self.dropout.prob)
             .add("min_float", &self.min_float)
@@ -130,18 +142,29 @@ pub struct MhaInput {
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init(&self, device: &Device) -> MultiHeadAttention {
+        let n_kv_heads = self.num_kv_heads.unwrap_or(self.n_heads);
+        assert!(
+            n_kv_heads > 0 && self.n_heads % n_kv_heads == 0,
+            "The number of heads ({}) must be divisible by the number of key and value heads ({n_kv_heads})",
+            self.n_heads
+        );
+
+        let d_k = self.d_model / self.n_heads;
         let linear_cfg = LinearConfig::new(self.d_model, self.d_model)
             .with_initializer(self.initializer.clone());
+        let linear_kv_cfg = LinearConfig::new(self.d_model, n_kv_heads * d_k)
+            .with_initializer(self.initializer.clone());
 
         MultiHeadAttention {
             query: linear_cfg.clone().with_bias(self.query_bias).init(device),
-            key: linear_cfg.clone().with_bias(self.key_bias).init(device),
-            value: linear_cfg.clone().with_bias(self.value_bias).init(device),
+            key: linear_kv_cfg.clone().with_bias(self.key_bias).init(device),
+            value: linear_kv_cfg.with_bias(self.value_bias).init(device),
             output: linear_cfg.clone().with_bias(self.output_bias).init(device),
             dropout: DropoutConfig::new(self.dropout).init(),
             activation: Gelu::new(),
             n_heads: self.n_heads,
-            d_k: self.d_model / self.n_heads,
+            n_kv_heads,
+            d_k,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
             d_model: self.d_model,
@@ -212,14 +235,12 @@ impl MultiHeadAttention {
     pub fn forward(&self, input: MhaInput) -> MhaOutput {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = self.attention_linear(input.query, &self.query);
-        let key = self.attention_linear(input.key, &self.key);
-        let value = self.attention_linear(input.value, &self.value);
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
-
-        let context = weights.clone().matmul(value);
+        let (weights, context) =
+            self.attn_context(query, key, value, input.mask_pad, input.mask_attn);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -239,20 +260,18 @@ impl MultiHeadAttention {
     pub fn forward_cache(&self, input: MhaInput, cache: &mut MhaCache) -> MhaOutput {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
-        let key = cache
-            .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
-        let value = cache
-            .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
-
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
-
-        let context = weights.clone().matmul(value);
+        let query = cache.query.forward(input.query, |t| {
+            self.attention_linear(t, &self.query, self.n_heads)
+        });
+        let key = cache.key.forward(input.key, |t| {
+            self.attention_linear(t, &self.key, self.n_kv_heads)
+        });
+        let value = cache.value.forward(input.value, |t| {
+            self.attention_linear(t, &self.value, self.n_kv_heads)
+        });
+
+        let (weights, context) =
+            self.attn_context(query, key, value, input.mask_pad, input.mask_attn);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -262,6 +281,200 @@ impl MultiHeadAttention {
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass with the fused [attention](burn::tensor::module::attention) op,
+    /// which returns the context without materializing the attention weights.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// With quiet softmax, or if dropout would be applied to the attention scores, since the
+    /// fused op supports neither.
+    pub fn forward_fused(&self, input: MhaInput) -> Tensor<3> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        self.assert_fused(&input.query.device());
+
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
+
+        let context = self.attn_context_fused(query, key, value, input.mask_pad, input.mask_attn);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+
+        self.output.forward(context)
+    }
+
+    /// Applies the forward pass using a [paged KV cache](PagedKvCache), for batched
+    /// autoregressive inference over sequences of different lengths.
+    ///
+    /// Each row of the batch holds the new tokens of one sequence of the cache. Their keys and
+    /// values are appended to the cache, then their queries attend causally to every token of
+    /// their sequence. Like [forward_fused](MultiHeadAttention::forward_fused), only the context
+    /// is returned.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length, d_model]`
+    /// - key: `[batch_size, seq_length, d_model]`
+    /// - value: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// If the input has masks, since the mask of each sequence comes from its block table, with
+    /// quiet softmax, or if dropout would be applied to the attention scores.
+    pub fn forward_paged(
+        &self,
+        input: MhaInput,
+        cache: &mut PagedKvCache,
+        sequences: &[SequenceId],
+    ) -> Tensor<3> {
+        assert!(
+            input.mask_pad.is_none() && input.mask_attn.is_none(),
+            "The paged cache masks each sequence from its block table"
+        );
+        self.assert_fused(&input.query.device());
+
+        let [batch_size, seq_length, d_model] = input.query.dims();
+        assert_eq!(
+            batch_size,
+            sequences.len(),
+            "The batch size must match the number of sequences"
+        );
+
+        let query = self.attention_linear(input.query, &self.query, self.n_heads);
+        let key = self.attention_linear(input.key, &self.key, self.n_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.n_kv_heads);
+
+        let shape = [self.n_kv_heads, seq_length, self.d_k];
+        for (i, id) in sequences.iter().enumerate() {
+            cache.append(
+                *id,
+                key.clone().slice(i..i + 1).reshape(shape),
+                value.clone().slice(i..i + 1).reshape(shape),
+            );
+        }
+
+        let context = cache
+            .attention(query, sequences, AttentionModuleOptions::default())
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+
+        self.output.forward(context)
+    }
+
+    /// Computes the attention weights and the context of each query head.
+    ///
+    /// The query heads sharing a key and value head are folded into the sequence dimension, so
+    /// each key and value head is attended by `n_heads / n_kv_heads` times more queries instead
+    /// of being repeated.
+    fn attn_context(
+        &self,
+        query: Tensor<4>,
+        key: Tensor<4>,
+        value: Tensor<4>,
+        mask_pad: Option<Tensor<2, Bool>>,
+        mask_attn: Option<Tensor<3, Bool>>,
+    ) -> (Tensor<4>, Tensor<4>) {
+        let [batch_size, n_heads, seq_length_1, d_k] = query.dims();
+        let seq_length_2 = key.dims()[2];
+        let n_groups = n_heads / self.n_kv_heads;
+
+        let query = query.reshape([batch_size, self.n_kv_heads, n_groups * seq_length_1, d_k]);
+        let attn_scores =
+            self.attn_scores(query, key)
+                .reshape([batch_size, n_heads, seq_length_1, seq_length_2]);
+        let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+
+        let context = weights
+            .clone()
+            .reshape([
+                batch_size,
+                self.n_kv_heads,
+                n_groups * seq_length_1,
+                seq_length_2,
+            ])
+            .matmul(value)
+            .reshape([batch_size, n_heads, seq_length_1, d_k]);
+
+        (weights, context)
+    }
+
+    /// Computes the context of each query head with the fused attention op, with the query heads
+    /// folded like in [attn_context](MultiHeadAttention::attn_context).
+    fn attn_context_fused(
+        &self,
+        query: Tensor<4>,
+        key: Tensor<4>,
+        value: Tensor<4>,
+        mask_pad: Option<Tensor<2, Bool>>,
+        mask_attn: Option<Tensor<3, Bool>>,
+    ) -> Tensor<4> {
+        let [batch_size, n_heads, seq_length_1, d_k] = query.dims();
+        let seq_length_2 = key.dims()[2];
+        let n_groups = n_heads / self.n_kv_heads;
+        let shape_folded = [
+            batch_size,
+            self.n_kv_heads,
+            n_groups * seq_length_1,
+            seq_length_2,
+        ];
+
+        // Query head `h` attends the key and value head `h / n_groups`.
+        let query = query.reshape([batch_size, self.n_kv_heads, n_groups * seq_length_1, d_k]);
+
+        let mask_pad = mask_pad.map(|mask_pad| {
+            mask_pad
+                .reshape([batch_size, 1, 1, seq_length_2])
+                .expand(shape_folded)
+        });
+        let mask_attn = mask_attn.map(|mask_attn| {
+            mask_attn
+                .reshape([batch_size, 1, 1, seq_length_1, seq_length_2])
+                .expand([
+                    batch_size,
+                    self.n_kv_heads,
+                    n_groups,
+                    seq_length_1,
+                    seq_length_2,
+                ])
+                .reshape(shape_folded)
+        });
+        let mask = match (mask_pad, mask_attn) {
+            (Some(mask_pad), Some(mask_attn)) => Some(mask_pad.bool_or(mask_attn)),
+            (mask_pad, mask_attn) => mask_pad.or(mask_attn),
+        };
+
+        attention(
+            query,
+            key,
+            value,
+            mask,
+            None,
+            AttentionModuleOptions::default(),
+        )
+        .reshape([batch_size, n_heads, seq_length_1, d_k])
+    }
+
+    /// Panics if the fused attention op can't compute the attention of the layer.
+    fn assert_fused(&self, device: &Device) {
+        assert!(
+            !self.quiet_softmax,
+            "Quiet softmax isn't supported by the fused attention"
+        );
+        assert!(
+            self.dropout.prob == 0.0 || !device.is_autodiff(),
+            "Dropout on the attention scores isn't supported by the fused attention"
+        );
+    }
+
     fn attn_scores(&self, query: Tensor<4>, key: Tensor<4>) -> Tensor<4> {
         let attn_scores = query
             .matmul(key.transpose())
@@ -301,18 +514,19 @@ impl MultiHeadAttention {
         }
     }
 
-    fn attention_linear(&self, x: Tensor<3>, linear: &Linear) -> Tensor<4> {
+    fn attention_linear(&self, x: Tensor<3>, linear: &Linear, n_heads: usize) -> Tensor<4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
             .swap_dims(1, 2)
     }
 }
 
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
 ///
-/// To be used during inference when decoding tokens.
+/// To be used during inference when decoding tokens. With grouped-query attention, the keys and
+/// values are cached for the `n_kv_heads` key and value heads only.
 pub struct MhaCache {
     query: MhaLinearCache<4>,
     key: MhaLinearCache<4>,
@@ -367,10 +581,14 @@ impl<const D: usize> MhaLinearCache<D> {
 mod tests {
     use super::*;
     use crate::attention::generate_autoregressive_mask;
+    use crate::cache::PagedKvCacheConfig;
+    use alloc::vec;
     use alloc::vec::Vec;
+    use burn::module::Param;
     use burn::tensor::Int;
     use burn::tensor::Tolerance;
     use burn::tensor::{Distribution, Shape};
+    use rstest::rstest;
 
     #[test]
     fn test_enable_bias() {
@@ -564,6 +782,339 @@ mod tests {
             .assert_approx_eq::<f32>(&output_2.into_data(), Tolerance::default());
     }
 
+    #[test]
+    fn test_grouped_query_attention_shapes() {
+        let [
+            batch_size,
+            seq_length_1,
+            seq_length_2,
+            d_model,
+            n_heads,
+            n_kv_heads,
+        ] = [7, 13, 15, 32, 4, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_kv_heads(Some(n_kv_heads))
+            .init(&device);
+        let input = MhaInput::new(
+            Tensor::random(
+                [batch_size, seq_length_1, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            Tensor::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            Tensor::random(
+                [batch_size, seq_length_2, d_model],
+                Distribution::Default,
+                &device,
+            ),
+        );
+
+        let output = mha.forward(input);
+
+        assert_eq!(
+            mha.key.weight.val().shape(),
+            Shape::new([d_model, n_kv_heads * d_model / n_heads]),
+            "Key projection should only have the key and value heads",
+        );
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length_1, d_model]),
+            "Context should have the correct shape",
+        );
+        assert_eq!(
+            output.weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length_1, seq_length_2]),
+            "Weights should have the correct shape",
+        );
+    }
+
+    #[test]
+    fn test_grouped_query_attention_should_match_repeated_kv_heads() {
+        let [batch_size, seq_length, d_model, n_heads, n_kv_heads] = [2, 5, 16, 4, 2];
+        let d_k = d_model / n_heads;
+        let n_groups = n_heads / n_kv_heads;
+        let device = Default::default();
+        let gqa = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_kv_heads(Some(n_kv_heads))
+            .with_dropout(0.0)
+            .init(&device);
+        let mut mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_dropout(0.0)
+            .init(&device);
+
+        // Each key and value head of the grouped layer, repeated for its group of query heads.
+        let repeat_heads = |linear: &Linear| Linear {
+            weight: Param::from_tensor(
+                linear
+                    .weight
+                    .val()
+                    .reshape([d_model, n_kv_heads, 1, d_k])
+                    .expand([d_model, n_kv_heads, n_groups, d_k])
+                    .reshape([d_model, d_model]),
+            ),
+            bias: linear.bias.as_ref().map(|bias| {
+                Param::from_tensor(
+                    bias.val()
+                        .reshape([n_kv_heads, 1, d_k])
+                        .expand([n_kv_heads, n_groups, d_k])
+                        .reshape([d_model]),
+                )
+            }),
+        };
+        mha.query = gqa.query.clone();
+        mha.key = repeat_heads(&gqa.key);
+        mha.value = repeat_heads(&gqa.value);
+        mha.output = gqa.output.clone();
+
+        let tensor = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_pad = Tensor::<1, Int>::arange(0..seq_length as i64, &device)
+            .reshape([1, seq_length])
+            .repeat_dim(0, batch_size)
+            .greater_equal_elem(seq_length as i64 - 1);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let output_gqa = gqa.forward(input.clone());
+        let output_mha = mha.forward(input);
+
+        output_gqa
+            .context
+            .into_data()
+            .assert_approx_eq::<f32>(&output_mha.context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_grouped_query_attention_autoregressive_decoding() {
+        let [batch_size, seq_length, d_model, n_heads, n_kv_heads] = [3, 4, 12, 6, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_kv_heads(Some(n_kv_heads))
+            .init(&device);
+
+        let tensor = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = MhaCache::autoregressive();
+
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().slice([0..batch_size, 0..i, 0..d_model]);
+            let input = MhaInput::self_attn(tensor);
+            let next_tok = mha.forward_cache(input, &mut cache).context.slice([
+                0..batch_size,
+                i - 1..i,
+                0..d_model,
+            ]);
+            output_2.push(next_tok);
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq::<f32>(&output_2.into_data(), Tolerance::default());
+    }
+
+    #[rstest]
+    #[case::mha(None)]
+    #[case::gqa(Some(2))]
+    fn test_forward_fused_should_match_forward(#[case] num_kv_heads: Option<usize>) {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 16, 4];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_kv_heads(num_kv_heads)
+            .init(&device);
+
+        let tensor = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_pad = Tensor::<1, Int>::arange(0..seq_length as i64, &device)
+            .reshape([1, seq_length])
+            .repeat_dim(0, batch_size)
+            .greater_equal_elem(seq_length as i64 - 1);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let output = mha.forward(input.clone());
+        let context = mha.forward_fused(input);
+
+        context
+            .into_data()
+            .assert_approx_eq::<f32>(&output.context.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be divisible by the number of key and value heads")]
+    fn test_grouped_query_attention_invalid_heads() {
+        MultiHeadAttentionConfig::new(12, 4)
+            .with_num_kv_heads(Some(3))
+            .init(&Default::default());
+    }
+
+    /// Causal self-attention over a whole sequence `[1, seq_length, d_model]`.
+    fn causal_forward(mha: &MultiHeadAttention, tensor: Tensor<3>) -> Tensor<3> {
+        let seq_length = tensor.dims()[1];
+        let mask_attn = generate_autoregressive_mask(1, seq_length, &tensor.device());
+
+        mha.forward(MhaInput::self_attn(tensor).mask_attn(mask_attn))
+            .context
+    }
+
+    #[rstest]
+    #[case::mha(None)]
+    #[case::gqa(Some(2))]
+    fn test_paged_cache_should_match_full_attention(#[case] num_kv_heads: Option<usize>) {
+        let [d_model, n_heads, block_size] = [12, 4, 2];
+        let [prompt_a, prompt_b, num_steps] = [5, 3, 3];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_kv_heads(num_kv_heads)
+            .init(&device);
+        let mut cache =
+            PagedKvCacheConfig::new(16, block_size, mha.n_kv_heads, mha.d_k).init(&device);
+
+        let tensor_a = Tensor::<3>::random(
+            [1, prompt_a + num_steps, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let tensor_b = Tensor::<3>::random(
+            [1, prompt_b + num_steps, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let seq_a = cache.add_sequence();
+        let seq_b = cache.add_sequence();
+
+        // Prefill each prompt, then decode both sequences in the same batch.
+        let mut output_a = Vec::new();
+        let mut output_b = Vec::new();
+        let input = MhaInput::self_attn(tensor_a.clone().slice([0..1, 0..prompt_a]));
+        output_a.push(mha.forward_paged(input, &mut cache, &[seq_a]));
+        let input = MhaInput::self_attn(tensor_b.clone().slice([0..1, 0..prompt_b]));
+        output_b.push(mha.forward_paged(input, &mut cache, &[seq_b]));
+
+        for i in 0..num_steps {
+            let tokens = Tensor::cat(
+                vec![
+                    tensor_a
+                        .clone()
+                        .slice([0..1, prompt_a + i..prompt_a + i + 1]),
+                    tensor_b
+                        .clone()
+                        .slice([0..1, prompt_b + i..prompt_b + i + 1]),
+                ],
+                0,
+            );
+            let context =
+                mha.forward_paged(MhaInput::self_attn(tokens), &mut cache, &[seq_a, seq_b]);
+            output_a.push(context.clone().slice(0..1));
+            output_b.push(context.slice(1..2));
+        }
+
+        assert_eq!(cache.block_table(seq_a).len(), prompt_a + num_steps);
+        assert_eq!(cache.block_table(seq_b).len(), prompt_b + num_steps);
+        assert_eq!(cache.num_free_blocks(), 16 - 4 - 3);
+
+        Tensor::cat(output_a, 1)
+            .into_data()
+            .assert_approx_eq::<f32>(
+                &causal_forward(&mha, tensor_a).into_data(),
+                Tolerance::default(),
+            );
+        Tensor::cat(output_b, 1)
+            .into_data()
+            .assert_approx_eq::<f32>(
+                &causal_forward(&mha, tensor_b).into_data(),
+                Tolerance::default(),
+            );
+
+        cache.remove_sequence(seq_a);
+        cache.remove_sequence(seq_b);
+        assert_eq!(cache.num_free_blocks(), 16);
+    }
+
+    #[test]
+    fn test_paged_cache_fork_should_copy_shared_block_on_write() {
+        let [d_model, n_heads, block_size, prompt] = [12, 4, 2, 3];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init(&device);
+        let mut cache = PagedKvCacheConfig::new(8, block_size, n_heads, mha.d_k).init(&device);
+
+        let tensor = Tensor::<3>::random([1, prompt, d_model], Distribution::Default, &device);
+        let next_a = Tensor::<3>::random([1, 1, d_model], Distribution::Default, &device);
+        let next_b = Tensor::<3>::random([1, 1, d_model], Distribution::Default, &device);
+
+        let seq_a = cache.add_sequence();
+        mha.forward_paged(MhaInput::self_attn(tensor.clone()), &mut cache, &[seq_a]);
+        assert_eq!(cache.num_free_blocks(), 6);
+
+        // The fork shares both blocks of the prompt, the last one being partially filled.
+        let seq_b = cache.fork_sequence(seq_a);
+        assert_eq!(cache.block_table(seq_b), cache.block_table(seq_a));
+        assert_eq!(cache.num_free_blocks(), 6);
+        assert_eq!(cache.blocks_needed(seq_a, 1), 1);
+
+        let tokens = Tensor::cat(vec![next_a.clone(), next_b.clone()], 0);
+        let context = mha.forward_paged(MhaInput::self_attn(tokens), &mut cache, &[seq_a, seq_b]);
+
+        // The first sequence to append copies the shared block, the other one owns it again.
+        let blocks_a = cache.block_table(seq_a).blocks();
+        let blocks_b = cache.block_table(seq_b).blocks();
+        assert_eq!(blocks_a[0], blocks_b[0]);
+        assert_ne!(blocks_a[1], blocks_b[1]);
+        assert_eq!(cache.num_free_blocks(), 5);
+
+        let expected_a = causal_forward(&mha, Tensor::cat(vec![tensor.clone(), next_a], 1));
+        let expected_b = causal_forward(&mha, Tensor::cat(vec![tensor, next_b], 1));
+        context
+            .clone()
+            .slice(0..1)
+            .into_data()
+            .assert_approx_eq::<f32>(
+                &expected_a.slice([0..1, prompt..prompt + 1]).into_data(),
+                Tolerance::default(),
+            );
+        context.slice(1..2).into_data().assert_approx_eq::<f32>(
+            &expected_b.slice([0..1, prompt..prompt + 1]).into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "requires 3 free blocks")]
+    fn test_paged_cache_out_of_blocks() {
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(12, 4).init(&device);
+        let mut cache = PagedKvCacheConfig::new(2, 2, 4, mha.d_k).init(&device);
+        let sequence = cache.add_sequence();
+
+        let tensor = Tensor::<3>::random([1, 5, 12], Distribution::Default, &device);
+        mha.forward_paged(MhaInput::self_attn(tensor), &mut cache, &[sequence]);
+    }
+
     #[test]
     fn display() {
         let config = MultiHeadAttentionConfig::new(2, 4);
@@ -571,7 +1122,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{mha}"),
-            "MultiHeadAttention {d_model: 2, n_heads: 4, d_k: 0, \
+            "MultiHeadAttention {d_model: 2, n_heads: 4, n_kv_heads: 4, d_k: 0, \
             dropout: 0.1, min_float: -10000, quiet_softmax: false, params: 24}"
         );
     }
@@ -0,0 +1,143 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Allocator of the fixed-size blocks of a [paged cache](super::PagedKvCache).
+///
+/// Blocks are reference counted, so that a block can be shared by several
+/// [block tables](BlockTable) and is only freed once the last of them releases it.
+#[derive(Debug, Clone)]
+pub struct BlockAllocator {
+    free: Vec<usize>,
+    ref_counts: Vec<usize>,
+}
+
+impl BlockAllocator {
+    /// Creates an allocator of `num_blocks` blocks, all free.
+    pub fn new(num_blocks: usize) -> Self {
+        Self {
+            // Reversed, so that the blocks are allocated in increasing order.
+            free: (0..num_blocks).rev().collect(),
+            ref_counts: vec![0; num_blocks],
+        }
+    }
+
+    /// Allocates a free block, or returns `None` when all the blocks are in use.
+    pub fn allocate(&mut self) -> Option<usize> {
+        let block = self.free.pop()?;
+        self.ref_counts[block] = 1;
+
+        Some(block)
+    }
+
+    /// Shares an allocated block, which is only freed once it is [released](Self::release) by
+    /// every owner.
+    ///
+    /// # Panics
+    ///
+    /// If the block isn't allocated.
+    pub fn share(&mut self, block: usize) {
+        assert!(self.ref_count(block) > 0, "Block {block} isn't allocated");
+        self.ref_counts[block] += 1;
+    }
+
+    /// Releases a block, which becomes free when it has no owner left.
+    ///
+    /// # Panics
+    ///
+    /// If the block isn't allocated.
+    pub fn release(&mut self, block: usize) {
+        assert!(self.ref_count(block) > 0, "Block {block} isn't allocated");
+        self.ref_counts[block] -= 1;
+
+        if self.ref_counts[block] == 0 {
+            self.free.push(block);
+        }
+    }
+
+    /// Returns the number of owners of a block, zero when it is free.
+    pub fn ref_count(&self, block: usize) -> usize {
+        self.ref_counts[block]
+    }
+
+    /// Returns the number of free blocks.
+    pub fn num_free(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns the total number of blocks.
+    pub fn num_blocks(&self) -> usize {
+        self.ref_counts.len()
+    }
+}
+
+/// The blocks holding the tokens of a sequence in a [paged cache](super::PagedKvCache), in order.
+///
+/// Token `i` of the sequence is stored at offset `i % block_size` of block
+/// `blocks[i / block_size]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockTable {
+    pub(crate) blocks: Vec<usize>,
+    pub(crate) len: usize,
+}
+
+impl BlockTable {
+    /// Returns the blocks of the sequence.
+    pub fn blocks(&self) -> &[usize] {
+        &self.blocks
+    }
+
+    /// Returns the number of tokens of the sequence.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the sequence has no tokens.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_and_release() {
+        let mut allocator = BlockAllocator::new(3);
+
+        assert_eq!(allocator.allocate(), Some(0));
+        assert_eq!(allocator.allocate(), Some(1));
+        assert_eq!(allocator.allocate(), Some(2));
+        assert_eq!(allocator.allocate(), None);
+        assert_eq!(allocator.num_free(), 0);
+
+        allocator.release(1);
+        assert_eq!(allocator.num_free(), 1);
+        assert_eq!(allocator.ref_count(1), 0);
+        assert_eq!(allocator.allocate(), Some(1));
+    }
+
+    #[test]
+    fn test_shared_block_is_freed_by_last_owner() {
+        let mut allocator = BlockAllocator::new(2);
+        let block = allocator.allocate().unwrap();
+
+        allocator.share(block);
+        assert_eq!(allocator.ref_count(block), 2);
+
+        allocator.release(block);
+        assert_eq!(allocator.ref_count(block), 1);
+        assert_eq!(allocator.num_free(), 1);
+
+        allocator.release(block);
+        assert_eq!(allocator.ref_count(block), 0);
+        assert_eq!(allocator.num_free(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Block 0 isn't allocated")]
+    fn test_release_free_block_panics() {
+        let mut allocator = BlockAllocator::new(2);
+        allocator.release(0);
+    }
+}
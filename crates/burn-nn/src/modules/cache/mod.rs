@@ -1,4 +1,8 @@
 mod autoregressive;
 mod base;
+mod block;
+mod paged;
 
 pub use base::*;
+pub use block::*;
+pub use paged::*;
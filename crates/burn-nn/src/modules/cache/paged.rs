@@ -0,0 +1,381 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use burn_core as burn;
+
+use super::{BlockAllocator, BlockTable, CacheState};
+use burn::config::Config;
+use burn::tensor::module::paged_attention;
+use burn::tensor::ops::AttentionModuleOptions;
+use burn::tensor::{Device, Tensor, TensorData};
+
+/// Configuration to create a [paged KV cache](PagedKvCache) using the [init function](PagedKvCacheConfig::init).
+#[derive(Config, Debug)]
+pub struct PagedKvCacheConfig {
+    /// The number of blocks, shared by all the sequences.
+    pub num_blocks: usize,
+    /// The number of tokens per block.
+    pub block_size: usize,
+    /// The number of key and value heads.
+    pub n_kv_heads: usize,
+    /// The size of the key and value vectors of each head.
+    pub d_k: usize,
+}
+
+impl PagedKvCacheConfig {
+    /// Initialize a new [paged KV cache](PagedKvCache), with all its blocks free.
+    pub fn init(&self, device: &Device) -> PagedKvCache {
+        assert!(self.block_size > 0, "The block size must be positive");
+
+        let shape = [self.num_blocks, self.n_kv_heads, self.block_size, self.d_k];
+
+        PagedKvCache {
+            blocks: CacheState::Value(KvBlocks {
+                key: Tensor::zeros(shape, device),
+                value: Tensor::zeros(shape, device),
+            }),
+            allocator: BlockAllocator::new(self.num_blocks),
+            tables: BTreeMap::new(),
+            next_id: 0,
+            block_size: self.block_size,
+        }
+    }
+}
+
+/// Identifier of a sequence in a [paged KV cache](PagedKvCache).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SequenceId(usize);
+
+/// A block-paged cache of keys and values, for batched autoregressive inference over many
+/// sequences of different lengths.
+///
+/// Instead of a contiguous tensor per sequence, the keys and values are stored in fixed-size
+/// blocks of `block_size` tokens taken from a shared pool, and the [block table](BlockTable) of
+/// each sequence maps its tokens to their blocks. Sequences only hold the blocks they use, and
+/// their blocks are returned to the pool when they are [removed](Self::remove_sequence), so the
+/// memory is allocated once for all the sequences.
+///
+/// A [forked](Self::fork_sequence) sequence shares the blocks of its parent, like a common
+/// prompt shared by several generations. A shared block is copied when one of its owners
+/// appends to it (copy-on-write), while its full blocks stay shared.
+///
+/// One cache holds the keys and values of one attention layer, see
+/// [MultiHeadAttention::forward_paged](crate::attention::MultiHeadAttention::forward_paged).
+pub struct PagedKvCache {
+    blocks: CacheState<KvBlocks>,
+    allocator: BlockAllocator,
+    tables: BTreeMap<SequenceId, BlockTable>,
+    next_id: usize,
+    block_size: usize,
+}
+
+/// The keys and values of all the blocks `[num_blocks, n_kv_heads, block_size, d_k]`.
+struct KvBlocks {
+    key: Tensor<4>,
+    value: Tensor<4>,
+}
+
+/// An update of the blocks, planned before being applied on the tensors.
+enum BlockUpdate {
+    /// Copies a block into another.
+    Copy { src: usize, dst: usize },
+    /// Writes the tokens `tokens` of the appended keys and values at `offset` in a block.
+    Write {
+        block: usize,
+        offset: usize,
+        tokens: core::ops::Range<usize>,
+    },
+}
+
+impl PagedKvCache {
+    /// Adds an empty sequence.
+    pub fn add_sequence(&mut self) -> SequenceId {
+        let id = SequenceId(self.next_id);
+        self.next_id += 1;
+        self.tables.insert(id, BlockTable::default());
+
+        id
+    }
+
+    /// Adds a sequence starting with the tokens of the parent sequence, sharing its blocks.
+    ///
+    /// No block is allocated or copied until one of the sequences appends to a shared block.
+    pub fn fork_sequence(&mut self, parent: SequenceId) -> SequenceId {
+        let table = self.block_table(parent).clone();
+        for &block in table.blocks() {
+            self.allocator.share(block);
+        }
+
+        let id = SequenceId(self.next_id);
+        self.next_id += 1;
+        self.tables.insert(id, table);
+
+        id
+    }
+
+    /// Removes a sequence, releasing its blocks.
+    pub fn remove_sequence(&mut self, id: SequenceId) {
+        let table = self
+            .tables
+            .remove(&id)
+            .unwrap_or_else(|| panic!("Sequence {id:?} isn't in the paged cache"));
+
+        for block in table.blocks {
+            self.allocator.release(block);
+        }
+    }
+
+    /// Returns the block table of a sequence.
+    pub fn block_table(&self, id: SequenceId) -> &BlockTable {
+        self.tables
+            .get(&id)
+            .unwrap_or_else(|| panic!("Sequence {id:?} isn't in the paged cache"))
+    }
+
+    /// Returns the number of tokens per block.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the number of free blocks.
+    pub fn num_free_blocks(&self) -> usize {
+        self.allocator.num_free()
+    }
+
+    /// Returns the number of blocks to allocate to append `num_tokens` tokens to a sequence,
+    /// including the copy of its last block when it is shared.
+    ///
+    /// Appending succeeds when this doesn't exceed the [free blocks](Self::num_free_blocks),
+    /// which lets a scheduler decide which sequences fit before running them.
+    pub fn blocks_needed(&self, id: SequenceId, num_tokens: usize) -> usize {
+        let table = self.block_table(id);
+        if num_tokens == 0 {
+            return 0;
+        }
+
+        let num_blocks = (table.len + num_tokens).div_ceil(self.block_size);
+        let copy_on_write = match table.blocks.last() {
+            Some(&last) => table.len % self.block_size != 0 && self.allocator.ref_count(last) > 1,
+            None => false,
+        };
+
+        num_blocks - table.blocks.len() + copy_on_write as usize
+    }
+
+    /// Appends the keys and values of new tokens to a sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - key: `[n_kv_heads, num_tokens, d_k]`
+    /// - value: `[n_kv_heads, num_tokens, d_k]`
+    ///
+    /// # Panics
+    ///
+    /// If there aren't enough free blocks, see [blocks_needed](Self::blocks_needed).
+    pub fn append(&mut self, id: SequenceId, key: Tensor<3>, value: Tensor<3>) {
+        let [n_kv_heads, num_tokens, d_k] = key.dims();
+        let needed = self.blocks_needed(id, num_tokens);
+        assert!(
+            needed <= self.allocator.num_free(),
+            "Appending {num_tokens} tokens requires {needed} free blocks, but the paged cache only has {}",
+            self.allocator.num_free()
+        );
+
+        let updates = self.plan_append(id, num_tokens);
+        let KvBlocks {
+            key: mut key_blocks,
+            value: mut value_blocks,
+        } = self.take_blocks();
+
+        for update in updates {
+            match update {
+                BlockUpdate::Copy { src, dst } => {
+                    key_blocks = copy_block(key_blocks, src, dst);
+                    value_blocks = copy_block(value_blocks, src, dst);
+                }
+                BlockUpdate::Write {
+                    block,
+                    offset,
+                    tokens,
+                } => {
+                    let slots = [
+                        block..block + 1,
+                        0..n_kv_heads,
+                        offset..offset + tokens.len(),
+                        0..d_k,
+                    ];
+                    let key = key
+                        .clone()
+                        .slice([0..n_kv_heads, tokens.clone(), 0..d_k])
+                        .unsqueeze();
+                    let value = value
+                        .clone()
+                        .slice([0..n_kv_heads, tokens, 0..d_k])
+                        .unsqueeze();
+
+                    key_blocks = key_blocks.slice_assign(slots.clone(), key);
+                    value_blocks = value_blocks.slice_assign(slots, value);
+                }
+            }
+        }
+
+        self.blocks = CacheState::Value(KvBlocks {
+            key: key_blocks,
+            value: value_blocks,
+        });
+    }
+
+    /// Computes the attention of the queries over the keys and values of their sequence, with
+    /// the [paged attention](burn::tensor::module::paged_attention) op.
+    ///
+    /// The queries are the last `seq_length` tokens of each sequence, which must already be
+    /// [appended](Self::append), and attend causally to the tokens of their sequence. The
+    /// [sliding window](AttentionModuleOptions::sliding_window) and the
+    /// [ALiBi biases](AttentionModuleOptions::alibi_max_bias) are applied to the positions of
+    /// each sequence. With fewer key and value heads than query heads, each key and value head
+    /// is shared by a group of query heads.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, n_heads, seq_length, d_k]`, where `batch_size` is the number of
+    ///   sequences
+    /// - output: `[batch_size, n_heads, seq_length, d_k]`
+    pub fn attention(
+        &self,
+        query: Tensor<4>,
+        sequences: &[SequenceId],
+        options: AttentionModuleOptions,
+    ) -> Tensor<4> {
+        let [batch_size, _, seq_length, _] = query.dims();
+        assert_eq!(
+            batch_size,
+            sequences.len(),
+            "The batch size of the queries must match the number of sequences"
+        );
+
+        let blocks = self.blocks();
+        let device = query.device();
+        let tables = sequences
+            .iter()
+            .map(|id| self.block_table(*id))
+            .collect::<Vec<_>>();
+
+        let seq_lengths = tables
+            .iter()
+            .zip(sequences)
+            .map(|(table, id)| {
+                assert!(
+                    table.len >= seq_length,
+                    "Sequence {id:?} has {} tokens, fewer than the {seq_length} queries",
+                    table.len
+                );
+
+                table.len as i64
+            })
+            .collect::<Vec<_>>();
+
+        // Padded with the first block, which is masked.
+        let max_blocks = tables
+            .iter()
+            .map(|table| table.blocks.len())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let block_tables = tables
+            .iter()
+            .flat_map(|table| {
+                let padding = max_blocks - table.blocks.len();
+                table
+                    .blocks
+                    .iter()
+                    .copied()
+                    .chain(core::iter::repeat_n(0, padding))
+            })
+            .map(|block| block as i64)
+            .collect::<Vec<_>>();
+
+        paged_attention(
+            query,
+            blocks.key.clone(),
+            blocks.value.clone(),
+            Tensor::from_data(
+                TensorData::new(block_tables, [batch_size, max_blocks]),
+                &device,
+            ),
+            Tensor::from_data(TensorData::new(seq_lengths, [batch_size]), &device),
+            options,
+        )
+    }
+
+    /// Updates the block table of a sequence for the appended tokens, and returns the updates
+    /// of the blocks.
+    fn plan_append(&mut self, id: SequenceId, num_tokens: usize) -> Vec<BlockUpdate> {
+        let block_size = self.block_size;
+        let allocator = &mut self.allocator;
+        let table = self
+            .tables
+            .get_mut(&id)
+            .unwrap_or_else(|| panic!("Sequence {id:?} isn't in the paged cache"));
+
+        let mut updates = Vec::new();
+        let mut written = 0;
+
+        while written < num_tokens {
+            let offset = table.len % block_size;
+            let block = if offset == 0 {
+                let block = allocator.allocate().expect("Free blocks were checked");
+                table.blocks.push(block);
+
+                block
+            } else {
+                let last = table
+                    .blocks
+                    .last_mut()
+                    .expect("A partially filled sequence has blocks");
+
+                if allocator.ref_count(*last) > 1 {
+                    // Copy-on-write: the partially filled block is shared with another sequence.
+                    let block = allocator.allocate().expect("Free blocks were checked");
+                    updates.push(BlockUpdate::Copy {
+                        src: *last,
+                        dst: block,
+                    });
+                    allocator.release(*last);
+                    *last = block;
+                }
+
+                *last
+            };
+
+            let len = (block_size - offset).min(num_tokens - written);
+            updates.push(BlockUpdate::Write {
+                block,
+                offset,
+                tokens: written..written + len,
+            });
+
+            table.len += len;
+            written += len;
+        }
+
+        updates
+    }
+
+    fn blocks(&self) -> &KvBlocks {
+        match &self.blocks {
+            CacheState::Value(blocks) => blocks,
+            CacheState::Empty => unreachable!("The blocks are only taken during an update"),
+        }
+    }
+
+    fn take_blocks(&mut self) -> KvBlocks {
+        match core::mem::replace(&mut self.blocks, CacheState::Empty) {
+            CacheState::Value(blocks) => blocks,
+            CacheState::Empty => unreachable!("The blocks are only taken during an update"),
+        }
+    }
+}
+
+fn copy_block(tensor: Tensor<4>, src: usize, dst: usize) -> Tensor<4> {
+    let block = tensor.clone().slice(src..src + 1);
+    tensor.slice_assign(dst..dst + 1, block)
+}
@@ -1,6 +1,6 @@
 use alloc::format;
 
-use burn::tensor::module::interpolate;
+use burn::tensor::module::{interpolate, interpolate_antialiased};
 
 use burn_core as burn;
 
@@ -36,6 +36,12 @@ pub struct Interpolate1dConfig {
     /// If `false`, half-pixel coordinate mapping is used instead.
     #[config(default = true)]
     pub align_corners: bool,
+
+    /// If `true`, the filter is widened when downsampling so that every input value
+    /// contributes to the output, like the antialiased resize of PIL and torchvision.
+    /// Only supported with the linear, cubic and lanczos modes.
+    #[config(default = false)]
+    pub antialias: bool,
 }
 
 /// Interpolate module for resizing 1D tensors with shape [N, C, L].
@@ -65,6 +71,9 @@ pub struct Interpolate1d {
 
     /// Whether to align corner pixels
     pub align_corners: bool,
+
+    /// Whether to antialias when downsampling
+    pub antialias: bool,
 }
 
 impl Interpolate1dConfig {
@@ -75,6 +84,7 @@ impl Interpolate1dConfig {
             scale_factor: self.scale_factor,
             mode: self.mode,
             align_corners: self.align_corners,
+            antialias: self.antialias,
         }
     }
 }
@@ -108,12 +118,13 @@ impl Interpolate1d {
         // by adding a new dimension for the interpolation axis
         let input = input.unsqueeze_dim(2);
 
-        let result = interpolate(
-            input,
-            [1, output_size],
-            InterpolateOptions::new(self.mode.clone().into())
-                .with_align_corners(self.align_corners),
-        );
+        let options = InterpolateOptions::new(self.mode.clone().into())
+            .with_align_corners(self.align_corners);
+        let result = if self.antialias {
+            interpolate_antialiased(input, [1, output_size], options)
+        } else {
+            interpolate(input, [1, output_size], options)
+        };
 
         result.squeeze_dims(&[2])
     }
@@ -1,6 +1,6 @@
 use alloc::format;
 
-use burn::tensor::module::interpolate;
+use burn::tensor::module::{interpolate, interpolate_antialiased};
 
 use burn_core as burn;
 
@@ -36,6 +36,12 @@ pub struct Interpolate2dConfig {
     /// If `false`, half-pixel coordinate mapping is used instead.
     #[config(default = true)]
     pub align_corners: bool,
+
+    /// If `true`, the filter is widened when downsampling so that every input value
+    /// contributes to the output, like the antialiased resize of PIL and torchvision.
+    /// Only supported with the linear, cubic and lanczos modes.
+    #[config(default = false)]
+    pub antialias: bool,
 }
 
 /// Interpolate module for resizing tensors with shape [N, C, H, W].
@@ -66,6 +72,9 @@ pub struct Interpolate2d {
 
     /// Whether to align corner pixels
     pub align_corners: bool,
+
+    /// Whether to antialias when downsampling
+    pub antialias: bool,
 }
 
 impl Interpolate2dConfig {
@@ -76,6 +85,7 @@ impl Interpolate2dConfig {
             scale_factor: self.scale_factor,
             mode: self.mode,
             align_corners: self.align_corners,
+            antialias: self.antialias,
         }
     }
 }
@@ -103,12 +113,13 @@ impl Interpolate2d {
     /// ```
     pub fn forward(&self, input: Tensor<4>) -> Tensor<4> {
         let output_size = calculate_output_size(input.dims(), self.output_size, self.scale_factor);
-        interpolate(
-            input,
-            output_size,
-            InterpolateOptions::new(self.mode.clone().into())
-                .with_align_corners(self.align_corners),
-        )
+        let options = InterpolateOptions::new(self.mode.clone().into())
+            .with_align_corners(self.align_corners);
+        if self.antialias {
+            interpolate_antialiased(input, output_size, options)
+        } else {
+            interpolate(input, output_size, options)
+        }
     }
 }
 
@@ -0,0 +1,252 @@
+use alloc::format;
+
+use burn::tensor::module::{interpolate3d, interpolate3d_antialiased};
+
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use burn::tensor::Tensor;
+use burn::tensor::ops::InterpolateOptions;
+
+use super::InterpolateMode;
+
+/// Configuration for the 3D interpolation module.
+///
+/// This struct defines the configuration options for the 3D interpolation operation.
+/// It allows specifying the output size, scale factor, and interpolation mode.
+#[derive(Config, Debug)]
+pub struct Interpolate3dConfig {
+    /// Output size of the interpolated tensor.
+    /// If specified, this takes precedence over `scale_factor`.
+    #[config(default = "None")]
+    pub output_size: Option<[usize; 3]>,
+
+    /// Scale factor for resizing the input tensor.
+    /// This is used when `output_size` is not specified.
+    #[config(default = "None")]
+    pub scale_factor: Option<[f32; 3]>,
+
+    /// Interpolation mode to use for resizing.
+    /// Determines how the output values are calculated, [Linear](InterpolateMode::Linear)
+    /// is a trilinear interpolation.
+    #[config(default = "InterpolateMode::Nearest")]
+    pub mode: InterpolateMode,
+
+    /// If `true`, the input and output tensors are aligned by their corner pixels.
+    /// If `false`, half-pixel coordinate mapping is used instead.
+    #[config(default = true)]
+    pub align_corners: bool,
+
+    /// If `true`, the filter is widened when downsampling so that every input value
+    /// contributes to the output, like the antialiased resize of PIL and torchvision.
+    /// Only supported with the linear, cubic and lanczos modes.
+    #[config(default = false)]
+    pub antialias: bool,
+}
+
+/// Interpolate module for resizing volumetric tensors with shape [N, C, D, H, W].
+///
+/// This struct represents an interpolation module that can resize tensors
+/// using various interpolation methods. It provides flexibility in specifying
+/// either an output size or a scale factor for resizing, along with options
+/// for the interpolation mode.
+///
+/// The module can be used to upsample or downsample tensors, preserving the
+/// number of channels and batch size while adjusting the depth, height and width
+/// dimensions.
+///
+/// The module can be created using the [Interpolate3dConfig] struct and the
+/// `init` method, which returns an instance of the [Interpolate3d] struct.
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Interpolate3d {
+    /// Output size of the interpolated tensor
+    pub output_size: Option<[usize; 3]>,
+
+    /// Scale factor for resizing the input tensor
+    pub scale_factor: Option<[f32; 3]>,
+
+    /// Interpolation mode used for resizing
+    #[module(skip)]
+    pub mode: InterpolateMode,
+
+    /// Whether to align corner pixels
+    pub align_corners: bool,
+
+    /// Whether to antialias when downsampling
+    pub antialias: bool,
+}
+
+impl Interpolate3dConfig {
+    /// Initialize the interpolation module
+    pub fn init(self) -> Interpolate3d {
+        Interpolate3d {
+            output_size: self.output_size,
+            scale_factor: self.scale_factor,
+            mode: self.mode,
+            align_corners: self.align_corners,
+            antialias: self.antialias,
+        }
+    }
+}
+impl Interpolate3d {
+    /// Performs the forward pass of the interpolation module
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor with shape [N, C, D, H, W]
+    ///
+    /// # Returns
+    ///
+    /// Resized tensor with shape [N, C, D', H', W'], where D', H' and W' are determined by
+    /// the output_size or scale_factor specified in the module configuration
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let input = Tensor::<5>::random([1, 3, 16, 64, 64], Distribution::Uniform(0.0, 1.0), &device);
+    /// let interpolate = Interpolate3dConfig::new()
+    ///     .with_output_size(Some([32, 128, 128]))
+    ///     .with_mode(InterpolateMode::Linear)
+    ///     .init();
+    /// let output = interpolate.forward(input);
+    /// assert_eq!(output.dims(), [1, 3, 32, 128, 128]);
+    /// ```
+    pub fn forward(&self, input: Tensor<5>) -> Tensor<5> {
+        let output_size = calculate_output_size(input.dims(), self.output_size, self.scale_factor);
+        let options = InterpolateOptions::new(self.mode.clone().into())
+            .with_align_corners(self.align_corners);
+        if self.antialias {
+            interpolate3d_antialiased(input, output_size, options)
+        } else {
+            interpolate3d(input, output_size, options)
+        }
+    }
+}
+
+/// Calculates the output size for tensor interpolation.
+///
+/// # Arguments
+///
+/// * `input_dims` - The dimensions of the input tensor [N, C, D, H, W].
+/// * `output_size` - Optional desired output size [D', H', W'].
+/// * `scale_factor` - Optional scale factor for depth, height and width.
+///
+/// # Returns
+///
+/// An array [D', H', W'] representing the calculated output size.
+///
+/// # Panics
+///
+/// Panics if neither `output_size` nor `scale_factor` is provided,
+/// or if the scale factor results in dimensions exceeding usize::MAX.
+fn calculate_output_size(
+    input_dims: [usize; 5],
+    output_size: Option<[usize; 3]>,
+    scale_factor: Option<[f32; 3]>,
+) -> [usize; 3] {
+    match (output_size, scale_factor) {
+        (Some(output_size), None) => output_size,
+        (None, Some(scale_factor)) => {
+            let [_, _, d, h, w] = input_dims;
+            let names = ["depth", "height", "width"];
+
+            let mut size = [0; 3];
+            for (i, dim) in [d, h, w].into_iter().enumerate() {
+                let new_dim = (dim as f64) * (scale_factor[i] as f64);
+
+                if new_dim > usize::MAX as f64 {
+                    panic!("Scale factor for {} is too large", names[i]);
+                }
+
+                size[i] = new_dim as usize;
+            }
+
+            size
+        }
+        _ => panic!("Either output_size or scale_factor must be provided"),
+    }
+}
+
+impl ModuleDisplay for Interpolate3d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add_debug_attribute("mode", &self.mode)
+            .add("output_size", &format!("{:?}", self.output_size))
+            .add("scale_factor", &self.scale_factor)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::tensor::Distribution;
+
+    use super::*;
+
+    #[test]
+    fn test_calculate_output_size() {
+        let input_dims = [1, 1, 2, 4, 4];
+
+        let output_size = calculate_output_size(input_dims, Some([1, 2, 2]), None);
+        assert_eq!(output_size, [1, 2, 2]);
+
+        let output_size = calculate_output_size(input_dims, None, Some([2.0, 0.5, 1.5]));
+        assert_eq!(output_size, [4, 2, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Either output_size or scale_factor must be provided")]
+    fn test_missing_params() {
+        calculate_output_size([1, 1, 2, 4, 4], None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale factor for depth is too large")]
+    fn test_infinite_depth() {
+        calculate_output_size([1, 1, usize::MAX - 1, 4, 4], None, Some([2.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_module() {
+        let input = Tensor::<5>::random(
+            [2, 3, 4, 4, 4],
+            Distribution::Uniform(0.0, 1.0),
+            &Default::default(),
+        );
+
+        let config = Interpolate3dConfig::new()
+            .with_output_size(Some([8, 6, 2]))
+            .with_mode(InterpolateMode::Linear);
+        let interpolate = config.init();
+        let output = interpolate.forward(input.clone());
+        assert_eq!(output.dims(), [2, 3, 8, 6, 2]);
+
+        let config = Interpolate3dConfig::new()
+            .with_scale_factor(Some([0.5, 0.5, 0.5]))
+            .with_mode(InterpolateMode::Linear)
+            .with_antialias(true);
+        let interpolate = config.init();
+        let output = interpolate.forward(input);
+        assert_eq!(output.dims(), [2, 3, 2, 2, 2]);
+    }
+
+    #[test]
+    fn display() {
+        let config = Interpolate3dConfig::new().with_output_size(Some([4, 20, 20]));
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "Interpolate3d {mode: Nearest, output_size: Some([4, 20, 20]), \
+            scale_factor: None}"
+        );
+    }
+}
@@ -10,6 +10,9 @@ pub mod conv;
 /// Pooling module
 pub mod pool;
 
+/// State space model module
+pub mod ssm;
+
 /// Transformer module
 pub mod transformer;
 
@@ -0,0 +1,87 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::Module;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::Tensor;
+
+use burn::tensor::module::adaptive_max_pool3d;
+
+/// Configuration to create a [3D adaptive max pooling](AdaptiveMaxPool3d) layer using the [init function](AdaptiveMaxPool3dConfig::init).
+#[derive(Config, Debug)]
+pub struct AdaptiveMaxPool3dConfig {
+    /// The size of the output.
+    pub output_size: [usize; 3],
+}
+
+/// Applies a 3D adaptive max pooling over input tensors.
+///
+/// Should be created with [AdaptiveMaxPool3dConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct AdaptiveMaxPool3d {
+    /// The size of the output.
+    pub output_size: [usize; 3],
+}
+
+impl ModuleDisplay for AdaptiveMaxPool3d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let output_size = alloc::format!("{:?}", self.output_size);
+
+        content.add("output_size", &output_size).optional()
+    }
+}
+
+impl AdaptiveMaxPool3dConfig {
+    /// Initialize a new [adaptive max pool 3d](AdaptiveMaxPool3d) module.
+    pub fn init(&self) -> AdaptiveMaxPool3d {
+        AdaptiveMaxPool3d {
+            output_size: self.output_size,
+        }
+    }
+}
+
+impl AdaptiveMaxPool3d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [adaptive_max_pool3d](burn::tensor::module::adaptive_max_pool3d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, depth_in, height_in, width_in]`
+    /// - output: `[batch_size, channels, depth_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<5>) -> Tensor<5> {
+        adaptive_max_pool3d(input, self.output_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let config = AdaptiveMaxPool3dConfig::new([3, 3, 3]);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "AdaptiveMaxPool3d {output_size: [3, 3, 3]}"
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let device = Default::default();
+        let layer = AdaptiveMaxPool3dConfig::new([2, 2, 2]).init();
+        let input = Tensor::<5>::ones([1, 2, 4, 4, 4], &device);
+        let output = layer.forward(input);
+        assert_eq!(output.dims(), [1, 2, 2, 2, 2]);
+    }
+}
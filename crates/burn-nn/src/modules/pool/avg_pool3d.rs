@@ -0,0 +1,182 @@
+use burn_core as burn;
+
+use crate::PaddingConfig3d;
+use burn::config::Config;
+use burn::module::Module;
+use burn::module::{Content, DisplaySettings, ModuleDisplay};
+use burn::tensor::Tensor;
+
+use burn::tensor::module::avg_pool3d;
+
+/// Configuration to create a [3D avg pooling](AvgPool3d) layer using the [init function](AvgPool3dConfig::init).
+#[derive(Config, Debug)]
+pub struct AvgPool3dConfig {
+    /// The size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// The strides.
+    #[config(default = "kernel_size")]
+    pub strides: [usize; 3],
+    /// The padding configuration.
+    ///
+    /// Only symmetric padding is supported, `Same` padding requires odd kernel sizes.
+    #[config(default = "PaddingConfig3d::Valid")]
+    pub padding: PaddingConfig3d,
+    /// If the padding is counted in the denominator when computing the average.
+    #[config(default = "true")]
+    pub count_include_pad: bool,
+    /// If true, use ceiling instead of floor for output size calculation.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
+}
+
+/// Applies a 3D avg pooling over input tensors.
+///
+/// Should be created with [AvgPool3dConfig](AvgPool3dConfig).
+///
+/// # Remarks
+///
+/// By default, the zero-padding values are counted in the denominator of the average, which is
+/// equivalent to `torch.nn.AvgPool3d` with `count_include_pad=True`.
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct AvgPool3d {
+    /// Stride of the pooling.
+    pub stride: [usize; 3],
+    /// Size of the kernel.
+    pub kernel_size: [usize; 3],
+    /// Padding configuration.
+    #[module(skip)]
+    pub padding: PaddingConfig3d,
+    /// If the padding is counted in the denominator when computing the average.
+    pub count_include_pad: bool,
+    /// If true, use ceiling instead of floor for output size calculation.
+    pub ceil_mode: bool,
+}
+
+impl ModuleDisplay for AvgPool3d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("kernel_size", &alloc::format!("{:?}", self.kernel_size))
+            .add("stride", &alloc::format!("{:?}", self.stride))
+            .add_debug_attribute("padding", &self.padding)
+            .add("count_include_pad", &self.count_include_pad)
+            .add("ceil_mode", &self.ceil_mode)
+            .optional()
+    }
+}
+
+impl AvgPool3dConfig {
+    /// Initialize a new [avg pool 3d](AvgPool3d) module.
+    pub fn init(&self) -> AvgPool3d {
+        AvgPool3d {
+            stride: self.strides,
+            kernel_size: self.kernel_size,
+            padding: self.padding.clone(),
+            count_include_pad: self.count_include_pad,
+            ceil_mode: self.ceil_mode,
+        }
+    }
+}
+
+impl AvgPool3d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [avg_pool3d](burn::tensor::module::avg_pool3d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, depth_in, height_in, width_in]`
+    /// - output: `[batch_size, channels, depth_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<5>) -> Tensor<5> {
+        let [_batch_size, _channels_in, depth_in, height_in, width_in] = input.dims();
+        let padding = self.padding.calculate_padding_3d(
+            depth_in,
+            height_in,
+            width_in,
+            &self.kernel_size,
+            &self.stride,
+        );
+
+        avg_pool3d(
+            input,
+            self.kernel_size,
+            self.stride,
+            padding,
+            self.count_include_pad,
+            self.ceil_mode,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{TensorData, Tolerance};
+    use rstest::rstest;
+
+    #[test]
+    fn same_padding_preserves_dims() {
+        let device = Default::default();
+        let config = AvgPool3dConfig::new([3, 3, 3])
+            .with_strides([1, 1, 1])
+            .with_padding(PaddingConfig3d::Same);
+        let pool = config.init();
+
+        let input = Tensor::<5>::ones([1, 2, 4, 5, 6], &device);
+        let output = pool.forward(input);
+
+        assert_eq!(output.dims(), [1, 2, 4, 5, 6]);
+    }
+
+    #[test]
+    fn display() {
+        let config = AvgPool3dConfig::new([3, 3, 3]);
+
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{layer}"),
+            "AvgPool3d {kernel_size: [3, 3, 3], stride: [3, 3, 3], padding: Valid, count_include_pad: true, ceil_mode: false}"
+        );
+    }
+
+    #[rstest]
+    #[case([2, 2, 2])]
+    #[case([1, 2, 3])]
+    fn default_strides_match_kernel_size(#[case] kernel_size: [usize; 3]) {
+        let config = AvgPool3dConfig::new(kernel_size);
+
+        assert_eq!(
+            config.strides, kernel_size,
+            "Expected strides ({:?}) to match kernel size ({:?}) in default AvgPool3dConfig::new constructor",
+            config.strides, config.kernel_size
+        );
+    }
+
+    #[test]
+    fn count_include_pad_forward() {
+        let device = Default::default();
+        let input = Tensor::<5>::ones([1, 1, 2, 2, 2], &device);
+        let config =
+            AvgPool3dConfig::new([2, 2, 2]).with_padding(PaddingConfig3d::Explicit(1, 1, 1));
+
+        // Each window of the corners covers a single element of the input.
+        let output = config.clone().init().forward(input.clone());
+        output.into_data().assert_approx_eq::<f32>(
+            &TensorData::full([1, 1, 2, 2, 2], 0.125f32),
+            Tolerance::default(),
+        );
+
+        let output = config.with_count_include_pad(false).init().forward(input);
+        output.into_data().assert_approx_eq::<f32>(
+            &TensorData::full([1, 1, 2, 2, 2], 1.0f32),
+            Tolerance::default(),
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_core as burn;
+
+use crate::conv::{Conv1d, Conv1dConfig};
+use crate::{Linear, LinearConfig};
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Initializer, Module, ModuleDisplay, Param};
+use burn::tensor::activation::{silu, softplus};
+use burn::tensor::{Device, Distribution, Tensor, TensorData, s};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+/// The smallest initial time step, so that the initial `dt_proj` bias stays finite.
+const DT_INIT_FLOOR: f64 = 1e-4;
+
+/// Configuration to create a [Mamba](Mamba) block using the [init function](MambaConfig::init).
+#[derive(Config, Debug)]
+pub struct MambaConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the state of each channel. Default: 16
+    #[config(default = 16)]
+    pub d_state: usize,
+    /// The size of the kernel of the causal convolution. Default: 4
+    #[config(default = 4)]
+    pub d_conv: usize,
+    /// The expansion factor of the inner channels, `d_inner = expand * d_model`. Default: 2
+    #[config(default = 2)]
+    pub expand: usize,
+    /// The rank of the time step projection. Default: `None`, where it is `ceil(d_model / 16)`
+    #[config(default = "None")]
+    pub dt_rank: Option<usize>,
+    /// The smallest initial time step. Default: 0.001
+    #[config(default = 0.001)]
+    pub dt_min: f64,
+    /// The largest initial time step. Default: 0.1
+    #[config(default = 0.1)]
+    pub dt_max: f64,
+    /// The type of function used to initialize the projections
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The [Mamba](https://arxiv.org/abs/2312.00752) block, a selective state space model.
+///
+/// The input is projected to the inner channels `x` and the gate `z`. `x` goes through a causal
+/// depthwise convolution and the selective scan, whose time step `dt` and projections `B` and `C`
+/// depend on the input:
+///
+/// - `h[t] = exp(dt[t] * A) * h[t - 1] + dt[t] * B[t] * x[t]`
+/// - `y[t] = C[t] · h[t] + D * x[t]`
+///
+/// and `y * silu(z)` is projected back to `d_model`. The recurrence runs along the sequence with
+/// [linear_recurrence](Tensor::linear_recurrence), on the `[batch_size, seq_length, d_inner,
+/// d_state]` discretized states.
+///
+/// The block is recurrent: [forward](Mamba::forward) takes and returns a [MambaState], so a
+/// sequence can be processed in chunks, and [step](Mamba::step) processes one token at a time
+/// for inference.
+///
+/// # Params
+///
+/// - `in_proj`: [`Linear`] layer without bias from `d_model` to `2 * d_inner` (`x` and `z`).
+/// - `conv1d`: depthwise [`Conv1d`] layer with `d_inner` channels and a kernel of `d_conv`.
+/// - `x_proj`: [`Linear`] layer without bias from `d_inner` to `dt_rank + 2 * d_state`.
+/// - `dt_proj`: [`Linear`] layer from `dt_rank` to `d_inner`.
+/// - `a_log`: the log of `-A`, of shape `[d_inner, d_state]`.
+/// - `d`: the skip connection `D`, of shape `[d_inner]`.
+/// - `out_proj`: [`Linear`] layer without bias from `d_inner` to `d_model`.
+///
+/// The parameters are named like the reference checkpoints, which name `a_log` and `d` as
+/// `A_log` and `D`.
+///
+/// Should be created with [MambaConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Mamba {
+    /// Linear layer with `d_model` input features and `2 * d_inner` output features.
+    pub in_proj: Linear,
+    /// Depthwise causal convolution over the inner channels.
+    pub conv1d: Conv1d,
+    /// Linear layer with `d_inner` input features and `dt_rank + 2 * d_state` output features.
+    pub x_proj: Linear,
+    /// Linear layer with `dt_rank` input features and `d_inner` output features.
+    pub dt_proj: Linear,
+    /// The log of `-A`, of shape `[d_inner, d_state]`.
+    pub a_log: Param<Tensor<2>>,
+    /// The skip connection, of shape `[d_inner]`.
+    pub d: Param<Tensor<1>>,
+    /// Linear layer with `d_inner` input features and `d_model` output features.
+    pub out_proj: Linear,
+}
+
+impl ModuleDisplay for Mamba {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_model, _] = self.in_proj.weight.shape().dims();
+        let [d_inner, d_state] = self.a_log.shape().dims();
+
+        content
+            .add("d_model", &d_model)
+            .add("d_inner", &d_inner)
+            .add("d_state", &d_state)
+            .optional()
+    }
+}
+
+/// The recurrent state of a [Mamba](Mamba) block.
+#[derive(Debug, Clone)]
+pub struct MambaState {
+    /// The last `d_conv - 1` inputs of the convolution `[batch_size, d_inner, d_conv - 1]`.
+    pub conv: Tensor<3>,
+    /// The state of the selective scan `[batch_size, d_inner, d_state]`.
+    pub ssm: Tensor<3>,
+}
+
+impl MambaConfig {
+    /// Initialize a new [Mamba](Mamba) block.
+    pub fn init(&self, device: &Device) -> Mamba {
+        assert!(
+            self.d_conv > 1,
+            "The kernel size of the convolution must be at least 2, got {}",
+            self.d_conv
+        );
+
+        let d_inner = self.expand * self.d_model;
+        let dt_rank = self.dt_rank.unwrap_or(self.d_model.div_ceil(16));
+        let linear = |d_input, d_output| {
+            LinearConfig::new(d_input, d_output)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        let dt_std = (dt_rank as f64).powf(-0.5);
+        let mut dt_proj = LinearConfig::new(dt_rank, d_inner)
+            .with_initializer(Initializer::Uniform {
+                min: -dt_std,
+                max: dt_std,
+            })
+            .init(device);
+        dt_proj.bias = Some(Param::from_tensor(self.init_dt_bias(d_inner, device)));
+
+        // S4D-real initialization: `A = -[1, 2, ..., d_state]` for every channel.
+        let a = (1..=self.d_state)
+            .cycle()
+            .take(d_inner * self.d_state)
+            .map(|n| n as f32)
+            .collect::<Vec<_>>();
+        let a = Tensor::from_data(TensorData::new(a, [d_inner, self.d_state]), device);
+
+        Mamba {
+            in_proj: linear(self.d_model, 2 * d_inner),
+            conv1d: Conv1dConfig::new(d_inner, d_inner, self.d_conv)
+                .with_groups(d_inner)
+                .init(device),
+            x_proj: linear(d_inner, dt_rank + 2 * self.d_state),
+            dt_proj,
+            a_log: Param::from_tensor(a.log()),
+            d: Param::from_tensor(Tensor::ones([d_inner], device)),
+            out_proj: linear(d_inner, self.d_model),
+        }
+    }
+
+    /// The bias of `dt_proj`, such that the initial time steps `softplus(bias)` are log-uniform
+    /// between `dt_min` and `dt_max`.
+    fn init_dt_bias(&self, d_inner: usize, device: &Device) -> Tensor<1> {
+        let (log_min, log_max) = (self.dt_min.ln(), self.dt_max.ln());
+        let dt = Tensor::<1>::random([d_inner], Distribution::Uniform(log_min, log_max), device)
+            .exp()
+            .clamp_min(DT_INIT_FLOOR);
+
+        // Inverse of softplus: `dt + log(1 - exp(-dt))`.
+        dt.clone() + dt.neg().exp().neg().add_scalar(1.0).log()
+    }
+}
+
+impl Mamba {
+    /// Creates the zero state of a batch.
+    pub fn initial_state(&self, batch_size: usize, device: &Device) -> MambaState {
+        let [d_inner, d_state] = self.a_log.shape().dims();
+        let d_conv = self.conv1d.kernel_size;
+
+        MambaState {
+            conv: Tensor::zeros([batch_size, d_inner, d_conv - 1], device),
+            ssm: Tensor::zeros([batch_size, d_inner, d_state], device),
+        }
+    }
+
+    /// Applies the forward pass on the input tensor, starting from the given state or from
+    /// the [zero state](Mamba::initial_state).
+    ///
+    /// See [Mamba](Mamba) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    ///
+    /// # Returns
+    ///
+    /// The output and the state after the last token of the sequence.
+    pub fn forward(&self, input: Tensor<3>, state: Option<MambaState>) -> (Tensor<3>, MambaState) {
+        let [batch_size, seq_length, _] = input.dims();
+        let [d_inner, d_state] = self.a_log.shape().dims();
+        let [dt_rank, _] = self.dt_proj.weight.shape().dims();
+        let state = state.unwrap_or_else(|| self.initial_state(batch_size, &input.device()));
+
+        let xz = self.in_proj.forward(input);
+        let x = xz.clone().narrow(2, 0, d_inner);
+        let z = xz.narrow(2, d_inner, d_inner);
+
+        // Causal convolution: the previous inputs replace the left padding.
+        let x = Tensor::cat(vec![state.conv, x.swap_dims(1, 2)], 2);
+        let conv_length = x.dims()[2];
+        let conv_state = x.clone().narrow(2, seq_length, conv_length - seq_length);
+        let x = silu(self.conv1d.forward(x)).swap_dims(1, 2);
+
+        // Input-dependent time step and projections.
+        let x_dbl = self.x_proj.forward(x.clone());
+        let dt = x_dbl.clone().narrow(2, 0, dt_rank);
+        let b = x_dbl.clone().narrow(2, dt_rank, d_state);
+        let c = x_dbl.narrow(2, dt_rank + d_state, d_state);
+        let dt = softplus(self.dt_proj.forward(dt), 1.0);
+
+        // Discretized states `[batch_size, seq_length, d_inner, d_state]`.
+        let a = self.a_log.val().exp().neg().unsqueeze::<4>();
+        let decay = (dt.clone().unsqueeze_dim::<4>(3) * a).exp();
+        let inputs = (dt * x.clone()).unsqueeze_dim::<4>(3) * b.unsqueeze_dim::<4>(2);
+
+        // The initial state is folded into the first input.
+        let first = inputs.clone().slice(s![.., 0..1])
+            + decay.clone().slice(s![.., 0..1]) * state.ssm.unsqueeze_dim::<4>(1);
+        let inputs = inputs.slice_assign(s![.., 0..1], first);
+        let h = decay.linear_recurrence(inputs, 1);
+
+        let ssm_state = h.clone().narrow(1, seq_length - 1, 1).squeeze_dim(1);
+        let y = (h * c.unsqueeze_dim::<4>(2)).sum_dim(3).squeeze_dim::<3>(3)
+            + x * self.d.val().unsqueeze::<3>();
+        let output = self.out_proj.forward(y * silu(z));
+
+        (
+            output,
+            MambaState {
+                conv: conv_state,
+                ssm: ssm_state,
+            },
+        )
+    }
+
+    /// Applies the block on a single token, for autoregressive inference.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, d_model]`
+    /// - output: `[batch_size, d_model]`
+    ///
+    /// # Returns
+    ///
+    /// The output and the state after the token.
+    pub fn step(&self, input: Tensor<2>, state: Option<MambaState>) -> (Tensor<2>, MambaState) {
+        let (output, state) = self.forward(input.unsqueeze_dim(1), state);
+
+        (output.squeeze_dim(1), state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::Tolerance;
+
+    #[test]
+    fn test_mamba_shapes() {
+        let [batch_size, seq_length, d_model] = [2, 5, 8];
+        let device = Default::default();
+        let mamba = MambaConfig::new(d_model).with_d_state(4).init(&device);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let (output, state) = mamba.forward(input, None);
+
+        assert_eq!(output.dims(), [batch_size, seq_length, d_model]);
+        assert_eq!(state.conv.dims(), [batch_size, 2 * d_model, 3]);
+        assert_eq!(state.ssm.dims(), [batch_size, 2 * d_model, 4]);
+    }
+
+    #[test]
+    fn test_mamba_init() {
+        let device = Default::default();
+        let mamba = MambaConfig::new(8).with_d_state(3).init(&device);
+
+        mamba.a_log.val().exp().into_data().assert_approx_eq::<f32>(
+            &Tensor::<1>::from_data([1.0, 2.0, 3.0], &device)
+                .unsqueeze::<2>()
+                .repeat_dim(0, 16)
+                .into_data(),
+            Tolerance::default(),
+        );
+
+        // The initial time steps are within [dt_min, dt_max].
+        let dt = softplus(mamba.dt_proj.bias.unwrap().val(), 1.0).into_data();
+        for dt in dt.iter::<f32>() {
+            assert!((0.00099..=0.101).contains(&dt), "dt = {dt}");
+        }
+    }
+
+    #[test]
+    fn test_mamba_step_should_match_forward() {
+        let [batch_size, seq_length, d_model] = [2, 6, 4];
+        let device = Default::default();
+        let mamba = MambaConfig::new(d_model).with_d_state(4).init(&device);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let (expected, expected_state) = mamba.forward(input.clone(), None);
+
+        let mut state = None;
+        let mut outputs = Vec::new();
+        for t in 0..seq_length {
+            let token = input.clone().narrow(1, t, 1).squeeze_dim(1);
+            let (output, next_state) = mamba.step(token, state);
+            outputs.push(output);
+            state = Some(next_state);
+        }
+        let state = state.unwrap();
+
+        Tensor::stack::<3>(outputs, 1)
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+        state
+            .conv
+            .into_data()
+            .assert_approx_eq::<f32>(&expected_state.conv.into_data(), Tolerance::default());
+        state
+            .ssm
+            .into_data()
+            .assert_approx_eq::<f32>(&expected_state.ssm.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_mamba_chunked_forward_should_match_forward() {
+        let [batch_size, seq_length, d_model] = [2, 7, 4];
+        let device = Default::default();
+        let mamba = MambaConfig::new(d_model).init(&device);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let (expected, _) = mamba.forward(input.clone(), None);
+
+        // Chunks shorter than the convolution kernel carry the older inputs in the state.
+        let (first, state) = mamba.forward(input.clone().narrow(1, 0, 2), None);
+        let (second, state) = mamba.forward(input.clone().narrow(1, 2, 1), Some(state));
+        let (third, _) = mamba.forward(input.narrow(1, 3, 4), Some(state));
+
+        Tensor::cat(vec![first, second, third], 1)
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = MambaConfig::new(4);
+        let mamba = config.init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{mamba}"),
+            "Mamba {d_model: 4, d_inner: 8, d_state: 16, params: 552}"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod mamba;
+
+pub use mamba::*;
@@ -0,0 +1,617 @@
+use alloc::vec;
+use burn_core as burn;
+
+use crate::{Linear, LinearConfig};
+use burn::config::Config;
+use burn::module::{Content, DisplaySettings, Initializer, Module, ModuleDisplay, Param};
+use burn::tensor::activation::{silu, softmax};
+use burn::tensor::{Device, IndexingUpdateOp, Int, Tensor};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float as _;
+
+/// Configuration to create a [Mixture of Experts](MixtureOfExperts) layer using the [init function](MixtureOfExpertsConfig::init).
+#[derive(Config, Debug)]
+pub struct MixtureOfExpertsConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the hidden features of each expert.
+    pub d_ff: usize,
+    /// The number of routed experts.
+    pub n_experts: usize,
+    /// The number of experts each token is routed to. Default: 2
+    #[config(default = 2)]
+    pub top_k: usize,
+    /// The number of tokens each expert can receive, relative to an even share of the routed
+    /// tokens: `ceil(capacity_factor * n_tokens * top_k / n_experts)`. The tokens routed to a
+    /// full expert are dropped from it. Default: `None`, where no token is dropped
+    #[config(default = "None")]
+    pub capacity_factor: Option<f64>,
+    /// Renormalize the gates of the top-k experts of each token to sum to one, like Mixtral.
+    /// Otherwise the gates are the router probabilities, like DeepSeek-V2. Default: true
+    #[config(default = true)]
+    pub normalize_gates: bool,
+    /// The number of shared experts applied to every token, fused into one expert with
+    /// `n_shared_experts * d_ff` hidden features like DeepSeek. Default: 0
+    #[config(default = 0)]
+    pub n_shared_experts: usize,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A sparse Mixture of Experts feed-forward layer, as used by
+/// [Mixtral](https://arxiv.org/abs/2401.04088) and [DeepSeek](https://arxiv.org/abs/2405.04434).
+///
+/// A learned router picks the `top_k` experts of each token, and the output is the sum of their
+/// outputs weighted by their gates. The tokens are gathered into a buffer of
+/// [capacity](MixtureOfExperts::capacity) rows per expert, the experts run together as batched
+/// matmuls over their stacked weights, and the gated outputs are scattered back with
+/// [select_assign](Tensor::select_assign). With a capacity factor, the routing stays on the
+/// device and the tokens routed to a full expert are dropped, their first choices being served
+/// before their second choices. Without one, the capacity is the number of tokens routed to the
+/// busiest expert, read back from the device, so no token is dropped.
+///
+/// # Params
+///
+/// - `gate`: the router, a [`Linear`] layer without bias from `d_model` to `n_experts` logits.
+/// - `experts`: the routed [experts](MoeExperts), with their weights stacked.
+/// - `shared_experts`: the [expert](MoeExpert) applied to every token, if any.
+///
+/// The router and the shared experts are named like the DeepSeek checkpoints, e.g.
+/// `gate.weight` and `shared_experts.down_proj.weight`, so they can be loaded from PyTorch with
+/// the `PyTorchToBurnAdapter` of `burn-store`. Checkpoints with one set of weights per expert,
+/// e.g. `experts.0.gate_proj.weight`, can be loaded into a `Vec<MoeExpert>` and stacked with
+/// [MoeExperts::from_experts]. Mixtral checkpoints name the projections of the experts `w1`
+/// (gate), `w3` (up) and `w2` (down), which can be remapped to `gate_proj`, `up_proj` and
+/// `down_proj`.
+///
+/// Should be created with [MixtureOfExpertsConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct MixtureOfExperts {
+    /// The router, with `d_model` input features and `n_experts` output features.
+    pub gate: Linear,
+    /// The routed experts.
+    pub experts: MoeExperts,
+    /// The shared experts, applied to every token.
+    pub shared_experts: Option<MoeExpert>,
+    /// The number of experts each token is routed to.
+    pub top_k: usize,
+    /// The capacity factor of the experts.
+    pub capacity_factor: Option<f64>,
+    /// Renormalize the gates of the top-k experts of each token.
+    pub normalize_gates: bool,
+}
+
+impl ModuleDisplay for MixtureOfExperts {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_model, n_experts] = self.gate.weight.shape().dims();
+
+        content
+            .add("d_model", &d_model)
+            .add("n_experts", &n_experts)
+            .add("top_k", &self.top_k)
+            .optional()
+    }
+}
+
+/// A SwiGLU feed-forward expert of a [Mixture of Experts](MixtureOfExperts) layer.
+///
+/// `Expert(x) = down_proj(silu(gate_proj(x)) * up_proj(x))`
+#[derive(Module, Debug)]
+pub struct MoeExpert {
+    /// Linear layer with `d_model` input features and `d_ff` output features, activated.
+    pub gate_proj: Linear,
+    /// Linear layer with `d_model` input features and `d_ff` output features.
+    pub up_proj: Linear,
+    /// Linear layer with `d_ff` input features and `d_model` output features.
+    pub down_proj: Linear,
+}
+
+impl MoeExpert {
+    /// Applies the expert to the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_model]`
+    /// - output: `[..., d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<D>) -> Tensor<D> {
+        let x = silu(self.gate_proj.forward(input.clone())) * self.up_proj.forward(input);
+
+        self.down_proj.forward(x)
+    }
+}
+
+/// The routed experts of a [Mixture of Experts](MixtureOfExperts) layer, with the weights of
+/// every expert stacked so that they run together as batched matmuls.
+///
+/// Expert `e` is the [SwiGLU expert](MoeExpert) with the weights `gate_proj[e]`, `up_proj[e]`
+/// and `down_proj[e]`.
+#[derive(Module, Debug)]
+pub struct MoeExperts {
+    /// The gate projections `[n_experts, d_model, d_ff]`, activated.
+    pub gate_proj: Param<Tensor<3>>,
+    /// The up projections `[n_experts, d_model, d_ff]`.
+    pub up_proj: Param<Tensor<3>>,
+    /// The down projections `[n_experts, d_ff, d_model]`.
+    pub down_proj: Param<Tensor<3>>,
+}
+
+impl MoeExperts {
+    /// Stacks the weights of the experts, e.g. loaded from a checkpoint with one set of weights
+    /// per expert.
+    ///
+    /// # Panics
+    ///
+    /// If there is no expert, or if the projections of an expert have a bias.
+    pub fn from_experts(experts: &[MoeExpert]) -> Self {
+        assert!(!experts.is_empty(), "At least one expert is required");
+        let stack = |linear: fn(&MoeExpert) -> &Linear| {
+            let weights = experts
+                .iter()
+                .map(|expert| {
+                    let linear = linear(expert);
+                    assert!(
+                        linear.bias.is_none(),
+                        "The projections of the experts can't have a bias"
+                    );
+                    linear.weight.val()
+                })
+                .collect();
+            Param::from_tensor(Tensor::stack::<3>(weights, 0))
+        };
+
+        Self {
+            gate_proj: stack(|expert| &expert.gate_proj),
+            up_proj: stack(|expert| &expert.up_proj),
+            down_proj: stack(|expert| &expert.down_proj),
+        }
+    }
+
+    /// Returns the number of experts.
+    pub fn n_experts(&self) -> usize {
+        self.gate_proj.dims()[0]
+    }
+
+    /// Returns the expert `index` as a standalone [expert](MoeExpert).
+    pub fn expert(&self, index: usize) -> MoeExpert {
+        let linear = |weight: &Param<Tensor<3>>| {
+            let [_, d_input, d_output] = weight.dims();
+            Linear {
+                weight: Param::from_tensor(
+                    weight
+                        .val()
+                        .slice([index..index + 1])
+                        .reshape([d_input, d_output]),
+                ),
+                bias: None,
+            }
+        };
+
+        MoeExpert {
+            gate_proj: linear(&self.gate_proj),
+            up_proj: linear(&self.up_proj),
+            down_proj: linear(&self.down_proj),
+        }
+    }
+
+    /// Applies every expert to its own tokens.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[n_experts, n_tokens, d_model]`
+    /// - output: `[n_experts, n_tokens, d_model]`
+    pub fn forward(&self, input: Tensor<3>) -> Tensor<3> {
+        let x = silu(input.clone().matmul(self.gate_proj.val())) * input.matmul(self.up_proj.val());
+
+        x.matmul(self.down_proj.val())
+    }
+}
+
+/// [Mixture of Experts](MixtureOfExperts) outputs.
+#[derive(Debug, Clone)]
+pub struct MoeOutput {
+    /// The output `[batch_size, seq_length, d_model]`.
+    pub output: Tensor<3>,
+    /// The load-balancing loss of the [Switch Transformer](https://arxiv.org/abs/2101.03961),
+    /// `n_experts * sum_e(f_e * p_e)`, where `f_e` is the fraction of the routed tokens and `p_e`
+    /// the mean router probability of expert `e`. It is 1 when the routing is balanced.
+    ///
+    /// Shape `[1]`, to be added to the training loss with a small coefficient (e.g. 0.01).
+    pub load_balancing_loss: Tensor<1>,
+    /// The router z-loss of [ST-MoE](https://arxiv.org/abs/2202.08906), the mean squared
+    /// log-sum-exp of the router logits, which keeps the logits small.
+    ///
+    /// Shape `[1]`, to be added to the training loss with a small coefficient (e.g. 0.001).
+    pub router_z_loss: Tensor<1>,
+    /// The number of tokens dropped from a full expert.
+    ///
+    /// Shape `[1]`, kept on the device so that, with a capacity factor, the forward pass doesn't
+    /// wait for the routing.
+    pub dropped_tokens: Tensor<1, Int>,
+}
+
+impl MixtureOfExpertsConfig {
+    /// Initialize a new [Mixture of Experts](MixtureOfExperts) layer.
+    pub fn init(&self, device: &Device) -> MixtureOfExperts {
+        assert!(
+            self.top_k > 0 && self.top_k <= self.n_experts,
+            "The number of experts per token ({}) must be between 1 and the number of experts ({})",
+            self.top_k,
+            self.n_experts
+        );
+
+        MixtureOfExperts {
+            gate: LinearConfig::new(self.d_model, self.n_experts)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            experts: self.init_experts(device),
+            shared_experts: (self.n_shared_experts > 0)
+                .then(|| self.init_expert(self.n_shared_experts * self.d_ff, device)),
+            top_k: self.top_k,
+            capacity_factor: self.capacity_factor,
+            normalize_gates: self.normalize_gates,
+        }
+    }
+
+    fn init_experts(&self, device: &Device) -> MoeExperts {
+        let weight = |d_input, d_output| {
+            self.initializer.init_with(
+                [self.n_experts, d_input, d_output],
+                Some(d_input),
+                Some(d_output),
+                device,
+            )
+        };
+
+        MoeExperts {
+            gate_proj: weight(self.d_model, self.d_ff),
+            up_proj: weight(self.d_model, self.d_ff),
+            down_proj: weight(self.d_ff, self.d_model),
+        }
+    }
+
+    fn init_expert(&self, d_ff: usize, device: &Device) -> MoeExpert {
+        let linear = |d_input, d_output| {
+            LinearConfig::new(d_input, d_output)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        MoeExpert {
+            gate_proj: linear(self.d_model, d_ff),
+            up_proj: linear(self.d_model, d_ff),
+            down_proj: linear(d_ff, self.d_model),
+        }
+    }
+}
+
+impl MixtureOfExperts {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [MixtureOfExperts](MixtureOfExperts) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<3>) -> MoeOutput {
+        let [batch_size, seq_length, d_model] = input.dims();
+        let n_tokens = batch_size * seq_length;
+        let n_experts = self.experts.n_experts();
+        let x = input.reshape([n_tokens, d_model]);
+
+        let logits = self.gate.forward(x.clone());
+        let probs = softmax(logits.clone(), 1);
+        let (gates, indices) = probs.clone().topk_with_indices(self.top_k, 1);
+        let gates = if self.normalize_gates {
+            gates.clone() / gates.sum_dim(1)
+        } else {
+            gates
+        };
+
+        // Number of times each token is routed to each expert, either 0 or 1.
+        let routed = probs.zeros_like().scatter(
+            1,
+            indices.clone(),
+            gates.ones_like(),
+            IndexingUpdateOp::Add,
+        );
+        let fraction = routed.sum_dim(0).div_scalar((n_tokens * self.top_k) as f64);
+        let load_balancing_loss = (fraction * probs.mean_dim(0))
+            .sum()
+            .mul_scalar(n_experts as f64);
+        let router_z_loss = logits.logsumexp(1).square().mean();
+
+        let (output, dropped_tokens) = self.dispatch(x.clone(), gates, indices);
+        let output = match &self.shared_experts {
+            Some(shared_experts) => output + shared_experts.forward(x),
+            None => output,
+        };
+
+        MoeOutput {
+            output: output.reshape([batch_size, seq_length, d_model]),
+            load_balancing_loss,
+            router_z_loss,
+            dropped_tokens,
+        }
+    }
+
+    /// Returns the number of tokens each expert can receive out of `n_tokens` tokens, or `None`
+    /// without a capacity factor, where it's the number of tokens routed to the busiest expert.
+    pub fn capacity(&self, n_tokens: usize) -> Option<usize> {
+        self.capacity_factor.map(|capacity_factor| {
+            let even_share = (n_tokens * self.top_k) as f64 / self.experts.n_experts() as f64;
+            (capacity_factor * even_share).ceil() as usize
+        })
+    }
+
+    /// Runs each expert on the tokens routed to it, and sums their gated outputs per token.
+    ///
+    /// Returns the output `[n_tokens, d_model]` and the number of dropped tokens `[1]`.
+    fn dispatch(
+        &self,
+        x: Tensor<2>,
+        gates: Tensor<2>,
+        indices: Tensor<2, Int>,
+    ) -> (Tensor<2>, Tensor<1, Int>) {
+        let [n_tokens, d_model] = x.dims();
+        let n_experts = self.experts.n_experts();
+        let n_slots = n_tokens * self.top_k;
+        let device = x.device();
+
+        // The routing slots are ordered by rank, so the first choices of all the tokens are
+        // served before their second choices.
+        let experts = indices.swap_dims(0, 1).reshape([n_slots]);
+        let gates = gates.swap_dims(0, 1).reshape([n_slots, 1]);
+        let tokens =
+            Tensor::<1, Int>::arange(0..n_tokens as i64, &device).repeat_dim(0, self.top_k);
+
+        // Position of each slot among the slots routed to the same expert.
+        let routed = experts.clone().one_hot::<2>(n_experts);
+        let positions = (routed.clone().cumsum(0) * routed)
+            .sum_dim(1)
+            .reshape([n_slots])
+            .sub_scalar(1);
+        let capacity = match self.capacity(n_tokens) {
+            Some(capacity) => capacity,
+            None if n_slots == 0 => 0,
+            // Room for the busiest expert, which waits for the routing.
+            None => positions.clone().max().into_scalar::<i64>() as usize + 1,
+        };
+        let dropped = positions.clone().greater_equal_elem(capacity as i64);
+        let dropped_tokens = dropped.clone().int().sum();
+
+        // Row of each slot in the buffer `[n_experts * capacity + 1, d_model]`, where the last
+        // row receives the dropped slots.
+        let rows = (experts.mul_scalar(capacity as i64) + positions)
+            .mask_fill(dropped, (n_experts * capacity) as i64);
+        let dtype = x.dtype();
+        let buffer = Tensor::zeros([n_experts * capacity + 1, d_model], (&device, dtype))
+            .select_assign(
+                0,
+                rows.clone(),
+                x.clone().select(0, tokens.clone()),
+                IndexingUpdateOp::Add,
+            );
+        let buffer = buffer
+            .slice([0..n_experts * capacity])
+            .reshape([n_experts, capacity, d_model]);
+
+        let buffer = self
+            .experts
+            .forward(buffer)
+            .reshape([n_experts * capacity, d_model]);
+
+        // The dropped slots read a row of zeros.
+        let buffer = Tensor::cat(
+            vec![buffer, Tensor::zeros([1, d_model], (&device, dtype))],
+            0,
+        );
+        let routed = buffer.select(0, rows) * gates;
+        let output = x
+            .zeros_like()
+            .select_assign(0, tokens, routed, IndexingUpdateOp::Add);
+
+        (output, dropped_tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::module::Param;
+    use burn::tensor::{Distribution, TensorData, Tolerance};
+
+    #[test]
+    fn test_moe_shapes() {
+        let [batch_size, seq_length, d_model, d_ff, n_experts] = [2, 5, 8, 16, 4];
+        let device = Default::default();
+        let moe = MixtureOfExpertsConfig::new(d_model, d_ff, n_experts)
+            .with_n_shared_experts(1)
+            .init(&device);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let output = moe.forward(input);
+
+        assert_eq!(output.output.dims(), [batch_size, seq_length, d_model]);
+        assert_eq!(output.load_balancing_loss.dims(), [1]);
+        assert_eq!(output.router_z_loss.dims(), [1]);
+        assert_eq!(output.dropped_tokens.into_scalar::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_moe_all_experts_should_match_dense_mixture() {
+        let [batch_size, seq_length, d_model, d_ff, n_experts] = [2, 3, 6, 8, 3];
+        let device = Default::default();
+        let moe = MixtureOfExpertsConfig::new(d_model, d_ff, n_experts)
+            .with_top_k(n_experts)
+            .init(&device);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let output = moe.forward(input.clone());
+
+        // Every expert weighted by its router probability.
+        let probs = softmax(moe.gate.forward(input.clone()), 2);
+        let expected = (0..n_experts)
+            .map(|e| {
+                let gate = probs
+                    .clone()
+                    .slice([0..batch_size, 0..seq_length, e..e + 1]);
+                moe.experts.expert(e).forward(input.clone()) * gate
+            })
+            .reduce(|acc, output| acc + output)
+            .unwrap();
+
+        output
+            .output
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_moe_capacity_should_drop_tokens() {
+        let [batch_size, seq_length, d_model, d_ff, n_experts] = [1, 8, 4, 8, 4];
+        let device = Default::default();
+        let mut moe = MixtureOfExpertsConfig::new(d_model, d_ff, n_experts)
+            .with_top_k(1)
+            .with_capacity_factor(Some(1.0))
+            .init(&device);
+
+        // Positive inputs are all routed to the first expert.
+        let mut weight = Tensor::<2>::zeros([d_model, n_experts], &device);
+        weight = weight.slice_assign([0..d_model, 0..1], Tensor::ones([d_model, 1], &device));
+        moe.gate.weight = Param::from_tensor(weight);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Uniform(0.5, 1.0),
+            &device,
+        );
+        let output = moe.forward(input.clone());
+
+        // Each expert receives at most 8 / 4 = 2 tokens.
+        assert_eq!(moe.capacity(seq_length), Some(2));
+        assert_eq!(output.dropped_tokens.into_scalar::<i64>(), 6);
+
+        let expected = moe.experts.expert(0).forward(input.slice([0..1, 0..2]));
+        output
+            .output
+            .clone()
+            .slice([0..1, 0..2])
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+        output
+            .output
+            .slice([0..1, 2..seq_length])
+            .into_data()
+            .assert_approx_eq::<f32>(
+                &Tensor::<3>::zeros([1, seq_length - 2, d_model], &device).into_data(),
+                Tolerance::default(),
+            );
+    }
+
+    #[test]
+    fn test_moe_without_capacity_factor_should_fit_the_busiest_expert() {
+        let [batch_size, seq_length, d_model, d_ff, n_experts] = [1, 8, 4, 8, 4];
+        let device = Default::default();
+        let mut moe = MixtureOfExpertsConfig::new(d_model, d_ff, n_experts)
+            .with_top_k(1)
+            .init(&device);
+
+        // Positive inputs are all routed to the first expert.
+        let mut weight = Tensor::<2>::zeros([d_model, n_experts], &device);
+        weight = weight.slice_assign([0..d_model, 0..1], Tensor::ones([d_model, 1], &device));
+        moe.gate.weight = Param::from_tensor(weight);
+
+        let input = Tensor::<3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Uniform(0.5, 1.0),
+            &device,
+        );
+        let output = moe.forward(input.clone());
+
+        assert_eq!(moe.capacity(seq_length), None);
+        assert_eq!(output.dropped_tokens.into_scalar::<i64>(), 0);
+        output.output.into_data().assert_approx_eq::<f32>(
+            &moe.experts.expert(0).forward(input).into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_moe_experts_from_experts_should_stack_the_weights() {
+        let device = Default::default();
+        let config = MixtureOfExpertsConfig::new(4, 8, 3);
+        let experts = config.init(&device).experts;
+        let stacked =
+            MoeExperts::from_experts(&[experts.expert(0), experts.expert(1), experts.expert(2)]);
+
+        let input = Tensor::<3>::random([3, 5, 4], Distribution::Default, &device);
+        stacked
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq::<f32>(&experts.forward(input).into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_moe_auxiliary_losses_with_uniform_router() {
+        let [d_model, d_ff, n_experts] = [4, 8, 4];
+        let device = Default::default();
+        let moe = MixtureOfExpertsConfig::new(d_model, d_ff, n_experts)
+            .with_initializer(Initializer::Zeros)
+            .init(&device);
+
+        let input = Tensor::<3>::random([2, 3, d_model], Distribution::Default, &device);
+        let output = moe.forward(input);
+
+        // Uniform probabilities: balanced whatever the routing, and logsumexp = ln(n_experts).
+        let z_loss = (n_experts as f32).ln().powi(2);
+        output
+            .load_balancing_loss
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([1.0]), Tolerance::default());
+        output
+            .router_z_loss
+            .into_data()
+            .assert_approx_eq::<f32>(&TensorData::from([z_loss]), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be between 1 and the number of experts")]
+    fn test_moe_invalid_top_k() {
+        MixtureOfExpertsConfig::new(4, 8, 2)
+            .with_top_k(3)
+            .init(&Default::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = MixtureOfExpertsConfig::new(4, 8, 4);
+        let moe = config.init(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{moe}"),
+            "MixtureOfExperts {d_model: 4, n_experts: 4, top_k: 2, params: 400}"
+        );
+    }
+}
@@ -17,6 +17,9 @@ pub use optim::*;
 /// Gradient clipping module.
 pub mod grad_clipping;
 
+/// Differential privacy module.
+pub mod privacy;
+
 /// Learning rate scheduler module.
 #[cfg(feature = "std")]
 pub mod lr_scheduler;
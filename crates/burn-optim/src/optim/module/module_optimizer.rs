@@ -422,6 +422,7 @@ impl ModuleMapper for ModuleOptimizerMapper<'_> {
 
         let tensor = if let Some((grad, device)) = grad {
             let is_require_grad = tensor.is_require_grad();
+            let is_per_sample_grad = tensor.is_per_sample_grad();
             #[cfg(feature = "std")]
             let is_distributed = tensor.is_distributed();
 
@@ -491,6 +492,9 @@ impl ModuleMapper for ModuleOptimizerMapper<'_> {
             if is_require_grad {
                 tensor = tensor.require_grad();
             }
+            if is_per_sample_grad {
+                tensor = tensor.set_per_sample_grad();
+            }
             #[cfg(feature = "std")]
             if is_distributed {
                 tensor = tensor.set_distributed(id)
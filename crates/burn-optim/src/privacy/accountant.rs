@@ -0,0 +1,181 @@
+use num_traits::Float as _;
+
+/// The Rényi orders tracked by the [accountant](RdpAccountant) in addition to the integer orders
+/// from 2 to 64.
+const EXTRA_ORDERS: [u32; 4] = [80, 96, 128, 256];
+
+/// Tracks the privacy loss of [DP-SGD](super::DpSgd) with Rényi differential privacy (RDP).
+///
+/// Each step of DP-SGD is a sampled Gaussian mechanism: every sample of the dataset is part of
+/// the batch with probability `sample_rate`, and Gaussian noise with a standard deviation of
+/// `noise_multiplier` times the clipping norm is added to the sum of the clipped gradients. The
+/// RDP of the mechanism is computed for a range of integer orders (Mironov et al., 2019), the
+/// steps compose additively, and the result is converted to an `(epsilon, delta)` guarantee with
+/// the conversion of Balle et al. (2020).
+#[derive(Clone, Debug)]
+pub struct RdpAccountant {
+    noise_multiplier: f64,
+    sample_rate: f64,
+    steps: usize,
+}
+
+impl RdpAccountant {
+    /// Creates an accountant for the given noise multiplier and sample rate, the batch size
+    /// divided by the size of the dataset.
+    ///
+    /// # Panics
+    ///
+    /// If the noise multiplier isn't positive or the sample rate isn't in `[0, 1]`.
+    pub fn new(noise_multiplier: f64, sample_rate: f64) -> Self {
+        assert!(
+            noise_multiplier > 0.0,
+            "The noise multiplier should be positive, got {noise_multiplier}."
+        );
+        assert!(
+            (0.0..=1.0).contains(&sample_rate),
+            "The sample rate should be in [0, 1], got {sample_rate}."
+        );
+
+        Self {
+            noise_multiplier,
+            sample_rate,
+            steps: 0,
+        }
+    }
+
+    /// Records a training step.
+    pub fn step(&mut self) {
+        self.steps += 1;
+    }
+
+    /// The number of recorded steps.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Returns the epsilon spent by the recorded steps for the given delta.
+    ///
+    /// The epsilon is zero before the first step.
+    pub fn epsilon(&self, delta: f64) -> f64 {
+        assert!(
+            delta > 0.0 && delta < 1.0,
+            "The delta should be in (0, 1), got {delta}."
+        );
+
+        if self.steps == 0 {
+            return 0.0;
+        }
+
+        (2..=64)
+            .chain(EXTRA_ORDERS)
+            .map(|order| {
+                let alpha = order as f64;
+                let rdp = self.steps as f64 * self.log_moment(order) / (alpha - 1.0);
+                rdp - (delta.ln() + alpha.ln()) / (alpha - 1.0) + ((alpha - 1.0) / alpha).ln()
+            })
+            .filter(|epsilon| !epsilon.is_nan())
+            .fold(f64::INFINITY, f64::min)
+            .max(0.0)
+    }
+
+    /// Computes `log A_alpha`, the log of the moment of order `alpha` of the privacy loss of one
+    /// step, with the binomial expansion for integer orders.
+    fn log_moment(&self, alpha: u32) -> f64 {
+        let q = self.sample_rate;
+        let variance = self.noise_multiplier * self.noise_multiplier;
+
+        if q == 0.0 {
+            return 0.0;
+        }
+        if q == 1.0 {
+            // The Gaussian mechanism without sampling.
+            let alpha = alpha as f64;
+            return alpha * (alpha - 1.0) / (2.0 * variance);
+        }
+
+        let mut log_sum = f64::NEG_INFINITY;
+        let mut log_binomial = 0.0;
+        for i in 0..=alpha {
+            if i > 0 {
+                log_binomial += ((alpha - i + 1) as f64).ln() - (i as f64).ln();
+            }
+            let i_f = i as f64;
+            let term = log_binomial
+                + i_f * q.ln()
+                + (alpha - i) as f64 * (1.0 - q).ln()
+                + (i_f * i_f - i_f) / (2.0 * variance);
+            log_sum = log_add(log_sum, term);
+        }
+
+        log_sum
+    }
+}
+
+/// Computes `log(exp(a) + exp(b))` without overflow.
+fn log_add(a: f64, b: f64) -> f64 {
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+    if low == f64::NEG_INFINITY {
+        return high;
+    }
+
+    high + (low - high).exp().ln_1p()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epsilon_no_steps() {
+        let accountant = RdpAccountant::new(1.0, 0.01);
+
+        assert_eq!(accountant.epsilon(1e-5), 0.0);
+    }
+
+    #[test]
+    fn test_epsilon_grows_with_steps() {
+        let mut accountant = RdpAccountant::new(1.1, 0.01);
+        let mut previous = 0.0;
+
+        for _ in 0..3 {
+            for _ in 0..100 {
+                accountant.step();
+            }
+            let epsilon = accountant.epsilon(1e-5);
+            assert!(epsilon > previous);
+            previous = epsilon;
+        }
+    }
+
+    #[test]
+    fn test_epsilon_shrinks_with_noise() {
+        let mut low_noise = RdpAccountant::new(0.8, 0.01);
+        let mut high_noise = RdpAccountant::new(2.0, 0.01);
+        for _ in 0..1000 {
+            low_noise.step();
+            high_noise.step();
+        }
+
+        assert!(high_noise.epsilon(1e-5) < low_noise.epsilon(1e-5));
+    }
+
+    #[test]
+    fn test_epsilon_reference() {
+        let mut accountant = RdpAccountant::new(1.1, 256.0 / 60000.0);
+        for _ in 0..(60 * 60000 / 256) {
+            accountant.step();
+        }
+
+        let epsilon = accountant.epsilon(1e-5);
+        assert!((epsilon - 2.597).abs() < 1e-3, "{epsilon}");
+    }
+
+    #[test]
+    fn test_epsilon_gaussian_mechanism() {
+        let mut accountant = RdpAccountant::new(1.0, 1.0);
+        accountant.step();
+
+        let epsilon = accountant.epsilon(1e-5);
+        assert!((epsilon - 4.753).abs() < 1e-3, "{epsilon}");
+    }
+}
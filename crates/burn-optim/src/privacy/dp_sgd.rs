@@ -0,0 +1,312 @@
+use burn_core as burn;
+
+use burn::config::Config;
+use burn::module::{AutodiffModule, ModuleMapper, ModuleVisitor, Param};
+use burn::tensor::{Distribution, Tensor};
+
+use super::RdpAccountant;
+use crate::lr_scheduler::module_lr_scheduler::ModuleLearningRate;
+use crate::{GradientsParams, ModuleOptimizer};
+
+/// Configuration to create [DP-SGD](DpSgd).
+#[derive(Config, Debug)]
+pub struct DpSgdConfig {
+    /// The maximum L2 norm of the gradients of each sample, over all the parameters.
+    pub max_grad_norm: f64,
+    /// The standard deviation of the noise, relative to the maximum gradient norm.
+    pub noise_multiplier: f64,
+}
+
+impl DpSgdConfig {
+    /// Initializes [DP-SGD](DpSgd) around the optimizer that applies the private gradients.
+    pub fn init(&self, optim: impl Into<ModuleOptimizer>) -> DpSgd {
+        assert!(
+            self.max_grad_norm > 0.0,
+            "The maximum gradient norm should be positive, got {}.",
+            self.max_grad_norm
+        );
+        assert!(
+            self.noise_multiplier >= 0.0,
+            "The noise multiplier should be positive or zero, got {}.",
+            self.noise_multiplier
+        );
+
+        DpSgd {
+            optim: optim.into(),
+            max_grad_norm: self.max_grad_norm,
+            noise_multiplier: self.noise_multiplier,
+        }
+    }
+
+    /// Creates an [accountant](RdpAccountant) of the privacy loss of the training with this
+    /// configuration, where `sample_rate` is the batch size divided by the size of the dataset.
+    pub fn accountant(&self, sample_rate: f64) -> RdpAccountant {
+        RdpAccountant::new(self.noise_multiplier, sample_rate)
+    }
+}
+
+/// Differentially private optimization, following DP-SGD (Abadi et al., 2016).
+///
+/// The gradients of each sample are clipped to a maximum L2 norm over all the parameters, summed
+/// over the batch, and Gaussian noise with a standard deviation of `noise_multiplier *
+/// max_grad_norm` is added before averaging. The wrapped optimizer then applies the private
+/// gradients: DP-SGD with [SGD](crate::Sgd), or DP-Adam with [Adam](crate::Adam).
+///
+/// The per-sample gradients come from a single backward pass: the parameters are marked with
+/// [per_sample](DpSgd::per_sample), so the autodiff backend computes the gradient of each sample
+/// from the inputs and output gradients of the linear, convolution and embedding layers. Other
+/// layers with parameters aren't supported. The loss should be the sum of the losses of the
+/// samples, not their mean.
+///
+/// The privacy spent by the training is tracked by an [accountant](RdpAccountant), stepped once
+/// per batch.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut dp_sgd = DpSgdConfig::new(1.0, 1.1).init(SgdConfig::new().init());
+/// let mut model = DpSgd::per_sample(model);
+///
+/// for batch in dataloader.iter() {
+///     let loss = model.forward(batch.inputs).loss(batch.targets).sum();
+///     let grads = GradientsParams::from_grads(loss.backward(), &model);
+///     model = dp_sgd.step(lr, model, grads);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DpSgd {
+    optim: ModuleOptimizer,
+    max_grad_norm: f64,
+    noise_multiplier: f64,
+}
+
+impl DpSgd {
+    /// Marks the parameters of the module that require gradients to receive per-sample
+    /// gradients.
+    ///
+    /// The marks are kept by the optimizer steps, so it's only needed once.
+    pub fn per_sample<M: AutodiffModule>(module: M) -> M {
+        module.map(&mut PerSampleMarker)
+    }
+
+    /// Updates the module with the private gradients of a batch, computed from the per-sample
+    /// gradients.
+    pub fn step<M: AutodiffModule>(
+        &mut self,
+        lr: impl Into<ModuleLearningRate>,
+        module: M,
+        grads: GradientsParams,
+    ) -> M {
+        let grads = self.privatize(&module, grads);
+        self.optim.step(lr, module, grads)
+    }
+
+    /// Computes the private gradients of a batch from the per-sample gradients.
+    ///
+    /// Every parameter that requires gradients receives noise, even without gradients in the
+    /// batch, as skipping it would reveal that no sample used it.
+    ///
+    /// # Panics
+    ///
+    /// If a parameter that requires gradients isn't marked with [per_sample](DpSgd::per_sample),
+    /// or if no parameter has gradients.
+    pub fn privatize<M: AutodiffModule>(
+        &self,
+        module: &M,
+        grads: GradientsParams,
+    ) -> GradientsParams {
+        let mut norms = SquaredNorms {
+            grads: &grads,
+            batch_size: None,
+            squared_norms: None,
+        };
+        module.visit(&mut norms);
+        let (Some(batch_size), Some(squared_norms)) = (norms.batch_size, norms.squared_norms)
+        else {
+            panic!("The batch should have per-sample gradients.");
+        };
+
+        let scale = squared_norms
+            .sqrt()
+            .add_scalar(1e-6)
+            .recip()
+            .mul_scalar(self.max_grad_norm)
+            .clamp_max(1.0);
+        let mut noise = ClipNoise {
+            grads,
+            output: GradientsParams::new(),
+            scale,
+            std: self.noise_multiplier * self.max_grad_norm,
+            batch_size,
+        };
+        module.visit(&mut noise);
+
+        noise.output
+    }
+}
+
+struct PerSampleMarker;
+
+impl ModuleMapper for PerSampleMarker {
+    fn map_float<const D: usize>(&mut self, param: Param<Tensor<D>>) -> Param<Tensor<D>> {
+        let (id, tensor, mapper) = param.consume();
+        let tensor = match tensor.is_require_grad() && !tensor.is_per_sample_grad() {
+            true => tensor.set_per_sample_grad(),
+            false => tensor,
+        };
+        Param::from_mapped_value(id, tensor, mapper)
+    }
+}
+
+/// Flattens a per-sample gradient, which has the batch as an extra leading dimension, to
+/// `[batch_size, num_elements]`.
+fn flatten_samples<const D: usize>(grad: Tensor<D>) -> Tensor<2> {
+    let batch_size = grad.shape()[0];
+    grad.reshape([batch_size as i64, -1])
+}
+
+struct SquaredNorms<'a> {
+    grads: &'a GradientsParams,
+    batch_size: Option<usize>,
+    squared_norms: Option<Tensor<2>>,
+}
+
+impl ModuleVisitor for SquaredNorms<'_> {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let tensor = param.val();
+        if !tensor.is_require_grad() {
+            return;
+        }
+        assert!(
+            tensor.is_per_sample_grad(),
+            "The parameters should be marked with `DpSgd::per_sample` to get per-sample gradients."
+        );
+
+        let Some(grad) = self.grads.get::<D>(param.id) else {
+            return;
+        };
+        let batch_size = grad.shape()[0];
+        assert_eq!(
+            *self.batch_size.get_or_insert(batch_size),
+            batch_size,
+            "The per-sample gradients should have the same batch size."
+        );
+
+        let squared = flatten_samples(grad).square().sum_dim(1);
+        self.squared_norms = Some(match self.squared_norms.take() {
+            Some(norms) => norms.add(squared.to_device(&norms.device())),
+            None => squared,
+        });
+    }
+}
+
+struct ClipNoise {
+    grads: GradientsParams,
+    output: GradientsParams,
+    scale: Tensor<2>,
+    std: f64,
+    batch_size: usize,
+}
+
+impl ModuleVisitor for ClipNoise {
+    fn visit_float<const D: usize>(&mut self, param: &Param<Tensor<D>>) {
+        let tensor = param.val();
+        if !tensor.is_require_grad() {
+            return;
+        }
+
+        let sum = match self.grads.remove::<D>(param.id) {
+            Some(grad) => {
+                let scale = self.scale.clone().to_device(&grad.device());
+                flatten_samples(grad)
+                    .mul(scale)
+                    .sum_dim(0)
+                    .reshape(tensor.shape())
+            }
+            None => tensor.zeros_like().no_grad(),
+        };
+        let noise = sum.random_like(Distribution::Normal(0.0, self.std));
+        self.output
+            .register::<D>(param.id, sum.add(noise).div_scalar(self.batch_size as f64));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SgdConfig;
+    use burn::tensor::{Device, TensorData, Tolerance};
+    use burn_nn::{Linear, LinearConfig};
+
+    #[test]
+    fn test_dp_sgd_clips_per_sample_gradients() {
+        let device = Device::default().autodiff();
+        let layer = DpSgd::per_sample(layer(&device));
+        let inputs = Tensor::<2>::from_floats([[10.0, 0.0], [0.0, 0.1]], &device);
+        // Without noise, the result is the mean of the clipped per-sample gradients.
+        let dp_sgd = DpSgdConfig::new(2.0, 0.0).init(SgdConfig::new().init());
+
+        let loss = layer.forward(inputs).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let grads = dp_sgd.privatize(&layer, grads);
+
+        // The gradients of the weight and the bias are [x^T, 1], with norms sqrt(101) and
+        // sqrt(1.01), so only the first sample is clipped.
+        let first = 2.0 / 101.0f32.sqrt();
+        let weight = grads.get::<2>(layer.weight.id).unwrap();
+        weight.into_data().assert_approx_eq::<f32>(
+            &TensorData::from([[5.0 * first], [0.05]]),
+            Tolerance::default(),
+        );
+        let bias = grads.get::<1>(layer.bias.as_ref().unwrap().id).unwrap();
+        bias.into_data().assert_approx_eq::<f32>(
+            &TensorData::from([(first + 1.0) / 2.0]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_dp_sgd_adds_noise() {
+        let device = Device::default().autodiff();
+        let layer = DpSgd::per_sample(layer(&device));
+        let inputs = Tensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let dp_sgd = DpSgdConfig::new(1.0, 1.0).init(SgdConfig::new().init());
+        let noiseless = DpSgdConfig::new(1.0, 0.0).init(SgdConfig::new().init());
+
+        let grads = |layer: &Linear| {
+            let loss = layer.forward(inputs.clone()).sum();
+            GradientsParams::from_grads(loss.backward(), layer)
+        };
+        let noisy = dp_sgd.privatize(&layer, grads(&layer));
+        let expected = noiseless.privatize(&layer, grads(&layer));
+
+        let weight = noisy.get::<2>(layer.weight.id).unwrap();
+        let expected = expected.get::<2>(layer.weight.id).unwrap();
+        assert_eq!(weight.dims(), [2, 1]);
+        assert!(weight.sub(expected).abs().sum().into_scalar::<f32>() > 0.0);
+    }
+
+    #[test]
+    fn test_dp_sgd_step_keeps_per_sample_gradients() {
+        let device = Device::default().autodiff();
+        let layer = DpSgd::per_sample(layer(&device));
+        let inputs = Tensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let mut dp_sgd = DpSgdConfig::new(1.0, 1.0).init(SgdConfig::new().init());
+
+        let loss = layer.forward(inputs).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let layer = dp_sgd.step(0.1, layer, grads);
+
+        assert!(layer.weight.val().is_per_sample_grad());
+    }
+
+    #[test]
+    #[should_panic = "The noise multiplier should be positive or zero"]
+    fn test_dp_sgd_rejects_negative_noise() {
+        DpSgdConfig::new(1.0, -1.0).init(SgdConfig::new().init());
+    }
+
+    fn layer(device: &Device) -> Linear {
+        LinearConfig::new(2, 1).init(device)
+    }
+}
@@ -0,0 +1,5 @@
+mod accountant;
+mod dp_sgd;
+
+pub use accountant::*;
+pub use dp_sgd::*;
@@ -156,6 +156,29 @@ fn dtype_and_byte_len_preserved() {
     }
 }
 
+#[test]
+fn fp8_tensors_round_trip() {
+    // 1.0, -2.0 and 0.5 encoded in each FP8 format.
+    let e4m3 = vec![0x38, 0xC0, 0x30];
+    let e5m2 = vec![0x3C, 0xC0, 0x38];
+
+    let packed = Writer::new(vec![
+        raw_tensor("e4m3", DType::F8E4M3, &[3], e4m3.clone(), None),
+        raw_tensor("e5m2", DType::F8E5M2, &[3], e5m2.clone(), None),
+    ])
+    .into_bytes()
+    .unwrap();
+    let read = Reader::from_bytes(packed).unwrap().into_tensors().unwrap();
+
+    for (name, dtype, bytes) in [("e4m3", DType::F8E4M3, e4m3), ("e5m2", DType::F8E5M2, e5m2)] {
+        let t = read.iter().find(|t| t.name == name).unwrap();
+        assert_eq!(t.dtype, dtype, "dtype preserved for {name}");
+        assert_eq!(t.byte_len(), 3);
+        let materialized: &[u8] = &t.bytes;
+        assert_eq!(materialized, &bytes[..]);
+    }
+}
+
 #[test]
 fn in_memory_round_trip() {
     let packed = Writer::new(vec![f32_tensor("w", &[1.5, 2.5, 3.5], &[3], None)])
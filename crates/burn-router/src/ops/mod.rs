@@ -5,6 +5,7 @@ mod distributed;
 mod int_tensor;
 mod module;
 mod qtensor;
+mod sparse;
 mod tensor;
 mod transaction;
 mod unary;
@@ -0,0 +1,4 @@
+use crate::{BackendRouter, RouterChannel};
+use burn_backend::ops::SparseTensorOps;
+
+impl<R: RouterChannel> SparseTensorOps<Self> for BackendRouter<R> {}
@@ -227,6 +227,7 @@ impl TensorData {
             DType::F32 | DType::Flex32 => self.assert_eq_elem::<f32>(other),
             DType::F16 => self.assert_eq_elem::<f16>(other),
             DType::BF16 => self.assert_eq_elem::<bf16>(other),
+            DType::F8E4M3 | DType::F8E5M2 => self.assert_eq_elem::<f32>(other),
             DType::I64 => self.assert_eq_elem::<i64>(other),
             DType::I32 => self.assert_eq_elem::<i32>(other),
             DType::I16 => self.assert_eq_elem::<i16>(other),
@@ -253,6 +254,12 @@ impl TensorData {
                     panic!("Quantization schemes differ ({q:?} != {q_other:?})")
                 }
             }
+            DType::Complex64 | DType::Complex32 => {
+                let (re, im) = self.clone().into_complex_parts();
+                let (other_re, other_im) = other.clone().into_complex_parts();
+                re.assert_eq(&other_re, strict);
+                im.assert_eq(&other_im, strict);
+            }
         }
     }
 
@@ -300,6 +307,14 @@ impl TensorData {
     /// Panics if the data is not approximately equal.
     #[track_caller]
     pub fn assert_approx_eq<F: Float + Element>(&self, other: &Self, tolerance: Tolerance<F>) {
+        if self.dtype.is_complex() {
+            let (re, im) = self.clone().into_complex_parts();
+            let (other_re, other_im) = other.clone().into_complex_parts();
+            re.assert_approx_eq(&other_re, tolerance);
+            im.assert_approx_eq(&other_im, tolerance);
+            return;
+        }
+
         let mut message = String::new();
         if self.shape != other.shape {
             message += format!(
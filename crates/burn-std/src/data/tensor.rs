@@ -14,7 +14,7 @@ use crate::element::{Element, ElementConversion};
 use crate::tensor::DType;
 use crate::{
     BoolStore, Bytes, QuantLevel, QuantMode, QuantScheme, QuantValue, QuantizedBytes, Shape, bf16,
-    f16,
+    e4m3, e5m2, f16,
 };
 
 use serde::{Deserialize, Serialize};
@@ -286,6 +286,16 @@ impl TensorData {
                         .iter()
                         .map(|e: &f64| e.elem::<E>()),
                 ),
+                DType::F8E4M3 => Box::new(
+                    self.bytes
+                        .iter()
+                        .map(|e| e4m3::from_bits(*e).to_f32().elem::<E>()),
+                ),
+                DType::F8E5M2 => Box::new(
+                    self.bytes
+                        .iter()
+                        .map(|e| e5m2::from_bits(*e).to_f32().elem::<E>()),
+                ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool(BoolStore::Native) | DType::Bool(BoolStore::U8) => {
                     Box::new(self.bytes.iter().map(|e| e.elem::<E>()))
@@ -341,6 +351,12 @@ impl TensorData {
                         unimplemented!("two-level quantization is not supported yet")
                     }
                 },
+                DType::Complex64 | DType::Complex32 => {
+                    unimplemented!(
+                        "Complex values can't be iterated as real values, use \
+                         `into_complex_parts` instead"
+                    )
+                }
             }
         }
     }
@@ -426,6 +442,9 @@ impl TensorData {
             DType::F32 | DType::Flex32 => Self::full::<f32, _>(shape, fill_value.elem()),
             DType::F16 => Self::full::<f16, _>(shape, fill_value.elem()),
             DType::BF16 => Self::full::<bf16, _>(shape, fill_value.elem()),
+            DType::F8E4M3 | DType::F8E5M2 => {
+                Self::full::<f32, _>(shape, fill_value.elem()).convert_dtype(dtype)
+            }
             DType::I64 => Self::full::<i64, _>(shape, fill_value.elem()),
             DType::I32 => Self::full::<i32, _>(shape, fill_value.elem()),
             DType::I16 => Self::full::<i16, _>(shape, fill_value.elem()),
@@ -442,6 +461,15 @@ impl TensorData {
                 Self::full::<u32, _>(shape, fill_value.elem()).into_bool_u32()
             }
             DType::QFloat(_) => unreachable!(),
+            DType::Complex64 | DType::Complex32 => {
+                // The fill value is real.
+                let part: DType = dtype.complex_part().unwrap().into();
+                let shape: Shape = shape.into();
+                Self::from_complex_parts(
+                    Self::full_dtype(shape.clone(), fill_value, part),
+                    Self::full_dtype(shape, 0.0, part),
+                )
+            }
         }
     }
 
@@ -466,6 +494,10 @@ impl TensorData {
     pub fn convert_dtype(self, dtype: DType) -> Self {
         if dtype == self.dtype {
             self
+        } else if self.dtype.is_complex() || dtype.is_complex() {
+            self.convert_complex_dtype(dtype)
+        } else if self.dtype.is_fp8() || dtype.is_fp8() {
+            self.convert_fp8_dtype(dtype)
         } else if dtype.size() == self.dtype.size()
             && !matches!(
                 self.dtype,
@@ -488,7 +520,12 @@ impl TensorData {
                 DType::U8 => self.convert_inplace_dtype::<u8>(dtype),
                 DType::Bool(BoolStore::U8) => self.convert_inplace_dtype::<u8>(dtype),
                 DType::Bool(BoolStore::U32) => self.convert_inplace_dtype::<u32>(dtype),
-                DType::Bool(BoolStore::Native) | DType::QFloat(_) => unreachable!(),
+                DType::Bool(BoolStore::Native)
+                | DType::F8E4M3
+                | DType::F8E5M2
+                | DType::QFloat(_)
+                | DType::Complex64
+                | DType::Complex32 => unreachable!(),
             }
         } else {
             match self.dtype {
@@ -507,7 +544,11 @@ impl TensorData {
                 DType::Bool(BoolStore::Native) => self.convert_clone_dtype::<bool>(dtype),
                 DType::Bool(BoolStore::U8) => self.convert_clone_dtype::<u8>(dtype),
                 DType::Bool(BoolStore::U32) => self.convert_clone_dtype::<u32>(dtype),
-                DType::QFloat(_) => unreachable!(),
+                DType::F8E4M3
+                | DType::F8E5M2
+                | DType::QFloat(_)
+                | DType::Complex64
+                | DType::Complex32 => unreachable!(),
             }
         }
     }
@@ -530,7 +571,12 @@ impl TensorData {
             DType::Bool(BoolStore::U32) => {
                 self.convert_inplace_bool::<Current, u32>().into_bool_u32()
             }
-            DType::Bool(BoolStore::Native) | DType::QFloat(_) => unreachable!(),
+            DType::Bool(BoolStore::Native)
+            | DType::F8E4M3
+            | DType::F8E5M2
+            | DType::QFloat(_)
+            | DType::Complex64
+            | DType::Complex32 => unreachable!(),
         }
     }
 
@@ -580,7 +626,11 @@ impl TensorData {
             DType::Bool(BoolStore::U32) => {
                 self.convert_clone_bool::<Current, u32>().into_bool_u32()
             }
-            DType::QFloat(_) => unreachable!(),
+            DType::F8E4M3
+            | DType::F8E5M2
+            | DType::QFloat(_)
+            | DType::Complex64
+            | DType::Complex32 => unreachable!(),
         }
     }
 
@@ -610,6 +660,99 @@ impl TensorData {
         Self::new(out, self.shape)
     }
 
+    /// Converts from or to an 8-bit float data type, through `f32`.
+    ///
+    /// Values out of the range of the 8-bit type saturate for `F8E4M3`, which has no infinity,
+    /// and round to infinity for `F8E5M2`.
+    fn convert_fp8_dtype(self, dtype: DType) -> Self {
+        let shape = self.shape.clone();
+        let values: Vec<f32> = self.iter::<f32>().collect();
+
+        let bytes = match dtype {
+            DType::F8E4M3 => values
+                .iter()
+                .map(|v| e4m3::from_f32(*v).to_bits())
+                .collect(),
+            DType::F8E5M2 => values
+                .iter()
+                .map(|v| e5m2::from_f32(*v).to_bits())
+                .collect(),
+            _ => return Self::new(values, shape).convert_dtype(dtype),
+        };
+
+        Self::from_bytes_vec(bytes, shape, dtype)
+    }
+
+    /// Converts complex data to another complex data type, by converting both parts.
+    fn convert_complex_dtype(self, dtype: DType) -> Self {
+        let part = match dtype.complex_part() {
+            Some(part) if self.dtype.is_complex() => part,
+            _ => panic!(
+                "Complex data can only be converted to another complex data type, got {:?} to \
+                 {dtype:?}",
+                self.dtype
+            ),
+        };
+
+        let (re, im) = self.into_complex_parts();
+        Self::from_complex_parts(re.convert_dtype(part.into()), im.convert_dtype(part.into()))
+    }
+
+    /// Creates complex data from its real and imaginary parts, which must have the same shape.
+    ///
+    /// The data type is [Complex64](DType::Complex64) when both parts are `f64`, and
+    /// [Complex32](DType::Complex32) otherwise, with the parts converted to `f32`.
+    pub fn from_complex_parts(re: TensorData, im: TensorData) -> Self {
+        assert_eq!(
+            re.shape, im.shape,
+            "The real and imaginary parts should have the same shape"
+        );
+
+        if re.dtype == DType::F64 && im.dtype == DType::F64 {
+            Self::interleave::<f64>(re, im, DType::Complex64)
+        } else {
+            Self::interleave::<f32>(re.convert::<f32>(), im.convert::<f32>(), DType::Complex32)
+        }
+    }
+
+    /// Splits complex data into its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the data type isn't complex.
+    pub fn into_complex_parts(self) -> (TensorData, TensorData) {
+        match self.dtype {
+            DType::Complex64 => self.deinterleave::<f64>(),
+            DType::Complex32 => self.deinterleave::<f32>(),
+            dtype => panic!("Expected complex data type, got {dtype:?}"),
+        }
+    }
+
+    fn interleave<E: Element>(re: TensorData, im: TensorData, dtype: DType) -> Self {
+        let mut values = Vec::with_capacity(2 * re.num_elements());
+        for (re, im) in re.iter::<E>().zip(im.iter::<E>()) {
+            values.push(re);
+            values.push(im);
+        }
+
+        Self {
+            bytes: Bytes::from_elems(values),
+            shape: re.shape,
+            dtype,
+        }
+    }
+
+    fn deinterleave<E: Element>(self) -> (TensorData, TensorData) {
+        let values: &[E] = bytemuck::checked::cast_slice(&self.bytes);
+        let re = values.iter().step_by(2).copied().collect();
+        let im = values.iter().skip(1).step_by(2).copied().collect();
+
+        (
+            TensorData::new(re, self.shape.clone()),
+            TensorData::new(im, self.shape),
+        )
+    }
+
     /// Returns the data as a slice of bytes.
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
@@ -734,6 +877,9 @@ impl core::fmt::Display for TensorData {
             DType::F32 | DType::Flex32 => format!("{:?}", self.as_slice::<f32>().unwrap()),
             DType::F16 => format!("{:?}", self.as_slice::<f16>().unwrap()),
             DType::BF16 => format!("{:?}", self.as_slice::<bf16>().unwrap()),
+            DType::F8E4M3 | DType::F8E5M2 => {
+                format!("{:?}", self.iter::<f32>().collect::<Vec<_>>())
+            }
             DType::I64 => format!("{:?}", self.as_slice::<i64>().unwrap()),
             DType::I32 => format!("{:?}", self.as_slice::<i32>().unwrap()),
             DType::I16 => format!("{:?}", self.as_slice::<i16>().unwrap()),
@@ -777,6 +923,11 @@ impl core::fmt::Display for TensorData {
                     unimplemented!("two-level quantization is not supported yet")
                 }
             },
+            DType::Complex64 | DType::Complex32 => {
+                let (re, im) = self.clone().into_complex_parts();
+                let values = re.iter::<f64>().zip(im.iter::<f64>()).collect::<Vec<_>>();
+                format!("{values:?}")
+            }
         };
         f.write_str(fmt.as_str())
     }
@@ -857,6 +1008,56 @@ mod tests {
         assert_eq!(num_elements, data.as_slice::<f32>().unwrap().len());
     }
 
+    #[test]
+    fn should_interleave_complex_parts() {
+        let re = TensorData::from([1.0f32, 2.0]);
+        let im = TensorData::from([3.0f32, 4.0]);
+
+        let data = TensorData::from_complex_parts(re.clone(), im.clone());
+
+        assert_eq!(data.dtype, DType::Complex32);
+        assert_eq!(data.num_elements(), 2);
+        assert_eq!(data.bytes.len(), 16);
+        let (data_re, data_im) = data.into_complex_parts();
+        data_re.assert_eq(&re, true);
+        data_im.assert_eq(&im, true);
+    }
+
+    #[test]
+    fn should_convert_complex_dtype() {
+        let data = TensorData::from_complex_parts(
+            TensorData::from([1.5f32, -2.0]),
+            TensorData::from([0.5f32, 3.0]),
+        );
+
+        let data = data.convert_dtype(DType::Complex64);
+
+        assert_eq!(data.dtype, DType::Complex64);
+        let (re, im) = data.into_complex_parts();
+        re.assert_eq(&TensorData::from([1.5f64, -2.0]), true);
+        im.assert_eq(&TensorData::from([0.5f64, 3.0]), true);
+    }
+
+    #[test]
+    fn should_convert_fp8_dtype() {
+        let data = TensorData::from([1.0f32, -2.0, 0.3, 1000.0]);
+
+        let e4m3 = data.clone().convert_dtype(DType::F8E4M3);
+        assert_eq!(e4m3.dtype, DType::F8E4M3);
+        assert_eq!(e4m3.bytes[0], 0x38);
+        // 1000 saturates to the largest finite value, 448.
+        e4m3.convert::<f32>()
+            .assert_eq(&TensorData::from([1.0f32, -2.0, 0.3125, 448.0]), true);
+
+        let e5m2 = data.convert_dtype(DType::F8E5M2);
+        assert_eq!(e5m2.bytes[0], 0x3C);
+        e5m2.clone()
+            .convert_dtype(DType::F8E4M3)
+            .assert_eq(&TensorData::from([1.0f32, -2.0, 0.3125, 448.0]), false);
+        e5m2.convert::<f64>()
+            .assert_eq(&TensorData::from([1.0f64, -2.0, 0.3125, 1024.0]), true);
+    }
+
     #[test]
     fn should_have_right_shape() {
         let data = TensorData::from([[3.0, 5.0, 6.0]]);
@@ -385,6 +385,18 @@ pub struct AttentionModuleOptions {
     /// passing an explicit lower-triangular bool mask because backends can use optimized
     /// kernel paths (e.g. flash attention with causal mode).
     pub is_causal: bool,
+
+    /// Local attention window, used by Mistral and similar models: each query position only
+    /// attends to the `sliding_window` key positions ending at its own position, so keys
+    /// `sliding_window` or more positions behind it are masked. Positions are aligned to the
+    /// bottom-right corner like `is_causal`, which is usually enabled alongside.
+    /// Must be positive when set.
+    pub sliding_window: Option<usize>,
+
+    /// ALiBi linear biases with the given maximum bias (8 in the paper), used by BLOOM, MPT and
+    /// similar models: `-slope_h * |i - j|` is added to the score of query position `i` and key
+    /// position `j`, with the geometric slope sequence of the paper over the query heads.
+    pub alibi_max_bias: Option<f64>,
 }
 
 /// Computation to be used to update the existing values in indexed assignment operations (scatter/select).
@@ -13,6 +13,10 @@ pub enum DType {
     Flex32,
     F16,
     BF16,
+    /// 8-bit float with 4 exponent and 3 mantissa bits, without infinities (`e4m3fn`).
+    F8E4M3,
+    /// 8-bit float with 5 exponent and 2 mantissa bits.
+    F8E5M2,
     I64,
     I32,
     I16,
@@ -23,6 +27,10 @@ pub enum DType {
     U8,
     Bool(BoolStore),
     QFloat(QuantScheme),
+    /// Complex number with `f64` real and imaginary parts, interleaved in memory.
+    Complex64,
+    /// Complex number with `f32` real and imaginary parts, interleaved in memory.
+    Complex32,
 }
 
 // Conversions between `DType` and cubecl's `ElemType` / `StorageType` are
@@ -40,6 +48,7 @@ impl DType {
             DType::Flex32 => core::mem::size_of::<f32>(),
             DType::F16 => core::mem::size_of::<f16>(),
             DType::BF16 => core::mem::size_of::<bf16>(),
+            DType::F8E4M3 | DType::F8E5M2 => core::mem::size_of::<u8>(),
             DType::I64 => core::mem::size_of::<i64>(),
             DType::I32 => core::mem::size_of::<i32>(),
             DType::I16 => core::mem::size_of::<i16>(),
@@ -72,15 +81,28 @@ impl DType {
                     _ => 0,
                 },
             },
+            DType::Complex64 => 2 * core::mem::size_of::<f64>(),
+            DType::Complex32 => 2 * core::mem::size_of::<f32>(),
         }
     }
     /// Returns true if the data type is a floating point type.
     pub fn is_float(&self) -> bool {
         matches!(
             self,
-            DType::F64 | DType::F32 | DType::Flex32 | DType::F16 | DType::BF16
+            DType::F64
+                | DType::F32
+                | DType::Flex32
+                | DType::F16
+                | DType::BF16
+                | DType::F8E4M3
+                | DType::F8E5M2
         )
     }
+
+    /// Returns true if the data type is an 8-bit float type.
+    pub fn is_fp8(&self) -> bool {
+        matches!(self, DType::F8E4M3 | DType::F8E5M2)
+    }
     /// Returns true if the data type is a signed integer type.
     pub fn is_int(&self) -> bool {
         matches!(self, DType::I64 | DType::I32 | DType::I16 | DType::I8)
@@ -95,6 +117,21 @@ impl DType {
         matches!(self, DType::Bool(_))
     }
 
+    /// Returns true if the data type is a complex type.
+    pub fn is_complex(&self) -> bool {
+        matches!(self, DType::Complex64 | DType::Complex32)
+    }
+
+    /// Returns the data type of the real and imaginary parts if this is a complex dtype, `None`
+    /// otherwise.
+    pub const fn complex_part(&self) -> Option<FloatDType> {
+        match self {
+            DType::Complex64 => Some(FloatDType::F64),
+            DType::Complex32 => Some(FloatDType::F32),
+            _ => None,
+        }
+    }
+
     /// Returns float precision info if this is a float dtype, `None` otherwise.
     ///
     /// Analogous to `torch.finfo(dtype)` or `numpy.finfo(dtype)`.
@@ -105,6 +142,8 @@ impl DType {
             DType::Flex32 => Some(FloatDType::Flex32.finfo()),
             DType::F16 => Some(FloatDType::F16.finfo()),
             DType::BF16 => Some(FloatDType::BF16.finfo()),
+            DType::F8E4M3 => Some(FloatDType::F8E4M3.finfo()),
+            DType::F8E5M2 => Some(FloatDType::F8E5M2.finfo()),
             _ => None,
         }
     }
@@ -117,6 +156,8 @@ impl DType {
             DType::Flex32 => "flex32",
             DType::F16 => "f16",
             DType::BF16 => "bf16",
+            DType::F8E4M3 => "f8e4m3",
+            DType::F8E5M2 => "f8e5m2",
             DType::I64 => "i64",
             DType::I32 => "i32",
             DType::I16 => "i16",
@@ -131,6 +172,8 @@ impl DType {
                 BoolStore::U32 => "bool(u32)",
             },
             DType::QFloat(_) => "qfloat",
+            DType::Complex64 => "complex64",
+            DType::Complex32 => "complex32",
         }
     }
 }
@@ -143,6 +186,8 @@ pub enum FloatDType {
     Flex32,
     F16,
     BF16,
+    F8E4M3,
+    F8E5M2,
 }
 
 /// Numerical precision properties for a floating-point dtype.
@@ -200,6 +245,29 @@ impl FloatDType {
                 min: bf16::MIN.to_f64_const(),
                 min_positive: bf16::MIN_POSITIVE.to_f64_const(), // ~1.175e-38
             },
+            FloatDType::F8E4M3 => FloatInfo {
+                epsilon: 0.125,
+                max: 448.0,
+                min: -448.0,
+                min_positive: 0.015625, // 2^-6
+            },
+            FloatDType::F8E5M2 => FloatInfo {
+                epsilon: 0.25,
+                max: 57344.0,
+                min: -57344.0,
+                min_positive: 6.103515625e-5, // 2^-14
+            },
+        }
+    }
+
+    /// Returns the complex data type with real and imaginary parts of this float type.
+    ///
+    /// Complex numbers are stored with at least `f32` parts, so the other types map to
+    /// [Complex32](DType::Complex32).
+    pub const fn complex(self) -> DType {
+        match self {
+            FloatDType::F64 => DType::Complex64,
+            _ => DType::Complex32,
         }
     }
 }
@@ -212,6 +280,8 @@ impl From<DType> for FloatDType {
             DType::Flex32 => FloatDType::Flex32,
             DType::F16 => FloatDType::F16,
             DType::BF16 => FloatDType::BF16,
+            DType::F8E4M3 => FloatDType::F8E4M3,
+            DType::F8E5M2 => FloatDType::F8E5M2,
             _ => panic!("Expected float data type, got {value:?}"),
         }
     }
@@ -225,6 +295,8 @@ impl From<FloatDType> for DType {
             FloatDType::Flex32 => DType::Flex32,
             FloatDType::F16 => DType::F16,
             FloatDType::BF16 => DType::BF16,
+            FloatDType::F8E4M3 => DType::F8E4M3,
+            FloatDType::F8E5M2 => DType::F8E5M2,
         }
     }
 }
@@ -390,6 +462,8 @@ mod tests {
             FloatDType::F16,
             FloatDType::BF16,
             FloatDType::Flex32,
+            FloatDType::F8E4M3,
+            FloatDType::F8E5M2,
         ] {
             let info = dtype.finfo();
             assert!(info.epsilon > 0.0, "{dtype:?}: epsilon must be positive");
@@ -405,4 +479,37 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn finfo_fp8_matches_encoding() {
+        use crate::{e4m3, e5m2};
+
+        let info = FloatDType::F8E4M3.finfo();
+        for value in [info.max, 1.0 + info.epsilon, info.min_positive] {
+            assert_eq!(e4m3::from_f32(value as f32).to_f32() as f64, value);
+        }
+
+        let info = FloatDType::F8E5M2.finfo();
+        for value in [info.max, 1.0 + info.epsilon, info.min_positive] {
+            assert_eq!(e5m2::from_f32(value as f32).to_f32() as f64, value);
+        }
+
+        assert_eq!(DType::F8E4M3.size(), 1);
+        assert!(DType::F8E5M2.is_float());
+        assert!(DType::F8E5M2.is_fp8());
+        assert!(!DType::BF16.is_fp8());
+    }
+
+    #[test]
+    fn complex_dtype_parts() {
+        assert_eq!(DType::Complex64.size(), 16);
+        assert_eq!(DType::Complex32.size(), 8);
+        assert_eq!(DType::Complex64.complex_part(), Some(FloatDType::F64));
+        assert_eq!(DType::Complex32.complex_part(), Some(FloatDType::F32));
+        assert_eq!(FloatDType::F64.complex(), DType::Complex64);
+        assert_eq!(FloatDType::F16.complex(), DType::Complex32);
+        assert!(DType::Complex32.is_complex());
+        assert!(!DType::Complex32.is_float());
+        assert!(DType::F32.complex_part().is_none());
+    }
 }
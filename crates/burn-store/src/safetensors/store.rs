@@ -1051,6 +1051,8 @@ fn safetensor_dtype_to_burn(dtype: safetensors::Dtype) -> Result<DType, Safetens
         Dtype::F32 => Ok(DType::F32),
         Dtype::F16 => Ok(DType::F16),
         Dtype::BF16 => Ok(DType::BF16),
+        Dtype::F8_E4M3 => Ok(DType::F8E4M3),
+        Dtype::F8_E5M2 => Ok(DType::F8E5M2),
         Dtype::I64 => Ok(DType::I64),
         Dtype::I32 => Ok(DType::I32),
         Dtype::I16 => Ok(DType::I16),
@@ -1075,6 +1077,8 @@ fn dtype_to_safetensors(dtype: DType) -> Result<safetensors::Dtype, SafetensorsS
         DType::F32 | DType::Flex32 => Ok(Dtype::F32), // Flex32 is stored as F32
         DType::F16 => Ok(Dtype::F16),
         DType::BF16 => Ok(Dtype::BF16),
+        DType::F8E4M3 => Ok(Dtype::F8_E4M3),
+        DType::F8E5M2 => Ok(Dtype::F8_E5M2),
         DType::I64 => Ok(Dtype::I64),
         DType::I32 => Ok(Dtype::I32),
         DType::I16 => Ok(Dtype::I16),
@@ -1091,5 +1095,8 @@ fn dtype_to_safetensors(dtype: DType) -> Result<safetensors::Dtype, SafetensorsS
         DType::QFloat(_) => Err(SafetensorsStoreError::Other(
             "Quantized tensors not yet supported in safetensors".to_string(),
         )),
+        DType::Complex64 | DType::Complex32 => Err(SafetensorsStoreError::Other(
+            "Complex tensors not yet supported in safetensors".to_string(),
+        )),
     }
 }
@@ -4,7 +4,7 @@ use burn_core::module::{Module, Param, ParamId};
 use burn_core::tensor::{Bool, Device, Int, Tensor};
 use burn_nn as nn;
 
-use crate::{ModuleSnapshot, SafetensorsStore};
+use crate::{ModuleSnapshot, ModuleStore, SafetensorsStore};
 
 /// Simple model with different data types for testing
 #[derive(Module, Debug)]
@@ -197,6 +197,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fp8_round_trip() {
+        use burn::tensor::{DType, FloatDType, TensorData};
+
+        let device = Device::default();
+
+        #[derive(Module, Debug)]
+        struct Fp8Model {
+            e4m3: Param<Tensor<1>>,
+            e5m2: Param<Tensor<1>>,
+        }
+
+        impl Fp8Model {
+            fn new(values: [f32; 4], device: &Device) -> Self {
+                let tensor = Tensor::<1>::from_floats(values, device);
+                Self {
+                    e4m3: Param::from_tensor(tensor.clone().cast(FloatDType::F8E4M3)),
+                    e5m2: Param::from_tensor(tensor.cast(FloatDType::F8E5M2)),
+                }
+            }
+        }
+
+        // Values exactly representable in both FP8 formats.
+        let model = Fp8Model::new([1.0, -2.0, 0.5, 96.0], &device);
+
+        let mut save_store = SafetensorsStore::from_bytes(None);
+        model.save_into(&mut save_store).expect("Failed to save");
+        let bytes = save_store.get_bytes().expect("Failed to get bytes");
+
+        let mut load_store = SafetensorsStore::from_bytes(Some(bytes));
+        let snapshots = load_store.get_all_snapshots().expect("Failed to read");
+        assert_eq!(snapshots["e4m3"].dtype, DType::F8E4M3);
+        assert_eq!(snapshots["e5m2"].dtype, DType::F8E5M2);
+
+        let mut loaded_model = Fp8Model::new([0.0; 4], &device);
+        loaded_model
+            .load_from(&mut load_store)
+            .expect("Failed to load");
+
+        let expected = TensorData::from([1.0f32, -2.0, 0.5, 96.0]);
+        for (param, dtype) in [
+            (&loaded_model.e4m3, DType::F8E4M3),
+            (&loaded_model.e5m2, DType::F8E5M2),
+        ] {
+            let data = param.val().into_data();
+            assert_eq!(data.dtype, dtype, "FP8 dtype not preserved");
+            data.convert::<f32>().assert_eq(&expected, true);
+        }
+    }
+
     #[test]
     fn test_mixed_precision_floats() {
         // Note: While SafeTensors format supports storing tensors with different precisions
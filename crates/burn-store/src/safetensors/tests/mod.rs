@@ -7,6 +7,8 @@ mod filtering;
 mod integration;
 mod metadata;
 mod mixed_datatypes;
+#[cfg(feature = "std")]
+mod moe_import;
 mod multi_layer_verify;
 mod pytorch_import;
 mod round_trip;
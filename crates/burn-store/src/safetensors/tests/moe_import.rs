@@ -0,0 +1,141 @@
+//! Tests for loading Mixture of Experts checkpoints with PyTorch naming
+use burn_core as burn;
+
+use crate::{BurnToPyTorchAdapter, ModuleSnapshot, PyTorchToBurnAdapter, SafetensorsStore};
+use burn_core::module::Module;
+use burn_core::tensor::{Device, Distribution, Tensor, Tolerance};
+use burn_nn::transformer::{MixtureOfExperts, MoeExpert, MoeExperts};
+use burn_nn::{Linear, LinearConfig};
+
+const D_MODEL: usize = 8;
+const D_FF: usize = 16;
+const N_EXPERTS: usize = 4;
+
+/// An expert of a Mixtral checkpoint, with `w1` (gate), `w2` (down) and `w3` (up).
+#[derive(Module, Debug)]
+struct MixtralExpert {
+    w1: Linear,
+    w2: Linear,
+    w3: Linear,
+}
+
+#[derive(Module, Debug)]
+struct MixtralSparseMoe {
+    gate: Linear,
+    experts: Vec<MixtralExpert>,
+}
+
+#[derive(Module, Debug)]
+struct MixtralLayer {
+    block_sparse_moe: MixtralSparseMoe,
+}
+
+/// The Mixtral layer with Burn experts, which are stacked into a [MixtureOfExperts] once loaded.
+#[derive(Module, Debug)]
+struct BurnSparseMoe {
+    gate: Linear,
+    experts: Vec<MoeExpert>,
+}
+
+#[derive(Module, Debug)]
+struct BurnLayer {
+    block_sparse_moe: BurnSparseMoe,
+}
+
+impl MixtralLayer {
+    fn new(device: &Device) -> Self {
+        let linear = |d_input, d_output| {
+            LinearConfig::new(d_input, d_output)
+                .with_bias(false)
+                .init(device)
+        };
+
+        Self {
+            block_sparse_moe: MixtralSparseMoe {
+                gate: linear(D_MODEL, N_EXPERTS),
+                experts: (0..N_EXPERTS)
+                    .map(|_| MixtralExpert {
+                        w1: linear(D_MODEL, D_FF),
+                        w2: linear(D_FF, D_MODEL),
+                        w3: linear(D_MODEL, D_FF),
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+#[test]
+fn mixtral_experts_load_into_mixture_of_experts() {
+    let device = Default::default();
+    let mixtral = MixtralLayer::new(&device);
+
+    // Saved with the PyTorch layout, like a Mixtral checkpoint.
+    let mut save_store = SafetensorsStore::from_bytes(None).with_to_adapter(BurnToPyTorchAdapter);
+    mixtral.save_into(&mut save_store).unwrap();
+
+    let mut load_store = SafetensorsStore::from_bytes(None)
+        .with_from_adapter(PyTorchToBurnAdapter)
+        .with_key_remapping(r"\.w1\.", ".gate_proj.")
+        .with_key_remapping(r"\.w2\.", ".down_proj.")
+        .with_key_remapping(r"\.w3\.", ".up_proj.");
+    if let SafetensorsStore::Memory(ref mut p) = load_store
+        && let SafetensorsStore::Memory(ref p_save) = save_store
+    {
+        p.set_data(p_save.data().unwrap().as_ref().clone());
+    }
+
+    let linear = |d_input, d_output| {
+        LinearConfig::new(d_input, d_output)
+            .with_bias(false)
+            .init(&device)
+    };
+    let mut layer = BurnLayer {
+        block_sparse_moe: BurnSparseMoe {
+            gate: linear(D_MODEL, N_EXPERTS),
+            experts: (0..N_EXPERTS)
+                .map(|_| MoeExpert {
+                    gate_proj: linear(D_MODEL, D_FF),
+                    up_proj: linear(D_MODEL, D_FF),
+                    down_proj: linear(D_FF, D_MODEL),
+                })
+                .collect(),
+        },
+    };
+    let result = layer.load_from(&mut load_store).unwrap();
+    assert!(result.is_success());
+    assert_eq!(result.applied.len(), 1 + 3 * N_EXPERTS);
+
+    let BurnSparseMoe { gate, experts } = layer.block_sparse_moe;
+    let moe = MixtureOfExperts {
+        gate,
+        experts: MoeExperts::from_experts(&experts),
+        shared_experts: None,
+        top_k: 2,
+        capacity_factor: None,
+        normalize_gates: true,
+    };
+
+    // Same routing and experts as Mixtral.
+    let source = &mixtral.block_sparse_moe;
+    let input = Tensor::<2>::random([5, D_MODEL], Distribution::Default, &device);
+    moe.gate
+        .forward(input.clone())
+        .into_data()
+        .assert_approx_eq::<f32>(
+            &source.gate.forward(input.clone()).into_data(),
+            Tolerance::default(),
+        );
+
+    for (e, source) in source.experts.iter().enumerate() {
+        let expected = source.w2.forward(
+            burn::tensor::activation::silu(source.w1.forward(input.clone()))
+                * source.w3.forward(input.clone()),
+        );
+        moe.experts
+            .expert(e)
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq::<f32>(&expected.into_data(), Tolerance::default());
+    }
+}
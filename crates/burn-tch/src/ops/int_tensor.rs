@@ -3,7 +3,7 @@ use std::ops::Range;
 use burn_backend::{
     BoolDType, Distribution, ExecutionError, FloatDType, IntDType, Scalar, Shape, TensorData,
     TensorMetadata,
-    ops::{FloatTensorOps, IntTensorOps},
+    ops::{FloatTensorOps, IntTensorOps, sampling::random_default},
     tensor::IntTensor,
 };
 
@@ -426,6 +426,10 @@ impl IntTensorOps<Self> for LibTorch {
                 let mut tensor = TchTensor::empty(shape, *device, dtype.into());
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            distribution => Self::float_into_int(
+                random_default::<Self>(shape, distribution, device, FloatDType::F32),
+                dtype,
+            ),
         }
     }
 
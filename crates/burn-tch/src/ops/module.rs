@@ -4,7 +4,10 @@ use burn_backend::{
     ops::{
         AttentionModuleOptions, ConvOptions, ConvTransposeOptions, DeformConv2dBackward,
         DeformConvOptions, InterpolateMode, InterpolateOptions, MaxPool1dWithIndices,
-        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, attention::attention_fallback,
+        MaxPool2dBackward, MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices,
+        ModuleOps,
+        attention::attention_fallback,
+        interpolate::{interpolate3d_backward_from_2d, interpolate3d_from_2d},
     },
     tensor::{FloatTensor, IntTensor},
 };
@@ -375,6 +378,126 @@ impl ModuleOps<Self> for LibTorch {
         MaxPool2dBackward::new(TchTensor::new(grad))
     }
 
+    fn avg_pool3d(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> TchTensor {
+        let tensor = tch::Tensor::avg_pool3d(
+            &x.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            ceil_mode,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d_backward(
+        x: TchTensor,
+        grad: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> TchTensor {
+        let tensor = tch::Tensor::avg_pool3d_backward(
+            &x.tensor,
+            &grad.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            ceil_mode,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d_with_indices(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        let (tensor, indices) = tch::Tensor::max_pool3d_with_indices(
+            &x.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            dilation.map(|e| e as i64),
+            ceil_mode,
+        );
+
+        MaxPool3dWithIndices::new(
+            TchTensor::new(tensor),
+            TchTensor::new(indices.to_kind(indices_dtype.into_kind())),
+        )
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: TchTensor,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        ceil_mode: bool,
+        output_grad: TchTensor,
+        indices: TchTensor,
+    ) -> MaxPool3dBackward<Self> {
+        let grad = tch::Tensor::max_pool3d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            dilation.map(|e| e as i64),
+            ceil_mode,
+            &indices.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
+    fn adaptive_max_pool3d_with_indices(
+        x: TchTensor,
+        output_size: [usize; 3],
+        indices_dtype: IntDType,
+    ) -> MaxPool3dWithIndices<Self> {
+        let (tensor, indices) =
+            tch::Tensor::adaptive_max_pool3d(&x.tensor, output_size.map(|e| e as i64));
+
+        MaxPool3dWithIndices::new(
+            TchTensor::new(tensor),
+            TchTensor::new(indices.to_kind(indices_dtype.into_kind())),
+        )
+    }
+
+    fn adaptive_max_pool3d_with_indices_backward(
+        x: TchTensor,
+        output_grad: TchTensor,
+        indices: TchTensor,
+    ) -> MaxPool3dBackward<Self> {
+        let grad = tch::Tensor::adaptive_max_pool3d_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            &indices.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
     fn adaptive_avg_pool2d(x: TchTensor, output_size: [usize; 2]) -> TchTensor {
         let tensor = tch::Tensor::adaptive_avg_pool2d(&x.tensor, output_size.map(|e| e as i64));
 
@@ -482,6 +605,65 @@ impl ModuleOps<Self> for LibTorch {
         TchTensor::new(tensor)
     }
 
+    fn interpolate3d(
+        x: TchTensor,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> TchTensor {
+        let size = output_size.map(|e| e as i64);
+
+        let tensor = match options.mode {
+            InterpolateMode::Nearest => {
+                tch::Tensor::upsample_nearest3d(&x.tensor, size, None, None, None)
+            }
+            InterpolateMode::Bilinear => tch::Tensor::upsample_trilinear3d(
+                &x.tensor,
+                size,
+                options.align_corners,
+                None,
+                None,
+                None,
+            ),
+            _ => return interpolate3d_from_2d::<Self>(x, output_size, options),
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn interpolate3d_backward(
+        x: TchTensor,
+        grad: TchTensor,
+        output_size: [usize; 3],
+        options: InterpolateOptions,
+    ) -> TchTensor {
+        let size = output_size.map(|e| e as i64);
+        let [n, c, d_in, h_in, w_in] = x.shape().dims();
+        let input_size = [n, c, d_in, h_in, w_in].map(|e| e as i64);
+
+        let tensor = match options.mode {
+            InterpolateMode::Nearest => tch::Tensor::upsample_nearest3d_backward(
+                &grad.tensor,
+                size,
+                input_size,
+                None,
+                None,
+                None,
+            ),
+            InterpolateMode::Bilinear => tch::Tensor::upsample_trilinear3d_backward(
+                &grad.tensor,
+                size,
+                input_size,
+                options.align_corners,
+                None,
+                None,
+                None,
+            ),
+            _ => return interpolate3d_backward_from_2d::<Self>(x, grad, output_size, options),
+        };
+
+        TchTensor::new(tensor)
+    }
+
     fn attention(
         query: TchTensor,
         key: TchTensor,
@@ -490,7 +672,11 @@ impl ModuleOps<Self> for LibTorch {
         attn_bias: Option<TchTensor>,
         options: AttentionModuleOptions,
     ) -> TchTensor {
-        if attn_bias.is_some() {
+        // LibTorch's scaled dot-product attention has no sliding window or ALiBi.
+        if attn_bias.is_some()
+            || options.sliding_window.is_some()
+            || options.alibi_max_bias.is_some()
+        {
             return attention_fallback::<Self>(query, key, value, mask, attn_bias, options);
         }
 
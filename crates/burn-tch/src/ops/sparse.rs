@@ -0,0 +1,4 @@
+use crate::LibTorch;
+use burn_backend::ops::SparseTensorOps;
+
+impl SparseTensorOps<Self> for LibTorch {}
@@ -1,6 +1,7 @@
 use super::TchOps;
 use crate::{IntoKind, LibTorch, LibTorchDevice, TchShape, TchTensor};
 use burn_backend::backend::ExecutionError;
+use burn_backend::ops::sampling::random_default;
 use burn_backend::tensor::{BoolTensor, FloatTensor, IntTensor};
 use burn_backend::{BoolDType, IntDType, Scalar, bf16, f16};
 use burn_backend::{
@@ -45,6 +46,7 @@ impl FloatTensorOps<Self> for LibTorch {
                 let mut tensor = TchTensor::empty(shape, *device, dtype.into());
                 tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
             }
+            distribution => random_default::<Self>(shape, distribution, device, dtype),
         }
     }
 
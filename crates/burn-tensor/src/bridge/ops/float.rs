@@ -693,6 +693,47 @@ impl Ordered for Float {
         }
     }
 
+    fn searchsorted(
+        sorted_sequence: BridgeTensor,
+        values: BridgeTensor,
+        right: bool,
+    ) -> BridgeTensor {
+        let int_dtype = values.device_settings().int_dtype;
+        BridgeTensor::int(Dispatch::float_searchsorted(
+            sorted_sequence.into_float(),
+            values.into_float(),
+            right,
+            int_dtype,
+        ))
+    }
+
+    async fn unique(
+        tensor: BridgeTensor,
+        sorted: bool,
+    ) -> (BridgeTensor, BridgeTensor, BridgeTensor) {
+        let int_dtype = tensor.device_settings().int_dtype;
+        let (values, inverse, counts) =
+            Dispatch::float_unique(tensor.into_float(), sorted, int_dtype).await;
+        (
+            BridgeTensor::float(values),
+            BridgeTensor::int(inverse),
+            BridgeTensor::int(counts),
+        )
+    }
+
+    async fn unique_consecutive(
+        tensor: BridgeTensor,
+    ) -> (BridgeTensor, BridgeTensor, BridgeTensor) {
+        let int_dtype = tensor.device_settings().int_dtype;
+        let (values, inverse, counts) =
+            Dispatch::float_unique_consecutive(tensor.into_float(), int_dtype).await;
+        (
+            BridgeTensor::float(values),
+            BridgeTensor::int(inverse),
+            BridgeTensor::int(counts),
+        )
+    }
+
     fn cummin(tensor: BridgeTensor, dim: usize) -> BridgeTensor {
         let (kind, tensor) = tensor.into_parts();
         match kind {
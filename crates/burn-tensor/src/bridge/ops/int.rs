@@ -359,6 +359,41 @@ impl Ordered for Int {
         BridgeTensor::int(Dispatch::int_argsort(tensor.into(), dim, descending))
     }
 
+    fn searchsorted(
+        sorted_sequence: BridgeTensor,
+        values: BridgeTensor,
+        right: bool,
+    ) -> BridgeTensor {
+        BridgeTensor::int(Dispatch::int_searchsorted(
+            sorted_sequence.into(),
+            values.into(),
+            right,
+        ))
+    }
+
+    async fn unique(
+        tensor: BridgeTensor,
+        sorted: bool,
+    ) -> (BridgeTensor, BridgeTensor, BridgeTensor) {
+        let (values, inverse, counts) = Dispatch::int_unique(tensor.into(), sorted).await;
+        (
+            BridgeTensor::int(values),
+            BridgeTensor::int(inverse),
+            BridgeTensor::int(counts),
+        )
+    }
+
+    async fn unique_consecutive(
+        tensor: BridgeTensor,
+    ) -> (BridgeTensor, BridgeTensor, BridgeTensor) {
+        let (values, inverse, counts) = Dispatch::int_unique_consecutive(tensor.into()).await;
+        (
+            BridgeTensor::int(values),
+            BridgeTensor::int(inverse),
+            BridgeTensor::int(counts),
+        )
+    }
+
     fn cummin(tensor: BridgeTensor, dim: usize) -> BridgeTensor {
         BridgeTensor::int(Dispatch::int_cummin(tensor.into(), dim))
     }
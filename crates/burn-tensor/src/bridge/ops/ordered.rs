@@ -87,6 +87,79 @@ pub(crate) trait Ordered: Numeric {
     /// function, which is more high-level and designed for public use.
     fn argsort(tensor: BridgeTensor, dim: usize, descending: bool) -> BridgeTensor;
 
+    /// Finds the indices where the `values` should be inserted to keep the `sorted_sequence`
+    /// sorted along its last dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The tensor sorted in ascending order along its last dimension.
+    /// * `values` - The values to insert.
+    /// * `right` - If true, returns the last suitable index instead of the first one.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same shape as `values`.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [`Tensor::searchsorted`](crate::Tensor::searchsorted)
+    /// function, which is more high-level and designed for public use.
+    fn searchsorted(
+        sorted_sequence: BridgeTensor,
+        values: BridgeTensor,
+        right: bool,
+    ) -> BridgeTensor;
+
+    /// Returns the unique elements of the flattened `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `sorted` - Whether to return the unique elements in ascending order instead of the order
+    ///   of their first occurrence.
+    ///
+    /// # Returns
+    ///
+    /// The 1D tensor of unique elements, the int tensor of inverse indices with the shape of the
+    /// input, and the 1D int tensor of counts.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [`Tensor::unique`](crate::Tensor::unique)
+    /// function, which is more high-level and designed for public use.
+    fn unique(
+        tensor: BridgeTensor,
+        sorted: bool,
+    ) -> impl Future<Output = (BridgeTensor, BridgeTensor, BridgeTensor)> + Send;
+
+    /// Collapses the consecutive equal elements of the flattened `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// The 1D tensor of collapsed elements, the int tensor of inverse indices with the shape of
+    /// the input, and the 1D int tensor of counts.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [`Tensor::unique_consecutive`](crate::Tensor::unique_consecutive)
+    /// function, which is more high-level and designed for public use.
+    fn unique_consecutive(
+        tensor: BridgeTensor,
+    ) -> impl Future<Output = (BridgeTensor, BridgeTensor, BridgeTensor)> + Send;
+
     /// Computes the cumulative minimum of elements along a dimension.
     ///
     /// # Arguments
@@ -2924,6 +2924,7 @@ impl DataIterFmt {
             DType::F32 | DType::Flex32 => fmt_float(self.next_elem::<f32>(), self.precision),
             DType::F16 => fmt_float(self.next_elem::<burn_std::f16>(), self.precision),
             DType::BF16 => fmt_float(self.next_elem::<burn_std::bf16>(), self.precision),
+            DType::F8E4M3 | DType::F8E5M2 => fmt_float(self.next_elem::<f32>(), self.precision),
             DType::I64 => fmt_elem(self.next_elem::<i64>()),
             DType::I32 => fmt_elem(self.next_elem::<i32>()),
             DType::I16 => fmt_elem(self.next_elem::<i16>()),
@@ -2938,6 +2939,7 @@ impl DataIterFmt {
                 burn_std::BoolStore::U32 => fmt_elem(self.next_elem::<u32>().to_bool()),
             },
             DType::QFloat(_) => todo!(), // unreachable but we should fix that
+            DType::Complex64 | DType::Complex32 => unreachable!(),
         }
     }
 
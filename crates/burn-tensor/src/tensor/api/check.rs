@@ -1,5 +1,6 @@
 use crate::bridge::{BasicOps, Ordered};
-use crate::{DType, Shape, Slice, Tensor, cast::ToElement};
+use crate::ops::InterpolateMode;
+use crate::{DType, Distribution, FloatDType, Shape, Slice, Tensor, cast::ToElement};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -503,6 +504,27 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn linear_recurrence<const D: usize, K>(a: &Tensor<D, K>, b: &Tensor<D, K>) -> Self
+    where
+        K: BasicOps,
+    {
+        let mut check = Self::Ok.binary_ops_device("Linear Recurrence", &a.device(), &b.device());
+
+        if a.shape() != b.shape() {
+            check = check.register(
+                "Linear Recurrence",
+                TensorError::new("The decay factors and the inputs must have the same shape.")
+                    .details(format!(
+                        "Decay factors shape {:?}, inputs shape {:?}.",
+                        a.shape(),
+                        b.shape()
+                    )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn cross<const D: usize, K>(
         lhs: &Tensor<D, K>,
         rhs: &Tensor<D, K>,
@@ -807,6 +829,42 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn interpolate_antialias(mode: &InterpolateMode) -> Self {
+        let mut check = Self::Ok;
+
+        if !matches!(
+            mode,
+            InterpolateMode::Bilinear | InterpolateMode::Bicubic | InterpolateMode::Lanczos3
+        ) {
+            check = check.register(
+                "Interpolate",
+                TensorError::new(
+                    "Antialiasing is only supported with the bilinear, bicubic and lanczos3 modes.",
+                )
+                .details(format!("Got the {mode:?} mode.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn segment_reduce(shape: &Shape, shape_ids: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.num_dims() == 0 || shape_ids[0] != shape[0] {
+            check = check.register(
+                "Segment Reduce",
+                TensorError::new("The segment ids should have one entry per row of the tensor.")
+                    .details(format!(
+                        "Got {} segment ids for a tensor of shape {:?}.",
+                        shape_ids[0], shape
+                    )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn scatter_nd<const D: usize, const M: usize, const DV: usize>(
         data_shape: &Shape,
         indices_shape: &Shape,
@@ -1352,6 +1410,19 @@ impl TensorCheck {
         Self::lu_input_tensor::<D>(ops, dims, dtype)
     }
 
+    /// Check if the einsum equation was successfully parsed and validated against the operands.
+    pub(crate) fn einsum<T>(equation: &Result<T, String>) -> Self {
+        match equation {
+            Ok(_) => Self::Ok,
+            Err(reason) => Self::Ok.register("Einsum", TensorError::new(reason.clone())),
+        }
+    }
+
+    /// Check if input tensor for singular value decomposition is valid
+    pub fn svd_input_tensor<const D: usize>(ops: &str, dims: &[usize], dtype: DType) -> Self {
+        Self::lu_input_tensor::<D>(ops, dims, dtype)
+    }
+
     /// Check if input tensor and generic parameters of `linalg::det()` are valid.
     pub fn det<const D: usize, const D1: usize, const D2: usize>(
         dims: [usize; D],
@@ -1407,6 +1478,70 @@ impl TensorCheck {
         check
     }
 
+    /// Check if the input tensor is a valid batch of square matrices.
+    pub fn square_matrix_input<const D: usize>(ops: &str, dims: &[usize], dtype: DType) -> Self {
+        let mut check = Self::lu_input_tensor::<D>(ops, dims, dtype);
+
+        if D >= 2 && dims[D - 1] != dims[D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("The last two dimensions of the input tensor must be equal")
+                    .details(format!("Got input tensor with shape {:?}", dims)),
+            );
+        }
+
+        check
+    }
+
+    /// Check if the coefficient and right-hand side tensors of a linear system are compatible.
+    ///
+    /// `left` is true when solving `A X = B` and false when solving `X A = B`.
+    pub fn linear_system_input<const D: usize>(
+        ops: &str,
+        a_dims: &[usize],
+        b_dims: &[usize],
+        left: bool,
+        dtype: DType,
+    ) -> Self {
+        let mut check = Self::square_matrix_input::<D>(ops, a_dims, dtype);
+
+        if D < 2 {
+            return check;
+        }
+
+        let (b_n, b_dim) = if left {
+            (b_dims[D - 2], D - 2)
+        } else {
+            (b_dims[D - 1], D - 1)
+        };
+        if b_n != a_dims[D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The right-hand side must have as many rows (or columns) as the coefficient matrix",
+                )
+                .details(format!(
+                    "Got coefficient shape {:?} and right-hand side shape {:?} (dim {b_dim})",
+                    a_dims, b_dims
+                )),
+            );
+        }
+
+        if a_dims[..D - 2] != b_dims[..D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("The batch dimensions of both tensors must be equal").details(
+                    format!(
+                        "Got coefficient shape {:?} and right-hand side shape {:?}",
+                        a_dims, b_dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn topk(op: &str, k: usize, dim: usize, shape: &Shape) -> Self {
         let mut check = Self::Ok;
 
@@ -1422,6 +1557,395 @@ impl TensorCheck {
 
         check
     }
+
+    pub(crate) fn searchsorted(op: &str, sorted_shape: &Shape, values_shape: &Shape) -> Self {
+        let mut check = Self::Ok;
+        let rank = values_shape.num_dims();
+
+        if sorted_shape.num_dims() != rank || sorted_shape[..rank - 1] != values_shape[..rank - 1] {
+            check = check.register(
+                op,
+                TensorError::new(
+                    "The sorted sequence must have the same leading dimensions as the values.",
+                )
+                .details(format!(
+                    "Got sorted sequence shape {:?} and values shape {:?}.",
+                    sorted_shape.as_slice(),
+                    values_shape.as_slice(),
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histc(bins: usize, min: f64, max: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histc",
+                TensorError::new("The number of bins must be greater than zero."),
+            );
+        }
+
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            check = check.register(
+                "Histc",
+                TensorError::new("The range must be finite with min < max.")
+                    .details(format!("Got min={min} and max={max}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bincount(length: usize, shape: &Shape, weights_shape: Option<&Shape>) -> Self {
+        let mut check = Self::Ok;
+
+        if length == 0 {
+            check = check.register(
+                "Bincount",
+                TensorError::new("The number of bins must be greater than zero."),
+            );
+        }
+
+        if let Some(weights_shape) = weights_shape
+            && weights_shape != shape
+        {
+            check = check.register(
+                "Bincount",
+                TensorError::new("The weights must have the same shape as the tensor.").details(
+                    format!(
+                        "Got tensor shape {:?} and weights shape {:?}.",
+                        shape.as_slice(),
+                        weights_shape.as_slice(),
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantile(ops: &str, q: f64, dim: usize, shape: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if !(0.0..=1.0).contains(&q) {
+            check = check.register(
+                ops,
+                TensorError::new("The quantile must be between 0 and 1.")
+                    .details(format!("Got q={q}.")),
+            );
+        }
+
+        if shape[dim] == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("Can't compute the quantile of an empty dimension.")
+                    .details(format!("Dimension {dim} of shape {:?}.", shape.as_slice())),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn distribution(distribution: &Distribution) -> Self {
+        let mut check = Self::Ok;
+        let positive = |param: f64| param.is_finite() && param > 0.0;
+
+        match *distribution {
+            Distribution::Gamma(shape, scale) if !positive(shape) || !positive(scale) => {
+                check = check.register(
+                    "Random",
+                    TensorError::new(
+                        "The shape and scale of the gamma distribution must be finite and positive.",
+                    )
+                    .details(format!("Got shape {shape} and scale {scale}.")),
+                );
+            }
+            Distribution::Beta(alpha, beta) if !positive(alpha) || !positive(beta) => {
+                check = check.register(
+                    "Random",
+                    TensorError::new(
+                        "The parameters of the beta distribution must be finite and positive.",
+                    )
+                    .details(format!("Got alpha {alpha} and beta {beta}.")),
+                );
+            }
+            _ => {}
+        }
+
+        check
+    }
+
+    pub(crate) fn dirichlet(valid_concentrations: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if !valid_concentrations {
+            check = check.register(
+                "Dirichlet",
+                TensorError::new("The concentrations must be finite and positive."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial<const D: usize>(
+        num_samples: usize,
+        replacement: bool,
+        shape: &Shape,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let num_categories = shape[D - 1];
+
+        if num_categories == 0 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("Can't sample from a distribution without categories.")
+                    .details(format!("Shape {:?}.", shape.as_slice())),
+            );
+        }
+
+        if !replacement && num_samples > num_categories {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("Can't draw more samples than categories without replacement.")
+                    .details(format!(
+                        "Got {num_samples} samples for {num_categories} categories."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_coo<const D: usize>(indices: &Shape, values: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if indices[0] != D {
+            check = check.register(
+                "Sparse COO",
+                TensorError::new(format!(
+                    "The indices should have one row per dimension, but got {} rows for {D} \
+                     dimensions.",
+                    indices[0]
+                ))
+                .details(format!("Indices shape {:?}.", indices.as_slice())),
+            );
+        }
+
+        if indices[1] != values[0] {
+            check = check.register(
+                "Sparse COO",
+                TensorError::new("The indices and the values should have the same length.")
+                    .details(format!(
+                        "Indices shape {:?}, values shape {:?}.",
+                        indices.as_slice(),
+                        values.as_slice()
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_csr(
+        crow_indices: &Shape,
+        col_indices: &Shape,
+        values: &Shape,
+        shape: &Shape,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if crow_indices[0] != shape[0] + 1 {
+            check = check.register(
+                "Sparse CSR",
+                TensorError::new("The row offsets should have one more element than the rows.")
+                    .details(format!(
+                        "Row offsets shape {:?}, shape {:?}.",
+                        crow_indices.as_slice(),
+                        shape.as_slice()
+                    )),
+            );
+        }
+
+        if col_indices[0] != values[0] {
+            check = check.register(
+                "Sparse CSR",
+                TensorError::new("The column indices and the values should have the same length.")
+                    .details(format!(
+                        "Column indices shape {:?}, values shape {:?}.",
+                        col_indices.as_slice(),
+                        values.as_slice()
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn spmm(sparse: &Shape, dense: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if sparse[1] != dense[0] {
+            check = check.register(
+                "Spmm",
+                TensorError::new(format!(
+                    "The inner dimension of spmm should be the same, but got {} and {}.",
+                    sparse[1], dense[0]
+                ))
+                .details(format!(
+                    "Sparse shape {:?}, dense shape {:?}.",
+                    sparse.as_slice(),
+                    dense.as_slice()
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fp8_dtype(ops: &str, dtype: FloatDType) -> Self {
+        let mut check = Self::Ok;
+
+        if !DType::from(dtype).is_fp8() {
+            check = check.register(
+                ops,
+                TensorError::new("Expected an 8-bit float data type.")
+                    .details(format!("Got {dtype:?}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn complex_parts(re: &Shape, im: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if re != im {
+            check = check.register(
+                "Complex",
+                TensorError::new("The real and imaginary parts should have the same shape.")
+                    .details(format!(
+                        "Real shape {:?}, imaginary shape {:?}.",
+                        re.as_slice(),
+                        im.as_slice()
+                    )),
+            );
+        }
+
+        check
+    }
+
+    #[cfg(feature = "autodiff")]
+    pub(crate) fn dual_parts(primal: &Shape, tangent: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if primal != tangent {
+            check = check.register(
+                "Dual",
+                TensorError::new("The primal and tangent should have the same shape.").details(
+                    format!(
+                        "Primal shape {:?}, tangent shape {:?}.",
+                        primal.as_slice(),
+                        tangent.as_slice()
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn custom_function_grad(index: usize, input: &Shape, grad: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if input != grad {
+            check = check.register(
+                "Custom Function",
+                TensorError::new(
+                    "The gradient of an input should have the same shape as the input.",
+                )
+                .details(format!(
+                    "Input {index} shape {:?}, gradient shape {:?}.",
+                    input.as_slice(),
+                    grad.as_slice()
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn custom_function_saved<const D: usize>(
+        index: usize,
+        num_saved: usize,
+        rank: Option<usize>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        match rank {
+            None => {
+                check = check.register(
+                    "Custom Function",
+                    TensorError::new("The saved tensor index is out of bounds.").details(format!(
+                        "Index {index}, number of saved tensors {num_saved}."
+                    )),
+                );
+            }
+            Some(rank) if rank != D => {
+                check = check.register(
+                    "Custom Function",
+                    TensorError::new("The saved tensor doesn't have the requested rank.").details(
+                        format!("Saved tensor {index} rank {rank}, requested rank {D}."),
+                    ),
+                );
+            }
+            Some(_) => {}
+        }
+
+        check
+    }
+
+    pub(crate) fn vjp_cotangent(output: &Shape, cotangent: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if output != cotangent {
+            check = check.register(
+                "Vjp",
+                TensorError::new("The cotangent should have the same shape as the output.")
+                    .details(format!(
+                        "Output shape {:?}, cotangent shape {:?}.",
+                        output.as_slice(),
+                        cotangent.as_slice()
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn complex_interleaved<const D: usize, const D2: usize>(shape: &Shape) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D + 1 || shape[D2 - 1] != 2 {
+            check = check.register(
+                "Complex",
+                TensorError::new(
+                    "Interleaved complex tensors should have one more dimension than the complex \
+                     tensor, of size 2.",
+                )
+                .details(format!(
+                    "Complex rank {D}, interleaved shape {:?}.",
+                    shape.as_slice()
+                )),
+            );
+        }
+
+        check
+    }
 }
 
 pub(crate) struct FailedTensorCheck {
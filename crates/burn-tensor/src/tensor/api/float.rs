@@ -10,6 +10,7 @@ use crate::kind::FloatMath;
 use crate::ops::{BridgeKind, BridgeTensor};
 use crate::quantization::{QuantScheme, QuantizationParameters};
 use crate::tensor::stats;
+use crate::tensor::stats::QuantileInterpolation;
 use crate::tensor::{Distribution, TensorData};
 use crate::{Bool, Float, Int, TensorPrimitive};
 #[cfg(feature = "std")]
@@ -25,6 +26,7 @@ use burn_backend::ops::GridSampleOptions;
 use burn_backend::ops::QTensorOps;
 use burn_backend::quantization::QuantizationParametersPrimitive;
 use burn_dispatch::Dispatch;
+use burn_std::FloatDType;
 use core::f32;
 
 /// Default RTOL value for `is_close` and `all_close`.
@@ -51,6 +53,109 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
         Self::new(erf_impl(self.primitive))
     }
 
+    /// Applies the inverse of the [error function](Self::erf) element wise.
+    ///
+    /// The input is expected to be in `[-1, 1]`, with `-1` and `1` mapped to negative and positive
+    /// infinity.
+    ///
+    #[cfg_attr(doc, doc = r#"$y_i = \text{erf}^{-1}\(x_i\)$"#)]
+    #[cfg_attr(not(doc), doc = "`y_i = erfinv(x_i)`")]
+    pub fn erfinv(self) -> Self {
+        Self::new(erfinv_impl(self.primitive))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the
+    /// [gamma function](https://en.wikipedia.org/wiki/Gamma_function) element wise.
+    ///
+    #[cfg_attr(doc, doc = r#"$y_i = \ln\|\Gamma\(x_i\)\|$"#)]
+    #[cfg_attr(not(doc), doc = "`y_i = ln|gamma(x_i)|`")]
+    pub fn lgamma(self) -> Self {
+        Self::new(lgamma_impl(self.primitive))
+    }
+
+    /// Applies the [digamma function](https://en.wikipedia.org/wiki/Digamma_function), the
+    /// logarithmic derivative of the gamma function, element wise.
+    ///
+    #[cfg_attr(
+        doc,
+        doc = r#"$y_i = \psi\(x_i\) = \frac{\Gamma'\(x_i\)}{\Gamma\(x_i\)}$"#
+    )]
+    #[cfg_attr(not(doc), doc = "`y_i = gamma'(x_i) / gamma(x_i)`")]
+    pub fn digamma(self) -> Self {
+        Self::new(digamma_impl(self.primitive))
+    }
+
+    /// Applies the [polygamma function](https://en.wikipedia.org/wiki/Polygamma_function) of
+    /// order `n`, the `n`-th derivative of the digamma function, element wise.
+    ///
+    /// `polygamma(0)` is the [digamma](Self::digamma) function.
+    ///
+    #[cfg_attr(doc, doc = r#"$y_i = \psi^{\(n\)}\(x_i\)$"#)]
+    #[cfg_attr(not(doc), doc = "`y_i = digamma^(n)(x_i)`")]
+    pub fn polygamma(self, n: usize) -> Self {
+        Self::new(polygamma_impl(self.primitive, n))
+    }
+
+    /// Applies the [modified Bessel function](https://en.wikipedia.org/wiki/Bessel_function#Modified_Bessel_functions:_I%CE%B1,_K%CE%B1)
+    /// of the first kind of order 0 element wise.
+    ///
+    #[cfg_attr(doc, doc = r#"$y_i = I_0\(x_i\)$"#)]
+    #[cfg_attr(not(doc), doc = "`y_i = I0(x_i)`")]
+    pub fn i0(self) -> Self {
+        Self::new(i0_impl(self.primitive))
+    }
+
+    /// Applies the [modified Bessel function](https://en.wikipedia.org/wiki/Bessel_function#Modified_Bessel_functions:_I%CE%B1,_K%CE%B1)
+    /// of the first kind of order 1 element wise.
+    ///
+    #[cfg_attr(doc, doc = r#"$y_i = I_1\(x_i\)$"#)]
+    #[cfg_attr(not(doc), doc = "`y_i = I1(x_i)`")]
+    pub fn i1(self) -> Self {
+        Self::new(i1_impl(self.primitive))
+    }
+
+    /// Computes `x * log(y)` element wise, with `self` as `x` and `other` as `y`.
+    ///
+    /// The result is zero where `x` is zero, even when `y` is zero, unless `y` is NaN. The
+    /// gradient with respect to `y` is zero there as well, so it can be used for entropies and
+    /// KL divergences of distributions with zero probabilities.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor `y`, with the same shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let x = Tensor::<1>::from_data([0.0, 2.0, 0.0], &device);
+    /// let y = Tensor::<1>::from_data([0.0, 1.0, f32::NAN], &device);
+    /// let tensor = x.xlogy(y);
+    /// println!("{tensor}");
+    /// // [0.0, 0.0, NaN]
+    /// ```
+    pub fn xlogy(self, other: Self) -> Self {
+        let (zero, nan) = (self.clone().equal_elem(0.0), other.clone().is_nan());
+        self.mul(other.mask_fill(zero, 1.0).log())
+            .mask_fill(nan, f64::NAN)
+    }
+
+    /// Computes `x * log(1 + y)` element wise, with `self` as `x` and `other` as `y`.
+    ///
+    /// The result is zero where `x` is zero, even when `y` is `-1`, unless `y` is NaN.
+    /// See [xlogy](Self::xlogy).
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor `y`, with the same shape as `self`.
+    pub fn xlog1py(self, other: Self) -> Self {
+        let (zero, nan) = (self.clone().equal_elem(0.0), other.clone().is_nan());
+        self.mul(other.mask_fill(zero, 0.0).log1p())
+            .mask_fill(nan, f64::NAN)
+    }
+
     /// Applies [hypotenuse operation](https://en.wikipedia.org/wiki/Hypotenuse) element wise.
     ///
     #[cfg_attr(doc, doc = r#"$y_i = \sqrt{x_i^2 + y_i^2}$"#)]
@@ -141,9 +246,104 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
     /// Returns a new tensor with the same shape, dtype, and device as the current tensor filled random
     /// values sampled from the given distribution.
     pub fn random_like(&self, distribution: Distribution) -> Self {
+        check!(TensorCheck::distribution(&distribution));
         Self::new(random_like_impl(&self.primitive, distribution))
     }
 
+    /// Samples category indices from the multinomial distribution defined by the last dimension.
+    ///
+    /// The last dimension holds the non-negative weights of the categories, which don't need to
+    /// be normalized. To sample from logits, apply [softmax](crate::activation::softmax) first.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of samples drawn for each distribution.
+    /// * `replacement` - Whether a category can be drawn more than once. Without replacement,
+    ///   `num_samples` can't exceed the number of categories with a non-zero weight.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same leading dimensions and `num_samples` indices in the last one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let weights = Tensor::<2>::from_data([[0.0, 1.0, 3.0], [1.0, 0.0, 0.0]], &device);
+    /// let samples = weights.multinomial(4, true);
+    /// println!("{samples}");
+    /// // [[2, 2, 1, 2], [0, 0, 0, 0]]
+    /// ```
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<D, Int> {
+        let shape = self.shape();
+        check!(TensorCheck::multinomial::<D>(
+            num_samples,
+            replacement,
+            &shape
+        ));
+        let num_categories = shape[D - 1];
+
+        if replacement {
+            // Inverse transform sampling on the normalized cumulative weights.
+            let cdf = self.cumsum(D - 1);
+            let total = cdf.clone().narrow(D - 1, num_categories - 1, 1);
+            let cdf = cdf / total;
+
+            let mut samples_shape = shape;
+            samples_shape[D - 1] = num_samples;
+            let uniform = Tensor::<D>::random(
+                samples_shape,
+                Distribution::Default,
+                (&cdf.device(), cdf.dtype()),
+            );
+            // Rounding can leave the last cumulative weight below one.
+            cdf.searchsorted(uniform, true)
+                .clamp_max(num_categories as i64 - 1)
+        } else {
+            // Gumbel top-k: the largest `log(w) + g` with `g ~ Gumbel(0, 1)` are distributed as
+            // successive draws without replacement.
+            let uniform = self.random_like(Distribution::Default);
+            let gumbel = uniform.log().neg().log().neg();
+            let (_, indices) = (self.log() + gumbel).topk_with_indices(num_samples, D - 1);
+            indices
+        }
+    }
+
+    /// Samples from the Dirichlet distributions whose concentrations are along the last
+    /// dimension.
+    ///
+    /// Each sample is made of independent `Gamma(alpha_i, 1)` samples, drawn like
+    /// [Distribution::Gamma], normalized by their sum.
+    ///
+    /// # Panics
+    ///
+    /// If a concentration isn't finite and positive, which reads the concentrations back from
+    /// the device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let concentration = Tensor::<2>::from_data([[1.0, 1.0, 1.0], [0.5, 2.0, 8.0]], &device);
+    /// let samples = concentration.dirichlet();
+    /// println!("{samples}");
+    /// // [[0.21, 0.52, 0.27], [0.01, 0.18, 0.81]]
+    /// ```
+    pub fn dirichlet(self) -> Self {
+        let valid = self
+            .clone()
+            .is_finite()
+            .bool_and(self.clone().greater_elem(0.0));
+        check!(TensorCheck::dirichlet(valid.all().into_scalar::<bool>()));
+
+        let gamma = Self::new(random_gamma_impl(self.primitive));
+        gamma.clone() / gamma.sum_dim(D - 1)
+    }
+
     /// Calculate the variance along the given dimension.
     ///
     /// Negative dimensions are supported and count from the end.
@@ -341,6 +541,27 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
         Self::new(set_require_grad_impl(self.primitive, require_grad))
     }
 
+    /// Mark the tensor to receive per-sample gradients during the backward pass.
+    ///
+    /// The gradient of the tensor gets an extra leading dimension with the gradient of each
+    /// sample of the batch, the first dimension of the inputs, instead of their sum. This is
+    /// only supported when the tensor is used as the weight or the bias of the linear,
+    /// convolution and embedding operations.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    ///
+    /// # Panics
+    ///
+    /// If autodiff is enabled and the tensor doesn't require gradients.
+    pub fn set_per_sample_grad(self) -> Self {
+        Self::new(set_per_sample_grad_impl(self.primitive))
+    }
+
+    /// Returns true if the tensor receives per-sample gradients during the backward pass.
+    pub fn is_per_sample_grad(&self) -> bool {
+        is_per_sample_grad_impl(&self.primitive)
+    }
+
     /// Applies the relu function to the tensor.
     pub(crate) fn relu(self) -> Self {
         Self::new(relu_impl(self.primitive))
@@ -364,6 +585,158 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
             .div_scalar(n as f32 - correction_factor as f32)
     }
 
+    /// Calculate a batch of covariance matrices between different entries alongside a given
+    /// dimension.
+    ///
+    /// The observations are along `dim` and the variables along the last remaining dimension,
+    /// the other dimensions are batch dimensions. A tensor of shape `[..., variables, n]` (with
+    /// `dim` the last one) gives a `[..., variables, variables]` covariance matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension of the observations.
+    ///   Negative dimensions are supported and count from the end.
+    /// * `correction_factor` - Is usually 1 for samples and 0 for population.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<3>::from_data(
+    ///     [[[1.0, 2.0, 3.0], [3.0, 2.0, 1.0]], [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]],
+    ///     &device,
+    /// );
+    /// let cov = tensor.cov_batched(2, 1);
+    /// println!("{cov}");
+    /// // [[[1.0, -1.0], [-1.0, 1.0]], [[1.0, 2.0], [2.0, 4.0]]]
+    /// ```
+    pub fn cov_batched<I: AsIndex>(self, dim: I, correction_factor: usize) -> Tensor<D> {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "CovBatched");
+        stats::cov_batched(self, dim, correction_factor)
+    }
+
+    /// Calculate the Pearson correlation coefficients between different entries alongside a
+    /// given dimension.
+    ///
+    /// The layout is the same as [cov_batched](Tensor::cov_batched), and the coefficients are the covariances
+    /// normalized by the standard deviations of the variables, clamped to `[-1, 1]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension of the observations.
+    ///   Negative dimensions are supported and count from the end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1.0, 2.0, 3.0], [3.0, 2.0, 1.0]], &device);
+    /// let corr = tensor.corrcoef(1);
+    /// println!("{corr}");
+    /// // [[1.0, -1.0], [-1.0, 1.0]]
+    /// ```
+    pub fn corrcoef<I: AsIndex>(self, dim: I) -> Tensor<D> {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Corrcoef");
+        stats::corrcoef(self, dim)
+    }
+
+    /// Returns the `q`-th quantile of the elements along the given dimension.
+    ///
+    /// The result is NaN where the elements along `dim` contain NaN, see
+    /// [nanquantile](Tensor::nanquantile) to ignore them.
+    ///
+    /// # Note
+    ///
+    /// Like [median](Tensor::median), this performs a full sort along the specified dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile, between 0 and 1.
+    /// * `dim` - The dimension to reduce.
+    ///   Negative dimensions are supported and count from the end.
+    /// * `interpolation` - How to pick the value when the quantile lies between two elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{QuantileInterpolation, Tensor};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1.0, 4.0, 2.0, 3.0]], &device);
+    /// let quantile = tensor.clone().quantile(0.5, 1, QuantileInterpolation::Linear);
+    /// println!("{quantile}");
+    /// // [[2.5]]
+    /// let quantile = tensor.quantile(0.5, 1, QuantileInterpolation::Lower);
+    /// println!("{quantile}");
+    /// // [[2.0]]
+    /// ```
+    pub fn quantile<I: AsIndex>(
+        self,
+        q: f64,
+        dim: I,
+        interpolation: QuantileInterpolation,
+    ) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Quantile");
+        check!(TensorCheck::quantile("Quantile", q, dim, &self.shape()));
+        stats::quantile(self, q, dim, interpolation)
+    }
+
+    /// Returns the `q`-th quantile of the elements along the given dimension, ignoring NaN.
+    ///
+    /// The result is NaN where all the elements along `dim` are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile, between 0 and 1.
+    /// * `dim` - The dimension to reduce.
+    ///   Negative dimensions are supported and count from the end.
+    /// * `interpolation` - How to pick the value when the quantile lies between two elements.
+    pub fn nanquantile<I: AsIndex>(
+        self,
+        q: f64,
+        dim: I,
+        interpolation: QuantileInterpolation,
+    ) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Nanquantile");
+        check!(TensorCheck::quantile("Nanquantile", q, dim, &self.shape()));
+        stats::nanquantile(self, q, dim, interpolation)
+    }
+
+    /// Returns the most frequent value along the given dimension and its index.
+    ///
+    /// When several values are the most frequent, the smallest one is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce.
+    ///   Negative dimensions are supported and count from the end.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - A tensor with the most frequent values.
+    /// - A tensor with the index of one of their occurrences in the original tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[3.0, 1.0, 3.0, 2.0], [1.0, 2.0, 2.0, 1.0]], &device);
+    /// let (values, _indices) = tensor.mode(1);
+    /// println!("{values}");
+    /// // [[3.0], [1.0]]
+    /// ```
+    pub fn mode<I: AsIndex>(self, dim: I) -> (Self, Tensor<D, Int>) {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Mode");
+        stats::mode(self, dim)
+    }
+
     /// Convert the tensor to a lower precision data type based on the quantization scheme.
     ///
     /// # Arguments
@@ -409,6 +782,91 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
         Tensor::new(dequantize_impl(self.primitive))
     }
 
+    /// Casts the tensor to an 8-bit float data type with a per-tensor scale.
+    ///
+    /// The tensor is divided by `scale = max(|x|) / fp8_max`, so its largest magnitude maps to
+    /// the largest finite value of the FP8 type, and the values are clamped to the FP8 range
+    /// before the cast. This is the scaling used by FP8 checkpoints and training recipes, where
+    /// the scale is stored next to the FP8 values.
+    ///
+    /// # Arguments
+    ///
+    /// * `dtype` - The FP8 data type, [F8E4M3](FloatDType::F8E4M3) or
+    ///   [F8E5M2](FloatDType::F8E5M2).
+    ///
+    /// # Returns
+    ///
+    /// The FP8 tensor and the scale of shape `[1]`, in the data type of the input. The original
+    /// values are recovered with [from_fp8_scaled](Tensor::from_fp8_scaled).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{FloatDType, Tensor};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<1>::from_floats([0.5, -896.0], &device);
+    /// let (fp8, scale) = tensor.to_fp8_scaled(FloatDType::F8E4M3);
+    /// println!("{scale}"); // [2.0]
+    /// let tensor = fp8.from_fp8_scaled(scale, FloatDType::F32);
+    /// println!("{tensor}"); // [0.5, -896.0]
+    /// ```
+    pub fn to_fp8_scaled(self, dtype: FloatDType) -> (Tensor<D>, Tensor<1>) {
+        check!(TensorCheck::fp8_dtype("To FP8 Scaled", dtype));
+
+        let fp8_max = dtype.finfo().max;
+        let min_scale = FloatDType::from(self.dtype()).finfo().min_positive;
+        let scale = self
+            .clone()
+            .max_abs()
+            .div_scalar(fp8_max)
+            .clamp_min(min_scale);
+        let values = self
+            .div(scale.clone().unsqueeze())
+            .clamp(-fp8_max, fp8_max)
+            .cast(dtype);
+
+        (values, scale)
+    }
+
+    /// Casts an 8-bit float tensor back to a wider float data type, multiplying it by its
+    /// per-tensor scale.
+    ///
+    /// This is the inverse of [to_fp8_scaled](Tensor::to_fp8_scaled).
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale of shape `[1]`.
+    /// * `dtype` - The output float data type.
+    pub fn from_fp8_scaled(self, scale: Tensor<1>, dtype: FloatDType) -> Tensor<D> {
+        let scale = scale.cast(dtype);
+        self.cast(dtype).mul(scale.unsqueeze())
+    }
+
+    /// Performs the matrix multiplication of two 8-bit float tensors with per-tensor scales.
+    ///
+    /// The operands are cast to `dtype` before the multiplication, and the product is
+    /// multiplied by `lhs_scale * rhs_scale`, like `torch._scaled_mm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The right-hand side FP8 tensor.
+    /// * `lhs_scale` - The scale of `self`, of shape `[1]`.
+    /// * `rhs_scale` - The scale of `rhs`, of shape `[1]`.
+    /// * `dtype` - The float data type of the computation and the output.
+    pub fn scaled_matmul(
+        self,
+        rhs: Tensor<D>,
+        lhs_scale: Tensor<1>,
+        rhs_scale: Tensor<1>,
+        dtype: FloatDType,
+    ) -> Tensor<D> {
+        let scale = lhs_scale.cast(dtype).mul(rhs_scale.cast(dtype));
+        self.cast(dtype)
+            .matmul(rhs.cast(dtype))
+            .mul(scale.unsqueeze())
+    }
+
     /// Checks element wise if the tensor is close to another tensor.
     ///
     /// The tolerance is defined by the following equation:
@@ -602,6 +1060,153 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
             .bool_and(self.is_inf().bool_not())
     }
 
+    /// Replaces NaN, positive infinity and negative infinity with the given values.
+    ///
+    /// # Arguments
+    ///
+    /// * `nan` - The value replacing NaN.
+    /// * `posinf` - The value replacing positive infinity, or the largest finite value of the
+    ///   dtype if `None`.
+    /// * `neginf` - The value replacing negative infinity, or the lowest finite value of the
+    ///   dtype if `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<1>::from_data([1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY], &device);
+    /// let tensor = tensor.nan_to_num(0.0, Some(100.0), None);
+    /// println!("{tensor}");
+    /// // [1.0, 0.0, 100.0, -3.4028235e38]
+    /// ```
+    pub fn nan_to_num(self, nan: f64, posinf: Option<f64>, neginf: Option<f64>) -> Self {
+        let finfo = self.dtype().finfo().unwrap_or(FloatDType::F32.finfo());
+        let posinf = posinf.unwrap_or(finfo.max);
+        let neginf = neginf.unwrap_or(finfo.min);
+        Self::new(nan_to_num_impl(
+            self.primitive,
+            nan.into(),
+            posinf.into(),
+            neginf.into(),
+        ))
+    }
+
+    /// Computes `log(sum(exp(x)))` along the given dimension.
+    ///
+    /// The elements are shifted by their maximum before taking the exponential, so the result
+    /// is accurate for large inputs where the naive computation would overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce; supports negative indexing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1000.0, 1000.0], [0.0, 0.0]], &device);
+    /// let tensor = tensor.logsumexp(1);
+    /// println!("{tensor}");
+    /// // [[1000.6931], [0.6931472]]
+    /// ```
+    pub fn logsumexp<I: AsIndex>(self, dim: I) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Logsumexp");
+        Self::new(logsumexp_impl(self.primitive, dim))
+    }
+
+    /// Sums all the elements, treating NaN as zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1.0, f64::NAN], [3.0, 4.0]], &device);
+    /// let tensor = tensor.nansum();
+    /// println!("{tensor}");
+    /// // [8.0]
+    /// ```
+    pub fn nansum(self) -> Tensor<1> {
+        self.reshape([-1]).nansum_dim(0)
+    }
+
+    /// Sums the elements along the given dimension, treating NaN as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce; supports negative indexing.
+    pub fn nansum_dim<I: AsIndex>(self, dim: I) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Nansum Dim");
+        Self::new(nansum_dim_impl(self.primitive, dim))
+    }
+
+    /// Averages all the non-NaN elements.
+    ///
+    /// Returns NaN when every element is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1.0, f64::NAN], [3.0, 5.0]], &device);
+    /// let tensor = tensor.nanmean();
+    /// println!("{tensor}");
+    /// // [3.0]
+    /// ```
+    pub fn nanmean(self) -> Tensor<1> {
+        self.reshape([-1]).nanmean_dim(0)
+    }
+
+    /// Averages the non-NaN elements along the given dimension.
+    ///
+    /// Returns NaN where every element along `dim` is NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce; supports negative indexing.
+    pub fn nanmean_dim<I: AsIndex>(self, dim: I) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Nanmean Dim");
+        Self::new(nanmean_dim_impl(self.primitive, dim))
+    }
+
+    /// Returns the maximum of all the non-NaN elements.
+    ///
+    /// Returns NaN when every element is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1.0, f64::NAN], [3.0, 2.0]], &device);
+    /// let tensor = tensor.nanmax();
+    /// println!("{tensor}");
+    /// // [3.0]
+    /// ```
+    pub fn nanmax(self) -> Tensor<1> {
+        self.reshape([-1]).nanmax_dim(0)
+    }
+
+    /// Returns the maximum of the non-NaN elements along the given dimension.
+    ///
+    /// Returns NaN where every element along `dim` is NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce; supports negative indexing.
+    pub fn nanmax_dim<I: AsIndex>(self, dim: I) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Nanmax Dim");
+        Self::new(nanmax_dim_impl(self.primitive, dim))
+    }
+
     /// Samples tensor as a two-dimensional spatial grid of (possibly multi-channel) values,
     /// using the given locations in [-1, 1].
     ///
@@ -660,6 +1265,41 @@ $$\text{erf}\(x\) = \frac{2}{\sqrt{\pi}} \int_0^x e^{-t^2} dt$$
         Tensor::new(cross_impl(self.primitive, other.primitive, dim))
     }
 
+    /// Computes the linear recurrence `h[t] = self[t] * h[t - 1] + b[t]` along a dimension,
+    /// starting from `h[-1] = 0`.
+    ///
+    /// This is the [scan](Tensor::scan) of the affine maps `h -> a * h + b`, which backends
+    /// implement with a dedicated kernel. It is the core of linear RNNs and state space models,
+    /// where `self` holds the decay factors and `b` the inputs. A non-zero initial state `h0`
+    /// can be folded into the first input as `b[0] + self[0] * h0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - The inputs, with the same shape as `self`.
+    /// * `dim` - The dimension along which to run the recurrence; supports negative indexing.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with every state `h[t]` along `dim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let a = Tensor::<2>::from_data([[0.5, 0.5, 0.5], [1.0, 2.0, 0.0]], &device);
+    /// let b = Tensor::<2>::from_data([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]], &device);
+    /// let h = a.linear_recurrence(b, 1);
+    /// println!("{h}");
+    /// // [[1.0, 1.5, 1.75], [1.0, 3.0, 1.0]]
+    /// ```
+    pub fn linear_recurrence<Dim: AsIndex>(self, b: Tensor<D>, dim: Dim) -> Tensor<D> {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Linear Recurrence");
+        check!(TensorCheck::linear_recurrence(&self, &b));
+        Tensor::new(linear_recurrence_impl(self.primitive, b.primitive, dim))
+    }
+
     /// Applies element wise power operation with a float Tensor
     ///
     /// # Arguments
@@ -792,6 +1432,42 @@ impl<const D: usize> Tensor<D> {
         out_shape[D - 1] = num_samples;
         indices.reshape(out_shape)
     }
+
+    /// Computes the histogram of the tensor, with `bins` equal-width bins between `min` and `max`.
+    ///
+    /// All the elements are counted, regardless of the shape of the tensor. Elements outside
+    /// of `[min, max]` and NaN are ignored, and `max` belongs to the last bin. The range must be
+    /// given explicitly, so the histogram is computed on the device without reading the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `min` - The lower end of the range (inclusive).
+    /// * `max` - The upper end of the range (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is 0, or if the range is not finite with `min < max`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[bins]` with the number of elements in each bin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[0.0, 0.5, 1.0], [2.0, 2.5, 4.0]], &device);
+    /// let histogram = tensor.histc(4, 0.0, 4.0);
+    /// println!("{histogram}");
+    /// // [2.0, 1.0, 2.0, 1.0]
+    /// ```
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Tensor<1> {
+        check!(TensorCheck::histc(bins, min, max));
+        Tensor::new(histc_impl(self.primitive, bins, min.into(), max.into()))
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1075,6 +1751,30 @@ fn erf_impl(p: BridgeTensor) -> BridgeTensor {
     BridgeTensor::float(Dispatch::float_erf(p.into_float()))
 }
 
+fn erfinv_impl(p: BridgeTensor) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_erfinv(p.into_float()))
+}
+
+fn lgamma_impl(p: BridgeTensor) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_lgamma(p.into_float()))
+}
+
+fn digamma_impl(p: BridgeTensor) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_digamma(p.into_float()))
+}
+
+fn polygamma_impl(p: BridgeTensor, n: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_polygamma(p.into_float(), n))
+}
+
+fn i0_impl(p: BridgeTensor) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_i0(p.into_float()))
+}
+
+fn i1_impl(p: BridgeTensor) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_i1(p.into_float()))
+}
+
 fn recip_impl(p: BridgeTensor) -> BridgeTensor {
     BridgeTensor::float(Dispatch::float_recip(p.into_float()))
 }
@@ -1135,6 +1835,22 @@ fn set_require_grad_impl(p: BridgeTensor, require_grad: bool) -> BridgeTensor {
     }
 }
 
+fn is_per_sample_grad_impl(p: &BridgeTensor) -> bool {
+    let (kind, tensor) = p.as_parts();
+    match kind {
+        BridgeKind::Float => Dispatch::float_is_per_sample_grad(tensor),
+        _ => panic!("Should be Float primitive kind"),
+    }
+}
+
+fn set_per_sample_grad_impl(p: BridgeTensor) -> BridgeTensor {
+    let (kind, tensor) = p.into_parts();
+    match kind {
+        BridgeKind::Float => BridgeTensor::float(Dispatch::float_set_per_sample_grad(tensor)),
+        _ => panic!("Should be Float primitive kind"),
+    }
+}
+
 fn relu_impl(p: BridgeTensor) -> BridgeTensor {
     BridgeTensor::float(Dispatch::relu(p.into_float()))
 }
@@ -1167,6 +1883,31 @@ fn is_inf_impl(p: BridgeTensor) -> BridgeTensor {
     BridgeTensor::bool(Dispatch::float_is_inf(p.into_float(), bool_dtype))
 }
 
+fn nan_to_num_impl(p: BridgeTensor, nan: Scalar, posinf: Scalar, neginf: Scalar) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_nan_to_num(
+        p.into_float(),
+        nan,
+        posinf,
+        neginf,
+    ))
+}
+
+fn logsumexp_impl(p: BridgeTensor, dim: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_logsumexp_dim(p.into_float(), dim))
+}
+
+fn nansum_dim_impl(p: BridgeTensor, dim: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_nansum_dim(p.into_float(), dim))
+}
+
+fn nanmean_dim_impl(p: BridgeTensor, dim: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_nanmean_dim(p.into_float(), dim))
+}
+
+fn nanmax_dim_impl(p: BridgeTensor, dim: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_nanmax_dim(p.into_float(), dim))
+}
+
 fn grid_sample_2d_impl(
     p: BridgeTensor,
     grid: BridgeTensor,
@@ -1179,6 +1920,22 @@ fn grid_sample_2d_impl(
     ))
 }
 
+fn histc_impl(p: BridgeTensor, bins: usize, min: Scalar, max: Scalar) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_histc(p.into_float(), bins, min, max))
+}
+
+fn random_gamma_impl(alpha: BridgeTensor) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_random_gamma(alpha.into_float()))
+}
+
+fn linear_recurrence_impl(a: BridgeTensor, b: BridgeTensor, dim: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_linear_recurrence(
+        a.into_float(),
+        b.into_float(),
+        dim,
+    ))
+}
+
 fn cross_impl(p: BridgeTensor, other: BridgeTensor, dim: usize) -> BridgeTensor {
     BridgeTensor::float(Dispatch::float_cross(
         p.into_float(),
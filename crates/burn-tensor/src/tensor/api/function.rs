@@ -0,0 +1,328 @@
+// Sealed traits, limited to the tensors, arrays and tuples which implement the pub(crate) traits.
+#![allow(private_bounds)]
+
+use alloc::vec::Vec;
+
+use crate::Tensor;
+use crate::check;
+use crate::check::TensorCheck;
+use crate::ops::BridgeTensor;
+
+#[cfg(feature = "autodiff")]
+use crate::Float;
+#[cfg(feature = "autodiff")]
+use crate::ops::{BasicAutodiffOps, BasicOps};
+#[cfg(feature = "autodiff")]
+use alloc::{boxed::Box, sync::Arc};
+#[cfg(feature = "autodiff")]
+use burn_dispatch::{CustomBackward, CustomForward, Dispatch, DispatchTensor};
+
+/// A differentiable operation with a user defined backward pass, similar to
+/// `torch.autograd.Function`.
+///
+/// The [forward](CustomFunction::forward) pass runs on the inner tensors of the inputs, so its
+/// operations are not recorded by autodiff. Instead, the [backward](CustomFunction::backward)
+/// pass computes the gradients of the inputs from the gradient of the output. Both passes are
+/// made of regular tensor operations, which are fused like any other on backends with fusion.
+///
+/// The tensors needed by the backward pass are saved on the [FunctionCtx] during the forward
+/// pass. Under gradient checkpointing, only the inputs are kept: the forward pass runs again
+/// during the backward pass to save the tensors, so it should be deterministic.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::{CustomFunction, FunctionCtx, Tensor};
+///
+/// /// Computes `x^3`, with the gradient `3x^2`.
+/// struct Cube;
+///
+/// impl CustomFunction<2> for Cube {
+///     type Inputs = Tensor<2>;
+///
+///     fn forward(&self, ctx: &mut FunctionCtx, x: Tensor<2>) -> Tensor<2> {
+///         ctx.save_for_backward(x.clone());
+///         x.powi_scalar(3)
+///     }
+///
+///     fn backward(&self, ctx: &FunctionCtx, grad: Tensor<2>) -> Option<Tensor<2>> {
+///         let x = ctx.saved_tensor::<2>(0);
+///         Some(grad * x.square().mul_scalar(3))
+///     }
+/// }
+///
+/// let device = Default::default();
+/// let x = Tensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+/// let y = Cube.apply(x);
+/// println!("{y}");
+/// // [[1.0, 8.0], [27.0, 64.0]]
+/// ```
+pub trait CustomFunction<const D: usize>: Send + Sync + Sized + 'static {
+    /// The inputs of the function, see [FunctionInputs].
+    type Inputs: FunctionInputs;
+
+    /// Computes the output from the inner tensors of the inputs.
+    fn forward(&self, ctx: &mut FunctionCtx, inputs: Self::Inputs) -> Tensor<D>;
+
+    /// Computes the gradients of the inputs from the gradient of the output.
+    ///
+    /// A `None` gradient is a zero gradient.
+    fn backward(
+        &self,
+        ctx: &FunctionCtx,
+        grad: Tensor<D>,
+    ) -> <Self::Inputs as FunctionInputs>::Grads;
+
+    /// Applies the function to the inputs.
+    ///
+    /// When the inputs are on an autodiff device, the output is recorded on the graph with the
+    /// [backward](CustomFunction::backward) pass of the function.
+    fn apply(self, inputs: Self::Inputs) -> Tensor<D> {
+        apply_impl(self, inputs)
+    }
+}
+
+/// The state of a [custom function](CustomFunction), from its forward pass to its backward pass.
+#[derive(Debug, Default)]
+pub struct FunctionCtx {
+    saved: Vec<BridgeTensor>,
+    needs_input_grad: Vec<bool>,
+}
+
+impl FunctionCtx {
+    /// Saves a tensor for the backward pass, without its autodiff graph.
+    ///
+    /// Returns the index of the tensor for [saved_tensor](FunctionCtx::saved_tensor).
+    pub fn save_for_backward<const D: usize>(&mut self, tensor: Tensor<D>) -> usize {
+        self.saved.push(tensor.no_grad().primitive);
+        self.saved.len() - 1
+    }
+
+    /// Returns the tensor saved at the given index by
+    /// [save_for_backward](FunctionCtx::save_for_backward).
+    ///
+    /// # Panics
+    ///
+    /// If no tensor is saved at the index, or if the saved tensor doesn't have the rank `D`.
+    pub fn saved_tensor<const D: usize>(&self, index: usize) -> Tensor<D> {
+        let rank = self
+            .saved
+            .get(index)
+            .map(|tensor| tensor.shape().num_dims());
+        check!(TensorCheck::custom_function_saved::<D>(
+            index,
+            self.saved.len(),
+            rank
+        ));
+
+        Tensor::new(self.saved[index].clone())
+    }
+
+    /// Returns the number of saved tensors.
+    pub fn num_saved(&self) -> usize {
+        self.saved.len()
+    }
+
+    /// Returns whether the input tensor at the given index requires a gradient, where the inputs
+    /// are numbered in order.
+    ///
+    /// This is only known during the backward pass, where the gradients of the other inputs are
+    /// ignored and don't need to be computed. It is always `true` during the forward pass.
+    pub fn needs_input_grad(&self, index: usize) -> bool {
+        self.needs_input_grad.get(index).copied().unwrap_or(true)
+    }
+}
+
+/// The inputs of a [custom function](CustomFunction).
+///
+/// Implemented for a float tensor, an array of float tensors of the same rank, and tuples of up
+/// to four inputs.
+pub trait FunctionInputs: FunctionInputsOps {
+    /// The gradients of the inputs, with an optional tensor for each input tensor.
+    type Grads: FunctionGrads;
+}
+
+/// The gradients of the [inputs](FunctionInputs) of a [custom function](CustomFunction).
+pub trait FunctionGrads: FunctionGradsOps {}
+impl<G: FunctionGradsOps> FunctionGrads for G {}
+
+pub(crate) trait FunctionInputsOps: Sized {
+    /// Appends the tensors of the inputs, in order.
+    fn push_tensors(self, tensors: &mut Vec<BridgeTensor>);
+
+    /// Takes the tensors of the inputs, in order.
+    fn take_tensors(tensors: &mut impl Iterator<Item = BridgeTensor>) -> Self;
+}
+
+pub(crate) trait FunctionGradsOps {
+    /// Appends the gradients of the inputs, in order.
+    fn push_grads(self, grads: &mut Vec<Option<BridgeTensor>>);
+}
+
+impl<const D: usize> FunctionInputsOps for Tensor<D> {
+    fn push_tensors(self, tensors: &mut Vec<BridgeTensor>) {
+        tensors.push(self.primitive);
+    }
+
+    fn take_tensors(tensors: &mut impl Iterator<Item = BridgeTensor>) -> Self {
+        Tensor::new(
+            tensors
+                .next()
+                .expect("Should have a tensor for each custom function input"),
+        )
+    }
+}
+
+impl<const D: usize> FunctionInputs for Tensor<D> {
+    type Grads = Option<Tensor<D>>;
+}
+
+impl<const D: usize> FunctionGradsOps for Option<Tensor<D>> {
+    fn push_grads(self, grads: &mut Vec<Option<BridgeTensor>>) {
+        grads.push(self.map(|grad| grad.primitive));
+    }
+}
+
+impl<const D: usize, const N: usize> FunctionInputsOps for [Tensor<D>; N] {
+    fn push_tensors(self, tensors: &mut Vec<BridgeTensor>) {
+        tensors.extend(self.map(|tensor| tensor.primitive));
+    }
+
+    fn take_tensors(tensors: &mut impl Iterator<Item = BridgeTensor>) -> Self {
+        core::array::from_fn(|_| Tensor::<D>::take_tensors(tensors))
+    }
+}
+
+impl<const D: usize, const N: usize> FunctionInputs for [Tensor<D>; N] {
+    type Grads = [Option<Tensor<D>>; N];
+}
+
+impl<const D: usize, const N: usize> FunctionGradsOps for [Option<Tensor<D>>; N] {
+    fn push_grads(self, grads: &mut Vec<Option<BridgeTensor>>) {
+        grads.extend(self.map(|grad| grad.map(|grad| grad.primitive)));
+    }
+}
+
+macro_rules! impl_function_tuple {
+    ($($input:ident: $var:ident),+) => {
+        impl<$($input: FunctionInputs),+> FunctionInputsOps for ($($input,)+) {
+            fn push_tensors(self, tensors: &mut Vec<BridgeTensor>) {
+                let ($($var,)+) = self;
+                $($var.push_tensors(tensors);)+
+            }
+
+            fn take_tensors(tensors: &mut impl Iterator<Item = BridgeTensor>) -> Self {
+                ($($input::take_tensors(tensors),)+)
+            }
+        }
+
+        impl<$($input: FunctionInputs),+> FunctionInputs for ($($input,)+) {
+            type Grads = ($($input::Grads,)+);
+        }
+
+        impl<$($input: FunctionGrads),+> FunctionGradsOps for ($($input,)+) {
+            fn push_grads(self, grads: &mut Vec<Option<BridgeTensor>>) {
+                let ($($var,)+) = self;
+                $($var.push_grads(grads);)+
+            }
+        }
+    };
+}
+
+impl_function_tuple!(I1: i1, I2: i2);
+impl_function_tuple!(I1: i1, I2: i2, I3: i3);
+impl_function_tuple!(I1: i1, I2: i2, I3: i3, I4: i4);
+
+fn apply_impl<const D: usize, F: CustomFunction<D>>(function: F, inputs: F::Inputs) -> Tensor<D> {
+    let mut tensors = Vec::new();
+    inputs.push_tensors(&mut tensors);
+
+    #[cfg(feature = "autodiff")]
+    if tensors
+        .iter()
+        .any(|tensor| Float::device(tensor).is_autodiff())
+    {
+        return apply_autodiff(function, tensors);
+    }
+
+    let inputs = <F::Inputs as FunctionInputsOps>::take_tensors(&mut tensors.into_iter());
+    function.forward(&mut FunctionCtx::default(), inputs)
+}
+
+#[cfg(feature = "autodiff")]
+fn apply_autodiff<const D: usize, F: CustomFunction<D>>(
+    function: F,
+    tensors: Vec<BridgeTensor>,
+) -> Tensor<D> {
+    let function = Arc::new(function);
+    let inputs = tensors
+        .iter()
+        .map(|tensor| (tensor.shape(), tensor.dtype()))
+        .collect::<Vec<_>>();
+
+    // Called again during the backward pass under gradient checkpointing, so the saved tensors
+    // are recomputed from the inputs instead of being kept.
+    let forward: CustomForward = Arc::new(move |tensors: Vec<DispatchTensor>| {
+        let mut ctx = FunctionCtx::default();
+        let output = function
+            .forward(
+                &mut ctx,
+                <F::Inputs as FunctionInputsOps>::take_tensors(
+                    &mut tensors.into_iter().map(BridgeTensor::float),
+                ),
+            )
+            .no_grad();
+        let device = output.device();
+        let function = function.clone();
+        let inputs = inputs.clone();
+
+        let backward: CustomBackward = Box::new(
+            move |grad: DispatchTensor, required: &[bool]| -> Vec<Option<DispatchTensor>> {
+                ctx.needs_input_grad = required.to_vec();
+                let grads = function.backward(&ctx, Tensor::new(BridgeTensor::float(grad)));
+
+                let mut grads_flat = Vec::with_capacity(inputs.len());
+                grads.push_grads(&mut grads_flat);
+
+                grads_flat
+                    .into_iter()
+                    .zip(inputs)
+                    .zip(required)
+                    .enumerate()
+                    .map(|(index, ((grad, (shape, dtype)), &required))| {
+                        if !required {
+                            return None;
+                        }
+
+                        let grad = match grad {
+                            Some(grad) => {
+                                check!(TensorCheck::custom_function_grad(
+                                    index,
+                                    &shape,
+                                    &grad.shape()
+                                ));
+                                if Float::device(&grad).is_autodiff() {
+                                    Float::inner(grad)
+                                } else {
+                                    grad
+                                }
+                            }
+                            None => Float::zeros(shape, &device, dtype),
+                        };
+
+                        Some(grad.into_float())
+                    })
+                    .collect()
+            },
+        );
+
+        (output.primitive.into_float(), backward)
+    });
+
+    let output = Dispatch::float_custom_op(
+        tensors.into_iter().map(BridgeTensor::into_float).collect(),
+        forward,
+    );
+
+    Tensor::new(BridgeTensor::float(output))
+}
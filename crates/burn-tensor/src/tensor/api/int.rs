@@ -1,9 +1,13 @@
-use burn_backend::{ElementConversion, Scalar, ops::IntTensorOps};
+use burn_backend::{
+    ElementConversion, Scalar,
+    ops::{FloatTensorOps, IntTensorOps},
+};
 use burn_dispatch::Dispatch;
 
+use crate::check::TensorCheck;
 use crate::{
     Cast, Device, Float, Int, Shape, Tensor, TensorCreationOptions, TensorData, cartesian_grid,
-    ops::BridgeTensor,
+    check, ops::BridgeTensor,
 };
 
 use core::ops::Range;
@@ -36,6 +40,82 @@ impl Tensor<1, Int> {
         let dtype = opt.resolve_dtype::<Int>();
         Tensor::new(arange_step_impl(range, step, opt.device, dtype))
     }
+
+    /// Counts the number of occurrences of each value in `0..length`.
+    ///
+    /// Values outside of `0..length` are ignored. Since the number of bins is given
+    /// explicitly, the counts are computed on the device without reading the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The number of bins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is 0.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[length]`, where element `i` is the number of elements equal to `i`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// let device = Default::default();
+    /// let labels = Tensor::<1, Int>::from_data([0, 2, 2, 3, 2], &device);
+    /// let counts = labels.bincount(4);
+    /// println!("{counts}");
+    /// // [1, 0, 3, 1]
+    /// ```
+    pub fn bincount(self, length: usize) -> Self {
+        check!(TensorCheck::bincount(length, &self.shape(), None));
+        Tensor::new(bincount_impl(self.primitive, length))
+    }
+
+    /// Sums the `weights` of the elements for each value in `0..length`.
+    ///
+    /// Values outside of `0..length` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weight of each element, with the same shape as the tensor.
+    /// * `length` - The number of bins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is 0, or if the weights don't have the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[length]`, where element `i` is the sum of the weights of the
+    /// elements equal to `i`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// let device = Default::default();
+    /// let labels = Tensor::<1, Int>::from_data([0, 2, 2], &device);
+    /// let weights = Tensor::<1>::from_data([0.5, 1.0, 2.0], &device);
+    /// let sums = labels.bincount_weighted(weights, 3);
+    /// println!("{sums}");
+    /// // [0.5, 0.0, 3.0]
+    /// ```
+    pub fn bincount_weighted(self, weights: Tensor<1, Float>, length: usize) -> Tensor<1, Float> {
+        check!(TensorCheck::bincount(
+            length,
+            &self.shape(),
+            Some(&weights.shape())
+        ));
+        Tensor::new(bincount_weighted_impl(
+            self.primitive,
+            weights.primitive,
+            length,
+        ))
+    }
 }
 
 impl<const D: usize> Tensor<D, Int> {
@@ -199,6 +279,18 @@ impl<const D: usize> Tensor<D, Int> {
 // See the crate-level docs for the rationale behind this pattern.
 // =========================================================================
 
+fn bincount_impl(p: BridgeTensor, length: usize) -> BridgeTensor {
+    BridgeTensor::int(Dispatch::int_bincount(p.into(), length))
+}
+
+fn bincount_weighted_impl(p: BridgeTensor, weights: BridgeTensor, length: usize) -> BridgeTensor {
+    BridgeTensor::float(Dispatch::float_bincount(
+        p.into(),
+        weights.into_float(),
+        length,
+    ))
+}
+
 fn arange_impl(range: Range<i64>, device: Device, dtype: burn_std::DType) -> BridgeTensor {
     BridgeTensor::int(Dispatch::int_arange(
         range,
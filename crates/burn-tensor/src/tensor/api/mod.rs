@@ -7,6 +7,8 @@ mod cartesian_grid;
 mod cast;
 mod float;
 mod fmod;
+mod function;
+pub use function::{CustomFunction, FunctionCtx, FunctionGrads, FunctionInputs};
 mod graph;
 mod int;
 mod numeric;
@@ -14,6 +16,8 @@ mod options;
 mod orderable;
 mod pad;
 pub use pad::IntoPadding;
+mod scatter_reduce;
+pub use scatter_reduce::ScatterReduce;
 mod take;
 mod transaction;
 
@@ -27,6 +31,7 @@ pub use cast::*;
 pub use float::{DEFAULT_ATOL, DEFAULT_RTOL};
 pub use graph::{Graph, capture};
 pub use options::*;
+pub use orderable::Unique;
 pub use transaction::*;
 
 #[cfg(feature = "extension")]
@@ -1,6 +1,7 @@
 use burn_backend::Scalar;
 
 use crate::alloc::borrow::ToOwned;
+use crate::alloc::vec;
 use crate::check::unwrap_dim_index;
 use crate::kind::Numeric;
 
@@ -587,6 +588,58 @@ where
         Self::new(K::cumprod(self.primitive, dim))
     }
 
+    /// Computes the inclusive scan of the elements along the given *dimension* or *axis* with an
+    /// associative operator.
+    ///
+    /// Position `i` of the output holds `x[0] ⊕ x[1] ⊕ ... ⊕ x[i]`, where `combine(earlier,
+    /// later)` applies `⊕` element-wise. The operator must be associative, but doesn't need to
+    /// be commutative. The scan takes `ceil(log2(n))` steps, each applying `combine` to slices
+    /// of the tensor, so it is differentiable whenever `combine` is.
+    ///
+    /// Prefer [cumsum](Self::cumsum) and [cumprod](Self::cumprod) for sums and products, and
+    /// [linear_recurrence](Tensor::linear_recurrence) for first-order linear recurrences,
+    /// which backends implement with dedicated kernels.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to scan.
+    ///   Negative dimensions are supported and count from the end.
+    /// * `combine` - The associative operator, applied to the earlier and later elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_data([[1.0, 3.0, 2.0], [4.0, 0.0, 6.0]], &device);
+    /// let result = tensor.scan(1, |earlier, later| earlier.max_pair(later));
+    /// println!("{result}");
+    /// // [[1.0, 3.0, 3.0], [4.0, 4.0, 6.0]]
+    /// ```
+    pub fn scan<I: AsIndex, F>(self, dim: I, combine: F) -> Self
+    where
+        F: Fn(Self, Self) -> Self,
+    {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Scan");
+        let size = self.dims()[dim];
+
+        // Hillis-Steele scan: after the step with `offset`, each element combines the
+        // `2 * offset` elements ending at its position.
+        let mut output = self;
+        let mut offset = 1;
+        while offset < size {
+            let head = output.clone().narrow(dim, 0, offset);
+            let earlier = output.clone().narrow(dim, 0, size - offset);
+            let later = output.narrow(dim, offset, size - offset);
+
+            output = Tensor::cat(vec![head, combine(earlier, later)], dim);
+            offset *= 2;
+        }
+
+        output
+    }
+
     /// Apply element wise absolute value operation.
     ///
     /// # Example
@@ -799,6 +852,7 @@ where
         distribution: Distribution,
         options: impl Into<TensorCreationOptions>,
     ) -> Self {
+        check!(TensorCheck::distribution(&distribution));
         // Use the given dtype when provided, otherwise default device dtype
         let opt = options.into();
         let dtype = opt.resolve_dtype::<K>();
@@ -3,9 +3,42 @@ use burn_std::{AsIndex, IndexingUpdateOp};
 
 use crate::check::unwrap_dim_index;
 use crate::kind::Ordered;
-use crate::{Bool, Int, check};
+use crate::ops::BridgeTensor;
+use crate::{Bool, Float, Int, check};
 use crate::{Tensor, check::TensorCheck};
 
+/// The unique elements of a tensor, returned by [`Tensor::unique`] and
+/// [`Tensor::unique_consecutive`].
+#[derive(Clone, Debug)]
+pub struct Unique<const D: usize, K: Ordered = Float> {
+    /// The 1D tensor of unique elements.
+    pub values: Tensor<1, K>,
+    /// The index of the unique element of each input element, with the shape of the input.
+    ///
+    /// Only returned when `return_inverse` is set.
+    pub inverse: Option<Tensor<D, Int>>,
+    /// The number of occurrences of each unique element.
+    ///
+    /// Only returned when `return_counts` is set.
+    pub counts: Option<Tensor<1, Int>>,
+}
+
+impl<const D: usize, K: Ordered> Unique<D, K> {
+    fn new(
+        values: BridgeTensor,
+        inverse: BridgeTensor,
+        counts: BridgeTensor,
+        return_inverse: bool,
+        return_counts: bool,
+    ) -> Self {
+        Self {
+            values: Tensor::new(values),
+            inverse: return_inverse.then(|| Tensor::new(inverse)),
+            counts: return_counts.then(|| Tensor::new(counts)),
+        }
+    }
+}
+
 impl<const D: usize, K> Tensor<D, K>
 where
     K: Ordered,
@@ -190,6 +223,199 @@ where
         Tensor::new(K::argsort(self.primitive, dim, /*descending*/ true))
     }
 
+    /// Finds the indices where the `values` should be inserted to keep the tensor sorted.
+    ///
+    /// The tensor must be sorted in ascending order along its last dimension. Each row of
+    /// `values` is searched in the corresponding row of the tensor, so both tensors must have
+    /// the same leading dimensions. Use [bucketize](Tensor::bucketize) to search all the values
+    /// in a single sorted sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to insert, with the same leading dimensions as the tensor.
+    /// * `right` - If false, returns the first suitable index `i` such that
+    ///   `sorted[i - 1] < v <= sorted[i]`, otherwise the last one such that
+    ///   `sorted[i - 1] <= v < sorted[i]`.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same shape as `values`, with indices between `0` and the size
+    /// of the last dimension of the tensor (inclusive).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let sorted = Tensor::<2>::from_data([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]], &device);
+    /// let values = Tensor::<2>::from_data([[3.0, 6.0], [1.0, 4.0]], &device);
+    /// let indices = sorted.clone().searchsorted(values.clone(), false);
+    /// println!("{indices}");
+    /// // [[1, 3], [0, 1]]
+    /// let indices = sorted.searchsorted(values, true);
+    /// println!("{indices}");
+    /// // [[2, 3], [0, 2]]
+    /// ```
+    pub fn searchsorted(self, values: Self, right: bool) -> Tensor<D, Int> {
+        check!(TensorCheck::searchsorted(
+            "Searchsorted",
+            &self.shape(),
+            &values.shape()
+        ));
+        Tensor::new(K::searchsorted(self.primitive, values.primitive, right))
+    }
+
+    /// Returns the index of the bucket each element belongs to, given the sorted `boundaries`
+    /// of the buckets.
+    ///
+    /// With `right` set to false, the element `v` is assigned to the bucket `i` such that
+    /// `boundaries[i - 1] < v <= boundaries[i]`, otherwise to the one such that
+    /// `boundaries[i - 1] <= v < boundaries[i]`. Elements smaller than all the boundaries get
+    /// the bucket `0`, and the ones greater than all of them get the bucket `boundaries.len()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundaries` - The 1D tensor of bucket boundaries, sorted in ascending order.
+    /// * `right` - Which side of the boundaries the values equal to a boundary belong to.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same shape as the input, containing the bucket indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let boundaries = Tensor::<1>::from_data([1.0, 3.0, 5.0], &device);
+    /// let tensor = Tensor::<2>::from_data([[0.5, 3.0], [4.0, 9.0]], &device);
+    /// let buckets = tensor.bucketize(boundaries, false);
+    /// println!("{buckets}");
+    /// // [[0, 1], [2, 3]]
+    /// ```
+    pub fn bucketize(self, boundaries: Tensor<1, K>, right: bool) -> Tensor<D, Int> {
+        Tensor::new(K::searchsorted(boundaries.primitive, self.primitive, right))
+    }
+
+    /// Returns the unique elements of the flattened tensor.
+    ///
+    /// NaN values are never equal to each other, so each one is returned as a separate element.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - If true, the unique elements are returned in ascending order, otherwise in the
+    ///   order of their first occurrence.
+    /// * `return_inverse` - Whether to also return the index of the unique element of each input
+    ///   element.
+    /// * `return_counts` - Whether to also return the number of occurrences of each unique element.
+    ///
+    /// # Returns
+    ///
+    /// The [unique elements](Unique), with the requested inverse indices and counts.
+    ///
+    /// # Notes
+    ///
+    /// The number of unique elements is data-dependent, so this synchronizes with the device,
+    /// consistent with [`argwhere`](Tensor::argwhere). On backends without a native
+    /// implementation, the unique elements are computed on the CPU.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor data cannot be read synchronously (e.g. on wasm); use
+    /// [`unique_async`](Tensor::unique_async) instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2, Int>::from_data([[3, 1, 3], [2, 1, 3]], &device);
+    /// let unique = tensor.unique(true, true, true);
+    /// println!("{}", unique.values);
+    /// // [1, 2, 3]
+    /// println!("{}", unique.inverse.unwrap());
+    /// // [[2, 0, 2], [1, 0, 2]]
+    /// println!("{}", unique.counts.unwrap());
+    /// // [2, 1, 3]
+    /// ```
+    pub fn unique(self, sorted: bool, return_inverse: bool, return_counts: bool) -> Unique<D, K> {
+        crate::try_read_sync(self.unique_async(sorted, return_inverse, return_counts))
+            .expect("Failed to read tensor data synchronously. Try using unique_async instead.")
+    }
+
+    /// Returns the unique elements of the flattened tensor.
+    ///
+    /// Asynchronous version of [`unique`](Tensor::unique), for backends where the tensor cannot
+    /// be read synchronously (e.g. wasm).
+    pub async fn unique_async(
+        self,
+        sorted: bool,
+        return_inverse: bool,
+        return_counts: bool,
+    ) -> Unique<D, K> {
+        let (values, inverse, counts) = K::unique(self.primitive, sorted).await;
+        Unique::new(values, inverse, counts, return_inverse, return_counts)
+    }
+
+    /// Collapses the consecutive equal elements of the flattened tensor, keeping one element of
+    /// each run.
+    ///
+    /// Unlike [`unique`](Tensor::unique), equal elements that are not next to each other are
+    /// kept separate, and the order of the elements is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `return_inverse` - Whether to also return the index of the collapsed element of each
+    ///   input element.
+    /// * `return_counts` - Whether to also return the length of each run of equal elements.
+    ///
+    /// # Returns
+    ///
+    /// The [collapsed elements](Unique), with the requested inverse indices and counts.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor data cannot be read synchronously (e.g. on wasm); use
+    /// [`unique_consecutive_async`](Tensor::unique_consecutive_async) instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<1, Int>::from_data([1, 1, 2, 2, 3, 1, 1, 2], &device);
+    /// let unique = tensor.unique_consecutive(true, true);
+    /// println!("{}", unique.values);
+    /// // [1, 2, 3, 1, 2]
+    /// println!("{}", unique.inverse.unwrap());
+    /// // [0, 0, 1, 1, 2, 3, 3, 4]
+    /// println!("{}", unique.counts.unwrap());
+    /// // [2, 2, 1, 2, 1]
+    /// ```
+    pub fn unique_consecutive(self, return_inverse: bool, return_counts: bool) -> Unique<D, K> {
+        crate::try_read_sync(self.unique_consecutive_async(return_inverse, return_counts)).expect(
+            "Failed to read tensor data synchronously. Try using unique_consecutive_async instead.",
+        )
+    }
+
+    /// Collapses the consecutive equal elements of the flattened tensor, keeping one element of
+    /// each run.
+    ///
+    /// Asynchronous version of [`unique_consecutive`](Tensor::unique_consecutive), for backends
+    /// where the tensor cannot be read synchronously (e.g. wasm).
+    pub async fn unique_consecutive_async(
+        self,
+        return_inverse: bool,
+        return_counts: bool,
+    ) -> Unique<D, K> {
+        let (values, inverse, counts) = K::unique_consecutive(self.primitive).await;
+        Unique::new(values, inverse, counts, return_inverse, return_counts)
+    }
+
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     ///
     /// # Arguments
@@ -0,0 +1,200 @@
+use crate::check;
+use crate::check::{TensorCheck, unwrap_dim_index};
+use crate::{AsIndex, Float, IndexingUpdateOp, Int, Tensor};
+
+/// The reduction used by [scatter_reduce](Tensor::scatter_reduce) to combine the values scattered
+/// to the same position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterReduce {
+    /// The sum of the values.
+    Sum,
+    /// The product of the values.
+    Prod,
+    /// The mean of the values.
+    Mean,
+    /// The maximum of the values.
+    Amax,
+    /// The minimum of the values.
+    Amin,
+}
+
+impl ScatterReduce {
+    /// The value that leaves the reduction unchanged.
+    fn identity(self) -> f64 {
+        match self {
+            ScatterReduce::Sum | ScatterReduce::Mean => 0.0,
+            ScatterReduce::Prod => 1.0,
+            ScatterReduce::Amax => f64::NEG_INFINITY,
+            ScatterReduce::Amin => f64::INFINITY,
+        }
+    }
+}
+
+impl<const D: usize> Tensor<D, Float> {
+    /// Reduces all the values of `values` into the tensor at the indices given along `dim`.
+    ///
+    /// For a 3D tensor, each value is combined with the output at:
+    ///
+    /// `output[indices[i, j, k], j, k] // dim = 0`
+    /// `output[i, indices[i, j, k], k] // dim = 1`
+    /// `output[i, j, indices[i, j, k]] // dim = 2`
+    ///
+    /// Unlike [scatter](Tensor::scatter), duplicate indices are well defined: all the values
+    /// scattered to the same position are reduced together.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter elements. Supports negative indexing.
+    /// * `indices` - The indices of the elements to scatter, with the same shape as `values`.
+    /// * `values` - The values to scatter into the tensor.
+    /// * `reduce` - The reduction applied to the values scattered to the same position.
+    /// * `include_self` - Whether the original values of the tensor take part in the reduction.
+    ///   When `false`, the positions that receive at least one value are overwritten by the
+    ///   reduction of those values. The other positions keep their original values either way.
+    ///
+    /// # Autodiff
+    ///
+    /// The gradients of [Amax](ScatterReduce::Amax) and [Amin](ScatterReduce::Amin) are split
+    /// evenly between the elements equal to the output, and the gradient of the values for
+    /// [Prod](ScatterReduce::Prod) is not finite when they contain zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, ScatterReduce, Tensor};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<1>::zeros([3], &device);
+    /// let indices = Tensor::<1, Int>::from_ints([0, 1, 0, 1, 2], &device);
+    /// let values = Tensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0, 5.0], &device);
+    /// let output = tensor.scatter_reduce(0, indices, values, ScatterReduce::Mean, false);
+    /// println!("{output}");
+    /// // [2.0, 3.0, 5.0]
+    /// ```
+    pub fn scatter_reduce(
+        self,
+        dim: impl AsIndex,
+        indices: Tensor<D, Int>,
+        values: Self,
+        reduce: ScatterReduce,
+        include_self: bool,
+    ) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Scatter Reduce");
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        let counts = (reduce == ScatterReduce::Mean).then(|| {
+            self.zeros_like().scatter(
+                dim,
+                indices.clone(),
+                values.ones_like(),
+                IndexingUpdateOp::Add,
+            )
+        });
+
+        let tensor = if include_self {
+            self
+        } else {
+            // Replace the scattered positions by the identity of the reduction, so only the
+            // values are reduced there.
+            let identity = values.full_like(reduce.identity());
+            self.scatter(dim, indices.clone(), identity, IndexingUpdateOp::Assign)
+        };
+
+        let update = match reduce {
+            ScatterReduce::Sum | ScatterReduce::Mean => IndexingUpdateOp::Add,
+            ScatterReduce::Prod => IndexingUpdateOp::Mul,
+            ScatterReduce::Amax => IndexingUpdateOp::Max,
+            ScatterReduce::Amin => IndexingUpdateOp::Min,
+        };
+        let output = tensor.scatter(dim, indices, values, update);
+
+        match counts {
+            Some(counts) if include_self => output.div(counts.add_scalar(1)),
+            // The positions without values have a count of zero and keep their original value.
+            Some(counts) => output.div(counts.clamp_min(1)),
+            None => output,
+        }
+    }
+
+    /// Sums the rows of the tensor that belong to the same segment.
+    ///
+    /// See [segment_reduce](Tensor::segment_reduce).
+    pub fn segment_sum(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        self.segment_reduce(segment_ids, num_segments, ScatterReduce::Sum)
+    }
+
+    /// Computes the mean of the rows of the tensor that belong to the same segment.
+    ///
+    /// See [segment_reduce](Tensor::segment_reduce).
+    pub fn segment_mean(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        self.segment_reduce(segment_ids, num_segments, ScatterReduce::Mean)
+    }
+
+    /// Computes the maximum of the rows of the tensor that belong to the same segment.
+    ///
+    /// See [segment_reduce](Tensor::segment_reduce).
+    pub fn segment_max(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        self.segment_reduce(segment_ids, num_segments, ScatterReduce::Amax)
+    }
+
+    /// Computes the minimum of the rows of the tensor that belong to the same segment.
+    ///
+    /// See [segment_reduce](Tensor::segment_reduce).
+    pub fn segment_min(self, segment_ids: Tensor<1, Int>, num_segments: usize) -> Self {
+        self.segment_reduce(segment_ids, num_segments, ScatterReduce::Amin)
+    }
+
+    /// Reduces the rows of the tensor, along the first dimension, that belong to the same
+    /// segment.
+    ///
+    /// This is the pooling of ragged groups, like the nodes of the graphs in a batch: row `i`
+    /// belongs to the segment `segment_ids[i]`, and row `s` of the output is the reduction of all
+    /// the rows of segment `s`. The segment ids are usually sorted, but any order is accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_ids` - The segment of each row, in `[0, num_segments)`.
+    /// * `num_segments` - The number of segments, the size of the first dimension of the output.
+    /// * `reduce` - The reduction applied to the rows of each segment.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[num_segments, ...]`, where the segments without rows are zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, ScatterReduce, Tensor};
+    ///
+    /// let device = Default::default();
+    /// let tensor = Tensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    /// let segment_ids = Tensor::<1, Int>::from_ints([0, 0, 2], &device);
+    /// let output = tensor.segment_reduce(segment_ids, 3, ScatterReduce::Sum);
+    /// println!("{output}");
+    /// // [[4.0, 6.0], [0.0, 0.0], [5.0, 6.0]]
+    /// ```
+    pub fn segment_reduce(
+        self,
+        segment_ids: Tensor<1, Int>,
+        num_segments: usize,
+        reduce: ScatterReduce,
+    ) -> Self {
+        let shape = self.shape();
+        check!(TensorCheck::segment_reduce(&shape, &segment_ids.shape()));
+
+        let mut ids_shape = [1; D];
+        ids_shape[0] = shape[0];
+        let indices = segment_ids.reshape(ids_shape).expand(shape.clone());
+
+        let mut output_shape = shape;
+        output_shape[0] = num_segments;
+        let output = Self::zeros(output_shape, (&self.device(), self.dtype()));
+
+        output.scatter_reduce(0, indices, self, reduce, false)
+    }
+}
@@ -0,0 +1,214 @@
+use alloc::vec;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{DType, Device, FloatDType, Shape, Tensor, TensorCreationOptions, TensorData};
+
+/// A tensor of complex numbers.
+///
+/// The real and imaginary parts are stored as two float tensors of the same shape, and the
+/// [data type](ComplexTensor::dtype) is [Complex64](DType::Complex64) for `f64` parts and
+/// [Complex32](DType::Complex32) otherwise. The [data](ComplexTensor::into_data) of a complex
+/// tensor interleaves the real and imaginary parts, like the `[..., 2]` layout of the
+/// [signal](crate::signal) functions.
+///
+/// # Autodiff
+///
+/// The gradients flow through the real operations on the parts. For a real loss `L`, the
+/// [gradient](ComplexTensor::grad) of a complex tensor `z = x + iy` is `dL/dx + i dL/dy`, which
+/// is twice the conjugate Wirtinger derivative `dL/dz*`, following the PyTorch convention. It is
+/// the direction of steepest ascent, so the usual gradient descent updates apply unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::Tensor;
+/// use burn_tensor::complex::ComplexTensor;
+///
+/// let device = Default::default();
+/// let re = Tensor::<1>::from_data([1.0, 0.0], &device);
+/// let im = Tensor::<1>::from_data([1.0, 2.0], &device);
+/// let z = ComplexTensor::new(re, im);
+/// let product = z.clone().mul(z.conj());
+/// println!("{}", product.real());
+/// // [2.0, 4.0]
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComplexTensor<const D: usize> {
+    pub(crate) re: Tensor<D>,
+    pub(crate) im: Tensor<D>,
+}
+
+impl<const D: usize> ComplexTensor<D> {
+    /// Creates a complex tensor from its real and imaginary parts, which must have the same
+    /// shape.
+    pub fn new(re: Tensor<D>, im: Tensor<D>) -> Self {
+        check!(TensorCheck::complex_parts(&re.shape(), &im.shape()));
+        Self { re, im }
+    }
+
+    /// Creates a complex tensor with the given real part and a zero imaginary part.
+    pub fn from_real(re: Tensor<D>) -> Self {
+        let im = re.zeros_like();
+        Self { re, im }
+    }
+
+    /// Creates a complex tensor from its magnitude and phase, `abs * exp(i angle)`.
+    pub fn from_polar(abs: Tensor<D>, angle: Tensor<D>) -> Self {
+        let re = abs.clone().mul(angle.clone().cos());
+        let im = abs.mul(angle.sin());
+        Self::new(re, im)
+    }
+
+    /// Creates a complex tensor filled with zeros.
+    pub fn zeros<S: Into<Shape>>(shape: S, options: impl Into<TensorCreationOptions>) -> Self {
+        Self::from_real(Tensor::zeros(shape, options))
+    }
+
+    /// Creates a complex tensor from complex data.
+    ///
+    /// The parts follow the data type of the options, or the default float data type of the
+    /// device, like [Tensor::from_data].
+    ///
+    /// # Panics
+    ///
+    /// If the data type of the data isn't complex.
+    pub fn from_data<T>(data: T, options: impl Into<TensorCreationOptions>) -> Self
+    where
+        T: Into<TensorData>,
+    {
+        let options = options.into();
+        let (re, im) = data.into().into_complex_parts();
+        Self {
+            re: Tensor::from_data(re, options.clone()),
+            im: Tensor::from_data(im, options),
+        }
+    }
+
+    /// Creates a complex tensor from a float tensor holding the real and imaginary parts in a
+    /// trailing dimension of size 2, the layout of the [signal](crate::signal) functions.
+    ///
+    /// # Generic Parameters
+    ///
+    /// - `D2`: The number of dimensions of the float tensor, `D + 1`.
+    pub fn from_interleaved<const D2: usize>(tensor: Tensor<D2>) -> Self {
+        check!(TensorCheck::complex_interleaved::<D, D2>(&tensor.shape()));
+
+        let re = tensor.clone().narrow(D, 0, 1).squeeze_dim(D);
+        let im = tensor.narrow(D, 1, 1).squeeze_dim(D);
+        Self { re, im }
+    }
+
+    /// Converts the complex tensor to a float tensor holding the real and imaginary parts in a
+    /// trailing dimension of size 2, the layout of the [signal](crate::signal) functions.
+    ///
+    /// # Generic Parameters
+    ///
+    /// - `D2`: The number of dimensions of the float tensor, `D + 1`.
+    pub fn into_interleaved<const D2: usize>(self) -> Tensor<D2> {
+        Tensor::stack(vec![self.re, self.im], D)
+    }
+
+    /// Returns the complex data, with interleaved real and imaginary parts.
+    pub fn into_data(self) -> TensorData {
+        TensorData::from_complex_parts(self.re.into_data(), self.im.into_data())
+    }
+
+    /// Returns the complex data, with interleaved real and imaginary parts.
+    pub fn to_data(&self) -> TensorData {
+        self.clone().into_data()
+    }
+
+    /// Returns the real part.
+    pub fn real(&self) -> Tensor<D> {
+        self.re.clone()
+    }
+
+    /// Returns the imaginary part.
+    pub fn imag(&self) -> Tensor<D> {
+        self.im.clone()
+    }
+
+    /// Returns the real and imaginary parts.
+    pub fn into_parts(self) -> (Tensor<D>, Tensor<D>) {
+        (self.re, self.im)
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> Shape {
+        self.re.shape()
+    }
+
+    /// Returns the dimensions of the tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.re.dims()
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> Device {
+        self.re.device()
+    }
+
+    /// Returns the complex data type, [Complex64](DType::Complex64) for `f64` parts and
+    /// [Complex32](DType::Complex32) otherwise.
+    pub fn dtype(&self) -> DType {
+        FloatDType::from(self.re.dtype()).complex()
+    }
+
+    /// Moves the tensor to the given device.
+    pub fn to_device(self, device: &Device) -> Self {
+        Self {
+            re: self.re.to_device(device),
+            im: self.im.to_device(device),
+        }
+    }
+
+    /// Detaches the tensor from the autodiff graph.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn detach(self) -> Self {
+        Self {
+            re: self.re.detach(),
+            im: self.im.detach(),
+        }
+    }
+
+    /// Marks the tensor to keep gradients during the backward pass.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn require_grad(self) -> Self {
+        self.set_require_grad(true)
+    }
+
+    /// Marks the tensor as tracked or untracked depending on the require_grad argument.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn set_require_grad(self, require_grad: bool) -> Self {
+        Self {
+            re: self.re.set_require_grad(require_grad),
+            im: self.im.set_require_grad(require_grad),
+        }
+    }
+
+    /// Returns the gradient of the tensor, `dL/dx + i dL/dy` for `z = x + iy`.
+    ///
+    /// See the [autodiff convention](ComplexTensor#autodiff). A part that didn't contribute to
+    /// the loss has a zero gradient, and `None` is returned when neither did.
+    #[cfg(feature = "autodiff")]
+    pub fn grad(&self, grads: &crate::Gradients) -> Option<Self> {
+        match (self.re.grad(grads), self.im.grad(grads)) {
+            (None, None) => None,
+            (re, im) => {
+                let re = re.unwrap_or_else(|| self.re.zeros_like().detach());
+                let im = im.unwrap_or_else(|| self.im.zeros_like().detach());
+                Some(Self { re, im })
+            }
+        }
+    }
+}
+
+impl<const D: usize> core::fmt::Display for ComplexTensor<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Complex {{\n  re: {},\n  im: {}\n}}", self.re, self.im)
+    }
+}
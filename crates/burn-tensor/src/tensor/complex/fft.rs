@@ -0,0 +1,128 @@
+use crate::complex::ComplexTensor;
+use crate::signal::{FftNorm, fftn_parts_impl, irfft, rfft, signal_fft_dims};
+use crate::{AsIndex, Tensor};
+
+impl<const D: usize> ComplexTensor<D> {
+    /// Computes the 1-dimensional discrete Fourier Transform of a real signal, as a complex
+    /// tensor.
+    ///
+    /// This is [`signal::rfft`](crate::signal::rfft) with a native complex output: only the
+    /// `n / 2 + 1` non-redundant frequencies are returned along `dim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    /// use burn_tensor::complex::ComplexTensor;
+    ///
+    /// let device = Default::default();
+    /// let signal = Tensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device);
+    /// let spectrum = ComplexTensor::rfft(signal, 0, None);
+    /// println!("{}", spectrum.imag());
+    /// // [0.0, 2.0, 0.0]
+    /// ```
+    pub fn rfft(signal: Tensor<D>, dim: impl AsIndex, n: Option<usize>) -> Self {
+        let (re, im) = rfft(signal, dim, n);
+        Self { re, im }
+    }
+
+    /// Computes the 1-dimensional inverse discrete Fourier Transform of a half spectrum, as a real
+    /// signal.
+    ///
+    /// This is [`signal::irfft`](crate::signal::irfft) on the native complex form, the inverse of
+    /// [`rfft`](ComplexTensor::rfft).
+    pub fn irfft(self, dim: impl AsIndex, n: Option<usize>) -> Tensor<D> {
+        irfft(self.re, self.im, dim, n)
+    }
+
+    /// Computes the 1-dimensional discrete Fourier Transform along the given dimension.
+    ///
+    /// This is [`signal::fft`](crate::signal::fft) on the native complex form, with the same
+    /// constraints on `n` and the same normalization modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which to take the FFT. Negative dimensions are supported.
+    /// * `n` - Optional FFT length, a power of two. The signal is truncated or zero-padded to
+    ///   length `n`.
+    /// * `norm` - The normalization mode, see [`FftNorm`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    /// use burn_tensor::complex::ComplexTensor;
+    /// use burn_tensor::signal::FftNorm;
+    ///
+    /// let device = Default::default();
+    /// // x[n] = exp(i * 2π * n / 4)
+    /// let re = Tensor::<1>::from_data([1.0, 0.0, -1.0, 0.0], &device);
+    /// let im = Tensor::<1>::from_data([0.0, 1.0, 0.0, -1.0], &device);
+    /// let spectrum = ComplexTensor::new(re, im).fft(0, None, FftNorm::Backward);
+    /// println!("{}", spectrum.real());
+    /// // [0.0, 4.0, 0.0, 0.0]
+    /// ```
+    pub fn fft(self, dim: impl AsIndex, n: Option<usize>, norm: FftNorm) -> Self {
+        self.fftn([dim], n.map(|n| [n]), norm)
+    }
+
+    /// Computes the 1-dimensional inverse discrete Fourier Transform along the given dimension.
+    ///
+    /// See [`fft`](ComplexTensor::fft) for the arguments.
+    pub fn ifft(self, dim: impl AsIndex, n: Option<usize>, norm: FftNorm) -> Self {
+        self.ifftn([dim], n.map(|n| [n]), norm)
+    }
+
+    /// Computes the N-dimensional discrete Fourier Transform over the given dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The dimensions along which to take the FFT. Negative dimensions are supported.
+    /// * `s` - Optional FFT length for each dimension of `dims`, with the same constraints as `n`
+    ///   in [`fft`](ComplexTensor::fft).
+    /// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is the product of
+    ///   the FFT lengths.
+    pub fn fftn<const N: usize>(
+        self,
+        dims: [impl AsIndex; N],
+        s: Option<[usize; N]>,
+        norm: FftNorm,
+    ) -> Self {
+        self.fftn_inner(dims, s, norm, false, "Complex fftn")
+    }
+
+    /// Computes the N-dimensional inverse discrete Fourier Transform over the given dimensions.
+    ///
+    /// See [`fftn`](ComplexTensor::fftn) for the arguments.
+    pub fn ifftn<const N: usize>(
+        self,
+        dims: [impl AsIndex; N],
+        s: Option<[usize; N]>,
+        norm: FftNorm,
+    ) -> Self {
+        self.fftn_inner(dims, s, norm, true, "Complex ifftn")
+    }
+
+    fn fftn_inner<const N: usize>(
+        self,
+        dims: [impl AsIndex; N],
+        s: Option<[usize; N]>,
+        norm: FftNorm,
+        inverse: bool,
+        op: &str,
+    ) -> Self {
+        let (dims, sizes) = signal_fft_dims(&self.shape(), D, dims, s, op);
+        let (re, im) = fftn_parts_impl(
+            self.re.primitive,
+            self.im.primitive,
+            &dims,
+            &sizes,
+            norm,
+            inverse,
+        );
+        Self {
+            re: Tensor::new(re),
+            im: Tensor::new(im),
+        }
+    }
+}
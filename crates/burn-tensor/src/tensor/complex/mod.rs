@@ -0,0 +1,11 @@
+//! Complex tensors, with a [complex data type](crate::DType::Complex32).
+//!
+//! A [complex tensor](ComplexTensor) holds its real and imaginary parts as two float tensors, so
+//! every backend supports it and the gradients of a real loss with respect to a complex tensor
+//! follow the Wirtinger calculus through the real operations on the parts.
+
+mod base;
+mod fft;
+mod ops;
+
+pub use base::*;
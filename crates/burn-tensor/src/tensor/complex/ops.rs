@@ -0,0 +1,156 @@
+use crate::check::unwrap_dim_index;
+use crate::complex::ComplexTensor;
+use crate::{AsIndex, ElementConversion, Shape, Tensor};
+
+impl<const D: usize> ComplexTensor<D> {
+    /// Adds two complex tensors element wise.
+    pub fn add(self, other: Self) -> Self {
+        Self {
+            re: self.re.add(other.re),
+            im: self.im.add(other.im),
+        }
+    }
+
+    /// Subtracts two complex tensors element wise.
+    pub fn sub(self, other: Self) -> Self {
+        Self {
+            re: self.re.sub(other.re),
+            im: self.im.sub(other.im),
+        }
+    }
+
+    /// Applies element wise complex multiplication operation.
+    ///
+    /// `(a + ib)(c + id) = (ac - bd) + i(ad + bc)`
+    pub fn mul(self, other: Self) -> Self {
+        let re = self.re.clone().mul(other.re.clone()) - self.im.clone().mul(other.im.clone());
+        let im = self.re.mul(other.im) + self.im.mul(other.re);
+        Self { re, im }
+    }
+
+    /// Applies element wise complex division operation.
+    ///
+    /// `(a + ib) / (c + id) = ((ac + bd) + i(bc - ad)) / (c^2 + d^2)`
+    pub fn div(self, other: Self) -> Self {
+        self.mul(other.recip())
+    }
+
+    /// Negates the real and imaginary parts.
+    pub fn neg(self) -> Self {
+        Self {
+            re: self.re.neg(),
+            im: self.im.neg(),
+        }
+    }
+
+    /// Returns the complex conjugate, `a - ib` for `a + ib`.
+    pub fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: self.im.neg(),
+        }
+    }
+
+    /// Applies element wise complex reciprocal operation.
+    ///
+    /// `1 / (a + ib) = (a - ib) / (a^2 + b^2)`
+    pub fn recip(self) -> Self {
+        let denom = self.re.clone().square() + self.im.clone().square();
+        Self {
+            re: self.re.div(denom.clone()),
+            im: self.im.neg().div(denom),
+        }
+    }
+
+    /// Returns the magnitude, `sqrt(a^2 + b^2)` for `a + ib`.
+    pub fn abs(self) -> Tensor<D> {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the phase in radians, in `[-pi, pi]`, with the [atan2](Tensor::atan2) convention.
+    pub fn angle(self) -> Tensor<D> {
+        self.im.atan2(self.re)
+    }
+
+    /// Applies element wise complex exponential operation.
+    ///
+    /// `exp(a + ib) = exp(a) (cos(b) + i sin(b))`
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Applies element wise principal complex logarithm operation.
+    ///
+    /// `log(z) = log(|z|) + i angle(z)`
+    pub fn log(self) -> Self {
+        Self {
+            re: self.clone().abs().log(),
+            im: self.angle(),
+        }
+    }
+
+    /// Multiplies the real and imaginary parts by a real scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = other.elem::<f64>();
+        Self {
+            re: self.re.mul_scalar(other),
+            im: self.im.mul_scalar(other),
+        }
+    }
+
+    /// Multiplies the real and imaginary parts by a real tensor, with broadcasting.
+    pub fn mul_real(self, other: Tensor<D>) -> Self {
+        Self {
+            re: self.re.mul(other.clone()),
+            im: self.im.mul(other),
+        }
+    }
+
+    /// Applies the complex matrix multiplication over the last two dimensions, with the same
+    /// broadcasting rules as [Tensor::matmul].
+    pub fn matmul(self, other: Self) -> Self {
+        let re =
+            self.re.clone().matmul(other.re.clone()) - self.im.clone().matmul(other.im.clone());
+        let im = self.re.matmul(other.im) + self.im.matmul(other.re);
+        Self { re, im }
+    }
+
+    /// Sums all the elements.
+    pub fn sum(self) -> ComplexTensor<1> {
+        ComplexTensor {
+            re: self.re.sum(),
+            im: self.im.sum(),
+        }
+    }
+
+    /// Sums the elements along the given dimension, keeping it with a size of 1.
+    pub fn sum_dim(self, dim: impl AsIndex) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Complex sum_dim");
+        Self {
+            re: self.re.sum_dim(dim),
+            im: self.im.sum_dim(dim),
+        }
+    }
+
+    /// Reshapes the tensor, see [Tensor::reshape].
+    pub fn reshape<const D2: usize, S: Into<Shape>>(self, shape: S) -> ComplexTensor<D2> {
+        let shape = shape.into();
+        ComplexTensor {
+            re: self.re.reshape(shape.clone()),
+            im: self.im.reshape(shape),
+        }
+    }
+
+    /// Swaps the last two dimensions, without conjugating the values.
+    pub fn transpose(self) -> Self {
+        Self {
+            re: self.re.transpose(),
+            im: self.im.transpose(),
+        }
+    }
+
+    /// Returns the conjugate transpose over the last two dimensions.
+    pub fn conj_transpose(self) -> Self {
+        self.conj().transpose()
+    }
+}
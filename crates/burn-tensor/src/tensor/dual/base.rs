@@ -0,0 +1,129 @@
+use burn_autodiff::forward::ForwardTensor;
+use burn_dispatch::Dispatch;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::ops::BridgeTensor;
+use crate::{Device, Shape, Tensor};
+
+/// A tensor of dual numbers, used for forward-mode automatic differentiation.
+///
+/// The primal holds the values and the tangent holds their directional derivative with respect
+/// to the inputs of the computation. Every operation on dual tensors applies the chain rule to
+/// the tangent, so the tangent of the output is the Jacobian-vector product `J t` of the
+/// function for the input tangent `t`. See [Tensor::jvp].
+///
+/// The tensors that don't depend on the inputs are [constants](DualTensor::constant), with a zero
+/// tangent.
+///
+/// The operations run on the [Dual](burn_autodiff::forward::Dual) backend decorator, which holds
+/// the differentiation rules of every float operation.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::Tensor;
+/// use burn_tensor::dual::DualTensor;
+///
+/// let device = Default::default();
+/// let x = Tensor::<1>::from_data([1.0, 2.0], &device);
+/// let direction = Tensor::<1>::from_data([1.0, 0.0], &device);
+/// let output = DualTensor::new(x, direction).square();
+/// println!("{}", output.tangent());
+/// // [2.0, 0.0]
+/// ```
+#[derive(Clone, Debug)]
+pub struct DualTensor<const D: usize> {
+    pub(crate) primal: Tensor<D>,
+    pub(crate) tangent: Tensor<D>,
+}
+
+impl<const D: usize> DualTensor<D> {
+    /// Creates a dual tensor from its primal values and its tangent, which must have the same
+    /// shape.
+    pub fn new(primal: Tensor<D>, tangent: Tensor<D>) -> Self {
+        check!(TensorCheck::dual_parts(&primal.shape(), &tangent.shape()));
+        Self { primal, tangent }
+    }
+
+    /// Creates a dual tensor with the given primal values and a zero tangent.
+    pub fn constant(primal: Tensor<D>) -> Self {
+        let tangent = primal.zeros_like();
+        Self { primal, tangent }
+    }
+
+    /// Returns the primal values.
+    pub fn primal(&self) -> Tensor<D> {
+        self.primal.clone()
+    }
+
+    /// Returns the tangent.
+    pub fn tangent(&self) -> Tensor<D> {
+        self.tangent.clone()
+    }
+
+    /// Returns the primal values and the tangent.
+    pub fn into_parts(self) -> (Tensor<D>, Tensor<D>) {
+        (self.primal, self.tangent)
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> Shape {
+        self.primal.shape()
+    }
+
+    /// Returns the dimensions of the tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.primal.dims()
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> Device {
+        self.primal.device()
+    }
+
+    /// Moves the tensor to the given device.
+    pub fn to_device(self, device: &Device) -> Self {
+        Self {
+            primal: self.primal.to_device(device),
+            tangent: self.tangent.to_device(device),
+        }
+    }
+
+    /// Detaches the primal values and the tangent from the autodiff graph.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn detach(self) -> Self {
+        Self {
+            primal: self.primal.detach(),
+            tangent: self.tangent.detach(),
+        }
+    }
+
+    /// Converts the tensor to the float primitive of the dual backend.
+    pub(crate) fn into_forward(self) -> ForwardTensor<Dispatch> {
+        ForwardTensor::new(
+            self.primal.primitive.into_float(),
+            self.tangent.primitive.into_float(),
+        )
+    }
+
+    /// Creates a dual tensor from the float primitive of the dual backend.
+    pub(crate) fn from_forward(tensor: ForwardTensor<Dispatch>) -> Self {
+        let tangent = tensor.tangent_or_zeros();
+        Self {
+            primal: Tensor::new(BridgeTensor::float(tensor.primal)),
+            tangent: Tensor::new(BridgeTensor::float(tangent)),
+        }
+    }
+}
+
+impl<const D: usize> core::fmt::Display for DualTensor<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Dual {{\n  primal: {},\n  tangent: {}\n}}",
+            self.primal, self.tangent
+        )
+    }
+}
@@ -0,0 +1,21 @@
+//! Forward-mode automatic differentiation with dual numbers.
+//!
+//! A [dual tensor](DualTensor) carries a tangent alongside its primal values, and every operation
+//! propagates the tangent with the derivative of the operation, so the
+//! [Jacobian-vector product](crate::Tensor::jvp) of a function is computed in a single forward
+//! pass. Since the tangents are computed with regular tensor operations, they are recorded by the
+//! autodiff backend like any other value, which gives exact
+//! [Hessian-vector products](crate::Tensor::hvp) by running the backward pass over the tangent.
+//!
+//! The backward pass itself isn't differentiable: the gradients are computed on the inner backend,
+//! so a gradient of a gradient (double backward) isn't supported. Second-order derivatives go
+//! through [hvp](crate::Tensor::hvp) instead.
+//!
+//! The module requires the `autodiff` feature, since the dual operations run on the
+//! [Dual](burn_autodiff::forward::Dual) backend decorator of `burn-autodiff`.
+
+mod base;
+mod ops;
+mod transforms;
+
+pub use base::*;
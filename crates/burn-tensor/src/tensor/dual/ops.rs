@@ -0,0 +1,205 @@
+use burn_autodiff::forward::Dual;
+use burn_backend::Scalar;
+use burn_backend::ops::{ActivationOps, FloatTensorOps};
+use burn_dispatch::Dispatch;
+
+use crate::check::unwrap_dim_index;
+use crate::dual::DualTensor;
+use crate::{AsIndex, ElementConversion, ReshapeArgs};
+
+type DualBackend = Dual<Dispatch>;
+
+impl<const D: usize> DualTensor<D> {
+    /// Adds two dual tensors element wise.
+    pub fn add(self, other: Self) -> Self {
+        Self::from_forward(DualBackend::float_add(
+            self.into_forward(),
+            other.into_forward(),
+        ))
+    }
+
+    /// Subtracts two dual tensors element wise.
+    pub fn sub(self, other: Self) -> Self {
+        Self::from_forward(DualBackend::float_sub(
+            self.into_forward(),
+            other.into_forward(),
+        ))
+    }
+
+    /// Applies element wise multiplication operation.
+    ///
+    /// `(a b)' = a' b + a b'`
+    pub fn mul(self, other: Self) -> Self {
+        Self::from_forward(DualBackend::float_mul(
+            self.into_forward(),
+            other.into_forward(),
+        ))
+    }
+
+    /// Applies element wise division operation.
+    ///
+    /// `(a / b)' = (a' - (a / b) b') / b`
+    pub fn div(self, other: Self) -> Self {
+        Self::from_forward(DualBackend::float_div(
+            self.into_forward(),
+            other.into_forward(),
+        ))
+    }
+
+    /// Negates the primal values and the tangent.
+    pub fn neg(self) -> Self {
+        Self::from_forward(DualBackend::float_neg(self.into_forward()))
+    }
+
+    /// Adds a scalar to the primal values, leaving the tangent unchanged.
+    pub fn add_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Scalar::new(other, &self.primal.dtype());
+        Self::from_forward(DualBackend::float_add_scalar(self.into_forward(), other))
+    }
+
+    /// Multiplies the primal values and the tangent by a scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Scalar::new(other, &self.primal.dtype());
+        Self::from_forward(DualBackend::float_mul_scalar(self.into_forward(), other))
+    }
+
+    /// Divides the primal values and the tangent by a scalar.
+    pub fn div_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Scalar::new(other, &self.primal.dtype());
+        Self::from_forward(DualBackend::float_div_scalar(self.into_forward(), other))
+    }
+
+    /// Applies element wise power operation with a float scalar.
+    ///
+    /// `(a^k)' = k a^(k - 1) a'`
+    pub fn powf_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Scalar::new(other, &self.primal.dtype());
+        Self::from_forward(DualBackend::float_powf_scalar(self.into_forward(), other))
+    }
+
+    /// Applies element wise square operation.
+    ///
+    /// `(a^2)' = 2 a a'`
+    pub fn square(self) -> Self {
+        let tensor = self.into_forward();
+        Self::from_forward(DualBackend::float_mul(tensor.clone(), tensor))
+    }
+
+    /// Applies element wise square root operation.
+    ///
+    /// `sqrt(a)' = a' / (2 sqrt(a))`
+    pub fn sqrt(self) -> Self {
+        Self::from_forward(DualBackend::float_sqrt(self.into_forward()))
+    }
+
+    /// Applies element wise reciprocal operation.
+    ///
+    /// `(1 / a)' = -a' / a^2`
+    pub fn recip(self) -> Self {
+        Self::from_forward(DualBackend::float_recip(self.into_forward()))
+    }
+
+    /// Applies element wise exponential operation.
+    ///
+    /// `exp(a)' = exp(a) a'`
+    pub fn exp(self) -> Self {
+        Self::from_forward(DualBackend::float_exp(self.into_forward()))
+    }
+
+    /// Applies element wise natural logarithm operation.
+    ///
+    /// `log(a)' = a' / a`
+    pub fn log(self) -> Self {
+        Self::from_forward(DualBackend::float_log(self.into_forward()))
+    }
+
+    /// Applies element wise sine operation.
+    ///
+    /// `sin(a)' = cos(a) a'`
+    pub fn sin(self) -> Self {
+        Self::from_forward(DualBackend::float_sin(self.into_forward()))
+    }
+
+    /// Applies element wise cosine operation.
+    ///
+    /// `cos(a)' = -sin(a) a'`
+    pub fn cos(self) -> Self {
+        Self::from_forward(DualBackend::float_cos(self.into_forward()))
+    }
+
+    /// Applies element wise hyperbolic tangent operation.
+    ///
+    /// `tanh(a)' = (1 - tanh(a)^2) a'`
+    pub fn tanh(self) -> Self {
+        Self::from_forward(DualBackend::float_tanh(self.into_forward()))
+    }
+
+    /// Applies the sigmoid function element wise.
+    ///
+    /// `sigmoid(a)' = sigmoid(a) (1 - sigmoid(a)) a'`
+    pub fn sigmoid(self) -> Self {
+        Self::from_forward(DualBackend::sigmoid(self.into_forward()))
+    }
+
+    /// Applies the rectified linear unit function element wise.
+    ///
+    /// The tangent is zero where the primal values aren't positive.
+    pub fn relu(self) -> Self {
+        Self::from_forward(DualBackend::relu(self.into_forward()))
+    }
+
+    /// Applies the matrix multiplication over the last two dimensions, with the same
+    /// broadcasting rules as [Tensor::matmul](crate::Tensor::matmul).
+    ///
+    /// `(A B)' = A' B + A B'`
+    pub fn matmul(self, other: Self) -> Self {
+        Self::from_forward(DualBackend::float_matmul(
+            self.into_forward(),
+            other.into_forward(),
+        ))
+    }
+
+    /// Sums all the elements.
+    pub fn sum(self) -> DualTensor<1> {
+        DualTensor::from_forward(DualBackend::float_sum(self.into_forward()))
+    }
+
+    /// Sums the elements along the given dimension, keeping it with a size of 1.
+    pub fn sum_dim(self, dim: impl AsIndex) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Dual sum_dim");
+        Self::from_forward(DualBackend::float_sum_dim(self.into_forward(), dim))
+    }
+
+    /// Computes the mean of all the elements.
+    pub fn mean(self) -> DualTensor<1> {
+        DualTensor::from_forward(DualBackend::float_mean(self.into_forward()))
+    }
+
+    /// Computes the mean of the elements along the given dimension, keeping it with a size of 1.
+    pub fn mean_dim(self, dim: impl AsIndex) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Dual mean_dim");
+        Self::from_forward(DualBackend::float_mean_dim(self.into_forward(), dim))
+    }
+
+    /// Reshapes the tensor, see [Tensor::reshape](crate::Tensor::reshape).
+    pub fn reshape<const D2: usize, S: ReshapeArgs<D2>>(self, shape: S) -> DualTensor<D2> {
+        let shape = shape.into_shape::<D2>(self.shape());
+        DualTensor::from_forward(DualBackend::float_reshape(self.into_forward(), shape))
+    }
+
+    /// Swaps the last two dimensions.
+    pub fn transpose(self) -> Self {
+        Self::from_forward(DualBackend::float_transpose(self.into_forward()))
+    }
+
+    /// Swaps two dimensions, see [Tensor::swap_dims](crate::Tensor::swap_dims).
+    pub fn swap_dims(self, dim1: impl AsIndex, dim2: impl AsIndex) -> Self {
+        let dim1 = unwrap_dim_index(dim1.try_dim_index(D), "Dual swap_dims");
+        let dim2 = unwrap_dim_index(dim2.try_dim_index(D), "Dual swap_dims");
+        Self::from_forward(DualBackend::float_swap_dims(
+            self.into_forward(),
+            dim1,
+            dim2,
+        ))
+    }
+}
@@ -0,0 +1,111 @@
+use crate::Tensor;
+use crate::check;
+use crate::check::TensorCheck;
+use crate::dual::DualTensor;
+
+impl<const D: usize> Tensor<D> {
+    /// Computes the Jacobian-vector product of a function with forward-mode differentiation.
+    ///
+    /// The function is evaluated once on a [dual tensor](DualTensor) made of the tensor and the
+    /// tangent, which gives both the output of the function and its directional derivative
+    /// `J tangent` along the tangent, without any backward pass. It works on every backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `tangent` - The direction of the derivative, with the same shape as the tensor.
+    /// * `func` - The function to differentiate, written with dual tensor operations.
+    ///
+    /// # Returns
+    ///
+    /// The output of the function and the Jacobian-vector product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::Tensor;
+    ///
+    /// let device = Default::default();
+    /// let x = Tensor::<1>::from_data([1.0, 2.0], &device);
+    /// let tangent = Tensor::<1>::from_data([1.0, 1.0], &device);
+    /// let (output, jvp) = x.jvp(tangent, |x| x.clone().mul(x).sum());
+    /// println!("{output} {jvp}");
+    /// // [5.0] [6.0]
+    /// ```
+    pub fn jvp<const D2: usize, F>(self, tangent: Tensor<D>, func: F) -> (Tensor<D2>, Tensor<D2>)
+    where
+        F: FnOnce(DualTensor<D>) -> DualTensor<D2>,
+    {
+        func(DualTensor::new(self, tangent)).into_parts()
+    }
+
+    /// Computes the vector-Jacobian product of a function with reverse-mode differentiation.
+    ///
+    /// The function is evaluated on a tracked copy of the tensor, detached from any existing
+    /// graph, and the backward pass of `sum(output * cotangent)` gives `cotangent^T J`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cotangent` - The weights of the outputs, with the same shape as the output.
+    /// * `func` - The function to differentiate.
+    ///
+    /// # Returns
+    ///
+    /// The output of the function, detached from the graph, and the vector-Jacobian product,
+    /// with the shape of the tensor. The product is zero when the output doesn't depend on the
+    /// tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor isn't on an autodiff device.
+    pub fn vjp<const D2: usize, F>(self, cotangent: Tensor<D2>, func: F) -> (Tensor<D2>, Tensor<D>)
+    where
+        F: FnOnce(Tensor<D>) -> Tensor<D2>,
+    {
+        let input = self.detach().require_grad();
+        let output = func(input.clone());
+        check!(TensorCheck::vjp_cotangent(
+            &output.shape(),
+            &cotangent.shape()
+        ));
+
+        let grads = output.clone().mul(cotangent.detach()).sum().backward();
+        let vjp = input
+            .grad(&grads)
+            .unwrap_or_else(|| input.zeros_like().detach());
+
+        (output.detach(), vjp)
+    }
+
+    /// Computes the Hessian-vector product of a scalar function.
+    ///
+    /// The product is computed with forward-mode differentiation over reverse mode: the function
+    /// is evaluated on a [dual tensor](DualTensor) whose primal is tracked by the autodiff
+    /// backend, so the output tangent is the directional derivative `grad(f) . vector`, and its
+    /// backward pass gives the exact product `H vector`, without materializing the Hessian.
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - The vector multiplied by the Hessian, with the same shape as the tensor.
+    /// * `func` - The function to differentiate, written with dual tensor operations. Its output
+    ///   should have a single element, otherwise the Hessian of the sum of the outputs is used.
+    ///
+    /// # Returns
+    ///
+    /// The Hessian-vector product, with the shape of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor isn't on an autodiff device.
+    pub fn hvp<F>(self, vector: Tensor<D>, func: F) -> Tensor<D>
+    where
+        F: FnOnce(DualTensor<D>) -> DualTensor<1>,
+    {
+        let input = self.detach().require_grad();
+        let output = func(DualTensor::new(input.clone(), vector.detach()));
+
+        let grads = output.tangent.sum().backward();
+        input
+            .grad(&grads)
+            .unwrap_or_else(|| input.zeros_like().detach())
+    }
+}
@@ -0,0 +1,241 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A label of a subscript term.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Label(char),
+    Ellipsis,
+}
+
+/// A parsed einsum equation, where every subscript label is replaced by an index.
+///
+/// The dimensions covered by an ellipsis get their own labels, `0..num_ellipsis_dims`, aligned
+/// from the right across the operands so that they broadcast like regular tensor dimensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct EinsumEquation {
+    /// The labels of every dimension of each input operand.
+    pub inputs: Vec<Vec<usize>>,
+    /// The labels of every dimension of the output.
+    pub output: Vec<usize>,
+    /// The size of each label.
+    pub sizes: Vec<usize>,
+}
+
+impl EinsumEquation {
+    /// Parses the `equation` for operands with the given `shapes`, validating that the output has
+    /// `output_rank` dimensions.
+    ///
+    /// A scalar output (no output subscripts) is returned as a tensor of shape `[1]`, so it
+    /// requires an output rank of 1.
+    pub(super) fn parse(
+        equation: &str,
+        shapes: &[Vec<usize>],
+        output_rank: usize,
+    ) -> Result<Self, String> {
+        let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+        let (lhs, rhs) = match equation.split_once("->") {
+            Some((lhs, rhs)) => (lhs, Some(rhs)),
+            None => (equation.as_str(), None),
+        };
+
+        let terms = lhs
+            .split(',')
+            .map(parse_term)
+            .collect::<Result<Vec<_>, _>>()?;
+        if terms.len() != shapes.len() {
+            return Err(format!(
+                "The equation has {} input terms, but {} operands were provided.",
+                terms.len(),
+                shapes.len()
+            ));
+        }
+
+        // Number of dimensions covered by the ellipsis of each operand.
+        let mut ellipsis_dims = Vec::with_capacity(terms.len());
+        for (i, (term, shape)) in terms.iter().zip(shapes).enumerate() {
+            let num_labels = term.iter().filter(|t| **t != Token::Ellipsis).count();
+            let has_ellipsis = term.contains(&Token::Ellipsis);
+            if num_labels > shape.len() || (!has_ellipsis && num_labels != shape.len()) {
+                return Err(format!(
+                    "The subscripts of operand {i} don't match its rank: got {num_labels} labels \
+                     for a tensor of shape {shape:?}."
+                ));
+            }
+            ellipsis_dims.push(shape.len() - num_labels);
+        }
+        let num_ellipsis_dims = ellipsis_dims.iter().copied().max().unwrap_or(0);
+
+        // Labels are numbered in order of appearance, after the ellipsis dimensions.
+        let mut label_ids = BTreeMap::new();
+        let mut inputs = Vec::with_capacity(terms.len());
+        for (term, &num_ellipsis) in terms.iter().zip(&ellipsis_dims) {
+            let mut labels = Vec::with_capacity(term.len() + num_ellipsis);
+            for token in term {
+                match token {
+                    Token::Label(c) => {
+                        let next_id = num_ellipsis_dims + label_ids.len();
+                        labels.push(*label_ids.entry(*c).or_insert(next_id));
+                    }
+                    Token::Ellipsis => {
+                        labels.extend(num_ellipsis_dims - num_ellipsis..num_ellipsis_dims);
+                    }
+                }
+            }
+            inputs.push(labels);
+        }
+
+        let mut sizes = vec![1; num_ellipsis_dims + label_ids.len()];
+        for (i, (labels, shape)) in inputs.iter().zip(shapes).enumerate() {
+            for (&label, &size) in labels.iter().zip(shape) {
+                if size == 1 {
+                    continue;
+                }
+                if sizes[label] != 1 && sizes[label] != size {
+                    return Err(format!(
+                        "The size of a subscript label of operand {i} doesn't match the other \
+                         operands: got {size}, expected {} (or 1 to broadcast).",
+                        sizes[label]
+                    ));
+                }
+                sizes[label] = size;
+            }
+        }
+
+        let output = match rhs {
+            Some(rhs) => {
+                let term = parse_term(rhs)?;
+                let mut output = Vec::with_capacity(term.len() + num_ellipsis_dims);
+                for token in term {
+                    match token {
+                        Token::Label(c) => {
+                            let label = *label_ids.get(&c).ok_or_else(|| {
+                                format!("The output label '{c}' doesn't appear in the inputs.")
+                            })?;
+                            if output.contains(&label) {
+                                return Err(format!(
+                                    "The output label '{c}' appears more than once."
+                                ));
+                            }
+                            output.push(label);
+                        }
+                        Token::Ellipsis => output.extend(0..num_ellipsis_dims),
+                    }
+                }
+                output
+            }
+            None => {
+                // Implicit mode: the broadcast dimensions followed by the labels that appear
+                // exactly once, in alphabetical order.
+                let mut output: Vec<usize> = (0..num_ellipsis_dims).collect();
+                for (_, &label) in label_ids.iter() {
+                    let count = inputs.iter().flatten().filter(|&&l| l == label).count();
+                    if count == 1 {
+                        output.push(label);
+                    }
+                }
+                output
+            }
+        };
+
+        if output.len().max(1) != output_rank {
+            return Err(format!(
+                "The output has {} dimensions, but the output rank is {output_rank}.",
+                output.len()
+            ));
+        }
+
+        Ok(Self {
+            inputs,
+            output,
+            sizes,
+        })
+    }
+}
+
+/// Parses a single subscript term, such as `bhqd` or `...qd`.
+fn parse_term(term: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::with_capacity(term.len());
+    let mut chars = term.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '.' {
+            if chars.next() != Some('.') || chars.next() != Some('.') {
+                return Err(format!("Invalid ellipsis in subscripts '{term}'."));
+            }
+            if tokens.contains(&Token::Ellipsis) {
+                return Err(format!(
+                    "The subscripts '{term}' contain more than one ellipsis."
+                ));
+            }
+            tokens.push(Token::Ellipsis);
+        } else if c.is_ascii_alphabetic() {
+            tokens.push(Token::Label(c));
+        } else {
+            return Err(format!(
+                "Invalid character '{c}' in subscripts '{term}', only ASCII letters and '...' \
+                 are allowed."
+            ));
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_explicit_equation() {
+        let shapes = [vec![2, 3, 4, 5], vec![2, 3, 6, 5]];
+        let equation = EinsumEquation::parse("bhqd,bhkd->bhqk", &shapes, 4).unwrap();
+
+        assert_eq!(equation.inputs, vec![vec![0, 1, 2, 3], vec![0, 1, 4, 3]]);
+        assert_eq!(equation.output, vec![0, 1, 2, 4]);
+        assert_eq!(equation.sizes, vec![2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn should_parse_implicit_output_in_alphabetical_order() {
+        let shapes = [vec![2, 3], vec![3, 4]];
+        let equation = EinsumEquation::parse("kj, ji", &shapes, 2).unwrap();
+
+        // k = 0, j = 1, i = 2
+        assert_eq!(equation.output, vec![2, 0]);
+    }
+
+    #[test]
+    fn should_align_ellipsis_dims_from_the_right() {
+        let shapes = [vec![7, 2, 3, 4], vec![2, 4, 5]];
+        let equation = EinsumEquation::parse("...ij,...jk->...ik", &shapes, 4).unwrap();
+
+        assert_eq!(equation.inputs, vec![vec![0, 1, 2, 3], vec![1, 3, 4]]);
+        assert_eq!(equation.output, vec![0, 1, 2, 4]);
+        assert_eq!(equation.sizes, vec![7, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn should_broadcast_size_one_dims() {
+        let shapes = [vec![1, 3], vec![4, 3]];
+        let equation = EinsumEquation::parse("bi,bi->b", &shapes, 1).unwrap();
+
+        assert_eq!(equation.sizes, vec![4, 3]);
+    }
+
+    #[test]
+    fn should_reject_invalid_equations() {
+        let shapes = [vec![2, 3], vec![3, 4]];
+
+        assert!(EinsumEquation::parse("ij,jk", &shapes[..1], 2).is_err());
+        assert!(EinsumEquation::parse("ijk,jk->ik", &shapes, 2).is_err());
+        assert!(EinsumEquation::parse("ij,jk->il", &shapes, 2).is_err());
+        assert!(EinsumEquation::parse("ij,jk->ii", &shapes, 2).is_err());
+        assert!(EinsumEquation::parse("ij,jk->ik", &shapes, 3).is_err());
+        assert!(EinsumEquation::parse("i1,jk->ik", &shapes, 2).is_err());
+        assert!(EinsumEquation::parse("ij,kk->ik", &shapes, 2).is_err());
+    }
+}
@@ -0,0 +1,284 @@
+mod equation;
+mod path;
+
+use crate::check::TensorCheck;
+use crate::ops::{BasicOps, BridgeTensor, Numeric};
+use crate::{Float, Shape, Tensor, check};
+use alloc::vec;
+use alloc::vec::Vec;
+use equation::EinsumEquation;
+use path::{contraction_path, kept_labels};
+
+/// Evaluates the Einstein summation convention on the operands.
+///
+/// The `equation` lists the subscripts of each operand, separated by commas, optionally
+/// followed by `->` and the subscripts of the output. Each subscript is an ASCII letter that
+/// labels one dimension; `...` stands for the remaining dimensions of an operand, which are
+/// aligned from the right and broadcast across operands. Labels that don't appear in the
+/// output are summed over. Without `->`, the output holds the broadcast dimensions followed by
+/// the labels that appear exactly once, in alphabetical order.
+///
+/// # Arguments
+/// - `equation` - The subscripts, e.g. `"bhqd,bhkd->bhqk"`. Whitespace is ignored.
+/// - `tensors` - The operands, one per input term.
+///
+/// # Returns
+/// - The output tensor, with the dimensions of the output subscripts. A scalar output (empty
+///   output subscripts) is returned as a tensor of shape `[1]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the operands. Operands of lower rank can be unsqueezed,
+///   since dimensions of size 1 broadcast against the other operands.
+/// - `D2`: The number of dimensions of the output.
+///
+/// # Panics
+/// This function will panic if:
+/// - The equation is malformed, or its terms don't match the number or the ranks of the operands.
+/// - The same label is used with incompatible sizes (other than 1).
+/// - The output has a label that is repeated or doesn't appear in the inputs.
+/// - The number of output subscripts doesn't match `D2`.
+///
+/// # Performance Note
+/// Repeated labels within an operand (diagonals) are taken by masking with an identity
+/// matrix, and labels only used by a single operand are summed first. The operands are then
+/// contracted two at a time, in the order that greedily keeps the intermediate tensors the
+/// smallest. Each contraction is lowered to a batched matrix multiplication (or a broadcast
+/// multiplication when nothing is summed), so it runs on every backend, under autodiff and
+/// fusion.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn_tensor::{Tensor, einsum};
+///
+/// fn example() {
+///     let device = Default::default();
+///     let q = Tensor::<4>::ones([2, 8, 16, 64], &device);
+///     let k = Tensor::<4>::ones([2, 8, 32, 64], &device);
+///
+///     // Attention scores.
+///     let scores: Tensor<4> = einsum("bhqd,bhkd->bhqk", &[q, k]);
+///     // Shape: [2, 8, 16, 32]
+///
+///     // Trace of a matrix.
+///     let trace: Tensor<1> = einsum("ii", &[Tensor::<2>::eye(3, &device)]);
+///     // Output: [3.0]
+/// }
+/// ```
+pub fn einsum<const D: usize, const D2: usize>(
+    equation: &str,
+    tensors: &[Tensor<D>],
+) -> Tensor<D2> {
+    let shapes: Vec<Vec<usize>> = tensors.iter().map(|t| t.dims().to_vec()).collect();
+    let equation = EinsumEquation::parse(equation, &shapes, D2);
+    check!(TensorCheck::einsum(&equation));
+    let Ok(equation) = equation else {
+        unreachable!()
+    };
+
+    let operands = tensors.iter().map(|t| t.primitive.clone()).collect();
+    Tensor::new(einsum_impl(equation, operands))
+}
+
+// =====================================================================
+// Non-generic implementation helpers (outlined from the generic API).
+// =====================================================================
+
+/// An intermediate operand, along with the label of each of its dimensions.
+///
+/// An operand without labels (a scalar) is stored with the shape `[1]`.
+struct Operand {
+    tensor: BridgeTensor,
+    labels: Vec<usize>,
+}
+
+fn einsum_impl(equation: EinsumEquation, tensors: Vec<BridgeTensor>) -> BridgeTensor {
+    let EinsumEquation {
+        inputs,
+        output,
+        sizes,
+    } = equation;
+
+    let operands: Vec<Operand> = tensors
+        .into_iter()
+        .zip(inputs)
+        .map(|(tensor, labels)| {
+            let shape = labels.iter().map(|&l| sizes[l]).collect::<Vec<_>>();
+            let operand = Operand {
+                tensor: Float::expand(tensor, Shape::from(shape)),
+                labels,
+            };
+            take_diagonals(operand, &sizes)
+        })
+        .collect();
+
+    // Sum the labels that are used by a single operand before any contraction.
+    let labels: Vec<Vec<usize>> = operands.iter().map(|o| o.labels.clone()).collect();
+    let mut operands: Vec<Operand> = operands
+        .into_iter()
+        .enumerate()
+        .map(|(i, operand)| sum_labels(operand, &kept_labels(&labels, i, i, &output), &sizes))
+        .collect();
+
+    let labels: Vec<Vec<usize>> = operands.iter().map(|o| o.labels.clone()).collect();
+    for (lhs, rhs) in contraction_path(&labels, &output, &sizes) {
+        let keep = kept_labels(
+            &operands
+                .iter()
+                .map(|o| o.labels.clone())
+                .collect::<Vec<_>>(),
+            lhs,
+            rhs,
+            &output,
+        );
+        let rhs_operand = operands.remove(rhs);
+        let lhs_operand = operands.remove(lhs);
+        operands.push(contract_pair(lhs_operand, rhs_operand, &keep, &sizes));
+    }
+
+    let operand = operands
+        .pop()
+        .expect("Einsum requires at least one operand");
+    let operand = sum_labels(operand, &output, &sizes);
+    permute_labels(operand, &output).tensor
+}
+
+/// Reshapes the tensor to the sizes of the labels, using `[1]` when there are no labels.
+fn reshape_labels(tensor: BridgeTensor, labels: &[usize], sizes: &[usize]) -> BridgeTensor {
+    let mut shape: Vec<usize> = labels.iter().map(|&l| sizes[l]).collect();
+    if shape.is_empty() {
+        shape.push(1);
+    }
+    Float::reshape(tensor, Shape::from(shape))
+}
+
+/// Takes the diagonal of every label that appears more than once in the operand.
+fn take_diagonals(mut operand: Operand, sizes: &[usize]) -> Operand {
+    while let Some((first, second)) = find_repeated_label(&operand.labels) {
+        let label = operand.labels[first];
+        let size = sizes[label];
+
+        // Mask the off-diagonal entries with an identity matrix spanning both dimensions.
+        let device = Float::device(&operand.tensor);
+        let dtype = operand.tensor.dtype();
+        let identity = Tensor::<2>::eye(size, &device).cast(dtype).primitive;
+        let mut mask_shape = vec![1; operand.labels.len()];
+        mask_shape[first] = size;
+        mask_shape[second] = size;
+        let mask = Float::reshape(identity, Shape::from(mask_shape));
+
+        let tensor = Float::mul(operand.tensor, mask);
+        let tensor = Float::sum_dim(tensor, second);
+        operand.labels.remove(second);
+        operand.tensor = reshape_labels(tensor, &operand.labels, sizes);
+    }
+
+    operand
+}
+
+fn find_repeated_label(labels: &[usize]) -> Option<(usize, usize)> {
+    labels.iter().enumerate().find_map(|(first, label)| {
+        labels[first + 1..]
+            .iter()
+            .position(|l| l == label)
+            .map(|offset| (first, first + 1 + offset))
+    })
+}
+
+/// Sums the dimensions of the operand whose labels are not in `keep`.
+fn sum_labels(mut operand: Operand, keep: &[usize], sizes: &[usize]) -> Operand {
+    let mut summed = false;
+    for dim in (0..operand.labels.len()).rev() {
+        if !keep.contains(&operand.labels[dim]) {
+            operand.tensor = Float::sum_dim(operand.tensor, dim);
+            operand.labels.remove(dim);
+            summed = true;
+        }
+    }
+
+    if summed {
+        operand.tensor = reshape_labels(operand.tensor, &operand.labels, sizes);
+    }
+    operand
+}
+
+/// Permutes the dimensions of the operand to follow the order of `labels`.
+///
+/// The operand must have the same labels, in any order.
+fn permute_labels(operand: Operand, labels: &[usize]) -> Operand {
+    let axes: Vec<usize> = labels
+        .iter()
+        .map(|label| {
+            operand
+                .labels
+                .iter()
+                .position(|l| l == label)
+                .expect("The operand should have the label")
+        })
+        .collect();
+
+    if axes.iter().enumerate().all(|(i, &axis)| i == axis) {
+        return operand;
+    }
+
+    Operand {
+        tensor: Float::permute(operand.tensor, &axes),
+        labels: labels.to_vec(),
+    }
+}
+
+/// Contracts two operands, keeping only the labels in `keep`.
+///
+/// The operands are permuted and reshaped into a batched matrix multiplication
+/// `[batch, lhs_free, summed] x [batch, summed, rhs_free]`.
+fn contract_pair(lhs: Operand, rhs: Operand, keep: &[usize], sizes: &[usize]) -> Operand {
+    // Labels that only appear in one of the operands and are not kept can be summed directly.
+    let lhs_keep = [keep, &rhs.labels[..]].concat();
+    let rhs_keep = [keep, &lhs.labels[..]].concat();
+    let lhs = sum_labels(lhs, &lhs_keep, sizes);
+    let rhs = sum_labels(rhs, &rhs_keep, sizes);
+
+    let (mut batch, mut summed, mut lhs_free) = (Vec::new(), Vec::new(), Vec::new());
+    for &label in lhs.labels.iter() {
+        match (rhs.labels.contains(&label), keep.contains(&label)) {
+            (true, true) => batch.push(label),
+            (true, false) => summed.push(label),
+            (false, _) => lhs_free.push(label),
+        }
+    }
+    let rhs_free: Vec<usize> = rhs
+        .labels
+        .iter()
+        .copied()
+        .filter(|l| !lhs.labels.contains(l))
+        .collect();
+
+    let num_elements = |labels: &[usize]| labels.iter().map(|&l| sizes[l]).product::<usize>();
+    let (b, m, k, n) = (
+        num_elements(&batch),
+        num_elements(&lhs_free),
+        num_elements(&summed),
+        num_elements(&rhs_free),
+    );
+
+    let lhs = permute_labels(lhs, &[&batch[..], &lhs_free, &summed].concat());
+    let rhs = permute_labels(rhs, &[&batch[..], &summed, &rhs_free].concat());
+
+    let tensor = if summed.is_empty() {
+        // Nothing to sum: a broadcast multiplication computes the outer product per batch.
+        let lhs = Float::reshape(lhs.tensor, Shape::new([b, m, 1]));
+        let rhs = Float::reshape(rhs.tensor, Shape::new([b, 1, n]));
+        Float::mul(lhs, rhs)
+    } else {
+        let lhs = Float::reshape(lhs.tensor, Shape::new([b, m, k]));
+        let rhs = Float::reshape(rhs.tensor, Shape::new([b, k, n]));
+        Float::matmul(lhs, rhs)
+    };
+
+    let labels = [batch, lhs_free, rhs_free].concat();
+
+    Operand {
+        tensor: reshape_labels(tensor, &labels, sizes),
+        labels,
+    }
+}
@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+
+/// Returns the labels kept by the contraction of two operands: the union of their labels
+/// that are still needed by the output or by the other operands.
+pub(super) fn contracted_labels(lhs: &[usize], rhs: &[usize], keep: &[usize]) -> Vec<usize> {
+    let mut labels: Vec<usize> = lhs.iter().copied().filter(|l| keep.contains(l)).collect();
+    for label in rhs {
+        if keep.contains(label) && !labels.contains(label) {
+            labels.push(*label);
+        }
+    }
+    labels
+}
+
+/// Returns the labels still needed after contracting the operands `lhs` and `rhs` out of
+/// `operands`: the output labels and the labels of every other operand.
+pub(super) fn kept_labels(
+    operands: &[Vec<usize>],
+    lhs: usize,
+    rhs: usize,
+    output: &[usize],
+) -> Vec<usize> {
+    let mut keep = output.to_vec();
+    for (i, labels) in operands.iter().enumerate() {
+        if i != lhs && i != rhs {
+            keep.extend(labels.iter().copied().filter(|l| !keep.contains(l)));
+        }
+    }
+    keep
+}
+
+/// Returns the labels of `lhs` followed by the labels of `rhs` that aren't in `lhs`.
+fn union(lhs: &[usize], rhs: &[usize]) -> Vec<usize> {
+    let mut labels = lhs.to_vec();
+    labels.extend(rhs.iter().copied().filter(|l| !lhs.contains(l)));
+    labels
+}
+
+fn num_elements(labels: &[usize], sizes: &[usize]) -> usize {
+    labels.iter().map(|&l| sizes[l]).product()
+}
+
+/// Chooses the order in which the operands are contracted two at a time.
+///
+/// Each step greedily picks the pair whose contraction reduces the total size of the
+/// operands the most, breaking ties with the number of multiply-adds. The chosen pair is
+/// removed from the list of operands and its result is pushed at the end, so the returned
+/// indices refer to the list of operands as it is when the step is performed.
+pub(super) fn contraction_path(
+    inputs: &[Vec<usize>],
+    output: &[usize],
+    sizes: &[usize],
+) -> Vec<(usize, usize)> {
+    let mut operands = inputs.to_vec();
+    let mut path = Vec::with_capacity(operands.len().saturating_sub(1));
+
+    while operands.len() > 1 {
+        let mut best: Option<((i128, usize), (usize, usize))> = None;
+
+        for lhs in 0..operands.len() {
+            for rhs in lhs + 1..operands.len() {
+                let keep = kept_labels(&operands, lhs, rhs, output);
+                let result = contracted_labels(&operands[lhs], &operands[rhs], &keep);
+
+                let removed = num_elements(&operands[lhs], sizes) as i128
+                    + num_elements(&operands[rhs], sizes) as i128;
+                let growth = num_elements(&result, sizes) as i128 - removed;
+                let flops = num_elements(&union(&operands[lhs], &operands[rhs]), sizes);
+
+                let cost = (growth, flops);
+                match best {
+                    Some((best_cost, _)) if best_cost <= cost => {}
+                    _ => best = Some((cost, (lhs, rhs))),
+                }
+            }
+        }
+
+        let (_, (lhs, rhs)) = best.expect("At least two operands remain");
+        let keep = kept_labels(&operands, lhs, rhs, output);
+        let result = contracted_labels(&operands[lhs], &operands[rhs], &keep);
+
+        // `rhs > lhs`, so removing it first keeps `lhs` valid.
+        operands.remove(rhs);
+        operands.remove(lhs);
+        operands.push(result);
+        path.push((lhs, rhs));
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn should_contract_the_cheapest_pair_first() {
+        // "ij,jk,kl->il" with a large `j` and `l`: contracting over `j` first keeps the
+        // intermediate small, while starting with "jk,kl" would create a `j x l` tensor.
+        let inputs = vec![vec![0, 1], vec![1, 2], vec![2, 3]];
+        let sizes = vec![2, 100, 2, 100];
+        let path = contraction_path(&inputs, &[0, 3], &sizes);
+
+        assert_eq!(path, vec![(0, 1), (0, 1)]);
+    }
+
+    #[test]
+    fn should_avoid_outer_products() {
+        // "ij,kl,jk->il": the first two operands share no labels.
+        let inputs = vec![vec![0, 1], vec![2, 3], vec![1, 2]];
+        let sizes = vec![8, 8, 8, 8];
+        let path = contraction_path(&inputs, &[0, 3], &sizes);
+
+        assert_ne!(path[0], (0, 1));
+        assert_eq!(path.len(), 2);
+    }
+}
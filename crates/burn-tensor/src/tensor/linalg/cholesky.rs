@@ -0,0 +1,102 @@
+use crate::{Tensor, check, check::TensorCheck};
+use alloc::vec;
+use burn_std::{DType, FloatDType, Slice};
+
+/// Computes the Cholesky decomposition of a batch of symmetric positive-definite matrices.
+///
+/// This function decomposes the input tensor A into a lower triangular tensor L
+/// such that A = LL^T. Only the lower triangular part of the input is read.
+///
+/// # Arguments
+/// - `tensor` - The input tensor of shape `[..., n, n]`.
+///
+/// # Returns
+/// - `L` - The lower triangular tensor of shape `[..., n, n]` with positive diagonal elements.
+///   The upper triangular factor can be obtained with `L.transpose()`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensor.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensor has less than 2 dimensions (`D < 2`).
+/// - The last two dimensions of the input tensor are not equal.
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Numerical Behavior
+/// - The input is not checked for positive-definiteness, since it would require a
+///   synchronization with the device. Matrices that are not positive-definite produce
+///   NaN values in the columns following the first non-positive pivot.
+/// - If the input tensor has dtype F16 or BF16, it is internally upcast to F32
+///   for the computation and cast back to the original dtype before returning.
+/// - The decomposition is built from differentiable tensor operations, so gradients flow
+///   back to the lower triangular part of the input when autodiff is enabled.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let tensor = Tensor::<2>::from_data([[4.0, 2.0], [2.0, 5.0]], &device);
+///
+///     let l = linalg::cholesky(tensor);
+///
+///     // Expected Output:
+///     // l: [[2.0, 0.0],
+///     //     [1.0, 2.0]]
+/// }
+/// ```
+pub fn cholesky<const D: usize>(mut tensor: Tensor<D>) -> Tensor<D> {
+    let dims = tensor.dims();
+    let original_dtype = tensor.dtype();
+    check!(TensorCheck::square_matrix_input::<D>(
+        "linalg::cholesky",
+        &dims,
+        original_dtype
+    ));
+
+    // Upcast f16 and bf16 to f32
+    let needs_upcast = original_dtype == DType::F16 || original_dtype == DType::BF16;
+    if needs_upcast {
+        tensor = tensor.cast(FloatDType::F32)
+    }
+
+    let n = dims[D - 1];
+    let mut l = tensor.zeros_like();
+    let mut slices = vec![Slice::full(); D];
+
+    // Left-looking Cholesky–Banachiewicz: column j only depends on the columns
+    // that were already computed on its left.
+    for j in 0..n {
+        // Shape: [..., n - j, 1]
+        let mut column = tensor
+            .clone()
+            .slice_dim(D - 2, j..)
+            .slice_dim(D - 1, j..j + 1);
+
+        if j > 0 {
+            // L_{j:, :j} L_{j, :j}^T
+            let l_rows = l.clone().slice_dim(D - 2, j..).slice_dim(D - 1, 0..j);
+            let l_j = l.clone().slice_dim(D - 2, j..j + 1).slice_dim(D - 1, 0..j);
+            column = column - l_rows.matmul(l_j.transpose());
+        }
+
+        // Dividing the whole column by sqrt(pivot) yields sqrt(pivot) on the diagonal
+        // and the scaled sub-diagonal entries below it.
+        let pivot = column.clone().slice_dim(D - 2, 0..1).sqrt();
+        let column = column / pivot;
+
+        slices[D - 2] = Slice::from(j..);
+        slices[D - 1] = Slice::from(j..j + 1);
+        l = l.slice_assign(&slices, column);
+    }
+
+    if needs_upcast {
+        l.cast(original_dtype)
+    } else {
+        l
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    Tensor, check,
+    check::TensorCheck,
+    linalg::{batched_identity, jacobi},
+};
+use burn_std::{DType, FloatDType};
+
+/// Computes the eigendecomposition of a batch of symmetric matrices.
+///
+/// This function decomposes the input tensor A into its eigenvalues and eigenvectors
+/// such that `A = V diag(L) V^T`, where V is orthonormal.
+///
+/// # Arguments
+/// - `tensor` - The input tensor of shape `[..., n, n]`. Only its symmetric part
+///   `(A + A^T) / 2` is used.
+///
+/// # Returns
+/// A tuple of two tensors `(L, V)`:
+/// - `L` - The eigenvalues of shape `[..., n]`, sorted in ascending order.
+/// - `V` - The orthonormal eigenvectors of shape `[..., n, n]`, stored as columns.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensor.
+/// - `D1`: The number of dimensions of the eigenvalues tensor. Must be exactly `D - 1`.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensor has less than 2 dimensions (`D < 2`).
+/// - The generic parameters do not satisfy `D - 1 == D1`.
+/// - The last two dimensions of the input tensor are not equal.
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Performance Note
+/// The decomposition uses the parallel cyclic Jacobi method, where each round applies a
+/// batch of disjoint rotations with matrix multiplications. It synchronizes with the device
+/// once per sweep to check for convergence, and will not be as fast as highly tuned
+/// specialized libraries for large matrices.
+///
+/// # Numerical Behavior
+/// - If the input tensor has dtype F16 or BF16, it is internally upcast to F32
+///   for the computation and cast back to the original dtype before returning.
+/// - The eigenvectors are only unique up to their sign, and up to a rotation within the
+///   eigenspace of repeated eigenvalues.
+/// - When autodiff is enabled, gradients are computed analytically from the decomposition
+///   instead of differentiating through the Jacobi iterations. The `1 / (l_j - l_i)` terms
+///   of the eigenvector gradient are smoothed for (nearly) repeated eigenvalues, so the
+///   backward pass never produces infinite values.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let tensor = Tensor::<2>::from_data([[2.0, 1.0], [1.0, 2.0]], &device);
+///
+///     let (eigenvalues, eigenvectors) = linalg::eigh::<2, 1>(tensor);
+///
+///     // Expected Output:
+///     // eigenvalues: [1.0, 3.0]
+///     // eigenvectors: [[-0.7071, 0.7071],
+///     //                [ 0.7071, 0.7071]] (up to the sign of each column)
+/// }
+/// ```
+pub fn eigh<const D: usize, const D1: usize>(mut tensor: Tensor<D>) -> (Tensor<D1>, Tensor<D>) {
+    let dims = tensor.dims();
+    let original_dtype = tensor.dtype();
+    check!(TensorCheck::lu_generic_param::<D, D1>("linalg::eigh"));
+    check!(TensorCheck::square_matrix_input::<D>(
+        "linalg::eigh",
+        &dims,
+        original_dtype
+    ));
+
+    // Upcast f16 and bf16 to f32
+    let needs_upcast = original_dtype == DType::F16 || original_dtype == DType::BF16;
+    if needs_upcast {
+        tensor = tensor.cast(FloatDType::F32)
+    }
+
+    let tensor = (tensor.clone() + tensor.transpose()).div_scalar(2.0);
+    let (eigenvalues, eigenvectors) = eigh_impl(tensor);
+    let eigenvalues = eigenvalues.squeeze_dim::<D1>(D - 2);
+
+    if needs_upcast {
+        (
+            eigenvalues.cast(original_dtype),
+            eigenvectors.cast(original_dtype),
+        )
+    } else {
+        (eigenvalues, eigenvectors)
+    }
+}
+
+/// Computes the sorted eigendecomposition of a batch of symmetric matrices, returning the
+/// eigenvalues as a row tensor of shape `[..., 1, n]`.
+pub(super) fn eigh_impl<const D: usize>(tensor: Tensor<D>) -> (Tensor<D>, Tensor<D>) {
+    let dims = tensor.dims();
+    let device = tensor.device();
+
+    // The decomposition itself is not differentiated, see `eigh_backward_surrogate`.
+    let constant = tensor.clone().no_grad();
+    let (diagonal, eigenvectors) = jacobi(constant, false);
+
+    let identity = batched_identity(dims, diagonal.dtype(), &diagonal.device());
+    let eigenvalues = (diagonal * identity).sum_dim(D - 2);
+
+    // Sort the eigenvalues in ascending order, along with their eigenvectors.
+    let (eigenvalues, indices) = eigenvalues.sort_with_indices(D - 1);
+    let eigenvectors = eigenvectors.gather(D - 1, indices.expand(dims));
+
+    if !device.is_autodiff() {
+        return (eigenvalues, eigenvectors);
+    }
+
+    eigh_backward_surrogate(
+        tensor,
+        Tensor::from_inner(eigenvalues),
+        Tensor::from_inner(eigenvectors),
+    )
+}
+
+/// Attaches the analytical gradients of the eigendecomposition to its (constant) results.
+///
+/// With `P = V^T dA V`, the first-order perturbations are:
+/// - `dL = diag(P)`
+/// - `dV = V (F * P)` with `F_ij = 1 / (l_j - l_i)` for `i != j` and `F_ii = 0`
+///
+/// The added terms are built from `dA = A - detach(A)`, which is zero in value, so the
+/// returned tensors are unchanged while their gradients follow the formulas above.
+fn eigh_backward_surrogate<const D: usize>(
+    tensor: Tensor<D>,
+    eigenvalues: Tensor<D>,
+    eigenvectors: Tensor<D>,
+) -> (Tensor<D>, Tensor<D>) {
+    let dims = tensor.dims();
+    let delta = tensor.clone() - tensor.detach();
+    let projected = eigenvectors
+        .clone()
+        .transpose()
+        .matmul(delta)
+        .matmul(eigenvectors.clone());
+
+    let identity = batched_identity(dims, projected.dtype(), &projected.device());
+    let d_eigenvalues = (projected.clone() * identity).sum_dim(D - 2);
+
+    let f = smoothed_reciprocal_gaps(eigenvalues.clone());
+    let d_eigenvectors = eigenvectors.clone().matmul(f * projected);
+
+    (eigenvalues + d_eigenvalues, eigenvectors + d_eigenvectors)
+}
+
+/// Returns `F_ij = 1 / (x_j - x_i)` for a row tensor `x` of shape `[..., 1, n]`, with
+/// `F_ii = 0`.
+///
+/// The reciprocal is smoothed as `g / (g^2 + eps)` so that (nearly) degenerate values
+/// produce large but finite gradients.
+pub(super) fn smoothed_reciprocal_gaps<const D: usize>(x: Tensor<D>) -> Tensor<D> {
+    let eps = x
+        .dtype()
+        .finfo()
+        .expect("Eigenvalue gaps are only defined for float tensors")
+        .epsilon
+        .powi(2);
+    let gaps = x.clone() - x.transpose();
+    gaps.clone() / gaps.square().add_scalar(eps)
+}
@@ -0,0 +1,193 @@
+use crate::{DType, Device, Tensor, TensorData};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Maximum number of Jacobi sweeps before giving up on convergence.
+///
+/// Cyclic Jacobi converges quadratically, so well-conditioned inputs typically need
+/// fewer than ten sweeps.
+const MAX_SWEEPS: usize = 32;
+
+/// A round of disjoint `(p, q)` index pairs, stored as one-hot selection matrices.
+///
+/// Both matrices have shape `[..., k, n]`, where `k` is the number of pairs in the round.
+/// Since the pairs of a round are disjoint, all their rotations can be applied at once.
+pub(super) struct JacobiRound<const D: usize> {
+    p: Tensor<D>,
+    q: Tensor<D>,
+}
+
+/// Builds the parallel (round-robin) ordering of all `(p, q)` pairs with `p < q < n`.
+///
+/// Every pair appears exactly once over the `n - 1` rounds (or `n` rounds when `n` is odd),
+/// which makes a full sweep of the cyclic Jacobi method.
+pub(super) fn jacobi_rounds<const D: usize>(
+    dims: [usize; D],
+    dtype: DType,
+    device: &Device,
+) -> Vec<JacobiRound<D>> {
+    let n = dims[D - 1];
+    // Pad to an even number of players, the extra one being a "bye".
+    let players = n + n % 2;
+    let mut order: Vec<usize> = (0..players).collect();
+    let mut rounds = Vec::with_capacity(players.saturating_sub(1));
+
+    for _ in 0..players.saturating_sub(1) {
+        let mut pairs = Vec::with_capacity(players / 2);
+        for k in 0..players / 2 {
+            let (a, b) = (order[k], order[players - 1 - k]);
+            if a < n && b < n {
+                pairs.push((a.min(b), a.max(b)));
+            }
+        }
+
+        if !pairs.is_empty() {
+            let k = pairs.len();
+            let mut p = vec![0.0f32; k * n];
+            let mut q = vec![0.0f32; k * n];
+            for (i, (pi, qi)) in pairs.into_iter().enumerate() {
+                p[i * n + pi] = 1.0;
+                q[i * n + qi] = 1.0;
+            }
+
+            let mut shape = dims;
+            shape[D - 2] = k;
+            let selection = |values: Vec<f32>| {
+                Tensor::<2>::from_data(TensorData::new(values, [k, n]), (device, dtype))
+                    .unsqueeze::<D>()
+                    .expand(shape)
+            };
+            rounds.push(JacobiRound {
+                p: selection(p),
+                q: selection(q),
+            });
+        }
+
+        // Keep the first player fixed and rotate the others.
+        order[1..].rotate_right(1);
+    }
+
+    rounds
+}
+
+/// Computes the rotation `J` such that `J^T S J` annihilates the `(p, q)` entries of the
+/// symmetric tensor `S` for every pair of the round.
+///
+/// The rotation angles follow the classical `sym.schur2` formulation (Golub & Van Loan),
+/// which always picks the smallest rotation angle.
+pub(super) fn jacobi_rotation<const D: usize>(
+    s: &Tensor<D>,
+    round: &JacobiRound<D>,
+    identity: &Tensor<D>,
+) -> Tensor<D> {
+    // Shape: [..., k, 1]
+    let ps = round.p.clone().matmul(s.clone());
+    let a_pp = (ps.clone() * round.p.clone()).sum_dim(D - 1);
+    let a_pq = (ps * round.q.clone()).sum_dim(D - 1);
+    let a_qq = (round.q.clone().matmul(s.clone()) * round.q.clone()).sum_dim(D - 1);
+
+    // Pairs that are already diagonal must not be rotated.
+    let is_diagonal = a_pq.clone().equal_scalar(0.0);
+    let safe_a_pq = a_pq.mask_fill(is_diagonal.clone(), 1.0);
+    let tau = (a_qq - a_pp) / safe_a_pq.mul_scalar(2.0);
+
+    // removing zeros from the sign
+    let sign = tau.clone().sign();
+    let mask = sign.clone().equal_scalar(0.0);
+    let sign = sign.mask_fill(mask, 1.0);
+
+    let t = sign / (tau.clone().abs() + tau.square().add_scalar(1.0).sqrt());
+    let t = t.mask_fill(is_diagonal, 0.0);
+    let c = t.clone().square().add_scalar(1.0).sqrt().recip();
+    let sn = t * c.clone();
+
+    // J = I + P^T (C - I) P + Q^T (C - I) Q + P^T S Q - Q^T S P
+    let c_minus_one = c.sub_scalar(1.0).transpose();
+    let sn = sn.transpose();
+    let pt = round.p.clone().transpose();
+    let qt = round.q.clone().transpose();
+    let lhs_p = pt.clone() * c_minus_one.clone() - qt.clone() * sn.clone();
+    let lhs_q = qt * c_minus_one + pt * sn;
+
+    identity.clone() + lhs_p.matmul(round.p.clone()) + lhs_q.matmul(round.q.clone())
+}
+
+/// Returns a batch of identity matrices with the shape `[..., n, n]`.
+pub(super) fn batched_identity<const D: usize>(
+    dims: [usize; D],
+    dtype: DType,
+    device: &Device,
+) -> Tensor<D> {
+    let n = dims[D - 1];
+    let mut shape = dims;
+    shape[D - 2] = n;
+    Tensor::<2>::eye(n, device)
+        .cast(dtype)
+        .unsqueeze::<D>()
+        .expand(shape)
+}
+
+/// Diagonalizes a batch of symmetric matrices with the cyclic Jacobi eigenvalue method.
+///
+/// Returns the diagonalized tensor `V^T S V` and the accumulated rotations `V`.
+/// When `one_sided` is true, the rotations are instead applied to the columns of the input
+/// using its Gram matrix (Hestenes' method), which returns `S V` with orthogonal columns.
+pub(super) fn jacobi<const D: usize>(s: Tensor<D>, one_sided: bool) -> (Tensor<D>, Tensor<D>) {
+    let dims = s.dims();
+    let dtype = s.dtype();
+    let device = s.device();
+    let n = dims[D - 1];
+
+    let identity = batched_identity(dims, dtype, &device);
+    let mut v = identity.clone();
+    let mut s = s;
+
+    if n < 2 {
+        return (s, v);
+    }
+
+    let rounds = jacobi_rounds(dims, dtype, &device);
+    // Rounding errors accumulate over the `n` columns, so the off-diagonal part can't
+    // be expected to vanish below `n * eps` relative to the whole matrix.
+    let tolerance = dtype
+        .finfo()
+        .expect("Jacobi rotations are only defined for float tensors")
+        .epsilon
+        * n as f64;
+    let off_diagonal_mask = identity.clone().ones_like() - identity.clone();
+
+    for _ in 0..MAX_SWEEPS {
+        for round in rounds.iter() {
+            if one_sided {
+                let gram = s.clone().transpose().matmul(s.clone());
+                let rotation = jacobi_rotation(&gram, round, &identity);
+                s = s.matmul(rotation.clone());
+                v = v.matmul(rotation);
+            } else {
+                let rotation = jacobi_rotation(&s, round, &identity);
+                s = rotation
+                    .clone()
+                    .transpose()
+                    .matmul(s)
+                    .matmul(rotation.clone());
+                v = v.matmul(rotation);
+            }
+        }
+
+        let sym = if one_sided {
+            s.clone().transpose().matmul(s.clone())
+        } else {
+            s.clone()
+        };
+        let total = sym.clone().square().sum().into_scalar::<f64>();
+        let off = (sym * off_diagonal_mask.clone())
+            .square()
+            .sum()
+            .into_scalar::<f64>();
+        if off <= tolerance * tolerance * total {
+            break;
+        }
+    }
+
+    (s, v)
+}
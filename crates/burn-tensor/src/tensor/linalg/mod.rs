@@ -1,19 +1,34 @@
+mod cholesky;
 mod cosine_similarity;
 mod det;
 mod diag;
+mod eigh;
+mod jacobi;
 mod lu;
 mod matvec;
 mod outer;
+mod pinv;
 mod qr;
+mod solve;
+mod solve_triangular;
+mod svd;
 mod trace;
 mod vector_norm;
 
+pub use cholesky::*;
 pub use cosine_similarity::*;
 pub use det::*;
 pub use diag::*;
+pub use eigh::*;
 pub use lu::*;
 pub use matvec::*;
 pub use outer::*;
+pub use pinv::*;
 pub use qr::*;
+pub use solve::*;
+pub use solve_triangular::*;
+pub use svd::*;
 pub use trace::*;
 pub use vector_norm::*;
+
+use jacobi::{batched_identity, jacobi};
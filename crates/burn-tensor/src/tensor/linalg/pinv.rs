@@ -0,0 +1,98 @@
+use crate::{Tensor, check, check::TensorCheck, linalg::svd_impl};
+use burn_std::{DType, FloatDType};
+
+/// Computes the Moore-Penrose pseudo-inverse of a batch of matrices.
+///
+/// The pseudo-inverse is computed from the singular value decomposition `A = U diag(S) V^T`
+/// as `V diag(S+) U^T`, where the singular values below the cutoff are treated as zero.
+///
+/// # Arguments
+/// - `tensor` - The input tensor of shape `[..., m, n]`.
+/// - `rtol` - The relative tolerance for small singular values. Singular values smaller than
+///   or equal to `rtol * max(S)` are treated as zero. Defaults to `max(m, n) * eps`, with
+///   `eps` the machine epsilon of the input dtype.
+///
+/// # Returns
+/// - The pseudo-inverse tensor of shape `[..., n, m]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensor.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensor has less than 2 dimensions (`D < 2`).
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Performance Note
+/// The pseudo-inverse relies on the same Jacobi method as [svd](crate::linalg::svd). To solve
+/// a well-conditioned square system, [solve](crate::linalg::solve) is much faster.
+///
+/// # Numerical Behavior
+/// - If the input tensor has dtype F16 or BF16, it is internally upcast to F32
+///   for the computation and cast back to the original dtype before returning.
+/// - Gradients flow through the singular value decomposition when autodiff is enabled.
+///   The discarded singular values don't receive gradients.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let tensor = Tensor::<2>::from_data([[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]], &device);
+///
+///     let pinv = linalg::pinv(tensor, None);
+///
+///     // Expected Output:
+///     // pinv: [[1.0, 0.0, 0.0],
+///     //        [0.0, 0.5, 0.0]]
+/// }
+/// ```
+pub fn pinv<const D: usize>(mut tensor: Tensor<D>, rtol: Option<f64>) -> Tensor<D> {
+    let dims = tensor.dims();
+    let original_dtype = tensor.dtype();
+    check!(TensorCheck::svd_input_tensor::<D>(
+        "linalg::pinv",
+        &dims,
+        original_dtype
+    ));
+
+    let rtol = rtol.unwrap_or_else(|| {
+        let eps = original_dtype
+            .finfo()
+            .expect("The pseudo-inverse is only defined for float tensors")
+            .epsilon;
+        dims[D - 2].max(dims[D - 1]) as f64 * eps
+    });
+
+    // Upcast f16 and bf16 to f32
+    let needs_upcast = original_dtype == DType::F16 || original_dtype == DType::BF16;
+    if needs_upcast {
+        tensor = tensor.cast(FloatDType::F32)
+    }
+
+    // Shape: u [..., m, k], s [..., 1, k], v [..., n, k]
+    let (u, s, v) = svd_impl(tensor);
+
+    // The singular values are sorted in descending order, so the first one is the largest.
+    let cutoff = s
+        .clone()
+        .slice_dim(D - 1, 0..1)
+        .mul_scalar(rtol)
+        .expand(s.dims());
+    let discarded = s.clone().lower_equal(cutoff);
+    let s_inv = s
+        .mask_fill(discarded.clone(), 1.0)
+        .recip()
+        .mask_fill(discarded, 0.0);
+
+    let pinv = (v * s_inv).matmul(u.transpose());
+
+    if needs_upcast {
+        pinv.cast(original_dtype)
+    } else {
+        pinv
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    Tensor, check,
+    check::TensorCheck,
+    linalg::{apply_permutations_to_tensor, compute_lu_decomposition, substitution},
+};
+use burn_std::{DType, FloatDType};
+
+/// Solves a batch of square linear systems `A X = B` using the LU decomposition with
+/// partial pivoting.
+///
+/// # Arguments
+/// - `a` - The coefficient tensor of shape `[..., n, n]`.
+/// - `b` - The right-hand side tensor of shape `[..., n, k]`.
+///
+/// # Returns
+/// - `X` - The solution tensor of shape `[..., n, k]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensors.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensors have less than 2 dimensions (`D < 2`).
+/// - The last two dimensions of `a` are not equal.
+/// - The shape of `b` is not compatible with `a`.
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Performance Note
+/// The solver relies on the same LU decomposition as [lu](crate::linalg::lu), which is not
+/// fully optimized and synchronizes with the device to apply the row permutations.
+///
+/// # Numerical Behavior
+/// - Singular matrices produce infinite or NaN values.
+/// - If the input tensors have dtype F16 or BF16, they are internally upcast to F32
+///   for the computation and cast back to the original dtype before returning.
+/// - Gradients flow to both `a` and `b` when autodiff is enabled. The pivots are treated
+///   as constants, which matches the piecewise-smooth derivative of the solution.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let a = Tensor::<2>::from_data([[3.0, 1.0], [1.0, 2.0]], &device);
+///     let b = Tensor::<2>::from_data([[9.0], [8.0]], &device);
+///
+///     let x = linalg::solve(a, b);
+///
+///     // Expected Output:
+///     // x: [[2.0],
+///     //     [3.0]]
+/// }
+/// ```
+pub fn solve<const D: usize>(mut a: Tensor<D>, mut b: Tensor<D>) -> Tensor<D> {
+    let original_dtype = b.dtype();
+    check!(TensorCheck::linear_system_input::<D>(
+        "linalg::solve",
+        &a.dims(),
+        &b.dims(),
+        true,
+        original_dtype
+    ));
+
+    // Upcast f16 and bf16 to f32
+    let needs_upcast = original_dtype == DType::F16 || original_dtype == DType::BF16;
+    if needs_upcast {
+        a = a.cast(FloatDType::F32);
+        b = b.cast(FloatDType::F32);
+    }
+
+    let device = a.device();
+    let (lu, pivots) = compute_lu_decomposition(a);
+
+    // P^T A = L U  =>  L U X = P^T B
+    let b = apply_permutations_to_tensor(b, pivots, &device);
+    // The packed LU tensor holds the strictly lower part of L (unit diagonal) and U.
+    let y = substitution(lu.clone(), b, false, true);
+    let x = substitution(lu, y, true, false);
+
+    if needs_upcast {
+        x.cast(original_dtype)
+    } else {
+        x
+    }
+}
+
+/// Computes the inverse of a batch of square matrices.
+///
+/// # Arguments
+/// - `tensor` - The input tensor of shape `[..., n, n]`.
+///
+/// # Returns
+/// - The inverse tensor of shape `[..., n, n]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensor.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensor has less than 2 dimensions (`D < 2`).
+/// - The last two dimensions of the input tensor are not equal.
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Note
+/// Solving a linear system with [solve] is faster and more accurate than multiplying with
+/// the inverse, and should be preferred whenever the inverse itself is not needed.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let tensor = Tensor::<2>::from_data([[4.0, 7.0], [2.0, 6.0]], &device);
+///
+///     let inverse = linalg::inverse(tensor);
+///
+///     // Expected Output:
+///     // inverse: [[ 0.6, -0.7],
+///     //           [-0.2,  0.4]]
+/// }
+/// ```
+pub fn inverse<const D: usize>(tensor: Tensor<D>) -> Tensor<D> {
+    let dims = tensor.dims();
+    check!(TensorCheck::square_matrix_input::<D>(
+        "linalg::inverse",
+        &dims,
+        tensor.dtype()
+    ));
+
+    let n = dims[D - 1];
+    let identity: Tensor<2> = Tensor::eye(n, &tensor.device()).cast(tensor.dtype());
+    let mut reshape_dims = [1; D];
+    reshape_dims[D - 2] = n;
+    reshape_dims[D - 1] = n;
+    let identity = identity.reshape(reshape_dims).expand(dims);
+
+    solve(tensor, identity)
+}
@@ -0,0 +1,134 @@
+use crate::{Tensor, check, check::TensorCheck};
+use alloc::vec;
+use burn_std::{DType, FloatDType, Slice};
+
+/// Solves a batch of triangular linear systems.
+///
+/// When `left` is true, this function solves `A X = B` for `X`. Otherwise it solves
+/// `X A = B`. Only the upper (`upper = true`) or lower (`upper = false`) triangular part
+/// of `A` is read; the other part is ignored.
+///
+/// # Arguments
+/// - `a` - The triangular coefficient tensor of shape `[..., n, n]`.
+/// - `b` - The right-hand side tensor of shape `[..., n, k]` when `left` is true,
+///   or `[..., k, n]` otherwise.
+/// - `upper` - Whether `A` is upper triangular (true) or lower triangular (false).
+/// - `left` - Whether to solve `A X = B` (true) or `X A = B` (false).
+/// - `unit_diagonal` - When true, the diagonal of `A` is assumed to be all ones and is not read.
+///
+/// # Returns
+/// - `X` - The solution tensor with the same shape as `b`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensors.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensors have less than 2 dimensions (`D < 2`).
+/// - The last two dimensions of `a` are not equal.
+/// - The shape of `b` is not compatible with `a`.
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Numerical Behavior
+/// - Singular triangular matrices (with a zero on the diagonal) produce infinite or NaN values.
+/// - If the input tensors have dtype F16 or BF16, they are internally upcast to F32
+///   for the computation and cast back to the original dtype before returning.
+/// - Gradients flow to both `a` and `b` when autodiff is enabled.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let a = Tensor::<2>::from_data([[2.0, 0.0], [1.0, 4.0]], &device);
+///     let b = Tensor::<2>::from_data([[2.0], [9.0]], &device);
+///
+///     let x = linalg::solve_triangular(a, b, false, true, false);
+///
+///     // Expected Output:
+///     // x: [[1.0],
+///     //     [2.0]]
+/// }
+/// ```
+pub fn solve_triangular<const D: usize>(
+    mut a: Tensor<D>,
+    mut b: Tensor<D>,
+    upper: bool,
+    left: bool,
+    unit_diagonal: bool,
+) -> Tensor<D> {
+    let original_dtype = b.dtype();
+    check!(TensorCheck::linear_system_input::<D>(
+        "linalg::solve_triangular",
+        &a.dims(),
+        &b.dims(),
+        left,
+        original_dtype
+    ));
+
+    // Upcast f16 and bf16 to f32
+    let needs_upcast = original_dtype == DType::F16 || original_dtype == DType::BF16;
+    if needs_upcast {
+        a = a.cast(FloatDType::F32);
+        b = b.cast(FloatDType::F32);
+    }
+
+    let x = if left {
+        substitution(a, b, upper, unit_diagonal)
+    } else {
+        // X A = B  <=>  A^T X^T = B^T, where A^T swaps the triangular part.
+        substitution(a.transpose(), b.transpose(), !upper, unit_diagonal).transpose()
+    };
+
+    if needs_upcast {
+        x.cast(original_dtype)
+    } else {
+        x
+    }
+}
+
+/// Solves `A X = B` with forward (lower) or backward (upper) substitution, one row at a time.
+pub(super) fn substitution<const D: usize>(
+    a: Tensor<D>,
+    b: Tensor<D>,
+    upper: bool,
+    unit_diagonal: bool,
+) -> Tensor<D> {
+    let n = a.dims()[D - 1];
+    let mut x = b.clone();
+    let mut slices = vec![Slice::full(); D];
+
+    for step in 0..n {
+        let i = if upper { n - 1 - step } else { step };
+
+        // Shape: [..., 1, k]
+        let mut row = b.clone().slice_dim(D - 2, i..i + 1);
+
+        // Subtract the contribution of the rows that are already solved.
+        let solved = if upper { i + 1..n } else { 0..i };
+        if !solved.is_empty() {
+            let a_row = a
+                .clone()
+                .slice_dim(D - 2, i..i + 1)
+                .slice_dim(D - 1, solved.clone());
+            let x_solved = x.clone().slice_dim(D - 2, solved);
+            row = row - a_row.matmul(x_solved);
+        }
+
+        if !unit_diagonal {
+            let a_ii = a
+                .clone()
+                .slice_dim(D - 2, i..i + 1)
+                .slice_dim(D - 1, i..i + 1);
+            row = row / a_ii;
+        }
+
+        slices[D - 2] = Slice::from(i..i + 1);
+        x = x.slice_assign(&slices, row);
+    }
+
+    x
+}
@@ -0,0 +1,227 @@
+use crate::{
+    Tensor, check,
+    check::TensorCheck,
+    linalg::{batched_identity, jacobi, qr, smoothed_reciprocal_gaps},
+};
+use alloc::vec;
+use burn_std::{DType, FloatDType};
+
+/// Computes the singular value decomposition of a batch of matrices.
+///
+/// This function decomposes the input tensor A into three tensors U, S and Vh such that
+/// `A = U diag(S) Vh`, where U and Vh have orthonormal columns and rows respectively.
+/// If `full_matrices` is false, the reduced decomposition is returned.
+///
+/// # Arguments
+/// - `tensor` - The input tensor of shape `[..., m, n]`.
+/// - `full_matrices` - Whether to return the full (square) U and Vh instead of the reduced ones.
+///
+/// # Returns
+/// A tuple of three tensors `(U, S, Vh)`, with `k = min(m, n)`:
+/// - `U` - The left singular vectors of shape `[..., m, m]` if `full_matrices` is true,
+///   otherwise `[..., m, k]`.
+/// - `S` - The singular values of shape `[..., k]`, sorted in descending order.
+/// - `Vh` - The transposed right singular vectors of shape `[..., n, n]` if `full_matrices`
+///   is true, otherwise `[..., k, n]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the input tensor.
+/// - `D1`: The number of dimensions of the singular values tensor. Must be exactly `D - 1`.
+///
+/// # Panics
+/// This function will panic if the tensor checks fail:
+/// - The input tensor has less than 2 dimensions (`D < 2`).
+/// - The generic parameters do not satisfy `D - 1 == D1`.
+/// - The input is a quantized tensor with dtype `DType::QFloat`.
+///
+/// # Performance Note
+/// The decomposition uses the one-sided (Hestenes) Jacobi method with a parallel ordering,
+/// which keeps the accuracy of the small singular values. It synchronizes with the device
+/// once per sweep to check for convergence, and will not be as fast as highly tuned
+/// specialized libraries for large matrices.
+///
+/// # Numerical Behavior
+/// - If the input tensor has dtype F16 or BF16, it is internally upcast to F32
+///   for the computation and cast back to the original dtype before returning.
+/// - The singular vectors are only unique up to their sign, and up to a rotation within the
+///   subspace of repeated singular values.
+/// - When autodiff is enabled, gradients are computed analytically from the reduced
+///   decomposition instead of differentiating through the Jacobi iterations. The
+///   `1 / (s_j^2 - s_i^2)` and `1 / s_i` terms are smoothed, so (nearly) repeated or zero
+///   singular values never produce infinite gradients. The columns added by
+///   `full_matrices` don't receive gradients.
+///
+/// # Example
+/// ```rust,ignore
+/// use burn::tensor::Tensor;
+/// use burn::tensor::linalg;
+///
+/// fn example() {
+///     let device = Default::default();
+///     let tensor = Tensor::<2>::from_data([[3.0, 0.0], [0.0, -2.0], [0.0, 0.0]], &device);
+///
+///     let (u, s, vh) = linalg::svd::<2, 1>(tensor, false);
+///
+///     // Expected Output:
+///     // u: [[1.0, 0.0], [0.0, -1.0], [0.0, 0.0]]
+///     // s: [3.0, 2.0]
+///     // vh: [[1.0, 0.0], [0.0, 1.0]] (up to the sign of each singular pair)
+/// }
+/// ```
+pub fn svd<const D: usize, const D1: usize>(
+    mut tensor: Tensor<D>,
+    full_matrices: bool,
+) -> (Tensor<D>, Tensor<D1>, Tensor<D>) {
+    let dims = tensor.dims();
+    let original_dtype = tensor.dtype();
+    check!(TensorCheck::lu_generic_param::<D, D1>("linalg::svd"));
+    check!(TensorCheck::svd_input_tensor::<D>(
+        "linalg::svd",
+        &dims,
+        original_dtype
+    ));
+
+    // Upcast f16 and bf16 to f32
+    let needs_upcast = original_dtype == DType::F16 || original_dtype == DType::BF16;
+    if needs_upcast {
+        tensor = tensor.cast(FloatDType::F32)
+    }
+
+    let (mut u, s, mut v) = svd_impl(tensor);
+    if full_matrices {
+        u = complete_orthonormal_basis(u);
+        v = complete_orthonormal_basis(v);
+    }
+    let s = s.squeeze_dim::<D1>(D - 2);
+    let vh = v.transpose();
+
+    if needs_upcast {
+        (
+            u.cast(original_dtype),
+            s.cast(original_dtype),
+            vh.cast(original_dtype),
+        )
+    } else {
+        (u, s, vh)
+    }
+}
+
+/// Computes the reduced singular value decomposition `A = U diag(S) V^T`.
+///
+/// Returns `U` of shape `[..., m, k]`, the singular values as a row tensor of shape
+/// `[..., 1, k]` and `V` of shape `[..., n, k]`.
+pub(super) fn svd_impl<const D: usize>(tensor: Tensor<D>) -> (Tensor<D>, Tensor<D>, Tensor<D>) {
+    let dims = tensor.dims();
+    if dims[D - 2] < dims[D - 1] {
+        // A^T = U' S V'^T  =>  A = V' S U'^T
+        let (u, s, v) = svd_impl(tensor.transpose());
+        return (v, s, u);
+    }
+
+    let device = tensor.device();
+
+    // The decomposition itself is not differentiated, see `svd_backward_surrogate`.
+    let constant = tensor.clone().no_grad();
+    let (w, v) = jacobi(constant, true);
+
+    // The columns of W = A V are orthogonal, with norms equal to the singular values.
+    let s = w.clone().square().sum_dim(D - 2).sqrt();
+    let is_zero = s.clone().equal_scalar(0.0);
+    let u = w / s.clone().mask_fill(is_zero, 1.0);
+
+    // Sort the singular values in descending order, along with their singular vectors.
+    let (s, indices) = s.sort_descending_with_indices(D - 1);
+    let u = u.gather(D - 1, indices.clone().expand(dims));
+    let mut v_dims = dims;
+    v_dims[D - 2] = dims[D - 1];
+    let v = v.gather(D - 1, indices.expand(v_dims));
+
+    if !device.is_autodiff() {
+        return (u, s, v);
+    }
+
+    svd_backward_surrogate(
+        tensor,
+        Tensor::from_inner(u),
+        Tensor::from_inner(s),
+        Tensor::from_inner(v),
+    )
+}
+
+/// Attaches the analytical gradients of the reduced singular value decomposition to its
+/// (constant) results.
+///
+/// With `P = U^T dA V` and `F_ij = 1 / (s_j^2 - s_i^2)` (`F_ii = 0`), the first-order
+/// perturbations are:
+/// - `dS = diag(P)`
+/// - `dU = U (F * (P S + S P^T)) + (I - U U^T) dA V S^-1`
+/// - `dV = V (F * (S P + P^T S)) + (I - V V^T) dA^T U S^-1`
+///
+/// The added terms are built from `dA = A - detach(A)`, which is zero in value, so the
+/// returned tensors are unchanged while their gradients follow the formulas above.
+fn svd_backward_surrogate<const D: usize>(
+    tensor: Tensor<D>,
+    u: Tensor<D>,
+    s: Tensor<D>,
+    v: Tensor<D>,
+) -> (Tensor<D>, Tensor<D>, Tensor<D>) {
+    let delta = tensor.clone() - tensor.detach();
+    let projected = u
+        .clone()
+        .transpose()
+        .matmul(delta.clone())
+        .matmul(v.clone());
+    let projected_t = projected.clone().transpose();
+
+    let identity = batched_identity(s.dims(), s.dtype(), &s.device());
+    let d_s = (projected.clone() * identity).sum_dim(D - 2);
+
+    // Row (`[..., 1, k]`) and column (`[..., k, 1]`) views of the singular values, used to
+    // scale the columns and the rows of a matrix respectively.
+    let s_row = s.clone();
+    let s_col = s.clone().transpose();
+    let f = smoothed_reciprocal_gaps(s.clone().square());
+
+    let eps = s
+        .dtype()
+        .finfo()
+        .expect("Singular values are only defined for float tensors")
+        .epsilon
+        .powi(2);
+    let s_inv = s.clone() / s.clone().square().add_scalar(eps);
+
+    let inner_u =
+        f.clone() * (projected.clone() * s_row.clone() + projected_t.clone() * s_col.clone());
+    let outer_u = delta.clone().matmul(v.clone()) * s_inv.clone();
+    let outer_u = outer_u.clone() - u.clone().matmul(u.clone().transpose().matmul(outer_u));
+    let d_u = u.clone().matmul(inner_u) + outer_u;
+
+    let inner_v = f * (projected * s_col + projected_t * s_row);
+    let outer_v = delta.transpose().matmul(u.clone()) * s_inv;
+    let outer_v = outer_v.clone() - v.clone().matmul(v.clone().transpose().matmul(outer_v));
+    let d_v = v.clone().matmul(inner_v) + outer_v;
+
+    (u + d_u, s + d_s, v + d_v)
+}
+
+/// Completes a batch of matrices with `k` orthonormal columns of shape `[..., m, k]` into
+/// square orthonormal matrices of shape `[..., m, m]`.
+fn complete_orthonormal_basis<const D: usize>(tensor: Tensor<D>) -> Tensor<D> {
+    let dims = tensor.dims();
+    let (m, k) = (dims[D - 2], dims[D - 1]);
+    if m == k {
+        return tensor;
+    }
+
+    // The last `m - k` columns of the full Q factor span the orthogonal complement.
+    let (q, _r) = qr(tensor.clone().no_grad(), false);
+    let complement = q.slice_dim(D - 1, k..);
+    let complement = if tensor.device().is_autodiff() {
+        Tensor::from_inner(complement)
+    } else {
+        complement
+    };
+
+    Tensor::cat(vec![tensor, complement], D - 1)
+}
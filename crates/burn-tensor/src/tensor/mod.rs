@@ -3,6 +3,7 @@ pub(crate) mod stats;
 mod api;
 
 pub use api::*;
+pub use stats::QuantileInterpolation;
 
 // Re-exported types
 pub use burn_std::{
@@ -17,6 +18,9 @@ pub use kind::{Bool, Float, Int};
 /// The activation module.
 pub mod activation;
 
+mod einsum;
+pub use einsum::einsum;
+
 /// The container module.
 pub mod container {
     pub use burn_std::tensor::container::TensorContainer;
@@ -46,6 +50,16 @@ pub mod ops {
 /// Tensor quantization module.
 pub mod quantization;
 
+/// The sparse tensor module.
+pub mod sparse;
+
+/// The complex tensor module.
+pub mod complex;
+
+/// The dual tensor module, for forward-mode differentiation.
+#[cfg(feature = "autodiff")]
+pub mod dual;
+
 #[cfg(feature = "std")]
 pub mod distributed;
 
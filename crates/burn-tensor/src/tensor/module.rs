@@ -1,13 +1,15 @@
+use alloc::{vec, vec::Vec};
 use burn_backend::ops::ModuleOps;
 use burn_dispatch::Dispatch;
 use burn_std::{MatmulTransformAction, MatmulTransformAnalysis, MatmulTransformPolicy};
+use num_traits::float::Float;
 
 use crate::{
-    Bool, DType, Int, Tensor, check,
+    Bool, DType, Int, Tensor, TensorData, check,
     check::TensorCheck,
     ops::{
         AttentionModuleOptions, BridgeTensor, ConvOptions, ConvTransposeOptions, DeformConvOptions,
-        InterpolateOptions, PadMode, PaddedConvOptions, UnfoldOptions,
+        InterpolateMode, InterpolateOptions, PadMode, PaddedConvOptions, UnfoldOptions,
     },
 };
 
@@ -336,6 +338,25 @@ pub fn max_pool2d(
     )))
 }
 
+/// Applies a [3D max pooling](burn_backend::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d(
+    x: Tensor<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+    ceil_mode: bool,
+) -> Tensor<5> {
+    Tensor::new(BridgeTensor::float(Dispatch::max_pool3d(
+        x.primitive.into_float(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        ceil_mode,
+    )))
+}
+
 /// Applies a [2D avg pooling](burn_backend::ops::ModuleOps::avg_pool2d).
 pub fn avg_pool2d(
     x: Tensor<4>,
@@ -355,6 +376,25 @@ pub fn avg_pool2d(
     )))
 }
 
+/// Applies a [3D avg pooling](burn_backend::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d(
+    x: Tensor<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+    ceil_mode: bool,
+) -> Tensor<5> {
+    Tensor::new(BridgeTensor::float(Dispatch::avg_pool3d(
+        x.primitive.into_float(),
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+        ceil_mode,
+    )))
+}
+
 /// Applies a [1D avg pooling](burn_backend::ops::ModuleOps::avg_pool1d).
 pub fn avg_pool1d(
     x: Tensor<3>,
@@ -426,6 +466,32 @@ pub fn max_pool2d_with_indices(
     )
 }
 
+/// Applies a [3D max pooling with indices](burn_backend::ops::ModuleOps::max_pool3d_with_indices).
+pub fn max_pool3d_with_indices(
+    x: Tensor<5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+    ceil_mode: bool,
+) -> (Tensor<5>, Tensor<5, Int>) {
+    let indices_dtype = x.device().settings().int_dtype;
+    let output = Dispatch::max_pool3d_with_indices(
+        x.primitive.into_float(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        ceil_mode,
+        indices_dtype,
+    );
+
+    (
+        Tensor::new(BridgeTensor::float(output.output)),
+        Tensor::new(BridgeTensor::int(output.indices)),
+    )
+}
+
 /// Applies a [2D adaptive avg pooling](burn_backend::ops::ModuleOps::adaptive_avg_pool2d).
 pub fn adaptive_avg_pool2d(x: Tensor<4>, output_size: [usize; 2]) -> Tensor<4> {
     Tensor::new(BridgeTensor::float(Dispatch::adaptive_avg_pool2d(
@@ -450,6 +516,84 @@ pub fn adaptive_avg_pool1d(x: Tensor<3>, output_size: usize) -> Tensor<3> {
     )))
 }
 
+/// Applies a [1D adaptive max pooling](burn_backend::ops::ModuleOps::adaptive_max_pool1d).
+pub fn adaptive_max_pool1d(x: Tensor<3>, output_size: usize) -> Tensor<3> {
+    Tensor::new(BridgeTensor::float(Dispatch::adaptive_max_pool1d(
+        x.primitive.into_float(),
+        output_size,
+    )))
+}
+
+/// Applies a [2D adaptive max pooling](burn_backend::ops::ModuleOps::adaptive_max_pool2d).
+pub fn adaptive_max_pool2d(x: Tensor<4>, output_size: [usize; 2]) -> Tensor<4> {
+    Tensor::new(BridgeTensor::float(Dispatch::adaptive_max_pool2d(
+        x.primitive.into_float(),
+        output_size,
+    )))
+}
+
+/// Applies a [3D adaptive max pooling](burn_backend::ops::ModuleOps::adaptive_max_pool3d).
+pub fn adaptive_max_pool3d(x: Tensor<5>, output_size: [usize; 3]) -> Tensor<5> {
+    Tensor::new(BridgeTensor::float(Dispatch::adaptive_max_pool3d(
+        x.primitive.into_float(),
+        output_size,
+    )))
+}
+
+/// Applies a [1D adaptive max pooling with indices](burn_backend::ops::ModuleOps::adaptive_max_pool1d_with_indices).
+pub fn adaptive_max_pool1d_with_indices(
+    x: Tensor<3>,
+    output_size: usize,
+) -> (Tensor<3>, Tensor<3, Int>) {
+    let indices_dtype = x.device().settings().int_dtype;
+    let output = Dispatch::adaptive_max_pool1d_with_indices(
+        x.primitive.into_float(),
+        output_size,
+        indices_dtype,
+    );
+
+    (
+        Tensor::new(BridgeTensor::float(output.output)),
+        Tensor::new(BridgeTensor::int(output.indices)),
+    )
+}
+
+/// Applies a [2D adaptive max pooling with indices](burn_backend::ops::ModuleOps::adaptive_max_pool2d_with_indices).
+pub fn adaptive_max_pool2d_with_indices(
+    x: Tensor<4>,
+    output_size: [usize; 2],
+) -> (Tensor<4>, Tensor<4, Int>) {
+    let indices_dtype = x.device().settings().int_dtype;
+    let output = Dispatch::adaptive_max_pool2d_with_indices(
+        x.primitive.into_float(),
+        output_size,
+        indices_dtype,
+    );
+
+    (
+        Tensor::new(BridgeTensor::float(output.output)),
+        Tensor::new(BridgeTensor::int(output.indices)),
+    )
+}
+
+/// Applies a [3D adaptive max pooling with indices](burn_backend::ops::ModuleOps::adaptive_max_pool3d_with_indices).
+pub fn adaptive_max_pool3d_with_indices(
+    x: Tensor<5>,
+    output_size: [usize; 3],
+) -> (Tensor<5>, Tensor<5, Int>) {
+    let indices_dtype = x.device().settings().int_dtype;
+    let output = Dispatch::adaptive_max_pool3d_with_indices(
+        x.primitive.into_float(),
+        output_size,
+        indices_dtype,
+    );
+
+    (
+        Tensor::new(BridgeTensor::float(output.output)),
+        Tensor::new(BridgeTensor::int(output.indices)),
+    )
+}
+
 /// Applies a [2D interpolation](burn_backend::ops::ModuleOps::interpolate).
 pub fn interpolate(
     x: Tensor<4>,
@@ -463,6 +607,160 @@ pub fn interpolate(
     )))
 }
 
+/// Applies a [3D interpolation](burn_backend::ops::ModuleOps::interpolate3d), for example a
+/// trilinear interpolation with the [Bilinear](InterpolateMode::Bilinear) mode.
+pub fn interpolate3d(
+    x: Tensor<5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> Tensor<5> {
+    Tensor::new(BridgeTensor::float(Dispatch::interpolate3d(
+        x.primitive.into_float(),
+        output_size,
+        options,
+    )))
+}
+
+/// Applies an antialiased 2D interpolation.
+///
+/// Each dimension is resampled with a filter widened by the downsampling factor, so every input
+/// value contributes to the output, which matches PIL and torchvision. Only supported with the
+/// [Bilinear](InterpolateMode::Bilinear), [Bicubic](InterpolateMode::Bicubic) and
+/// [Lanczos3](InterpolateMode::Lanczos3) modes.
+///
+/// The filter is applied as matrix products with the filter weights, no backend kernel is
+/// involved.
+pub fn interpolate_antialiased(
+    x: Tensor<4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> Tensor<4> {
+    interpolate_antialias(x, &output_size, &options)
+}
+
+/// Applies an antialiased 3D interpolation.
+///
+/// See [interpolate_antialiased] for the supported modes.
+pub fn interpolate3d_antialiased(
+    x: Tensor<5>,
+    output_size: [usize; 3],
+    options: InterpolateOptions,
+) -> Tensor<5> {
+    interpolate_antialias(x, &output_size, &options)
+}
+
+/// Resamples the last `output_size.len()` dimensions one after the other, as a product with the
+/// matrix of the filter weights.
+fn interpolate_antialias<const D: usize>(
+    x: Tensor<D>,
+    output_size: &[usize],
+    options: &InterpolateOptions,
+) -> Tensor<D> {
+    check!(TensorCheck::interpolate_antialias(&options.mode));
+
+    let device = x.device();
+    let dtype = x.dtype();
+    let first = D - output_size.len();
+
+    output_size.iter().enumerate().fold(x, |x, (i, &size_out)| {
+        let dim = first + i;
+        let size_in = x.dims()[dim];
+        if size_in == size_out {
+            return x;
+        }
+
+        let weights = antialias_weights(size_in, size_out, &options.mode, options.align_corners);
+        let mut shape = [1; D];
+        shape[D - 2] = size_in;
+        shape[D - 1] = size_out;
+        let weights = Tensor::<D>::from_data(TensorData::new(weights, shape), (&device, dtype));
+
+        x.swap_dims(dim, D - 1)
+            .matmul(weights)
+            .swap_dims(dim, D - 1)
+    })
+}
+
+/// The `[size_in, size_out]` weights of an antialiased resampling along one dimension.
+///
+/// Follows PyTorch's antialiased upsampling kernels and PIL: the output `i` is centered on the
+/// input coordinate `(i + 0.5) * scale`, the support of the filter grows with the scale when
+/// downsampling, and the weights of each output are normalized to sum to one.
+fn antialias_weights(
+    size_in: usize,
+    size_out: usize,
+    mode: &InterpolateMode,
+    align_corners: bool,
+) -> Vec<f32> {
+    let (filter, support): (fn(f64) -> f64, f64) = match mode {
+        InterpolateMode::Bilinear => (|x| (1.0 - x.abs()).max(0.0), 1.0),
+        InterpolateMode::Bicubic => (cubic_filter, 2.0),
+        InterpolateMode::Lanczos3 => (lanczos3_filter, 3.0),
+        _ => unreachable!("Checked by interpolate_antialias"),
+    };
+    let scale = match align_corners {
+        true if size_out > 1 => (size_in - 1) as f64 / (size_out - 1) as f64,
+        true => 0.0,
+        false => size_in as f64 / size_out as f64,
+    };
+    let (support, inv_scale) = if scale >= 1.0 {
+        (support * scale, 1.0 / scale)
+    } else {
+        (support, 1.0)
+    };
+
+    let mut weights = vec![0.0; size_in * size_out];
+    for i in 0..size_out {
+        let center = scale * (i as f64 + 0.5);
+        // Truncated toward zero like the reference implementations.
+        let start = ((center - support + 0.5) as i64).max(0) as usize;
+        let end = ((center + support + 0.5) as i64).min(size_in as i64).max(0) as usize;
+
+        let row: Vec<f64> = (start..end)
+            .map(|j| filter((j as f64 - center + 0.5) * inv_scale))
+            .collect();
+        let total: f64 = row.iter().sum();
+        let total = if total == 0.0 { 1.0 } else { total };
+
+        for (j, weight) in (start..end).zip(row) {
+            weights[j * size_out + i] = (weight / total) as f32;
+        }
+    }
+
+    weights
+}
+
+/// The cubic convolution filter with `a = -0.5`, used by PIL and the antialiased bicubic of
+/// PyTorch.
+fn cubic_filter(x: f64) -> f64 {
+    const A: f64 = -0.5;
+    let x = x.abs();
+    if x < 1.0 {
+        ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0
+    } else if x < 2.0 {
+        (((x - 5.0) * x + 8.0) * x - 4.0) * A
+    } else {
+        0.0
+    }
+}
+
+fn lanczos3_filter(x: f64) -> f64 {
+    let sinc = |x: f64| {
+        if x == 0.0 {
+            1.0
+        } else {
+            let x = x * core::f64::consts::PI;
+            x.sin() / x
+        }
+    };
+
+    if x.abs() < 3.0 {
+        sinc(x) * sinc(x / 3.0)
+    } else {
+        0.0
+    }
+}
+
 /// Applies a linear transformation to the input tensor using the given weight and bias.
 ///
 /// ```math
@@ -552,7 +850,8 @@ fn linear_impl(
 
 /// Computes scaled dot-product attention: softmax(QKᵗ * scale) · V,
 /// where scale defaults to 1/sqrt(head_dim) (configurable via `options.scale`).
-/// Optionally applies masking, additive bias, causal masking, and softcap.
+/// Optionally applies masking, additive bias, causal and sliding window masking, ALiBi,
+/// and softcap.
 ///
 /// # Arguments
 /// - `query`: Query tensor of shape `[batch_size, num_heads, seq_len_q, head_dim]`
@@ -561,8 +860,9 @@ fn linear_impl(
 /// - `mask`: Optional boolean mask of shape `[batch_size, num_heads, seq_len_q, seq_len_k]`,
 ///   where `true` indicates positions to mask (i.e. set to -inf before softmax).
 /// - `attn_bias`: Optional float tensor of shape `[batch_size, num_heads, seq_len_q, seq_len_k]`
-///   added to the attention scores before softmax (e.g. ALiBi, relative position biases).
-/// - `options`: Additional attention options (custom scale, softcap, causal masking).
+///   added to the attention scores before softmax (e.g. relative position biases).
+/// - `options`: Additional attention options (custom scale, softcap, causal masking,
+///   sliding window and ALiBi).
 ///
 /// # Returns
 /// A tensor of shape `[batch_size, num_heads, seq_len_q, val_dim]`
@@ -589,6 +889,41 @@ pub fn attention(
     )))
 }
 
+/// Computes the attention of a batch of sequences over the keys and values of a paged KV cache,
+/// see [paged_attention](burn_backend::ops::ModuleOps::paged_attention).
+///
+/// # Arguments
+/// - `query`: Query tensor of shape `[batch_size, num_heads, seq_len_q, head_dim]`, the last
+///   `seq_len_q` tokens of each sequence.
+/// - `key_blocks`: Key blocks of shape `[num_blocks, num_kv_heads, block_size, head_dim]`
+/// - `value_blocks`: Value blocks of shape `[num_blocks, num_kv_heads, block_size, val_dim]`
+/// - `block_tables`: The blocks of each sequence `[batch_size, max_blocks]`, padded with any
+///   valid block index.
+/// - `seq_lengths`: The number of tokens of each sequence `[batch_size]`, including the queries.
+/// - `options`: Additional attention options (custom scale, softcap, sliding window and ALiBi).
+///   The attention is always causal.
+///
+/// # Returns
+/// A tensor of shape `[batch_size, num_heads, seq_len_q, val_dim]`
+/// representing the attended context per head.
+pub fn paged_attention(
+    query: Tensor<4>,
+    key_blocks: Tensor<4>,
+    value_blocks: Tensor<4>,
+    block_tables: Tensor<2, Int>,
+    seq_lengths: Tensor<1, Int>,
+    options: AttentionModuleOptions,
+) -> Tensor<4> {
+    Tensor::new(BridgeTensor::float(Dispatch::paged_attention(
+        query.primitive.into_float(),
+        key_blocks.primitive.into_float(),
+        value_blocks.primitive.into_float(),
+        block_tables.primitive.into(),
+        seq_lengths.primitive.into(),
+        options,
+    )))
+}
+
 /// Exports attention fallback to test backend's attention against.
 pub fn attention_fallback(
     query: Tensor<4>,
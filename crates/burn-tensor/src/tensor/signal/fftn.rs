@@ -0,0 +1,489 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_backend::ops::ModuleOps;
+use burn_dispatch::Dispatch;
+
+use crate::check::unwrap_dim_index;
+use crate::ops::{BasicOps, BridgeTensor, Numeric};
+use crate::{AsIndex, Float, Shape, Slice, Tensor};
+
+/// Normalization mode of the complex FFT functions, following the NumPy and PyTorch convention.
+///
+/// With `n` the number of points of the transform, the forward and inverse transforms are
+/// scaled by:
+///
+/// | Mode       | Forward         | Inverse         |
+/// |------------|-----------------|-----------------|
+/// | `Backward` | `1`             | `1 / n`         |
+/// | `Ortho`    | `1 / sqrt(n)`   | `1 / sqrt(n)`   |
+/// | `Forward`  | `1 / n`         | `1`             |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FftNorm {
+    /// Only the inverse transform is scaled, by `1 / n`.
+    #[default]
+    Backward,
+    /// Both transforms are scaled by `1 / sqrt(n)`, making them unitary.
+    Ortho,
+    /// Only the forward transform is scaled, by `1 / n`.
+    Forward,
+}
+
+impl FftNorm {
+    /// The factor applied to a transform over `n` points.
+    fn scale(self, n: usize, inverse: bool) -> f64 {
+        let n = n as f64;
+        match (self, inverse) {
+            (Self::Backward, false) | (Self::Forward, true) => 1.0,
+            (Self::Backward, true) | (Self::Forward, false) => 1.0 / n,
+            (Self::Ortho, _) => 1.0 / n.sqrt(),
+        }
+    }
+}
+
+/// Computes the 1-dimensional discrete Fourier Transform of complex-valued input.
+///
+/// Complex tensors hold the real and imaginary parts in a trailing dimension of size 2, the
+/// same layout as the output of [`stft`](super::stft). The signal dimension `dim` indexes the
+/// other dimensions.
+///
+#[cfg_attr(
+    doc,
+    doc = r#"
+The mathematical formulation for each element $k$ in the frequency domain is:
+
+$$X\[k\] = \sum_{n=0}^{N-1} x\[n\] e^{-i 2\pi kn/N}$$
+
+where $N$ is the size of the signal along the specified dimension, before normalization.
+"#
+)]
+#[cfg_attr(not(doc), doc = r"X\[k\] = Σ x\[n\] * exp(-i*2πkn/N)")]
+///
+/// # Arguments
+///
+/// * `signal` - The complex input tensor of shape `[..., 2]`.
+/// * `dim` - The dimension along which to take the FFT, excluding the trailing real/imaginary
+///   dimension. Negative dimensions are supported and count from the last signal dimension.
+/// * `n` - Optional FFT length. When `None`, the signal must be a power of two along `dim`.
+///   When `Some(n)`, `n` must also be a power of two; the signal is truncated or zero-padded
+///   to length `n`.
+/// * `norm` - The normalization mode, see [`FftNorm`].
+///
+/// # Returns
+///
+/// The complex spectrum of shape `[..., 2]`, with `n` elements along `dim`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::Tensor;
+/// use burn_tensor::signal::{FftNorm, fft};
+///
+/// let device = Default::default();
+/// // x[n] = exp(i * 2π * n / 4)
+/// let signal = Tensor::<2>::from_floats(
+///     [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]],
+///     &device,
+/// );
+/// let spectrum = fft(signal, 0, None, FftNorm::Backward);
+/// // [[0.0, 0.0], [4.0, 0.0], [0.0, 0.0], [0.0, 0.0]]
+/// ```
+pub fn fft<const D: usize>(
+    signal: Tensor<D>,
+    dim: impl AsIndex,
+    n: Option<usize>,
+    norm: FftNorm,
+) -> Tensor<D> {
+    fftn(signal, [dim], n.map(|n| [n]), norm)
+}
+
+/// Computes the 1-dimensional inverse discrete Fourier Transform of complex-valued input.
+///
+/// See [`fft`] for the layout of complex tensors.
+///
+/// # Arguments
+///
+/// * `spectrum` - The complex spectrum of shape `[..., 2]`.
+/// * `dim` - The dimension along which to take the inverse FFT, excluding the trailing
+///   real/imaginary dimension. Negative dimensions are supported.
+/// * `n` - Optional FFT length, with the same constraints as [`fft`].
+/// * `norm` - The normalization mode, see [`FftNorm`].
+///
+/// # Returns
+///
+/// The complex signal of shape `[..., 2]`, with `n` elements along `dim`.
+pub fn ifft<const D: usize>(
+    spectrum: Tensor<D>,
+    dim: impl AsIndex,
+    n: Option<usize>,
+    norm: FftNorm,
+) -> Tensor<D> {
+    ifftn(spectrum, [dim], n.map(|n| [n]), norm)
+}
+
+/// Computes the 2-dimensional discrete Fourier Transform of complex-valued input, over the
+/// last two signal dimensions.
+///
+/// # Arguments
+///
+/// * `signal` - The complex input tensor of shape `[..., H, W, 2]`.
+/// * `s` - Optional FFT lengths `[h, w]`, with the same constraints as `n` in [`fft`].
+/// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is `h * w`.
+///
+/// # Returns
+///
+/// The complex spectrum of shape `[..., h, w, 2]`.
+pub fn fft2<const D: usize>(signal: Tensor<D>, s: Option<[usize; 2]>, norm: FftNorm) -> Tensor<D> {
+    fftn(signal, [-2, -1], s, norm)
+}
+
+/// Computes the 2-dimensional inverse discrete Fourier Transform of complex-valued input,
+/// over the last two signal dimensions.
+///
+/// # Arguments
+///
+/// * `spectrum` - The complex spectrum of shape `[..., H, W, 2]`.
+/// * `s` - Optional FFT lengths `[h, w]`, with the same constraints as `n` in [`fft`].
+/// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is `h * w`.
+///
+/// # Returns
+///
+/// The complex signal of shape `[..., h, w, 2]`.
+pub fn ifft2<const D: usize>(
+    spectrum: Tensor<D>,
+    s: Option<[usize; 2]>,
+    norm: FftNorm,
+) -> Tensor<D> {
+    ifftn(spectrum, [-2, -1], s, norm)
+}
+
+/// Computes the N-dimensional discrete Fourier Transform of complex-valued input.
+///
+/// The transform is separable: a 1-dimensional FFT is taken along each dimension of `dims`.
+///
+/// # Arguments
+///
+/// * `signal` - The complex input tensor of shape `[..., 2]`.
+/// * `dims` - The dimensions along which to take the FFT, excluding the trailing
+///   real/imaginary dimension. Negative dimensions are supported.
+/// * `s` - Optional FFT length for each dimension of `dims`, with the same constraints as `n`
+///   in [`fft`].
+/// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is the product of
+///   the FFT lengths.
+///
+/// # Returns
+///
+/// The complex spectrum of shape `[..., 2]`, with the FFT lengths along `dims`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::Tensor;
+/// use burn_tensor::signal::{FftNorm, fftn, ifftn};
+///
+/// let device = Default::default();
+/// let signal = Tensor::<4>::ones([2, 4, 8, 2], &device);
+/// let spectrum = fftn(signal, [0, 1, 2], None, FftNorm::Ortho);
+/// let signal = ifftn(spectrum, [0, 1, 2], None, FftNorm::Ortho);
+/// ```
+pub fn fftn<const D: usize, const N: usize>(
+    signal: Tensor<D>,
+    dims: [impl AsIndex; N],
+    s: Option<[usize; N]>,
+    norm: FftNorm,
+) -> Tensor<D> {
+    let (dims, sizes) = fft_dims(&signal.shape(), dims, s, "fftn");
+    Tensor::new(fftn_impl(signal.primitive, &dims, &sizes, norm, false))
+}
+
+/// Computes the N-dimensional inverse discrete Fourier Transform of complex-valued input.
+///
+/// # Arguments
+///
+/// * `spectrum` - The complex spectrum of shape `[..., 2]`.
+/// * `dims` - The dimensions along which to take the inverse FFT, excluding the trailing
+///   real/imaginary dimension. Negative dimensions are supported.
+/// * `s` - Optional FFT length for each dimension of `dims`, with the same constraints as `n`
+///   in [`fft`].
+/// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is the product of
+///   the FFT lengths.
+///
+/// # Returns
+///
+/// The complex signal of shape `[..., 2]`, with the FFT lengths along `dims`.
+pub fn ifftn<const D: usize, const N: usize>(
+    spectrum: Tensor<D>,
+    dims: [impl AsIndex; N],
+    s: Option<[usize; N]>,
+    norm: FftNorm,
+) -> Tensor<D> {
+    let (dims, sizes) = fft_dims(&spectrum.shape(), dims, s, "ifftn");
+    Tensor::new(fftn_impl(spectrum.primitive, &dims, &sizes, norm, true))
+}
+
+/// Computes the 2-dimensional discrete Fourier Transform of real-valued input, over the last
+/// two dimensions.
+///
+/// Since the input is real, only the first `w / 2 + 1` bins of the last dimension are returned,
+/// like [`rfft`](super::rfft).
+///
+/// # Arguments
+///
+/// * `signal` - The real input tensor of shape `[..., H, W]`.
+/// * `s` - Optional FFT lengths `[h, w]`, with the same constraints as `n` in [`fft`].
+/// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is `h * w`.
+///
+/// # Returns
+///
+/// The complex spectrum of shape `[..., h, w / 2 + 1, 2]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the real input, at least 2.
+/// - `D2`: The number of dimensions of the complex output, `D + 1`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::Tensor;
+/// use burn_tensor::signal::{FftNorm, irfft2, rfft2};
+///
+/// let device = Default::default();
+/// let image = Tensor::<3>::ones([3, 8, 8], &device);
+/// let spectrum: Tensor<4> = rfft2(image, None, FftNorm::Backward);
+/// // Shape: [3, 8, 5, 2]
+/// let image: Tensor<3> = irfft2(spectrum, Some([8, 8]), FftNorm::Backward);
+/// ```
+pub fn rfft2<const D: usize, const D2: usize>(
+    signal: Tensor<D>,
+    s: Option<[usize; 2]>,
+    norm: FftNorm,
+) -> Tensor<D2> {
+    assert!(D >= 2, "rfft2: the signal must have at least 2 dimensions");
+    assert!(
+        D2 == D + 1,
+        "rfft2: the output rank must be the input rank plus one, got {D2} for a {D}-D signal"
+    );
+    let shape = signal.shape();
+    let s = s.unwrap_or([shape[D - 2], shape[D - 1]]);
+    s.iter().for_each(|&n| assert_fft_size(n, "rfft2"));
+
+    Tensor::new(rfft2_impl(signal.primitive, s, norm))
+}
+
+/// Computes the inverse of [`rfft2`], reconstructing a real-valued signal from the
+/// non-redundant half of its 2-dimensional spectrum.
+///
+/// # Arguments
+///
+/// * `spectrum` - The complex spectrum of shape `[..., H, B, 2]`.
+/// * `s` - Optional output signal lengths `[h, w]`, with the same constraints as `n` in
+///   [`fft`]. Defaults to `[H, 2 * (B - 1)]`, where `B` is the number of frequency bins of
+///   the last signal dimension.
+/// * `norm` - The normalization mode, see [`FftNorm`]. The number of points is `h * w`.
+///
+/// # Returns
+///
+/// The real signal of shape `[..., h, w]`.
+///
+/// # Generic Parameters
+///
+/// - `D`: The number of dimensions of the complex input, at least 3.
+/// - `D2`: The number of dimensions of the real output, `D - 1`.
+pub fn irfft2<const D: usize, const D2: usize>(
+    spectrum: Tensor<D>,
+    s: Option<[usize; 2]>,
+    norm: FftNorm,
+) -> Tensor<D2> {
+    let shape = spectrum.shape();
+    assert_complex(&shape, "irfft2");
+    assert!(
+        D >= 3,
+        "irfft2: the spectrum must have at least 2 signal dimensions"
+    );
+    assert!(
+        D2 + 1 == D,
+        "irfft2: the output rank must be the input rank minus one, got {D2} for a {D}-D spectrum"
+    );
+    let s = s.unwrap_or([shape[D - 3], 2 * (shape[D - 2] - 1)]);
+    s.iter().for_each(|&n| assert_fft_size(n, "irfft2"));
+
+    Tensor::new(irfft2_impl(spectrum.primitive, s, norm))
+}
+
+fn assert_complex(shape: &Shape, op: &str) {
+    let rank = shape.num_dims();
+    assert!(
+        rank >= 2 && shape[rank - 1] == 2,
+        "{op}: complex tensors must hold the real and imaginary parts in a trailing dimension \
+         of size 2, got shape {:?}",
+        shape.as_slice()
+    );
+}
+
+fn assert_fft_size(n: usize, op: &str) {
+    assert!(
+        n >= 1 && n.is_power_of_two(),
+        "{op}: the FFT length must be a power of two, got {n}. True non-power-of-two \
+         DFT support is tracked as a follow-up (Bluestein's algorithm)."
+    );
+}
+
+/// Resolves the signal dimensions of a complex FFT and their FFT lengths.
+fn fft_dims<const N: usize>(
+    shape: &Shape,
+    dims: [impl AsIndex; N],
+    s: Option<[usize; N]>,
+    op: &str,
+) -> (Vec<usize>, Vec<usize>) {
+    assert_complex(shape, op);
+    signal_fft_dims(shape, shape.num_dims() - 1, dims, s, op)
+}
+
+/// Resolves the signal dimensions of a complex FFT over the first `signal_rank` dimensions of
+/// `shape`, and their FFT lengths.
+pub(crate) fn signal_fft_dims<const N: usize>(
+    shape: &Shape,
+    signal_rank: usize,
+    dims: [impl AsIndex; N],
+    s: Option<[usize; N]>,
+    op: &str,
+) -> (Vec<usize>, Vec<usize>) {
+    let dims: Vec<usize> = dims
+        .into_iter()
+        .map(|dim| unwrap_dim_index(dim.try_dim_index(signal_rank), op))
+        .collect();
+    for (i, dim) in dims.iter().enumerate() {
+        assert!(
+            !dims[..i].contains(dim),
+            "{op}: dimension {dim} is transformed more than once"
+        );
+    }
+
+    let sizes: Vec<usize> = match s {
+        Some(s) => s.to_vec(),
+        None => dims.iter().map(|&dim| shape[dim]).collect(),
+    };
+    sizes.iter().for_each(|&n| assert_fft_size(n, op));
+
+    (dims, sizes)
+}
+
+// =====================================================================
+// Non-generic implementation helpers (outlined from the generic API).
+// =====================================================================
+
+/// Splits a complex tensor `[..., 2]` into its real and imaginary parts, of shape `[..., 1]`.
+fn split_complex(tensor: BridgeTensor) -> (BridgeTensor, BridgeTensor) {
+    let rank = tensor.shape().num_dims();
+    let mut slices = vec![Slice::full(); rank];
+
+    slices[rank - 1] = Slice::new(0, Some(1), 1);
+    let re = Float::slice(tensor.clone(), &slices);
+    slices[rank - 1] = Slice::new(1, Some(2), 1);
+    let im = Float::slice(tensor, &slices);
+
+    (re, im)
+}
+
+/// Merges real and imaginary parts of shape `[..., 1]` into a complex tensor `[..., 2]`.
+fn merge_complex(re: BridgeTensor, im: BridgeTensor) -> BridgeTensor {
+    let rank = re.shape().num_dims();
+    Float::cat(vec![re, im], rank - 1)
+}
+
+/// Appends a trailing dimension of size 1.
+fn unsqueeze_last(tensor: BridgeTensor) -> BridgeTensor {
+    let mut dims = tensor.shape().as_slice().to_vec();
+    dims.push(1);
+    Float::reshape(tensor, Shape::from(dims))
+}
+
+fn scale(tensor: BridgeTensor, factor: f64) -> BridgeTensor {
+    if factor == 1.0 {
+        tensor
+    } else {
+        Float::mul_scalar(tensor, factor.into())
+    }
+}
+
+fn complex_fft(
+    re: BridgeTensor,
+    im: BridgeTensor,
+    dim: usize,
+    n: usize,
+    inverse: bool,
+) -> (BridgeTensor, BridgeTensor) {
+    let (re, im) = if inverse {
+        Dispatch::ifft(re.into_float(), im.into_float(), dim, Some(n))
+    } else {
+        Dispatch::fft(re.into_float(), im.into_float(), dim, Some(n))
+    };
+    (BridgeTensor::float(re), BridgeTensor::float(im))
+}
+
+fn fftn_impl(
+    tensor: BridgeTensor,
+    dims: &[usize],
+    sizes: &[usize],
+    norm: FftNorm,
+    inverse: bool,
+) -> BridgeTensor {
+    let (re, im) = split_complex(tensor);
+    let (re, im) = fftn_parts_impl(re, im, dims, sizes, norm, inverse);
+    merge_complex(re, im)
+}
+
+/// Complex FFT over the given dimensions of the real and imaginary parts.
+pub(crate) fn fftn_parts_impl(
+    mut re: BridgeTensor,
+    mut im: BridgeTensor,
+    dims: &[usize],
+    sizes: &[usize],
+    norm: FftNorm,
+    inverse: bool,
+) -> (BridgeTensor, BridgeTensor) {
+    for (&dim, &n) in dims.iter().zip(sizes) {
+        (re, im) = complex_fft(re, im, dim, n, inverse);
+    }
+
+    // The backend inverse transform is already scaled by `1 / n`.
+    let num_points: usize = sizes.iter().product();
+    let mut factor = norm.scale(num_points, inverse);
+    if inverse {
+        factor *= num_points as f64;
+    }
+
+    (scale(re, factor), scale(im, factor))
+}
+
+fn rfft2_impl(signal: BridgeTensor, s: [usize; 2], norm: FftNorm) -> BridgeTensor {
+    let rank = signal.shape().num_dims();
+
+    let (re, im) = Dispatch::rfft(signal.into_float(), rank - 1, Some(s[1]));
+    let (re, im) = (BridgeTensor::float(re), BridgeTensor::float(im));
+    let (re, im) = complex_fft(re, im, rank - 2, s[0], false);
+
+    let factor = norm.scale(s[0] * s[1], false);
+    merge_complex(
+        unsqueeze_last(scale(re, factor)),
+        unsqueeze_last(scale(im, factor)),
+    )
+}
+
+fn irfft2_impl(spectrum: BridgeTensor, s: [usize; 2], norm: FftNorm) -> BridgeTensor {
+    let rank = spectrum.shape().num_dims();
+
+    let (re, im) = split_complex(spectrum);
+    let (re, im) = complex_fft(re, im, rank - 3, s[0], true);
+    let signal = Dispatch::irfft(re.into_float(), im.into_float(), rank - 2, Some(s[1]));
+    let signal = BridgeTensor::float(signal);
+
+    // Drop the trailing real/imaginary dimension, which has size 1 after the inverse.
+    let mut dims = signal.shape().as_slice().to_vec();
+    dims.pop();
+    let signal = Float::reshape(signal, Shape::from(dims));
+
+    // The backend inverse transforms are already scaled by `1 / n`.
+    let num_points = s[0] * s[1];
+    scale(signal, norm.scale(num_points, true) * num_points as f64)
+}
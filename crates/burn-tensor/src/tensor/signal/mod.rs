@@ -1,11 +1,13 @@
 mod blackman_window;
 mod fft;
+mod fftn;
 mod hamming_window;
 mod hann_window;
 mod stft;
 
 pub use blackman_window::*;
 pub use fft::*;
+pub use fftn::*;
 pub use hamming_window::*;
 pub use hann_window::*;
 pub use stft::*;
@@ -0,0 +1,72 @@
+use burn_backend::ops::SparseTensorOps;
+use burn_dispatch::Dispatch;
+
+use crate::Tensor;
+use crate::check;
+use crate::check::TensorCheck;
+use crate::ops::BridgeTensor;
+use crate::sparse::{SparseLayout, SparseTensor};
+
+impl SparseTensor<2> {
+    /// Performs the matrix multiplication of the sparse matrix with a dense matrix.
+    ///
+    /// The rows of the dense matrix selected by the column of each value are scaled by the value
+    /// and accumulated in the output row of the value, so the cost is proportional to the number
+    /// of stored values. Duplicate indices are summed, so the matrix doesn't need to be coalesced.
+    ///
+    /// Backends can provide dedicated kernels for both layouts, see
+    /// [SparseTensorOps](burn_backend::ops::SparseTensorOps).
+    ///
+    /// With autodiff, the gradients flow to both the values and the dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `dense` - The dense matrix of shape `[k, n]`, where `k` is the number of columns of the
+    ///   sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// The dense matrix of shape `[m, n]`, where `m` is the number of rows of the sparse matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::{Int, Tensor};
+    /// use burn_tensor::sparse::SparseTensor;
+    ///
+    /// let device = Default::default();
+    /// let indices = Tensor::<2, Int>::from_data([[0, 1], [1, 0]], &device);
+    /// let values = Tensor::<1>::from_data([2.0, 3.0], &device);
+    /// let sparse = SparseTensor::from_coo(indices, values, [2, 2]);
+    /// let dense = Tensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    /// println!("{}", sparse.spmm(dense));
+    /// // [[6.0, 8.0], [3.0, 6.0]]
+    /// ```
+    pub fn spmm(self, dense: Tensor<2>) -> Tensor<2> {
+        check!(TensorCheck::spmm(&self.shape(), &dense.shape()));
+
+        let [rows, _] = self.dims();
+        let values = self.values().primitive.into_float();
+        let dense = dense.primitive.into_float();
+
+        let output = match self.layout() {
+            SparseLayout::Csr => Dispatch::sparse_csr_spmm(
+                self.crow_indices().unwrap().primitive.into(),
+                self.col_indices().unwrap().primitive.into(),
+                values,
+                dense,
+            ),
+            SparseLayout::Coo => {
+                let (row_indices, col_indices) = self.row_col_indices();
+                Dispatch::sparse_coo_spmm(
+                    row_indices.primitive.into(),
+                    col_indices.primitive.into(),
+                    values,
+                    dense,
+                    rows,
+                )
+            }
+        };
+        Tensor::new(BridgeTensor::float(output))
+    }
+}
@@ -0,0 +1,15 @@
+//! Sparse tensors in the coordinate (COO) and compressed sparse row (CSR) layouts.
+//!
+//! A [sparse tensor](SparseTensor) only stores its non-zero values with their indices. Most
+//! operations are composed of dense gather and scatter operations on the values, so they run on
+//! every backend and the gradients flow to the values and the dense operands with autodiff.
+//!
+//! Sparse tensors aren't a tensor kind like [Float](crate::Float): the backends receive their
+//! index tensors and values. The sparse-dense matrix multiplication goes through
+//! [SparseTensorOps](burn_backend::ops::SparseTensorOps), so backends can provide dedicated
+//! kernels for it.
+
+mod matmul;
+mod tensor;
+
+pub use tensor::*;
@@ -0,0 +1,434 @@
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::{TensorCheck, unwrap_dim_index};
+use crate::{AsIndex, Device, IndexingUpdateOp, Int, Shape, Tensor, s};
+
+/// The layout of the indices of a [sparse tensor](SparseTensor).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SparseLayout {
+    /// Coordinate layout, with the index of each value along every dimension.
+    Coo,
+    /// Compressed sparse row layout, with the offsets of the values of each row and the column of
+    /// each value. Only available for matrices.
+    Csr,
+}
+
+#[derive(Clone, Debug)]
+enum SparseIndices {
+    Coo {
+        /// The indices of the values, of shape `[D, nnz]`.
+        indices: Tensor<2, Int>,
+    },
+    Csr {
+        /// The offsets of the values of each row, of shape `[rows + 1]`.
+        crow_indices: Tensor<1, Int>,
+        /// The column of each value, of shape `[nnz]`.
+        col_indices: Tensor<1, Int>,
+    },
+}
+
+/// A float tensor where only the non-zero values are stored, with their indices.
+///
+/// The values are a regular float tensor, so gradients flow to them with autodiff. A sparse
+/// tensor is *coalesced* when its indices are unique and sorted in row-major order. Duplicate
+/// indices are allowed otherwise, and their values are summed.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::{Int, Tensor};
+/// use burn_tensor::sparse::SparseTensor;
+///
+/// let device = Default::default();
+/// let indices = Tensor::<2, Int>::from_data([[0, 1, 1], [2, 0, 2]], &device);
+/// let values = Tensor::<1>::from_data([3.0, 4.0, 5.0], &device);
+/// let sparse = SparseTensor::from_coo(indices, values, [2, 3]);
+/// println!("{}", sparse.to_dense());
+/// // [[0.0, 0.0, 3.0], [4.0, 0.0, 5.0]]
+/// ```
+#[derive(Clone, Debug)]
+pub struct SparseTensor<const D: usize> {
+    indices: SparseIndices,
+    values: Tensor<1>,
+    shape: [usize; D],
+    coalesced: bool,
+}
+
+impl<const D: usize> SparseTensor<D> {
+    /// Creates a sparse tensor in the coordinate layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The indices of the values along each dimension, of shape `[D, nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape of the dense tensor.
+    ///
+    /// The indices may be duplicated and unordered; see [coalesce](SparseTensor::coalesce).
+    pub fn from_coo(indices: Tensor<2, Int>, values: Tensor<1>, shape: [usize; D]) -> Self {
+        check!(TensorCheck::sparse_coo::<D>(
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self {
+            indices: SparseIndices::Coo { indices },
+            values,
+            shape,
+            coalesced: false,
+        }
+    }
+
+    /// Creates a sparse tensor in the coordinate layout from the non-zero elements of a dense
+    /// tensor.
+    ///
+    /// The result is coalesced. The number of non-zero elements is data-dependent, so this
+    /// synchronizes with the device, consistent with [`argwhere`](Tensor::argwhere).
+    pub fn from_dense(tensor: Tensor<D>) -> Self {
+        let shape = tensor.dims();
+        let indices = tensor.clone().not_equal_elem(0.0).argwhere().transpose();
+        let linear = linear_indices(indices.clone(), &shape);
+        let values = tensor.reshape([num_elements(&shape)]).select(0, linear);
+
+        Self {
+            indices: SparseIndices::Coo { indices },
+            values,
+            shape,
+            coalesced: true,
+        }
+    }
+
+    /// Converts the sparse tensor to a dense tensor, where the values of duplicate indices are
+    /// summed.
+    pub fn to_dense(self) -> Tensor<D> {
+        let shape = self.shape;
+        let values = self.values;
+        let linear = match self.indices {
+            SparseIndices::Coo { indices } => linear_indices(indices, &shape),
+            SparseIndices::Csr {
+                crow_indices,
+                col_indices,
+            } => {
+                let rows = expand_rows(crow_indices, col_indices.dims()[0]);
+                rows.mul_scalar(shape[D - 1] as i64).add(col_indices)
+            }
+        };
+
+        Tensor::<1>::zeros([num_elements(&shape)], (&values.device(), values.dtype()))
+            .select_assign(0, linear, values, IndexingUpdateOp::Add)
+            .reshape(shape)
+    }
+
+    /// Sums the values of duplicate indices and sorts the indices in row-major order.
+    ///
+    /// The layout is preserved. Coalescing an already coalesced tensor is a no-op. The number of
+    /// unique indices is data-dependent, so this synchronizes with the device, consistent with
+    /// [`unique`](Tensor::unique).
+    ///
+    /// # Notes
+    ///
+    /// The indices are linearized in the integer data type of the indices, so the number of
+    /// elements of the dense tensor must fit in it.
+    pub fn coalesce(self) -> Self {
+        if self.coalesced {
+            return self;
+        }
+
+        let layout = self.layout();
+        let shape = self.shape;
+        let indices = self.indices();
+        let values = self.values;
+
+        let unique = linear_indices(indices, &shape).unique(true, true, false);
+        let inverse = unique.inverse.expect("Inverse indices should be returned");
+        let num_unique = unique.values.dims()[0];
+        let values = Tensor::<1>::zeros([num_unique], (&values.device(), values.dtype()))
+            .select_assign(0, inverse, values, IndexingUpdateOp::Add);
+
+        let coalesced = Self {
+            indices: SparseIndices::Coo {
+                indices: unravel_indices(unique.values, &shape),
+            },
+            values,
+            shape,
+            coalesced: true,
+        };
+
+        match layout {
+            SparseLayout::Coo => coalesced,
+            SparseLayout::Csr => coalesced.into_csr(),
+        }
+    }
+
+    /// Converts the sparse tensor to the coordinate layout.
+    pub fn to_coo(self) -> Self {
+        let indices = self.indices();
+        Self {
+            indices: SparseIndices::Coo { indices },
+            ..self
+        }
+    }
+
+    /// Sums the values along the given dimension, which is kept with a size of one.
+    ///
+    /// The result is in the coordinate layout and is not coalesced, since the values that are
+    /// reduced together share the same indices. Use [coalesce](SparseTensor::coalesce) to sum
+    /// them.
+    pub fn sum_dim(self, dim: impl AsIndex) -> Self {
+        let dim = unwrap_dim_index(dim.try_dim_index(D), "Sparse Sum Dim");
+        let mut shape = self.shape;
+        shape[dim] = 1;
+        let indices = self.indices().slice_fill(s![dim..dim + 1, ..], 0);
+
+        Self {
+            indices: SparseIndices::Coo { indices },
+            values: self.values,
+            shape,
+            coalesced: false,
+        }
+    }
+
+    /// Sums all the values.
+    pub fn sum(self) -> Tensor<1> {
+        self.values.sum()
+    }
+
+    /// Returns the indices of the values along each dimension, of shape `[D, nnz]`.
+    ///
+    /// For the [CSR](SparseLayout::Csr) layout, the row indices are expanded from the row offsets.
+    pub fn indices(&self) -> Tensor<2, Int> {
+        match &self.indices {
+            SparseIndices::Coo { indices } => indices.clone(),
+            SparseIndices::Csr {
+                crow_indices,
+                col_indices,
+            } => {
+                let rows = expand_rows(crow_indices.clone(), self.nnz());
+                Tensor::stack(alloc::vec![rows, col_indices.clone()], 0)
+            }
+        }
+    }
+
+    /// Returns the stored values, of shape `[nnz]`.
+    pub fn values(&self) -> Tensor<1> {
+        self.values.clone()
+    }
+
+    /// Returns the number of stored values.
+    ///
+    /// Duplicate indices are counted separately when the tensor is not coalesced.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the shape of the dense tensor.
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.shape)
+    }
+
+    /// Returns the dimensions of the dense tensor.
+    pub fn dims(&self) -> [usize; D] {
+        self.shape
+    }
+
+    /// Returns the layout of the indices.
+    pub fn layout(&self) -> SparseLayout {
+        match self.indices {
+            SparseIndices::Coo { .. } => SparseLayout::Coo,
+            SparseIndices::Csr { .. } => SparseLayout::Csr,
+        }
+    }
+
+    /// Whether the indices are unique and sorted in row-major order.
+    pub fn is_coalesced(&self) -> bool {
+        self.coalesced
+    }
+
+    /// Returns the device of the values.
+    pub fn device(&self) -> Device {
+        self.values.device()
+    }
+
+    /// Converts a coalesced tensor in the coordinate layout to the compressed sparse row layout.
+    fn into_csr(self) -> Self {
+        let indices = self.indices();
+        let nnz = self.nnz();
+        let rows = self.shape[0];
+        let row_indices = indices.clone().slice(s![0..1, ..]).reshape([nnz]);
+        let col_indices = indices.slice(s![1..2, ..]).reshape([nnz]);
+
+        // Counts the values of each row after its offset, then accumulates the counts.
+        let crow_indices =
+            Tensor::<1, Int>::zeros([rows + 1], (&row_indices.device(), row_indices.dtype()))
+                .select_assign(
+                    0,
+                    row_indices.clone().add_scalar(1),
+                    row_indices.ones_like(),
+                    IndexingUpdateOp::Add,
+                )
+                .cumsum(0);
+
+        Self {
+            indices: SparseIndices::Csr {
+                crow_indices,
+                col_indices,
+            },
+            ..self
+        }
+    }
+}
+
+impl SparseTensor<2> {
+    /// Creates a sparse matrix in the compressed sparse row layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `crow_indices` - The offsets of the values of each row, of shape `[rows + 1]`, starting
+    ///   at zero and ending at `nnz`.
+    /// * `col_indices` - The column of each value, of shape `[nnz]`.
+    /// * `values` - The values, of shape `[nnz]`.
+    /// * `shape` - The shape of the dense matrix.
+    pub fn from_csr(
+        crow_indices: Tensor<1, Int>,
+        col_indices: Tensor<1, Int>,
+        values: Tensor<1>,
+        shape: [usize; 2],
+    ) -> Self {
+        check!(TensorCheck::sparse_csr(
+            &crow_indices.shape(),
+            &col_indices.shape(),
+            &values.shape(),
+            &Shape::new(shape)
+        ));
+
+        Self {
+            indices: SparseIndices::Csr {
+                crow_indices,
+                col_indices,
+            },
+            values,
+            shape,
+            coalesced: false,
+        }
+    }
+
+    /// Converts the sparse matrix to the compressed sparse row layout, coalescing it.
+    ///
+    /// A matrix created with [from_csr](SparseTensor::from_csr) may have duplicate or unsorted
+    /// columns within a row, so it is coalesced as well.
+    pub fn to_csr(self) -> Self {
+        match self.layout() {
+            SparseLayout::Csr => self.coalesce(),
+            SparseLayout::Coo => self.coalesce().into_csr(),
+        }
+    }
+
+    /// Returns the offsets of the values of each row, of shape `[rows + 1]`, when the matrix is
+    /// in the [CSR](SparseLayout::Csr) layout.
+    pub fn crow_indices(&self) -> Option<Tensor<1, Int>> {
+        match &self.indices {
+            SparseIndices::Coo { .. } => None,
+            SparseIndices::Csr { crow_indices, .. } => Some(crow_indices.clone()),
+        }
+    }
+
+    /// Returns the column of each value, of shape `[nnz]`, when the matrix is in the
+    /// [CSR](SparseLayout::Csr) layout.
+    pub fn col_indices(&self) -> Option<Tensor<1, Int>> {
+        match &self.indices {
+            SparseIndices::Coo { .. } => None,
+            SparseIndices::Csr { col_indices, .. } => Some(col_indices.clone()),
+        }
+    }
+
+    /// Returns the row and the column of each value.
+    pub(crate) fn row_col_indices(&self) -> (Tensor<1, Int>, Tensor<1, Int>) {
+        let nnz = self.nnz();
+        match &self.indices {
+            SparseIndices::Coo { indices } => (
+                indices.clone().slice(s![0..1, ..]).reshape([nnz]),
+                indices.clone().slice(s![1..2, ..]).reshape([nnz]),
+            ),
+            SparseIndices::Csr {
+                crow_indices,
+                col_indices,
+            } => (expand_rows(crow_indices.clone(), nnz), col_indices.clone()),
+        }
+    }
+}
+
+impl<const D: usize> Tensor<D> {
+    /// Converts the tensor to a [sparse tensor](SparseTensor) in the coordinate layout, keeping
+    /// its non-zero elements.
+    ///
+    /// See [SparseTensor::from_dense].
+    pub fn to_sparse(self) -> SparseTensor<D> {
+        SparseTensor::from_dense(self)
+    }
+}
+
+fn num_elements(shape: &[usize]) -> usize {
+    shape.iter().product()
+}
+
+/// Row-major strides of the given shape.
+fn strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = alloc::vec![1; shape.len()];
+    for d in (0..shape.len().saturating_sub(1)).rev() {
+        strides[d] = strides[d + 1] * shape[d + 1];
+    }
+    strides
+}
+
+/// Linearizes indices of shape `[D, nnz]` in row-major order.
+fn linear_indices(indices: Tensor<2, Int>, shape: &[usize]) -> Tensor<1, Int> {
+    let [rank, nnz] = indices.dims();
+    let strides = strides(shape);
+
+    (0..rank)
+        .map(|d| {
+            indices
+                .clone()
+                .slice(s![d..d + 1, ..])
+                .reshape([nnz])
+                .mul_scalar(strides[d] as i64)
+        })
+        .reduce(|acc, index| acc.add(index))
+        .expect("Sparse tensors should have at least one dimension")
+}
+
+/// Inverse of [linear_indices], returning indices of shape `[D, nnz]`.
+fn unravel_indices(linear: Tensor<1, Int>, shape: &[usize]) -> Tensor<2, Int> {
+    let strides = strides(shape);
+    let indices: Vec<Tensor<1, Int>> = shape
+        .iter()
+        .zip(strides)
+        .map(|(&size, stride)| {
+            linear
+                .clone()
+                .div_scalar(stride as i64)
+                .remainder_scalar(size as i64)
+        })
+        .collect();
+
+    Tensor::stack(indices, 0)
+}
+
+/// Expands row offsets of shape `[rows + 1]` to the row of each of the `nnz` values.
+fn expand_rows(crow_indices: Tensor<1, Int>, nnz: usize) -> Tensor<1, Int> {
+    let rows = crow_indices.dims()[0] - 1;
+    let options = (&crow_indices.device(), crow_indices.dtype());
+    if rows <= 1 {
+        return Tensor::zeros([nnz], options);
+    }
+
+    // Every row offset after the first one marks the start of a new row, so the row of a value is
+    // the number of offsets at or before its position. Offsets of trailing empty rows are equal to
+    // `nnz`, hence the extra position.
+    let starts = crow_indices.slice(s![1..rows]);
+    let markers = Tensor::<1, Int>::ones([rows - 1], options);
+    Tensor::<1, Int>::zeros([nnz + 1], options)
+        .select_assign(0, starts, markers, IndexingUpdateOp::Add)
+        .cumsum(0)
+        .slice(s![0..nnz])
+}
@@ -1,5 +1,25 @@
+use alloc::vec;
+
 use crate::{Int, Tensor};
 
+/// How [quantile](Tensor::quantile) picks a value when the quantile falls between two
+/// elements `lower <= upper` of the sorted data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuantileInterpolation {
+    /// `lower + (upper - lower) * fraction`, with `fraction` the position of the quantile
+    /// between the two elements.
+    #[default]
+    Linear,
+    /// The lower element.
+    Lower,
+    /// The higher element.
+    Higher,
+    /// The nearest element, rounding half to even.
+    Nearest,
+    /// `(lower + upper) / 2`.
+    Midpoint,
+}
+
 pub fn var<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
     let mean = tensor.clone().mean_dim(dim);
     var_with_mean(tensor, mean, dim)
@@ -67,3 +87,141 @@ pub fn median_with_indices<const D: usize>(
     let median_indices = indices.narrow(dim, median_index, 1);
     (median_values, median_indices)
 }
+
+pub fn quantile<const D: usize>(
+    tensor: Tensor<D>,
+    q: f64,
+    dim: usize,
+    interpolation: QuantileInterpolation,
+) -> Tensor<D> {
+    let n = tensor.dims()[dim];
+    let has_nan = tensor.clone().is_nan().any_dim(dim);
+    let sorted = tensor.sort(dim);
+    let count = sorted.clone().narrow(dim, 0, 1).full_like(n as f64);
+
+    // Like PyTorch, a single NaN makes the quantile NaN.
+    quantile_sorted(sorted, count, q, dim, interpolation).mask_fill(has_nan, f64::NAN)
+}
+
+pub fn nanquantile<const D: usize>(
+    tensor: Tensor<D>,
+    q: f64,
+    dim: usize,
+    interpolation: QuantileInterpolation,
+) -> Tensor<D> {
+    let is_nan = tensor.clone().is_nan();
+    let count = tensor
+        .ones_like()
+        .mask_fill(is_nan.clone(), 0.0)
+        .sum_dim(dim);
+    // NaN is replaced by infinity so that the valid elements come first once sorted.
+    let sorted = tensor.mask_fill(is_nan, f64::INFINITY).sort(dim);
+    let all_nan = count.clone().equal_elem(0.0);
+
+    quantile_sorted(sorted, count, q, dim, interpolation).mask_fill(all_nan, f64::NAN)
+}
+
+/// Computes the quantile of the `count` first elements of each slice of `sorted` along `dim`.
+///
+/// `count` has a size of 1 along `dim`, so the position of the quantile can differ per slice.
+fn quantile_sorted<const D: usize>(
+    sorted: Tensor<D>,
+    count: Tensor<D>,
+    q: f64,
+    dim: usize,
+    interpolation: QuantileInterpolation,
+) -> Tensor<D> {
+    let last = (sorted.dims()[dim] - 1) as f64;
+    let position = count.sub_scalar(1.0).mul_scalar(q).clamp(0.0, last);
+    let at = |index: Tensor<D>| sorted.clone().gather(dim, index.int());
+
+    match interpolation {
+        QuantileInterpolation::Lower => at(position.floor()),
+        QuantileInterpolation::Higher => at(position.ceil()),
+        QuantileInterpolation::Nearest => at(position.round()),
+        QuantileInterpolation::Midpoint => {
+            let lower = at(position.clone().floor());
+            (lower + at(position.ceil())).div_scalar(2.0)
+        }
+        QuantileInterpolation::Linear => {
+            let lower_position = position.clone().floor();
+            let lower = at(lower_position.clone());
+            let upper = at(position.clone().ceil());
+            let fraction = position - lower_position;
+            lower.clone() + (upper - lower) * fraction
+        }
+    }
+}
+
+pub fn mode<const D: usize>(tensor: Tensor<D>, dim: usize) -> (Tensor<D>, Tensor<D, Int>) {
+    let n = tensor.dims()[dim];
+    let (sorted, indices) = tensor.sort_with_indices(dim);
+
+    // Equal elements are contiguous once sorted, so each run starts where an element differs
+    // from the previous one.
+    let positions = Tensor::<1, Int>::arange(0..n as i64, &sorted.device())
+        .reshape(broadcast_dims::<D>(dim, n))
+        .expand(sorted.dims());
+    let starts = if n > 1 {
+        let previous = sorted.clone().narrow(dim, 0, n - 1);
+        let repeated = sorted.clone().narrow(dim, 1, n - 1).equal(previous);
+        Tensor::cat(
+            vec![
+                positions.clone().narrow(dim, 0, 1),
+                positions
+                    .clone()
+                    .narrow(dim, 1, n - 1)
+                    .mask_fill(repeated, 0),
+            ],
+            dim,
+        )
+    } else {
+        positions.clone()
+    };
+
+    // The length of the run so far peaks at the last element of each run, and the first
+    // maximum is the smallest of the most frequent values.
+    let run_length = positions - starts.cummax(dim);
+    let position = run_length.argmax(dim);
+
+    let values = sorted.gather(dim, position.clone());
+    let indices = indices.gather(dim, position);
+    (values, indices)
+}
+
+/// Shape with `size` along `dim` and 1 everywhere else.
+fn broadcast_dims<const D: usize>(dim: usize, size: usize) -> [usize; D] {
+    let mut dims = [1; D];
+    dims[dim] = size;
+    dims
+}
+
+pub fn cov_batched<const D: usize>(
+    tensor: Tensor<D>,
+    dim: usize,
+    correction_factor: usize,
+) -> Tensor<D> {
+    let n = tensor.dims()[dim];
+    let centered = centered_observations(tensor, dim);
+    centered
+        .clone()
+        .matmul(centered.transpose())
+        .div_scalar(n as f32 - correction_factor as f32)
+}
+
+pub fn corrcoef<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
+    let centered = centered_observations(tensor, dim);
+    let norm = centered.clone().square().sum_dim(D - 1).sqrt();
+    let cov = centered.clone().matmul(centered.transpose());
+
+    // Rounding errors can push the coefficients slightly out of range.
+    cov.div(norm.clone().matmul(norm.transpose()))
+        .clamp(-1.0, 1.0)
+}
+
+/// Moves the observations along `dim` to the last dimension and centers them, which gives
+/// `[..., variables, observations]`.
+fn centered_observations<const D: usize>(tensor: Tensor<D>, dim: usize) -> Tensor<D> {
+    let tensor = tensor.movedim(dim, D - 1);
+    tensor.clone() - tensor.mean_dim(D - 1)
+}
@@ -45,6 +45,7 @@ mod learning_rate;
 mod loss;
 mod perplexity;
 mod precision;
+mod privacy;
 mod recall;
 mod rouge;
 mod top_k_acc;
@@ -64,6 +65,7 @@ pub use learning_rate::*;
 pub use loss::*;
 pub use perplexity::*;
 pub use precision::*;
+pub use privacy::*;
 pub use recall::*;
 pub use rouge::*;
 pub use top_k_acc::*;
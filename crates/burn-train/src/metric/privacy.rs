@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use super::{
+    MetricAttributes, MetricMetadata, NumericAttributes, NumericEntry,
+    state::{FormatOptions, NumericMetricState},
+};
+use crate::metric::{Metric, MetricName, Numeric, SerializedEntry};
+use burn_optim::privacy::RdpAccountant;
+
+/// Track the privacy budget (epsilon) spent by a differentially private training.
+///
+/// The metric owns an [accountant](RdpAccountant) created with the same configuration as the
+/// [DP-SGD](burn_optim::privacy::DpSgd) optimizer of the training step, and records a step at
+/// each update, so it should be registered as a training metric.
+#[derive(Clone)]
+pub struct PrivacyBudgetMetric {
+    name: MetricName,
+    state: NumericMetricState,
+    accountant: RdpAccountant,
+    delta: f64,
+}
+
+impl PrivacyBudgetMetric {
+    /// Creates a new privacy budget metric reporting the epsilon for the given delta.
+    pub fn new(accountant: RdpAccountant, delta: f64) -> Self {
+        Self {
+            name: Arc::new("Epsilon".to_string()),
+            state: NumericMetricState::new(),
+            accountant,
+            delta,
+        }
+    }
+}
+
+impl Metric for PrivacyBudgetMetric {
+    type Input = ();
+
+    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> SerializedEntry {
+        self.accountant.step();
+        let epsilon = self.accountant.epsilon(self.delta);
+
+        self.state.update(epsilon, 1);
+        self.state
+            .compute_update(FormatOptions::new(self.name()).precision(3))
+    }
+
+    fn compute(&mut self) -> SerializedEntry {
+        self.state
+            .compute_final(FormatOptions::new(self.name()).precision(3))
+    }
+
+    fn clear(&mut self) {
+        // The accountant is kept, since the privacy loss accumulates over the whole training.
+        self.state.reset()
+    }
+
+    fn name(&self) -> MetricName {
+        self.name.clone()
+    }
+
+    fn attributes(&self) -> MetricAttributes {
+        NumericAttributes {
+            unit: None,
+            higher_is_better: false,
+        }
+        .into()
+    }
+}
+
+impl Numeric for PrivacyBudgetMetric {
+    fn value(&self) -> Option<NumericEntry> {
+        Some(self.state.current_value())
+    }
+
+    fn running_value(&self) -> Option<NumericEntry> {
+        Some(self.state.running_value())
+    }
+
+    fn final_value(&self) -> NumericEntry {
+        self.state.final_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_privacy_budget_accumulates_across_epochs() {
+        let mut accountant = RdpAccountant::new(1.0, 0.1);
+        let mut metric = PrivacyBudgetMetric::new(accountant.clone(), 1e-5);
+
+        let _entry = metric.update(&(), &MetricMetadata::fake());
+        metric.clear();
+        let _entry = metric.update(&(), &MetricMetadata::fake());
+
+        accountant.step();
+        accountant.step();
+        let epsilon = metric.value().unwrap().current();
+        assert!((epsilon - accountant.epsilon(1e-5)).abs() < 1e-9);
+    }
+}
@@ -97,7 +97,9 @@ fn morph_impl<B: Element>(
         DType::Bool(BoolStore::Native) => {
             morph_bool::<B>(data, shape, kernel, op, iter, btype, bvalue)
         }
-        DType::QFloat(_) => unimplemented!(),
+        DType::F8E4M3 | DType::F8E5M2 | DType::QFloat(_) | DType::Complex64 | DType::Complex32 => {
+            unimplemented!()
+        }
     }
 }
 